@@ -62,6 +62,23 @@ impl WheelFilename {
         compatible_tags.compatibility(&self.python_tag, &self.abi_tag, &self.platform_tag)
     }
 
+    /// Returns the minimum CPython version supported by an `abi3` wheel (e.g., `(3, 8)` for
+    /// `cp38-abi3`), or `None` if the wheel doesn't target the stable ABI.
+    pub fn abi3_minimum_python(&self) -> Option<(u8, u8)> {
+        if !self.abi_tag.iter().any(|tag| tag == "abi3") {
+            return None;
+        }
+        self.python_tag
+            .iter()
+            .filter_map(|tag| {
+                let version = tag.strip_prefix("cp")?;
+                let major = version.get(..1)?.parse::<u8>().ok()?;
+                let minor = version.get(1..)?.parse::<u8>().ok()?;
+                Some((major, minor))
+            })
+            .min()
+    }
+
     /// Get the tag for this wheel.
     pub fn get_tag(&self) -> String {
         format!(
@@ -319,4 +336,16 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn abi3_minimum_python() {
+        let filename =
+            WheelFilename::from_str("cryptography-42.0.5-cp39-abi3-manylinux_2_28_x86_64.whl")
+                .unwrap();
+        assert_eq!(filename.abi3_minimum_python(), Some((3, 9)));
+
+        let filename =
+            WheelFilename::from_str("numpy-1.26.2-cp311-cp311-manylinux_2_17_x86_64.whl").unwrap();
+        assert_eq!(filename.abi3_minimum_python(), None);
+    }
 }
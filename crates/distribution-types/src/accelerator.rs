@@ -0,0 +1,177 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use serde::{de, Deserialize, Deserializer};
+use url::Url;
+
+use pep508_rs::VerbatimUrl;
+
+use crate::IndexUrl;
+
+/// A hardware accelerator, used to select between builds of a package (e.g., CUDA vs. CPU-only
+/// builds of PyTorch) that are published to separate indexes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Accelerator {
+    /// No accelerator, i.e., CPU-only builds.
+    Cpu,
+    /// An NVIDIA GPU, supporting CUDA up to the given version (e.g., `cu121` for CUDA 12.1).
+    Cuda { major: u8, minor: u8 },
+    /// An AMD GPU, with ROCm installed at the given version (e.g., `rocm5.7`).
+    Rocm { major: u8, minor: u8 },
+}
+
+impl Accelerator {
+    /// Select the index to use for this accelerator from a set of configured indexes.
+    ///
+    /// For CUDA and ROCm, the index with the highest version that's supported by the accelerator
+    /// is selected, falling back to the CPU index if no such index is configured.
+    pub fn select<'a>(&self, indexes: &'a [AcceleratorIndex]) -> Option<&'a AcceleratorIndex> {
+        let version = |accelerator: &Accelerator| match *accelerator {
+            Self::Cpu => None,
+            Self::Cuda { major, minor } | Self::Rocm { major, minor } => Some((major, minor)),
+        };
+        let compatible = indexes
+            .iter()
+            .filter(|index| match (*self, index.accelerator) {
+                (Self::Cuda { .. }, Self::Cuda { .. }) | (Self::Rocm { .. }, Self::Rocm { .. }) => {
+                    version(&index.accelerator) <= version(self)
+                }
+                _ => false,
+            })
+            .max_by_key(|index| version(&index.accelerator));
+        compatible.or_else(|| indexes.iter().find(|index| index.accelerator == Self::Cpu))
+    }
+}
+
+impl FromStr for Accelerator {
+    type Err = AcceleratorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "cpu" {
+            return Ok(Self::Cpu);
+        }
+
+        if let Some(version) = s.strip_prefix("cu") {
+            // CUDA versions are written without a separator, with a single-digit minor version
+            // (e.g., `cu118` for 11.8, or `cu121` for 12.1).
+            if version.len() >= 2 && version.bytes().all(|byte| byte.is_ascii_digit()) {
+                let (major, minor) = version.split_at(version.len() - 1);
+                if let (Ok(major), Ok(minor)) = (major.parse(), minor.parse()) {
+                    return Ok(Self::Cuda { major, minor });
+                }
+            }
+        }
+
+        if let Some(version) = s.strip_prefix("rocm") {
+            if let Some((major, minor)) = version.split_once('.') {
+                if let (Ok(major), Ok(minor)) = (major.parse(), minor.parse()) {
+                    return Ok(Self::Rocm { major, minor });
+                }
+            }
+        }
+
+        Err(AcceleratorError(s.to_string()))
+    }
+}
+
+impl Display for Accelerator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Cpu => write!(f, "cpu"),
+            Self::Cuda { major, minor } => write!(f, "cu{major}{minor}"),
+            Self::Rocm { major, minor } => write!(f, "rocm{major}.{minor}"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Accelerator {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        FromStr::from_str(&s).map_err(de::Error::custom)
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+#[error("Invalid accelerator `{0}` (expected `cpu`, `cu<version>`, or `rocm<version>`)")]
+pub struct AcceleratorError(String);
+
+/// An index to use for a specific accelerator, as configured via `[[pip.accelerator-index]]`.
+///
+/// When resolving, the index for the detected (or requested) accelerator is queried ahead of any
+/// other indexes.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct AcceleratorIndex {
+    /// The accelerator to which the index applies.
+    pub accelerator: Accelerator,
+    /// The URL of the index.
+    pub url: Url,
+}
+
+impl AcceleratorIndex {
+    /// Return the [`IndexUrl`] for the index.
+    pub fn index_url(&self) -> IndexUrl {
+        IndexUrl::from(VerbatimUrl::from_url(self.url.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index(accelerator: &str) -> AcceleratorIndex {
+        AcceleratorIndex {
+            accelerator: Accelerator::from_str(accelerator).unwrap(),
+            url: Url::parse(&format!("https://download.pytorch.org/whl/{accelerator}")).unwrap(),
+        }
+    }
+
+    #[test]
+    fn parse() {
+        for accelerator in ["cpu", "cu118", "cu121", "rocm5.7"] {
+            assert_eq!(
+                Accelerator::from_str(accelerator).unwrap().to_string(),
+                accelerator
+            );
+        }
+        assert_eq!(
+            Accelerator::from_str("cu121").unwrap(),
+            Accelerator::Cuda {
+                major: 12,
+                minor: 1
+            }
+        );
+        assert!(Accelerator::from_str("cu").is_err());
+        assert!(Accelerator::from_str("rocm5").is_err());
+        assert!(Accelerator::from_str("gpu").is_err());
+    }
+
+    #[test]
+    fn select() {
+        let indexes = [
+            index("cpu"),
+            index("cu118"),
+            index("cu121"),
+            index("rocm5.7"),
+        ];
+        let select = |accelerator: &str| {
+            Accelerator::from_str(accelerator)
+                .unwrap()
+                .select(&indexes)
+                .map(|index| index.accelerator.to_string())
+        };
+
+        // Select the newest CUDA index supported by the driver.
+        assert_eq!(select("cu124").as_deref(), Some("cu121"));
+        assert_eq!(select("cu120").as_deref(), Some("cu118"));
+
+        // Fall back to the CPU index if the driver is too old.
+        assert_eq!(select("cu102").as_deref(), Some("cpu"));
+
+        assert_eq!(select("rocm6.0").as_deref(), Some("rocm5.7"));
+        assert_eq!(select("cpu").as_deref(), Some("cpu"));
+    }
+}
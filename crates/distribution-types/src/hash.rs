@@ -4,8 +4,8 @@ use pypi_types::{HashAlgorithm, HashDigest};
 pub enum HashPolicy<'a> {
     /// No hash policy is specified.
     None,
-    /// Hashes should be generated (specifically, a SHA-256 hash), but not validated.
-    Generate,
+    /// Hashes should be generated (using the given algorithms), but not validated.
+    Generate(&'a [HashAlgorithm]),
     /// Hashes should be validated against a pre-defined list of hashes. If necessary, hashes should
     /// be generated so as to ensure that the archive is valid.
     Validate(&'a [HashDigest]),
@@ -19,7 +19,7 @@ impl<'a> HashPolicy<'a> {
 
     /// Returns `true` if the hash policy is `Generate`.
     pub fn is_generate(&self) -> bool {
-        matches!(self, Self::Generate)
+        matches!(self, Self::Generate(_))
     }
 
     /// Returns `true` if the hash policy is `Validate`.
@@ -31,7 +31,7 @@ impl<'a> HashPolicy<'a> {
     pub fn algorithms(&self) -> Vec<HashAlgorithm> {
         match self {
             Self::None => vec![],
-            Self::Generate => vec![HashAlgorithm::Sha256],
+            Self::Generate(algorithms) => algorithms.to_vec(),
             Self::Validate(hashes) => {
                 let mut algorithms = hashes.iter().map(HashDigest::algorithm).collect::<Vec<_>>();
                 algorithms.sort();
@@ -45,7 +45,7 @@ impl<'a> HashPolicy<'a> {
     pub fn digests(&self) -> &[HashDigest] {
         match self {
             Self::None => &[],
-            Self::Generate => &[],
+            Self::Generate(_) => &[],
             Self::Validate(hashes) => hashes,
         }
     }
@@ -59,10 +59,11 @@ pub trait Hashed {
     fn satisfies(&self, hashes: HashPolicy) -> bool {
         match hashes {
             HashPolicy::None => true,
-            HashPolicy::Generate => self
-                .hashes()
-                .iter()
-                .any(|hash| hash.algorithm == HashAlgorithm::Sha256),
+            HashPolicy::Generate(algorithms) => algorithms.iter().all(|algorithm| {
+                self.hashes()
+                    .iter()
+                    .any(|hash| hash.algorithm == *algorithm)
+            }),
             HashPolicy::Validate(hashes) => self.hashes().iter().any(|hash| hashes.contains(hash)),
         }
     }
@@ -71,10 +72,11 @@ pub trait Hashed {
     fn has_digests(&self, hashes: HashPolicy) -> bool {
         match hashes {
             HashPolicy::None => true,
-            HashPolicy::Generate => self
-                .hashes()
-                .iter()
-                .any(|hash| hash.algorithm == HashAlgorithm::Sha256),
+            HashPolicy::Generate(algorithms) => algorithms.iter().all(|algorithm| {
+                self.hashes()
+                    .iter()
+                    .any(|hash| hash.algorithm == *algorithm)
+            }),
             HashPolicy::Validate(hashes) => hashes
                 .iter()
                 .map(HashDigest::algorithm)
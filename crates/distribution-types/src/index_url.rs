@@ -11,8 +11,9 @@ use url::Url;
 
 use pep508_rs::{expand_env_vars, split_scheme, strip_host, Scheme, VerbatimUrl};
 use uv_fs::normalize_url_path;
+use uv_normalize::PackageName;
 
-use crate::Verbatim;
+use crate::{IndexApi, IndexFlavor, IndexSettings, Verbatim};
 
 static PYPI_URL: Lazy<Url> = Lazy::new(|| Url::parse("https://pypi.org/simple").unwrap());
 
@@ -180,6 +181,7 @@ pub struct IndexLocations {
     extra_index: Vec<IndexUrl>,
     flat_index: Vec<FlatIndexLocation>,
     no_index: bool,
+    settings: Vec<IndexSettings>,
 }
 
 impl Default for IndexLocations {
@@ -190,6 +192,7 @@ impl Default for IndexLocations {
             extra_index: Vec::new(),
             flat_index: Vec::new(),
             no_index: false,
+            settings: Vec::new(),
         }
     }
 }
@@ -207,9 +210,16 @@ impl IndexLocations {
             extra_index,
             flat_index,
             no_index,
+            settings: Vec::new(),
         }
     }
 
+    /// Set the per-index [`IndexSettings`] (e.g., the flavor of each index server).
+    #[must_use]
+    pub fn with_settings(self, settings: Vec<IndexSettings>) -> Self {
+        Self { settings, ..self }
+    }
+
     /// Combine a set of index locations.
     ///
     /// If either the current or the other index locations have `no_index` set, the result will
@@ -229,6 +239,7 @@ impl IndexLocations {
             extra_index: self.extra_index.into_iter().chain(extra_index).collect(),
             flat_index: self.flat_index.into_iter().chain(flat_index).collect(),
             no_index: self.no_index || no_index,
+            settings: self.settings,
         }
     }
 }
@@ -264,17 +275,36 @@ impl<'a> IndexLocations {
         self.index().into_iter().chain(self.extra_index())
     }
 
+    /// Return an iterator over the [`IndexUrl`] entries from which the given package may be
+    /// fetched.
+    ///
+    /// See [`IndexUrls::indexes_for`].
+    pub fn indexes_for(
+        &'a self,
+        package: &'a PackageName,
+    ) -> impl Iterator<Item = &'a IndexUrl> + 'a {
+        let pinned = is_pinned(&self.settings, package);
+        self.indexes()
+            .filter(move |index| !pinned || is_pinned_to(&self.settings, package, index))
+    }
+
     /// Return an iterator over the [`FlatIndexLocation`] entries.
     pub fn flat_index(&'a self) -> impl Iterator<Item = &'a FlatIndexLocation> + 'a {
         self.flat_index.iter()
     }
 
+    /// Return the per-index [`IndexSettings`].
+    pub fn settings(&'a self) -> &'a [IndexSettings] {
+        &self.settings
+    }
+
     /// Clone the index locations into a [`IndexUrls`] instance.
     pub fn index_urls(&'a self) -> IndexUrls {
         IndexUrls {
             index: self.index.clone(),
             extra_index: self.extra_index.clone(),
             no_index: self.no_index,
+            settings: self.settings.clone(),
         }
     }
 
@@ -297,6 +327,7 @@ pub struct IndexUrls {
     index: Option<IndexUrl>,
     extra_index: Vec<IndexUrl>,
     no_index: bool,
+    settings: Vec<IndexSettings>,
 }
 
 impl Default for IndexUrls {
@@ -306,6 +337,7 @@ impl Default for IndexUrls {
             index: Some(DEFAULT_INDEX_URL.clone()),
             extra_index: Vec::new(),
             no_index: false,
+            settings: Vec::new(),
         }
     }
 }
@@ -345,6 +377,53 @@ impl<'a> IndexUrls {
     pub fn indexes(&'a self) -> impl Iterator<Item = &'a IndexUrl> + 'a {
         self.extra_index().chain(self.index())
     }
+
+    /// Return an iterator over the [`IndexUrl`] entries from which the given package may be
+    /// fetched, in order.
+    ///
+    /// If the package is pinned to one or more indexes (via [`IndexSettings::packages`]), only
+    /// those indexes are returned, if in use. Otherwise, all indexes are returned.
+    pub fn indexes_for(
+        &'a self,
+        package: &'a PackageName,
+    ) -> impl Iterator<Item = &'a IndexUrl> + 'a {
+        let pinned = is_pinned(&self.settings, package);
+        self.indexes()
+            .filter(move |index| !pinned || is_pinned_to(&self.settings, package, index))
+    }
+
+    /// Return the [`IndexSettings`] configured for the given index, if any.
+    fn settings(&self, index: &IndexUrl) -> Option<&IndexSettings> {
+        self.settings
+            .iter()
+            .find(|settings| settings.matches(index.url()))
+    }
+
+    /// Return the [`IndexFlavor`] configured for the given index.
+    pub fn flavor(&self, index: &IndexUrl) -> IndexFlavor {
+        self.settings(index)
+            .map(|settings| settings.flavor)
+            .unwrap_or_default()
+    }
+
+    /// Return the [`IndexApi`] configured for the given index.
+    pub fn api(&self, index: &IndexUrl) -> IndexApi {
+        self.settings(index)
+            .map(|settings| settings.api)
+            .unwrap_or_default()
+    }
+}
+
+/// Returns `true` if the given package is pinned to any index.
+fn is_pinned(settings: &[IndexSettings], package: &PackageName) -> bool {
+    settings.iter().any(|settings| settings.pins(package))
+}
+
+/// Returns `true` if the given package is pinned to the given index.
+fn is_pinned_to(settings: &[IndexSettings], package: &PackageName, index: &IndexUrl) -> bool {
+    settings
+        .iter()
+        .any(|settings| settings.matches(index.url()) && settings.pins(package))
 }
 
 impl From<IndexLocations> for IndexUrls {
@@ -353,6 +432,7 @@ impl From<IndexLocations> for IndexUrls {
             index: locations.index,
             extra_index: locations.extra_index,
             no_index: locations.no_index,
+            settings: locations.settings,
         }
     }
 }
@@ -360,6 +440,8 @@ impl From<IndexLocations> for IndexUrls {
 #[cfg(test)]
 #[cfg(unix)]
 mod test {
+    use crate::PackageSelector;
+
     use super::*;
 
     #[test]
@@ -384,4 +466,43 @@ mod test {
             )
         );
     }
+
+    #[test]
+    fn indexes_for() {
+        let internal = IndexUrl::from_str("https://pypi.corp.example.com/simple").unwrap();
+        let index_urls = IndexLocations::new(None, vec![internal.clone()], vec![], false)
+            .with_settings(vec![IndexSettings {
+                url: Url::parse("https://pypi.corp.example.com/simple/").unwrap(),
+                flavor: IndexFlavor::default(),
+                api: IndexApi::default(),
+                packages: vec![
+                    PackageSelector::from_str("corp-lib").unwrap(),
+                    PackageSelector::from_str("internal-*").unwrap(),
+                ],
+                proxy: None,
+                cert: None,
+                allow_insecure: false,
+                pins: vec![],
+            }])
+            .index_urls();
+
+        // Pinned packages are only fetched from the pinned index.
+        let corp_lib = PackageName::from_str("corp-lib").unwrap();
+        assert_eq!(
+            index_urls.indexes_for(&corp_lib).collect::<Vec<_>>(),
+            vec![&internal]
+        );
+        let internal_auth = PackageName::from_str("internal-auth").unwrap();
+        assert_eq!(
+            index_urls.indexes_for(&internal_auth).collect::<Vec<_>>(),
+            vec![&internal]
+        );
+
+        // Other packages are fetched from every index.
+        let anyio = PackageName::from_str("anyio").unwrap();
+        assert_eq!(
+            index_urls.indexes_for(&anyio).collect::<Vec<_>>(),
+            vec![&internal, &*DEFAULT_INDEX_URL]
+        );
+    }
 }
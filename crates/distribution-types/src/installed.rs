@@ -129,6 +129,12 @@ impl InstalledDist {
         }
     }
 
+    /// Return true if the distribution was requested directly by the user (as opposed to being
+    /// installed as a dependency), as indicated by the presence of a `REQUESTED` file.
+    pub fn is_requested(&self) -> bool {
+        self.path().join("REQUESTED").is_file()
+    }
+
     /// Return true if the distribution is editable.
     pub fn is_editable(&self) -> bool {
         match self {
@@ -44,6 +44,7 @@ use pep440_rs::Version;
 use pep508_rs::{Scheme, VerbatimUrl};
 use uv_normalize::PackageName;
 
+pub use crate::accelerator::*;
 pub use crate::any::*;
 pub use crate::buildable::*;
 pub use crate::cached::*;
@@ -53,6 +54,7 @@ pub use crate::error::*;
 pub use crate::file::*;
 pub use crate::hash::*;
 pub use crate::id::*;
+pub use crate::index_settings::*;
 pub use crate::index_url::*;
 pub use crate::installed::*;
 pub use crate::prioritized_distribution::*;
@@ -60,6 +62,7 @@ pub use crate::resolution::*;
 pub use crate::resolved::*;
 pub use crate::traits::*;
 
+mod accelerator;
 mod any;
 mod buildable;
 mod cached;
@@ -69,6 +72,7 @@ mod error;
 mod file;
 mod hash;
 mod id;
+mod index_settings;
 mod index_url;
 mod installed;
 mod prioritized_distribution;
@@ -149,6 +149,28 @@ pub enum Hash {
     Matched,
 }
 
+impl Hash {
+    /// Compare the hashes reported for a distribution against the required hashes.
+    ///
+    /// As in pip, a distribution matches if any of its hashes matches any of the required hashes.
+    /// If the distribution doesn't report a hash for any of the required algorithms (e.g., the
+    /// index only reports SHA-256, but a SHA-512 hash is required), its hash is considered missing
+    /// rather than mismatched, since it can only be verified once downloaded.
+    pub fn from_digests(required: &[HashDigest], hashes: &[HashDigest]) -> Self {
+        if hashes.iter().any(|hash| required.contains(hash)) {
+            Self::Matched
+        } else if hashes.iter().any(|hash| {
+            required
+                .iter()
+                .any(|required| required.algorithm == hash.algorithm)
+        }) {
+            Self::Mismatched
+        } else {
+            Self::Missing
+        }
+    }
+}
+
 impl PrioritizedDist {
     /// Create a new [`PrioritizedDist`] from the given wheel distribution.
     pub fn from_built(
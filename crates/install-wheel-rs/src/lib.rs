@@ -11,15 +11,18 @@ use zip::result::ZipError;
 use pep440_rs::Version;
 use platform_tags::{Arch, Os};
 use pypi_types::Scheme;
-pub use uninstall::{uninstall_wheel, Uninstall};
+pub use uninstall::{backup_wheel, uninstall_legacy, uninstall_wheel, LegacyUninstall, Uninstall};
 use uv_fs::Simplified;
 use uv_normalize::PackageName;
+pub use verify::{installed_files, installed_scripts, FileStatus, InstalledFile};
+pub use wheel::{installed_tags, mark_requested};
 
 pub mod linker;
 pub mod metadata;
 mod record;
 mod script;
 mod uninstall;
+mod verify;
 mod wheel;
 
 /// The layout of the target environment into which a wheel can be installed.
@@ -8,6 +8,7 @@ use std::time::SystemTime;
 use fs_err as fs;
 use fs_err::{DirEntry, File};
 use reflink_copy as reflink;
+use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
 use tempfile::tempdir_in;
 use tracing::{debug, instrument};
@@ -20,7 +21,7 @@ use uv_normalize::PackageName;
 use crate::script::{scripts_from_ini, Script};
 use crate::wheel::{
     extra_dist_info, install_data, parse_metadata, parse_wheel_file, read_record_file,
-    write_script_entrypoints, LibKind,
+    write_record_file, write_script_entrypoints, LibKind,
 };
 use crate::{Error, Layout};
 
@@ -36,9 +37,11 @@ pub fn install_wheel(
     layout: &Layout,
     wheel: impl AsRef<Path>,
     filename: &WheelFilename,
+    requested: bool,
     direct_url: Option<&DirectUrl>,
     installer: Option<&str>,
     link_mode: LinkMode,
+    renamed_scripts: &FxHashMap<String, String>,
 ) -> Result<(), Error> {
     let dist_info_prefix = find_dist_info(&wheel)?;
     let metadata = dist_info_metadata(&dist_info_prefix, &wheel)?;
@@ -88,8 +91,29 @@ pub fn install_wheel(
     debug!(name, "Writing entrypoints");
     let (console_scripts, gui_scripts) =
         parse_scripts(&wheel, &dist_info_prefix, None, layout.python_version.1)?;
-    write_script_entrypoints(layout, site_packages, &console_scripts, &mut record, false)?;
-    write_script_entrypoints(layout, site_packages, &gui_scripts, &mut record, true)?;
+    // Install any scripts that conflict with those of other packages under their new names.
+    let rename = |scripts: &[Script]| -> Vec<Script> {
+        scripts
+            .iter()
+            .map(|script| match renamed_scripts.get(&script.name) {
+                Some(name) => Script {
+                    name: name.clone(),
+                    ..script.clone()
+                },
+                None => script.clone(),
+            })
+            .collect()
+    };
+    let console_entrypoints = rename(&console_scripts);
+    let gui_entrypoints = rename(&gui_scripts);
+    write_script_entrypoints(
+        layout,
+        site_packages,
+        &console_entrypoints,
+        &mut record,
+        false,
+    )?;
+    write_script_entrypoints(layout, site_packages, &gui_entrypoints, &mut record, true)?;
 
     // 2.a Unpacked archive includes distribution-1.0.dist-info/ and (if there is data) distribution-1.0.data/.
     // 2.b Move each subtree of distribution-1.0.data/ onto its destination path. Each subdirectory of distribution-1.0.data/ is a key into a dict of destination directories, such as distribution-1.0.data/(purelib|platlib|headers|scripts|data). The initially supported paths are taken from distutils.command.install.
@@ -117,23 +141,17 @@ pub fn install_wheel(
     extra_dist_info(
         site_packages,
         &dist_info_prefix,
-        true,
+        requested,
         direct_url,
         installer,
         &mut record,
     )?;
 
     debug!(name, "Writing record");
-    let mut record_writer = csv::WriterBuilder::new()
-        .has_headers(false)
-        .escape(b'"')
-        .from_path(site_packages.join(format!("{dist_info_prefix}.dist-info/RECORD")))?;
-    record.sort();
-    for entry in record {
-        record_writer.serialize(entry)?;
-    }
-
-    Ok(())
+    write_record_file(
+        &site_packages.join(format!("{dist_info_prefix}.dist-info/RECORD")),
+        record,
+    )
 }
 
 /// Find the `dist-info` directory in an unzipped wheel.
@@ -179,6 +197,18 @@ fn dist_info_metadata(dist_info_prefix: &str, wheel: impl AsRef<Path>) -> Result
     Ok(fs::read(metadata_file)?)
 }
 
+/// Return the names of the console and GUI scripts declared by the given (unzipped) wheel.
+pub fn wheel_scripts(wheel: impl AsRef<Path>, python_minor: u8) -> Result<Vec<String>, Error> {
+    let dist_info_prefix = find_dist_info(&wheel)?;
+    let (console_scripts, gui_scripts) =
+        parse_scripts(&wheel, &dist_info_prefix, None, python_minor)?;
+    Ok(console_scripts
+        .into_iter()
+        .chain(gui_scripts)
+        .map(|script| script.name)
+        .collect())
+}
+
 /// Parses the `entry_points.txt` entry in the wheel for console scripts
 ///
 /// Returns (`script_name`, module, function)
@@ -211,6 +241,13 @@ pub enum LinkMode {
     Copy,
     /// Hard link packages from the wheel into the site packages.
     Hardlink,
+    /// Symbolically link packages from the wheel into the site packages.
+    ///
+    /// The environment becomes a lightweight view onto the global cache, such that each package
+    /// is stored on disk exactly once, regardless of the number of environments in which it's
+    /// installed. As a consequence, clearing or pruning the cache will break any environments
+    /// that were installed with this mode.
+    Symlink,
 }
 
 impl Default for LinkMode {
@@ -235,6 +272,7 @@ impl LinkMode {
             Self::Clone => clone_wheel_files(site_packages, wheel),
             Self::Copy => copy_wheel_files(site_packages, wheel),
             Self::Hardlink => hardlink_wheel_files(site_packages, wheel),
+            Self::Symlink => symlink_wheel_files(site_packages, wheel),
         }
     }
 }
@@ -332,7 +370,7 @@ fn clone_recursive(
                         let tempdir = tempdir_in(site_packages)?;
                         let tempfile = tempdir.path().join(from.file_name().unwrap());
                         if reflink::reflink(&from, &tempfile).is_ok() {
-                            fs::rename(&tempfile, to)?;
+                            uv_fs::rename_with_retry_sync(&tempfile, to)?;
                         } else {
                             debug!(
                                 "Failed to clone `{}` to temporary location `{}`, attempting to copy files as a fallback",
@@ -367,7 +405,7 @@ fn clone_recursive(
                         let tempdir = tempdir_in(site_packages)?;
                         let tempfile = tempdir.path().join(from.file_name().unwrap());
                         reflink::reflink(&from, &tempfile)?;
-                        fs::rename(&tempfile, to)?;
+                        uv_fs::rename_with_retry_sync(&tempfile, to)?;
                     }
                 } else {
                     return Err(Error::Reflink { from, to, err });
@@ -465,7 +503,7 @@ fn hardlink_wheel_files(
                         let tempdir = tempdir_in(&site_packages)?;
                         let tempfile = tempdir.path().join(entry.file_name());
                         if fs::hard_link(path, &tempfile).is_ok() {
-                            fs_err::rename(&tempfile, &out_path)?;
+                            uv_fs::rename_with_retry_sync(&tempfile, &out_path)?;
                         } else {
                             debug!(
                                 "Failed to hardlink `{}` to `{}`, attempting to copy files as a fallback",
@@ -498,7 +536,7 @@ fn hardlink_wheel_files(
                         let tempdir = tempdir_in(&site_packages)?;
                         let tempfile = tempdir.path().join(entry.file_name());
                         fs::hard_link(path, &tempfile)?;
-                        fs_err::rename(&tempfile, &out_path)?;
+                        uv_fs::rename_with_retry_sync(&tempfile, &out_path)?;
                     } else {
                         return Err(err.into());
                     }
@@ -514,3 +552,221 @@ fn hardlink_wheel_files(
 
     Ok(count)
 }
+
+/// Extract a wheel by symbolically linking all of its files into site packages.
+fn symlink_wheel_files(
+    site_packages: impl AsRef<Path>,
+    wheel: impl AsRef<Path>,
+) -> Result<usize, Error> {
+    let mut attempt = Attempt::default();
+    let mut count = 0usize;
+
+    // Resolve the wheel to its location in the archive bucket, such that the links remain
+    // stable even if the (symlinked) cache entry is later replaced.
+    let wheel = fs::canonicalize(wheel.as_ref())?;
+
+    // Walk over the directory.
+    for entry in walkdir::WalkDir::new(&wheel) {
+        let entry = entry?;
+        let path = entry.path();
+
+        let relative = path.strip_prefix(&wheel).unwrap();
+        let out_path = site_packages.as_ref().join(relative);
+
+        if entry.file_type().is_dir() {
+            fs::create_dir_all(&out_path)?;
+            continue;
+        }
+
+        // Files that are modified during installation are copied instead of linked, to avoid
+        // writing through the link into the cache.
+        if is_modified_on_install(relative) {
+            copy_file(path, &out_path)?;
+            count += 1;
+            continue;
+        }
+
+        // Fallback to copying if symlinks aren't supported for this installation (e.g., on Windows
+        // without Developer Mode).
+        match attempt {
+            Attempt::Initial => {
+                attempt = Attempt::Subsequent;
+                if let Err(err) = create_symlink(path, &out_path) {
+                    // If the file already exists, remove it and try again.
+                    if err.kind() == std::io::ErrorKind::AlreadyExists {
+                        debug!(
+                            "File already exists (initial attempt), overwriting: {}",
+                            out_path.display()
+                        );
+                        // Removing and recreating would lead to race conditions.
+                        let tempdir = tempdir_in(&site_packages)?;
+                        let tempfile = tempdir.path().join(entry.file_name());
+                        if create_symlink(path, &tempfile).is_ok() {
+                            uv_fs::rename_with_retry_sync(&tempfile, &out_path)?;
+                        } else {
+                            debug!(
+                                "Failed to symlink `{}` to `{}`, attempting to copy files as a fallback",
+                                out_path.display(),
+                                path.display()
+                            );
+                            copy_file(path, &out_path)?;
+                            attempt = Attempt::UseCopyFallback;
+                        }
+                    } else {
+                        debug!(
+                            "Failed to symlink `{}` to `{}`, attempting to copy files as a fallback",
+                            out_path.display(),
+                            path.display()
+                        );
+                        copy_file(path, &out_path)?;
+                        attempt = Attempt::UseCopyFallback;
+                    }
+                }
+            }
+            Attempt::Subsequent => {
+                if let Err(err) = create_symlink(path, &out_path) {
+                    // If the file already exists, remove it and try again.
+                    if err.kind() == std::io::ErrorKind::AlreadyExists {
+                        debug!(
+                            "File already exists (subsequent attempt), overwriting: {}",
+                            out_path.display()
+                        );
+                        // Removing and recreating would lead to race conditions.
+                        let tempdir = tempdir_in(&site_packages)?;
+                        let tempfile = tempdir.path().join(entry.file_name());
+                        create_symlink(path, &tempfile)?;
+                        uv_fs::rename_with_retry_sync(&tempfile, &out_path)?;
+                    } else {
+                        return Err(err.into());
+                    }
+                }
+            }
+            Attempt::UseCopyFallback => {
+                copy_file(path, &out_path)?;
+            }
+        }
+
+        count += 1;
+    }
+
+    Ok(count)
+}
+
+/// Returns `true` if the file at the given path (relative to the wheel root) is rewritten or has
+/// its permissions changed during installation.
+///
+/// This includes everything in the `.dist-info` directory (e.g., `RECORD` is rewritten, and
+/// `INSTALLER` is added) and the scripts in `.data/scripts`, which may have their shebang rewritten
+/// and are marked as executable.
+fn is_modified_on_install(relative: &Path) -> bool {
+    let mut components = relative.components().map(|component| component.as_os_str());
+    let Some(first) = components.next() else {
+        return false;
+    };
+    match Path::new(first).extension() {
+        Some(ext) if ext == "dist-info" => true,
+        Some(ext) if ext == "data" => components.next().is_some_and(|second| second == "scripts"),
+        _ => false,
+    }
+}
+
+/// Copy a file to `dst`, replacing any existing symbolic link rather than writing through it
+/// (e.g., into the cache, for a file that was previously installed via symlink).
+fn copy_file(src: &Path, dst: &Path) -> std::io::Result<u64> {
+    if fs::symlink_metadata(dst).is_ok_and(|metadata| metadata.file_type().is_symlink()) {
+        fs::remove_file(dst)?;
+    }
+    fs::copy(src, dst)
+}
+
+/// Create a symbolic link to a file at `dst`, pointing to `src`.
+#[cfg(unix)]
+fn create_symlink(src: &Path, dst: &Path) -> std::io::Result<()> {
+    fs_err::os::unix::fs::symlink(src, dst)
+}
+
+/// Create a symbolic link to a file at `dst`, pointing to `src`.
+#[cfg(windows)]
+fn create_symlink(src: &Path, dst: &Path) -> std::io::Result<()> {
+    fs_err::os::windows::fs::symlink_file(src, dst)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use fs_err as fs;
+
+    use crate::Error;
+
+    use super::{is_modified_on_install, symlink_wheel_files};
+
+    #[test]
+    fn modified_on_install() {
+        assert!(is_modified_on_install(Path::new(
+            "foo-1.0.dist-info/RECORD"
+        )));
+        assert!(is_modified_on_install(Path::new(
+            "foo-1.0.data/scripts/foo"
+        )));
+        assert!(!is_modified_on_install(Path::new("foo-1.0.data/data/foo")));
+        assert!(!is_modified_on_install(Path::new("foo/scripts/foo.py")));
+        assert!(!is_modified_on_install(Path::new("foo/__init__.py")));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn symlink_copies_modified_files() -> Result<(), Error> {
+        let wheel = tempfile::tempdir()?;
+        fs::create_dir_all(wheel.path().join("foo"))?;
+        fs::create_dir_all(wheel.path().join("foo-1.0.dist-info"))?;
+        fs::create_dir_all(wheel.path().join("foo-1.0.data/scripts"))?;
+        fs::write(wheel.path().join("foo/__init__.py"), "")?;
+        fs::write(wheel.path().join("foo-1.0.dist-info/RECORD"), "")?;
+        fs::write(wheel.path().join("foo-1.0.data/scripts/foo"), "#!python\n")?;
+
+        let site_packages = tempfile::tempdir()?;
+        let count = symlink_wheel_files(site_packages.path(), wheel.path())?;
+        assert_eq!(count, 3);
+
+        let is_symlink = |path: &str| -> Result<bool, Error> {
+            Ok(fs::symlink_metadata(site_packages.path().join(path))?
+                .file_type()
+                .is_symlink())
+        };
+        assert!(is_symlink("foo/__init__.py")?);
+        assert!(!is_symlink("foo-1.0.dist-info/RECORD")?);
+        assert!(!is_symlink("foo-1.0.data/scripts/foo")?);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn symlink_replaces_existing_symlink() -> Result<(), Error> {
+        let wheel = tempfile::tempdir()?;
+        fs::create_dir_all(wheel.path().join("foo-1.0.dist-info"))?;
+        fs::write(wheel.path().join("foo-1.0.dist-info/RECORD"), "new")?;
+
+        // A file from a previous installation, linked into the cache.
+        let cache = tempfile::tempdir()?;
+        fs::write(cache.path().join("RECORD"), "old")?;
+
+        let site_packages = tempfile::tempdir()?;
+        fs::create_dir_all(site_packages.path().join("foo-1.0.dist-info"))?;
+        fs_err::os::unix::fs::symlink(
+            cache.path().join("RECORD"),
+            site_packages.path().join("foo-1.0.dist-info/RECORD"),
+        )?;
+
+        symlink_wheel_files(site_packages.path(), wheel.path())?;
+
+        // The link should be replaced by a copy, leaving the linked file untouched.
+        let record = site_packages.path().join("foo-1.0.dist-info/RECORD");
+        assert!(!fs::symlink_metadata(&record)?.file_type().is_symlink());
+        assert_eq!(fs::read_to_string(&record)?, "new");
+        assert_eq!(fs::read_to_string(cache.path().join("RECORD"))?, "old");
+
+        Ok(())
+    }
+}
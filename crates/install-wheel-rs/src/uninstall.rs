@@ -118,6 +118,165 @@ pub fn uninstall_wheel(dist_info: &Path) -> Result<Uninstall, Error> {
     })
 }
 
+/// Uninstall the package represented by the given `dist_info` directory on a best-effort basis,
+/// for packages without a `RECORD` file (e.g., those installed by `distutils`).
+///
+/// In lieu of a `RECORD`, the top-level packages and modules listed in `top_level.txt` are removed
+/// from `site-packages`, along with the `.dist-info` directory itself. Any other files (like
+/// scripts or data files) are left in place.
+pub fn uninstall_legacy(dist_info: &Path) -> Result<LegacyUninstall, Error> {
+    let Some(site_packages) = dist_info.parent() else {
+        return Err(Error::BrokenVenv(
+            "dist-info directory is not in a site-packages directory".to_string(),
+        ));
+    };
+
+    // Read the `top_level.txt` file, if it exists.
+    let top_level = match fs::read_to_string(dist_info.join("top_level.txt")) {
+        Ok(top_level) => Some(
+            top_level
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+        ),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => None,
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut uninstall = LegacyUninstall {
+        top_level: top_level.is_some(),
+        ..LegacyUninstall::default()
+    };
+
+    for name in top_level.iter().flatten() {
+        // Ignore anything that isn't a plain module name, to avoid removing files outside of
+        // `site-packages`.
+        if !matches!(
+            Path::new(name).components().collect::<Vec<_>>().as_slice(),
+            [Component::Normal(_)]
+        ) {
+            uninstall.missing.push(name.clone());
+            continue;
+        }
+
+        let mut found = false;
+
+        // Remove the package directory, e.g., `site-packages/foo`.
+        let package = site_packages.join(name);
+        if package.is_dir() {
+            fs::remove_dir_all(&package)?;
+            debug!("Removed directory: {}", package.display());
+            uninstall.dir_count += 1;
+            found = true;
+        }
+
+        // Remove any modules, e.g., `site-packages/foo.py` or `site-packages/foo.cpython-312.so`,
+        // along with their bytecode.
+        for directory in [
+            site_packages.to_path_buf(),
+            site_packages.join("__pycache__"),
+        ] {
+            let read_dir = match fs::read_dir(&directory) {
+                Ok(read_dir) => read_dir,
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(err) => return Err(err.into()),
+            };
+            for entry in read_dir {
+                let entry = entry?;
+                let file_name = entry.file_name();
+                let Some(file_name) = file_name.to_str() else {
+                    continue;
+                };
+                if !is_module(file_name, name) || !entry.file_type()?.is_file() {
+                    continue;
+                }
+                let path = entry.path();
+                fs::remove_file(&path)?;
+                debug!("Removed file: {}", path.display());
+                uninstall.file_count += 1;
+                found = true;
+            }
+        }
+
+        if !found {
+            uninstall.missing.push(name.clone());
+        }
+    }
+
+    // Remove the `.dist-info` directory itself.
+    fs::remove_dir_all(dist_info)?;
+    debug!("Removed directory: {}", dist_info.display());
+    uninstall.dir_count += 1;
+
+    Ok(uninstall)
+}
+
+/// Returns `true` if the given file name is a module (or compiled module) with the given name.
+fn is_module(file_name: &str, name: &str) -> bool {
+    let Some(suffix) = file_name
+        .strip_prefix(name)
+        .and_then(|suffix| suffix.strip_prefix('.'))
+    else {
+        return false;
+    };
+    let extension = suffix.rsplit('.').next().unwrap_or(suffix);
+    matches!(extension, "py" | "pyc" | "pyo" | "so" | "pyd")
+}
+
+/// Back up the files of the wheel represented by the given `dist_info` directory into `backup`,
+/// such that they can be restored if a subsequent uninstallation needs to be rolled back.
+///
+/// Files are hard-linked into the backup directory where possible, falling back to a copy.
+///
+/// Returns the list of `(original, backup)` paths.
+pub fn backup_wheel(dist_info: &Path, backup: &Path) -> Result<Vec<(PathBuf, PathBuf)>, Error> {
+    let Some(site_packages) = dist_info.parent() else {
+        return Err(Error::BrokenVenv(
+            "dist-info directory is not in a site-packages directory".to_string(),
+        ));
+    };
+
+    // Read the RECORD file.
+    let record = {
+        let record_path = dist_info.join("RECORD");
+        let mut record_file = match fs::File::open(&record_path) {
+            Ok(record_file) => record_file,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Err(Error::MissingRecord(record_path));
+            }
+            Err(err) => return Err(err.into()),
+        };
+        read_record_file(&mut record_file)?
+    };
+
+    fs::create_dir_all(backup)?;
+
+    let mut entries = Vec::with_capacity(record.len());
+    for (index, entry) in record.iter().enumerate() {
+        let path = normalize_path(&site_packages.join(&entry.path));
+        if !path.is_file() {
+            continue;
+        }
+
+        // Use an opaque name within the backup directory, since RECORD entries may point outside
+        // of `site-packages` (e.g., to `bin`).
+        let target = backup.join(index.to_string());
+        if let Err(err) = fs::hard_link(&path, &target) {
+            debug!(
+                "Failed to hardlink `{}` to `{}`, copying instead: {err}",
+                path.display(),
+                target.display()
+            );
+            fs::copy(&path, &target)?;
+        }
+        entries.push((path, target));
+    }
+
+    Ok(entries)
+}
+
 #[derive(Debug, Default)]
 pub struct Uninstall {
     /// The number of files that were removed during the uninstallation.
@@ -126,10 +285,22 @@ pub struct Uninstall {
     pub dir_count: usize,
 }
 
+#[derive(Debug, Default)]
+pub struct LegacyUninstall {
+    /// The number of files that were removed during the uninstallation.
+    pub file_count: usize,
+    /// The number of directories that were removed during the uninstallation.
+    pub dir_count: usize,
+    /// Whether the package declared its top-level modules via `top_level.txt`.
+    pub top_level: bool,
+    /// The top-level modules declared by the package that couldn't be found (and removed).
+    pub missing: Vec<String>,
+}
+
 /// Normalize a path, removing things like `.` and `..`.
 ///
 /// Source: <https://github.com/rust-lang/cargo/blob/b48c41aedbd69ee3990d62a0e2006edbb506a480/crates/cargo-util/src/paths.rs#L76C1-L109C2>
-fn normalize_path(path: &Path) -> PathBuf {
+pub(crate) fn normalize_path(path: &Path) -> PathBuf {
     let mut components = path.components().peekable();
     let mut ret = if let Some(c @ Component::Prefix(..)) = components.peek().copied() {
         components.next();
@@ -0,0 +1,131 @@
+use std::io;
+use std::path::Path;
+
+use data_encoding::BASE64URL_NOPAD;
+use fs_err as fs;
+use sha2::{Digest, Sha256};
+
+use crate::uninstall::normalize_path;
+use crate::wheel::read_record_file;
+use crate::Error;
+
+/// A file installed as part of a distribution, as listed in its `RECORD`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstalledFile {
+    /// The path to the file, relative to the `site-packages` directory.
+    pub path: String,
+    /// The result of verifying the file against its `RECORD` entry, if requested.
+    pub status: Option<FileStatus>,
+}
+
+/// The state of an installed file, relative to its `RECORD` entry.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FileStatus {
+    /// The file matches its recorded hash and size.
+    Unchanged,
+    /// The file doesn't match its recorded hash or size.
+    Modified,
+    /// The file no longer exists.
+    Missing,
+    /// The file exists, but can't be verified, as it has no recorded hash (e.g., the `RECORD`
+    /// itself, or bytecode compiled after installation) or uses an unsupported algorithm.
+    Unverifiable,
+}
+
+/// Read the files listed in the `RECORD` of the given `.dist-info` directory, optionally
+/// verifying each against its recorded hash and size.
+pub fn installed_files(dist_info: &Path, verify: bool) -> Result<Vec<InstalledFile>, Error> {
+    let Some(site_packages) = dist_info.parent() else {
+        return Err(Error::BrokenVenv(
+            "dist-info directory is not in a site-packages directory".to_string(),
+        ));
+    };
+
+    // Read the RECORD file.
+    let record = {
+        let record_path = dist_info.join("RECORD");
+        let mut record_file = match fs::File::open(&record_path) {
+            Ok(record_file) => record_file,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                return Err(Error::MissingRecord(record_path));
+            }
+            Err(err) => return Err(err.into()),
+        };
+        read_record_file(&mut record_file)?
+    };
+
+    record
+        .into_iter()
+        .map(|entry| {
+            let status = if verify {
+                Some(verify_file(
+                    &site_packages.join(&entry.path),
+                    entry.hash.as_deref(),
+                    entry.size,
+                )?)
+            } else {
+                None
+            };
+            Ok(InstalledFile {
+                path: entry.path,
+                status,
+            })
+        })
+        .collect()
+}
+
+/// Return the names of the scripts that the given `.dist-info` directory lists in its `RECORD` as
+/// installed to the given scripts directory (e.g., `black`).
+///
+/// On Windows, the `.exe` extension of each launcher is omitted, such that the names match those
+/// of the entrypoints from which they were generated.
+pub fn installed_scripts(dist_info: &Path, scripts: &Path) -> Result<Vec<String>, Error> {
+    let Some(site_packages) = dist_info.parent() else {
+        return Err(Error::BrokenVenv(
+            "dist-info directory is not in a site-packages directory".to_string(),
+        ));
+    };
+    let scripts = normalize_path(scripts);
+
+    Ok(installed_files(dist_info, false)?
+        .into_iter()
+        .filter_map(|file| {
+            let path = normalize_path(&site_packages.join(&file.path));
+            if path.parent()? != scripts {
+                return None;
+            }
+            let name = path.file_name()?.to_string_lossy();
+            let name = if cfg!(windows) {
+                name.strip_suffix(".exe").unwrap_or(&name)
+            } else {
+                name.as_ref()
+            };
+            Some(name.to_string())
+        })
+        .collect())
+}
+
+/// Verify a file against its recorded hash (e.g., `sha256=...`) and size.
+fn verify_file(path: &Path, hash: Option<&str>, size: Option<u64>) -> Result<FileStatus, Error> {
+    let metadata = match fs::metadata(path) {
+        Ok(metadata) => metadata,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(FileStatus::Missing),
+        Err(err) => return Err(err.into()),
+    };
+
+    // Check the size first, to avoid hashing files that have obviously changed.
+    if size.is_some_and(|size| size != metadata.len()) {
+        return Ok(FileStatus::Modified);
+    }
+
+    let Some(expected) = hash.and_then(|hash| hash.strip_prefix("sha256=")) else {
+        return Ok(FileStatus::Unverifiable);
+    };
+
+    let computed = Sha256::digest(fs::read(path)?);
+    if BASE64URL_NOPAD.encode(&computed) == expected {
+        Ok(FileStatus::Unchanged)
+    } else {
+        Ok(FileStatus::Modified)
+    }
+}
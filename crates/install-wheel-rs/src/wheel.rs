@@ -225,6 +225,11 @@ pub(crate) fn write_script_entrypoints(
     record: &mut Vec<RecordEntry>,
     is_gui: bool,
 ) -> Result<(), Error> {
+    // The scripts directory may not exist yet (e.g., for user installs).
+    if !entrypoints.is_empty() {
+        fs::create_dir_all(&layout.scheme.scripts)?;
+    }
+
     for entrypoint in entrypoints {
         let entrypoint_absolute = if cfg!(windows) {
             // On windows we actually build an .exe wrapper
@@ -407,7 +412,7 @@ pub(crate) fn move_folder_recorded(
         if entry.file_type().is_dir() {
             fs::create_dir_all(&target)?;
         } else {
-            fs::rename(src, &target)?;
+            uv_fs::rename_with_retry_sync(src, &target)?;
             let entry = record
                 .iter_mut()
                 .find(|entry| Path::new(&entry.path) == relative_to_site_packages)
@@ -471,7 +476,7 @@ fn install_script(
         let start = format_shebang(&layout.sys_executable, &layout.os_name)
             .as_bytes()
             .to_vec();
-        let mut target = File::create(&script_absolute)?;
+        let mut target = create_file(&script_absolute)?;
         let size_and_encoded_hash = copy_and_hash(&mut start.chain(script), &mut target)?;
         fs::remove_file(&path)?;
         Some(size_and_encoded_hash)
@@ -536,6 +541,8 @@ pub(crate) fn install_data(
                 move_folder_recorded(&path, &layout.scheme.data, site_packages, record)?;
             }
             Some("scripts") => {
+                // The scripts directory may not exist yet (e.g., for user installs).
+                fs::create_dir_all(&layout.scheme.scripts)?;
                 for file in fs::read_dir(path)? {
                     let file = file?;
 
@@ -595,7 +602,7 @@ pub(crate) fn write_file_recorded(
         relative_path.display()
     );
 
-    File::create(site_packages.join(relative_path))?.write_all(content.as_ref())?;
+    create_file(&site_packages.join(relative_path))?.write_all(content.as_ref())?;
     let hash = Sha256::new().chain_update(content.as_ref()).finalize();
     let encoded_hash = format!("sha256={}", BASE64URL_NOPAD.encode(&hash));
     record.push(RecordEntry {
@@ -606,6 +613,15 @@ pub(crate) fn write_file_recorded(
     Ok(())
 }
 
+/// Create a file at the given path, replacing any existing symbolic link rather than writing
+/// through it (e.g., into the cache, for a file that was previously installed via symlink).
+fn create_file(path: &Path) -> io::Result<File> {
+    if fs::symlink_metadata(path).is_ok_and(|metadata| metadata.file_type().is_symlink()) {
+        fs::remove_file(path)?;
+    }
+    File::create(path)
+}
+
 /// Adds `INSTALLER`, `REQUESTED` and `direct_url.json` to the .dist-info dir
 pub(crate) fn extra_dist_info(
     site_packages: &Path,
@@ -638,6 +654,72 @@ pub(crate) fn extra_dist_info(
     Ok(())
 }
 
+/// Mark the distribution represented by the given `.dist-info` directory as requested directly by
+/// the user, by adding a `REQUESTED` file (and recording it in the `RECORD`).
+///
+/// See: <https://packaging.python.org/en/latest/specifications/recording-installed-packages/#the-requested-file>
+pub fn mark_requested(dist_info: &Path) -> Result<(), Error> {
+    let (Some(site_packages), Some(dist_info_dir)) = (dist_info.parent(), dist_info.file_name())
+    else {
+        return Err(Error::BrokenVenv(
+            "dist-info directory is not in a site-packages directory".to_string(),
+        ));
+    };
+
+    if dist_info.join("REQUESTED").is_file() {
+        return Ok(());
+    }
+
+    // Read the RECORD file.
+    let record_path = dist_info.join("RECORD");
+    let mut record = {
+        let mut record_file = match File::open(&record_path) {
+            Ok(record_file) => record_file,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                return Err(Error::MissingRecord(record_path));
+            }
+            Err(err) => return Err(err.into()),
+        };
+        read_record_file(&mut record_file)?
+    };
+
+    write_file_recorded(
+        site_packages,
+        &Path::new(dist_info_dir).join("REQUESTED"),
+        "",
+        &mut record,
+    )?;
+    write_record_file(&record_path, record)
+}
+
+/// Read the compatibility tags (e.g., `cp312-cp312-manylinux_2_17_x86_64`) recorded in the `WHEEL`
+/// file of the distribution represented by the given `.dist-info` directory.
+///
+/// Returns an empty list if the distribution has no `WHEEL` file (e.g., if it was installed from a
+/// legacy source distribution).
+pub fn installed_tags(dist_info: &Path) -> Result<Vec<String>, Error> {
+    let wheel_file = match File::open(dist_info.join("WHEEL")) {
+        Ok(wheel_file) => wheel_file,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err.into()),
+    };
+    let mut data = parse_key_value_file(BufReader::new(wheel_file), "WHEEL")?;
+    Ok(data.remove("Tag").unwrap_or_default())
+}
+
+/// Write the record file, sorting its entries.
+pub(crate) fn write_record_file(path: &Path, mut record: Vec<RecordEntry>) -> Result<(), Error> {
+    let mut record_writer = csv::WriterBuilder::new()
+        .has_headers(false)
+        .escape(b'"')
+        .from_path(path)?;
+    record.sort();
+    for entry in record {
+        record_writer.serialize(entry)?;
+    }
+    Ok(())
+}
+
 /// Reads the record file
 /// <https://www.python.org/dev/peps/pep-0376/#record>
 pub(crate) fn read_record_file(record: &mut impl Read) -> Result<Vec<RecordEntry>, Error> {
@@ -1,5 +1,7 @@
-pub use platform::{Arch, Os, Platform, PlatformError};
+pub use libc::{TargetLibc, TargetLibcError};
+pub use platform::{Arch, IosSdk, Os, Platform, PlatformError};
 pub use tags::{IncompatibleTag, TagCompatibility, TagPriority, Tags, TagsError};
 
+mod libc;
 mod platform;
 mod tags;
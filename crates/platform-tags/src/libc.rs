@@ -0,0 +1,192 @@
+//! Overrides for the C library targeted on Linux (e.g., to select `musllinux` wheels on a
+//! glibc-based machine).
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{de, Deserialize, Deserializer};
+
+use crate::{Os, Platform, PlatformError};
+
+/// A Linux C library (and version) to target when selecting wheels, in lieu of the C library of
+/// the current interpreter.
+///
+/// Targeting a `manylinux` version is equivalent to installing a [`_manylinux`] module that
+/// declares compatibility up to (and including) the given glibc version.
+///
+/// [`_manylinux`]: https://peps.python.org/pep-0600/#package-installers
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum TargetLibc {
+    /// Target `manylinux` wheels up to the given glibc version (e.g., `manylinux_2_17`).
+    Manylinux { major: u16, minor: u16 },
+    /// Target `musllinux` wheels up to the given musl version (e.g., `musllinux_1_2`).
+    Musllinux { major: u16, minor: u16 },
+}
+
+impl TargetLibc {
+    /// The `manylinux` version targeted by `--force-manylinux`, i.e., `manylinux2014`, the most
+    /// widely published `manylinux` baseline.
+    pub const FORCE_MANYLINUX: Self = Self::Manylinux {
+        major: 2,
+        minor: 17,
+    };
+}
+
+impl FromStr for TargetLibc {
+    type Err = TargetLibcError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Support the legacy `manylinux` aliases.
+        // <https://peps.python.org/pep-0600/#legacy-manylinux-tags>
+        match s {
+            "manylinux1" => return Ok(Self::Manylinux { major: 2, minor: 5 }),
+            "manylinux2010" => {
+                return Ok(Self::Manylinux {
+                    major: 2,
+                    minor: 12,
+                })
+            }
+            "manylinux2014" => {
+                return Ok(Self::Manylinux {
+                    major: 2,
+                    minor: 17,
+                })
+            }
+            _ => {}
+        }
+
+        let parse = |version: &str| {
+            let (major, minor) = version.split_once('_')?;
+            Some((major.parse().ok()?, minor.parse().ok()?))
+        };
+        if let Some((major, minor)) = s.strip_prefix("manylinux_").and_then(parse) {
+            return Ok(Self::Manylinux { major, minor });
+        }
+        if let Some((major, minor)) = s.strip_prefix("musllinux_").and_then(parse) {
+            return Ok(Self::Musllinux { major, minor });
+        }
+
+        Err(TargetLibcError(s.to_string()))
+    }
+}
+
+impl fmt::Display for TargetLibc {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Manylinux { major, minor } => write!(f, "manylinux_{major}_{minor}"),
+            Self::Musllinux { major, minor } => write!(f, "musllinux_{major}_{minor}"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for TargetLibc {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        FromStr::from_str(&s).map_err(de::Error::custom)
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+#[error("Invalid libc `{0}` (expected, e.g., `manylinux_2_17` or `musllinux_1_2`)")]
+pub struct TargetLibcError(String);
+
+impl Platform {
+    /// Return a copy of the [`Platform`] that targets the given libc, rather than the libc
+    /// detected for the interpreter.
+    pub fn with_libc(&self, libc: TargetLibc) -> Result<Self, PlatformError> {
+        match self.os() {
+            Os::Manylinux { .. } | Os::Musllinux { .. } => {}
+            os => return Err(PlatformError::LibcOverride(libc, os.to_string())),
+        }
+        let os = match libc {
+            TargetLibc::Manylinux { major, minor } => Os::Manylinux { major, minor },
+            TargetLibc::Musllinux { major, minor } => Os::Musllinux { major, minor },
+        };
+        Ok(Self::new(os, self.arch()))
+    }
+
+    /// Return a copy of the [`Platform`] that accepts `manylinux` wheels, even if the interpreter
+    /// isn't glibc-based (e.g., on Alpine, with a glibc compatibility layer installed).
+    ///
+    /// If the interpreter is already glibc-based, the detected glibc version is retained.
+    pub fn with_manylinux(&self) -> Result<Self, PlatformError> {
+        match self.os() {
+            Os::Manylinux { .. } => Ok(self.clone()),
+            _ => self.with_libc(TargetLibc::FORCE_MANYLINUX),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::{Arch, Os, Platform, TargetLibc};
+
+    #[test]
+    fn parse() {
+        for libc in ["manylinux_2_17", "manylinux_2_28", "musllinux_1_2"] {
+            assert_eq!(TargetLibc::from_str(libc).unwrap().to_string(), libc);
+        }
+        assert_eq!(
+            TargetLibc::from_str("manylinux2014").unwrap(),
+            TargetLibc::Manylinux {
+                major: 2,
+                minor: 17
+            }
+        );
+        assert_eq!(
+            TargetLibc::from_str("manylinux1").unwrap(),
+            TargetLibc::Manylinux { major: 2, minor: 5 }
+        );
+        assert!(TargetLibc::from_str("musllinux").is_err());
+        assert!(TargetLibc::from_str("manylinux_2").is_err());
+        assert!(TargetLibc::from_str("glibc_2_17").is_err());
+    }
+
+    #[test]
+    fn with_libc() {
+        let glibc = Platform::new(
+            Os::Manylinux {
+                major: 2,
+                minor: 35,
+            },
+            Arch::X86_64,
+        );
+        let musl = Platform::new(Os::Musllinux { major: 1, minor: 2 }, Arch::Aarch64);
+        let macos = Platform::new(
+            Os::Macos {
+                major: 14,
+                minor: 0,
+            },
+            Arch::Aarch64,
+        );
+
+        assert_eq!(
+            glibc
+                .with_libc(TargetLibc::from_str("musllinux_1_2").unwrap())
+                .unwrap(),
+            Platform::new(Os::Musllinux { major: 1, minor: 2 }, Arch::X86_64)
+        );
+        assert!(macos
+            .with_libc(TargetLibc::from_str("musllinux_1_2").unwrap())
+            .is_err());
+
+        // Forcing `manylinux` retains the detected glibc version.
+        assert_eq!(glibc.with_manylinux().unwrap(), glibc);
+        assert_eq!(
+            musl.with_manylinux().unwrap(),
+            Platform::new(
+                Os::Manylinux {
+                    major: 2,
+                    minor: 17
+                },
+                Arch::Aarch64
+            )
+        );
+        assert!(macos.with_manylinux().is_err());
+    }
+}
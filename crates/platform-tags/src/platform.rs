@@ -5,12 +5,16 @@ use std::{fmt, io};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::TargetLibc;
+
 #[derive(Error, Debug)]
 pub enum PlatformError {
     #[error(transparent)]
     IOError(#[from] io::Error),
     #[error("Failed to detect the operating system version: {0}")]
     OsVersionDetectionError(String),
+    #[error("Cannot target `{0}` on a non-Linux platform ({1})")]
+    LibcOverride(TargetLibc, String),
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
@@ -50,6 +54,9 @@ pub enum Os {
     Dragonfly { release: String },
     Illumos { release: String, arch: String },
     Haiku { release: String },
+    Ios { major: u16, minor: u16, sdk: IosSdk },
+    Android { api_level: u16 },
+    Pyodide { major: u16, minor: u16 },
 }
 
 impl fmt::Display for Os {
@@ -65,6 +72,28 @@ impl fmt::Display for Os {
             Self::Dragonfly { .. } => write!(f, "DragonFly"),
             Self::Illumos { .. } => write!(f, "Illumos"),
             Self::Haiku { .. } => write!(f, "Haiku"),
+            Self::Ios { .. } => write!(f, "iOS"),
+            Self::Android { .. } => write!(f, "Android"),
+            Self::Pyodide { .. } => write!(f, "Pyodide"),
+        }
+    }
+}
+
+/// The iOS SDK for which an interpreter was built, i.e., whether it runs on a device or a
+/// simulator.
+/// <https://peps.python.org/pep-0730/#platform-identification>
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IosSdk {
+    Iphoneos,
+    Iphonesimulator,
+}
+
+impl fmt::Display for IosSdk {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Self::Iphoneos => write!(f, "iphoneos"),
+            Self::Iphonesimulator => write!(f, "iphonesimulator"),
         }
     }
 }
@@ -73,9 +102,10 @@ impl fmt::Display for Os {
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Arch {
-    #[serde(alias = "arm64")]
+    #[serde(alias = "arm64", alias = "arm64_v8a")]
     Aarch64,
     Armv6L,
+    #[serde(alias = "armeabi_v7a")]
     Armv7L,
     Powerpc64Le,
     Powerpc64,
@@ -84,6 +114,7 @@ pub enum Arch {
     #[serde(alias = "amd64")]
     X86_64,
     S390X,
+    Wasm32,
 }
 
 impl fmt::Display for Arch {
@@ -97,6 +128,7 @@ impl fmt::Display for Arch {
             Self::X86 => write!(f, "i686"),
             Self::X86_64 => write!(f, "x86_64"),
             Self::S390X => write!(f, "s390x"),
+            Self::Wasm32 => write!(f, "wasm32"),
         }
     }
 }
@@ -113,7 +145,29 @@ impl Arch {
             // manylinux 1
             Self::X86 | Self::X86_64 => Some(5),
             // unsupported
-            Self::Armv6L => None,
+            Self::Armv6L | Self::Wasm32 => None,
+        }
+    }
+
+    /// Returns the name of the architecture in iOS platform tags (e.g., `arm64`), if it's
+    /// supported on iOS.
+    pub(crate) fn ios_name(&self) -> Option<&'static str> {
+        match self {
+            Self::Aarch64 => Some("arm64"),
+            Self::X86_64 => Some("x86_64"),
+            _ => None,
+        }
+    }
+
+    /// Returns the Android ABI for the architecture (e.g., `arm64_v8a`), if it's supported on
+    /// Android.
+    pub(crate) fn android_abi(&self) -> Option<&'static str> {
+        match self {
+            Self::Aarch64 => Some("arm64_v8a"),
+            Self::Armv7L => Some("armeabi_v7a"),
+            Self::X86 => Some("x86"),
+            Self::X86_64 => Some("x86_64"),
+            _ => None,
         }
     }
 }
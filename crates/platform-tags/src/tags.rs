@@ -87,6 +87,103 @@ impl Tags {
         Self { map: Arc::new(map) }
     }
 
+    /// Return the tags from high to low priority (e.g., `cp312-cp312-manylinux_2_17_x86_64`).
+    pub fn prioritized(&self) -> Vec<String> {
+        let mut tags = BTreeSet::new();
+        for (python_tag, abi_tags) in self.map.iter() {
+            for (abi_tag, platform_tags) in abi_tags {
+                for (platform_tag, priority) in platform_tags {
+                    tags.insert((priority, format!("{python_tag}-{abi_tag}-{platform_tag}")));
+                }
+            }
+        }
+        tags.into_iter().rev().map(|(_, tag)| tag).collect()
+    }
+
+    /// Return a copy of the tags in which `abi3` tags take priority over all other tags (e.g.,
+    /// `cp38-abi3-manylinux_2_17_x86_64` over `cp312-cp312-manylinux_2_17_x86_64`), while
+    /// otherwise retaining their relative order.
+    #[must_use]
+    pub fn prefer_abi3(&self) -> Self {
+        let mut tags = BTreeSet::new();
+        for (python_tag, abi_tags) in self.map.iter() {
+            for (abi_tag, platform_tags) in abi_tags {
+                for (platform_tag, priority) in platform_tags {
+                    tags.insert((
+                        abi_tag == "abi3",
+                        *priority,
+                        python_tag.clone(),
+                        abi_tag.clone(),
+                        platform_tag.clone(),
+                    ));
+                }
+            }
+        }
+        Self::new(
+            tags.into_iter()
+                .rev()
+                .map(|(_, _, python_tag, abi_tag, platform_tag)| {
+                    (python_tag, abi_tag, platform_tag)
+                })
+                .collect(),
+        )
+    }
+
+    /// Return a copy of the tags in which the given platforms take priority, in the order given,
+    /// over any other platforms for the same Python version and ABI.
+    ///
+    /// Each platform may be given in full (e.g., `manylinux2014_x86_64`), or without the
+    /// architecture (e.g., `manylinux2014`).
+    #[must_use]
+    pub fn with_platform_preference(&self, platforms: &[String]) -> Self {
+        let rank = |platform_tag: &str| {
+            platforms
+                .iter()
+                .position(|preferred| matches_platform(platform_tag, preferred))
+                .unwrap_or(platforms.len())
+        };
+
+        // Order by the highest priority of each Python version and ABI, then by preference, then
+        // by the existing priority.
+        let mut tags = Vec::new();
+        for (python_tag, abi_tags) in self.map.iter() {
+            for (abi_tag, platform_tags) in abi_tags {
+                let group = platform_tags.values().max().copied();
+                for (platform_tag, priority) in platform_tags {
+                    tags.push((
+                        (
+                            cmp::Reverse(group),
+                            rank(platform_tag),
+                            cmp::Reverse(*priority),
+                        ),
+                        python_tag.clone(),
+                        abi_tag.clone(),
+                        platform_tag.clone(),
+                    ));
+                }
+            }
+        }
+        tags.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+        Self::new(
+            tags.into_iter()
+                .map(|(_, python_tag, abi_tag, platform_tag)| (python_tag, abi_tag, platform_tag))
+                .collect(),
+        )
+    }
+
+    /// Returns `true` if any of the tags are for the given platform (e.g., `manylinux2014` or
+    /// `manylinux2014_x86_64`).
+    pub fn supports_platform(&self, platform: &str) -> bool {
+        self.map
+            .values()
+            .flat_map(FxHashMap::values)
+            .any(|platform_tags| {
+                platform_tags
+                    .keys()
+                    .any(|platform_tag| matches_platform(platform_tag, platform))
+            })
+    }
+
     /// Returns the compatible tags for the given Python implementation (e.g., `cpython`), version,
     /// and platform.
     pub fn from_env(
@@ -257,6 +354,14 @@ impl Tags {
     }
 }
 
+/// Returns `true` if the platform tag (e.g., `manylinux2014_x86_64`) is for the given platform,
+/// with or without the architecture (e.g., `manylinux2014` or `manylinux2014_x86_64`).
+fn matches_platform(platform_tag: &str, platform: &str) -> bool {
+    platform_tag
+        .strip_prefix(platform)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('_'))
+}
+
 /// The priority of a platform tag.
 ///
 /// A wrapper around [`NonZeroU32`]. Higher values indicate higher priority.
@@ -279,15 +384,7 @@ impl TryFrom<usize> for TagPriority {
 impl std::fmt::Display for Tags {
     /// Display tags from high to low priority
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let mut tags = BTreeSet::new();
-        for (python_tag, abi_tags) in self.map.iter() {
-            for (abi_tag, platform_tags) in abi_tags {
-                for (platform_tag, priority) in platform_tags {
-                    tags.insert((priority, format!("{python_tag}-{abi_tag}-{platform_tag}")));
-                }
-            }
-        }
-        for (_, tag) in tags.iter().rev() {
+        for tag in self.prioritized() {
             writeln!(f, "{tag}")?;
         }
         Ok(())
@@ -514,6 +611,42 @@ fn compatible_tags(platform: &Platform) -> Result<Vec<String>, PlatformError> {
             let os = os.to_string().to_lowercase();
             vec![format!("{}_{}_{}", os, release, arch)]
         }
+        (Os::Ios { major, minor, sdk }, _) if arch.ios_name().is_some() => {
+            // See: https://peps.python.org/pep-0730/#platform-identification
+            let multiarch = format!("{}_{sdk}", arch.ios_name().unwrap_or_default());
+            let mut platform_tags = vec![];
+            // Consider every minor version of the current major version, then every minor version
+            // (up to X.9) of each earlier major version, down to iOS 12.0, the first version that's
+            // known to support CPython.
+            for minor in (0..=*minor).rev() {
+                platform_tags.push(format!("ios_{major}_{minor}_{multiarch}"));
+            }
+            for major in (12..*major).rev() {
+                for minor in (0..=9).rev() {
+                    platform_tags.push(format!("ios_{major}_{minor}_{multiarch}"));
+                }
+            }
+            platform_tags
+        }
+        (Os::Android { api_level }, _) if arch.android_abi().is_some() => {
+            // See: https://peps.python.org/pep-0738/#platform-identification
+            let abi = arch.android_abi().unwrap_or_default();
+            // API level 16 is the first that's known to support CPython.
+            (16..=*api_level)
+                .rev()
+                .map(|api_level| format!("android_{api_level}_{abi}"))
+                .collect()
+        }
+        (Os::Pyodide { major, minor }, Arch::Wasm32) => {
+            // See: https://pyodide.org/en/stable/development/abi.html
+            let mut platform_tags = vec![format!("pyodide_{major}_{minor}_{arch}")];
+            // Before the `pyodide` platform tag was standardized, wheels were tagged with the
+            // Emscripten version of the Pyodide ABI.
+            if let Some((major, minor, patch)) = emscripten_version(*major, *minor) {
+                platform_tags.push(format!("emscripten_{major}_{minor}_{patch}_{arch}"));
+            }
+            platform_tags
+        }
         _ => {
             return Err(PlatformError::OsVersionDetectionError(format!(
                 "Unsupported operating system and architecture combination: {os} {arch}"
@@ -523,6 +656,15 @@ fn compatible_tags(platform: &Platform) -> Result<Vec<String>, PlatformError> {
     Ok(platform_tags)
 }
 
+/// Return the Emscripten version used by the given Pyodide ABI version (e.g., `3.1.58` for
+/// `2024.0`), if known.
+fn emscripten_version(major: u16, minor: u16) -> Option<(u16, u16, u16)> {
+    match (major, minor) {
+        (2024, 0) => Some((3, 1, 58)),
+        _ => None,
+    }
+}
+
 /// Determine the appropriate binary formats for a macOS version.
 /// Source: <https://github.com/pypa/packaging/blob/fd4f11139d1c884a637be8aa26bb60a31fbc9411/packaging/tags.py#L314>
 fn get_mac_binary_formats(arch: Arch) -> Vec<String> {
@@ -554,6 +696,8 @@ fn get_mac_binary_formats(arch: Arch) -> Vec<String> {
 mod tests {
     use insta::{assert_debug_snapshot, assert_snapshot};
 
+    use crate::IosSdk;
+
     use super::*;
 
     /// Check platform tag ordering.
@@ -917,6 +1061,83 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_platform_tags_ios() {
+        let tags = compatible_tags(&Platform::new(
+            Os::Ios {
+                major: 13,
+                minor: 2,
+                sdk: IosSdk::Iphonesimulator,
+            },
+            Arch::Aarch64,
+        ))
+        .unwrap();
+        assert_debug_snapshot!(
+            tags,
+            @r###"
+        [
+            "ios_13_2_arm64_iphonesimulator",
+            "ios_13_1_arm64_iphonesimulator",
+            "ios_13_0_arm64_iphonesimulator",
+            "ios_12_9_arm64_iphonesimulator",
+            "ios_12_8_arm64_iphonesimulator",
+            "ios_12_7_arm64_iphonesimulator",
+            "ios_12_6_arm64_iphonesimulator",
+            "ios_12_5_arm64_iphonesimulator",
+            "ios_12_4_arm64_iphonesimulator",
+            "ios_12_3_arm64_iphonesimulator",
+            "ios_12_2_arm64_iphonesimulator",
+            "ios_12_1_arm64_iphonesimulator",
+            "ios_12_0_arm64_iphonesimulator",
+        ]
+        "###
+        );
+    }
+
+    #[test]
+    fn test_platform_tags_android() {
+        let tags =
+            compatible_tags(&Platform::new(Os::Android { api_level: 21 }, Arch::Armv7L)).unwrap();
+        assert_debug_snapshot!(
+            tags,
+            @r###"
+        [
+            "android_21_armeabi_v7a",
+            "android_20_armeabi_v7a",
+            "android_19_armeabi_v7a",
+            "android_18_armeabi_v7a",
+            "android_17_armeabi_v7a",
+            "android_16_armeabi_v7a",
+        ]
+        "###
+        );
+
+        assert!(
+            compatible_tags(&Platform::new(Os::Android { api_level: 21 }, Arch::S390X)).is_err()
+        );
+    }
+
+    #[test]
+    fn test_platform_tags_pyodide() {
+        let tags = compatible_tags(&Platform::new(
+            Os::Pyodide {
+                major: 2024,
+                minor: 0,
+            },
+            Arch::Wasm32,
+        ))
+        .unwrap();
+        assert_debug_snapshot!(
+            tags,
+            @r###"
+        [
+            "pyodide_2024_0_wasm32",
+            "emscripten_3_1_58_wasm32",
+        ]
+        "###
+        );
+    }
+
     /// Check full tag ordering.
     /// The list is displayed in decreasing priority.
     ///
@@ -2022,4 +2243,69 @@ mod tests {
         "###
         );
     }
+
+    #[test]
+    fn test_prefer_abi3() {
+        let tags = Tags::new(vec![
+            (
+                "cp312".to_string(),
+                "cp312".to_string(),
+                "manylinux_2_17_x86_64".to_string(),
+            ),
+            (
+                "cp312".to_string(),
+                "abi3".to_string(),
+                "manylinux_2_17_x86_64".to_string(),
+            ),
+            (
+                "cp38".to_string(),
+                "abi3".to_string(),
+                "manylinux_2_17_x86_64".to_string(),
+            ),
+            ("py3".to_string(), "none".to_string(), "any".to_string()),
+        ]);
+        assert_snapshot!(
+            tags.prefer_abi3(),
+            @r###"
+        cp312-abi3-manylinux_2_17_x86_64
+        cp38-abi3-manylinux_2_17_x86_64
+        cp312-cp312-manylinux_2_17_x86_64
+        py3-none-any
+        "###
+        );
+    }
+
+    #[test]
+    fn test_platform_preference() {
+        let tags = Tags::new(
+            [
+                ("cp312", "cp312", "manylinux_2_28_x86_64"),
+                ("cp312", "cp312", "manylinux_2_17_x86_64"),
+                ("cp312", "cp312", "manylinux2014_x86_64"),
+                ("cp312", "abi3", "manylinux_2_28_x86_64"),
+                ("cp312", "abi3", "manylinux_2_17_x86_64"),
+                ("cp312", "abi3", "manylinux2014_x86_64"),
+                ("py3", "none", "any"),
+            ]
+            .into_iter()
+            .map(|(python, abi, platform)| {
+                (python.to_string(), abi.to_string(), platform.to_string())
+            })
+            .collect(),
+        );
+        assert!(tags.supports_platform("manylinux2014"));
+        assert!(!tags.supports_platform("manylinux2"));
+        assert_snapshot!(
+            tags.with_platform_preference(&["manylinux2014".to_string()]),
+            @r###"
+        cp312-cp312-manylinux2014_x86_64
+        cp312-cp312-manylinux_2_28_x86_64
+        cp312-cp312-manylinux_2_17_x86_64
+        cp312-abi3-manylinux2014_x86_64
+        cp312-abi3-manylinux_2_28_x86_64
+        cp312-abi3-manylinux_2_17_x86_64
+        py3-none-any
+        "###
+        );
+    }
 }
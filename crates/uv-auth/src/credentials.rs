@@ -47,9 +47,10 @@ impl Credentials {
     /// If a username is provided, it must match the login in the netrc file or [`None`] is returned.
     pub fn from_netrc(netrc: &Netrc, url: &Url, username: Option<&str>) -> Option<Self> {
         let host = url.host_str()?;
-        let entry = netrc
-            .hosts
-            .get(host)
+        // Prefer an entry for the realm (i.e., the host and non-default port), then the host.
+        let entry = crate::netrc_files::machine(url)
+            .and_then(|machine| netrc.hosts.get(&machine))
+            .or_else(|| netrc.hosts.get(host))
             .or_else(|| netrc.hosts.get("default"))?;
 
         // Ensure the username matches if provided
@@ -0,0 +1,281 @@
+use url::Url;
+
+/// The base URL of the GitHub REST API.
+const API_URL: &str = "https://api.github.com";
+
+/// The environment variable containing the repositories for which the `GITHUB_TOKEN` may be used
+/// to fetch release assets, as a comma-separated list of `{owner}/{repo}` (a single repository) or
+/// `{owner}` (all of the owner's repositories).
+pub const UV_GITHUB_TOKEN_REPOSITORIES: &str = "UV_GITHUB_TOKEN_REPOSITORIES";
+
+/// A GitHub release asset, as referenced by a download or API URL.
+///
+/// Assets attached to releases in private repositories can't be downloaded from their
+/// `github.com` download URL with a token. Instead, they must be fetched from the REST API, with
+/// an `Accept: application/octet-stream` header, which redirects to a short-lived, pre-signed URL.
+///
+/// See: <https://docs.github.com/en/rest/releases/assets#get-a-release-asset>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum GitHubAsset {
+    /// A download URL, e.g., `https://github.com/{owner}/{repo}/releases/download/{tag}/{name}`,
+    /// which must be resolved to an asset via the release's metadata.
+    Download {
+        owner: String,
+        repo: String,
+        /// The tag of the release, or `None` for the latest release.
+        tag: Option<String>,
+        name: String,
+    },
+    /// An API URL, e.g., `https://api.github.com/repos/{owner}/{repo}/releases/assets/{id}`.
+    Api { url: Url },
+}
+
+impl GitHubAsset {
+    /// Parse a [`GitHubAsset`] from a URL, if it refers to a release asset.
+    pub(crate) fn from_url(url: &Url) -> Option<Self> {
+        if url.scheme() != "https" {
+            return None;
+        }
+        let segments = url.path_segments()?.collect::<Vec<_>>();
+        match url.host_str()? {
+            "github.com" => match segments.as_slice() {
+                // E.g., `/{owner}/{repo}/releases/latest/download/{name}`.
+                [owner, repo, "releases", "latest", "download", name] => Some(Self::Download {
+                    owner: decode(owner)?,
+                    repo: decode(repo)?,
+                    tag: None,
+                    name: decode(name)?,
+                }),
+                // E.g., `/{owner}/{repo}/releases/download/{tag}/{name}`, where the tag may itself
+                // contain slashes.
+                [owner, repo, "releases", "download", tag @ .., name] if !tag.is_empty() => {
+                    Some(Self::Download {
+                        owner: decode(owner)?,
+                        repo: decode(repo)?,
+                        tag: Some(
+                            tag.iter()
+                                .map(|segment| decode(segment))
+                                .collect::<Option<Vec<_>>>()?
+                                .join("/"),
+                        ),
+                        name: decode(name)?,
+                    })
+                }
+                _ => None,
+            },
+            "api.github.com" => match segments.as_slice() {
+                ["repos", _, _, "releases", "assets", id] if id.parse::<u64>().is_ok() => {
+                    Some(Self::Api { url: url.clone() })
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Return the owner and name of the repository to which the asset belongs.
+    pub(crate) fn repository(&self) -> Option<(&str, &str)> {
+        match self {
+            Self::Download { owner, repo, .. } => Some((owner, repo)),
+            Self::Api { url } => match url.path_segments()?.collect::<Vec<_>>().as_slice() {
+                ["repos", owner, repo, ..] => Some((owner, repo)),
+                _ => None,
+            },
+        }
+    }
+
+    /// Returns `true` if the asset belongs to one of the given repositories, as a comma-separated
+    /// list of `{owner}/{repo}` or `{owner}` (see [`UV_GITHUB_TOKEN_REPOSITORIES`]).
+    ///
+    /// Like GitHub itself, owner and repository names are compared case-insensitively.
+    pub(crate) fn is_in(&self, repositories: &str) -> bool {
+        let Some((owner, repo)) = self.repository() else {
+            return false;
+        };
+        repositories
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .any(|entry| match entry.split_once('/') {
+                Some((entry_owner, entry_repo)) => {
+                    entry_owner.eq_ignore_ascii_case(owner) && entry_repo.eq_ignore_ascii_case(repo)
+                }
+                None => entry.eq_ignore_ascii_case(owner),
+            })
+    }
+
+    /// Return the API URL for the release metadata, from which the asset can be resolved, if the
+    /// asset is referenced by its download URL.
+    pub(crate) fn release_url(&self) -> Option<Url> {
+        let Self::Download {
+            owner, repo, tag, ..
+        } = self
+        else {
+            return None;
+        };
+        let mut url = Url::parse(API_URL).expect("GitHub API URL is valid");
+        {
+            let mut segments = url.path_segments_mut().expect("GitHub API URL is a base");
+            segments.extend(["repos", owner, repo, "releases"]);
+            match tag {
+                Some(tag) => segments.extend(["tags", tag]),
+                None => segments.push("latest"),
+            };
+        }
+        Some(url)
+    }
+
+    /// Return the API URL for the asset, given the release metadata returned by the
+    /// [`GitHubAsset::release_url`] endpoint, if applicable.
+    pub(crate) fn asset_url(&self, release: Option<&serde_json::Value>) -> Option<Url> {
+        match self {
+            Self::Api { url } => Some(url.clone()),
+            Self::Download { name, .. } => release?
+                .get("assets")?
+                .as_array()?
+                .iter()
+                .find(|asset| asset.get("name").and_then(|name| name.as_str()) == Some(name))?
+                .get("url")?
+                .as_str()
+                .and_then(|url| Url::parse(url).ok()),
+        }
+    }
+}
+
+/// Percent-decode a path segment.
+fn decode(segment: &str) -> Option<String> {
+    urlencoding::decode(segment)
+        .ok()
+        .map(std::borrow::Cow::into_owned)
+}
+
+#[cfg(test)]
+mod tests {
+    use url::Url;
+
+    use super::GitHubAsset;
+
+    #[test]
+    fn download_url() {
+        let url = Url::parse(
+            "https://github.com/astral-sh/private/releases/download/v1.0.0/foo-1.0.0.tar.gz",
+        )
+        .unwrap();
+        let asset = GitHubAsset::from_url(&url).unwrap();
+        assert_eq!(
+            asset,
+            GitHubAsset::Download {
+                owner: "astral-sh".to_string(),
+                repo: "private".to_string(),
+                tag: Some("v1.0.0".to_string()),
+                name: "foo-1.0.0.tar.gz".to_string(),
+            }
+        );
+        assert_eq!(
+            asset.release_url().unwrap().as_str(),
+            "https://api.github.com/repos/astral-sh/private/releases/tags/v1.0.0"
+        );
+    }
+
+    #[test]
+    fn download_url_nested_tag() {
+        let url = Url::parse(
+            "https://github.com/astral-sh/private/releases/download/foo/v1.0.0/foo-1.0.0.tar.gz",
+        )
+        .unwrap();
+        let asset = GitHubAsset::from_url(&url).unwrap();
+        assert_eq!(
+            asset.release_url().unwrap().as_str(),
+            "https://api.github.com/repos/astral-sh/private/releases/tags/foo%2Fv1.0.0"
+        );
+    }
+
+    #[test]
+    fn download_url_latest() {
+        let url = Url::parse(
+            "https://github.com/astral-sh/private/releases/latest/download/foo-1.0.0.tar.gz",
+        )
+        .unwrap();
+        let asset = GitHubAsset::from_url(&url).unwrap();
+        assert_eq!(
+            asset.release_url().unwrap().as_str(),
+            "https://api.github.com/repos/astral-sh/private/releases/latest"
+        );
+    }
+
+    #[test]
+    fn repositories() {
+        let url = Url::parse(
+            "https://github.com/astral-sh/private/releases/download/v1.0.0/foo-1.0.0.tar.gz",
+        )
+        .unwrap();
+        let asset = GitHubAsset::from_url(&url).unwrap();
+        assert_eq!(asset.repository(), Some(("astral-sh", "private")));
+        assert!(asset.is_in("astral-sh/private"));
+        assert!(asset.is_in("pypa/pip, Astral-SH/Private"));
+        assert!(asset.is_in("astral-sh"));
+        assert!(!asset.is_in("astral-sh/public"));
+        assert!(!asset.is_in("astral"));
+        assert!(!asset.is_in(""));
+
+        let url =
+            Url::parse("https://api.github.com/repos/astral-sh/private/releases/assets/12345")
+                .unwrap();
+        let asset = GitHubAsset::from_url(&url).unwrap();
+        assert_eq!(asset.repository(), Some(("astral-sh", "private")));
+        assert!(asset.is_in("astral-sh/private"));
+    }
+
+    #[test]
+    fn api_url() {
+        let url =
+            Url::parse("https://api.github.com/repos/astral-sh/private/releases/assets/12345")
+                .unwrap();
+        let asset = GitHubAsset::from_url(&url).unwrap();
+        assert_eq!(asset.release_url(), None);
+        assert_eq!(asset.asset_url(None), Some(url));
+    }
+
+    #[test]
+    fn asset_url() {
+        let url = Url::parse(
+            "https://github.com/astral-sh/private/releases/download/v1.0.0/foo-1.0.0.tar.gz",
+        )
+        .unwrap();
+        let asset = GitHubAsset::from_url(&url).unwrap();
+        let release = serde_json::json!({
+            "assets": [
+                {
+                    "name": "foo-1.0.0-py3-none-any.whl",
+                    "url": "https://api.github.com/repos/astral-sh/private/releases/assets/1"
+                },
+                {
+                    "name": "foo-1.0.0.tar.gz",
+                    "url": "https://api.github.com/repos/astral-sh/private/releases/assets/2"
+                }
+            ]
+        });
+        assert_eq!(
+            asset.asset_url(Some(&release)).unwrap().as_str(),
+            "https://api.github.com/repos/astral-sh/private/releases/assets/2"
+        );
+    }
+
+    #[test]
+    fn other_urls() {
+        for url in [
+            "https://github.com/astral-sh/private",
+            "https://github.com/astral-sh/private/releases/tag/v1.0.0",
+            "https://github.com/astral-sh/private/archive/refs/tags/v1.0.0.tar.gz",
+            "https://api.github.com/repos/astral-sh/private/releases/assets/latest",
+            "http://github.com/astral-sh/private/releases/download/v1.0.0/foo-1.0.0.tar.gz",
+            "https://example.com/astral-sh/private/releases/download/v1.0.0/foo-1.0.0.tar.gz",
+        ] {
+            assert_eq!(
+                GitHubAsset::from_url(&Url::parse(url).unwrap()),
+                None,
+                "{url}"
+            );
+        }
+    }
+}
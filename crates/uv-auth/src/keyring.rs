@@ -1,4 +1,5 @@
-use std::{collections::HashSet, process::Command, sync::Mutex};
+use std::io::Write;
+use std::{collections::HashSet, process::Command, process::Stdio, sync::Mutex};
 
 use tracing::{debug, instrument, warn};
 use url::Url;
@@ -20,8 +21,9 @@ pub struct KeyringProvider {
 pub enum KeyringProviderBackend {
     /// Use the `keyring` command to fetch credentials.
     Subprocess,
+    /// Use an in-memory store, keyed by service name and username.
     #[cfg(test)]
-    Dummy(std::collections::HashMap<(String, &'static str), &'static str>),
+    Dummy(Mutex<std::collections::HashMap<(String, String), String>>),
 }
 
 impl KeyringProvider {
@@ -118,16 +120,128 @@ impl KeyringProvider {
         }
     }
 
+    /// Store a password for the given [`Url`] and username in the keyring.
+    ///
+    /// Passwords are stored for the URL's host, such that they're found for any URL on the host.
+    pub fn store(&self, url: &Url, username: &str, password: &str) -> Result<(), KeyringError> {
+        let host = url
+            .host_str()
+            .ok_or_else(|| KeyringError::InvalidUrl(url.clone()))?;
+
+        match self.backend {
+            KeyringProviderBackend::Subprocess => {
+                self.store_subprocess(host, username, password)?;
+            }
+            #[cfg(test)]
+            KeyringProviderBackend::Dummy(ref store) => {
+                store.lock().unwrap().insert(
+                    (host.to_string(), username.to_string()),
+                    password.to_string(),
+                );
+            }
+        }
+
+        // Clear any cached miss, such that the new password is picked up.
+        self.cache
+            .lock()
+            .unwrap()
+            .remove(&(host.to_string(), username.to_string()));
+
+        Ok(())
+    }
+
+    /// Remove the password for the given [`Url`] and username from the keyring.
+    pub fn remove(&self, url: &Url, username: &str) -> Result<(), KeyringError> {
+        let host = url
+            .host_str()
+            .ok_or_else(|| KeyringError::InvalidUrl(url.clone()))?;
+
+        match self.backend {
+            KeyringProviderBackend::Subprocess => self.remove_subprocess(host, username),
+            #[cfg(test)]
+            KeyringProviderBackend::Dummy(ref store) => Self::remove_dummy(store, host, username),
+        }
+    }
+
+    #[instrument(skip(password))]
+    fn store_subprocess(
+        &self,
+        service_name: &str,
+        username: &str,
+        password: &str,
+    ) -> Result<(), KeyringError> {
+        // Pass the password via stdin, rather than as an argument, to keep it out of the process
+        // list.
+        let mut child = Command::new("keyring")
+            .arg("set")
+            .arg(service_name)
+            .arg(username)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(KeyringError::Spawn)?;
+        if let Some(mut stdin) = child.stdin.take() {
+            writeln!(stdin, "{password}").map_err(KeyringError::Spawn)?;
+        }
+        let output = child.wait_with_output().map_err(KeyringError::Spawn)?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err(KeyringError::Command(
+                "set",
+                String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            ))
+        }
+    }
+
+    #[instrument]
+    fn remove_subprocess(&self, service_name: &str, username: &str) -> Result<(), KeyringError> {
+        let output = Command::new("keyring")
+            .arg("del")
+            .arg(service_name)
+            .arg(username)
+            .output()
+            .map_err(KeyringError::Spawn)?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err(KeyringError::Command(
+                "del",
+                String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            ))
+        }
+    }
+
     #[cfg(test)]
     fn fetch_dummy(
         &self,
-        store: &std::collections::HashMap<(String, &'static str), &'static str>,
+        store: &Mutex<std::collections::HashMap<(String, String), String>>,
         service_name: &str,
         username: &str,
     ) -> Option<String> {
         store
-            .get(&(service_name.to_string(), username))
-            .map(|password| password.to_string())
+            .lock()
+            .unwrap()
+            .get(&(service_name.to_string(), username.to_string()))
+            .cloned()
+    }
+
+    /// Remove a password from the dummy store, failing if it's missing (like `keyring del`).
+    #[cfg(test)]
+    fn remove_dummy(
+        store: &Mutex<std::collections::HashMap<(String, String), String>>,
+        service_name: &str,
+        username: &str,
+    ) -> Result<(), KeyringError> {
+        store
+            .lock()
+            .unwrap()
+            .remove(&(service_name.to_string(), username.to_string()))
+            .map(|_| ())
+            .ok_or_else(|| KeyringError::Command("del", "Password not found".to_string()))
     }
 
     /// Create a new provider with [`KeyringProviderBackend::Dummy`].
@@ -139,10 +253,11 @@ impl KeyringProvider {
 
         Self {
             cache: Mutex::new(HashSet::new()),
-            backend: KeyringProviderBackend::Dummy(HashMap::from_iter(
-                iter.into_iter()
-                    .map(|((service, username), password)| ((service.into(), username), password)),
-            )),
+            backend: KeyringProviderBackend::Dummy(Mutex::new(HashMap::from_iter(
+                iter.into_iter().map(|((service, username), password)| {
+                    ((service.into(), username.to_string()), password.to_string())
+                }),
+            ))),
         }
     }
 
@@ -153,11 +268,25 @@ impl KeyringProvider {
 
         Self {
             cache: Mutex::new(HashSet::new()),
-            backend: KeyringProviderBackend::Dummy(HashMap::new()),
+            backend: KeyringProviderBackend::Dummy(Mutex::new(HashMap::new())),
         }
     }
 }
 
+#[derive(thiserror::Error, Debug)]
+pub enum KeyringError {
+    #[error("Failed to run the `keyring` command")]
+    Spawn(#[source] std::io::Error),
+
+    #[error("The `keyring {0}` command failed: {1}")]
+    Command(&'static str, String),
+
+    #[error(
+        "Credentials can only be stored in the keyring for URLs with a host, but found: `{0}`"
+    )]
+    InvalidUrl(Url),
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -297,4 +426,28 @@ mod test {
         let credentials = keyring.fetch(&url, "bar");
         assert_eq!(credentials, None);
     }
+
+    #[test]
+    fn store_and_remove() {
+        let url = Url::parse("https://example.com/simple").unwrap();
+        let keyring = KeyringProvider::empty();
+
+        // Cache a miss, which should be cleared by the login.
+        assert_eq!(keyring.fetch(&url, "user"), None);
+
+        keyring.store(&url, "user", "password").unwrap();
+        assert_eq!(
+            keyring.fetch(&url.join("other").unwrap(), "user"),
+            Some(Credentials::new(
+                Some("user".to_string()),
+                Some("password".to_string())
+            ))
+        );
+
+        keyring.remove(&url, "user").unwrap();
+        assert_eq!(keyring.fetch(&url, "user"), None);
+
+        // Removing a missing password fails.
+        assert!(keyring.remove(&url, "user").is_err());
+    }
 }
@@ -1,15 +1,24 @@
 mod cache;
 mod credentials;
+mod github;
 mod keyring;
 mod middleware;
 mod netloc;
+mod netrc_files;
+mod store;
 
 use cache::CredentialsCache;
 
-pub use keyring::KeyringProvider;
+pub use github::UV_GITHUB_TOKEN_REPOSITORIES;
+pub use keyring::{KeyringError, KeyringProvider};
 pub use middleware::AuthMiddleware;
 use netloc::NetLoc;
+pub use netrc_files::{write_netrc_entry, NetrcError, ScopedNetrc};
 use once_cell::sync::Lazy;
+pub use store::{
+    CredentialStore, CredentialStoreError, LazyCredentialStore, UV_CREDENTIALS_FILE,
+    UV_CREDENTIALS_KEY,
+};
 
 // TODO(zanieb): Consider passing a cache explicitly throughout
 
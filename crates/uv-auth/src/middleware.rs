@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use http::Extensions;
+use http::{Extensions, HeaderValue};
 
 use netrc::Netrc;
 use reqwest::{Request, Response};
@@ -8,15 +8,23 @@ use reqwest_middleware::{Middleware, Next};
 use tracing::{debug, trace};
 
 use crate::{
-    cache::CheckResponse, credentials::Credentials, CredentialsCache, KeyringProvider,
-    CREDENTIALS_CACHE,
+    cache::CheckResponse, credentials::Credentials, github::GitHubAsset, CredentialsCache,
+    KeyringProvider, LazyCredentialStore, ScopedNetrc, CREDENTIALS_CACHE,
+    UV_GITHUB_TOKEN_REPOSITORIES,
 };
 
-/// A middleware that adds basic authentication to requests based on the netrc file and the keyring.
+/// A middleware that adds basic authentication to requests based on the netrc file, the credential
+/// store, and the keyring.
+///
+/// Credentials are taken from the first of the following sources that has them: (1) the request
+/// URL, (2) the cache of credentials seen earlier in the invocation, (3) the project-scoped netrc
+/// files, then the user's netrc file, (4) the credential store, and (5) the keyring.
 ///
 /// Netrc support Based on: <https://github.com/gribouille/netrc>.
 pub struct AuthMiddleware {
     netrc: Option<Netrc>,
+    scoped_netrc: ScopedNetrc,
+    credential_store: Option<LazyCredentialStore>,
     keyring: Option<KeyringProvider>,
     cache: Option<CredentialsCache>,
 }
@@ -25,6 +33,8 @@ impl AuthMiddleware {
     pub fn new() -> Self {
         Self {
             netrc: Netrc::new().ok(),
+            scoped_netrc: ScopedNetrc::default(),
+            credential_store: None,
             keyring: None,
             cache: None,
         }
@@ -39,6 +49,24 @@ impl AuthMiddleware {
         self
     }
 
+    /// Configure the project-scoped [`Netrc`] credential files to use, in order of precedence.
+    ///
+    /// Project-scoped files take precedence over the user's netrc file.
+    #[must_use]
+    pub fn with_scoped_netrc(mut self, scoped_netrc: ScopedNetrc) -> Self {
+        self.scoped_netrc = scoped_netrc;
+        self
+    }
+
+    /// Configure the credential store to use.
+    ///
+    /// `None` disables authentication via the credential store.
+    #[must_use]
+    pub fn with_credential_store(mut self, credential_store: Option<LazyCredentialStore>) -> Self {
+        self.credential_store = credential_store;
+        self
+    }
+
     /// Configure the [`KeyringProvider`] to use.
     #[must_use]
     pub fn with_keyring(mut self, keyring: Option<KeyringProvider>) -> Self {
@@ -59,6 +87,113 @@ impl AuthMiddleware {
     fn cache(&self) -> &CredentialsCache {
         self.cache.as_ref().unwrap_or(&CREDENTIALS_CACHE)
     }
+
+    /// Find a token for the GitHub API, to authenticate requests for release assets.
+    ///
+    /// Tokens are read from (1) the credentials attached to the request, (2) the netrc file entry
+    /// for `api.github.com` (but not the `default` entry), and (3) the `GITHUB_TOKEN` environment
+    /// variable, if the asset's repository is listed in `UV_GITHUB_TOKEN_REPOSITORIES`. As
+    /// `GITHUB_TOKEN` is commonly set in CI, it's never sent for repositories the user hasn't
+    /// opted in.
+    fn github_authorization(&self, request: &Request, asset: &GitHubAsset) -> Option<HeaderValue> {
+        if let Some(credentials) = Credentials::from_request(request)
+            .filter(|credentials| credentials.password().is_some())
+        {
+            return Some(credentials.to_header_value());
+        }
+
+        if let Some(entry) = self
+            .netrc
+            .as_ref()
+            .and_then(|netrc| netrc.hosts.get("api.github.com"))
+        {
+            let credentials =
+                Credentials::new(Some(entry.login.clone()), Some(entry.password.clone()));
+            return Some(credentials.to_header_value());
+        }
+
+        let repositories = std::env::var(UV_GITHUB_TOKEN_REPOSITORIES).ok()?;
+        if !asset.is_in(&repositories) {
+            trace!(
+                "Skipping `GITHUB_TOKEN` for unlisted repository: {}",
+                request.url()
+            );
+            return None;
+        }
+        let token = std::env::var("GITHUB_TOKEN")
+            .ok()
+            .filter(|token| !token.is_empty())?;
+        let mut header = HeaderValue::from_str(&format!("Bearer {token}")).ok()?;
+        header.set_sensitive(true);
+        Some(header)
+    }
+
+    /// Fetch a GitHub release asset via the GitHub API, which (unlike the asset's download URL)
+    /// accepts token authentication for assets in private repositories.
+    ///
+    /// If the asset can't be resolved via the API (e.g., the token lacks access to the
+    /// repository), the request is sent as-is.
+    async fn fetch_github_asset(
+        &self,
+        asset: GitHubAsset,
+        authorization: HeaderValue,
+        mut request: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        // If necessary, resolve the asset from the release metadata.
+        let release = if let Some(release_url) = asset.release_url() {
+            trace!("Fetching GitHub release metadata from {release_url}");
+            let mut release_request = Request::new(http::Method::GET, release_url);
+            let headers = release_request.headers_mut();
+            headers.insert(
+                reqwest::header::ACCEPT,
+                HeaderValue::from_static("application/vnd.github+json"),
+            );
+            headers.insert(reqwest::header::AUTHORIZATION, authorization.clone());
+
+            let response = next.clone().run(release_request, extensions).await?;
+            if !response.status().is_success() {
+                debug!(
+                    "Failed to fetch GitHub release metadata for {} ({})",
+                    request.url(),
+                    response.status()
+                );
+                return next.run(request, extensions).await;
+            }
+            let bytes = response.bytes().await?;
+            Some(
+                serde_json::from_slice::<serde_json::Value>(&bytes)
+                    .map_err(reqwest_middleware::Error::middleware)?,
+            )
+        } else {
+            None
+        };
+
+        let Some(asset_url) = asset.asset_url(release.as_ref()) else {
+            debug!(
+                "No matching GitHub release asset found for {}",
+                request.url()
+            );
+            return next.run(request, extensions).await;
+        };
+
+        debug!(
+            "Fetching GitHub release asset {} via {asset_url}",
+            request.url()
+        );
+        *request.url_mut() = asset_url;
+        let headers = request.headers_mut();
+        headers.insert(
+            reqwest::header::ACCEPT,
+            HeaderValue::from_static("application/octet-stream"),
+        );
+        headers.insert(reqwest::header::AUTHORIZATION, authorization);
+
+        // The API redirects to a pre-signed URL on another host, to which the authorization
+        // header is not forwarded.
+        next.run(request, extensions).await
+    }
 }
 
 impl Default for AuthMiddleware {
@@ -75,6 +210,15 @@ impl Middleware for AuthMiddleware {
         extensions: &mut Extensions,
         next: Next<'_>,
     ) -> reqwest_middleware::Result<Response> {
+        // Release assets on GitHub require an exchange via the GitHub API, if authenticated.
+        if let Some(asset) = GitHubAsset::from_url(request.url()) {
+            if let Some(authorization) = self.github_authorization(&request, &asset) {
+                return self
+                    .fetch_github_asset(asset, authorization, request, extensions, next)
+                    .await;
+            }
+        }
+
         // Check for credentials attached to (1) the request itself
         let credentials = Credentials::from_request(&request);
         // In the middleware, existing credentials are already moved from the URL
@@ -117,21 +261,44 @@ impl Middleware for AuthMiddleware {
                 CheckResponse::None => unreachable!("No credentials cannot be authenticated"),
             }
         // Otherwise, look for complete credentials in:
-        // (3) The netrc file
-        } else if let Some(credentials) = self.netrc.as_ref().and_then(|netrc| {
-            trace!("Checking netrc for credentials for {url}");
-            Credentials::from_netrc(
-                netrc,
-                request.url(),
-                credentials
-                    .get()
-                    .and_then(|credentials| credentials.username()),
-            )
-        }) {
+        // (3) The netrc files
+        } else if let Some(credentials) = self
+            .scoped_netrc
+            .iter()
+            .chain(self.netrc.as_ref())
+            .find_map(|netrc| {
+                trace!("Checking netrc for credentials for {url}");
+                Credentials::from_netrc(
+                    netrc,
+                    request.url(),
+                    credentials
+                        .get()
+                        .and_then(|credentials| credentials.username()),
+                )
+            })
+        {
             debug!("Found credentials in netrc file for {url}");
             request = credentials.authenticate(request);
             new_credentials = Some(Arc::new(credentials));
-        // (4) The keyring
+        // (4) The credential store
+        } else if let Some(credentials) = self
+            .credential_store
+            .as_ref()
+            .and_then(LazyCredentialStore::get)
+            .and_then(|store| {
+                trace!("Checking credential store for credentials for {url}");
+                store.get(
+                    request.url(),
+                    credentials
+                        .get()
+                        .and_then(|credentials| credentials.username()),
+                )
+            })
+        {
+            debug!("Found credentials in credential store for {url}");
+            request = credentials.authenticate(request);
+            new_credentials = Some(Arc::new(credentials));
+        // (5) The keyring
         // N.B. The keyring provider performs lookups for the exact URL then
         //      falls back to the host, but we cache the result per host so if a keyring
         //      implementation returns different credentials for different URLs in the
@@ -393,6 +560,86 @@ mod tests {
         Ok(())
     }
 
+    #[test(tokio::test)]
+    async fn test_scoped_netrc_file_precedence() -> Result<(), Error> {
+        let username = "user";
+        let password = "password";
+        let server = start_test_server(username, password).await;
+        let base_url = Url::parse(&server.uri())?;
+
+        let mut netrc_file = NamedTempFile::new()?;
+        writeln!(
+            netrc_file,
+            r#"machine {} login {username} password invalid"#,
+            base_url.host_str().unwrap()
+        )?;
+
+        let mut scoped_netrc_file = NamedTempFile::new()?;
+        writeln!(
+            scoped_netrc_file,
+            r#"machine {} login {username} password {password}"#,
+            base_url.host_str().unwrap()
+        )?;
+
+        let client = test_client_builder()
+            .with(
+                AuthMiddleware::new()
+                    .with_cache(CredentialsCache::new())
+                    .with_netrc(Some(
+                        Netrc::from_file(netrc_file.path()).expect("Test has valid netrc file"),
+                    ))
+                    .with_scoped_netrc(
+                        ScopedNetrc::from_files(&[scoped_netrc_file.path().to_path_buf()])
+                            .expect("Test has valid netrc file"),
+                    ),
+            )
+            .build();
+
+        assert_eq!(
+            client.get(server.uri()).send().await?.status(),
+            200,
+            "Credentials should be pulled from the scoped netrc file"
+        );
+
+        Ok(())
+    }
+
+    #[test(tokio::test)]
+    async fn test_netrc_file_matching_port() -> Result<(), Error> {
+        let username = "user";
+        let password = "password";
+        let server = start_test_server(username, password).await;
+        let base_url = Url::parse(&server.uri())?;
+
+        // The entry for the host and port takes precedence over the entry for the host.
+        let mut netrc_file = NamedTempFile::new()?;
+        writeln!(
+            netrc_file,
+            r#"machine {host} login {username} password invalid
+machine {host}:{port} login {username} password {password}"#,
+            host = base_url.host_str().unwrap(),
+            port = base_url.port().unwrap()
+        )?;
+
+        let client = test_client_builder()
+            .with(
+                AuthMiddleware::new()
+                    .with_cache(CredentialsCache::new())
+                    .with_netrc(Some(
+                        Netrc::from_file(netrc_file.path()).expect("Test has valid netrc file"),
+                    )),
+            )
+            .build();
+
+        assert_eq!(
+            client.get(server.uri()).send().await?.status(),
+            200,
+            "Credentials should be pulled from the netrc entry for the port"
+        );
+
+        Ok(())
+    }
+
     #[test(tokio::test)]
     async fn test_netrc_file_matching_host() -> Result<(), Error> {
         let username = "user";
@@ -0,0 +1,169 @@
+use std::fmt::Debug;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use netrc::Netrc;
+use url::Url;
+
+/// Project-scoped netrc files, which take precedence over the user's netrc file.
+#[derive(Clone)]
+pub struct ScopedNetrc(Arc<[Netrc]>);
+
+impl ScopedNetrc {
+    /// Read the given netrc files, in order of precedence.
+    ///
+    /// The files are read eagerly, such that a missing or malformed file is reported up front.
+    pub fn from_files(paths: &[PathBuf]) -> Result<Self, NetrcError> {
+        let files = paths
+            .iter()
+            .map(|path| {
+                Netrc::from_file(path)
+                    .map_err(|err| NetrcError::Read(path.clone(), err.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self(Arc::from(files)))
+    }
+
+    /// The netrc files, in order of precedence.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &Netrc> {
+        self.0.iter()
+    }
+}
+
+impl Default for ScopedNetrc {
+    fn default() -> Self {
+        Self(Arc::from(Vec::new()))
+    }
+}
+
+impl Debug for ScopedNetrc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ScopedNetrc")
+            .field("files", &self.0.len())
+            .finish()
+    }
+}
+
+/// The netrc `machine` name for a [`Url`]: the host, with the port if it isn't the default for
+/// the scheme.
+pub(crate) fn machine(url: &Url) -> Option<String> {
+    let host = url.host_str()?;
+    Some(match url.port() {
+        Some(port) => format!("{host}:{port}"),
+        None => host.to_string(),
+    })
+}
+
+/// Add an entry for the given [`Url`] to a netrc file, creating the file if necessary.
+///
+/// Existing entries are never overwritten, as the file may be shared with other tools.
+pub fn write_netrc_entry(
+    path: &Path,
+    url: &Url,
+    username: &str,
+    password: &str,
+) -> Result<(), NetrcError> {
+    let machine = machine(url).ok_or_else(|| NetrcError::InvalidUrl(url.clone()))?;
+
+    // The netrc format has no quoting, so tokens can't contain whitespace.
+    if [username, password]
+        .iter()
+        .any(|token| token.is_empty() || token.contains(char::is_whitespace))
+    {
+        return Err(NetrcError::InvalidToken);
+    }
+
+    // Ensure the new entry is separated from any existing entries.
+    let mut separator = "";
+    if path.exists() {
+        let netrc = Netrc::from_file(path)
+            .map_err(|err| NetrcError::Read(path.to_path_buf(), err.to_string()))?;
+        if netrc.hosts.contains_key(&machine) {
+            return Err(NetrcError::Exists(path.to_path_buf(), machine));
+        }
+        let contents = fs_err::read_to_string(path)?;
+        if !contents.is_empty() && !contents.ends_with('\n') {
+            separator = "\n";
+        }
+    }
+
+    let mut options = fs_err::OpenOptions::new();
+    options.create(true).append(true);
+    #[cfg(unix)]
+    {
+        use fs_err::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(path)?;
+    writeln!(
+        file,
+        "{separator}machine {machine} login {username} password {password}"
+    )?;
+
+    Ok(())
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum NetrcError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error("Failed to read netrc file at `{}`: {1}", _0.display())]
+    Read(PathBuf, String),
+
+    #[error("The netrc file at `{}` already contains an entry for `{1}`", _0.display())]
+    Exists(PathBuf, String),
+
+    #[error("Credentials can only be stored for URLs with a host, but found: `{0}`")]
+    InvalidUrl(Url),
+
+    #[error(
+        "Usernames and passwords in netrc files must be non-empty and can't contain whitespace"
+    )]
+    InvalidToken,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_entry() -> Result<(), NetrcError> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join(".netrc");
+
+        let url = Url::parse("https://pkgs.example.com:8443/simple/").unwrap();
+        write_netrc_entry(&path, &url, "user", "password")?;
+        assert_eq!(
+            fs_err::read_to_string(&path)?,
+            "machine pkgs.example.com:8443 login user password password\n"
+        );
+
+        // Existing entries aren't overwritten.
+        let err = write_netrc_entry(&path, &url, "user", "other").unwrap_err();
+        assert!(matches!(err, NetrcError::Exists(..)));
+
+        // Entries for other machines are appended.
+        let url = Url::parse("https://pkgs.example.com/simple/").unwrap();
+        write_netrc_entry(&path, &url, "user", "password")?;
+        let netrc = Netrc::from_file(&path).unwrap();
+        assert!(netrc.hosts.contains_key("pkgs.example.com"));
+        assert!(netrc.hosts.contains_key("pkgs.example.com:8443"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn write_entry_whitespace() -> Result<(), NetrcError> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join(".netrc");
+
+        let url = Url::parse("https://pkgs.example.com/simple/").unwrap();
+        let err = write_netrc_entry(&path, &url, "user", "pass word").unwrap_err();
+        assert!(matches!(err, NetrcError::InvalidToken));
+        assert!(!path.exists());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,470 @@
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::num::NonZeroU32;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
+
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, CHACHA20_POLY1305, NONCE_LEN};
+use ring::pbkdf2;
+use ring::rand::{SecureRandom, SystemRandom};
+use serde_json::{json, Value};
+use tracing::{debug, warn};
+use url::Url;
+
+use crate::credentials::Credentials;
+
+/// The environment variable containing the passphrase for the credential store.
+pub const UV_CREDENTIALS_KEY: &str = "UV_CREDENTIALS_KEY";
+
+/// The environment variable containing the path to the credential store, overriding the default.
+pub const UV_CREDENTIALS_FILE: &str = "UV_CREDENTIALS_FILE";
+
+/// The version of the credential store's file format.
+const VERSION: u64 = 1;
+
+/// The number of PBKDF2 iterations used to derive the encryption key from the passphrase.
+const ITERATIONS: NonZeroU32 = match NonZeroU32::new(100_000) {
+    Some(iterations) => iterations,
+    None => unreachable!(),
+};
+
+/// The length of the salt used to derive the encryption key from the passphrase.
+const SALT_LEN: usize = 16;
+
+/// An encrypted, file-based store of credentials, for environments without a keyring (e.g., CI
+/// images without a keyring daemon).
+///
+/// Credentials are keyed by origin (i.e., scheme, host, and port), and encrypted with
+/// ChaCha20-Poly1305 under a key derived from a passphrase via PBKDF2.
+///
+/// The passphrase is only ever read from `UV_CREDENTIALS_KEY`; it isn't retrieved from (or
+/// stored in) an OS key store, which would defeat the purpose in environments without one.
+pub struct CredentialStore {
+    /// The path to the store.
+    path: PathBuf,
+    /// The salt from which the key was derived.
+    salt: [u8; SALT_LEN],
+    /// The key used to encrypt and decrypt the store.
+    key: LessSafeKey,
+    /// The stored usernames and passwords, keyed by origin.
+    entries: BTreeMap<String, (String, String)>,
+}
+
+impl std::fmt::Debug for CredentialStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CredentialStore")
+            .field("path", &self.path)
+            .finish_non_exhaustive()
+    }
+}
+
+impl CredentialStore {
+    /// Open the credential store configured via the environment, if any.
+    ///
+    /// Returns `None` if `UV_CREDENTIALS_KEY` is unset, in which case the store is disabled.
+    pub fn from_env() -> Result<Option<Self>, CredentialStoreError> {
+        let Some(passphrase) = std::env::var(UV_CREDENTIALS_KEY)
+            .ok()
+            .filter(|passphrase| !passphrase.is_empty())
+        else {
+            return Ok(None);
+        };
+        let Some(path) = Self::default_path() else {
+            return Ok(None);
+        };
+        Self::open(path, &passphrase).map(Some)
+    }
+
+    /// The path to the credential store: `UV_CREDENTIALS_FILE`, if set, or a file in the user's
+    /// data directory.
+    pub fn default_path() -> Option<PathBuf> {
+        if let Some(path) = std::env::var_os(UV_CREDENTIALS_FILE).filter(|path| !path.is_empty()) {
+            return Some(PathBuf::from(path));
+        }
+        directories::ProjectDirs::from("", "", "uv")
+            .map(|dirs| dirs.data_dir().join("credentials.json"))
+    }
+
+    /// Open the credential store at the given path, decrypting it with the given passphrase.
+    ///
+    /// If the store doesn't exist, an empty store is returned; it's created on [`Self::save`].
+    pub fn open(path: PathBuf, passphrase: &str) -> Result<Self, CredentialStoreError> {
+        let contents = match fs_err::read(&path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                debug!("No credential store found at: {}", path.display());
+                let mut salt = [0; SALT_LEN];
+                SystemRandom::new()
+                    .fill(&mut salt)
+                    .map_err(|_| CredentialStoreError::Random)?;
+                let key = derive_key(passphrase, &salt);
+                return Ok(Self {
+                    path,
+                    salt,
+                    key,
+                    entries: BTreeMap::new(),
+                });
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        debug!("Reading credential store from: {}", path.display());
+        let malformed =
+            |reason: &str| CredentialStoreError::Malformed(path.clone(), reason.to_string());
+
+        let file: Value =
+            serde_json::from_slice(&contents).map_err(|err| malformed(&err.to_string()))?;
+        if file["version"].as_u64() != Some(VERSION) {
+            return Err(malformed("unsupported version"));
+        }
+        let field = |name: &str| {
+            file[name]
+                .as_str()
+                .and_then(|value| BASE64_STANDARD.decode(value).ok())
+                .ok_or_else(|| malformed(&format!("missing or invalid `{name}`")))
+        };
+        let salt: [u8; SALT_LEN] = field("salt")?
+            .try_into()
+            .map_err(|_| malformed("invalid `salt`"))?;
+        let nonce: [u8; NONCE_LEN] = field("nonce")?
+            .try_into()
+            .map_err(|_| malformed("invalid `nonce`"))?;
+        let mut ciphertext = field("ciphertext")?;
+
+        let key = derive_key(passphrase, &salt);
+        let plaintext = key
+            .open_in_place(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::empty(),
+                &mut ciphertext,
+            )
+            .map_err(|_| CredentialStoreError::Decrypt(path.clone()))?;
+
+        let plaintext: Value =
+            serde_json::from_slice(plaintext).map_err(|err| malformed(&err.to_string()))?;
+        let entries = plaintext
+            .as_object()
+            .ok_or_else(|| malformed("expected an object"))?
+            .iter()
+            .map(|(origin, entry)| {
+                let username = entry["username"].as_str();
+                let password = entry["password"].as_str();
+                match (username, password) {
+                    (Some(username), Some(password)) => {
+                        Ok((origin.clone(), (username.to_string(), password.to_string())))
+                    }
+                    _ => Err(malformed(&format!("invalid entry for `{origin}`"))),
+                }
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            path,
+            salt,
+            key,
+            entries,
+        })
+    }
+
+    /// The path to the credential store.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Return the [`Credentials`] for the origin of the given [`Url`], if any.
+    ///
+    /// If a username is provided, it must match the stored username or [`None`] is returned.
+    pub(crate) fn get(&self, url: &Url, username: Option<&str>) -> Option<Credentials> {
+        let (stored_username, password) = self.entries.get(&origin(url)?)?;
+        if username.is_some_and(|username| username != stored_username) {
+            return None;
+        }
+        Some(Credentials::new(
+            Some(stored_username.clone()),
+            Some(password.clone()),
+        ))
+    }
+
+    /// Store a username and password for the origin of the given [`Url`], replacing any existing
+    /// entry.
+    pub fn insert(
+        &mut self,
+        url: &Url,
+        username: String,
+        password: String,
+    ) -> Result<(), CredentialStoreError> {
+        let origin = origin(url).ok_or_else(|| CredentialStoreError::InvalidUrl(url.clone()))?;
+        self.entries.insert(origin, (username, password));
+        Ok(())
+    }
+
+    /// Remove the entry for the origin of the given [`Url`], returning `true` if one existed.
+    pub fn remove(&mut self, url: &Url) -> bool {
+        origin(url).is_some_and(|origin| self.entries.remove(&origin).is_some())
+    }
+
+    /// Iterate over the origins and usernames in the store.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.entries
+            .iter()
+            .map(|(origin, (username, _))| (origin.as_str(), username.as_str()))
+    }
+
+    /// Encrypt and write the credential store to disk.
+    pub fn save(&self) -> Result<(), CredentialStoreError> {
+        let plaintext = Value::Object(
+            self.entries
+                .iter()
+                .map(|(origin, (username, password))| {
+                    (
+                        origin.clone(),
+                        json!({ "username": username, "password": password }),
+                    )
+                })
+                .collect(),
+        );
+
+        // Use a fresh nonce on every write, such that no nonce is reused under the same key.
+        let mut nonce = [0; NONCE_LEN];
+        SystemRandom::new()
+            .fill(&mut nonce)
+            .map_err(|_| CredentialStoreError::Random)?;
+
+        let mut ciphertext = plaintext.to_string().into_bytes();
+        self.key
+            .seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::empty(),
+                &mut ciphertext,
+            )
+            .map_err(|_| CredentialStoreError::Encrypt)?;
+
+        let file = json!({
+            "version": VERSION,
+            "salt": BASE64_STANDARD.encode(self.salt),
+            "nonce": BASE64_STANDARD.encode(nonce),
+            "ciphertext": BASE64_STANDARD.encode(ciphertext),
+        });
+
+        let parent = self
+            .path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        fs_err::create_dir_all(parent)?;
+
+        // Write to a temporary file alongside the store, then move it into place, such that a
+        // failed write never leaves a truncated store behind.
+        let mut temp_file = tempfile::NamedTempFile::new_in(parent)?;
+
+        // Restrict the store to the current user, as with a netrc file.
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            temp_file
+                .as_file()
+                .set_permissions(std::fs::Permissions::from_mode(0o600))?;
+        }
+
+        temp_file.write_all(file.to_string().as_bytes())?;
+        temp_file.as_file().sync_all()?;
+        temp_file
+            .persist(&self.path)
+            .map_err(|err| CredentialStoreError::Io(err.error))?;
+
+        debug!("Wrote credential store to: {}", self.path.display());
+        Ok(())
+    }
+}
+
+/// The [`CredentialStore`] configured via the environment, which is only read (and decrypted) on
+/// first use, and shared by every clone.
+#[derive(Debug, Clone, Default)]
+pub struct LazyCredentialStore(Arc<OnceLock<Option<CredentialStore>>>);
+
+impl LazyCredentialStore {
+    /// Return the store, reading it if necessary.
+    ///
+    /// Returns `None` if the store is disabled, or can't be read.
+    pub(crate) fn get(&self) -> Option<&CredentialStore> {
+        self.0
+            .get_or_init(|| match CredentialStore::from_env() {
+                Ok(store) => store,
+                Err(err) => {
+                    warn!("Failed to read credential store: {err}");
+                    None
+                }
+            })
+            .as_ref()
+    }
+}
+
+/// Derive the encryption key for the store from a passphrase and salt.
+fn derive_key(passphrase: &str, salt: &[u8]) -> LessSafeKey {
+    let mut key = [0; 32];
+    pbkdf2::derive(
+        pbkdf2::PBKDF2_HMAC_SHA256,
+        ITERATIONS,
+        salt,
+        passphrase.as_bytes(),
+        &mut key,
+    );
+    LessSafeKey::new(
+        UnboundKey::new(&CHACHA20_POLY1305, &key).expect("ChaCha20-Poly1305 keys are 32 bytes"),
+    )
+}
+
+/// Return the origin (i.e., scheme, host, and port) under which credentials for the [`Url`] are
+/// stored, if any.
+fn origin(url: &Url) -> Option<String> {
+    let origin = url.origin();
+    origin.is_tuple().then(|| origin.ascii_serialization())
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum CredentialStoreError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error("Failed to parse credential store at `{}`: {1}", _0.display())]
+    Malformed(PathBuf, String),
+
+    #[error("Failed to decrypt credential store at `{}`; is `UV_CREDENTIALS_KEY` correct?", _0.display())]
+    Decrypt(PathBuf),
+
+    #[error("Credentials can only be stored for URLs with a host, but found: `{0}`")]
+    InvalidUrl(Url),
+
+    #[error("Failed to encrypt the credential store")]
+    Encrypt,
+
+    #[error("Failed to generate random bytes for the credential store")]
+    Random,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() -> Result<(), CredentialStoreError> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("credentials.json");
+
+        let mut store = CredentialStore::open(path.clone(), "passphrase")?;
+        store.insert(
+            &Url::parse("https://example.com/simple/").unwrap(),
+            "user".to_string(),
+            "password".to_string(),
+        )?;
+        store.save()?;
+
+        // The store should be encrypted at rest.
+        let contents = fs_err::read_to_string(&path)?;
+        assert!(!contents.contains("password"));
+
+        // Credentials should be found for any URL with the same origin.
+        let store = CredentialStore::open(path.clone(), "passphrase")?;
+        assert_eq!(
+            store.get(
+                &Url::parse("https://example.com/files/a.whl").unwrap(),
+                None
+            ),
+            Some(Credentials::new(
+                Some("user".to_string()),
+                Some("password".to_string())
+            ))
+        );
+        assert_eq!(
+            store.get(
+                &Url::parse("https://example.com/simple/").unwrap(),
+                Some("user")
+            ),
+            Some(Credentials::new(
+                Some("user".to_string()),
+                Some("password".to_string())
+            ))
+        );
+
+        // But not for a different origin, or a different username.
+        assert_eq!(
+            store.get(&Url::parse("http://example.com/simple/").unwrap(), None),
+            None
+        );
+        assert_eq!(
+            store.get(&Url::parse("https://example.com:8443/").unwrap(), None),
+            None
+        );
+        assert_eq!(
+            store.get(
+                &Url::parse("https://example.com/simple/").unwrap(),
+                Some("other")
+            ),
+            None
+        );
+        assert_eq!(
+            store.iter().collect::<Vec<_>>(),
+            vec![("https://example.com", "user")]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn wrong_passphrase() -> Result<(), CredentialStoreError> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("credentials.json");
+
+        CredentialStore::open(path.clone(), "passphrase")?.save()?;
+
+        let err = CredentialStore::open(path, "incorrect").unwrap_err();
+        assert!(matches!(err, CredentialStoreError::Decrypt(_)));
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn save_replaces_store() -> Result<(), CredentialStoreError> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("credentials.json");
+
+        let store = CredentialStore::open(path.clone(), "passphrase")?;
+
+        // A file at the store's path, readable by others.
+        fs_err::write(&path, "{}")?;
+        fs_err::set_permissions(&path, std::fs::Permissions::from_mode(0o644))?;
+
+        store.save()?;
+
+        // The store should be replaced, rather than rewritten in place, and restricted to the
+        // current user.
+        let mode = fs_err::metadata(&path)?.permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        assert!(CredentialStore::open(path, "passphrase").is_ok());
+
+        // No temporary files should be left behind.
+        assert_eq!(fs_err::read_dir(dir.path())?.count(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn remove() -> Result<(), CredentialStoreError> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("credentials.json");
+
+        let url = Url::parse("https://example.com/simple/").unwrap();
+        let mut store = CredentialStore::open(path, "passphrase")?;
+        store.insert(&url, "user".to_string(), "password".to_string())?;
+        assert!(store.remove(&url));
+        assert!(!store.remove(&url));
+        assert_eq!(store.get(&url, None), None);
+
+        Ok(())
+    }
+}
@@ -385,6 +385,7 @@ impl SourceBuild {
         interpreter: &Interpreter,
         build_context: &impl BuildContext,
         source_build_context: SourceBuildContext,
+        package_name: Option<&PackageName>,
         version_id: String,
         setup_py: SetupPyStrategy,
         config_settings: ConfigSettings,
@@ -407,21 +408,26 @@ impl SourceBuild {
             Self::extract_pep517_backend(&source_tree, setup_py, &default_backend)
                 .map_err(|err| *err)?;
 
+        // If the package name isn't known (e.g., for editables), fall back to the project name.
+        let package_name = package_name.or(project.as_ref().map(|project| &project.name));
+
         // Create a virtual environment, or install into the shared environment if requested.
-        let venv = match build_isolation {
-            BuildIsolation::Isolated => uv_virtualenv::create_venv(
+        let venv = if let Some(venv) = build_isolation.shared_environment(package_name) {
+            venv.clone()
+        } else {
+            uv_virtualenv::create_venv(
                 &temp_dir.path().join(".venv"),
                 interpreter.clone(),
                 uv_virtualenv::Prompt::None,
                 false,
+                uv_virtualenv::Activator::ALL,
                 Vec::new(),
-            )?,
-            BuildIsolation::Shared(venv) => venv.clone(),
+            )?
         };
 
         // Setup the build environment. If build isolation is disabled, we assume the build
         // environment is already setup.
-        if build_isolation.is_isolated() {
+        if build_isolation.is_isolated(package_name) {
             let resolved_requirements = Self::get_resolved_requirements(
                 build_context,
                 source_build_context,
@@ -470,7 +476,7 @@ impl SourceBuild {
 
         // Create the PEP 517 build environment. If build isolation is disabled, we assume the build
         // environment is already setup.
-        if build_isolation.is_isolated() {
+        if build_isolation.is_isolated(package_name) {
             if let Some(pep517_backend) = &pep517_backend {
                 create_pep517_build_environment(
                     &source_tree,
@@ -548,6 +554,24 @@ impl SourceBuild {
         })
     }
 
+    /// Return the static build requirements of a source tree: the `build-system.requires` of its
+    /// `pyproject.toml`, or those of the default backend.
+    ///
+    /// Unlike [`SourceBuild::setup`], this doesn't call `get_requires_for_build_wheel`, so any
+    /// requirements that the backend adds dynamically are omitted.
+    pub fn static_requirements(
+        source_tree: &Path,
+        setup_py: SetupPyStrategy,
+    ) -> Result<Vec<Requirement>, Error> {
+        let (pep517_backend, _) =
+            Self::extract_pep517_backend(source_tree, setup_py, &DEFAULT_BACKEND)
+                .map_err(|err| *err)?;
+        Ok(match pep517_backend {
+            Some(pep517_backend) => pep517_backend.requirements,
+            None => SETUP_PY_REQUIREMENTS.to_vec(),
+        })
+    }
+
     /// Extract the PEP 517 backend from the `pyproject.toml` or `setup.py` file.
     fn extract_pep517_backend(
         source_tree: &Path,
@@ -3,8 +3,9 @@ use std::path::PathBuf;
 
 use clap::Parser;
 use directories::ProjectDirs;
+use url::Url;
 
-use crate::Cache;
+use crate::{Cache, CacheScope, CacheSize};
 
 #[derive(Parser, Debug, Clone)]
 pub struct CacheArgs {
@@ -25,6 +26,75 @@ pub struct CacheArgs {
     /// on Windows.
     #[arg(global = true, long, env = "UV_CACHE_DIR")]
     cache_dir: Option<PathBuf>,
+
+    /// Path to a writable directory in which to store new cache entries, if the cache directory is
+    /// read-only (e.g., when baked into a container image).
+    ///
+    /// Existing entries continue to be read from the read-only cache directory. Defaults to a
+    /// directory within the system's temporary directory that's private to the current user. The
+    /// overlay directory must be owned by the current user.
+    #[arg(global = true, long, env = "UV_CACHE_OVERLAY_DIR", value_name = "PATH")]
+    cache_overlay_dir: Option<PathBuf>,
+
+    /// The maximum size of the cache (e.g., `10GB`).
+    ///
+    /// If the unzipped wheels in the cache exceed this size at the end of a command, the
+    /// least-recently used wheels are evicted. Other cache entries (e.g., metadata and built
+    /// wheels) don't count towards the limit. Eviction is skipped while the cache is in use by
+    /// another uv process, and wheels installed with `--link-mode symlink` are never evicted (nor
+    /// counted) while the environment that uses them exists.
+    #[arg(global = true, long, env = "UV_CACHE_MAX_SIZE", value_name = "SIZE")]
+    cache_max_size: Option<CacheSize>,
+
+    /// Store files that are identical across unzipped wheels once, as hard links to a shared copy.
+    ///
+    /// Each file is hashed as wheels are unzipped into the cache, and replaced with a hard link to
+    /// any identical file that's already cached. Shared copies are removed once no wheel links to
+    /// them. Only supported on Unix. To deduplicate an existing cache, use `uv cache dedupe`.
+    #[arg(global = true, long, env = "UV_CACHE_DEDUPE")]
+    cache_dedupe: bool,
+
+    /// The URL of a remote cache to share built wheels and metadata across machines (e.g., CI
+    /// runners).
+    ///
+    /// Before building a source distribution (or its metadata, during resolution), uv will attempt
+    /// to fetch a compatible wheel (or the metadata) from the remote cache; after building, the
+    /// wheel and metadata are uploaded via `PUT`. Entries are keyed by
+    /// the full build configuration, and signed with the `--remote-cache-key`; entries with a
+    /// missing or invalid signature are ignored. Any failure falls back to building locally.
+    ///
+    /// Requires `--remote-cache-key`.
+    #[arg(global = true, long, env = "UV_REMOTE_CACHE", value_name = "URL")]
+    remote_cache: Option<Url>,
+
+    /// The secret key used to sign and verify entries in the remote cache.
+    ///
+    /// Every machine that shares the remote cache must use the same key. Only entries signed with
+    /// this key are trusted.
+    #[arg(
+        global = true,
+        long,
+        env = "UV_REMOTE_CACHE_KEY",
+        value_name = "KEY",
+        hide_env_values = true
+    )]
+    remote_cache_key: Option<String>,
+
+    /// The scope at which mutable cache entries are shared.
+    ///
+    /// With `project`, wheels built from source distributions, Git checkouts, and cached
+    /// resolutions are namespaced by project (i.e., by the directory containing the `uv.toml` or
+    /// `pyproject.toml`, or the current directory), while downloaded wheels are shared across
+    /// projects.
+    #[arg(global = true, long, env = "UV_CACHE_SCOPE", value_enum)]
+    cache_scope: Option<CacheScope>,
+}
+
+impl CacheArgs {
+    /// Return the [`CacheScope`] requested on the command-line, if any.
+    pub fn cache_scope(&self) -> Option<CacheScope> {
+        self.cache_scope
+    }
 }
 
 impl TryFrom<CacheArgs> for Cache {
@@ -39,13 +109,21 @@ impl TryFrom<CacheArgs> for Cache {
     /// Returns an absolute cache dir.
     fn try_from(value: CacheArgs) -> Result<Self, Self::Error> {
         if value.no_cache {
-            Self::temp()
-        } else if let Some(cache_dir) = value.cache_dir {
-            Self::from_path(cache_dir)
+            return Self::temp();
+        }
+
+        let overlay = value.cache_overlay_dir;
+        let cache = if let Some(cache_dir) = value.cache_dir {
+            Self::from_path_with_overlay(cache_dir, overlay)?
         } else if let Some(project_dirs) = ProjectDirs::from("", "", "uv") {
-            Self::from_path(project_dirs.cache_dir())
+            Self::from_path_with_overlay(project_dirs.cache_dir(), overlay)?
         } else {
-            Self::from_path(".uv_cache")
-        }
+            Self::from_path_with_overlay(".uv_cache", overlay)?
+        };
+
+        Ok(cache
+            .with_max_size(value.cache_max_size)
+            .with_dedupe(value.cache_dedupe)
+            .with_remote(value.remote_cache, value.remote_cache_key))
     }
 }
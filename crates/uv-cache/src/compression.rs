@@ -0,0 +1,208 @@
+use std::io;
+use std::path::{Path, PathBuf};
+
+use fs_err as fs;
+use tracing::debug;
+use zip::write::FileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+use crate::removal::rm_rf;
+
+/// The extension of a compressed archive, stored alongside the (removed) archive directory, as
+/// `<archive>.zip`.
+const EXTENSION: &str = "zip";
+
+#[derive(Debug, Default)]
+pub struct Compression {
+    /// The number of archives that were compressed.
+    pub num_archives: u64,
+    /// The total number of bytes reclaimed.
+    pub total_bytes: u64,
+}
+
+/// Return the path to the compressed form of the given archive.
+pub(crate) fn compressed_path(archive: &Path) -> PathBuf {
+    let mut compressed = archive.as_os_str().to_owned();
+    compressed.push(".");
+    compressed.push(EXTENSION);
+    PathBuf::from(compressed)
+}
+
+/// Returns `true` if the given path is a compressed archive.
+pub(crate) fn is_compressed(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension == EXTENSION)
+}
+
+/// Compress an archive into a `.zip` file (using zstd) alongside it, and remove the archive
+/// directory, returning the number of bytes reclaimed.
+///
+/// Archives that contain anything other than regular files and directories are left as-is.
+/// Any hard links to a shared blob (see [`crate::dedupe::link_blobs`]) are replaced by a
+/// compressed copy.
+pub(crate) fn compress(archive: &Path) -> io::Result<Option<u64>> {
+    let bucket = archive.parent().expect("archive to have a parent");
+
+    let mut size = 0;
+    let mut writer = ZipWriter::new(tempfile::NamedTempFile::new_in(bucket)?);
+    for entry in walkdir::WalkDir::new(archive).sort_by_file_name() {
+        let entry = entry?;
+        let relative = entry
+            .path()
+            .strip_prefix(archive)
+            .expect("walkdir starts with root");
+        if relative.as_os_str().is_empty() {
+            continue;
+        }
+        let Some(name) = relative.to_str() else {
+            debug!(
+                "Skipping archive with non-UTF-8 path: {}",
+                archive.display()
+            );
+            return Ok(None);
+        };
+        let name = name.replace('\\', "/");
+
+        let options = FileOptions::default()
+            .compression_method(CompressionMethod::Zstd)
+            .unix_permissions(mode(&entry.metadata()?));
+        if entry.file_type().is_dir() {
+            writer.add_directory(name, options)?;
+        } else if entry.file_type().is_file() {
+            size += entry.metadata()?.len();
+            writer.start_file(name, options)?;
+            io::copy(&mut fs::File::open(entry.path())?, &mut writer)?;
+        } else {
+            debug!(
+                "Skipping archive with unsupported entry: {}",
+                entry.path().display()
+            );
+            return Ok(None);
+        }
+    }
+    let file = writer.finish()?;
+
+    let compressed_size = file.as_file().metadata()?.len();
+    file.persist(compressed_path(archive))
+        .map_err(|err| err.error)?;
+
+    // Remove the archive itself. Any links to the archive are left in place, to be restored on
+    // next use (see [`restore`]).
+    rm_rf(archive)?;
+
+    Ok(Some(size.saturating_sub(compressed_size)))
+}
+
+/// Restore a compressed archive to the given path, if the archive is missing and a compressed
+/// copy exists alongside it.
+///
+/// Returns `true` if the archive was restored. Safe to call concurrently: if another process
+/// restores the archive first, its copy is used.
+pub(crate) fn restore(archive: &Path) -> io::Result<bool> {
+    if archive.exists() {
+        return Ok(false);
+    }
+    let compressed = compressed_path(archive);
+    if !compressed.is_file() {
+        return Ok(false);
+    }
+
+    debug!("Restoring compressed cache entry: {}", archive.display());
+
+    // Unzip into a temporary directory alongside the archive, then move it into place.
+    let bucket = archive.parent().expect("archive to have a parent");
+    let temp_dir = tempfile::tempdir_in(bucket)?;
+    uv_extract::unzip_file(&compressed, temp_dir.path())
+        .map_err(|err| io::Error::new(io::ErrorKind::Other, err))?;
+    match fs::rename(temp_dir.path(), archive) {
+        Ok(()) => {}
+        Err(_) if archive.is_dir() => {
+            debug!(
+                "Compressed cache entry was restored concurrently: {}",
+                archive.display()
+            );
+        }
+        Err(err) => return Err(err),
+    }
+
+    // Mark the archive as used, such that it isn't immediately re-compressed or evicted.
+    uv_fs::touch(archive)?;
+
+    match fs::remove_file(&compressed) {
+        Ok(()) => {}
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+        Err(err) => return Err(err),
+    }
+
+    Ok(true)
+}
+
+/// Restore the archive behind the given link (e.g., a built wheel in the wheels bucket), if the
+/// link no longer resolves because the archive was compressed.
+///
+/// Returns `true` if the archive was restored.
+pub fn restore_link(link: &Path) -> io::Result<bool> {
+    if link.exists() {
+        return Ok(false);
+    }
+    let Ok(target) = fs::read_link(link) else {
+        return Ok(false);
+    };
+    restore(&target)
+}
+
+/// Return the Unix permissions to record for an entry.
+fn mode(metadata: &std::fs::Metadata) -> u32 {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        metadata.permissions().mode()
+    }
+
+    #[cfg(not(unix))]
+    {
+        if metadata.is_dir() {
+            0o755
+        } else {
+            0o644
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use fs_err as fs;
+
+    use super::{compress, compressed_path, restore};
+
+    #[test]
+    fn round_trip() -> io::Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let archive = temp_dir.path().join("archive");
+        fs::create_dir_all(archive.join("foo-1.0.0.dist-info"))?;
+        fs::create_dir_all(archive.join("empty"))?;
+        fs::write(archive.join("foo.py"), "print('hello')\n".repeat(100))?;
+        fs::write(archive.join("foo-1.0.0.dist-info").join("RECORD"), "")?;
+
+        let saved = compress(&archive)?.expect("archive to be compressed");
+        assert!(saved > 0);
+        assert!(!archive.exists());
+        assert!(compressed_path(&archive).is_file());
+
+        assert!(restore(&archive)?);
+        assert!(!compressed_path(&archive).exists());
+        assert_eq!(
+            fs::read_to_string(archive.join("foo.py"))?,
+            "print('hello')\n".repeat(100)
+        );
+        assert!(archive.join("foo-1.0.0.dist-info").join("RECORD").is_file());
+        assert!(archive.join("empty").is_dir());
+
+        // Restoring an archive that exists is a no-op.
+        assert!(!restore(&archive)?);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,245 @@
+use std::fmt::Write;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use fs_err as fs;
+use rustc_hash::FxHashMap;
+use sha2::{Digest, Sha256};
+use tracing::debug;
+
+use crate::removal::{rm_rf, Removal};
+
+#[derive(Debug, Default)]
+pub struct Deduplication {
+    /// The number of files that were replaced with a hard link.
+    pub num_files: u64,
+    /// The total number of bytes reclaimed.
+    pub total_bytes: u64,
+}
+
+impl std::ops::AddAssign for Deduplication {
+    fn add_assign(&mut self, other: Self) {
+        self.num_files += other.num_files;
+        self.total_bytes += other.total_bytes;
+    }
+}
+
+/// Replace identical files within a directory with hard links to a single copy.
+///
+/// Files are first grouped by size, and then by the SHA-256 digest of their contents, such that
+/// only files with a non-trivial chance of being identical are read. Files that are already
+/// hard-linked to one another are skipped.
+///
+/// Links are staged in `temp_dir`, which must reside on the same filesystem as `root`.
+pub(crate) fn dedupe(root: impl AsRef<Path>, temp_dir: &Path) -> io::Result<Deduplication> {
+    let mut summary = Deduplication::default();
+
+    // Group the files by size, skipping empty files (which don't consume any blocks).
+    let mut by_size: FxHashMap<u64, Vec<PathBuf>> = FxHashMap::default();
+    for entry in walkdir::WalkDir::new(root.as_ref()) {
+        let entry = entry?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let len = entry.metadata()?.len();
+        if len == 0 {
+            continue;
+        }
+        by_size.entry(len).or_default().push(entry.into_path());
+    }
+
+    for (len, paths) in by_size {
+        if paths.len() < 2 {
+            continue;
+        }
+
+        // Within each size class, group the files by content. Files with different permissions
+        // (e.g., the executable bit on scripts) can't share an inode, so they're kept apart.
+        let mut by_digest: FxHashMap<([u8; 32], u32), PathBuf> = FxHashMap::default();
+        for path in paths {
+            let key = (digest(&path)?, mode(&path)?);
+            let Some(original) = by_digest.get(&key) else {
+                by_digest.insert(key, path);
+                continue;
+            };
+
+            if same_file::is_same_file(original, &path)? {
+                continue;
+            }
+
+            debug!(
+                "Replacing duplicate cache entry: {} -> {}",
+                path.display(),
+                original.display()
+            );
+            link(original, &path, temp_dir)?;
+
+            summary.num_files += 1;
+            summary.total_bytes += len;
+        }
+    }
+
+    Ok(summary)
+}
+
+/// Replace each file in a directory with a hard link to the blob with the same contents (and
+/// permissions) in the blob store at `blobs`, adding any files that aren't yet present to the
+/// store.
+///
+/// Blobs are named by the SHA-256 digest of their contents, such that identical files across
+/// archives share a single copy on disk. A blob is removed once no archive links to it (see
+/// [`remove_unused_blobs`]).
+///
+/// Links are staged in `temp_dir`, which must reside on the same filesystem as `dir`.
+pub(crate) fn link_blobs(dir: &Path, blobs: &Path, temp_dir: &Path) -> io::Result<Deduplication> {
+    let mut summary = Deduplication::default();
+
+    for entry in walkdir::WalkDir::new(dir) {
+        let entry = entry?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let len = entry.metadata()?.len();
+        if len == 0 {
+            continue;
+        }
+
+        let path = entry.into_path();
+        let blob = blob_path(blobs, &digest(&path)?, mode(&path)?);
+        fs::create_dir_all(blob.parent().expect("blob to have a parent"))?;
+
+        match fs::hard_link(&path, &blob) {
+            // The file is now the blob, to be shared by any subsequent archives.
+            Ok(()) => {}
+            Err(err) if err.kind() == io::ErrorKind::AlreadyExists => {
+                debug!(
+                    "Replacing duplicate cache entry: {} -> {}",
+                    path.display(),
+                    blob.display()
+                );
+                link(&blob, &path, temp_dir)?;
+
+                summary.num_files += 1;
+                summary.total_bytes += len;
+            }
+            // Linking can fail for reasons that don't affect the archive itself (e.g., if the
+            // blob has reached the filesystem's link limit), in which case the file is kept as-is.
+            Err(err) => {
+                debug!("Failed to add {} to the blob store: {err}", path.display());
+            }
+        }
+    }
+
+    Ok(summary)
+}
+
+/// Remove any blobs that are no longer linked from an archive.
+///
+/// Blobs are only created on Unix, where the link count of each blob is used to determine whether
+/// it's still in use.
+pub(crate) fn remove_unused_blobs(blobs: &Path) -> io::Result<Removal> {
+    let mut summary = Removal::default();
+    if !blobs.is_dir() {
+        return Ok(summary);
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+
+        for entry in walkdir::WalkDir::new(blobs) {
+            let entry = entry?;
+            if entry.file_type().is_file() && entry.metadata()?.nlink() == 1 {
+                debug!("Removing unused blob: {}", entry.path().display());
+                summary += rm_rf(entry.path())?;
+            }
+        }
+    }
+
+    Ok(summary)
+}
+
+/// Return the path to the blob with the given digest and permissions, sharded by the first two
+/// characters of the digest (e.g., `ab/ab12...ef-100644`).
+fn blob_path(blobs: &Path, digest: &[u8; 32], mode: u32) -> PathBuf {
+    let digest = digest
+        .iter()
+        .fold(String::with_capacity(64), |mut hex, byte| {
+            let _ = write!(hex, "{byte:02x}");
+            hex
+        });
+    blobs.join(&digest[..2]).join(format!("{digest}-{mode:o}"))
+}
+
+/// Compute the SHA-256 digest of a file.
+fn digest(path: &Path) -> io::Result<[u8; 32]> {
+    let mut hasher = Sha256::new();
+    io::copy(&mut fs::File::open(path)?, &mut hasher)?;
+    Ok(hasher.finalize().into())
+}
+
+/// Return the permission bits of a file.
+fn mode(path: &Path) -> io::Result<u32> {
+    let permissions = fs::metadata(path)?.permissions();
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        Ok(permissions.mode())
+    }
+
+    #[cfg(not(unix))]
+    {
+        Ok(u32::from(permissions.readonly()))
+    }
+}
+
+/// Atomically replace `dst` with a hard link to `src`.
+fn link(src: &Path, dst: &Path, temp_dir: &Path) -> io::Result<()> {
+    let temp = temp_dir.join("link");
+    fs::hard_link(src, &temp)?;
+    uv_fs::rename_with_retry_sync(&temp, dst)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use fs_err as fs;
+
+    use super::{link_blobs, remove_unused_blobs};
+
+    #[test]
+    #[cfg(unix)]
+    fn shared_blob() -> io::Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let blobs = temp_dir.path().join("blobs");
+        let staging = tempfile::tempdir_in(temp_dir.path())?;
+
+        let first = temp_dir.path().join("first");
+        let second = temp_dir.path().join("second");
+        for archive in [&first, &second] {
+            fs::create_dir_all(archive)?;
+            fs::write(archive.join("LICENSE"), "MIT")?;
+        }
+        fs::write(second.join("module.py"), "print('hello')")?;
+
+        // The first archive populates the blob store, and the second links to it.
+        assert_eq!(link_blobs(&first, &blobs, staging.path())?.num_files, 0);
+        assert_eq!(link_blobs(&second, &blobs, staging.path())?.num_files, 1);
+        assert!(same_file::is_same_file(
+            first.join("LICENSE"),
+            second.join("LICENSE")
+        )?);
+
+        // Blobs are retained for as long as any archive links to them.
+        fs::remove_dir_all(&first)?;
+        assert_eq!(remove_unused_blobs(&blobs)?.num_files, 0);
+
+        fs::remove_dir_all(&second)?;
+        assert_eq!(remove_unused_blobs(&blobs)?.num_files, 2);
+
+        Ok(())
+    }
+}
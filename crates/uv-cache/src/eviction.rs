@@ -0,0 +1,359 @@
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, SystemTime};
+
+use fs_err as fs;
+use tracing::debug;
+
+use crate::compression;
+use crate::removal::{rm_rf, Removal};
+use crate::CacheBucket;
+
+/// A maximum size for the cache, e.g., `10GB` or `512MiB`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CacheSize(u64);
+
+impl CacheSize {
+    /// Return the size in bytes.
+    pub fn bytes(self) -> u64 {
+        self.0
+    }
+}
+
+impl FromStr for CacheSize {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let split = s
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(s.len());
+        let (number, unit) = s.split_at(split);
+
+        let number = number
+            .parse::<f64>()
+            .map_err(|_| format!("`{s}` is not a valid size (expected, e.g., `10GB`)"))?;
+
+        let multiplier: u64 = match unit.trim().to_ascii_lowercase().as_str() {
+            "" | "b" => 1,
+            "k" | "kb" => 1000,
+            "m" | "mb" => 1000_u64.pow(2),
+            "g" | "gb" => 1000_u64.pow(3),
+            "t" | "tb" => 1000_u64.pow(4),
+            "kib" => 1024,
+            "mib" => 1024_u64.pow(2),
+            "gib" => 1024_u64.pow(3),
+            "tib" => 1024_u64.pow(4),
+            unit => return Err(format!("`{unit}` is not a valid size unit")),
+        };
+
+        #[allow(
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            clippy::cast_precision_loss
+        )]
+        Ok(Self((number * multiplier as f64) as u64))
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for CacheSize {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+/// A maximum age for cache entries, e.g., `30d` or `12h`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CacheAge(Duration);
+
+impl CacheAge {
+    /// Return the age as a [`Duration`].
+    pub fn duration(self) -> Duration {
+        self.0
+    }
+}
+
+impl FromStr for CacheAge {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+        let (number, unit) = s.split_at(split);
+
+        let number = number
+            .parse::<u64>()
+            .map_err(|_| format!("`{s}` is not a valid age (expected, e.g., `30d`)"))?;
+
+        let seconds = match unit {
+            "s" => 1,
+            "m" => 60,
+            "h" => 60 * 60,
+            "d" => 60 * 60 * 24,
+            "w" => 60 * 60 * 24 * 7,
+            "" => {
+                return Err(format!(
+                    "`{s}` is missing a unit (one of `s`, `m`, `h`, `d`, or `w`)"
+                ))
+            }
+            unit => return Err(format!("`{unit}` is not a valid age unit")),
+        };
+
+        Ok(Self(Duration::from_secs(number.saturating_mul(seconds))))
+    }
+}
+
+/// An unzipped wheel in the archive bucket, along with its size and the time it was last used.
+#[derive(Debug)]
+pub(crate) struct ArchiveEntry {
+    pub(crate) path: PathBuf,
+    pub(crate) size: u64,
+    pub(crate) last_used: SystemTime,
+    /// Whether the archive is pinned by an environment (see [`pin_archive`]).
+    pub(crate) pinned: bool,
+}
+
+/// Pin an archive on behalf of the given environment, such that it's never evicted from the
+/// cache while that environment exists.
+///
+/// Environments installed with symbolic links (i.e., `--link-mode symlink`) reference the archive
+/// directly, and would break if it were evicted. The pin is stored alongside the archive, as
+/// `<archive>.pin`, and lists the root of every environment that holds it, one per line. Paths
+/// outside the archive bucket are ignored.
+pub fn pin_archive(archive: &Path, environment: &Path) -> io::Result<()> {
+    let archive = fs::canonicalize(archive)?;
+    if archive
+        .parent()
+        .and_then(Path::file_name)
+        .map_or(true, |bucket| bucket != CacheBucket::Archive.to_str())
+    {
+        return Ok(());
+    }
+    let environment = fs::canonicalize(environment)?;
+
+    let pin = pin_path(&archive);
+    if pin_holders(&pin)?.contains(&environment) {
+        return Ok(());
+    }
+    let mut file = fs::OpenOptions::new().create(true).append(true).open(pin)?;
+    writeln!(file, "{}", environment.to_string_lossy())?;
+    Ok(())
+}
+
+/// Return the path to the pin for the given archive.
+fn pin_path(archive: &Path) -> PathBuf {
+    let mut pin = archive.as_os_str().to_owned();
+    pin.push(".pin");
+    PathBuf::from(pin)
+}
+
+/// Read the environments that hold the given pin.
+fn pin_holders(pin: &Path) -> io::Result<Vec<PathBuf>> {
+    match fs::read_to_string(pin) {
+        Ok(contents) => Ok(contents
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(PathBuf::from)
+            .collect()),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(err) => Err(err),
+    }
+}
+
+/// Returns `true` if the given archive is pinned by an environment that still exists.
+fn is_pinned(archive: &Path) -> io::Result<bool> {
+    Ok(pin_holders(&pin_path(archive))?
+        .iter()
+        .any(|environment| environment.is_dir()))
+}
+
+/// Remove any pins in the archive bucket that are no longer held, i.e., pins for archives that
+/// no longer exist, and pins for which none of the recorded environments exist. Pins that are
+/// still held are rewritten to drop any environments that have since been removed.
+pub(crate) fn remove_stale_pins(bucket: &Path) -> io::Result<Removal> {
+    let mut summary = Removal::default();
+    if !bucket.is_dir() {
+        return Ok(summary);
+    }
+    for entry in fs::read_dir(bucket)? {
+        let pin = entry?.path();
+        if pin.extension().map_or(true, |extension| extension != "pin") {
+            continue;
+        }
+
+        let holders = pin_holders(&pin)?;
+        let live = holders
+            .iter()
+            .filter(|environment| environment.is_dir())
+            .collect::<Vec<_>>();
+        if live.is_empty() || !pin.with_extension("").is_dir() {
+            debug!("Removing stale pin: {}", pin.display());
+            summary += rm_rf(&pin)?;
+        } else if live.len() < holders.len() {
+            let mut contents = String::new();
+            for environment in live {
+                contents.push_str(&environment.to_string_lossy());
+                contents.push('\n');
+            }
+            fs::write(&pin, contents)?;
+        }
+    }
+    Ok(summary)
+}
+
+/// Read the entries in the archive bucket, including any compressed archives.
+///
+/// Archives are immutable once persisted, so the modification time of an archive directory
+/// reflects the last time it was installed (see [`uv_fs::touch`]).
+pub(crate) fn archives(bucket: &Path) -> io::Result<Vec<ArchiveEntry>> {
+    let mut archives = Vec::new();
+    if !bucket.is_dir() {
+        return Ok(archives);
+    }
+    for entry in fs::read_dir(bucket)? {
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+        if metadata.is_file() && compression::is_compressed(&entry.path()) {
+            archives.push(ArchiveEntry {
+                pinned: false,
+                path: entry.path(),
+                size: metadata.len(),
+                last_used: metadata.modified()?,
+            });
+            continue;
+        }
+        if !metadata.is_dir() {
+            continue;
+        }
+
+        let mut size = 0;
+        for entry in walkdir::WalkDir::new(entry.path()) {
+            let entry = entry?;
+            if entry.file_type().is_file() {
+                size += entry.metadata()?.len();
+            }
+        }
+
+        archives.push(ArchiveEntry {
+            pinned: is_pinned(&entry.path())?,
+            path: entry.path(),
+            size,
+            last_used: metadata.modified()?,
+        });
+    }
+    Ok(archives)
+}
+
+/// Remove any links into the archive bucket that no longer resolve, along with the archive
+/// pointers that accompany them (e.g., `foo-1.0.0-py3-none-any.http` alongside the
+/// `foo-1.0.0-py3-none-any` link), such that evicted archives are re-fetched on next use.
+pub(crate) fn remove_dangling_links(buckets: &[PathBuf]) -> io::Result<Removal> {
+    let mut summary = Removal::default();
+    for path in dangling_links(buckets)? {
+        debug!("Removing dangling cache entry: {}", path.display());
+        for extension in ["http", "rev"] {
+            let mut pointer = path.clone().into_os_string();
+            pointer.push(".");
+            pointer.push(extension);
+            summary += rm_rf(PathBuf::from(pointer))?;
+        }
+        if cfg!(windows) {
+            // On Windows, links into the archive bucket are junctions, which are removed as
+            // directories.
+            fs::remove_dir(&path)?;
+            summary.num_files += 1;
+        } else {
+            summary += rm_rf(path)?;
+        }
+    }
+    Ok(summary)
+}
+
+/// Find any links into the archive bucket that no longer resolve.
+///
+/// Links to compressed archives are retained, as the archive is restored on next use.
+pub(crate) fn dangling_links(buckets: &[PathBuf]) -> io::Result<Vec<PathBuf>> {
+    let mut dangling = Vec::new();
+    for bucket in buckets {
+        if !bucket.is_dir() {
+            continue;
+        }
+        for entry in walkdir::WalkDir::new(bucket) {
+            let entry = entry?;
+            if entry.file_type().is_symlink()
+                && entry.path().canonicalize().is_err()
+                && !fs::read_link(entry.path())
+                    .is_ok_and(|target| compression::compressed_path(&target).is_file())
+            {
+                dangling.push(entry.into_path());
+            }
+        }
+    }
+    Ok(dangling)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use fs_err as fs;
+
+    use crate::CacheBucket;
+
+    use super::{archives, pin_archive, pin_path, remove_stale_pins};
+
+    #[test]
+    fn pin_released_with_environment() -> io::Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let bucket = temp_dir.path().join(CacheBucket::Archive.to_str());
+        let archive = bucket.join("archive");
+        fs::create_dir_all(&archive)?;
+        let first = temp_dir.path().join("first");
+        let second = temp_dir.path().join("second");
+        fs::create_dir_all(&first)?;
+        fs::create_dir_all(&second)?;
+
+        pin_archive(&archive, &first)?;
+        pin_archive(&archive, &second)?;
+        pin_archive(&archive, &first)?;
+        let pin = pin_path(&fs::canonicalize(&archive)?);
+        assert_eq!(fs::read_to_string(&pin)?.lines().count(), 2);
+        assert!(archives(&bucket)?[0].pinned);
+
+        // The pin is held for as long as any of its environments exist.
+        fs::remove_dir_all(&first)?;
+        remove_stale_pins(&bucket)?;
+        assert_eq!(fs::read_to_string(&pin)?.lines().count(), 1);
+        assert!(archives(&bucket)?[0].pinned);
+
+        fs::remove_dir_all(&second)?;
+        assert!(!archives(&bucket)?[0].pinned);
+        remove_stale_pins(&bucket)?;
+        assert!(!pin.exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn pin_removed_with_archive() -> io::Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let bucket = temp_dir.path().join(CacheBucket::Archive.to_str());
+        let archive = bucket.join("archive");
+        fs::create_dir_all(&archive)?;
+
+        pin_archive(&archive, temp_dir.path())?;
+        let pin = pin_path(&fs::canonicalize(&archive)?);
+        assert!(pin.exists());
+
+        fs::remove_dir_all(&archive)?;
+        remove_stale_pins(&bucket)?;
+        assert!(!pin.exists());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,119 @@
+use std::io;
+use std::path::{Path, PathBuf};
+
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+
+use crate::{Cache, CacheBucket};
+
+/// The number of entries to report in [`CacheInfo::largest`].
+const MAX_LARGEST: usize = 10;
+
+/// A summary of the contents of the cache.
+#[derive(Debug, Serialize)]
+pub struct CacheInfo {
+    /// The size and number of files in each bucket.
+    pub buckets: Vec<BucketInfo>,
+    /// The largest entries in the archive bucket, from largest to smallest.
+    pub largest: Vec<EntryInfo>,
+}
+
+/// The size and number of files in a cache bucket.
+#[derive(Debug, Serialize)]
+pub struct BucketInfo {
+    /// The name of the bucket directory (e.g., `wheels-v1`).
+    pub name: &'static str,
+    /// The total size of the files in the bucket, in bytes.
+    pub size: u64,
+    /// The number of files in the bucket.
+    pub files: u64,
+}
+
+/// An unzipped wheel in the archive bucket.
+#[derive(Debug, Serialize)]
+pub struct EntryInfo {
+    /// The name of the wheel (e.g., `anyio-4.3.0-py3-none-any`), if the archive is still
+    /// referenced by the cache.
+    pub name: Option<String>,
+    /// The path to the archive.
+    pub path: PathBuf,
+    /// The total size of the files in the archive, in bytes.
+    pub size: u64,
+}
+
+impl CacheInfo {
+    /// Collect a summary of the contents of the given cache.
+    pub(crate) fn collect(cache: &Cache) -> io::Result<Self> {
+        let mut buckets = Vec::new();
+        for bucket in CacheBucket::iter() {
+            let (size, files) = size(&cache.bucket_root(bucket))?;
+            buckets.push(BucketInfo {
+                name: bucket.to_str(),
+                size,
+                files,
+            });
+        }
+
+        // Map each archive to the name of the wheel that references it.
+        let mut names = FxHashMap::default();
+        for bucket in CacheBucket::iter().filter(|bucket| *bucket != CacheBucket::Archive) {
+            let bucket = cache.bucket_root(bucket);
+            if !bucket.is_dir() {
+                continue;
+            }
+            for entry in walkdir::WalkDir::new(bucket) {
+                let entry = entry?;
+                if !entry.file_type().is_symlink() {
+                    continue;
+                }
+                if let Ok(target) = entry.path().canonicalize() {
+                    names.insert(target, entry.file_name().to_string_lossy().to_string());
+                }
+            }
+        }
+
+        let mut largest = Vec::new();
+        let archive = cache.bucket(CacheBucket::Archive);
+        if archive.is_dir() {
+            for entry in fs_err::read_dir(archive)? {
+                let path = entry?.path();
+                // Skip any pins alongside the archives.
+                if !path.is_dir() {
+                    continue;
+                }
+                let (size, _) = size(&path)?;
+                let name = path
+                    .canonicalize()
+                    .ok()
+                    .and_then(|path| names.get(&path).cloned());
+                largest.push(EntryInfo { name, path, size });
+            }
+        }
+        largest.sort_by(|a, b| b.size.cmp(&a.size));
+        largest.truncate(MAX_LARGEST);
+
+        Ok(Self { buckets, largest })
+    }
+
+    /// Return the total size of the cache, in bytes.
+    pub fn size(&self) -> u64 {
+        self.buckets.iter().map(|bucket| bucket.size).sum()
+    }
+}
+
+/// Return the total size and number of files within a directory.
+fn size(path: &Path) -> io::Result<(u64, u64)> {
+    let mut size = 0;
+    let mut files = 0;
+    if !path.exists() {
+        return Ok((size, files));
+    }
+    for entry in walkdir::WalkDir::new(path) {
+        let entry = entry?;
+        if entry.file_type().is_file() {
+            size += entry.metadata()?.len();
+            files += 1;
+        }
+    }
+    Ok((size, files))
+}
@@ -5,21 +5,30 @@ use std::io::Write;
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Duration;
 
 use fs_err as fs;
 use rustc_hash::FxHashSet;
 use tempfile::{tempdir, TempDir};
 use tracing::debug;
+use url::Url;
 
+use cache_key::CanonicalUrl;
 use distribution_types::InstalledDist;
 use pypi_types::Metadata23;
-use uv_fs::directories;
+use uv_fs::{directories, LockedFile};
 use uv_normalize::PackageName;
 
 pub use crate::by_timestamp::CachedByTimestamp;
 #[cfg(feature = "clap")]
 pub use crate::cli::CacheArgs;
+pub use crate::compression::{restore_link, Compression};
+pub use crate::dedupe::Deduplication;
+pub use crate::eviction::{pin_archive, CacheAge, CacheSize};
+pub use crate::info::{BucketInfo, CacheInfo, EntryInfo};
 use crate::removal::{rm_rf, Removal};
+pub use crate::scope::CacheScope;
+pub use crate::stats::{CacheStats, CommandStats};
 pub use crate::timestamp::Timestamp;
 pub use crate::wheel::WheelCache;
 use crate::wheel::WheelCacheKind;
@@ -29,7 +38,14 @@ mod archive;
 mod by_timestamp;
 #[cfg(feature = "clap")]
 mod cli;
+mod compression;
+mod dedupe;
+mod eviction;
+mod info;
+mod overlay;
 mod removal;
+mod scope;
+mod stats;
 mod timestamp;
 mod wheel;
 
@@ -110,13 +126,45 @@ impl Deref for CacheShard {
     }
 }
 
+/// The name of the file (within the cache root) in which hit/miss statistics are persisted.
+const STATS: &str = ".stats.msgpack";
+
+/// The name of the file (within the cache root) that's locked while the cache is in use.
+///
+/// Commands hold a shared lock for their duration, while eviction requires an exclusive lock,
+/// such that entries are never evicted from underneath another process.
+const LOCK: &str = ".lock";
+
+/// The name of the file (within the cache root) that records the process holding the exclusive
+/// lock on [`LOCK`], if any.
+const LOCK_OWNER: &str = ".lock.pid";
+
+/// The name of the directory (within each mutable cache bucket) in which project-scoped entries
+/// are stored, e.g., `built-wheels-v3/project/<digest(project_root)>`.
+const PROJECT: &str = "project";
+
 /// The main cache abstraction.
 #[derive(Debug, Clone)]
 pub struct Cache {
     /// The cache directory.
     root: PathBuf,
+    /// The read-only cache directory underlying `root`, if the cache is a writable overlay.
+    base: Option<PathBuf>,
+    /// The digest of the project to which mutable cache buckets are scoped, if any.
+    project: Option<String>,
     /// The refresh strategy to use when reading from the cache.
     refresh: Refresh,
+    /// The maximum size of the cache, beyond which the least-recently used entries are evicted.
+    max_size: Option<CacheSize>,
+    /// The URL of a remote cache to read built wheels from (and write built wheels to), if any.
+    remote: Option<Url>,
+    /// The secret key used to sign and verify entries in the remote cache.
+    remote_key: Option<String>,
+    /// The maximum time to wait for a lock held by another process, or `None` to wait
+    /// indefinitely.
+    lock_timeout: Option<Duration>,
+    /// Whether to share identical files across unzipped wheels via the blob store.
+    dedupe: bool,
     /// A temporary cache directory, if the user requested `--no-cache`.
     ///
     /// Included to ensure that the temporary directory exists for the length of the operation, but
@@ -126,10 +174,53 @@ pub struct Cache {
 
 impl Cache {
     /// A persistent cache directory at `root`.
+    ///
+    /// If `root` is read-only, a writable overlay in the system's temporary directory is used
+    /// instead (see [`Cache::from_path_with_overlay`]).
     pub fn from_path(root: impl Into<PathBuf>) -> Result<Self, io::Error> {
+        Self::from_path_with_overlay(root, None)
+    }
+
+    /// A persistent cache directory at `root`, backed by a writable overlay if `root` is
+    /// read-only (e.g., a cache baked into a container image).
+    ///
+    /// New entries are written to the overlay, while existing entries are read from `root`. If no
+    /// overlay directory is provided, one is created in the system's temporary directory.
+    pub fn from_path_with_overlay(
+        root: impl Into<PathBuf>,
+        overlay: Option<PathBuf>,
+    ) -> Result<Self, io::Error> {
+        let root = root.into();
+        let (root, base) = match Self::init(&root) {
+            Ok(root) => (root, None),
+            Err(err) if overlay::is_read_only(&err) && root.is_dir() => {
+                let base = fs::canonicalize(&root)?;
+                let (overlay, private) = match overlay {
+                    Some(overlay) => (overlay, false),
+                    None => (overlay::default_overlay(&base), true),
+                };
+                debug!(
+                    "Cache at {} is read-only; writing new entries to: {}",
+                    base.display(),
+                    overlay.display()
+                );
+                overlay::create(&overlay, private)?;
+                let overlay = Self::init(overlay)?;
+                overlay::seed(&base, &overlay)?;
+                (overlay, Some(base))
+            }
+            Err(err) => return Err(err),
+        };
         Ok(Self {
-            root: Self::init(root)?,
+            root,
+            base,
+            project: None,
             refresh: Refresh::None,
+            max_size: None,
+            remote: None,
+            remote_key: None,
+            lock_timeout: None,
+            dedupe: false,
             _temp_dir_drop: None,
         })
     }
@@ -139,7 +230,14 @@ impl Cache {
         let temp_dir = tempdir()?;
         Ok(Self {
             root: Self::init(temp_dir.path())?,
+            base: None,
+            project: None,
             refresh: Refresh::None,
+            max_size: None,
+            remote: None,
+            remote_key: None,
+            lock_timeout: None,
+            dedupe: false,
             _temp_dir_drop: Some(Arc::new(temp_dir)),
         })
     }
@@ -150,16 +248,124 @@ impl Cache {
         Self { refresh, ..self }
     }
 
+    /// Set the [`CacheScope`] for the cache, scoping mutable cache buckets to the project at the
+    /// given root if the scope is [`CacheScope::Project`].
+    pub fn with_scope(self, scope: CacheScope, project: &Path) -> Result<Self, io::Error> {
+        let project = match scope {
+            CacheScope::Global => return Ok(self),
+            CacheScope::Project => cache_key::digest(&project.to_path_buf()),
+        };
+        let cache = Self {
+            project: Some(project),
+            ..self
+        };
+
+        // Create the scoped build bucket, in which source distributions are unpacked and built.
+        // (The `.gitignore` and `.git` markers at the root of the build bucket apply to it, too.)
+        fs::create_dir_all(cache.bucket(CacheBucket::BuiltWheels))?;
+
+        Ok(cache)
+    }
+
+    /// Set the maximum size of the cache.
+    #[must_use]
+    pub fn with_max_size(self, max_size: Option<CacheSize>) -> Self {
+        Self { max_size, ..self }
+    }
+
+    /// Return the maximum size of the cache, if any.
+    pub fn max_size(&self) -> Option<CacheSize> {
+        self.max_size
+    }
+
+    /// Share identical files across unzipped wheels, by replacing each file with a hard link into
+    /// the blob store as wheels are persisted (see [`Cache::persist`]).
+    #[must_use]
+    pub fn with_dedupe(self, dedupe: bool) -> Self {
+        Self { dedupe, ..self }
+    }
+
+    /// Set the URL of the remote cache, along with the key used to sign its entries.
+    #[must_use]
+    pub fn with_remote(self, remote: Option<Url>, remote_key: Option<String>) -> Self {
+        Self {
+            remote,
+            remote_key,
+            ..self
+        }
+    }
+
+    /// Return the URL of the remote cache, if any.
+    pub fn remote(&self) -> Option<&Url> {
+        self.remote.as_ref()
+    }
+
+    /// Return the key used to sign and verify entries in the remote cache, if any.
+    pub fn remote_key(&self) -> Option<&str> {
+        self.remote_key.as_deref()
+    }
+
+    /// Set the maximum time to wait for a lock (e.g., on a cache entry) held by another process.
+    #[must_use]
+    pub fn with_lock_timeout(self, lock_timeout: Option<Duration>) -> Self {
+        Self {
+            lock_timeout,
+            ..self
+        }
+    }
+
+    /// Return the maximum time to wait for a lock held by another process, or `None` to wait
+    /// indefinitely.
+    pub fn lock_timeout(&self) -> Option<Duration> {
+        self.lock_timeout
+    }
+
     /// Return the root of the cache.
     pub fn root(&self) -> &Path {
         &self.root
     }
 
+    /// Return the read-only cache underlying the cache, if the cache is a writable overlay.
+    pub fn base(&self) -> Option<&Path> {
+        self.base.as_deref()
+    }
+
+    /// Return the [`Refresh`] policy for the cache.
+    pub fn refresh(&self) -> &Refresh {
+        &self.refresh
+    }
+
     /// The folder for a specific cache bucket
+    ///
+    /// If the cache is scoped to a project, mutable buckets resolve to the project's directory
+    /// within the bucket.
     pub fn bucket(&self, cache_bucket: CacheBucket) -> PathBuf {
+        match &self.project {
+            Some(project) if cache_bucket.is_mutable() => {
+                self.bucket_root(cache_bucket).join(PROJECT).join(project)
+            }
+            _ => self.bucket_root(cache_bucket),
+        }
+    }
+
+    /// The folder for a specific cache bucket, including the entries of every project scope.
+    fn bucket_root(&self, cache_bucket: CacheBucket) -> PathBuf {
         self.root.join(cache_bucket.to_str())
     }
 
+    /// The folders that hold the entries of a specific cache bucket: the bucket itself, along
+    /// with each project scope within a mutable bucket.
+    fn bucket_roots(&self, cache_bucket: CacheBucket) -> Vec<PathBuf> {
+        let root = self.bucket_root(cache_bucket);
+        let mut roots = if cache_bucket.is_mutable() {
+            directories(root.join(PROJECT)).collect()
+        } else {
+            Vec::new()
+        };
+        roots.push(root);
+        roots
+    }
+
     /// Compute an entry in the cache.
     pub fn shard(&self, cache_bucket: CacheBucket, dir: impl AsRef<Path>) -> CacheShard {
         CacheShard(self.bucket(cache_bucket).join(dir.as_ref()))
@@ -176,8 +382,26 @@ impl Cache {
     }
 
     /// Return the path to an archive in the cache.
+    ///
+    /// If the cache is an overlay, archives that only exist in the read-only cache are read from
+    /// there. If the archive was compressed (see [`Cache::compress`]), it's restored first.
     pub fn archive(&self, id: &ArchiveId) -> PathBuf {
-        self.bucket(CacheBucket::Archive).join(id)
+        let path = self.bucket(CacheBucket::Archive).join(id);
+        if let Err(err) = compression::restore(&path) {
+            debug!(
+                "Failed to restore compressed cache entry {}: {err}",
+                path.display()
+            );
+        }
+        if let Some(base) = &self.base {
+            if !path.exists() {
+                let base = base.join(CacheBucket::Archive.to_str()).join(id);
+                if base.exists() {
+                    return base;
+                }
+            }
+        }
+        path
     }
 
     /// Returns `true` if a cache entry must be revalidated given the [`Refresh`] policy.
@@ -185,7 +409,17 @@ impl Cache {
         match &self.refresh {
             Refresh::None => false,
             Refresh::All(_) => true,
-            Refresh::Packages(packages, _) => packages.contains(package),
+            Refresh::Selective(packages, _, _) => packages.contains(package),
+        }
+    }
+
+    /// Returns `true` if a cache entry for the artifact at the given URL must be revalidated given
+    /// the [`Refresh`] policy.
+    pub fn must_revalidate_url(&self, url: &Url) -> bool {
+        match &self.refresh {
+            Refresh::None => false,
+            Refresh::All(_) => true,
+            Refresh::Selective(_, urls, _) => urls.contains(&CanonicalUrl::new(url)),
         }
     }
 
@@ -199,8 +433,10 @@ impl Cache {
         let timestamp = match &self.refresh {
             Refresh::None => return Ok(Freshness::Fresh),
             Refresh::All(timestamp) => timestamp,
-            Refresh::Packages(packages, timestamp) => {
-                if package.map_or(true, |package| packages.contains(package)) {
+            Refresh::Selective(packages, _, timestamp) => {
+                // If the entry isn't linked to a package, refresh it conservatively, unless only
+                // URLs were selected.
+                if package.map_or(!packages.is_empty(), |package| packages.contains(package)) {
                     timestamp
                 } else {
                     return Ok(Freshness::Fresh);
@@ -208,9 +444,32 @@ impl Cache {
             }
         };
 
+        Self::freshness_since(entry, *timestamp)
+    }
+
+    /// Returns `true` if a cache entry for the artifact at the given URL is up-to-date given the
+    /// [`Refresh`] policy.
+    ///
+    /// Like [`Cache::freshness`], but also refreshes the entry if its URL was selected for refresh.
+    pub fn url_freshness(
+        &self,
+        entry: &CacheEntry,
+        package: Option<&PackageName>,
+        url: &Url,
+    ) -> io::Result<Freshness> {
+        if let Refresh::Selective(_, urls, timestamp) = &self.refresh {
+            if urls.contains(&CanonicalUrl::new(url)) {
+                return Self::freshness_since(entry, *timestamp);
+            }
+        }
+        self.freshness(entry, package)
+    }
+
+    /// Determine the freshness of a cache entry relative to the given cutoff timestamp.
+    fn freshness_since(entry: &CacheEntry, timestamp: Timestamp) -> io::Result<Freshness> {
         match fs::metadata(entry.path()) {
             Ok(metadata) => {
-                if Timestamp::from_metadata(&metadata) >= *timestamp {
+                if Timestamp::from_metadata(&metadata) >= timestamp {
                     Ok(Freshness::Fresh)
                 } else {
                     Ok(Freshness::Stale)
@@ -231,6 +490,26 @@ impl Cache {
         // TODO(charlie): Support content-addressed persistence via SHAs.
         let id = ArchiveId::new();
 
+        // If enabled, share any files that are already in the blob store. Blobs are only
+        // reclaimed on Unix, where their link count is available (see `remove_unused_blobs`).
+        if self.dedupe && cfg!(unix) {
+            let dir = temp_dir.as_ref().to_path_buf();
+            let blobs = self.bucket(CacheBucket::Blobs);
+            let root = self.root.clone();
+            let result = tokio::task::spawn_blocking(move || {
+                let temp_dir = tempfile::tempdir_in(root)?;
+                dedupe::link_blobs(&dir, &blobs, temp_dir.path())
+            })
+            .await?;
+            match result {
+                Ok(summary) => debug!(
+                    "Linked {} files ({} bytes) to the blob store",
+                    summary.num_files, summary.total_bytes
+                ),
+                Err(err) => debug!("Failed to link files to the blob store: {err}"),
+            }
+        }
+
         // Move the temporary directory into the directory store.
         let archive_entry = self.entry(CacheBucket::Archive, "", &id);
         fs_err::create_dir_all(archive_entry.dir())?;
@@ -247,6 +526,10 @@ impl Cache {
     fn init(root: impl Into<PathBuf>) -> Result<PathBuf, io::Error> {
         let root = root.into();
 
+        // On Windows, use an extended-length path, such that deeply nested cache entries (e.g.,
+        // unzipped wheels with long module paths) don't exceed `MAX_PATH`.
+        let root = uv_fs::verbatim_path(&uv_fs::absolutize_path(&root)?).into_owned();
+
         // Create the cache directory, if it doesn't exist.
         fs::create_dir_all(&root)?;
 
@@ -303,7 +586,9 @@ impl Cache {
     pub fn remove(&self, name: &PackageName) -> Result<Removal, io::Error> {
         let mut summary = Removal::default();
         for bucket in CacheBucket::iter() {
-            summary += bucket.remove(self, name)?;
+            for root in self.bucket_roots(bucket) {
+                summary += bucket.remove(&root, name)?;
+            }
         }
         Ok(summary)
     }
@@ -321,6 +606,10 @@ impl Cache {
             if entry.file_name() == "CACHEDIR.TAG"
                 || entry.file_name() == ".gitignore"
                 || entry.file_name() == ".git"
+                || entry.file_name() == STATS
+                || entry.file_name() == LOCK
+                || entry.file_name() == LOCK_OWNER
+                || entry.file_name() == overlay::OVERLAY
             {
                 continue;
             }
@@ -346,12 +635,17 @@ impl Cache {
         let mut references = FxHashSet::default();
 
         for bucket in CacheBucket::iter() {
-            let bucket = self.bucket(bucket);
+            let bucket = self.bucket_root(bucket);
             if bucket.is_dir() {
                 for entry in walkdir::WalkDir::new(bucket) {
                     let entry = entry?;
                     if entry.file_type().is_symlink() {
-                        references.insert(entry.path().canonicalize()?);
+                        // Links to compressed archives don't resolve until the archive is
+                        // restored, so fall back to the link target itself.
+                        match entry.path().canonicalize() {
+                            Ok(path) => references.insert(path),
+                            Err(_) => references.insert(fs::read_link(entry.path())?),
+                        };
                     }
                 }
             }
@@ -360,14 +654,230 @@ impl Cache {
         for entry in fs::read_dir(self.bucket(CacheBucket::Archive))? {
             let entry = entry?;
             let path = entry.path().canonicalize()?;
-            if !references.contains(&path) {
+            // Pins are removed below, once the archives they refer to are gone.
+            if path.extension().is_some_and(|extension| extension == "pin") {
+                continue;
+            }
+            // Compressed archives are retained for as long as the archive is referenced.
+            let archive = if compression::is_compressed(&path) {
+                path.with_extension("")
+            } else {
+                path.clone()
+            };
+            if !references.contains(&archive) {
                 debug!("Removing dangling cache entry: {}", path.display());
                 summary += rm_rf(path)?;
             }
         }
 
+        // Third, remove any pins that are no longer held, and any blobs that are no longer linked
+        // from an archive.
+        summary += eviction::remove_stale_pins(&self.bucket(CacheBucket::Archive))?;
+        summary += dedupe::remove_unused_blobs(&self.bucket(CacheBucket::Blobs))?;
+
         Ok(summary)
     }
+
+    /// Deduplicate the unzipped wheels in the cache, replacing identical files across archives
+    /// with hard links to a single copy.
+    ///
+    /// Archives are immutable once persisted, so sharing inodes between them is safe. Requires
+    /// exclusive access to the cache (see [`Cache::lock`]).
+    pub fn dedupe(&self) -> Result<Deduplication, io::Error> {
+        let archive = self.bucket(CacheBucket::Archive);
+        if !archive.is_dir() {
+            return Ok(Deduplication::default());
+        }
+
+        let _lock = self.lock()?;
+
+        // Stage the links at the top-level of the cache, such that any leftovers from an
+        // interrupted run are removed by `prune`.
+        let temp_dir = tempfile::tempdir_in(&self.root)?;
+        dedupe::dedupe(archive, temp_dir.path())
+    }
+
+    /// Compress any archives that haven't been used within the given age, such that they're
+    /// stored as a zstd-compressed `.zip` alongside the archive bucket until they're next used.
+    ///
+    /// Compressed archives are restored transparently on next use (see [`Cache::archive`]).
+    /// Archives that are pinned by an environment are never compressed, as the environment
+    /// references their files directly. Requires exclusive access to the cache (see
+    /// [`Cache::lock`]).
+    pub fn compress(&self, max_age: CacheAge) -> Result<Compression, io::Error> {
+        let _lock = self.lock()?;
+
+        let now = std::time::SystemTime::now();
+
+        let mut summary = Compression::default();
+        for archive in eviction::archives(&self.bucket(CacheBucket::Archive))? {
+            if archive.pinned || compression::is_compressed(&archive.path) {
+                continue;
+            }
+            let age = now.duration_since(archive.last_used).unwrap_or_default();
+            if age < max_age.duration() {
+                continue;
+            }
+            debug!("Compressing cache entry: {}", archive.path.display());
+            if let Some(total_bytes) = compression::compress(&archive.path)? {
+                summary.num_archives += 1;
+                summary.total_bytes += total_bytes;
+            }
+        }
+
+        Ok(summary)
+    }
+
+    /// Collect a summary of the contents of the cache.
+    pub fn info(&self) -> Result<CacheInfo, io::Error> {
+        CacheInfo::collect(self)
+    }
+
+    /// Read the hit/miss statistics for the most recent commands.
+    pub fn stats(&self) -> Result<CacheStats, io::Error> {
+        CacheStats::read(&self.root.join(STATS))
+    }
+
+    /// Record the number of distributions that were served from the cache (`hits`), and those
+    /// that had to be downloaded or built (`misses`), by the given command.
+    ///
+    /// Commands that didn't require any distributions aren't recorded. Statistics are
+    /// best-effort: any failure to record them is ignored.
+    pub fn record_stats(&self, command: &str, hits: usize, misses: usize) {
+        if hits == 0 && misses == 0 {
+            return;
+        }
+        if let Err(err) =
+            CacheStats::record(&self.root.join(STATS), command, hits as u64, misses as u64)
+        {
+            debug!("Failed to record cache statistics: {err}");
+        }
+    }
+
+    /// Acquire a shared lock on the cache, to be held while the cache is in use, such that no
+    /// entries are evicted by another process in the meantime.
+    pub fn lock_shared(&self) -> Result<LockedFile, io::Error> {
+        LockedFile::acquire_shared(self.root.join(LOCK), self.root.display())
+    }
+
+    /// Acquire an exclusive lock on the cache, waiting for any other processes that are using the
+    /// cache (see [`Cache::lock_shared`]) to finish.
+    pub fn lock(&self) -> Result<LockedFile, io::Error> {
+        LockedFile::acquire(self.root.join(LOCK), self.root.display(), self.lock_timeout)
+    }
+
+    /// Evict the least-recently used archives from the cache, until the unzipped wheels that can
+    /// be evicted fit within the given size.
+    ///
+    /// Only the archive bucket is subject to eviction: the other buckets (e.g., downloaded
+    /// metadata and built wheels) are small by comparison, and are excluded from the size, as are
+    /// any archives that are pinned by an environment (see [`pin_archive`]), which are never
+    /// evicted. Eviction is skipped entirely if the cache is in use by another process (see
+    /// [`Cache::lock_shared`]).
+    pub fn evict(&self, max_size: CacheSize) -> Result<Removal, io::Error> {
+        let Some(_lock) = LockedFile::try_acquire(self.root.join(LOCK))? else {
+            debug!("Skipping cache eviction, as the cache is in use by another process");
+            return Ok(Removal::default());
+        };
+
+        let mut archives = eviction::archives(&self.bucket(CacheBucket::Archive))?;
+        // Pinned archives can't be evicted, so they don't count towards the limit.
+        archives.retain(|archive| !archive.pinned);
+
+        let mut summary = Removal::default();
+        let mut total = archives.iter().map(|archive| archive.size).sum::<u64>();
+        if total > max_size.bytes() {
+            // Evict the least-recently used archives first.
+            archives.sort_by_key(|archive| archive.last_used);
+
+            for archive in archives {
+                if total <= max_size.bytes() {
+                    break;
+                }
+                debug!("Evicting cache entry: {}", archive.path.display());
+                summary += rm_rf(&archive.path)?;
+                total = total.saturating_sub(archive.size);
+            }
+        }
+        summary += self.remove_dangling_entries()?;
+
+        Ok(summary)
+    }
+
+    /// Evict any archives that haven't been used within the given age.
+    ///
+    /// As with [`Cache::evict`], archives that are pinned by an environment are never evicted.
+    pub fn evict_older_than(&self, max_age: CacheAge) -> Result<Removal, io::Error> {
+        let now = std::time::SystemTime::now();
+
+        let mut summary = Removal::default();
+        for archive in eviction::archives(&self.bucket(CacheBucket::Archive))? {
+            let age = now.duration_since(archive.last_used).unwrap_or_default();
+            if age >= max_age.duration() && !archive.pinned {
+                debug!("Evicting cache entry: {}", archive.path.display());
+                summary += rm_rf(&archive.path)?;
+            }
+        }
+        summary += self.remove_dangling_entries()?;
+
+        Ok(summary)
+    }
+
+    /// Return the paths to the unzipped wheels in the archive bucket.
+    pub fn archives(&self) -> Result<Vec<PathBuf>, io::Error> {
+        let bucket = self.bucket(CacheBucket::Archive);
+        if !bucket.is_dir() {
+            return Ok(Vec::new());
+        }
+        let mut archives = Vec::new();
+        for entry in fs::read_dir(bucket)? {
+            let entry = entry?;
+            if entry.metadata()?.is_dir() {
+                archives.push(entry.path());
+            }
+        }
+        archives.sort();
+        Ok(archives)
+    }
+
+    /// Return any links to archives that no longer exist (e.g., due to an archive having been
+    /// removed from the cache out-of-band).
+    pub fn dangling_links(&self) -> Result<Vec<PathBuf>, io::Error> {
+        eviction::dangling_links(&self.link_buckets())
+    }
+
+    /// Remove the given archives from the cache, along with any links to them and their archive
+    /// pointers, such that they're re-fetched on next use.
+    ///
+    /// Waits for any other processes that are using the cache to finish (see [`Cache::lock`]).
+    pub fn remove_archives(&self, archives: &[PathBuf]) -> Result<Removal, io::Error> {
+        let _lock = self.lock()?;
+
+        let mut summary = Removal::default();
+        for archive in archives {
+            debug!("Removing cache entry: {}", archive.display());
+            summary += rm_rf(archive)?;
+        }
+        summary += self.remove_dangling_entries()?;
+        Ok(summary)
+    }
+
+    /// Remove any links to archives that no longer exist, along with their archive pointers, and
+    /// any pins that are no longer held by an environment.
+    fn remove_dangling_entries(&self) -> Result<Removal, io::Error> {
+        let mut summary = eviction::remove_dangling_links(&self.link_buckets())?;
+        summary += eviction::remove_stale_pins(&self.bucket(CacheBucket::Archive))?;
+        summary += dedupe::remove_unused_blobs(&self.bucket(CacheBucket::Blobs))?;
+        Ok(summary)
+    }
+
+    /// Return the buckets that may contain links into the archive bucket.
+    fn link_buckets(&self) -> Vec<PathBuf> {
+        CacheBucket::iter()
+            .filter(|bucket| !matches!(bucket, CacheBucket::Archive | CacheBucket::Blobs))
+            .map(|bucket| self.bucket_root(bucket))
+            .collect()
+    }
 }
 
 /// The different kinds of data in the cache are stored in different bucket, which in our case
@@ -602,6 +1112,32 @@ pub enum CacheBucket {
     /// that cache entries can be atomically replaced and removed, as storing directories in the
     /// other buckets directly would make atomic operations impossible.
     Archive,
+    /// The output of `pip compile`, indexed by a digest of the inputs to the resolution, such that
+    /// an unchanged set of inputs can reuse the previous resolution.
+    ///
+    /// Cache structure:
+    ///  * `resolutions-v0/<digest(inputs)>.msgpack`
+    Resolutions,
+    /// Distribution files downloaded by `uv serve-index`, stored as-is, such that they can be
+    /// served to other clients.
+    ///
+    /// Cache structure:
+    ///  * `files-v0/pypi/foo/foo-1.0.0-py3-none-any.whl`
+    ///  * `files-v0/index/<digest(index-url)>/foo/foo-1.0.0-py3-none-any.whl`
+    Files,
+    /// Files shared across the unzipped wheels in [`CacheBucket::Archive`], indexed by the
+    /// SHA-256 digest of their contents and their permissions. Archive files are hard links to
+    /// these blobs, such that identical files across archives are stored once.
+    ///
+    /// Cache structure:
+    ///  * `blobs-v0/ab/<sha256(contents)>-<mode>`
+    Blobs,
+    /// The release notes fetched for `pip compile --summary --release-notes`, indexed by package
+    /// and version.
+    ///
+    /// Cache structure:
+    ///  * `release-notes-v0/<package_name>/<version>.json`
+    ReleaseNotes,
 }
 
 impl CacheBucket {
@@ -614,13 +1150,23 @@ impl CacheBucket {
             Self::Simple => "simple-v7",
             Self::Wheels => "wheels-v1",
             Self::Archive => "archive-v0",
+            Self::Resolutions => "resolutions-v0",
+            Self::Files => "files-v0",
+            Self::Blobs => "blobs-v0",
+            Self::ReleaseNotes => "release-notes-v0",
         }
     }
 
-    /// Remove a package from the cache bucket.
+    /// Returns `true` if the bucket holds mutable entries, which are namespaced by project when
+    /// the cache is scoped to a project (see [`CacheScope::Project`]).
+    fn is_mutable(self) -> bool {
+        matches!(self, Self::BuiltWheels | Self::Git | Self::Resolutions)
+    }
+
+    /// Remove a package from the cache bucket at the given path.
     ///
     /// Returns the number of entries removed from the cache.
-    fn remove(self, cache: &Cache, name: &PackageName) -> Result<Removal, io::Error> {
+    fn remove(self, bucket: &Path, name: &PackageName) -> Result<Removal, io::Error> {
         /// Returns `true` if the [`Path`] represents a built wheel for the given package.
         fn is_match(path: &Path, name: &PackageName) -> bool {
             let Ok(metadata) = fs_err::read(path.join("metadata.msgpack")) else {
@@ -636,31 +1182,31 @@ impl CacheBucket {
         match self {
             Self::Wheels => {
                 // For `pypi` wheels, we expect a directory per package (indexed by name).
-                let root = cache.bucket(self).join(WheelCacheKind::Pypi);
+                let root = bucket.join(WheelCacheKind::Pypi);
                 summary += rm_rf(root.join(name.to_string()))?;
 
                 // For alternate indices, we expect a directory for every index, followed by a
                 // directory per package (indexed by name).
-                let root = cache.bucket(self).join(WheelCacheKind::Index);
+                let root = bucket.join(WheelCacheKind::Index);
                 for directory in directories(root) {
                     summary += rm_rf(directory.join(name.to_string()))?;
                 }
 
                 // For direct URLs, we expect a directory for every URL, followed by a
                 // directory per package (indexed by name).
-                let root = cache.bucket(self).join(WheelCacheKind::Url);
+                let root = bucket.join(WheelCacheKind::Url);
                 for directory in directories(root) {
                     summary += rm_rf(directory.join(name.to_string()))?;
                 }
             }
             Self::BuiltWheels => {
                 // For `pypi` wheels, we expect a directory per package (indexed by name).
-                let root = cache.bucket(self).join(WheelCacheKind::Pypi);
+                let root = bucket.join(WheelCacheKind::Pypi);
                 summary += rm_rf(root.join(name.to_string()))?;
 
                 // For alternate indices, we expect a directory for every index, followed by a
                 // directory per package (indexed by name).
-                let root = cache.bucket(self).join(WheelCacheKind::Index);
+                let root = bucket.join(WheelCacheKind::Index);
                 for directory in directories(root) {
                     summary += rm_rf(directory.join(name.to_string()))?;
                 }
@@ -668,7 +1214,7 @@ impl CacheBucket {
                 // For direct URLs, we expect a directory for every URL, followed by a
                 // directory per version. To determine whether the URL is relevant, we need to
                 // search for a wheel matching the package name.
-                let root = cache.bucket(self).join(WheelCacheKind::Url);
+                let root = bucket.join(WheelCacheKind::Url);
                 for url in directories(root) {
                     if directories(&url).any(|version| is_match(&version, name)) {
                         summary += rm_rf(url)?;
@@ -678,7 +1224,7 @@ impl CacheBucket {
                 // For local dependencies, we expect a directory for every path, followed by a
                 // directory per version. To determine whether the path is relevant, we need to
                 // search for a wheel matching the package name.
-                let root = cache.bucket(self).join(WheelCacheKind::Path);
+                let root = bucket.join(WheelCacheKind::Path);
                 for path in directories(root) {
                     if directories(&path).any(|version| is_match(&version, name)) {
                         summary += rm_rf(path)?;
@@ -688,7 +1234,7 @@ impl CacheBucket {
                 // For Git dependencies, we expect a directory for every repository, followed by a
                 // directory for every SHA. To determine whether the SHA is relevant, we need to
                 // search for a wheel matching the package name.
-                let root = cache.bucket(self).join(WheelCacheKind::Git);
+                let root = bucket.join(WheelCacheKind::Git);
                 for repository in directories(root) {
                     for sha in directories(repository) {
                         if is_match(&sha, name) {
@@ -699,12 +1245,12 @@ impl CacheBucket {
             }
             Self::Simple => {
                 // For `pypi` wheels, we expect a rkyv file per package, indexed by name.
-                let root = cache.bucket(self).join(WheelCacheKind::Pypi);
+                let root = bucket.join(WheelCacheKind::Pypi);
                 summary += rm_rf(root.join(format!("{name}.rkyv")))?;
 
                 // For alternate indices, we expect a directory for every index, followed by a
                 // MsgPack file per package, indexed by name.
-                let root = cache.bucket(self).join(WheelCacheKind::Url);
+                let root = bucket.join(WheelCacheKind::Url);
                 for directory in directories(root) {
                     summary += rm_rf(directory.join(format!("{name}.rkyv")))?;
                 }
@@ -712,8 +1258,7 @@ impl CacheBucket {
             Self::FlatIndex => {
                 // We can't know if the flat index includes a package, so we just remove the entire
                 // cache entry.
-                let root = cache.bucket(self);
-                summary += rm_rf(root)?;
+                summary += rm_rf(bucket)?;
             }
             Self::Git => {
                 // Nothing to do.
@@ -721,9 +1266,30 @@ impl CacheBucket {
             Self::Interpreter => {
                 // Nothing to do.
             }
-            Self::Archive => {
+            Self::Archive | Self::Blobs => {
                 // Nothing to do.
             }
+            Self::Resolutions => {
+                // We can't know which resolutions include a package, so we just remove all of
+                // them.
+                summary += rm_rf(bucket)?;
+            }
+            Self::ReleaseNotes => {
+                // We expect a directory per package (indexed by name).
+                summary += rm_rf(bucket.join(name.to_string()))?;
+            }
+            Self::Files => {
+                // For `pypi` files, we expect a directory per package (indexed by name).
+                let root = bucket.join(WheelCacheKind::Pypi);
+                summary += rm_rf(root.join(name.to_string()))?;
+
+                // For alternate indices, we expect a directory for every index, followed by a
+                // directory per package (indexed by name).
+                let root = bucket.join(WheelCacheKind::Index);
+                for directory in directories(root) {
+                    summary += rm_rf(directory.join(name.to_string()))?;
+                }
+            }
         }
         Ok(summary)
     }
@@ -738,6 +1304,10 @@ impl CacheBucket {
             CacheBucket::Interpreter,
             CacheBucket::Simple,
             CacheBucket::Archive,
+            CacheBucket::Resolutions,
+            CacheBucket::Files,
+            CacheBucket::Blobs,
+            CacheBucket::ReleaseNotes,
         ]
         .iter()
         .copied()
@@ -890,19 +1460,28 @@ impl Freshness {
 pub enum Refresh {
     /// Don't refresh any entries.
     None,
-    /// Refresh entries linked to the given packages, if created before the given timestamp.
-    Packages(Vec<PackageName>, Timestamp),
+    /// Refresh entries linked to the given packages or artifact URLs, if created before the given
+    /// timestamp.
+    Selective(Vec<PackageName>, Vec<CanonicalUrl>, Timestamp),
     /// Refresh all entries created before the given timestamp.
     All(Timestamp),
 }
 
 impl Refresh {
     /// Determine the refresh strategy to use based on the command-line arguments.
-    pub fn from_args(refresh: bool, refresh_package: Vec<PackageName>) -> Self {
+    pub fn from_args(
+        refresh: bool,
+        refresh_package: Vec<PackageName>,
+        refresh_url: Vec<Url>,
+    ) -> Self {
         if refresh {
             Self::All(Timestamp::now())
-        } else if !refresh_package.is_empty() {
-            Self::Packages(refresh_package, Timestamp::now())
+        } else if !refresh_package.is_empty() || !refresh_url.is_empty() {
+            Self::Selective(
+                refresh_package,
+                refresh_url.iter().map(CanonicalUrl::new).collect(),
+                Timestamp::now(),
+            )
         } else {
             Self::None
         }
@@ -0,0 +1,373 @@
+use std::io;
+use std::path::{Path, PathBuf};
+
+use fs_err as fs;
+use tracing::debug;
+
+use crate::CacheBucket;
+
+/// The name of the marker file (within an overlay's root) that records the read-only cache from
+/// which the overlay was seeded.
+pub(crate) const OVERLAY: &str = ".overlay";
+
+/// Returns `true` if the error indicates that the cache can't be written to, e.g., because it's
+/// baked into a container image, or owned by another user.
+pub(crate) fn is_read_only(err: &io::Error) -> bool {
+    // `EROFS` on both Linux and macOS.
+    err.kind() == io::ErrorKind::PermissionDenied || (cfg!(unix) && err.raw_os_error() == Some(30))
+}
+
+/// Return the default overlay location for a read-only cache at `base`, within the system's
+/// temporary directory.
+///
+/// The temporary directory is typically shared across users on Unix, so the location includes the
+/// current user's ID. Regardless, another user could create the directory first, so it must be
+/// created (and verified) with [`create`] before use.
+pub(crate) fn default_overlay(base: &Path) -> PathBuf {
+    let digest = cache_key::digest(&base.to_path_buf());
+    #[cfg(unix)]
+    {
+        // SAFETY: `geteuid` is always successful.
+        let uid = unsafe { libc::geteuid() };
+        std::env::temp_dir().join(format!("uv-cache-overlay-{uid}-{digest}"))
+    }
+    #[cfg(not(unix))]
+    {
+        std::env::temp_dir().join(format!("uv-cache-overlay-{digest}"))
+    }
+}
+
+/// Create the overlay directory at `path`, if it doesn't exist.
+///
+/// On Unix, an existing overlay is refused unless it's a directory owned by the current user, as
+/// its contents are trusted as cache entries. If `private` is set (as for the default overlay,
+/// within the shared temporary directory), the overlay is created with mode `0700`, and an
+/// existing overlay is refused if it's accessible to any other user.
+pub(crate) fn create(path: &Path, private: bool) -> io::Result<()> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{DirBuilderExt, MetadataExt};
+
+        let mut builder = std::fs::DirBuilder::new();
+        builder.recursive(true);
+        if private {
+            builder.mode(0o700);
+        }
+        builder.create(path)?;
+
+        let metadata = fs::symlink_metadata(path)?;
+        if !metadata.is_dir() {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("Cache overlay is not a directory: {}", path.display()),
+            ));
+        }
+        // SAFETY: `geteuid` is always successful.
+        let uid = unsafe { libc::geteuid() };
+        if metadata.uid() != uid {
+            return Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                format!(
+                    "Cache overlay is owned by another user (uid {}): {}",
+                    metadata.uid(),
+                    path.display()
+                ),
+            ));
+        }
+        if private && metadata.mode() & 0o077 != 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                format!(
+                    "Cache overlay is accessible to other users (mode {:o}): {}",
+                    metadata.mode() & 0o777,
+                    path.display()
+                ),
+            ));
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = private;
+        fs::create_dir_all(path)?;
+    }
+    Ok(())
+}
+
+/// Seed an overlay with the contents of the read-only cache at `base`, refreshing it if the
+/// read-only cache has changed since the overlay was last seeded (e.g., because the container
+/// image it's baked into was rebuilt).
+///
+/// The archive bucket, which holds the bulk of the cache, isn't copied: links into it (and
+/// archive lookups) resolve to the read-only cache directly, since archives are immutable. The
+/// remaining entries are small pointers and metadata, which are copied, such that they can be
+/// refreshed and replaced within the overlay. On refresh, entries are only replaced if the
+/// read-only cache's copy is newer, such that any entries refreshed within the overlay are
+/// retained.
+pub(crate) fn seed(base: &Path, overlay: &Path) -> io::Result<()> {
+    let marker = overlay.join(OVERLAY);
+    let fingerprint = fingerprint(base)?;
+    match fs::read_to_string(&marker) {
+        Ok(seeded) if seeded == fingerprint => return Ok(()),
+        Ok(_) => debug!(
+            "Refreshing cache overlay at {} from: {}",
+            overlay.display(),
+            base.display()
+        ),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => debug!(
+            "Seeding cache overlay at {} from: {}",
+            overlay.display(),
+            base.display()
+        ),
+        Err(err) => return Err(err),
+    }
+
+    let archive = base.join(CacheBucket::Archive.to_str());
+    let walker = walkdir::WalkDir::new(base)
+        .min_depth(1)
+        .into_iter()
+        .filter_entry(|entry| entry.path() != archive);
+    for entry in walker {
+        let entry = entry?;
+        let relative = entry
+            .path()
+            .strip_prefix(base)
+            .expect("walkdir starts with root");
+        let target = overlay.join(relative);
+
+        if entry.file_type().is_dir() {
+            fs::create_dir_all(&target)?;
+            continue;
+        }
+
+        // Skip any entries that are at least as new in the overlay (e.g., the markers added on
+        // initialization, or entries that were refreshed within the overlay).
+        if let Ok(existing) = target.symlink_metadata() {
+            if existing.modified()? >= entry.metadata()?.modified()? {
+                continue;
+            }
+        }
+
+        if entry.file_type().is_symlink() {
+            // Links point into the read-only archive bucket, so relative links are made absolute.
+            let link = fs::read_link(entry.path())?;
+            let link = entry
+                .path()
+                .parent()
+                .expect("walkdir entries have a parent")
+                .join(link);
+            uv_fs::replace_symlink(link, &target)?;
+        } else {
+            // Write atomically, as the overlay may be in use by another process.
+            uv_fs::write_atomic_sync(&target, fs::read(entry.path())?)?;
+        }
+    }
+
+    uv_fs::write_atomic_sync(marker, fingerprint)?;
+
+    Ok(())
+}
+
+/// Compute a fingerprint of the read-only cache at `base`, to detect when it changes.
+///
+/// The fingerprint covers the path, size, and modification time of every entry within the cache
+/// buckets. The contents of the archive bucket are excluded, as archives are immutable: the
+/// modification time of the bucket itself reflects any archives that were added or removed.
+fn fingerprint(base: &Path) -> io::Result<String> {
+    let archive = base.join(CacheBucket::Archive.to_str());
+    let walker = walkdir::WalkDir::new(base)
+        .min_depth(1)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|entry| {
+            // Skip any top-level files (e.g., the lock file), which aren't cache entries.
+            (entry.depth() > 1 || entry.file_type().is_dir())
+                && entry.path().parent() != Some(archive.as_path())
+        });
+
+    let mut entries = Vec::new();
+    for entry in walker {
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+        let modified = metadata
+            .modified()?
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        let relative = entry
+            .path()
+            .strip_prefix(base)
+            .expect("walkdir starts with root")
+            .to_path_buf();
+        entries.push((relative, metadata.len(), modified));
+    }
+
+    Ok(cache_key::digest(&entries))
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use std::io;
+    use std::os::unix::fs::PermissionsExt;
+    use std::path::Path;
+
+    use fs_err as fs;
+
+    use crate::{Cache, CacheBucket};
+
+    /// Set the permissions of every file and directory within `root`.
+    fn chmod_all(root: &Path, dir: u32, file: u32) -> io::Result<()> {
+        for entry in walkdir::WalkDir::new(root) {
+            let entry = entry?;
+            let mode = if entry.file_type().is_dir() {
+                dir
+            } else {
+                file
+            };
+            if !entry.file_type().is_symlink() {
+                fs::set_permissions(entry.path(), std::fs::Permissions::from_mode(mode))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if the current user bypasses file permissions (e.g., `root`).
+    fn is_superuser() -> bool {
+        // SAFETY: `geteuid` is always successful.
+        unsafe { libc::geteuid() == 0 }
+    }
+
+    #[test]
+    fn read_only_fallback() -> io::Result<()> {
+        if is_superuser() {
+            return Ok(());
+        }
+
+        let temp_dir = tempfile::tempdir()?;
+        let base = temp_dir.path().join("cache");
+        let overlay = temp_dir.path().join("overlay");
+
+        // Populate a cache, then make it read-only.
+        let cache = Cache::from_path(&base)?;
+        assert!(cache.base().is_none());
+        let entry = cache.entry(CacheBucket::Simple, "pypi", "foo.rkyv");
+        fs::create_dir_all(entry.dir())?;
+        fs::write(entry.path(), "foo")?;
+        let archive = cache.bucket(CacheBucket::Archive).join("archive");
+        fs::create_dir_all(&archive)?;
+        chmod_all(&base, 0o555, 0o444)?;
+
+        let result = Cache::from_path_with_overlay(&base, Some(overlay.clone()));
+        chmod_all(&base, 0o755, 0o644)?;
+        let cache = result?;
+
+        // New entries are written to the overlay, which is seeded from the read-only cache.
+        assert_eq!(cache.root(), fs::canonicalize(&overlay)?);
+        assert_eq!(cache.base(), Some(fs::canonicalize(&base)?.as_path()));
+        let seeded = cache.entry(CacheBucket::Simple, "pypi", "foo.rkyv");
+        assert_eq!(fs::read_to_string(seeded.path())?, "foo");
+
+        // Archives aren't copied, but are read from the read-only cache.
+        assert!(!cache.bucket(CacheBucket::Archive).join("archive").exists());
+
+        // Re-opening the cache reuses the seeded overlay, rather than seeding it again.
+        fs::remove_file(seeded.path())?;
+        fs::write(seeded.path(), "bar")?;
+        chmod_all(&base, 0o555, 0o444)?;
+        let result = Cache::from_path_with_overlay(&base, Some(overlay.clone()));
+        chmod_all(&base, 0o755, 0o644)?;
+        let cache = result?;
+        let seeded = cache.entry(CacheBucket::Simple, "pypi", "foo.rkyv");
+        assert_eq!(fs::read_to_string(seeded.path())?, "bar");
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_only_refresh() -> io::Result<()> {
+        if is_superuser() {
+            return Ok(());
+        }
+
+        let temp_dir = tempfile::tempdir()?;
+        let base = temp_dir.path().join("cache");
+        let overlay = temp_dir.path().join("overlay");
+
+        let cache = Cache::from_path(&base)?;
+        let entry = cache.entry(CacheBucket::Simple, "pypi", "foo.rkyv");
+        fs::create_dir_all(entry.dir())?;
+        fs::write(entry.path(), "foo")?;
+        let other = cache.entry(CacheBucket::Simple, "pypi", "bar.rkyv");
+        fs::write(other.path(), "bar")?;
+        chmod_all(&base, 0o555, 0o444)?;
+
+        let result = Cache::from_path_with_overlay(&base, Some(overlay.clone()));
+        chmod_all(&base, 0o755, 0o644)?;
+        let cache = result?;
+
+        // Add an entry to the overlay, and update an entry within the overlay.
+        let added = cache.entry(CacheBucket::Simple, "pypi", "baz.rkyv");
+        fs::write(added.path(), "baz")?;
+        let refreshed = cache.entry(CacheBucket::Simple, "pypi", "bar.rkyv");
+        fs::remove_file(refreshed.path())?;
+        fs::write(refreshed.path(), "bar (refreshed)")?;
+
+        // Update an entry within the read-only cache, e.g., as if its image were rebuilt. (The
+        // modification time is set explicitly, as file timestamps are coarse.)
+        fs::write(entry.path(), "foo (updated)")?;
+        let file = fs::OpenOptions::new().write(true).open(entry.path())?;
+        file.file()
+            .set_modified(std::time::SystemTime::now() + std::time::Duration::from_secs(60))?;
+        drop(file);
+        chmod_all(&base, 0o555, 0o444)?;
+
+        // Re-opening the cache refreshes the overlay, retaining the overlay's own entries.
+        let result = Cache::from_path_with_overlay(&base, Some(overlay.clone()));
+        chmod_all(&base, 0o755, 0o644)?;
+        let cache = result?;
+        let updated = cache.entry(CacheBucket::Simple, "pypi", "foo.rkyv");
+        assert_eq!(fs::read_to_string(updated.path())?, "foo (updated)");
+        assert_eq!(fs::read_to_string(added.path())?, "baz");
+        assert_eq!(fs::read_to_string(refreshed.path())?, "bar (refreshed)");
+
+        Ok(())
+    }
+
+    #[test]
+    fn default_overlay_is_per_user() {
+        let overlay = super::default_overlay(Path::new("/cache"));
+        // SAFETY: `geteuid` is always successful.
+        let uid = unsafe { libc::geteuid() };
+        assert!(overlay
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .starts_with(&format!("uv-cache-overlay-{uid}-")));
+    }
+
+    #[test]
+    fn private_overlay() -> io::Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+
+        // A new overlay is only accessible to the current user.
+        let overlay = temp_dir.path().join("private");
+        super::create(&overlay, true)?;
+        assert_eq!(fs::metadata(&overlay)?.permissions().mode() & 0o777, 0o700);
+
+        // An existing overlay that's accessible to other users is refused.
+        let shared = temp_dir.path().join("shared");
+        fs::create_dir(&shared)?;
+        fs::set_permissions(&shared, std::fs::Permissions::from_mode(0o777))?;
+        let err = super::create(&shared, true).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+
+        // Unless the overlay was provided explicitly.
+        super::create(&shared, false)?;
+
+        // An overlay that isn't a directory is refused.
+        let file = temp_dir.path().join("file");
+        fs::write(&file, "")?;
+        assert!(super::create(&file, true).is_err());
+
+        Ok(())
+    }
+}
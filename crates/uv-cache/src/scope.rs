@@ -0,0 +1,13 @@
+/// The scope at which mutable cache entries are shared.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[serde(rename_all = "kebab-case")]
+pub enum CacheScope {
+    /// Share all cache entries across projects.
+    #[default]
+    Global,
+    /// Share immutable entries (like downloaded wheels) across projects, but namespace mutable
+    /// entries (like wheels built from source distributions, Git checkouts, and resolutions) by
+    /// project, such that projects sharing a cache can't affect one another's builds.
+    Project,
+}
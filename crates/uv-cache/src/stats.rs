@@ -0,0 +1,71 @@
+use std::collections::VecDeque;
+use std::io;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use fs_err as fs;
+use serde::{Deserialize, Serialize};
+
+/// The number of commands for which hit/miss statistics are retained.
+const MAX_COMMANDS: usize = 100;
+
+/// The cache hit/miss statistics for the most recent commands, persisted in the cache root.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CacheStats {
+    /// The statistics for each command, from oldest to newest.
+    pub commands: VecDeque<CommandStats>,
+}
+
+/// The cache hit/miss statistics for a single command.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommandStats {
+    /// The time at which the command completed, in seconds since the Unix epoch.
+    pub timestamp: u64,
+    /// The name of the command (e.g., `pip sync`).
+    pub command: String,
+    /// The number of distributions that were served from the cache.
+    pub hits: u64,
+    /// The number of distributions that had to be downloaded or built.
+    pub misses: u64,
+}
+
+impl CacheStats {
+    /// Read the statistics from the given path, returning an empty set if none exist.
+    pub(crate) fn read(path: &Path) -> io::Result<Self> {
+        match fs::read(path) {
+            Ok(data) => Ok(rmp_serde::from_slice(&data).unwrap_or_default()),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Append the statistics for a command, and persist them to the given path.
+    pub(crate) fn record(path: &Path, command: &str, hits: u64, misses: u64) -> io::Result<()> {
+        let mut stats = Self::read(path)?;
+        if stats.commands.len() >= MAX_COMMANDS {
+            stats.commands.pop_front();
+        }
+        stats.commands.push_back(CommandStats {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|duration| duration.as_secs())
+                .unwrap_or_default(),
+            command: command.to_string(),
+            hits,
+            misses,
+        });
+        let data = rmp_serde::to_vec(&stats)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        uv_fs::write_atomic_sync(path, data)
+    }
+
+    /// Return the total number of cache hits.
+    pub fn hits(&self) -> u64 {
+        self.commands.iter().map(|command| command.hits).sum()
+    }
+
+    /// Return the total number of cache misses.
+    pub fn misses(&self) -> u64 {
+        self.commands.iter().map(|command| command.misses).sum()
+    }
+}
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 
+use cache_key::{CacheKey, CacheKeyHasher};
+
 /// A timestamp used to measure changes to a file.
 ///
 /// On Unix, this uses `ctime` as a conservative approach. `ctime` should detect all
@@ -44,3 +46,13 @@ impl Timestamp {
         Self(std::time::SystemTime::now())
     }
 }
+
+impl CacheKey for Timestamp {
+    fn cache_key(&self, state: &mut CacheKeyHasher) {
+        self.0
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos()
+            .cache_key(state);
+    }
+}
@@ -1,6 +1,6 @@
 use pep508_rs::MarkerEnvironment;
 use platform_tags::Platform;
-use reqwest::{Client, ClientBuilder};
+use reqwest::{Client, ClientBuilder, Proxy};
 use reqwest_middleware::ClientWithMiddleware;
 use reqwest_retry::policies::ExponentialBackoff;
 use reqwest_retry::RetryTransientMiddleware;
@@ -8,15 +8,22 @@ use std::env;
 use std::fmt::Debug;
 use std::ops::Deref;
 use std::path::Path;
+use std::sync::Arc;
 use tracing::debug;
-use uv_auth::AuthMiddleware;
-use uv_configuration::KeyringProviderType;
+use uv_auth::{AuthMiddleware, LazyCredentialStore, ScopedNetrc};
+use uv_configuration::{KeyringProviderType, RetryPolicy};
 use uv_fs::Simplified;
 use uv_version::version;
 use uv_warnings::warn_user_once;
 
+use crate::dns::{DnsConfig, DnsResolver};
+use crate::http_trace::{HttpTrace, HttpTraceMiddleware};
 use crate::linehaul::LineHaul;
 use crate::middleware::OfflineMiddleware;
+use crate::proxy::ProxyConfig;
+use crate::retry::PolicyRetryableStrategy;
+use crate::signature::{SignatureMiddleware, SignaturePolicy};
+use crate::tls::{validate_tls_overrides, TlsError, TlsOverride, TlsScopeMiddleware};
 use crate::Connectivity;
 
 /// A builder for an [`BaseClient`].
@@ -25,6 +32,14 @@ pub struct BaseClientBuilder<'a> {
     keyring: KeyringProviderType,
     native_tls: bool,
     retries: u32,
+    retry_policy: RetryPolicy,
+    tls_overrides: Vec<TlsOverride>,
+    dns_config: Option<Arc<DnsConfig>>,
+    proxy_config: Option<ProxyConfig>,
+    signature_policy: Option<Arc<SignaturePolicy>>,
+    http_trace: Option<HttpTrace>,
+    scoped_netrc: ScopedNetrc,
+    credential_store: Option<LazyCredentialStore>,
     connectivity: Connectivity,
     client: Option<Client>,
     markers: Option<&'a MarkerEnvironment>,
@@ -43,7 +58,15 @@ impl BaseClientBuilder<'_> {
             keyring: KeyringProviderType::default(),
             native_tls: false,
             connectivity: Connectivity::Online,
-            retries: 3,
+            retries: RetryPolicy::default().retries,
+            retry_policy: RetryPolicy::default(),
+            tls_overrides: Vec::new(),
+            dns_config: None,
+            proxy_config: None,
+            signature_policy: None,
+            http_trace: None,
+            scoped_netrc: ScopedNetrc::default(),
+            credential_store: None,
             client: None,
             markers: None,
             platform: None,
@@ -70,12 +93,75 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
+    /// Set the policy for retrying failed requests (and downloads, via the built client).
+    ///
+    /// Also resets the number of retries to that of the policy; use [`BaseClientBuilder::retries`]
+    /// afterwards to override it.
+    #[must_use]
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retries = retry_policy.retries;
+        self.retry_policy = retry_policy;
+        self
+    }
+
     #[must_use]
     pub fn native_tls(mut self, native_tls: bool) -> Self {
         self.native_tls = native_tls;
         self
     }
 
+    /// Set the TLS settings that apply to specific hosts (e.g., that of an index).
+    ///
+    /// Returns an error if multiple settings apply to the same host.
+    pub fn tls_overrides(mut self, tls_overrides: Vec<TlsOverride>) -> Result<Self, TlsError> {
+        validate_tls_overrides(&tls_overrides)?;
+        self.tls_overrides = tls_overrides;
+        Ok(self)
+    }
+
+    /// Set the DNS resolution to use on top of the system resolver.
+    #[must_use]
+    pub fn dns_config(mut self, dns_config: DnsConfig) -> Self {
+        self.dns_config = (!dns_config.is_default()).then(|| Arc::new(dns_config));
+        self
+    }
+
+    /// Set the proxy selection to use on top of the standard proxy environment variables (e.g.,
+    /// `HTTPS_PROXY` and `NO_PROXY`).
+    #[must_use]
+    pub fn proxy_config(mut self, proxy_config: ProxyConfig) -> Self {
+        self.proxy_config = (!proxy_config.is_empty()).then_some(proxy_config);
+        self
+    }
+
+    /// Set the `--find-links` sources whose distributions must be signed.
+    #[must_use]
+    pub fn signature_policy(mut self, signature_policy: SignaturePolicy) -> Self {
+        self.signature_policy = (!signature_policy.is_empty()).then(|| Arc::new(signature_policy));
+        self
+    }
+
+    /// Record requests, responses, and cache verdicts to the given trace.
+    #[must_use]
+    pub fn http_trace(mut self, http_trace: HttpTrace) -> Self {
+        self.http_trace = Some(http_trace);
+        self
+    }
+
+    /// Set the project-scoped netrc files, which take precedence over the user's netrc file.
+    #[must_use]
+    pub fn scoped_netrc(mut self, scoped_netrc: ScopedNetrc) -> Self {
+        self.scoped_netrc = scoped_netrc;
+        self
+    }
+
+    /// Set the encrypted credential store to read credentials from.
+    #[must_use]
+    pub fn credential_store(mut self, credential_store: LazyCredentialStore) -> Self {
+        self.credential_store = Some(credential_store);
+        self
+    }
+
     #[must_use]
     pub fn client(mut self, client: Client) -> Self {
         self.client = Some(client);
@@ -98,6 +184,10 @@ impl<'a> BaseClientBuilder<'a> {
         matches!(self.connectivity, Connectivity::Offline)
     }
 
+    pub fn is_native_tls(&self) -> bool {
+        self.native_tls
+    }
+
     pub fn build(&self) -> BaseClient {
         // Create user agent.
         let mut user_agent_string = format!("uv/{}", version());
@@ -127,8 +217,16 @@ impl<'a> BaseClientBuilder<'a> {
             .unwrap_or(default_timeout);
         debug!("Using registry request timeout of {}s", timeout);
 
+        // Load the proxy auto-config script, if any, now that a client needs it. (Offline clients
+        // never make a request, and so never need a proxy.)
+        if self.connectivity == Connectivity::Online {
+            if let Some(config) = self.proxy_config.as_ref() {
+                config.load();
+            }
+        }
+
         // Initialize the base client.
-        let client = self.client.clone().unwrap_or_else(|| {
+        let build_client = |tls: Option<&TlsOverride>| {
             // Check for the presence of an `SSL_CERT_FILE`.
             let ssl_cert_file_exists = env::var_os("SSL_CERT_FILE").is_some_and(|path| {
                 let path_exists = Path::new(&path).exists();
@@ -143,20 +241,45 @@ impl<'a> BaseClientBuilder<'a> {
 
             // Configure the builder.
             let client_core = ClientBuilder::new()
-                .user_agent(user_agent_string)
+                .user_agent(user_agent_string.clone())
                 .pool_max_idle_per_host(20)
                 .timeout(std::time::Duration::from_secs(timeout))
                 .tls_built_in_root_certs(false);
 
             // Configure TLS.
-            let client_core = if self.native_tls || ssl_cert_file_exists {
+            let native_roots = self.native_tls || ssl_cert_file_exists;
+            let client_core = if native_roots {
                 client_core.tls_built_in_native_certs(true)
             } else {
                 client_core.tls_built_in_webpki_certs(true)
             };
 
+            // Apply any TLS settings that are scoped to a specific host.
+            let client_core = if let Some(tls) = tls {
+                tls.apply(client_core, native_roots)
+            } else {
+                client_core
+            };
+
+            // Configure DNS resolution, if the system defaults are overridden.
+            let client_core = if let Some(config) = self.dns_config.as_ref() {
+                client_core.dns_resolver(DnsResolver::new(config.clone()))
+            } else {
+                client_core
+            };
+
+            // Configure proxies, if the defaults (i.e., the environment variables) are overridden.
+            let client_core = if let Some(config) = self.proxy_config.clone() {
+                client_core
+                    .no_proxy()
+                    .proxy(Proxy::custom(move |url| config.resolve(url)))
+            } else {
+                client_core
+            };
+
             client_core.build().expect("Failed to build HTTP client.")
-        });
+        };
+        let client = self.client.clone().unwrap_or_else(|| build_client(None));
 
         // Wrap in any relevant middleware.
         let client = match self.connectivity {
@@ -164,14 +287,49 @@ impl<'a> BaseClientBuilder<'a> {
                 let client = reqwest_middleware::ClientBuilder::new(client.clone());
 
                 // Initialize the retry strategy.
-                let retry_policy =
-                    ExponentialBackoff::builder().build_with_max_retries(self.retries);
-                let retry_strategy = RetryTransientMiddleware::new_with_policy(retry_policy);
+                let policy = &self.retry_policy;
+                let backoff = ExponentialBackoff::builder()
+                    .retry_bounds(policy.min_backoff, policy.max_backoff)
+                    .build_with_max_retries(self.retries);
+                let retry_strategy = RetryTransientMiddleware::new_with_policy_and_strategy(
+                    backoff,
+                    PolicyRetryableStrategy(policy.clone()),
+                );
                 let client = client.with(retry_strategy);
 
                 // Initialize the authentication middleware to set headers.
-                let client =
-                    client.with(AuthMiddleware::new().with_keyring(self.keyring.to_provider()));
+                let client = client.with(
+                    AuthMiddleware::new()
+                        .with_scoped_netrc(self.scoped_netrc.clone())
+                        .with_credential_store(self.credential_store.clone())
+                        .with_keyring(self.keyring.to_provider()),
+                );
+
+                // Record each request (after authentication, such that every retry is recorded).
+                let client = if let Some(trace) = self.http_trace.clone() {
+                    client.with(HttpTraceMiddleware(trace))
+                } else {
+                    client
+                };
+
+                // Verify distributions from signed `--find-links` sources against their signatures.
+                let client = if let Some(policy) = self.signature_policy.clone() {
+                    client.with(SignatureMiddleware(policy))
+                } else {
+                    client
+                };
+
+                // Send requests for hosts with their own TLS settings via a dedicated client.
+                let client = if self.tls_overrides.is_empty() {
+                    client
+                } else {
+                    let clients = self
+                        .tls_overrides
+                        .iter()
+                        .map(|tls| (tls.clone(), build_client(Some(tls))))
+                        .collect();
+                    client.with(TlsScopeMiddleware::new(clients))
+                };
 
                 client.build()
             }
@@ -184,6 +342,9 @@ impl<'a> BaseClientBuilder<'a> {
             connectivity: self.connectivity,
             client,
             timeout,
+            retry_policy: self.retry_policy.clone(),
+            signature_policy: self.signature_policy.clone(),
+            http_trace: self.http_trace.clone(),
         }
     }
 }
@@ -197,6 +358,12 @@ pub struct BaseClient {
     connectivity: Connectivity,
     /// Configured client timeout, in seconds.
     timeout: u64,
+    /// The policy for retrying failed requests and downloads.
+    retry_policy: RetryPolicy,
+    /// The `--find-links` sources whose distributions must be signed, if any.
+    signature_policy: Option<Arc<SignaturePolicy>>,
+    /// The trace to record requests and cache verdicts to, if any.
+    http_trace: Option<HttpTrace>,
 }
 
 impl BaseClient {
@@ -214,6 +381,21 @@ impl BaseClient {
     pub fn connectivity(&self) -> Connectivity {
         self.connectivity
     }
+
+    /// The configured policy for retrying failed requests and downloads.
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// The `--find-links` sources whose distributions must be signed, if any.
+    pub(crate) fn signature_policy(&self) -> Option<&Arc<SignaturePolicy>> {
+        self.signature_policy.as_ref()
+    }
+
+    /// The trace to record requests and cache verdicts to, if any.
+    pub(crate) fn http_trace(&self) -> Option<&HttpTrace> {
+        self.http_trace.as_ref()
+    }
 }
 
 // To avoid excessively verbose call chains, as the [`BaseClient`] is often nested within other client types.
@@ -12,6 +12,7 @@ use uv_fs::write_atomic;
 
 use crate::BaseClient;
 use crate::{
+    http_trace::CacheVerdict,
     httpcache::{AfterResponse, BeforeRequest, CachePolicy, CachePolicyBuilder},
     rkyvutil::OwnedArchive,
     Error, ErrorKind,
@@ -130,6 +131,13 @@ pub enum CacheControl {
     MustRevalidate,
     /// Allow the client to return stale responses.
     AllowStale,
+    /// Respect the `cache-control` header from the response, but don't guess at a freshness
+    /// lifetime in its absence, as is appropriate for artifacts at arbitrary URLs.
+    ///
+    /// Instead, responses with a validator (i.e., an `ETag` or `Last-Modified` header) are
+    /// revalidated on every request, and responses without a validator are reused until they're
+    /// explicitly refreshed, since they can't be revalidated without downloading them again.
+    Artifact,
 }
 
 impl From<Freshness> for CacheControl {
@@ -142,6 +150,17 @@ impl From<Freshness> for CacheControl {
     }
 }
 
+impl CacheControl {
+    /// Determine the [`CacheControl`] for an artifact at an arbitrary URL (e.g., a direct URL
+    /// wheel or source distribution), given the freshness of its cache entry.
+    pub fn artifact(freshness: Freshness) -> Self {
+        match freshness {
+            Freshness::Fresh | Freshness::Missing => Self::Artifact,
+            Freshness::Stale => Self::MustRevalidate,
+        }
+    }
+}
+
 /// Custom caching layer over [`reqwest::Client`].
 ///
 /// The implementation takes inspiration from the `http-cache` crate, but adds support for running
@@ -170,6 +189,13 @@ impl CachedClient {
         self.0.clone()
     }
 
+    /// Record the cache verdict for a request to the HTTP trace, if enabled.
+    fn record_cache(&self, url: &url::Url, verdict: CacheVerdict) {
+        if let Some(trace) = self.0.http_trace() {
+            trace.record_cache(url, verdict);
+        }
+    }
+
     /// Make a cached request with a custom response transformation
     /// while using serde to (de)serialize cached responses.
     ///
@@ -232,6 +258,7 @@ impl CachedClient {
             Some(cached) => self.send_cached(req, cache_control, cached).boxed().await?,
             None => {
                 debug!("No cache entry for: {}", req.url());
+                self.record_cache(req.url(), CacheVerdict::Miss);
                 let (response, cache_policy) = self.fresh_request(req).await?;
                 CachedResponse::ModifiedOrNew {
                     response,
@@ -417,22 +444,47 @@ impl CachedClient {
                     http::HeaderValue::from_static("no-cache"),
                 );
             }
+            CacheControl::Artifact => {
+                // In lieu of an explicit freshness lifetime, revalidate any response with a
+                // validator, rather than applying heuristic freshness.
+                if !cached.cache_policy.has_explicit_freshness()
+                    && cached.cache_policy.has_validator()
+                {
+                    req.headers_mut().insert(
+                        http::header::CACHE_CONTROL,
+                        http::HeaderValue::from_static("no-cache"),
+                    );
+                }
+            }
         }
         Ok(match cached.cache_policy.before_request(&mut req) {
             BeforeRequest::Fresh => {
                 debug!("Found fresh response for: {}", req.url());
+                self.record_cache(req.url(), CacheVerdict::Fresh);
                 CachedResponse::FreshCache(cached)
             }
             BeforeRequest::Stale(new_cache_policy_builder) => match cache_control {
-                CacheControl::None | CacheControl::MustRevalidate => {
-                    debug!("Found stale response for: {}", req.url());
-                    self.send_cached_handle_stale(req, cached, new_cache_policy_builder)
-                        .await?
-                }
                 CacheControl::AllowStale => {
                     debug!("Found stale (but allowed) response for: {}", req.url());
+                    self.record_cache(req.url(), CacheVerdict::StaleAllowed);
                     CachedResponse::FreshCache(cached)
                 }
+                CacheControl::Artifact
+                    if !cached.cache_policy.has_explicit_freshness()
+                        && !cached.cache_policy.has_validator() =>
+                {
+                    debug!(
+                        "Found response without freshness information or validators for: {}",
+                        req.url()
+                    );
+                    self.record_cache(req.url(), CacheVerdict::StaleAllowed);
+                    CachedResponse::FreshCache(cached)
+                }
+                CacheControl::None | CacheControl::MustRevalidate | CacheControl::Artifact => {
+                    debug!("Found stale response for: {}", req.url());
+                    self.send_cached_handle_stale(req, cached, new_cache_policy_builder)
+                        .await?
+                }
             },
             BeforeRequest::NoMatch => {
                 // This shouldn't happen; if it does, we'll override the cache.
@@ -471,6 +523,7 @@ impl CachedClient {
         {
             AfterResponse::NotModified(new_policy) => {
                 debug!("Found not-modified response for: {url}");
+                self.record_cache(&url, CacheVerdict::NotModified);
                 Ok(CachedResponse::NotModified {
                     cached,
                     new_policy: Box::new(new_policy),
@@ -478,6 +531,7 @@ impl CachedClient {
             }
             AfterResponse::Modified(new_policy) => {
                 debug!("Found modified response for: {url}");
+                self.record_cache(&url, CacheVerdict::Modified);
                 Ok(CachedResponse::ModifiedOrNew {
                     response,
                     cache_policy: new_policy
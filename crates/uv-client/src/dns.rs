@@ -0,0 +1,141 @@
+use std::collections::BTreeMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+
+use hickory_resolver::config::{NameServerConfig, Protocol, ResolverConfig, ResolverOpts};
+use hickory_resolver::TokioAsyncResolver;
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use tracing::debug;
+
+use uv_configuration::IpStrategy;
+
+/// DNS resolution on top of the system resolver.
+#[derive(Debug, Clone, Default)]
+pub struct DnsConfig {
+    ip_strategy: IpStrategy,
+    nameservers: Vec<SocketAddr>,
+    hosts: BTreeMap<String, Vec<IpAddr>>,
+}
+
+impl DnsConfig {
+    /// Choose between IPv4 and IPv6 addresses with the given strategy.
+    #[must_use]
+    pub fn with_ip_strategy(mut self, ip_strategy: IpStrategy) -> Self {
+        self.ip_strategy = ip_strategy;
+        self
+    }
+
+    /// Resolve hostnames via the given nameservers, rather than the system resolver.
+    #[must_use]
+    pub fn with_nameservers(mut self, nameservers: Vec<SocketAddr>) -> Self {
+        self.nameservers = nameservers;
+        self
+    }
+
+    /// Resolve the given host to a fixed address, like an entry in `/etc/hosts`.
+    #[must_use]
+    pub fn with_host(mut self, host: &str, addr: IpAddr) -> Self {
+        self.hosts
+            .entry(host.trim_end_matches('.').to_ascii_lowercase())
+            .or_default()
+            .push(addr);
+        self
+    }
+
+    pub(crate) fn is_default(&self) -> bool {
+        self.ip_strategy == IpStrategy::Auto && self.nameservers.is_empty() && self.hosts.is_empty()
+    }
+
+    /// Filter and order the resolved addresses according to the [`IpStrategy`].
+    ///
+    /// The connector attempts the family of the first address, racing the other family after a
+    /// short delay, so ordering is sufficient to express a preference.
+    fn apply(&self, mut addrs: Vec<SocketAddr>) -> Vec<SocketAddr> {
+        match self.ip_strategy {
+            IpStrategy::Auto => {}
+            IpStrategy::PreferIpv4 => addrs.sort_by_key(SocketAddr::is_ipv6),
+            IpStrategy::PreferIpv6 => addrs.sort_by_key(SocketAddr::is_ipv4),
+            IpStrategy::Ipv4Only => addrs.retain(SocketAddr::is_ipv4),
+            IpStrategy::Ipv6Only => addrs.retain(SocketAddr::is_ipv6),
+        }
+        addrs
+    }
+}
+
+/// A [`Resolve`] implementation that applies the [`DnsConfig`].
+pub(crate) struct DnsResolver {
+    config: Arc<DnsConfig>,
+    /// The resolver for the custom nameservers, if any.
+    resolver: Option<TokioAsyncResolver>,
+}
+
+impl DnsResolver {
+    pub(crate) fn new(config: Arc<DnsConfig>) -> Arc<Self> {
+        let resolver = (!config.nameservers.is_empty()).then(|| {
+            let mut resolver_config = ResolverConfig::new();
+            for nameserver in &config.nameservers {
+                resolver_config.add_name_server(NameServerConfig::new(*nameserver, Protocol::Udp));
+                resolver_config.add_name_server(NameServerConfig::new(*nameserver, Protocol::Tcp));
+            }
+            TokioAsyncResolver::tokio(resolver_config, ResolverOpts::default())
+        });
+        Arc::new(Self { config, resolver })
+    }
+}
+
+impl Resolve for DnsResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let config = self.config.clone();
+        let resolver = self.resolver.clone();
+        let host = name.as_str().trim_end_matches('.').to_ascii_lowercase();
+        Box::pin(async move {
+            let addrs: Vec<SocketAddr> = if let Some(addrs) = config.hosts.get(&host) {
+                debug!("Resolved {host} via a static override");
+                addrs.iter().map(|addr| SocketAddr::new(*addr, 0)).collect()
+            } else if let Some(resolver) = resolver {
+                resolver
+                    .lookup_ip(host.as_str())
+                    .await?
+                    .iter()
+                    .map(|addr| SocketAddr::new(addr, 0))
+                    .collect()
+            } else {
+                tokio::net::lookup_host((host.as_str(), 0)).await?.collect()
+            };
+
+            let addrs = config.apply(addrs);
+            if addrs.is_empty() {
+                return Err(format!(
+                    "No addresses found for {host} (with IP strategy: {:?})",
+                    config.ip_strategy
+                )
+                .into());
+            }
+            let addrs: Addrs = Box::new(addrs.into_iter());
+            Ok(addrs)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn apply() {
+        let v4 = SocketAddr::from_str("10.0.0.1:0").unwrap();
+        let v6 = SocketAddr::from_str("[fd00::1]:0").unwrap();
+        let addrs = vec![v6, v4];
+
+        let config = DnsConfig::default();
+        assert_eq!(config.apply(addrs.clone()), vec![v6, v4]);
+
+        let config = DnsConfig::default().with_ip_strategy(IpStrategy::PreferIpv4);
+        assert_eq!(config.apply(addrs.clone()), vec![v4, v6]);
+
+        let config = DnsConfig::default().with_ip_strategy(IpStrategy::Ipv6Only);
+        assert_eq!(config.apply(addrs.clone()), vec![v6]);
+    }
+}
@@ -9,9 +9,11 @@ use distribution_filename::DistFilename;
 use distribution_types::{File, FileLocation, FlatIndexLocation, IndexUrl};
 use pep508_rs::VerbatimUrl;
 use uv_cache::{Cache, CacheBucket};
+use uv_warnings::warn_user;
 
 use crate::cached_client::{CacheControl, CachedClientError};
 use crate::html::SimpleHtml;
+use crate::signature::verify_file;
 use crate::{Connectivity, Error, ErrorKind, RegistryClient};
 
 #[derive(Debug, thiserror::Error)]
@@ -89,7 +91,8 @@ impl<'a> FlatIndexClient<'a> {
         let mut fetches = futures::stream::iter(indexes)
             .map(|index| async move {
                 let entries = match index {
-                    FlatIndexLocation::Path(path) => Self::read_from_directory(path)
+                    FlatIndexLocation::Path(path) => self
+                        .read_from_directory(path)
                         .map_err(|err| FlatIndexError::FindLinksDirectory(path.clone(), err))?,
                     FlatIndexLocation::Url(url) => self
                         .read_from_url(url)
@@ -181,6 +184,20 @@ impl<'a> FlatIndexClient<'a> {
             .await;
         match response {
             Ok(files) => {
+                // Record the listed distributions, such that they're verified on download.
+                if let Some(policy) = self.client.signature_policy() {
+                    policy.register(
+                        url,
+                        files.iter().filter_map(|file| match &file.url {
+                            FileLocation::RelativeUrl(base, url) => {
+                                pypi_types::base_url_join_relative(base, url).ok()
+                            }
+                            FileLocation::AbsoluteUrl(url) => Url::parse(url).ok(),
+                            FileLocation::Path(_) => None,
+                        }),
+                    );
+                }
+
                 let index_url = IndexUrl::Url(VerbatimUrl::from_url(url.clone()));
                 let files = files
                     .into_iter()
@@ -202,10 +219,14 @@ impl<'a> FlatIndexClient<'a> {
     }
 
     /// Read a flat remote index from a `--find-links` directory.
-    fn read_from_directory(path: &PathBuf) -> Result<FlatIndexEntries, std::io::Error> {
+    fn read_from_directory(&self, path: &PathBuf) -> Result<FlatIndexEntries, std::io::Error> {
         // Absolute paths are required for the URL conversion.
         let path = fs_err::canonicalize(path)?;
         let index_url = IndexUrl::Path(VerbatimUrl::from_path(&path));
+        let keys = self
+            .client
+            .signature_policy()
+            .and_then(|policy| policy.directory_keys(&path));
 
         let mut dists = Vec::new();
         for entry in fs_err::read_dir(path)? {
@@ -241,6 +262,15 @@ impl<'a> FlatIndexClient<'a> {
                 );
                 continue;
             };
+
+            // Skip any distributions that aren't signed by a trusted key.
+            if let Some(keys) = keys {
+                if let Err(err) = verify_file(keys, &entry.path()) {
+                    warn_user!("Skipping `--find-links` entry: {err}");
+                    continue;
+                }
+            }
+
             dists.push((filename, file, index_url.clone()));
         }
         Ok(FlatIndexEntries::from_entries(dists))
@@ -727,6 +727,37 @@ impl ArchivedCachePolicy {
         false
     }
 
+    /// Returns true if the cached response has a validator (i.e., a strong
+    /// `ETag` or a `Last-Modified` timestamp), such that it can be
+    /// revalidated via a conditional request, as per [RFC 9111 S4.3.1].
+    ///
+    /// The validators are recorded as part of the cache policy, alongside the
+    /// cached response.
+    ///
+    /// [RFC 9111 S4.3.1]: https://www.rfc-editor.org/rfc/rfc9111.html#section-4.3.1
+    pub fn has_validator(&self) -> bool {
+        self.response
+            .headers
+            .etag
+            .as_ref()
+            .is_some_and(|etag| !etag.weak)
+            || self.response.headers.last_modified_unix_timestamp.is_some()
+    }
+
+    /// Returns true if the cached response dictates its own freshness (i.e.,
+    /// via a `max-age` directive or an `Expires` header) or otherwise
+    /// constrains its reuse (i.e., via a `no-cache` or `immutable`
+    /// directive), such that its freshness doesn't need to be determined
+    /// heuristically, as per [RFC 9111 S4.2.2].
+    ///
+    /// [RFC 9111 S4.2.2]: https://www.rfc-editor.org/rfc/rfc9111.html#section-4.2.2
+    pub fn has_explicit_freshness(&self) -> bool {
+        self.response.headers.cc.max_age_seconds.is_some()
+            || self.response.headers.expires_unix_timestamp.is_some()
+            || self.response.headers.cc.no_cache
+            || self.response.headers.cc.immutable
+    }
+
     /// Returns true when a response is storable even if it has an
     /// `Authorization` header, as per [RFC 9111 S3.5].
     ///
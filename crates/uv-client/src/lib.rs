@@ -1,22 +1,33 @@
 pub use base_client::{BaseClient, BaseClientBuilder};
 pub use cached_client::{CacheControl, CachedClient, CachedClientError, DataWithCachePolicy};
+pub use dns::DnsConfig;
 pub use error::{BetterReqwestError, Error, ErrorKind};
 pub use flat_index::{FlatIndexClient, FlatIndexEntries, FlatIndexError};
+pub use http_trace::HttpTrace;
 pub use linehaul::LineHaul;
+pub use proxy::{HostPattern, PacScript, ProxyConfig, ProxyError};
 pub use registry_client::{
     Connectivity, RegistryClient, RegistryClientBuilder, SimpleMetadata, SimpleMetadatum,
     VersionFiles,
 };
 pub use rkyvutil::OwnedArchive;
+pub use signature::{SignatureError, SignaturePolicy};
+pub use tls::{CertificatePin, TlsError, TlsOverride};
 
 mod base_client;
 mod cached_client;
+mod dns;
 mod error;
 mod flat_index;
 mod html;
+mod http_trace;
 mod httpcache;
 mod linehaul;
 mod middleware;
+mod proxy;
 mod registry_client;
 mod remote_metadata;
+mod retry;
 mod rkyvutil;
+mod signature;
+mod tls;
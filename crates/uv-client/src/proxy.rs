@@ -0,0 +1,491 @@
+use std::env;
+use std::str::FromStr;
+use std::sync::{Arc, OnceLock};
+
+use tracing::debug;
+use url::Url;
+
+use uv_warnings::warn_user_once;
+
+/// A host pattern, as used in `NO_PROXY` (e.g., `localhost`, `.corp.example.com`, or
+/// `*.corp.example.com`).
+///
+/// A domain matches itself and any of its subdomains; `*` matches every host.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HostPattern {
+    /// Matches every host.
+    Any,
+    /// Matches the domain (or IP address), and any subdomains.
+    Domain(String),
+}
+
+impl HostPattern {
+    /// Returns `true` if the pattern matches the given host.
+    pub fn matches(&self, host: &str) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Domain(domain) => {
+                let host = host.trim_end_matches('.').to_ascii_lowercase();
+                host == *domain
+                    || host
+                        .strip_suffix(domain.as_str())
+                        .is_some_and(|prefix| prefix.ends_with('.'))
+            }
+        }
+    }
+}
+
+impl FromStr for HostPattern {
+    type Err = ProxyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s == "*" {
+            return Ok(Self::Any);
+        }
+        let domain = s
+            .trim_start_matches('*')
+            .trim_start_matches('.')
+            .trim_end_matches('.')
+            .to_ascii_lowercase();
+        if domain.is_empty() || domain.contains(['*', '/', ' ']) {
+            return Err(ProxyError::InvalidHostPattern(s.to_string()));
+        }
+        Ok(Self::Domain(domain))
+    }
+}
+
+/// Proxy selection on top of the standard proxy environment variables.
+///
+/// For each request, the proxy is selected as follows:
+///
+/// 1. A proxy configured for the request's host (e.g., for a specific index).
+/// 2. No proxy, if the host matches a `no-proxy` pattern.
+/// 3. The result of the proxy auto-config (PAC) script, if any.
+/// 4. The `HTTPS_PROXY`, `HTTP_PROXY`, `ALL_PROXY`, and `NO_PROXY` environment variables.
+#[derive(Debug, Clone, Default)]
+pub struct ProxyConfig {
+    host_proxies: Vec<(String, Url)>,
+    no_proxy: Vec<HostPattern>,
+    pac: Option<Arc<LazyPacScript>>,
+}
+
+impl ProxyConfig {
+    /// Route all requests to the host of the given URL (e.g., an index) through the given proxy.
+    #[must_use]
+    pub fn with_host_proxy(mut self, url: &Url, proxy: Url) -> Self {
+        if let Some(host) = url.host_str() {
+            self.host_proxies.push((host.to_ascii_lowercase(), proxy));
+        }
+        self
+    }
+
+    /// Bypass any proxy for hosts that match the given patterns.
+    #[must_use]
+    pub fn with_no_proxy(mut self, patterns: Vec<HostPattern>) -> Self {
+        self.no_proxy.extend(patterns);
+        self
+    }
+
+    /// Select proxies with the given proxy auto-config (PAC) script.
+    #[must_use]
+    pub fn with_pac(mut self, pac: PacScript) -> Self {
+        self.pac = Some(Arc::new(LazyPacScript {
+            url: None,
+            script: OnceLock::from(Some(pac)),
+        }));
+        self
+    }
+
+    /// Select proxies with the proxy auto-config (PAC) script at the given URL (including
+    /// `file://` URLs).
+    ///
+    /// The script is only loaded once a client that uses this configuration is built, such that
+    /// commands that never make a request don't fetch it.
+    #[must_use]
+    pub fn with_pac_url(mut self, url: Url) -> Self {
+        self.pac = Some(Arc::new(LazyPacScript {
+            url: Some(url),
+            script: OnceLock::new(),
+        }));
+        self
+    }
+
+    /// Load the proxy auto-config (PAC) script, if it hasn't been loaded yet.
+    pub(crate) fn load(&self) {
+        if let Some(pac) = self.pac.as_ref() {
+            pac.get();
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.host_proxies.is_empty() && self.no_proxy.is_empty() && self.pac.is_none()
+    }
+
+    /// Select the proxy for the given URL, or `None` to connect directly.
+    pub fn resolve(&self, url: &Url) -> Option<Url> {
+        let host = url.host_str()?.to_ascii_lowercase();
+
+        if let Some((_, proxy)) = self.host_proxies.iter().find(|(h, _)| *h == host) {
+            return Some(proxy.clone());
+        }
+
+        if self.no_proxy.iter().any(|pattern| pattern.matches(&host)) {
+            return None;
+        }
+
+        if let Some(pac) = self.pac.as_ref().and_then(|pac| pac.get()) {
+            match pac.find_proxy(url, &host) {
+                Ok(proxy) => return proxy,
+                Err(err) => debug!("Failed to evaluate proxy auto-config for {host}: {err}"),
+            }
+        }
+
+        env_proxy(url, &host)
+    }
+}
+
+/// Select the proxy for the given URL from the standard environment variables.
+fn env_proxy(url: &Url, host: &str) -> Option<Url> {
+    let var = |name: &str| {
+        env::var(name)
+            .or_else(|_| env::var(name.to_ascii_lowercase()))
+            .ok()
+            .filter(|value| !value.is_empty())
+    };
+
+    if let Some(no_proxy) = var("NO_PROXY") {
+        let bypass = no_proxy
+            .split(',')
+            .filter(|pattern| !pattern.trim().is_empty())
+            .filter_map(|pattern| HostPattern::from_str(pattern).ok())
+            .any(|pattern| pattern.matches(host));
+        if bypass {
+            return None;
+        }
+    }
+
+    let proxy = match url.scheme() {
+        "https" => var("HTTPS_PROXY"),
+        "http" => var("HTTP_PROXY"),
+        _ => None,
+    }
+    .or_else(|| var("ALL_PROXY"))?;
+
+    // Like `curl`, assume `http://` for proxies without a scheme.
+    if proxy.contains("://") {
+        Url::parse(&proxy).ok()
+    } else {
+        Url::parse(&format!("http://{proxy}")).ok()
+    }
+}
+
+/// A proxy auto-config (PAC) script that's loaded on first use, and shared by every client built
+/// from the same [`ProxyConfig`].
+#[derive(Debug)]
+struct LazyPacScript {
+    url: Option<Url>,
+    script: OnceLock<Option<PacScript>>,
+}
+
+impl LazyPacScript {
+    /// Return the script, loading it if necessary.
+    ///
+    /// If the script can't be loaded, a warning is shown once, and proxies are selected as if no
+    /// script were configured.
+    fn get(&self) -> Option<&PacScript> {
+        self.script
+            .get_or_init(|| {
+                let url = self.url.as_ref()?;
+                match PacScript::load_blocking(url) {
+                    Ok(script) => Some(script),
+                    Err(err) => {
+                        warn_user_once!("Failed to load proxy auto-config from `{url}`: {err}");
+                        None
+                    }
+                }
+            })
+            .as_ref()
+    }
+}
+
+/// A proxy auto-config (PAC) script, which selects a proxy for each URL via its
+/// `FindProxyForURL(url, host)` function.
+#[derive(Debug)]
+pub struct PacScript {
+    #[cfg_attr(not(feature = "pac"), allow(dead_code))]
+    source: String,
+    #[cfg(feature = "pac")]
+    results: std::sync::Mutex<std::collections::HashMap<(String, String), Option<Url>>>,
+}
+
+impl PacScript {
+    /// Load a PAC script from a URL (including `file://` URLs).
+    pub async fn load(url: &Url) -> Result<Self, ProxyError> {
+        if cfg!(not(feature = "pac")) {
+            return Err(ProxyError::PacUnsupported);
+        }
+        let source = if url.scheme() == "file" {
+            let path = url
+                .to_file_path()
+                .map_err(|()| ProxyError::InvalidPacUrl(url.clone()))?;
+            fs_err::tokio::read_to_string(path).await?
+        } else {
+            let client = crate::BaseClientBuilder::new().build();
+            client
+                .get(url.clone())
+                .send()
+                .await?
+                .error_for_status()
+                .map_err(reqwest_middleware::Error::Reqwest)?
+                .text()
+                .await
+                .map_err(reqwest_middleware::Error::Reqwest)?
+        };
+        Self::new(source)
+    }
+
+    /// Load a PAC script from a URL (including `file://` URLs), blocking the current thread.
+    ///
+    /// The script is loaded on a dedicated thread, such that this can be called from both
+    /// synchronous and asynchronous contexts.
+    fn load_blocking(url: &Url) -> Result<Self, ProxyError> {
+        let url = url.clone();
+        std::thread::spawn(move || {
+            tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()?
+                .block_on(Self::load(&url))
+        })
+        .join()
+        .unwrap_or_else(|_| Err(ProxyError::Pac("The loader thread panicked".to_string())))
+    }
+
+    /// Compile a PAC script from its source.
+    #[cfg(feature = "pac")]
+    pub fn new(source: String) -> Result<Self, ProxyError> {
+        let script = Self {
+            source,
+            results: std::sync::Mutex::default(),
+        };
+        // Evaluate the script up front, to surface any syntax errors.
+        script.context()?;
+        Ok(script)
+    }
+
+    /// Compile a PAC script from its source.
+    #[cfg(not(feature = "pac"))]
+    pub fn new(_source: String) -> Result<Self, ProxyError> {
+        Err(ProxyError::PacUnsupported)
+    }
+
+    /// Select the proxy for the given URL, or `None` to connect directly.
+    ///
+    /// Results are cached per scheme and host.
+    #[cfg(feature = "pac")]
+    fn find_proxy(&self, url: &Url, host: &str) -> Result<Option<Url>, ProxyError> {
+        let key = (url.scheme().to_string(), host.to_string());
+        if let Some(proxy) = self.results.lock().unwrap().get(&key) {
+            return Ok(proxy.clone());
+        }
+
+        let mut context = self.context()?;
+        let call = format!(
+            "FindProxyForURL({}, {})",
+            serde_json::Value::from(url.as_str()),
+            serde_json::Value::from(host)
+        );
+        let result = context
+            .eval(boa_engine::Source::from_bytes(&call))
+            .and_then(|value| value.to_string(&mut context))
+            .map_err(|err| ProxyError::Pac(err.to_string()))?
+            .to_std_string_escaped();
+        debug!("Proxy auto-config for {host}: {result}");
+
+        let proxy = parse_pac_result(&result);
+        self.results.lock().unwrap().insert(key, proxy.clone());
+        Ok(proxy)
+    }
+
+    #[cfg(not(feature = "pac"))]
+    #[allow(clippy::unused_self)]
+    fn find_proxy(&self, _url: &Url, _host: &str) -> Result<Option<Url>, ProxyError> {
+        Err(ProxyError::PacUnsupported)
+    }
+
+    /// Create a JavaScript context with the PAC helper functions and the script loaded.
+    #[cfg(feature = "pac")]
+    fn context(&self) -> Result<boa_engine::Context, ProxyError> {
+        let mut context = boa_engine::Context::default();
+        context
+            .eval(boa_engine::Source::from_bytes(PAC_PRELUDE))
+            .and_then(|_| context.eval(boa_engine::Source::from_bytes(&self.source)))
+            .map_err(|err| ProxyError::Pac(err.to_string()))?;
+        Ok(context)
+    }
+}
+
+/// Parse the result of `FindProxyForURL` (e.g., `PROXY proxy.corp:8080; DIRECT`), returning the
+/// first supported entry.
+#[cfg_attr(not(feature = "pac"), allow(dead_code))]
+fn parse_pac_result(result: &str) -> Option<Url> {
+    for entry in result.split(';') {
+        let mut parts = entry.split_whitespace();
+        let scheme = match parts.next().map(str::to_ascii_uppercase).as_deref() {
+            Some("DIRECT") | None => return None,
+            Some("PROXY" | "HTTP") => "http",
+            Some("HTTPS") => "https",
+            // SOCKS proxies aren't supported; fall through to the next entry.
+            Some(_) => continue,
+        };
+        if let Some(proxy) = parts
+            .next()
+            .and_then(|address| Url::parse(&format!("{scheme}://{address}")).ok())
+        {
+            return Some(proxy);
+        }
+    }
+    None
+}
+
+/// Implementations of the standard PAC helper functions.
+///
+/// DNS-based helpers (`dnsResolve`, `isResolvable`, and `isInNet`) only operate on IP address
+/// literals, as hostnames aren't resolved during evaluation.
+#[cfg(feature = "pac")]
+const PAC_PRELUDE: &str = r#"
+function isPlainHostName(host) { return host.indexOf(".") === -1; }
+function dnsDomainIs(host, domain) {
+    return host.length >= domain.length && host.substring(host.length - domain.length) === domain;
+}
+function localHostOrDomainIs(host, hostdom) {
+    return host === hostdom || (isPlainHostName(host) && hostdom.indexOf(host + ".") === 0);
+}
+function dnsDomainLevels(host) { return host.split(".").length - 1; }
+function shExpMatch(str, shexp) {
+    var pattern = shexp.replace(/[.+^${}()|[\]\\]/g, "\\$&").replace(/\*/g, ".*").replace(/\?/g, ".");
+    return new RegExp("^" + pattern + "$").test(str);
+}
+function __uvParseIp(ip) {
+    var parts = ip.split(".");
+    if (parts.length !== 4) { return null; }
+    var result = 0;
+    for (var i = 0; i < 4; i++) {
+        var part = parseInt(parts[i], 10);
+        if (isNaN(part) || part < 0 || part > 255) { return null; }
+        result = result * 256 + part;
+    }
+    return result;
+}
+function dnsResolve(host) { return __uvParseIp(host) === null ? null : host; }
+function isResolvable(host) { return dnsResolve(host) !== null; }
+function myIpAddress() { return "127.0.0.1"; }
+function isInNet(host, pattern, mask) {
+    var ip = __uvParseIp(host), net = __uvParseIp(pattern), bits = __uvParseIp(mask);
+    if (ip === null || net === null || bits === null) { return false; }
+    for (var i = 0; i < 4; i++) {
+        var shift = Math.pow(256, 3 - i);
+        var a = Math.floor(ip / shift) % 256, b = Math.floor(net / shift) % 256, m = Math.floor(bits / shift) % 256;
+        if ((a & m) !== (b & m)) { return false; }
+    }
+    return true;
+}
+function weekdayRange() { return true; }
+function dateRange() { return true; }
+function timeRange() { return true; }
+"#;
+
+#[derive(thiserror::Error, Debug)]
+pub enum ProxyError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error(transparent)]
+    Fetch(#[from] reqwest_middleware::Error),
+
+    #[error("Invalid `no-proxy` host pattern: `{0}`")]
+    InvalidHostPattern(String),
+
+    #[error("Invalid proxy auto-config URL: `{0}`")]
+    InvalidPacUrl(Url),
+
+    #[error("Failed to evaluate proxy auto-config script: {0}")]
+    Pac(String),
+
+    #[error("Proxy auto-config (PAC) scripts require uv to be built with the `pac` feature")]
+    PacUnsupported,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn host_pattern() {
+        let pattern = HostPattern::from_str(".corp.example.com").unwrap();
+        assert!(pattern.matches("corp.example.com"));
+        assert!(pattern.matches("pypi.corp.example.com"));
+        assert!(pattern.matches("PyPI.Corp.Example.com."));
+        assert!(!pattern.matches("notcorp.example.com"));
+        assert!(!pattern.matches("example.com"));
+
+        let pattern = HostPattern::from_str("*.corp.example.com").unwrap();
+        assert!(pattern.matches("pypi.corp.example.com"));
+
+        assert!(HostPattern::from_str("*").unwrap().matches("example.com"));
+        assert!(HostPattern::from_str("").is_err());
+        assert!(HostPattern::from_str("corp.*.com").is_err());
+    }
+
+    #[test]
+    fn resolve() {
+        let index = Url::parse("https://pypi.corp.example.com/simple/").unwrap();
+        let proxy = Url::parse("http://proxy.corp.example.com:3128").unwrap();
+        let config = ProxyConfig::default()
+            .with_host_proxy(&index, proxy.clone())
+            .with_no_proxy(vec![HostPattern::from_str(".corp.example.com").unwrap()]);
+
+        // The per-host proxy takes precedence over the `no-proxy` patterns.
+        assert_eq!(
+            config.resolve(&Url::parse("https://pypi.corp.example.com/simple/anyio/").unwrap()),
+            Some(proxy)
+        );
+        assert_eq!(
+            config.resolve(&Url::parse("https://files.corp.example.com/anyio.whl").unwrap()),
+            None
+        );
+    }
+
+    #[cfg(feature = "pac")]
+    #[test]
+    fn pac_url_is_loaded_lazily() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("proxy.pac");
+        let config = ProxyConfig::default().with_pac_url(Url::from_file_path(&path).unwrap());
+
+        // The script doesn't exist yet, but configuring it must not read it.
+        fs_err::write(
+            &path,
+            r#"function FindProxyForURL(url, host) { return "PROXY proxy.corp.example.com:3128"; }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.resolve(&Url::parse("https://pypi.org/simple/anyio/").unwrap()),
+            Some(Url::parse("http://proxy.corp.example.com:3128").unwrap())
+        );
+    }
+
+    #[test]
+    fn pac_result() {
+        assert_eq!(parse_pac_result("DIRECT"), None);
+        assert_eq!(
+            parse_pac_result("PROXY proxy.corp:8080; DIRECT"),
+            Some(Url::parse("http://proxy.corp:8080").unwrap())
+        );
+        assert_eq!(
+            parse_pac_result("SOCKS5 socks.corp:1080; HTTPS proxy.corp:443"),
+            Some(Url::parse("https://proxy.corp:443").unwrap())
+        );
+    }
+}
@@ -1,12 +1,13 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::Debug;
 use std::path::Path;
 use std::str::FromStr;
+use std::sync::{Arc, Mutex};
 
 use async_http_range_reader::AsyncHttpRangeReader;
 use futures::{FutureExt, TryStreamExt};
 use http::HeaderMap;
-use reqwest::{Client, Response, StatusCode};
+use reqwest::{Client, Request, Response, StatusCode};
 use serde::{Deserialize, Serialize};
 use tokio::io::AsyncReadExt;
 use tokio_util::compat::{FuturesAsyncReadCompatExt, TokioAsyncReadCompatExt};
@@ -14,15 +15,16 @@ use tracing::{info_span, instrument, trace, warn, Instrument};
 use url::Url;
 
 use distribution_filename::{DistFilename, SourceDistFilename, WheelFilename};
-use distribution_types::{BuiltDist, File, FileLocation, IndexUrl, IndexUrls, Name};
+use distribution_types::{BuiltDist, File, FileLocation, IndexApi, IndexUrl, IndexUrls, Name};
 use install_wheel_rs::metadata::{find_archive_dist_info, is_metadata_entry};
 use pep440_rs::Version;
 use pep508_rs::MarkerEnvironment;
 use platform_tags::Platform;
 use pypi_types::{Metadata23, SimpleJson};
-use uv_cache::{Cache, CacheBucket, WheelCache};
+use uv_cache::{Cache, CacheBucket, CacheShard, Freshness, WheelCache};
 use uv_configuration::IndexStrategy;
 use uv_configuration::KeyringProviderType;
+use uv_configuration::RetryPolicy;
 use uv_normalize::PackageName;
 
 use crate::base_client::{BaseClient, BaseClientBuilder};
@@ -30,6 +32,7 @@ use crate::cached_client::CacheControl;
 use crate::html::SimpleHtml;
 use crate::remote_metadata::wheel_metadata_from_remote_zip;
 use crate::rkyvutil::OwnedArchive;
+use crate::signature::SignaturePolicy;
 use crate::{CachedClient, CachedClientError, Error, ErrorKind};
 
 /// A builder for an [`RegistryClient`].
@@ -37,34 +40,28 @@ use crate::{CachedClient, CachedClientError, Error, ErrorKind};
 pub struct RegistryClientBuilder<'a> {
     index_urls: IndexUrls,
     index_strategy: IndexStrategy,
-    keyring: KeyringProviderType,
-    native_tls: bool,
-    retries: u32,
-    connectivity: Connectivity,
     cache: Cache,
-    client: Option<Client>,
-    markers: Option<&'a MarkerEnvironment>,
-    platform: Option<&'a Platform>,
+    base_client_builder: BaseClientBuilder<'a>,
 }
 
 impl RegistryClientBuilder<'_> {
     pub fn new(cache: Cache) -> Self {
+        Self::from_base_client(cache, BaseClientBuilder::new())
+    }
+}
+
+impl<'a> RegistryClientBuilder<'a> {
+    /// Create a [`RegistryClientBuilder`] that inherits the settings of the given
+    /// [`BaseClientBuilder`] (e.g., the retry policy, and the TLS and proxy settings).
+    pub fn from_base_client(cache: Cache, base_client_builder: BaseClientBuilder<'a>) -> Self {
         Self {
             index_urls: IndexUrls::default(),
             index_strategy: IndexStrategy::default(),
-            keyring: KeyringProviderType::default(),
-            native_tls: false,
             cache,
-            connectivity: Connectivity::Online,
-            retries: 3,
-            client: None,
-            markers: None,
-            platform: None,
+            base_client_builder,
         }
     }
-}
 
-impl<'a> RegistryClientBuilder<'a> {
     #[must_use]
     pub fn index_urls(mut self, index_urls: IndexUrls) -> Self {
         self.index_urls = index_urls;
@@ -79,25 +76,25 @@ impl<'a> RegistryClientBuilder<'a> {
 
     #[must_use]
     pub fn keyring(mut self, keyring_type: KeyringProviderType) -> Self {
-        self.keyring = keyring_type;
+        self.base_client_builder = self.base_client_builder.keyring(keyring_type);
         self
     }
 
     #[must_use]
     pub fn connectivity(mut self, connectivity: Connectivity) -> Self {
-        self.connectivity = connectivity;
+        self.base_client_builder = self.base_client_builder.connectivity(connectivity);
         self
     }
 
     #[must_use]
     pub fn retries(mut self, retries: u32) -> Self {
-        self.retries = retries;
+        self.base_client_builder = self.base_client_builder.retries(retries);
         self
     }
 
     #[must_use]
     pub fn native_tls(mut self, native_tls: bool) -> Self {
-        self.native_tls = native_tls;
+        self.base_client_builder = self.base_client_builder.native_tls(native_tls);
         self
     }
 
@@ -109,47 +106,30 @@ impl<'a> RegistryClientBuilder<'a> {
 
     #[must_use]
     pub fn client(mut self, client: Client) -> Self {
-        self.client = Some(client);
+        self.base_client_builder = self.base_client_builder.client(client);
         self
     }
 
     #[must_use]
     pub fn markers(mut self, markers: &'a MarkerEnvironment) -> Self {
-        self.markers = Some(markers);
+        self.base_client_builder = self.base_client_builder.markers(markers);
         self
     }
 
     #[must_use]
     pub fn platform(mut self, platform: &'a Platform) -> Self {
-        self.platform = Some(platform);
+        self.base_client_builder = self.base_client_builder.platform(platform);
         self
     }
 
     pub fn build(self) -> RegistryClient {
         // Build a base client
-        let mut builder = BaseClientBuilder::new();
-
-        if let Some(client) = self.client {
-            builder = builder.client(client)
-        }
-
-        if let Some(markers) = self.markers {
-            builder = builder.markers(markers)
-        }
-
-        if let Some(platform) = self.platform {
-            builder = builder.platform(platform)
-        }
-
-        let client = builder
-            .retries(self.retries)
-            .connectivity(self.connectivity)
-            .native_tls(self.native_tls)
-            .keyring(self.keyring)
-            .build();
+        let client = self.base_client_builder.build();
 
         let timeout = client.timeout();
         let connectivity = client.connectivity();
+        let retry_policy = client.retry_policy().clone();
+        let signature_policy = client.signature_policy().cloned();
 
         // Wrap in the cache middleware.
         let client = CachedClient::new(client);
@@ -161,6 +141,9 @@ impl<'a> RegistryClientBuilder<'a> {
             connectivity,
             client,
             timeout,
+            retry_policy,
+            signature_policy,
+            negotiated: Arc::default(),
         }
     }
 }
@@ -180,8 +163,22 @@ pub struct RegistryClient {
     connectivity: Connectivity,
     /// Configured client timeout, in seconds.
     timeout: u64,
+    /// The policy for retrying failed requests and downloads.
+    retry_policy: RetryPolicy,
+    /// The `--find-links` sources whose distributions must be signed, if any.
+    signature_policy: Option<Arc<SignaturePolicy>>,
+    /// The Simple API media type negotiated for each index, for indexes that failed to serve a
+    /// parseable response in the preferred media type.
+    ///
+    /// Negotiated media types are also persisted to the cache (see [`NEGOTIATED_MEDIA_TYPE`]),
+    /// such that subsequent invocations don't need to renegotiate.
+    negotiated: Arc<Mutex<HashMap<IndexUrl, MediaType>>>,
 }
 
+/// The name of the file in each index's Simple API cache shard that records the media type
+/// negotiated with the index.
+const NEGOTIATED_MEDIA_TYPE: &str = ".media-type";
+
 impl RegistryClient {
     /// Return the [`CachedClient`] used by this client.
     pub fn cached_client(&self) -> &CachedClient {
@@ -203,6 +200,22 @@ impl RegistryClient {
         self.timeout
     }
 
+    /// Return the policy for retrying failed requests and downloads.
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// Return the `--find-links` sources whose distributions must be signed, if any.
+    pub fn signature_policy(&self) -> Option<&SignaturePolicy> {
+        self.signature_policy.as_deref()
+    }
+
+    /// Returns `true` if the distribution at the given URL must be signed.
+    fn is_signed_artifact(&self, url: &Url) -> bool {
+        self.signature_policy()
+            .is_some_and(|policy| policy.is_signed_artifact(url))
+    }
+
     /// Set the index URLs to use for fetching packages.
     #[must_use]
     pub fn with_index_url(self, index_urls: IndexUrls) -> Self {
@@ -219,13 +232,12 @@ impl RegistryClient {
         &self,
         package_name: &PackageName,
     ) -> Result<Vec<(IndexUrl, OwnedArchive<SimpleMetadata>)>, Error> {
-        let mut it = self.index_urls.indexes().peekable();
-        if it.peek().is_none() {
+        if self.index_urls.indexes().next().is_none() {
             return Err(ErrorKind::NoIndex(package_name.as_ref().to_string()).into());
         }
 
         let mut results = Vec::new();
-        for index in it {
+        for index in self.index_urls.indexes_for(package_name) {
             match self.simple_single_index(package_name, index).await? {
                 Ok(metadata) => {
                     results.push((index.clone(), metadata));
@@ -243,6 +255,12 @@ impl RegistryClient {
                         {
                             continue;
                         }
+                        if err.status() == Some(StatusCode::UNAUTHORIZED)
+                            && self.index_urls.flavor(index).unauthorized_is_missing()
+                        {
+                            trace!("Unauthorized response from {index}; skipping");
+                            continue;
+                        }
                         return Err(ErrorKind::from(err).into());
                     }
                     other => return Err(other.into()),
@@ -280,15 +298,15 @@ impl RegistryClient {
 
         trace!("Fetching metadata for {package_name} from {url}");
 
-        let cache_entry = self.cache.entry(
+        let cache_shard = self.cache.shard(
             CacheBucket::Simple,
             Path::new(&match index {
                 IndexUrl::Pypi(_) => "pypi".to_string(),
                 IndexUrl::Url(url) => cache_key::digest(&cache_key::CanonicalUrl::new(url)),
                 IndexUrl::Path(url) => cache_key::digest(&cache_key::CanonicalUrl::new(url)),
             }),
-            format!("{package_name}.rkyv"),
         );
+        let cache_entry = cache_shard.entry(format!("{package_name}.rkyv"));
         let cache_control = match self.connectivity {
             Connectivity::Online => CacheControl::from(
                 self.cache
@@ -298,13 +316,6 @@ impl RegistryClient {
             Connectivity::Offline => CacheControl::AllowStale,
         };
 
-        let simple_request = self
-            .uncached_client()
-            .get(url.clone())
-            .header("Accept-Encoding", "gzip")
-            .header("Accept", MediaType::accepts())
-            .build()
-            .map_err(ErrorKind::from)?;
         let parse_simple_response = |response: Response| {
             async {
                 // Use the response URL, rather than the request URL, as the base for relative URLs.
@@ -347,6 +358,17 @@ impl RegistryClient {
             .boxed()
             .instrument(info_span!("parse_simple_api", package = %package_name))
         };
+
+        // Determine the media types to request, based on the configured API and any media type
+        // previously negotiated with the index.
+        let api = self.index_urls.api(index);
+        let media_type = match api {
+            IndexApi::Auto => self.negotiated_media_type(index, &cache_shard),
+            IndexApi::Json => Some(MediaType::Json),
+            IndexApi::Html => Some(MediaType::Html),
+        };
+
+        let simple_request = self.simple_request(&url, media_type)?;
         let result = self
             .cached_client()
             .get_cacheable(
@@ -356,7 +378,94 @@ impl RegistryClient {
                 parse_simple_response,
             )
             .await;
-        Ok(result)
+
+        // If the index served an unparseable response, and we're free to negotiate, retry with
+        // the other media type.
+        let fallback = match &result {
+            Err(CachedClientError::Callback(err)) if media_type.is_none() => match err.kind() {
+                ErrorKind::BadJson { .. } => Some(MediaType::Html),
+                ErrorKind::BadHtml { .. } => Some(MediaType::Json),
+                _ => None,
+            },
+            _ => None,
+        };
+        let Some(fallback) = fallback else {
+            return Ok(result);
+        };
+
+        warn!("Failed to parse Simple API response from {url}; retrying with {fallback}");
+        let simple_request = self.simple_request(&url, Some(fallback))?;
+        let fallback_result = self
+            .cached_client()
+            .get_cacheable(
+                simple_request,
+                &cache_entry,
+                cache_control,
+                parse_simple_response,
+            )
+            .await;
+        if fallback_result.is_err() {
+            // Report the original error.
+            return Ok(result);
+        }
+
+        // Use the media type that succeeded for all subsequent requests to the index.
+        self.negotiated
+            .lock()
+            .unwrap()
+            .insert(index.clone(), fallback);
+        let negotiated_entry = cache_shard.entry(NEGOTIATED_MEDIA_TYPE);
+        if let Err(err) = uv_fs::write_atomic(negotiated_entry.path(), fallback.as_str()).await {
+            warn!("Failed to persist the negotiated media type for {index}: {err}");
+        }
+        Ok(fallback_result)
+    }
+
+    /// Return the Simple API media type previously negotiated with the index, if any, whether in
+    /// this invocation or (as persisted to the cache) in a prior one.
+    ///
+    /// Persisted media types are ignored when the cache is refreshed, such that an index that has
+    /// since been fixed can be renegotiated.
+    fn negotiated_media_type(
+        &self,
+        index: &IndexUrl,
+        cache_shard: &CacheShard,
+    ) -> Option<MediaType> {
+        let mut negotiated = self.negotiated.lock().unwrap();
+        if let Some(media_type) = negotiated.get(index) {
+            return Some(*media_type);
+        }
+
+        let entry = cache_shard.entry(NEGOTIATED_MEDIA_TYPE);
+        if !self
+            .cache
+            .freshness(&entry, None)
+            .is_ok_and(Freshness::is_fresh)
+        {
+            return None;
+        }
+        let media_type = fs_err::read_to_string(entry.path())
+            .ok()
+            .and_then(|media_type| MediaType::from_str(media_type.trim()))?;
+        trace!("Using previously negotiated {media_type} media type for {index}");
+        negotiated.insert(index.clone(), media_type);
+        Some(media_type)
+    }
+
+    /// Create a request to the Simple API, accepting the given media type, or any supported media
+    /// type if `None`.
+    fn simple_request(&self, url: &Url, media_type: Option<MediaType>) -> Result<Request, Error> {
+        let request = self
+            .uncached_client()
+            .get(url.clone())
+            .header("Accept-Encoding", "gzip")
+            .header(
+                "Accept",
+                media_type.map_or(MediaType::accepts(), MediaType::accept),
+            )
+            .build()
+            .map_err(ErrorKind::from)?;
+        Ok(request)
     }
 
     /// Fetch the metadata for a remote wheel file.
@@ -394,6 +503,7 @@ impl RegistryClient {
                     &wheel.filename,
                     &wheel.url,
                     WheelCache::Url(&wheel.url),
+                    true,
                 )
                 .await?
             }
@@ -424,8 +534,12 @@ impl RegistryClient {
         url: &Url,
     ) -> Result<Metadata23, Error> {
         // If the metadata file is available at its own url (PEP 658), download it from there.
+        //
+        // Signed distributions are read in full, such that their metadata is verified along with
+        // the rest of the distribution.
         let filename = WheelFilename::from_str(&file.filename).map_err(ErrorKind::WheelFilename)?;
-        if file.dist_info_metadata {
+        let signed = self.is_signed_artifact(url);
+        if file.dist_info_metadata && !signed {
             let mut url = url.clone();
             url.set_path(&format!("{}.metadata", url.path()));
 
@@ -469,22 +583,37 @@ impl RegistryClient {
             // If we lack PEP 658 support, try using HTTP range requests to read only the
             // `.dist-info/METADATA` file from the zip, and if that also fails, download the whole wheel
             // into the cache and read from there
-            self.wheel_metadata_no_pep658(&filename, url, WheelCache::Index(index))
-                .await
+            self.wheel_metadata_no_pep658(
+                &filename,
+                url,
+                WheelCache::Index(index),
+                self.index_urls.flavor(index).supports_range_requests() && !signed,
+            )
+            .await
         }
     }
 
     /// Get the wheel metadata if it isn't available in an index through PEP 658
+    ///
+    /// If `range_requests` is `false`, the wheel is downloaded in full, rather than attempting to
+    /// read the metadata via HTTP range requests.
     async fn wheel_metadata_no_pep658<'data>(
         &self,
         filename: &'data WheelFilename,
         url: &'data Url,
         cache_shard: WheelCache<'data>,
+        range_requests: bool,
     ) -> Result<Metadata23, Error> {
+        // Metadata read from a verified distribution is cached separately, such that metadata
+        // cached before the distribution's source required signatures is never used.
         let cache_entry = self.cache.entry(
             CacheBucket::Wheels,
             cache_shard.wheel_dir(filename.name.as_ref()),
-            format!("{}.msgpack", filename.stem()),
+            if self.is_signed_artifact(url) {
+                format!("{}.signed.msgpack", filename.stem())
+            } else {
+                format!("{}.msgpack", filename.stem())
+            },
         );
         let cache_control = match self.connectivity {
             Connectivity::Online => CacheControl::from(
@@ -495,71 +624,75 @@ impl RegistryClient {
             Connectivity::Offline => CacheControl::AllowStale,
         };
 
-        let req = self
-            .uncached_client()
-            .head(url.clone())
-            .header(
-                "accept-encoding",
-                http::HeaderValue::from_static("identity"),
-            )
-            .build()
-            .map_err(ErrorKind::from)?;
+        if range_requests {
+            let req = self
+                .uncached_client()
+                .head(url.clone())
+                .header(
+                    "accept-encoding",
+                    http::HeaderValue::from_static("identity"),
+                )
+                .build()
+                .map_err(ErrorKind::from)?;
 
-        // Copy authorization headers from the HEAD request to subsequent requests
-        let mut headers = HeaderMap::default();
-        if let Some(authorization) = req.headers().get("authorization") {
-            headers.append("authorization", authorization.clone());
-        }
+            // Copy authorization headers from the HEAD request to subsequent requests
+            let mut headers = HeaderMap::default();
+            if let Some(authorization) = req.headers().get("authorization") {
+                headers.append("authorization", authorization.clone());
+            }
 
-        // This response callback is special, we actually make a number of subsequent requests to
-        // fetch the file from the remote zip.
-        let read_metadata_range_request = |response: Response| {
-            async {
-                let mut reader = AsyncHttpRangeReader::from_head_response(
-                    self.uncached_client().client(),
-                    response,
-                    headers,
+            // This response callback is special, we actually make a number of subsequent requests
+            // to fetch the file from the remote zip.
+            let read_metadata_range_request = |response: Response| {
+                async {
+                    let mut reader = AsyncHttpRangeReader::from_head_response(
+                        self.uncached_client().client(),
+                        response,
+                        headers,
+                    )
+                    .await
+                    .map_err(ErrorKind::AsyncHttpRangeReader)?;
+                    trace!("Getting metadata for {filename} by range request");
+                    let text = wheel_metadata_from_remote_zip(filename, &mut reader).await?;
+                    let metadata = Metadata23::parse_metadata(text.as_bytes()).map_err(|err| {
+                        Error::from(ErrorKind::MetadataParseError(
+                            filename.clone(),
+                            url.to_string(),
+                            Box::new(err),
+                        ))
+                    })?;
+                    Ok::<Metadata23, CachedClientError<Error>>(metadata)
+                }
+                .boxed()
+                .instrument(info_span!("read_metadata_range_request", wheel = %filename))
+            };
+
+            let result = self
+                .cached_client()
+                .get_serde(
+                    req,
+                    &cache_entry,
+                    cache_control,
+                    read_metadata_range_request,
                 )
                 .await
-                .map_err(ErrorKind::AsyncHttpRangeReader)?;
-                trace!("Getting metadata for {filename} by range request");
-                let text = wheel_metadata_from_remote_zip(filename, &mut reader).await?;
-                let metadata = Metadata23::parse_metadata(text.as_bytes()).map_err(|err| {
-                    Error::from(ErrorKind::MetadataParseError(
-                        filename.clone(),
-                        url.to_string(),
-                        Box::new(err),
-                    ))
-                })?;
-                Ok::<Metadata23, CachedClientError<Error>>(metadata)
-            }
-            .boxed()
-            .instrument(info_span!("read_metadata_range_request", wheel = %filename))
-        };
-
-        let result = self
-            .cached_client()
-            .get_serde(
-                req,
-                &cache_entry,
-                cache_control,
-                read_metadata_range_request,
-            )
-            .await
-            .map_err(crate::Error::from);
-
-        match result {
-            Ok(metadata) => return Ok(metadata),
-            Err(err) => {
-                if err.is_http_range_requests_unsupported() {
-                    // The range request version failed. Fall back to streaming the file to search
-                    // for the METADATA file.
-                    warn!("Range requests not supported for {filename}; streaming wheel");
-                } else {
-                    return Err(err);
+                .map_err(crate::Error::from);
+
+            match result {
+                Ok(metadata) => return Ok(metadata),
+                Err(err) => {
+                    if err.is_http_range_requests_unsupported() {
+                        // The range request version failed. Fall back to streaming the file to
+                        // search for the METADATA file.
+                        warn!("Range requests not supported for {filename}; streaming wheel");
+                    } else {
+                        return Err(err);
+                    }
                 }
-            }
-        };
+            };
+        } else {
+            trace!("Range requests disabled for {filename}; streaming wheel");
+        }
 
         // Create a request to stream the file.
         let req = self
@@ -821,7 +954,7 @@ impl ArchivedSimpleMetadata {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 enum MediaType {
     Json,
     Html,
@@ -843,6 +976,31 @@ impl MediaType {
         // See: https://peps.python.org/pep-0691/#version-format-selection
         "application/vnd.pypi.simple.v1+json, application/vnd.pypi.simple.v1+html;q=0.2, text/html;q=0.01"
     }
+
+    /// Return the canonical name of this media type.
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Json => "application/vnd.pypi.simple.v1+json",
+            Self::Html => "application/vnd.pypi.simple.v1+html",
+        }
+    }
+
+    /// Return the `Accept` header value for this media type alone.
+    fn accept(self) -> &'static str {
+        match self {
+            Self::Json => "application/vnd.pypi.simple.v1+json",
+            Self::Html => "application/vnd.pypi.simple.v1+html, text/html;q=0.01",
+        }
+    }
+}
+
+impl std::fmt::Display for MediaType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Json => write!(f, "JSON"),
+            Self::Html => write!(f, "HTML"),
+        }
+    }
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -0,0 +1,39 @@
+use reqwest::{Response, StatusCode};
+use reqwest_retry::{Retryable, RetryableStrategy};
+use tracing::debug;
+
+use uv_configuration::{RetryClass, RetryPolicy};
+
+/// A [`RetryableStrategy`] that retries the classes of failure enabled by the [`RetryPolicy`].
+pub(crate) struct PolicyRetryableStrategy(pub(crate) RetryPolicy);
+
+impl RetryableStrategy for PolicyRetryableStrategy {
+    fn handle(&self, res: &Result<Response, reqwest_middleware::Error>) -> Option<Retryable> {
+        let class = match res {
+            Ok(response) => match response.status() {
+                StatusCode::FORBIDDEN => RetryClass::Forbidden,
+                StatusCode::TOO_MANY_REQUESTS => RetryClass::TooManyRequests,
+                StatusCode::REQUEST_TIMEOUT => RetryClass::Timeout,
+                status if status.is_server_error() => RetryClass::ServerError,
+                _ => return None,
+            },
+            Err(reqwest_middleware::Error::Reqwest(err)) => {
+                if err.is_timeout() {
+                    RetryClass::Timeout
+                } else if err.is_connect() || err.is_request() {
+                    RetryClass::Connect
+                } else {
+                    return Some(Retryable::Fatal);
+                }
+            }
+            Err(reqwest_middleware::Error::Middleware(_)) => return Some(Retryable::Fatal),
+        };
+
+        if self.0.is_retryable(class) {
+            debug!("Request failed with a retryable error ({class:?})");
+            Some(Retryable::Transient)
+        } else {
+            Some(Retryable::Fatal)
+        }
+    }
+}
@@ -0,0 +1,411 @@
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use futures::StreamExt;
+use http::{Extensions, Method};
+use minisign_verify::{PublicKey, Signature, StreamVerifier};
+use reqwest::header::RANGE;
+use reqwest::{Request, Response, ResponseBuilderExt};
+use reqwest_middleware::{Middleware, Next};
+use tokio::io::{AsyncSeekExt, AsyncWriteExt};
+use tracing::debug;
+use url::Url;
+
+use distribution_types::{FlatIndexLocation, IndexUrl};
+use uv_fs::Simplified;
+
+/// The `--find-links` sources (local directories or flat indexes) whose distributions must carry
+/// a detached [minisign](https://jedisct1.github.io/minisign/) signature (e.g.,
+/// `anyio-4.3.0-py3-none-any.whl.minisig`) from one of a set of trusted keys.
+#[derive(Debug, Default)]
+pub struct SignaturePolicy {
+    sources: Vec<(FlatIndexLocation, Vec<PublicKey>)>,
+    /// The URLs of the distributions listed by signed flat indexes, mapped to their source.
+    artifacts: Mutex<HashMap<Url, usize>>,
+}
+
+impl SignaturePolicy {
+    /// Require signatures from one of the given (base64-encoded) public keys for a source.
+    pub fn with_source(
+        mut self,
+        location: FlatIndexLocation,
+        keys: &[String],
+    ) -> Result<Self, SignatureError> {
+        if keys.is_empty() {
+            return Err(SignatureError::NoKeys(location.to_string()));
+        }
+        let keys = keys
+            .iter()
+            .map(|key| {
+                PublicKey::from_base64(key.trim())
+                    .map_err(|err| SignatureError::InvalidKey(key.clone(), err))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Local directories are matched by their canonical path, as in `FlatIndexClient`.
+        let location = match location {
+            FlatIndexLocation::Path(path) => {
+                FlatIndexLocation::Path(fs_err::canonicalize(path).map_err(SignatureError::Io)?)
+            }
+            FlatIndexLocation::Url(url) => FlatIndexLocation::Url(url),
+        };
+        self.sources.push((location, keys));
+        Ok(self)
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.sources.is_empty()
+    }
+
+    /// Return the index of the source for the given location, if it requires signatures.
+    fn source(&self, location: &FlatIndexLocation) -> Option<usize> {
+        self.sources
+            .iter()
+            .position(|(source, _)| source == location)
+    }
+
+    /// Return the trusted keys for a local `--find-links` directory, if it requires signatures.
+    pub(crate) fn directory_keys(&self, path: &Path) -> Option<&[PublicKey]> {
+        let index = self.source(&FlatIndexLocation::Path(path.to_path_buf()))?;
+        Some(&self.sources[index].1)
+    }
+
+    /// Record the distributions listed by a flat index, such that they're verified on download.
+    pub(crate) fn register(&self, location: &Url, artifacts: impl IntoIterator<Item = Url>) {
+        if let Some(index) = self.source(&FlatIndexLocation::Url(location.clone())) {
+            let mut registry = self.artifacts.lock().unwrap();
+            for mut artifact in artifacts {
+                artifact.set_fragment(None);
+                registry.insert(artifact, index);
+            }
+        }
+    }
+
+    /// Return the trusted keys for a distribution URL, if it was listed by a signed flat index.
+    fn artifact_keys(&self, url: &Url) -> Option<&[PublicKey]> {
+        let mut url = url.clone();
+        url.set_fragment(None);
+        let index = *self.artifacts.lock().unwrap().get(&url)?;
+        Some(&self.sources[index].1)
+    }
+
+    /// Returns `true` if distributions from the given index must be signed.
+    pub fn is_signed_index(&self, index: &IndexUrl) -> bool {
+        let location = match index {
+            IndexUrl::Pypi(_) => return false,
+            IndexUrl::Url(url) => FlatIndexLocation::Url(url.to_url()),
+            IndexUrl::Path(url) => match url.to_file_path() {
+                Ok(path) => FlatIndexLocation::Path(path),
+                Err(()) => return false,
+            },
+        };
+        self.source(&location).is_some()
+    }
+
+    /// Returns `true` if the distribution at the given URL was listed by a signed flat index, in
+    /// which case it's verified by the client when it's downloaded in full.
+    pub fn is_signed_artifact(&self, url: &Url) -> bool {
+        self.artifact_keys(url).is_some()
+    }
+
+    /// Returns `true` if the given file is in a signed `--find-links` directory.
+    pub fn is_signed_file(&self, path: &Path) -> bool {
+        path.parent()
+            .and_then(|directory| self.directory_keys(directory))
+            .is_some()
+    }
+
+    /// Verify a file in a signed `--find-links` directory against the adjacent signature.
+    ///
+    /// Files outside of any signed directory are accepted as-is.
+    pub fn verify_file(&self, path: &Path) -> Result<(), SignatureError> {
+        match path
+            .parent()
+            .and_then(|directory| self.directory_keys(directory))
+        {
+            Some(keys) => verify_file(keys, path),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Incrementally verify a distribution against its detached signature, with any of the trusted
+/// keys.
+///
+/// Only prehashed signatures (the default for `minisign -S`) can be verified incrementally; legacy
+/// signatures are rejected as untrusted.
+struct Verifier<'a> {
+    verifiers: Vec<StreamVerifier<'a>>,
+}
+
+impl<'a> Verifier<'a> {
+    fn new(keys: &'a [PublicKey], signature: &'a Signature) -> Self {
+        Self {
+            verifiers: keys
+                .iter()
+                .filter_map(|key| key.verify_stream(signature).ok())
+                .collect(),
+        }
+    }
+
+    fn update(&mut self, chunk: &[u8]) {
+        for verifier in &mut self.verifiers {
+            verifier.update(chunk);
+        }
+    }
+
+    fn finalize(mut self, name: &str) -> Result<(), SignatureError> {
+        if self
+            .verifiers
+            .iter_mut()
+            .any(|verifier| verifier.finalize().is_ok())
+        {
+            debug!("Verified signature for: {name}");
+            Ok(())
+        } else {
+            Err(SignatureError::Untrusted(name.to_string()))
+        }
+    }
+}
+
+/// Decode a detached signature.
+fn decode(signature: &str, name: &str) -> Result<Signature, SignatureError> {
+    Signature::decode(signature)
+        .map_err(|err| SignatureError::MalformedSignature(name.to_string(), err))
+}
+
+/// Verify a distribution in a local `--find-links` directory against the adjacent signature.
+pub(crate) fn verify_file(keys: &[PublicKey], path: &Path) -> Result<(), SignatureError> {
+    let name = path.user_display().to_string();
+    let signature_path = signature_path(path);
+    let signature = match fs_err::read_to_string(&signature_path) {
+        Ok(signature) => signature,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            return Err(SignatureError::MissingSignature(
+                signature_path.user_display().to_string(),
+            ));
+        }
+        Err(err) => return Err(SignatureError::Io(err)),
+    };
+    let signature = decode(&signature, &name)?;
+
+    let mut verifier = Verifier::new(keys, &signature);
+    let mut file = fs_err::File::open(path)?;
+    let mut buffer = vec![0; 64 * 1024];
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        verifier.update(&buffer[..read]);
+    }
+    verifier.finalize(&name)
+}
+
+fn signature_path(path: &Path) -> PathBuf {
+    let mut signature = path.as_os_str().to_owned();
+    signature.push(".minisig");
+    PathBuf::from(signature)
+}
+
+/// A middleware that verifies distributions downloaded from signed flat indexes against their
+/// detached signatures, before any of the response is returned.
+///
+/// The distribution is streamed to a temporary file as it's verified, and the response is then
+/// served from that file. Partial (i.e., range) requests can't be verified, and are passed
+/// through; the registry client never issues them for signed distributions (see
+/// [`SignaturePolicy::is_signed_artifact`]).
+pub(crate) struct SignatureMiddleware(pub(crate) Arc<SignaturePolicy>);
+
+#[async_trait::async_trait]
+impl Middleware for SignatureMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let Some(keys) = self.0.artifact_keys(req.url()) else {
+            return next.run(req, extensions).await;
+        };
+        if req.method() != Method::GET || req.headers().contains_key(RANGE) {
+            return next.run(req, extensions).await;
+        }
+
+        let url = req.url().clone();
+        let mut signature_url = url.clone();
+        signature_url.set_path(&format!("{}.minisig", url.path()));
+        let signature_request = req.try_clone().map(|mut request| {
+            *request.url_mut() = signature_url.clone();
+            request
+        });
+
+        let response = next.clone().run(req, extensions).await?;
+        if !response.status().is_success() {
+            return Ok(response);
+        }
+
+        // Fetch the detached signature.
+        let Some(signature_request) = signature_request else {
+            return Err(reqwest_middleware::Error::Middleware(
+                SignatureError::MissingSignature(signature_url.to_string()).into(),
+            ));
+        };
+        let signature_response = next.run(signature_request, extensions).await?;
+        if !signature_response.status().is_success() {
+            return Err(reqwest_middleware::Error::Middleware(
+                SignatureError::MissingSignature(signature_url.to_string()).into(),
+            ));
+        }
+        let signature = signature_response.text().await?;
+        let signature = decode(&signature, url.as_str())
+            .map_err(|err| reqwest_middleware::Error::Middleware(err.into()))?;
+
+        // Stream the distribution to a temporary file, and verify it before returning any of it.
+        let status = response.status();
+        let version = response.version();
+        let headers = response.headers().clone();
+        let mut verifier = Verifier::new(keys, &signature);
+        let mut file = tokio::fs::File::from_std(
+            tempfile::tempfile()
+                .map_err(|err| reqwest_middleware::Error::Middleware(err.into()))?,
+        );
+        let mut stream = response.bytes_stream();
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk?;
+            verifier.update(&chunk);
+            file.write_all(&chunk)
+                .await
+                .map_err(|err| reqwest_middleware::Error::Middleware(err.into()))?;
+        }
+        verifier
+            .finalize(url.as_str())
+            .map_err(|err| reqwest_middleware::Error::Middleware(err.into()))?;
+        file.rewind()
+            .await
+            .map_err(|err| reqwest_middleware::Error::Middleware(err.into()))?;
+
+        let mut builder = http::Response::builder()
+            .status(status)
+            .version(version)
+            .url(url);
+        if let Some(builder_headers) = builder.headers_mut() {
+            *builder_headers = headers;
+        }
+        let response = builder
+            .body(reqwest::Body::from(file))
+            .map_err(|err| reqwest_middleware::Error::Middleware(err.into()))?;
+        Ok(Response::from(response))
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum SignatureError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error("No trusted keys configured for signed `--find-links` source: `{0}`")]
+    NoKeys(String),
+
+    #[error("Invalid minisign public key: `{0}`")]
+    InvalidKey(String, #[source] minisign_verify::Error),
+
+    #[error("Missing signature: `{0}`")]
+    MissingSignature(String),
+
+    #[error("Malformed signature for: `{0}`")]
+    MalformedSignature(String, #[source] minisign_verify::Error),
+
+    #[error("Signature verification failed for `{0}`: not signed by any trusted key")]
+    Untrusted(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &str = "RWQBAgMEBQYHCNcFbLkvi0vaU9s1fusSfDs1c5TiZcTFt/+Hj4bcz/wc";
+    const WHEEL: &str = "anyio-4.3.0-py3-none-any.whl";
+
+    /// Copy the signed fixtures into a directory that requires signatures from [`KEY`].
+    fn signed_directory() -> Result<(tempfile::TempDir, SignaturePolicy), SignatureError> {
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/signature");
+        let temp_dir = tempfile::tempdir()?;
+        for file in [WHEEL, "anyio-4.3.0-py3-none-any.whl.minisig"] {
+            fs_err::copy(fixtures.join(file), temp_dir.path().join(file))?;
+        }
+        let policy = SignaturePolicy::default().with_source(
+            FlatIndexLocation::Path(temp_dir.path().to_path_buf()),
+            &[KEY.to_string()],
+        )?;
+        Ok((temp_dir, policy))
+    }
+
+    #[test]
+    fn signature_path() {
+        assert_eq!(
+            super::signature_path(Path::new("/wheels/anyio-4.3.0-py3-none-any.whl")),
+            PathBuf::from("/wheels/anyio-4.3.0-py3-none-any.whl.minisig")
+        );
+    }
+
+    #[test]
+    fn verify_signed_file() -> Result<(), SignatureError> {
+        let (temp_dir, policy) = signed_directory()?;
+        let wheel = fs_err::canonicalize(temp_dir.path())?.join(WHEEL);
+        assert!(policy.is_signed_file(&wheel));
+        policy.verify_file(&wheel)?;
+
+        // Files outside of the signed directory are accepted as-is.
+        let unsigned = tempfile::tempdir()?;
+        let unsigned = fs_err::canonicalize(unsigned.path())?.join(WHEEL);
+        fs_err::write(&unsigned, "anything")?;
+        assert!(!policy.is_signed_file(&unsigned));
+        policy.verify_file(&unsigned)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn verify_tampered_file() -> Result<(), SignatureError> {
+        let (temp_dir, policy) = signed_directory()?;
+        let wheel = fs_err::canonicalize(temp_dir.path())?.join(WHEEL);
+        fs_err::write(&wheel, "not the signed wheel\n")?;
+        assert!(matches!(
+            policy.verify_file(&wheel),
+            Err(SignatureError::Untrusted(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn verify_missing_signature() -> Result<(), SignatureError> {
+        let (temp_dir, policy) = signed_directory()?;
+        let wheel = fs_err::canonicalize(temp_dir.path())?.join(WHEEL);
+        fs_err::remove_file(signature_path(&wheel))?;
+        assert!(matches!(
+            policy.verify_file(&wheel),
+            Err(SignatureError::MissingSignature(_))
+        ));
+        Ok(())
+    }
+
+    /// Legacy (i.e., not prehashed) signatures can't be verified incrementally, and are rejected.
+    #[test]
+    fn verify_legacy_signature() -> Result<(), SignatureError> {
+        let (temp_dir, policy) = signed_directory()?;
+        let wheel = fs_err::canonicalize(temp_dir.path())?.join(WHEEL);
+        fs_err::copy(
+            Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("tests/fixtures/signature/anyio-4.3.0-py3-none-any.whl.legacy.minisig"),
+            signature_path(&wheel),
+        )?;
+        assert!(matches!(
+            policy.verify_file(&wheel),
+            Err(SignatureError::Untrusted(_))
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,481 @@
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use http::Extensions;
+use reqwest::{Certificate, Client, Request, Response};
+use reqwest_middleware::{Middleware, Next};
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::client::WebPkiServerVerifier;
+use rustls::crypto::{ring, CryptoProvider};
+use rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+use rustls::{ClientConfig, DigitallySignedStruct, OtherError, RootCertStore, SignatureScheme};
+use sha2::{Digest, Sha256};
+use tracing::debug;
+use url::Url;
+
+use uv_fs::Simplified;
+
+/// Hosts for which certificate verification may never be disabled.
+const PROTECTED_HOSTS: &[&str] = &["pypi.org", "files.pythonhosted.org"];
+
+/// Ensure that no two sets of per-host TLS settings apply to the same host.
+pub(crate) fn validate_tls_overrides(overrides: &[TlsOverride]) -> Result<(), TlsError> {
+    for (index, tls) in overrides.iter().enumerate() {
+        if overrides[..index].iter().any(|prev| prev.host == tls.host) {
+            return Err(TlsError::ConflictingHost(tls.host.clone()));
+        }
+    }
+    Ok(())
+}
+
+/// TLS settings that apply to requests for a single host (e.g., that of an index), and no others.
+#[derive(Debug, Clone)]
+pub struct TlsOverride {
+    host: String,
+    certificates: Vec<Certificate>,
+    /// The DER encodings of `certificates`, for the TLS configuration used with pins.
+    roots: Vec<CertificateDer<'static>>,
+    insecure: bool,
+    pins: Vec<CertificatePin>,
+}
+
+impl TlsOverride {
+    /// Create TLS settings for the host of the given URL.
+    pub fn new(url: &Url) -> Result<Self, TlsError> {
+        let host = url
+            .host_str()
+            .ok_or_else(|| TlsError::MissingHost(url.clone()))?
+            .to_ascii_lowercase();
+        Ok(Self {
+            host,
+            certificates: Vec::new(),
+            roots: Vec::new(),
+            insecure: false,
+            pins: Vec::new(),
+        })
+    }
+
+    /// Trust the certificates in the given PEM bundle, in addition to the default roots.
+    pub fn with_ca_bundle(mut self, path: &Path) -> Result<Self, TlsError> {
+        let bundle = fs_err::read(path)?;
+        let certificates = Certificate::from_pem_bundle(&bundle)
+            .map_err(|err| TlsError::InvalidBundle(path.to_path_buf(), err))?;
+        if certificates.is_empty() {
+            return Err(TlsError::EmptyBundle(path.to_path_buf()));
+        }
+        let roots = rustls_pemfile::certs(&mut bundle.as_slice())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| TlsError::InvalidPem(path.to_path_buf(), err))?;
+        self.certificates.extend(certificates);
+        self.roots.extend(roots);
+        Ok(self)
+    }
+
+    /// Skip certificate verification for the host.
+    ///
+    /// If the host also has pins, the certificate must still match one of them.
+    pub fn with_insecure(mut self) -> Result<Self, TlsError> {
+        if PROTECTED_HOSTS.contains(&self.host.as_str()) {
+            return Err(TlsError::ProtectedHost(self.host));
+        }
+        self.insecure = true;
+        Ok(self)
+    }
+
+    /// Require the host's certificate to match one of the given pins.
+    #[must_use]
+    pub fn with_pins(mut self, pins: Vec<CertificatePin>) -> Self {
+        self.pins.extend(pins);
+        self
+    }
+
+    /// The host to which the settings apply.
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    /// Apply the settings to a [`reqwest::ClientBuilder`].
+    ///
+    /// If `native_roots` is set, the platform's root certificates are trusted (in addition to any
+    /// certificates from the bundle); otherwise, the bundled Mozilla roots are trusted.
+    pub(crate) fn apply(
+        &self,
+        builder: reqwest::ClientBuilder,
+        native_roots: bool,
+    ) -> reqwest::ClientBuilder {
+        if self.pins.is_empty() {
+            let builder = self
+                .certificates
+                .iter()
+                .cloned()
+                .fold(builder, reqwest::ClientBuilder::add_root_certificate);
+            return builder.danger_accept_invalid_certs(self.insecure);
+        }
+
+        // Pins are checked during the handshake, such that nothing (e.g., the credentials for an
+        // index) is sent to a server that doesn't match, which requires a custom verifier.
+        let provider = Arc::new(ring::default_provider());
+        let verifier = PinnedServerVerifier::new(self, native_roots, provider.clone());
+        let config = ClientConfig::builder_with_provider(provider)
+            .with_safe_default_protocol_versions()
+            .expect("The default protocol versions are supported by the default provider")
+            .dangerous()
+            .with_custom_certificate_verifier(Arc::new(verifier))
+            .with_no_client_auth();
+        builder.use_preconfigured_tls(config)
+    }
+}
+
+/// A [`ServerCertVerifier`] that verifies the certificate chain as usual (unless verification is
+/// disabled for the host), and then requires the server's certificate to match one of the pins.
+#[derive(Debug)]
+struct PinnedServerVerifier {
+    host: String,
+    pins: Vec<CertificatePin>,
+    /// The verifier for the certificate chain, or `None` if verification is disabled.
+    chain: Option<Result<Arc<WebPkiServerVerifier>, String>>,
+    provider: Arc<CryptoProvider>,
+}
+
+impl PinnedServerVerifier {
+    fn new(tls: &TlsOverride, native_roots: bool, provider: Arc<CryptoProvider>) -> Self {
+        let chain = (!tls.insecure).then(|| {
+            let mut roots = RootCertStore::empty();
+            if native_roots {
+                match rustls_native_certs::load_native_certs() {
+                    Ok(certificates) => {
+                        roots.add_parsable_certificates(certificates);
+                    }
+                    Err(err) => debug!("Failed to load native root certificates: {err}"),
+                }
+            } else {
+                roots.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+            }
+            roots.add_parsable_certificates(tls.roots.iter().cloned());
+            WebPkiServerVerifier::builder_with_provider(Arc::new(roots), provider.clone())
+                .build()
+                .map_err(|err| err.to_string())
+        });
+        Self {
+            host: tls.host.clone(),
+            pins: tls.pins.clone(),
+            chain,
+            provider,
+        }
+    }
+}
+
+impl ServerCertVerifier for PinnedServerVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        intermediates: &[CertificateDer<'_>],
+        server_name: &ServerName<'_>,
+        ocsp_response: &[u8],
+        now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        match &self.chain {
+            Some(Ok(chain)) => {
+                chain.verify_server_cert(
+                    end_entity,
+                    intermediates,
+                    server_name,
+                    ocsp_response,
+                    now,
+                )?;
+            }
+            Some(Err(err)) => return Err(rustls::Error::General(err.clone())),
+            None => {}
+        }
+
+        let certificate = end_entity.as_ref();
+        if self.pins.iter().any(|pin| pin.matches(certificate)) {
+            return Ok(ServerCertVerified::assertion());
+        }
+        let err = TlsError::PinMismatch {
+            host: self.host.clone(),
+            pins: self
+                .pins
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", "),
+            found: subject_public_key_info(certificate).map_or_else(
+                || CertificatePin::Certificate(Sha256::digest(certificate).into()).to_string(),
+                |spki| CertificatePin::PublicKey(Sha256::digest(spki).into()).to_string(),
+            ),
+        };
+        Err(rustls::Error::Other(OtherError(Arc::new(err))))
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls12_signature(
+            message,
+            cert,
+            dss,
+            &self.provider.signature_verification_algorithms,
+        )
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls13_signature(
+            message,
+            cert,
+            dss,
+            &self.provider.signature_verification_algorithms,
+        )
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.provider
+            .signature_verification_algorithms
+            .supported_schemes()
+    }
+}
+
+/// A pin for a server's certificate, as the SHA-256 hash of either its public key (i.e., its
+/// `SubjectPublicKeyInfo`, like `sha256/<base64>`) or the entire certificate (like
+/// `cert-sha256/<base64>`).
+///
+/// Public key pins survive certificate renewals, as long as the key is reused.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CertificatePin {
+    PublicKey([u8; 32]),
+    Certificate([u8; 32]),
+}
+
+impl CertificatePin {
+    /// Returns `true` if the DER-encoded certificate matches the pin.
+    fn matches(&self, certificate: &[u8]) -> bool {
+        match self {
+            Self::PublicKey(hash) => subject_public_key_info(certificate)
+                .is_some_and(|spki| Sha256::digest(spki).as_slice() == hash),
+            Self::Certificate(hash) => Sha256::digest(certificate).as_slice() == hash,
+        }
+    }
+}
+
+impl FromStr for CertificatePin {
+    type Err = TlsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || TlsError::InvalidPin(s.to_string());
+        let (kind, hash): (fn([u8; 32]) -> Self, &str) =
+            if let Some(hash) = s.strip_prefix("sha256/") {
+                (Self::PublicKey, hash)
+            } else if let Some(hash) = s.strip_prefix("cert-sha256/") {
+                (Self::Certificate, hash)
+            } else {
+                return Err(invalid());
+            };
+        let hash = BASE64_STANDARD.decode(hash).map_err(|_| invalid())?;
+        Ok(kind(hash.try_into().map_err(|_| invalid())?))
+    }
+}
+
+impl Display for CertificatePin {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::PublicKey(hash) => write!(f, "sha256/{}", BASE64_STANDARD.encode(hash)),
+            Self::Certificate(hash) => write!(f, "cert-sha256/{}", BASE64_STANDARD.encode(hash)),
+        }
+    }
+}
+
+/// Extract the DER-encoded `SubjectPublicKeyInfo` from a DER-encoded X.509 certificate.
+fn subject_public_key_info(certificate: &[u8]) -> Option<&[u8]> {
+    // Certificate ::= SEQUENCE { tbsCertificate TBSCertificate, ... }
+    let (_, certificate, _) = der_element(certificate)?;
+    let (_, tbs_certificate, _) = der_element(certificate)?;
+
+    // Skip the (optional) version, then the serial number, signature algorithm, issuer,
+    // validity, and subject.
+    let mut rest = tbs_certificate;
+    if rest.first() == Some(&0xA0) {
+        rest = der_element(rest)?.2;
+    }
+    for _ in 0..5 {
+        rest = der_element(rest)?.2;
+    }
+
+    let (tag, _, after) = der_element(rest)?;
+    (tag == 0x30).then(|| &rest[..rest.len() - after.len()])
+}
+
+/// Split a DER element into its tag, its contents, and the remaining input.
+fn der_element(input: &[u8]) -> Option<(u8, &[u8], &[u8])> {
+    let (&tag, input) = input.split_first()?;
+    let (&length, mut input) = input.split_first()?;
+    let length = if length & 0x80 == 0 {
+        usize::from(length)
+    } else {
+        let size = usize::from(length & 0x7F);
+        if size == 0 || size > 4 || input.len() < size {
+            return None;
+        }
+        let (bytes, rest) = input.split_at(size);
+        input = rest;
+        bytes
+            .iter()
+            .fold(0, |length, &byte| (length << 8) | usize::from(byte))
+    };
+    if input.len() < length {
+        return None;
+    }
+    let (contents, rest) = input.split_at(length);
+    Some((tag, contents, rest))
+}
+
+/// A middleware that sends requests for hosts with their own TLS settings via a dedicated client,
+/// such that the settings never apply to other hosts.
+///
+/// Must be the innermost middleware, as it doesn't call any subsequent middleware.
+pub(crate) struct TlsScopeMiddleware {
+    clients: Vec<(TlsOverride, Client)>,
+}
+
+impl TlsScopeMiddleware {
+    pub(crate) fn new(clients: Vec<(TlsOverride, Client)>) -> Self {
+        Self { clients }
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for TlsScopeMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let scope = req.url().host_str().and_then(|host| {
+            self.clients
+                .iter()
+                .find(|(tls, _)| tls.host.eq_ignore_ascii_case(host))
+        });
+        let Some((_, client)) = scope else {
+            return next.run(req, extensions).await;
+        };
+        client
+            .execute(req)
+            .await
+            .map_err(reqwest_middleware::Error::Reqwest)
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum TlsError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error("Index URL has no host: `{0}`")]
+    MissingHost(Url),
+
+    #[error("Failed to read certificates from: `{}`", _0.user_display())]
+    InvalidBundle(PathBuf, #[source] reqwest::Error),
+
+    #[error("Failed to read certificates from: `{}`", _0.user_display())]
+    InvalidPem(PathBuf, #[source] std::io::Error),
+
+    #[error("No certificates found in: `{}`", _0.user_display())]
+    EmptyBundle(PathBuf),
+
+    #[error("Certificate verification can't be disabled for `{0}`")]
+    ProtectedHost(String),
+
+    #[error("Conflicting TLS settings for `{0}`; each host may only be configured once")]
+    ConflictingHost(String),
+
+    #[error(
+        "Invalid certificate pin: `{0}` (expected `sha256/<base64>` or `cert-sha256/<base64>`)"
+    )]
+    InvalidPin(String),
+
+    #[error("Certificate for `{host}` doesn't match any pin (expected one of: {pins}; found: {found}). The connection may be intercepted by a proxy.")]
+    PinMismatch {
+        host: String,
+        pins: String,
+        found: String,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn protected_host() {
+        let url = Url::parse("https://pypi.org/simple").unwrap();
+        assert!(matches!(
+            TlsOverride::new(&url).unwrap().with_insecure(),
+            Err(TlsError::ProtectedHost(_))
+        ));
+
+        let url = Url::parse("https://pypi.lab.example.com/simple").unwrap();
+        let tls = TlsOverride::new(&url).unwrap().with_insecure().unwrap();
+        assert_eq!(tls.host(), "pypi.lab.example.com");
+    }
+
+    #[test]
+    fn conflicting_host() {
+        let first = Url::parse("https://pypi.lab.example.com/simple").unwrap();
+        let second = Url::parse("https://PYPI.lab.example.com/other").unwrap();
+        let overrides = vec![
+            TlsOverride::new(&first).unwrap(),
+            TlsOverride::new(&second).unwrap(),
+        ];
+        assert!(matches!(
+            crate::BaseClientBuilder::new().tls_overrides(overrides),
+            Err(TlsError::ConflictingHost(host)) if host == "pypi.lab.example.com"
+        ));
+    }
+
+    #[test]
+    fn pin() {
+        let pin = "sha256/47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU=";
+        assert_eq!(CertificatePin::from_str(pin).unwrap().to_string(), pin);
+        assert!(CertificatePin::from_str("sha256/AAAA").is_err());
+        assert!(
+            CertificatePin::from_str("md5/47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU=").is_err()
+        );
+    }
+
+    #[test]
+    fn spki() {
+        // A skeletal certificate: a version, serial number, signature algorithm, issuer,
+        // validity, subject, and (empty) public key.
+        let certificate = [
+            0x30, 0x16, // Certificate
+            0x30, 0x14, // TBSCertificate
+            0xA0, 0x03, 0x02, 0x01, 0x02, // Version
+            0x02, 0x01, 0x01, // Serial number
+            0x30, 0x00, // Signature algorithm
+            0x30, 0x00, // Issuer
+            0x30, 0x00, // Validity
+            0x30, 0x00, // Subject
+            0x30, 0x02, 0x05, 0x00, // SubjectPublicKeyInfo
+        ];
+        assert_eq!(
+            subject_public_key_info(&certificate),
+            Some([0x30, 0x02, 0x05, 0x00].as_slice())
+        );
+        assert_eq!(subject_public_key_info(&certificate[..10]), None);
+
+        let pin = CertificatePin::PublicKey(Sha256::digest([0x30, 0x02, 0x05, 0x00]).into());
+        assert!(pin.matches(&certificate));
+    }
+}
@@ -0,0 +1,101 @@
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use anyhow::Result;
+use futures::future;
+use http::header::{ACCEPT, CONTENT_TYPE};
+use http_body_util::Full;
+use hyper::body::Bytes;
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Request, Response};
+use hyper_util::rt::TokioIo;
+use tokio::net::TcpListener;
+
+use distribution_types::{IndexLocations, IndexUrl};
+use uv_cache::Cache;
+use uv_client::RegistryClientBuilder;
+use uv_normalize::PackageName;
+
+/// Verify that we fall back to the HTML API for an index that serves invalid JSON, and continue to
+/// request HTML from that index thereafter.
+#[tokio::test]
+async fn test_simple_api_json_fallback() -> Result<()> {
+    // Set up the TCP listener on a random available port
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+
+    // Count the requests that prefer the JSON API.
+    let json_requests = Arc::new(AtomicUsize::new(0));
+
+    // Spawn the server loop in a background task
+    let counter = json_requests.clone();
+    tokio::spawn(async move {
+        loop {
+            let (socket, _) = listener.accept().await.unwrap();
+            let counter = counter.clone();
+            let svc = service_fn(move |req: Request<hyper::body::Incoming>| {
+                let prefers_json = req
+                    .headers()
+                    .get(ACCEPT)
+                    .and_then(|v| v.to_str().ok())
+                    .is_some_and(|v| v.starts_with("application/vnd.pypi.simple.v1+json"));
+                let response = if prefers_json {
+                    // Serve a broken JSON response.
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    Response::builder()
+                        .header(CONTENT_TYPE, "application/vnd.pypi.simple.v1+json")
+                        .body(Full::new(Bytes::from("{\"files\": [")))
+                        .unwrap()
+                } else {
+                    // Serve a valid HTML response.
+                    let name = req.uri().path().trim_matches('/').rsplit('/').next();
+                    let name = name.unwrap_or_default().to_string();
+                    Response::builder()
+                        .header(CONTENT_TYPE, "text/html")
+                        .body(Full::new(Bytes::from(format!(
+                            "<a href=\"/files/{name}-1.0.0.tar.gz\">{name}-1.0.0.tar.gz</a>"
+                        ))))
+                        .unwrap()
+                };
+                future::ok::<_, hyper::Error>(response)
+            });
+            let socket = TokioIo::new(socket);
+            tokio::task::spawn(async move {
+                http1::Builder::new()
+                    .serve_connection(socket, svc)
+                    .await
+                    .expect("Server Started");
+            });
+        }
+    });
+
+    // Initialize uv-client
+    let index = IndexUrl::from_str(&format!("http://{addr}/simple"))?;
+    let index_locations = IndexLocations::new(Some(index), Vec::new(), Vec::new(), false);
+    let cache = Cache::temp()?;
+    let client = RegistryClientBuilder::new(cache.clone())
+        .index_urls(index_locations.index_urls())
+        .build();
+
+    // The first request falls back to the HTML API.
+    let results = client.simple(&PackageName::from_str("foo")?).await?;
+    assert_eq!(results.len(), 1);
+    assert_eq!(json_requests.load(Ordering::SeqCst), 1);
+
+    // Subsequent requests use the HTML API directly.
+    let results = client.simple(&PackageName::from_str("bar")?).await?;
+    assert_eq!(results.len(), 1);
+    assert_eq!(json_requests.load(Ordering::SeqCst), 1);
+
+    // As do requests from a new client that shares the cache.
+    let client = RegistryClientBuilder::new(cache)
+        .index_urls(index_locations.index_urls())
+        .build();
+    let results = client.simple(&PackageName::from_str("baz")?).await?;
+    assert_eq!(results.len(), 1);
+    assert_eq!(json_requests.load(Ordering::SeqCst), 1);
+
+    Ok(())
+}
@@ -0,0 +1,118 @@
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use anyhow::Result;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio_rustls::rustls::crypto::ring;
+use tokio_rustls::rustls::ServerConfig;
+use tokio_rustls::TlsAcceptor;
+use url::Url;
+
+use uv_client::{BaseClientBuilder, CertificatePin, TlsOverride};
+
+/// The SHA-256 hash of the public key of `fixtures/tls/localhost.pem`.
+const PIN: &str = "sha256/GVZHowsp8b0OiNuImUNGPWIE4obRB8CXLaCulv6GGNY=";
+
+/// Start an HTTPS server for `127.0.0.1` that answers every request with an empty response,
+/// recording whether any request was received.
+async fn serve(requested: Arc<AtomicBool>) -> Result<Url> {
+    let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/tls");
+    let certificates =
+        rustls_pemfile::certs(&mut fs_err::read(fixtures.join("localhost.pem"))?.as_slice())
+            .collect::<Result<Vec<_>, _>>()?;
+    let key = rustls_pemfile::private_key(
+        &mut fs_err::read(fixtures.join("localhost-key.pem"))?.as_slice(),
+    )?
+    .expect("The fixture contains a private key");
+    let config = ServerConfig::builder_with_provider(Arc::new(ring::default_provider()))
+        .with_safe_default_protocol_versions()?
+        .with_no_client_auth()
+        .with_single_cert(certificates, key)?;
+    let acceptor = TlsAcceptor::from(Arc::new(config));
+
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+    tokio::spawn(async move {
+        loop {
+            let (stream, _) = listener.accept().await.unwrap();
+            let acceptor = acceptor.clone();
+            let requested = requested.clone();
+            tokio::spawn(async move {
+                let Ok(mut stream) = acceptor.accept(stream).await else {
+                    return;
+                };
+                let mut buffer = vec![0; 4096];
+                if stream.read(&mut buffer).await.is_ok_and(|read| read > 0) {
+                    requested.store(true, Ordering::SeqCst);
+                    stream
+                        .write_all(
+                            b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+                        )
+                        .await
+                        .ok();
+                    stream.shutdown().await.ok();
+                }
+            });
+        }
+    });
+
+    Ok(Url::parse(&format!(
+        "https://127.0.0.1:{}/simple/",
+        addr.port()
+    ))?)
+}
+
+/// Send a request with credentials to the server, pinning its certificate to the given pin.
+async fn request(url: &Url, pin: &str) -> Result<reqwest::Response, reqwest_middleware::Error> {
+    let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/tls");
+    let tls = TlsOverride::new(url)
+        .unwrap()
+        .with_ca_bundle(&fixtures.join("ca.pem"))
+        .unwrap()
+        .with_pins(vec![CertificatePin::from_str(pin).unwrap()]);
+    let client = BaseClientBuilder::new()
+        .retries(0)
+        .tls_overrides(vec![tls])
+        .unwrap()
+        .build();
+
+    let mut url = url.clone();
+    url.set_username("user").unwrap();
+    url.set_password(Some("password")).unwrap();
+    client.client().get(url).send().await
+}
+
+#[tokio::test]
+async fn pin_match() -> Result<()> {
+    let requested = Arc::new(AtomicBool::new(false));
+    let url = serve(requested.clone()).await?;
+
+    let response = request(&url, PIN).await?;
+    assert!(response.status().is_success());
+    assert!(requested.load(Ordering::SeqCst));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn pin_mismatch() -> Result<()> {
+    let requested = Arc::new(AtomicBool::new(false));
+    let url = serve(requested.clone()).await?;
+
+    // The handshake must fail before the request (and its credentials) are sent.
+    let err = request(&url, "sha256/47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU=")
+        .await
+        .unwrap_err();
+    let err = anyhow::Error::from(err);
+    assert!(
+        err.chain()
+            .any(|cause| cause.to_string().contains("doesn't match any pin")),
+        "{err:?}"
+    );
+    assert!(!requested.load(Ordering::SeqCst));
+
+    Ok(())
+}
@@ -4,6 +4,8 @@ use std::{
     str::FromStr,
 };
 
+use cache_key::{CacheKey, CacheKeyHasher};
+
 #[derive(Debug, Clone)]
 pub struct ConfigSettingEntry {
     /// The key of the setting. For example, given `key=value`, this would be `key`.
@@ -36,6 +38,21 @@ enum ConfigSettingValue {
     List(Vec<String>),
 }
 
+impl CacheKey for ConfigSettingValue {
+    fn cache_key(&self, state: &mut CacheKeyHasher) {
+        match self {
+            ConfigSettingValue::String(value) => {
+                0u8.cache_key(state);
+                value.cache_key(state);
+            }
+            ConfigSettingValue::List(values) => {
+                1u8.cache_key(state);
+                values.cache_key(state);
+            }
+        }
+    }
+}
+
 #[cfg(feature = "serde")]
 impl serde::Serialize for ConfigSettingValue {
     fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
@@ -108,6 +125,12 @@ impl FromIterator<ConfigSettingEntry> for ConfigSettings {
     }
 }
 
+impl CacheKey for ConfigSettings {
+    fn cache_key(&self, state: &mut CacheKeyHasher) {
+        self.0.cache_key(state);
+    }
+}
+
 #[cfg(feature = "serde")]
 impl ConfigSettings {
     /// Convert the settings to a string that can be passed directly to a PEP 517 build backend.
@@ -0,0 +1,21 @@
+/// The strategy for choosing between IPv4 and IPv6 addresses when connecting to a host.
+///
+/// When both address families are available, connections are attempted in parallel ("Happy
+/// Eyeballs"), starting with the preferred family.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum IpStrategy {
+    /// Use the addresses in the order returned by the resolver.
+    #[default]
+    Auto,
+    /// Prefer IPv4 addresses, falling back to IPv6.
+    PreferIpv4,
+    /// Prefer IPv6 addresses, falling back to IPv4.
+    PreferIpv6,
+    /// Only connect via IPv4.
+    Ipv4Only,
+    /// Only connect via IPv6.
+    Ipv6Only,
+}
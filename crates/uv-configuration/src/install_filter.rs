@@ -0,0 +1,121 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use pep508_rs::PackageName;
+
+/// A glob pattern that matches package names, e.g., `django-*`.
+///
+/// Like package names, patterns are normalized, such that `Django_*` and `django-*` are
+/// equivalent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackageNamePattern(glob::Pattern);
+
+impl PackageNamePattern {
+    /// Returns `true` if the pattern matches the given package name.
+    pub fn matches(&self, name: &PackageName) -> bool {
+        self.0.matches(name.as_ref())
+    }
+}
+
+impl FromStr for PackageNamePattern {
+    type Err = glob::PatternError;
+
+    fn from_str(pattern: &str) -> Result<Self, Self::Err> {
+        // Normalize the pattern per PEP 503, leaving any glob syntax intact.
+        let mut normalized = String::with_capacity(pattern.len());
+        let mut last = None;
+        for char in pattern.chars() {
+            if matches!(char, '-' | '_' | '.') {
+                if last != Some('-') {
+                    normalized.push('-');
+                }
+                last = Some('-');
+            } else {
+                normalized.push(char.to_ascii_lowercase());
+                last = Some(char);
+            }
+        }
+        Ok(Self(glob::Pattern::new(&normalized)?))
+    }
+}
+
+impl Display for PackageNamePattern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// The subset of the requirements to install when syncing an environment.
+#[derive(Debug, Clone, Default)]
+pub struct InstallFilter {
+    /// Only install packages that match any of these patterns.
+    only: Vec<PackageNamePattern>,
+    /// Don't install packages that match any of these patterns.
+    exclude: Vec<PackageNamePattern>,
+}
+
+impl InstallFilter {
+    /// Determine the install filter from the command-line arguments.
+    pub fn from_args(
+        only_package: Vec<PackageNamePattern>,
+        no_install_package: Vec<PackageNamePattern>,
+    ) -> Self {
+        Self {
+            only: only_package,
+            exclude: no_install_package,
+        }
+    }
+
+    /// Returns `true` if all packages should be installed.
+    pub fn is_none(&self) -> bool {
+        self.only.is_empty() && self.exclude.is_empty()
+    }
+
+    /// Returns `true` if the given package should be installed.
+    pub fn includes(&self, name: &PackageName) -> bool {
+        (self.only.is_empty() || self.only.iter().any(|pattern| pattern.matches(name)))
+            && !self.excludes(name)
+    }
+
+    /// Returns `true` if the given package was excluded explicitly (as opposed to not being
+    /// selected).
+    pub fn excludes(&self, name: &PackageName) -> bool {
+        self.exclude.iter().any(|pattern| pattern.matches(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn name(name: &str) -> PackageName {
+        PackageName::from_str(name).unwrap()
+    }
+
+    #[test]
+    fn pattern() {
+        let pattern = PackageNamePattern::from_str("Django_*").unwrap();
+        assert!(pattern.matches(&name("django-rest-framework")));
+        assert!(pattern.matches(&name("django-stubs")));
+        assert!(!pattern.matches(&name("django")));
+
+        let pattern = PackageNamePattern::from_str("zope.*").unwrap();
+        assert!(pattern.matches(&name("zope-interface")));
+    }
+
+    #[test]
+    fn filter() {
+        let filter = InstallFilter::from_args(
+            vec![PackageNamePattern::from_str("flask*").unwrap()],
+            vec![PackageNamePattern::from_str("flask-sqlalchemy").unwrap()],
+        );
+        assert!(filter.includes(&name("flask")));
+        assert!(filter.includes(&name("flask-login")));
+        assert!(!filter.includes(&name("flask-sqlalchemy")));
+        assert!(!filter.includes(&name("werkzeug")));
+        assert!(!filter.excludes(&name("werkzeug")));
+        assert!(filter.excludes(&name("flask-sqlalchemy")));
+
+        assert!(InstallFilter::default().includes(&name("werkzeug")));
+    }
+}
@@ -2,14 +2,30 @@ pub use authentication::*;
 pub use build_options::*;
 pub use config_settings::*;
 pub use constraints::*;
+pub use dns::*;
+pub use install_filter::*;
 pub use name_specifiers::*;
 pub use overrides::*;
 pub use package_options::*;
+pub use retry::*;
+pub use script_conflicts::*;
+pub use sync_layer::*;
+pub use tag_policy::*;
+pub use target_platform::*;
+pub use update_channel::*;
 
 mod authentication;
 mod build_options;
 mod config_settings;
 mod constraints;
+mod dns;
+mod install_filter;
 mod name_specifiers;
 mod overrides;
 mod package_options;
+mod retry;
+mod script_conflicts;
+mod sync_layer;
+mod tag_policy;
+mod target_platform;
+mod update_channel;
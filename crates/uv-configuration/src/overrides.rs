@@ -3,8 +3,8 @@ use std::hash::BuildHasherDefault;
 use itertools::Either;
 use rustc_hash::FxHashMap;
 
-use pep508_rs::Requirement;
-use uv_normalize::PackageName;
+use pep508_rs::{MarkerEnvironment, Requirement};
+use uv_normalize::{ExtraName, PackageName};
 
 /// A set of overrides for a set of requirements.
 #[derive(Debug, Default, Clone)]
@@ -47,4 +47,37 @@ impl Overrides {
             }
         })
     }
+
+    /// Apply the overrides to a set of requirements, retaining only the requirements that are
+    /// relevant to the given marker environment and extras.
+    ///
+    /// An override replaces the requirement wholesale, including its extras and markers. However,
+    /// if none of the overrides for a package apply to the environment (e.g., the overrides are
+    /// scoped to other Python versions), the original requirement is retained.
+    pub fn apply_markers<'a>(
+        &'a self,
+        requirements: impl IntoIterator<Item = &'a Requirement>,
+        markers: &'a MarkerEnvironment,
+        extras: &'a [ExtraName],
+    ) -> impl Iterator<Item = &'a Requirement> {
+        requirements
+            .into_iter()
+            .flat_map(move |requirement| match self.get(&requirement.name) {
+                Some(overrides)
+                    if overrides
+                        .iter()
+                        .any(|override_| override_.evaluate_markers(markers, extras)) =>
+                {
+                    Either::Left(
+                        overrides
+                            .iter()
+                            .filter(move |override_| override_.evaluate_markers(markers, extras)),
+                    )
+                }
+                _ => Either::Right(
+                    std::iter::once(requirement)
+                        .filter(move |requirement| requirement.evaluate_markers(markers, extras)),
+                ),
+            })
+    }
 }
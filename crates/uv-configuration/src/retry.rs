@@ -0,0 +1,83 @@
+use std::time::Duration;
+
+/// A class of failure that may be retried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum RetryClass {
+    /// The connection failed, or the request couldn't be sent.
+    Connect,
+    /// The request timed out, or the server responded with `408 Request Timeout`.
+    Timeout,
+    /// The server responded with `403 Forbidden` (e.g., due to an expiring token).
+    Forbidden,
+    /// The server responded with `429 Too Many Requests`.
+    TooManyRequests,
+    /// The server responded with a `5xx` status code.
+    ServerError,
+    /// A downloaded wheel didn't match its expected hash (e.g., due to a corrupting proxy).
+    HashMismatch,
+}
+
+/// The policy for retrying failed network requests and downloads.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// The maximum number of retries for a single request or download.
+    pub retries: u32,
+    /// The minimum delay before a retry.
+    pub min_backoff: Duration,
+    /// The maximum delay before a retry.
+    pub max_backoff: Duration,
+    /// The classes of failure that are retried.
+    pub retryable: Vec<RetryClass>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            retries: 3,
+            min_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(30 * 60),
+            retryable: vec![
+                RetryClass::Connect,
+                RetryClass::Timeout,
+                RetryClass::TooManyRequests,
+                RetryClass::ServerError,
+            ],
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Returns `true` if the given class of failure should be retried.
+    pub fn is_retryable(&self, class: RetryClass) -> bool {
+        self.retryable.contains(&class)
+    }
+
+    /// The delay before the given retry (starting from zero), doubling after each attempt.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        self.min_backoff
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_backoff)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff() {
+        let policy = RetryPolicy {
+            min_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(3),
+            ..RetryPolicy::default()
+        };
+        assert_eq!(policy.backoff(0), Duration::from_millis(500));
+        assert_eq!(policy.backoff(1), Duration::from_secs(1));
+        assert_eq!(policy.backoff(2), Duration::from_secs(2));
+        assert_eq!(policy.backoff(3), Duration::from_secs(3));
+        assert_eq!(policy.backoff(64), Duration::from_secs(3));
+    }
+}
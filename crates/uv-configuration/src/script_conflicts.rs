@@ -0,0 +1,16 @@
+/// The strategy to use when installing a console or GUI script that's also provided by another
+/// package in the environment.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum ScriptConflictStrategy {
+    /// Warn about the conflict, and install the script anyway, overwriting the existing script.
+    #[default]
+    Warn,
+    /// Fail the installation before making any changes to the environment.
+    Error,
+    /// Install the script under a different name, by appending the name of the package that
+    /// provides it (e.g., `black-black-fork`).
+    Rename,
+}
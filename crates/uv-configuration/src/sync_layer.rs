@@ -0,0 +1,10 @@
+/// A layer of the environment to sync, such that a project and its dependencies can be installed
+/// in separate steps (e.g., as separate layers of a Docker image).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum SyncLayer {
+    /// The dependencies, i.e., all requirements other than editables and local directories.
+    Deps,
+    /// The project itself, i.e., any editables and local directories.
+    Project,
+}
@@ -0,0 +1,13 @@
+/// The policy to use when choosing among the wheels that are compatible with the target.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum TagPolicy {
+    /// Prefer the most specific compatible wheel (e.g., `cp312-cp312` over `cp38-abi3`).
+    #[default]
+    Default,
+    /// Prefer `abi3` wheels over version-specific wheels, such that the same wheel can be shared
+    /// across Python versions.
+    PreferAbi3,
+}
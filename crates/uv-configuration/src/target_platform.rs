@@ -0,0 +1,73 @@
+use pep440_rs::Version;
+use pep508_rs::{MarkerEnvironment, StringVersion};
+use platform_tags::{Arch, Os, Platform};
+
+/// A platform for which to resolve, in lieu of the platform of the current interpreter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum TargetPlatform {
+    /// Pyodide 0.26, i.e., CPython 3.12 compiled to WebAssembly with Emscripten 3.1.58, for
+    /// running Python in the browser.
+    Pyodide,
+}
+
+impl TargetPlatform {
+    /// Return the [`Platform`] used to select wheels for the target.
+    pub fn platform(&self) -> Platform {
+        match self {
+            Self::Pyodide => Platform::new(
+                Os::Pyodide {
+                    major: 2024,
+                    minor: 0,
+                },
+                Arch::Wasm32,
+            ),
+        }
+    }
+
+    /// Return the Python version (as a `(major, minor)` tuple) that ships with the target.
+    pub fn python_tuple(&self) -> (u8, u8) {
+        match self {
+            Self::Pyodide => (3, 12),
+        }
+    }
+
+    /// Return the [`MarkerEnvironment`] of the target.
+    ///
+    /// See: <https://pyodide.org/en/stable/usage/wasm-constraints.html>
+    pub fn markers(&self) -> MarkerEnvironment {
+        match self {
+            Self::Pyodide => MarkerEnvironment {
+                implementation_name: "cpython".to_string(),
+                implementation_version: string_version([3, 12, 1]),
+                os_name: "posix".to_string(),
+                platform_machine: "wasm32".to_string(),
+                platform_python_implementation: "CPython".to_string(),
+                platform_release: "3.1.58".to_string(),
+                platform_system: "Emscripten".to_string(),
+                platform_version: "#1".to_string(),
+                python_full_version: string_version([3, 12, 1]),
+                python_version: string_version([3, 12]),
+                sys_platform: "emscripten".to_string(),
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for TargetPlatform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pyodide => write!(f, "pyodide"),
+        }
+    }
+}
+
+fn string_version<const N: usize>(release: [u64; N]) -> StringVersion {
+    let version = Version::new(release);
+    StringVersion {
+        string: version.to_string(),
+        version,
+    }
+}
@@ -0,0 +1,14 @@
+/// The release channel to follow when updating the `uv` executable.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum UpdateChannel {
+    /// Update to the latest stable release.
+    #[default]
+    Stable,
+    /// Update to the latest release, including pre-releases.
+    Preview,
+    /// Update to the rolling `nightly` release, built from the latest commit on `main`.
+    Nightly,
+}
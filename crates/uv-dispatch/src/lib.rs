@@ -134,12 +134,16 @@ impl<'a> BuildContext for BuildDispatch<'a> {
         self.setup_py
     }
 
+    fn config_settings(&self) -> &ConfigSettings {
+        self.config_settings
+    }
+
     async fn resolve<'data>(&'data self, requirements: &'data [Requirement]) -> Result<Resolution> {
         let markers = self.interpreter.markers();
         let tags = self.interpreter.tags()?;
         let resolver = Resolver::new(
             Manifest::simple(requirements.to_vec()),
-            self.options,
+            self.options.clone(),
             markers,
             self.interpreter,
             tags,
@@ -308,6 +312,7 @@ impl<'a> BuildContext for BuildDispatch<'a> {
             self.interpreter,
             self,
             self.source_build_context.clone(),
+            dist.map(SourceDist::name),
             version_id.to_string(),
             self.setup_py,
             self.config_settings.clone(),
@@ -9,12 +9,26 @@ pub struct Archive {
     pub id: ArchiveId,
     /// The computed hashes of the archive.
     pub hashes: Vec<HashDigest>,
+    /// Whether the archive was verified against a detached signature, as required for
+    /// distributions from signed `--find-links` sources.
+    #[serde(default)]
+    pub signed: bool,
 }
 
 impl Archive {
     /// Create a new [`Archive`] with the given ID and hashes.
     pub(crate) fn new(id: ArchiveId, hashes: Vec<HashDigest>) -> Self {
-        Self { id, hashes }
+        Self {
+            id,
+            hashes,
+            signed: false,
+        }
+    }
+
+    /// Mark the archive as verified against a detached signature.
+    #[must_use]
+    pub(crate) fn with_signed(self, signed: bool) -> Self {
+        Self { signed, ..self }
     }
 }
 
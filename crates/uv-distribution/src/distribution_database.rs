@@ -1,5 +1,5 @@
 use std::io;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use futures::{FutureExt, TryStreamExt};
@@ -147,6 +147,34 @@ impl<'a, Context: BuildContext + Send + Sync> DistributionDatabase<'a, Context>
         Ok((wheel, metadata))
     }
 
+    /// Remove a remote wheel from the HTTP cache, such that it's downloaded again on the next
+    /// request (e.g., to retry a download with a mismatched hash).
+    ///
+    /// Local wheels aren't cached over HTTP, and so are left as-is.
+    pub fn evict_wheel(&self, dist: &BuiltDist) -> Result<(), Error> {
+        let (wheel_cache, filename) = match dist {
+            BuiltDist::Registry(wheel) => {
+                if matches!(wheel.file.url, FileLocation::Path(_)) {
+                    return Ok(());
+                }
+                (WheelCache::Index(&wheel.index), &wheel.filename)
+            }
+            BuiltDist::DirectUrl(wheel) => (WheelCache::Url(&wheel.url), &wheel.filename),
+            BuiltDist::Path(_) => return Ok(()),
+        };
+
+        let http_entry = self.build_context.cache().entry(
+            CacheBucket::Wheels,
+            wheel_cache.wheel_dir(filename.name.as_ref()),
+            format!("{}.http", filename.stem()),
+        );
+        match fs_err::remove_file(http_entry.path()) {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(Error::CacheWrite(err)),
+        }
+    }
+
     /// Fetch a wheel from the cache or download it from the index.
     ///
     /// While hashes will be generated in all cases, hash-checking is _not_ enforced and should
@@ -193,9 +221,23 @@ impl<'a, Context: BuildContext + Send + Sync> DistributionDatabase<'a, Context>
                     wheel.filename.stem(),
                 );
 
+                // Distributions from signed flat indexes are verified by the client as they're
+                // downloaded.
+                let signed = self
+                    .client
+                    .signature_policy()
+                    .is_some_and(|policy| policy.is_signed_artifact(&url));
+
                 // Download and unzip.
                 match self
-                    .stream_wheel(url.clone(), &wheel.filename, &wheel_entry, dist, hashes)
+                    .stream_wheel(
+                        url.clone(),
+                        &wheel.filename,
+                        &wheel_entry,
+                        dist,
+                        hashes,
+                        signed,
+                    )
                     .await
                 {
                     Ok(archive) => Ok(LocalWheel {
@@ -212,7 +254,14 @@ impl<'a, Context: BuildContext + Send + Sync> DistributionDatabase<'a, Context>
                         // If the request failed because streaming is unsupported, download the
                         // wheel directly.
                         let archive = self
-                            .download_wheel(url, &wheel.filename, &wheel_entry, dist, hashes)
+                            .download_wheel(
+                                url,
+                                &wheel.filename,
+                                &wheel_entry,
+                                dist,
+                                hashes,
+                                signed,
+                            )
                             .await?;
                         Ok(LocalWheel {
                             dist: Dist::Built(dist.clone()),
@@ -241,6 +290,7 @@ impl<'a, Context: BuildContext + Send + Sync> DistributionDatabase<'a, Context>
                         &wheel_entry,
                         dist,
                         hashes,
+                        false,
                     )
                     .await
                 {
@@ -264,6 +314,7 @@ impl<'a, Context: BuildContext + Send + Sync> DistributionDatabase<'a, Context>
                                 &wheel_entry,
                                 dist,
                                 hashes,
+                                false,
                             )
                             .await?;
                         Ok(LocalWheel {
@@ -409,7 +460,22 @@ impl<'a, Context: BuildContext + Send + Sync> DistributionDatabase<'a, Context>
         Ok(metadata)
     }
 
+    /// Download and unpack a source distribution into the cache, without building it, and return
+    /// the path to its source tree.
+    pub async fn unpack_source_dist(
+        &self,
+        dist: &SourceDist,
+        hashes: HashPolicy<'_>,
+    ) -> Result<PathBuf, Error> {
+        let source = BuildableSource::Dist(dist);
+        let lock = self.locks.acquire(&source).await;
+        let _guard = lock.lock().await;
+
+        self.builder.download_and_unpack(dist, hashes).boxed().await
+    }
+
     /// Stream a wheel from a URL, unzipping it into the cache as it's downloaded.
+    #[allow(clippy::too_many_arguments)]
     async fn stream_wheel(
         &self,
         url: Url,
@@ -417,6 +483,7 @@ impl<'a, Context: BuildContext + Send + Sync> DistributionDatabase<'a, Context>
         wheel_entry: &CacheEntry,
         dist: &BuiltDist,
         hashes: HashPolicy<'_>,
+        signed: bool,
     ) -> Result<Archive, Error> {
         // Create an entry for the HTTP cache.
         let http_entry = wheel_entry.with_file(format!("{}.http", filename.stem()));
@@ -451,25 +518,17 @@ impl<'a, Context: BuildContext + Send + Sync> DistributionDatabase<'a, Context>
                     .await
                     .map_err(Error::CacheRead)?;
 
-                Ok(Archive::new(
-                    id,
-                    hashers.into_iter().map(HashDigest::from).collect(),
-                ))
+                Ok(
+                    Archive::new(id, hashers.into_iter().map(HashDigest::from).collect())
+                        .with_signed(signed),
+                )
             }
             .instrument(info_span!("wheel", wheel = %dist))
         };
 
         // Fetch the archive from the cache, or download it if necessary.
         let req = self.request(url.clone())?;
-        let cache_control = match self.client.connectivity() {
-            Connectivity::Online => CacheControl::from(
-                self.build_context
-                    .cache()
-                    .freshness(&http_entry, Some(&filename.name))
-                    .map_err(Error::CacheRead)?,
-            ),
-            Connectivity::Offline => CacheControl::AllowStale,
-        };
+        let cache_control = self.wheel_cache_control(&http_entry, filename, dist)?;
         let archive = self
             .client
             .cached_client()
@@ -480,8 +539,9 @@ impl<'a, Context: BuildContext + Send + Sync> DistributionDatabase<'a, Context>
                 CachedClientError::Client(err) => Error::Client(err),
             })?;
 
-        // If the archive is missing the required hashes, force a refresh.
-        let archive = if archive.has_digests(hashes) {
+        // If the archive is missing the required hashes, or wasn't verified against its
+        // signature (e.g., it was cached before its source required signatures), force a refresh.
+        let archive = if archive.has_digests(hashes) && (archive.signed || !signed) {
             archive
         } else {
             self.client
@@ -497,7 +557,37 @@ impl<'a, Context: BuildContext + Send + Sync> DistributionDatabase<'a, Context>
         Ok(archive)
     }
 
+    /// Determine the [`CacheControl`] for a remote wheel.
+    ///
+    /// Wheels from a registry are refreshed according to the cache's freshness, while wheels at a
+    /// direct URL are revalidated against their recorded validator (i.e., `ETag` or
+    /// `Last-Modified`), and can be refreshed individually via `--refresh-url`.
+    fn wheel_cache_control(
+        &self,
+        http_entry: &CacheEntry,
+        filename: &WheelFilename,
+        dist: &BuiltDist,
+    ) -> Result<CacheControl, Error> {
+        if let Connectivity::Offline = self.client.connectivity() {
+            return Ok(CacheControl::AllowStale);
+        }
+        let cache = self.build_context.cache();
+        match dist {
+            BuiltDist::DirectUrl(wheel) => Ok(CacheControl::artifact(
+                cache
+                    .url_freshness(http_entry, Some(&filename.name), wheel.url.raw())
+                    .map_err(Error::CacheRead)?,
+            )),
+            BuiltDist::Registry(_) | BuiltDist::Path(_) => Ok(CacheControl::from(
+                cache
+                    .freshness(http_entry, Some(&filename.name))
+                    .map_err(Error::CacheRead)?,
+            )),
+        }
+    }
+
     /// Download a wheel from a URL, then unzip it into the cache.
+    #[allow(clippy::too_many_arguments)]
     async fn download_wheel(
         &self,
         url: Url,
@@ -505,6 +595,7 @@ impl<'a, Context: BuildContext + Send + Sync> DistributionDatabase<'a, Context>
         wheel_entry: &CacheEntry,
         dist: &BuiltDist,
         hashes: HashPolicy<'_>,
+        signed: bool,
     ) -> Result<Archive, Error> {
         // Create an entry for the HTTP cache.
         let http_entry = wheel_entry.with_file(format!("{}.http", filename.stem()));
@@ -567,21 +658,13 @@ impl<'a, Context: BuildContext + Send + Sync> DistributionDatabase<'a, Context>
                     .await
                     .map_err(Error::CacheRead)?;
 
-                Ok(Archive::new(id, hashes))
+                Ok(Archive::new(id, hashes).with_signed(signed))
             }
             .instrument(info_span!("wheel", wheel = %dist))
         };
 
         let req = self.request(url.clone())?;
-        let cache_control = match self.client.connectivity() {
-            Connectivity::Online => CacheControl::from(
-                self.build_context
-                    .cache()
-                    .freshness(&http_entry, Some(&filename.name))
-                    .map_err(Error::CacheRead)?,
-            ),
-            Connectivity::Offline => CacheControl::AllowStale,
-        };
+        let cache_control = self.wheel_cache_control(&http_entry, filename, dist)?;
         let archive = self
             .client
             .cached_client()
@@ -592,8 +675,9 @@ impl<'a, Context: BuildContext + Send + Sync> DistributionDatabase<'a, Context>
                 CachedClientError::Client(err) => Error::Client(err),
             })?;
 
-        // If the archive is missing the required hashes, force a refresh.
-        let archive = if archive.has_digests(hashes) {
+        // If the archive is missing the required hashes, or wasn't verified against its
+        // signature (e.g., it was cached before its source required signatures), force a refresh.
+        let archive = if archive.has_digests(hashes) && (archive.signed || !signed) {
             archive
         } else {
             self.client
@@ -625,23 +709,37 @@ impl<'a, Context: BuildContext + Send + Sync> DistributionDatabase<'a, Context>
         let pointer_entry = wheel_entry.with_file(format!("{}.rev", filename.stem()));
         let pointer = LocalArchivePointer::read_from(&pointer_entry)?;
 
+        // Distributions in signed `--find-links` directories are verified before they're unzipped.
+        let policy = self
+            .client
+            .signature_policy()
+            .filter(|policy| policy.is_signed_file(path));
+
         // Extract the archive from the pointer.
         let archive = pointer
             .filter(|pointer| pointer.is_up_to_date(modified))
             .map(LocalArchivePointer::into_archive)
-            .filter(|archive| archive.has_digests(hashes));
+            .filter(|archive| archive.has_digests(hashes) && (archive.signed || policy.is_none()));
 
         // If the file is already unzipped, and the cache is up-to-date, return it.
         if let Some(archive) = archive {
-            Ok(LocalWheel {
+            return Ok(LocalWheel {
                 dist: Dist::Built(dist.clone()),
                 archive: self.build_context.cache().archive(&archive.id),
                 hashes: archive.hashes,
                 filename: filename.clone(),
-            })
-        } else if hashes.is_none() {
-            // Otherwise, unzip the wheel.
-            let archive = Archive::new(self.unzip_wheel(path, wheel_entry.path()).await?, vec![]);
+            });
+        }
+
+        // Otherwise, verify the wheel against its signature, if necessary.
+        if let Some(policy) = policy {
+            policy.verify_file(path)?;
+        }
+
+        if hashes.is_none() {
+            // Unzip the wheel.
+            let archive = Archive::new(self.unzip_wheel(path, wheel_entry.path()).await?, vec![])
+                .with_signed(policy.is_some());
 
             // Write the archive pointer to the cache.
             let pointer = LocalArchivePointer {
@@ -686,7 +784,7 @@ impl<'a, Context: BuildContext + Send + Sync> DistributionDatabase<'a, Context>
                 .map_err(Error::CacheWrite)?;
 
             // Create an archive.
-            let archive = Archive::new(id, hashes);
+            let archive = Archive::new(id, hashes).with_signed(policy.is_some());
 
             // Write the archive pointer to the cache.
             let pointer = LocalArchivePointer {
@@ -712,7 +810,7 @@ impl<'a, Context: BuildContext + Send + Sync> DistributionDatabase<'a, Context>
             move || -> Result<TempDir, uv_extract::Error> {
                 // Unzip the wheel into a temporary directory.
                 let temp_dir = tempfile::tempdir_in(root)?;
-                uv_extract::unzip(fs_err::File::open(path)?, temp_dir.path())?;
+                uv_extract::unzip_file(&path, temp_dir.path())?;
                 Ok(temp_dir)
             }
         })
@@ -26,6 +26,8 @@ pub enum Error {
     Reqwest(#[from] BetterReqwestError),
     #[error(transparent)]
     Client(#[from] uv_client::Error),
+    #[error(transparent)]
+    Signature(#[from] uv_client::SignatureError),
 
     // Cache writing error
     #[error("Failed to read from the distribution cache")]
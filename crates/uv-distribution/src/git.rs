@@ -61,10 +61,12 @@ pub(crate) async fn fetch_git_archive(
         .await
         .map_err(Error::CacheWrite)?;
     let repository_url = RepositoryUrl::new(url);
-    let _lock = LockedFile::acquire(
+    let _lock = LockedFile::acquire_async(
         lock_dir.join(cache_key::digest(&repository_url)),
         &repository_url,
+        cache.lock_timeout(),
     )
+    .await
     .map_err(Error::CacheWrite)?;
 
     let DirectGitUrl { url, subdirectory } = DirectGitUrl::try_from(url).map_err(Error::Git)?;
@@ -1,10 +1,12 @@
 use std::path::Path;
 
+use tracing::debug;
+
 use distribution_filename::WheelFilename;
 use distribution_types::{CachedDirectUrlDist, CachedRegistryDist, Hashed};
 use pep508_rs::VerbatimUrl;
 use pypi_types::HashDigest;
-use uv_cache::{Cache, CacheBucket, CacheEntry};
+use uv_cache::{Cache, CacheEntry};
 
 use crate::{Archive, HttpArchivePointer, LocalArchivePointer};
 
@@ -16,6 +18,8 @@ pub struct CachedWheel {
     pub entry: CacheEntry,
     /// The [`HashDigest`]s for the wheel.
     pub hashes: Vec<HashDigest>,
+    /// Whether the wheel was verified against a detached signature.
+    pub signed: bool,
 }
 
 impl CachedWheel {
@@ -27,6 +31,14 @@ impl CachedWheel {
         let filename = path.file_name()?.to_str()?;
         let filename = WheelFilename::from_stem(filename).ok()?;
 
+        // If the archive was compressed, restore it.
+        if let Err(err) = uv_cache::restore_link(path) {
+            debug!(
+                "Failed to restore compressed cache entry {}: {err}",
+                path.display()
+            );
+        }
+
         // Convert to a cached wheel.
         let archive = path.canonicalize().ok()?;
         let entry = CacheEntry::from_path(archive);
@@ -35,6 +47,7 @@ impl CachedWheel {
             filename,
             entry,
             hashes,
+            signed: false,
         })
     }
 
@@ -68,14 +81,15 @@ impl CachedWheel {
 
         // Read the pointer.
         let pointer = HttpArchivePointer::read_from(path).ok()??;
-        let Archive { id, hashes } = pointer.into_archive();
+        let Archive { id, hashes, signed } = pointer.into_archive();
 
         // Convert to a cached wheel.
-        let entry = cache.entry(CacheBucket::Archive, "", id);
+        let entry = CacheEntry::from_path(cache.archive(&id));
         Some(Self {
             filename,
             entry,
             hashes,
+            signed,
         })
     }
 
@@ -89,14 +103,15 @@ impl CachedWheel {
 
         // Read the pointer.
         let pointer = LocalArchivePointer::read_from(path).ok()??;
-        let Archive { id, hashes } = pointer.into_archive();
+        let Archive { id, hashes, signed } = pointer.into_archive();
 
         // Convert to a cached wheel.
-        let entry = cache.entry(CacheBucket::Archive, "", id);
+        let entry = CacheEntry::from_path(cache.archive(&id));
         Some(Self {
             filename,
             entry,
             hashes,
+            signed,
         })
     }
 }
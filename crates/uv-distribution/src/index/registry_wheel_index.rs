@@ -8,6 +8,7 @@ use pep440_rs::Version;
 use pep508_rs::VerbatimUrl;
 use platform_tags::Tags;
 use uv_cache::{Cache, CacheBucket, WheelCache};
+use uv_client::SignaturePolicy;
 use uv_fs::{directories, files, symlinks};
 use uv_normalize::PackageName;
 use uv_types::HashStrategy;
@@ -22,6 +23,7 @@ pub struct RegistryWheelIndex<'a> {
     tags: &'a Tags,
     index_locations: &'a IndexLocations,
     hasher: &'a HashStrategy,
+    signature_policy: Option<&'a SignaturePolicy>,
     index: FxHashMap<&'a PackageName, BTreeMap<Version, CachedRegistryDist>>,
 }
 
@@ -32,12 +34,14 @@ impl<'a> RegistryWheelIndex<'a> {
         tags: &'a Tags,
         index_locations: &'a IndexLocations,
         hasher: &'a HashStrategy,
+        signature_policy: Option<&'a SignaturePolicy>,
     ) -> Self {
         Self {
             cache,
             tags,
             index_locations,
             hasher,
+            signature_policy,
             index: FxHashMap::default(),
         }
     }
@@ -73,6 +77,7 @@ impl<'a> RegistryWheelIndex<'a> {
                 self.tags,
                 self.index_locations,
                 self.hasher,
+                self.signature_policy,
             )),
         };
         versions
@@ -85,6 +90,7 @@ impl<'a> RegistryWheelIndex<'a> {
         tags: &Tags,
         index_locations: &IndexLocations,
         hasher: &HashStrategy,
+        signature_policy: Option<&SignaturePolicy>,
     ) -> BTreeMap<Version, CachedRegistryDist> {
         let mut versions = BTreeMap::new();
 
@@ -102,7 +108,15 @@ impl<'a> RegistryWheelIndex<'a> {
             })
             .collect();
 
-        for index_url in index_locations.indexes().chain(flat_index_urls.iter()) {
+        for index_url in index_locations
+            .indexes_for(package)
+            .chain(flat_index_urls.iter())
+        {
+            // Distributions from signed `--find-links` sources are only used if they were verified
+            // against their signatures (e.g., not if they were cached before the source required
+            // signatures).
+            let signed = signature_policy.is_some_and(|policy| policy.is_signed_index(index_url));
+
             // Index all the wheels that were downloaded directly from the registry.
             let wheel_dir = cache.shard(
                 CacheBucket::Wheels,
@@ -123,7 +137,9 @@ impl<'a> RegistryWheelIndex<'a> {
                                 CachedWheel::from_http_pointer(wheel_dir.join(file), cache)
                             {
                                 // Enforce hash-checking based on the built distribution.
-                                if wheel.satisfies(hasher.get_package(package)) {
+                                if wheel.satisfies(hasher.get_package(package))
+                                    && (wheel.signed || !signed)
+                                {
                                     Self::add_wheel(wheel, tags, &mut versions);
                                 }
                             }
@@ -139,7 +155,9 @@ impl<'a> RegistryWheelIndex<'a> {
                                 CachedWheel::from_local_pointer(wheel_dir.join(file), cache)
                             {
                                 // Enforce hash-checking based on the built distribution.
-                                if wheel.satisfies(hasher.get_package(package)) {
+                                if wheel.satisfies(hasher.get_package(package))
+                                    && (wheel.signed || !signed)
+                                {
                                     Self::add_wheel(wheel, tags, &mut versions);
                                 }
                             }
@@ -184,7 +202,9 @@ impl<'a> RegistryWheelIndex<'a> {
 
                 if let Some(revision) = revision {
                     // Enforce hash-checking based on the source distribution.
-                    if revision.satisfies(hasher.get_package(package)) {
+                    if revision.satisfies(hasher.get_package(package))
+                        && (revision.is_signed() || !signed)
+                    {
                         for wheel_dir in symlinks(cache_shard.join(revision.id())) {
                             if let Some(wheel) = CachedWheel::from_built_source(wheel_dir) {
                                 Self::add_wheel(wheel, tags, &mut versions);
@@ -15,6 +15,7 @@ mod error;
 mod git;
 mod index;
 mod locks;
+mod remote_cache;
 mod reporter;
 mod source;
 
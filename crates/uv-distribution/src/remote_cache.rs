@@ -0,0 +1,526 @@
+//! A remote cache of built wheels and metadata, shared across machines (e.g., ephemeral CI
+//! runners).
+//!
+//! The remote cache is a plain HTTP(S) endpoint that supports `GET` and `PUT` (e.g., an S3 or GCS
+//! bucket behind a pre-authenticated endpoint, or any WebDAV-style server). Built wheels are stored
+//! under the same key that's used for the local cache shard, scoped to a digest of the build
+//! configuration (the config settings, the build isolation mode, and the most specific tag of the
+//! interpreter, which captures the Python version, ABI, and platform):
+//!
+//! ```text
+//! <remote>/built-wheels-v3/pypi/foo/1.0.0/foo-1.0.0.tar.gz/<config>/wheel.msgpack
+//! <remote>/built-wheels-v3/pypi/foo/1.0.0/foo-1.0.0.tar.gz/<config>/<sha256>/foo-1.0.0-cp312-cp312-linux_x86_64.whl
+//! <remote>/built-wheels-v3/pypi/foo/1.0.0/foo-1.0.0.tar.gz/<config>/metadata.msgpack
+//! ```
+//!
+//! Each key holds a wheel entry, which records the filename and SHA-256 digest of the wheel, and
+//! a metadata entry, which holds the metadata resolved from the source distribution (such that
+//! resolutions can reuse it without building). Both are signed with a secret key that's shared by
+//! the machines using the remote cache. Entries with a missing or invalid signature are ignored,
+//! and downloaded wheels are verified against the signed digest. Since wheels are stored by digest
+//! and each entry is written with a single `PUT`, concurrent uploads for the same key never leave
+//! an entry that references a different wheel.
+//!
+//! The remote cache is strictly best-effort: any failure falls back to building the distribution
+//! locally.
+
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use hmac::{Hmac, Mac};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tracing::debug;
+use url::Url;
+
+use distribution_filename::WheelFilename;
+use platform_tags::Tags;
+use pypi_types::Metadata23;
+use uv_client::{Connectivity, RegistryClient};
+use uv_configuration::ConfigSettings;
+use uv_fs::write_atomic;
+use uv_types::{BuildContext, BuildIsolation};
+use uv_warnings::warn_user_once;
+
+/// The name of the entry file stored under each remote cache key.
+const ENTRY: &str = "wheel.msgpack";
+
+/// The name of the metadata file stored under each remote cache key.
+const METADATA_ENTRY: &str = "metadata.msgpack";
+
+/// A signed reference to a built wheel in the remote cache.
+#[derive(Debug, Serialize, Deserialize)]
+struct RemoteEntry {
+    filename: WheelFilename,
+    sha256: String,
+    /// The HMAC-SHA256 of the key, filename, and digest, hex-encoded.
+    signature: String,
+}
+
+/// Signed metadata for a source distribution in the remote cache.
+#[derive(Debug, Serialize, Deserialize)]
+struct RemoteMetadata {
+    /// The [`Metadata23`], encoded via `MsgPack`.
+    metadata: Vec<u8>,
+    /// The HMAC-SHA256 of the key and the digest of the metadata, hex-encoded.
+    signature: String,
+}
+
+/// A handle to the entry for a single distribution and build configuration in the remote cache.
+#[derive(Debug)]
+pub(crate) struct RemoteCache<'a> {
+    client: &'a RegistryClient,
+    /// The secret key used to sign and verify entries.
+    secret: String,
+    /// The remote cache key, relative to the root of the remote cache.
+    key: String,
+    /// The URL of the directory containing the entry.
+    url: Url,
+}
+
+impl<'a> RemoteCache<'a> {
+    /// Create a [`RemoteCache`] for the given (local) cache shard.
+    ///
+    /// Returns `None` if no remote cache is configured, if the client is offline, if the shard
+    /// isn't contained in the local cache, or if the build can't be reproduced from its
+    /// configuration alone (i.e., without build isolation).
+    pub(crate) fn new(
+        build_context: &impl BuildContext,
+        client: &'a RegistryClient,
+        shard: &Path,
+    ) -> Option<Self> {
+        let cache = build_context.cache();
+        let remote = cache.remote()?;
+        if client.connectivity() == Connectivity::Offline {
+            return None;
+        }
+        let Some(secret) = cache.remote_key() else {
+            warn_user_once!(
+                "Ignoring remote cache at `{remote}`, since no key was provided to verify its entries (use `--remote-cache-key`)"
+            );
+            return None;
+        };
+
+        // Builds without isolation depend on the contents of the local environment, which can't be
+        // captured in the key.
+        let isolation = match build_context.build_isolation() {
+            BuildIsolation::Isolated => "isolated",
+            BuildIsolation::Shared(_) | BuildIsolation::SharedPackage(..) => return None,
+        };
+        let tag = build_context
+            .interpreter()
+            .tags()
+            .ok()?
+            .prioritized()
+            .into_iter()
+            .next()?;
+        let config = config_digest(build_context.config_settings(), isolation, &tag);
+
+        // Use the path of the shard within the cache as the remote key.
+        let key = shard.strip_prefix(cache.root()).ok()?;
+        let key = key
+            .components()
+            .map(|component| component.as_os_str().to_str())
+            .chain(std::iter::once(Some(config.as_str())))
+            .collect::<Option<Vec<_>>>()?
+            .join("/");
+
+        let url = Url::parse(&format!("{}/{key}/", remote.as_str().trim_end_matches('/'))).ok()?;
+        Some(Self {
+            client,
+            secret: secret.to_string(),
+            key,
+            url,
+        })
+    }
+
+    /// Fetch a wheel compatible with the given tags from the remote cache into the given
+    /// directory, returning its filename.
+    ///
+    /// Returns `None` if no compatible wheel is available, or if the remote cache is unreachable.
+    pub(crate) async fn fetch(&self, tags: &Tags, target: &Path) -> Option<WheelFilename> {
+        match self.try_fetch(tags, target).await {
+            Ok(Some(filename)) => {
+                debug!("Fetched {filename} from remote cache: {}", self.url);
+                Some(filename)
+            }
+            Ok(None) => {
+                debug!("No compatible wheel in remote cache: {}", self.url);
+                None
+            }
+            Err(err) => {
+                warn_user_once!("Failed to read from remote cache: {err:#}");
+                None
+            }
+        }
+    }
+
+    /// Upload a built wheel to the remote cache.
+    pub(crate) async fn store(&self, wheel: &Path, filename: &WheelFilename) {
+        match self.try_store(wheel, filename).await {
+            Ok(()) => {
+                debug!("Uploaded {filename} to remote cache: {}", self.url);
+            }
+            Err(err) => {
+                warn_user_once!("Failed to write to remote cache: {err:#}");
+            }
+        }
+    }
+
+    /// Fetch the metadata for the distribution from the remote cache.
+    ///
+    /// Returns `None` if no metadata is available, or if the remote cache is unreachable.
+    pub(crate) async fn fetch_metadata(&self) -> Option<Metadata23> {
+        match self.try_fetch_metadata().await {
+            Ok(Some(metadata)) => {
+                debug!("Fetched metadata from remote cache: {}", self.url);
+                Some(metadata)
+            }
+            Ok(None) => {
+                debug!("No metadata in remote cache: {}", self.url);
+                None
+            }
+            Err(err) => {
+                warn_user_once!("Failed to read from remote cache: {err:#}");
+                None
+            }
+        }
+    }
+
+    /// Upload the metadata for the distribution to the remote cache.
+    pub(crate) async fn store_metadata(&self, metadata: &Metadata23) {
+        match self.try_store_metadata(metadata).await {
+            Ok(()) => {
+                debug!("Uploaded metadata to remote cache: {}", self.url);
+            }
+            Err(err) => {
+                warn_user_once!("Failed to write to remote cache: {err:#}");
+            }
+        }
+    }
+
+    async fn try_fetch(&self, tags: &Tags, target: &Path) -> Result<Option<WheelFilename>> {
+        let Some(entry) = self.read::<RemoteEntry>(ENTRY).await? else {
+            return Ok(None);
+        };
+
+        // Only trust entries that were signed with our key, for this key.
+        if !verify(self.secret.as_bytes(), &self.key, &entry) {
+            return Err(anyhow!(
+                "Invalid signature for remote cache entry: `{}`",
+                self.url.join(ENTRY)?
+            ));
+        }
+
+        if !entry.filename.is_compatible(tags) {
+            return Ok(None);
+        }
+
+        let url = self.wheel_url(&entry.sha256, &entry.filename)?;
+        let response = self
+            .client
+            .uncached_client()
+            .get(url.clone())
+            .send()
+            .await?;
+        let bytes = response.error_for_status()?.bytes().await?;
+
+        // Verify the integrity of the wheel before persisting it to the local cache.
+        let sha256 = format!("{:x}", Sha256::digest(&bytes));
+        if sha256 != entry.sha256 {
+            return Err(anyhow!(
+                "Hash mismatch for `{url}` (expected: `{}`, computed: `{sha256}`)",
+                entry.sha256
+            ));
+        }
+
+        fs_err::tokio::create_dir_all(target).await?;
+        write_atomic(target.join(entry.filename.to_string()), &bytes).await?;
+
+        Ok(Some(entry.filename))
+    }
+
+    async fn try_store(&self, wheel: &Path, filename: &WheelFilename) -> Result<()> {
+        let bytes = fs_err::tokio::read(wheel).await?;
+        let sha256 = format!("{:x}", Sha256::digest(&bytes));
+
+        // Upload the wheel first, such that the entry never references a missing wheel.
+        self.client
+            .uncached_client()
+            .put(self.wheel_url(&sha256, filename)?)
+            .body(bytes)
+            .send()
+            .await?
+            .error_for_status()?;
+
+        let signature = sign(self.secret.as_bytes(), &self.key, filename, &sha256);
+        let entry = RemoteEntry {
+            filename: filename.clone(),
+            sha256,
+            signature,
+        };
+
+        self.client
+            .uncached_client()
+            .put(self.url.join(ENTRY)?)
+            .body(rmp_serde::to_vec(&entry)?)
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(())
+    }
+
+    async fn try_fetch_metadata(&self) -> Result<Option<Metadata23>> {
+        let Some(entry) = self.read::<RemoteMetadata>(METADATA_ENTRY).await? else {
+            return Ok(None);
+        };
+
+        // Only trust metadata that was signed with our key, for this key.
+        let url = self.url.join(METADATA_ENTRY)?;
+        if !verify_metadata(self.secret.as_bytes(), &self.key, &entry) {
+            return Err(anyhow!("Invalid signature for remote cache entry: `{url}`"));
+        }
+
+        let metadata = rmp_serde::from_slice(&entry.metadata)
+            .with_context(|| format!("Failed to decode remote cache entry: `{url}`"))?;
+        Ok(Some(metadata))
+    }
+
+    async fn try_store_metadata(&self, metadata: &Metadata23) -> Result<()> {
+        let metadata = rmp_serde::to_vec(metadata)?;
+        let signature = sign_metadata(self.secret.as_bytes(), &self.key, &metadata);
+        let entry = RemoteMetadata {
+            metadata,
+            signature,
+        };
+
+        self.client
+            .uncached_client()
+            .put(self.url.join(METADATA_ENTRY)?)
+            .body(rmp_serde::to_vec(&entry)?)
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(())
+    }
+
+    /// Read the given entry for the distribution, if it exists.
+    async fn read<E: DeserializeOwned>(&self, name: &str) -> Result<Option<E>> {
+        let url = self.url.join(name)?;
+        let response = self
+            .client
+            .uncached_client()
+            .get(url.clone())
+            .send()
+            .await?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        let bytes = response.error_for_status()?.bytes().await?;
+        let entry = rmp_serde::from_slice(&bytes)
+            .with_context(|| format!("Failed to decode remote cache entry: `{url}`"))?;
+        Ok(Some(entry))
+    }
+
+    /// Return the URL at which the wheel with the given digest and filename is stored.
+    fn wheel_url(&self, sha256: &str, filename: &WheelFilename) -> Result<Url> {
+        Ok(self.url.join(&format!("{sha256}/{filename}"))?)
+    }
+}
+
+/// Return a digest of the configuration that determines the output of a build.
+fn config_digest(config_settings: &ConfigSettings, isolation: &str, tag: &str) -> String {
+    cache_key::digest(&(config_settings, isolation, tag))
+}
+
+/// Compute the MAC that authenticates the given parts of an entry.
+fn mac(secret: &[u8], parts: &[&str]) -> Hmac<Sha256> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret).expect("HMAC accepts keys of any size");
+    for part in parts {
+        mac.update(part.as_bytes());
+        mac.update(b"\n");
+    }
+    mac
+}
+
+/// Returns `true` if the hex-encoded signature authenticates the given parts of an entry.
+fn verify_parts(secret: &[u8], parts: &[&str], signature: &str) -> bool {
+    let Ok(signature) = hex::decode(signature) else {
+        return false;
+    };
+    mac(secret, parts).verify_slice(&signature).is_ok()
+}
+
+/// Sign the given wheel under the given remote cache key, returning the hex-encoded signature.
+fn sign(secret: &[u8], key: &str, filename: &WheelFilename, sha256: &str) -> String {
+    let parts = [key, &filename.to_string(), sha256];
+    hex::encode(mac(secret, &parts).finalize().into_bytes())
+}
+
+/// Returns `true` if the entry was signed with the given secret for the given remote cache key.
+fn verify(secret: &[u8], key: &str, entry: &RemoteEntry) -> bool {
+    let parts = [key, &entry.filename.to_string(), &entry.sha256];
+    verify_parts(secret, &parts, &entry.signature)
+}
+
+/// Sign the given (encoded) metadata under the given remote cache key, returning the hex-encoded
+/// signature.
+///
+/// The entry name is included in the MAC, such that a signed wheel entry can't be passed off as
+/// metadata (or vice versa).
+fn sign_metadata(secret: &[u8], key: &str, metadata: &[u8]) -> String {
+    let sha256 = format!("{:x}", Sha256::digest(metadata));
+    let parts = [key, METADATA_ENTRY, &sha256];
+    hex::encode(mac(secret, &parts).finalize().into_bytes())
+}
+
+/// Returns `true` if the metadata was signed with the given secret for the given remote cache key.
+fn verify_metadata(secret: &[u8], key: &str, entry: &RemoteMetadata) -> bool {
+    let sha256 = format!("{:x}", Sha256::digest(&entry.metadata));
+    let parts = [key, METADATA_ENTRY, &sha256];
+    verify_parts(secret, &parts, &entry.signature)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use distribution_filename::WheelFilename;
+    use uv_configuration::{ConfigSettingEntry, ConfigSettings};
+
+    use super::{
+        config_digest, sign, sign_metadata, verify, verify_metadata, RemoteEntry, RemoteMetadata,
+    };
+
+    const KEY: &str = "built-wheels-v3/pypi/foo/1.0.0/foo-1.0.0.tar.gz/0123456789abcdef";
+    const SHA256: &str = "a6d0b2d5d1a8a8e4fce2b2e1e7c9b93a2fcd2a9a2b3c4d5e6f708192a3b4c5d6";
+
+    fn entry(secret: &[u8], key: &str) -> RemoteEntry {
+        let filename = WheelFilename::from_str("foo-1.0.0-cp312-cp312-linux_x86_64.whl").unwrap();
+        let signature = sign(secret, key, &filename, SHA256);
+        RemoteEntry {
+            filename,
+            sha256: SHA256.to_string(),
+            signature,
+        }
+    }
+
+    fn config_settings(entries: &[&str]) -> ConfigSettings {
+        entries
+            .iter()
+            .map(|entry| ConfigSettingEntry::from_str(entry).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn signed_entry() {
+        let entry = entry(b"secret", KEY);
+        assert!(verify(b"secret", KEY, &entry));
+    }
+
+    #[test]
+    fn tampered_entry() {
+        // A different digest.
+        let mut tampered = entry(b"secret", KEY);
+        tampered.sha256 = "0".repeat(64);
+        assert!(!verify(b"secret", KEY, &tampered));
+
+        // A different wheel.
+        let mut tampered = entry(b"secret", KEY);
+        tampered.filename = WheelFilename::from_str("foo-1.0.0-cp312-cp312-win_amd64.whl").unwrap();
+        assert!(!verify(b"secret", KEY, &tampered));
+
+        // A malformed signature.
+        let mut tampered = entry(b"secret", KEY);
+        tampered.signature = "not-hex".to_string();
+        assert!(!verify(b"secret", KEY, &tampered));
+    }
+
+    #[test]
+    fn foreign_entry() {
+        // An entry signed with a different secret.
+        let entry = entry(b"other", KEY);
+        assert!(!verify(b"secret", KEY, &entry));
+    }
+
+    #[test]
+    fn relocated_entry() {
+        // An entry copied from another key (e.g., another build configuration).
+        let entry = entry(b"secret", KEY);
+        let key = "built-wheels-v3/pypi/foo/1.0.0/foo-1.0.0.tar.gz/fedcba9876543210";
+        assert!(!verify(b"secret", key, &entry));
+    }
+
+    #[test]
+    fn signed_metadata() {
+        let metadata = b"metadata".to_vec();
+        let entry = RemoteMetadata {
+            signature: sign_metadata(b"secret", KEY, &metadata),
+            metadata,
+        };
+        assert!(verify_metadata(b"secret", KEY, &entry));
+
+        // Signed with a different secret, or for a different key.
+        assert!(!verify_metadata(b"other", KEY, &entry));
+        let key = "built-wheels-v3/pypi/foo/1.0.0/foo-1.0.0.tar.gz/fedcba9876543210";
+        assert!(!verify_metadata(b"secret", key, &entry));
+
+        // Tampered metadata.
+        let tampered = RemoteMetadata {
+            metadata: b"tampered".to_vec(),
+            signature: entry.signature.clone(),
+        };
+        assert!(!verify_metadata(b"secret", KEY, &tampered));
+    }
+
+    #[test]
+    fn config_digest_differs() {
+        let tag = "cp312-cp312-manylinux_2_17_x86_64";
+        let base = config_digest(&ConfigSettings::default(), "isolated", tag);
+
+        // The digest is stable.
+        assert_eq!(
+            base,
+            config_digest(&ConfigSettings::default(), "isolated", tag)
+        );
+
+        // Config settings are part of the key.
+        assert_ne!(
+            base,
+            config_digest(
+                &config_settings(&["--build-option=--debug"]),
+                "isolated",
+                tag
+            )
+        );
+        assert_ne!(
+            config_digest(&config_settings(&["key=a", "key=b"]), "isolated", tag),
+            config_digest(&config_settings(&["key=a"]), "isolated", tag)
+        );
+
+        // As are the build isolation mode and the interpreter.
+        assert_ne!(
+            base,
+            config_digest(&ConfigSettings::default(), "shared", tag)
+        );
+        assert_ne!(
+            base,
+            config_digest(
+                &ConfigSettings::default(),
+                "isolated",
+                "cp313-cp313t-manylinux_2_17_x86_64"
+            )
+        );
+        assert_ne!(
+            base,
+            config_digest(
+                &ConfigSettings::default(),
+                "isolated",
+                "cp312-cp312-macosx_14_0_arm64"
+            )
+        );
+    }
+}
@@ -31,11 +31,13 @@ use uv_client::{
 };
 use uv_configuration::{BuildKind, NoBuild};
 use uv_extract::hash::Hasher;
-use uv_fs::write_atomic;
+use uv_fs::{write_atomic, LockedFile};
+use uv_git::Fetch;
 use uv_types::{BuildContext, SourceBuildTrait};
 
 use crate::error::Error;
 use crate::git::{fetch_git_archive, resolve_precise};
+use crate::remote_cache::RemoteCache;
 use crate::source::built_wheel_metadata::BuiltWheelMetadata;
 use crate::source::revision::Revision;
 use crate::{ArchiveMetadata, Reporter};
@@ -50,6 +52,27 @@ pub struct SourceDistributionBuilder<'a, T: BuildContext> {
     reporter: Option<Arc<dyn Reporter>>,
 }
 
+/// The location of a [`BuildableSource`], as determined by [`SourceDistributionBuilder::locate`].
+enum SourceLocation<'a> {
+    /// A remote archive (e.g., from a registry, or at a direct URL).
+    Url {
+        url: Cow<'a, Url>,
+        filename: Cow<'a, str>,
+        subdirectory: Option<PathBuf>,
+        cache_shard: CacheShard,
+    },
+    /// A local archive.
+    Archive {
+        url: Cow<'a, Url>,
+        path: Cow<'a, Path>,
+        cache_shard: CacheShard,
+    },
+    /// A local source tree.
+    SourceTree(PathSourceUrl<'a>),
+    /// A Git repository.
+    Git(GitSourceUrl<'a>),
+}
+
 /// The name of the file that contains the revision ID for a remote distribution, encoded via `MsgPack`.
 pub(crate) const HTTP_REVISION: &str = "revision.http";
 
@@ -85,64 +108,13 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         tags: &Tags,
         hashes: HashPolicy<'_>,
     ) -> Result<BuiltWheelMetadata, Error> {
-        let built_wheel_metadata = match &source {
-            BuildableSource::Dist(SourceDist::Registry(dist)) => {
-                // For registry source distributions, shard by package, then version, for
-                // convenience in debugging.
-                let cache_shard = self.build_context.cache().shard(
-                    CacheBucket::BuiltWheels,
-                    WheelCache::Index(&dist.index)
-                        .wheel_dir(dist.filename.name.as_ref())
-                        .join(dist.filename.version.to_string()),
-                );
-
-                let url = match &dist.file.url {
-                    FileLocation::RelativeUrl(base, url) => {
-                        pypi_types::base_url_join_relative(base, url)?
-                    }
-                    FileLocation::AbsoluteUrl(url) => {
-                        Url::parse(url).map_err(|err| Error::Url(url.clone(), err))?
-                    }
-                    FileLocation::Path(path) => {
-                        let url = Url::from_file_path(path).expect("path is absolute");
-                        return self
-                            .archive(
-                                source,
-                                &PathSourceUrl {
-                                    url: &url,
-                                    path: Cow::Borrowed(path),
-                                },
-                                &cache_shard,
-                                tags,
-                                hashes,
-                            )
-                            .boxed()
-                            .await;
-                    }
-                };
-
-                self.url(
-                    source,
-                    &dist.file.filename,
-                    &url,
-                    &cache_shard,
-                    None,
-                    tags,
-                    hashes,
-                )
-                .boxed()
-                .await?
-            }
-            BuildableSource::Dist(SourceDist::DirectUrl(dist)) => {
-                let filename = dist.filename().expect("Distribution must have a filename");
-                let DirectArchiveUrl { url, subdirectory } = DirectArchiveUrl::from(dist.url.raw());
-
-                // For direct URLs, cache directly under the hash of the URL itself.
-                let cache_shard = self
-                    .build_context
-                    .cache()
-                    .shard(CacheBucket::BuiltWheels, WheelCache::Url(&url).root());
-
+        let built_wheel_metadata = match self.locate(source)? {
+            SourceLocation::Url {
+                url,
+                filename,
+                subdirectory,
+                cache_shard,
+            } => {
                 self.url(
                     source,
                     &filename,
@@ -155,74 +127,23 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 .boxed()
                 .await?
             }
-            BuildableSource::Dist(SourceDist::Git(dist)) => {
-                self.git(source, &GitSourceUrl::from(dist), tags, hashes)
+            SourceLocation::Archive {
+                url,
+                path,
+                cache_shard,
+            } => {
+                let resource = PathSourceUrl { url: &url, path };
+                self.archive(source, &resource, &cache_shard, tags, hashes)
                     .boxed()
                     .await?
             }
-            BuildableSource::Dist(SourceDist::Path(dist)) => {
-                if dist.path.is_dir() {
-                    self.source_tree(source, &PathSourceUrl::from(dist), tags, hashes)
-                        .boxed()
-                        .await?
-                } else {
-                    let cache_shard = self
-                        .build_context
-                        .cache()
-                        .shard(CacheBucket::BuiltWheels, WheelCache::Path(&dist.url).root());
-                    self.archive(
-                        source,
-                        &PathSourceUrl::from(dist),
-                        &cache_shard,
-                        tags,
-                        hashes,
-                    )
+            SourceLocation::SourceTree(resource) => {
+                self.source_tree(source, &resource, tags, hashes)
                     .boxed()
                     .await?
-                }
             }
-            BuildableSource::Url(SourceUrl::Direct(resource)) => {
-                let filename = resource
-                    .url
-                    .filename()
-                    .expect("Distribution must have a filename");
-                let DirectArchiveUrl { url, subdirectory } = DirectArchiveUrl::from(resource.url);
-
-                // For direct URLs, cache directly under the hash of the URL itself.
-                let cache_shard = self
-                    .build_context
-                    .cache()
-                    .shard(CacheBucket::BuiltWheels, WheelCache::Url(&url).root());
-
-                self.url(
-                    source,
-                    &filename,
-                    &url,
-                    &cache_shard,
-                    subdirectory.as_deref(),
-                    tags,
-                    hashes,
-                )
-                .boxed()
-                .await?
-            }
-            BuildableSource::Url(SourceUrl::Git(resource)) => {
-                self.git(source, resource, tags, hashes).boxed().await?
-            }
-            BuildableSource::Url(SourceUrl::Path(resource)) => {
-                if resource.path.is_dir() {
-                    self.source_tree(source, resource, tags, hashes)
-                        .boxed()
-                        .await?
-                } else {
-                    let cache_shard = self.build_context.cache().shard(
-                        CacheBucket::BuiltWheels,
-                        WheelCache::Path(resource.url).root(),
-                    );
-                    self.archive(source, resource, &cache_shard, tags, hashes)
-                        .boxed()
-                        .await?
-                }
+            SourceLocation::Git(resource) => {
+                self.git(source, &resource, tags, hashes).boxed().await?
             }
         };
 
@@ -237,61 +158,13 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         source: &BuildableSource<'_>,
         hashes: HashPolicy<'_>,
     ) -> Result<ArchiveMetadata, Error> {
-        let metadata = match &source {
-            BuildableSource::Dist(SourceDist::Registry(dist)) => {
-                // For registry source distributions, shard by package, then version.
-                let cache_shard = self.build_context.cache().shard(
-                    CacheBucket::BuiltWheels,
-                    WheelCache::Index(&dist.index)
-                        .wheel_dir(dist.filename.name.as_ref())
-                        .join(dist.filename.version.to_string()),
-                );
-
-                let url = match &dist.file.url {
-                    FileLocation::RelativeUrl(base, url) => {
-                        pypi_types::base_url_join_relative(base, url)?
-                    }
-                    FileLocation::AbsoluteUrl(url) => {
-                        Url::parse(url).map_err(|err| Error::Url(url.clone(), err))?
-                    }
-                    FileLocation::Path(path) => {
-                        let url = Url::from_file_path(path).expect("path is absolute");
-                        return self
-                            .archive_metadata(
-                                source,
-                                &PathSourceUrl {
-                                    url: &url,
-                                    path: Cow::Borrowed(path),
-                                },
-                                &cache_shard,
-                                hashes,
-                            )
-                            .boxed()
-                            .await;
-                    }
-                };
-
-                self.url_metadata(
-                    source,
-                    &dist.file.filename,
-                    &url,
-                    &cache_shard,
-                    None,
-                    hashes,
-                )
-                .boxed()
-                .await?
-            }
-            BuildableSource::Dist(SourceDist::DirectUrl(dist)) => {
-                let filename = dist.filename().expect("Distribution must have a filename");
-                let DirectArchiveUrl { url, subdirectory } = DirectArchiveUrl::from(dist.url.raw());
-
-                // For direct URLs, cache directly under the hash of the URL itself.
-                let cache_shard = self
-                    .build_context
-                    .cache()
-                    .shard(CacheBucket::BuiltWheels, WheelCache::Url(&url).root());
-
+        let metadata = match self.locate(source)? {
+            SourceLocation::Url {
+                url,
+                filename,
+                subdirectory,
+                cache_shard,
+            } => {
                 self.url_metadata(
                     source,
                     &filename,
@@ -303,32 +176,165 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 .boxed()
                 .await?
             }
-            BuildableSource::Dist(SourceDist::Git(dist)) => {
-                self.git_metadata(source, &GitSourceUrl::from(dist), hashes)
+            SourceLocation::Archive {
+                url,
+                path,
+                cache_shard,
+            } => {
+                let resource = PathSourceUrl { url: &url, path };
+                self.archive_metadata(source, &resource, &cache_shard, hashes)
                     .boxed()
                     .await?
             }
-            BuildableSource::Dist(SourceDist::Path(dist)) => {
-                if dist.path.is_dir() {
-                    self.source_tree_metadata(source, &PathSourceUrl::from(dist), hashes)
-                        .boxed()
-                        .await?
-                } else {
+            SourceLocation::SourceTree(resource) => {
+                self.source_tree_metadata(source, &resource, hashes)
+                    .boxed()
+                    .await?
+            }
+            SourceLocation::Git(resource) => {
+                self.git_metadata(source, &resource, hashes).boxed().await?
+            }
+        };
+
+        Ok(metadata)
+    }
+
+    /// Download and unpack a [`SourceDist`] into the cache, without building it, and return the
+    /// path to its source tree (including any subdirectory).
+    pub(super) async fn download_and_unpack(
+        &self,
+        dist: &SourceDist,
+        hashes: HashPolicy<'_>,
+    ) -> Result<PathBuf, Error> {
+        let source = BuildableSource::Dist(dist);
+        let source_tree = match self.locate(&source)? {
+            SourceLocation::Url {
+                url,
+                filename,
+                subdirectory,
+                cache_shard,
+            } => {
+                let revision = self
+                    .url_revision(&source, &filename, &url, &cache_shard, hashes)
+                    .await?;
+                let source_tree = cache_shard
+                    .shard(revision.id())
+                    .entry(&*filename)
+                    .into_path_buf();
+                match subdirectory {
+                    Some(subdirectory) => source_tree.join(subdirectory),
+                    None => source_tree,
+                }
+            }
+            SourceLocation::Archive {
+                url,
+                path,
+                cache_shard,
+            } => {
+                let resource = PathSourceUrl { url: &url, path };
+                let revision = self
+                    .archive_revision(&source, &resource, &cache_shard, hashes)
+                    .await?;
+                cache_shard
+                    .shard(revision.id())
+                    .entry("source")
+                    .into_path_buf()
+            }
+            SourceLocation::SourceTree(resource) => resource.path.into_owned(),
+            SourceLocation::Git(resource) => {
+                let (_, fetch, subdirectory) = self.fetch_git(&resource).await?;
+                match subdirectory {
+                    Some(subdirectory) => fetch.path().join(subdirectory),
+                    None => fetch.path().to_path_buf(),
+                }
+            }
+        };
+        Ok(source_tree)
+    }
+
+    /// Determine where to fetch a [`BuildableSource`] from, along with the cache shard in which its
+    /// archive, if any, is unpacked and built.
+    fn locate<'data>(
+        &self,
+        source: &BuildableSource<'data>,
+    ) -> Result<SourceLocation<'data>, Error> {
+        let location = match source {
+            BuildableSource::Dist(dist) => match *dist {
+                SourceDist::Registry(dist) => {
+                    // For registry source distributions, shard by package, then version, for
+                    // convenience in debugging.
+                    let cache_shard = self.build_context.cache().shard(
+                        CacheBucket::BuiltWheels,
+                        WheelCache::Index(&dist.index)
+                            .wheel_dir(dist.filename.name.as_ref())
+                            .join(dist.filename.version.to_string()),
+                    );
+
+                    let url = match &dist.file.url {
+                        FileLocation::RelativeUrl(base, url) => {
+                            pypi_types::base_url_join_relative(base, url)?
+                        }
+                        FileLocation::AbsoluteUrl(url) => {
+                            Url::parse(url).map_err(|err| Error::Url(url.clone(), err))?
+                        }
+                        FileLocation::Path(path) => {
+                            return Ok(SourceLocation::Archive {
+                                url: Cow::Owned(
+                                    Url::from_file_path(path).expect("path is absolute"),
+                                ),
+                                path: Cow::Borrowed(path),
+                                cache_shard,
+                            });
+                        }
+                    };
+
+                    SourceLocation::Url {
+                        url: Cow::Owned(url),
+                        filename: Cow::Borrowed(&dist.file.filename),
+                        subdirectory: None,
+                        cache_shard,
+                    }
+                }
+                SourceDist::DirectUrl(dist) => {
+                    let filename = dist.filename().expect("Distribution must have a filename");
+                    let DirectArchiveUrl { url, subdirectory } =
+                        DirectArchiveUrl::from(dist.url.raw());
+
+                    // For direct URLs, cache directly under the hash of the URL itself.
                     let cache_shard = self
                         .build_context
                         .cache()
-                        .shard(CacheBucket::BuiltWheels, WheelCache::Path(&dist.url).root());
-                    self.archive_metadata(source, &PathSourceUrl::from(dist), &cache_shard, hashes)
-                        .boxed()
-                        .await?
+                        .shard(CacheBucket::BuiltWheels, WheelCache::Url(&url).root());
+
+                    SourceLocation::Url {
+                        url: Cow::Owned(url),
+                        filename,
+                        subdirectory,
+                        cache_shard,
+                    }
                 }
-            }
+                SourceDist::Git(dist) => SourceLocation::Git(GitSourceUrl::from(dist)),
+                SourceDist::Path(dist) => {
+                    if dist.path.is_dir() {
+                        SourceLocation::SourceTree(PathSourceUrl::from(dist))
+                    } else {
+                        let cache_shard = self
+                            .build_context
+                            .cache()
+                            .shard(CacheBucket::BuiltWheels, WheelCache::Path(&dist.url).root());
+                        let PathSourceUrl { url, path } = PathSourceUrl::from(dist);
+                        SourceLocation::Archive {
+                            url: Cow::Borrowed(url),
+                            path,
+                            cache_shard,
+                        }
+                    }
+                }
+            },
             BuildableSource::Url(SourceUrl::Direct(resource)) => {
-                let filename = resource
-                    .url
-                    .filename()
-                    .expect("Distribution must have a filename");
-                let DirectArchiveUrl { url, subdirectory } = DirectArchiveUrl::from(resource.url);
+                let url: &'data Url = resource.url;
+                let filename = url.filename().expect("Distribution must have a filename");
+                let DirectArchiveUrl { url, subdirectory } = DirectArchiveUrl::from(url);
 
                 // For direct URLs, cache directly under the hash of the URL itself.
                 let cache_shard = self
@@ -336,38 +342,32 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     .cache()
                     .shard(CacheBucket::BuiltWheels, WheelCache::Url(&url).root());
 
-                self.url_metadata(
-                    source,
-                    &filename,
-                    &url,
-                    &cache_shard,
-                    subdirectory.as_deref(),
-                    hashes,
-                )
-                .boxed()
-                .await?
-            }
-            BuildableSource::Url(SourceUrl::Git(resource)) => {
-                self.git_metadata(source, resource, hashes).boxed().await?
+                SourceLocation::Url {
+                    url: Cow::Owned(url),
+                    filename,
+                    subdirectory,
+                    cache_shard,
+                }
             }
+            BuildableSource::Url(SourceUrl::Git(resource)) => SourceLocation::Git(resource.clone()),
             BuildableSource::Url(SourceUrl::Path(resource)) => {
                 if resource.path.is_dir() {
-                    self.source_tree_metadata(source, resource, hashes)
-                        .boxed()
-                        .await?
+                    SourceLocation::SourceTree(resource.clone())
                 } else {
                     let cache_shard = self.build_context.cache().shard(
                         CacheBucket::BuiltWheels,
                         WheelCache::Path(resource.url).root(),
                     );
-                    self.archive_metadata(source, resource, &cache_shard, hashes)
-                        .boxed()
-                        .await?
+                    SourceLocation::Archive {
+                        url: Cow::Borrowed(resource.url),
+                        path: resource.path.clone(),
+                        cache_shard,
+                    }
                 }
             }
         };
 
-        Ok(metadata)
+        Ok(location)
     }
 
     /// Build a source distribution from a remote URL.
@@ -396,6 +396,10 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             ));
         }
 
+        // Remote cache entries are keyed by the shard prior to scoping it to the revision, since
+        // revision IDs are local to each cache.
+        let remote = RemoteCache::new(self.build_context, self.client, cache_shard);
+
         // Scope all operations to the revision. Within the revision, there's no need to check for
         // freshness, since entries have to be fresher than the revision itself.
         let cache_shard = cache_shard.shard(revision.id());
@@ -405,6 +409,29 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             return Ok(built_wheel.with_hashes(revision.into_hashes()));
         }
 
+        // If the remote cache contains a compatible wheel, fetch it.
+        if let Some(remote) = remote.as_ref() {
+            if let Some(wheel_filename) = remote.fetch(tags, &cache_shard).await {
+                let disk_filename = wheel_filename.to_string();
+                let metadata =
+                    read_wheel_metadata(&wheel_filename, cache_shard.join(&disk_filename))?;
+                validate(source, &metadata)?;
+
+                // Store the metadata.
+                let metadata_entry = cache_shard.entry(METADATA);
+                write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
+                    .await
+                    .map_err(Error::CacheWrite)?;
+
+                return Ok(BuiltWheelMetadata {
+                    path: cache_shard.join(&disk_filename),
+                    target: cache_shard.join(wheel_filename.stem()),
+                    filename: wheel_filename,
+                    hashes: revision.into_hashes(),
+                });
+            }
+        }
+
         let task = self
             .reporter
             .as_ref()
@@ -422,6 +449,14 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             }
         }
 
+        // Share the built wheel and its metadata via the remote cache.
+        if let Some(remote) = remote.as_ref() {
+            remote
+                .store(&cache_shard.join(&disk_filename), &wheel_filename)
+                .await;
+            remote.store_metadata(&metadata).await;
+        }
+
         // Store the metadata.
         let metadata_entry = cache_shard.entry(METADATA);
         write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
@@ -464,6 +499,10 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             ));
         }
 
+        // Remote cache entries are keyed by the shard prior to scoping it to the revision, since
+        // revision IDs are local to each cache.
+        let remote = RemoteCache::new(self.build_context, self.client, cache_shard);
+
         // Scope all operations to the revision. Within the revision, there's no need to check for
         // freshness, since entries have to be fresher than the revision itself.
         let cache_shard = cache_shard.shard(revision.id());
@@ -478,6 +517,26 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             });
         }
 
+        // If the remote cache contains the metadata, fetch it.
+        if let Some(remote) = remote.as_ref() {
+            if let Some(metadata) = remote.fetch_metadata().await {
+                validate(source, &metadata)?;
+
+                // Store the metadata.
+                fs::create_dir_all(metadata_entry.dir())
+                    .await
+                    .map_err(Error::CacheWrite)?;
+                write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
+                    .await
+                    .map_err(Error::CacheWrite)?;
+
+                return Ok(ArchiveMetadata {
+                    metadata,
+                    hashes: revision.into_hashes(),
+                });
+            }
+        }
+
         // Otherwise, we either need to build the metadata or the wheel.
         let source_dist_entry = cache_shard.entry(filename);
 
@@ -487,6 +546,11 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .boxed()
             .await?
         {
+            // Share the metadata via the remote cache.
+            if let Some(remote) = remote.as_ref() {
+                remote.store_metadata(&metadata).await;
+            }
+
             // Store the metadata.
             let cache_entry = cache_shard.entry(METADATA);
             fs::create_dir_all(cache_entry.dir())
@@ -508,10 +572,18 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .map(|reporter| reporter.on_build_start(source));
 
         // Build the source distribution.
-        let (_disk_filename, _wheel_filename, metadata) = self
+        let (disk_filename, wheel_filename, metadata) = self
             .build_distribution(source, source_dist_entry.path(), subdirectory, &cache_shard)
             .await?;
 
+        // Share the built wheel and its metadata via the remote cache.
+        if let Some(remote) = remote.as_ref() {
+            remote
+                .store(&cache_shard.join(&disk_filename), &wheel_filename)
+                .await;
+            remote.store_metadata(&metadata).await;
+        }
+
         // Store the metadata.
         let cache_entry = cache_shard.entry(METADATA);
         write_atomic(cache_entry.path(), rmp_serde::to_vec(&metadata)?)
@@ -541,15 +613,34 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
     ) -> Result<Revision, Error> {
         let cache_entry = cache_shard.entry(HTTP_REVISION);
         let cache_control = match self.client.connectivity() {
-            Connectivity::Online => CacheControl::from(
-                self.build_context
-                    .cache()
-                    .freshness(&cache_entry, source.name())
-                    .map_err(Error::CacheRead)?,
-            ),
+            Connectivity::Online => {
+                let cache = self.build_context.cache();
+                if let BuildableSource::Dist(SourceDist::Registry(_)) = source {
+                    CacheControl::from(
+                        cache
+                            .freshness(&cache_entry, source.name())
+                            .map_err(Error::CacheRead)?,
+                    )
+                } else {
+                    // Source distributions at a direct URL are revalidated against their recorded
+                    // validator, and can be refreshed individually.
+                    CacheControl::artifact(
+                        cache
+                            .url_freshness(&cache_entry, source.name(), url)
+                            .map_err(Error::CacheRead)?,
+                    )
+                }
+            }
             Connectivity::Offline => CacheControl::AllowStale,
         };
 
+        // Distributions from signed flat indexes are verified by the client as they're
+        // downloaded.
+        let signed = self
+            .client
+            .signature_policy()
+            .is_some_and(|policy| policy.is_signed_artifact(url));
+
         let download = |response| {
             async {
                 // At this point, we're seeing a new or updated source distribution. Initialize a
@@ -563,7 +654,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     .download_archive(response, source, filename, entry.path(), hashes)
                     .await?;
 
-                Ok(revision.with_hashes(hashes))
+                Ok(revision.with_hashes(hashes).with_signed(signed))
             }
             .boxed()
             .instrument(info_span!("download", source_dist = %source))
@@ -579,8 +670,9 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 CachedClientError::Client(err) => Error::Client(err),
             })?;
 
-        // If the archive is missing the required hashes, force a refresh.
-        if revision.has_digests(hashes) {
+        // If the archive is missing the required hashes, or wasn't verified against its
+        // signature, force a refresh.
+        if revision.has_digests(hashes) && (revision.is_signed() || !signed) {
             Ok(revision)
         } else {
             self.client
@@ -762,19 +854,28 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         // Read the existing metadata from the cache.
         let revision_entry = cache_shard.entry(LOCAL_REVISION);
 
+        // Distributions in signed `--find-links` directories are verified before they're unpacked.
+        let policy = self
+            .client
+            .signature_policy()
+            .filter(|policy| policy.is_signed_file(&resource.path));
+
         // If the revision already exists, return it. There's no need to check for freshness, since
         // we use an exact timestamp.
         if let Some(pointer) = LocalRevisionPointer::read_from(&revision_entry)? {
             if pointer.is_up_to_date(modified) {
                 let revision = pointer.into_revision();
-                if revision.has_digests(hashes) {
+                if revision.has_digests(hashes) && (revision.is_signed() || policy.is_none()) {
                     return Ok(revision);
                 }
             }
         }
 
         // Otherwise, we need to create a new revision.
-        let revision = Revision::new();
+        if let Some(policy) = policy {
+            policy.verify_file(&resource.path)?;
+        }
+        let revision = Revision::new().with_signed(policy.is_some());
 
         // Unzip the archive to a temporary directory.
         debug!("Unpacking source distribution: {source}");
@@ -994,22 +1095,8 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             return Err(Error::HashesNotSupportedGit(source.to_string()));
         }
 
-        // Resolve to a precise Git SHA.
-        let url = if let Some(url) = resolve_precise(
-            resource.url,
-            self.build_context.cache(),
-            self.reporter.as_ref(),
-        )
-        .await?
-        {
-            Cow::Owned(url)
-        } else {
-            Cow::Borrowed(resource.url)
-        };
-
-        // Fetch the Git repository.
-        let (fetch, subdirectory) =
-            fetch_git_archive(&url, self.build_context.cache(), self.reporter.as_ref()).await?;
+        // Resolve to a precise Git SHA, and fetch the Git repository.
+        let (url, fetch, subdirectory) = self.fetch_git(resource).await?;
 
         let git_sha = fetch.git().precise().expect("Exact commit after checkout");
         let cache_shard = self.build_context.cache().shard(
@@ -1066,22 +1153,8 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             return Err(Error::HashesNotSupportedGit(source.to_string()));
         }
 
-        // Resolve to a precise Git SHA.
-        let url = if let Some(url) = resolve_precise(
-            resource.url,
-            self.build_context.cache(),
-            self.reporter.as_ref(),
-        )
-        .await?
-        {
-            Cow::Owned(url)
-        } else {
-            Cow::Borrowed(resource.url)
-        };
-
-        // Fetch the Git repository.
-        let (fetch, subdirectory) =
-            fetch_git_archive(&url, self.build_context.cache(), self.reporter.as_ref()).await?;
+        // Resolve to a precise Git SHA, and fetch the Git repository.
+        let (url, fetch, subdirectory) = self.fetch_git(resource).await?;
 
         let git_sha = fetch.git().precise().expect("Exact commit after checkout");
         let cache_shard = self.build_context.cache().shard(
@@ -1146,6 +1219,32 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         Ok(ArchiveMetadata::from(metadata))
     }
 
+    /// Resolve a Git source to a precise commit, and fetch the repository at that commit into the
+    /// cache, returning the precise URL, the fetched checkout, and any subdirectory.
+    async fn fetch_git<'data>(
+        &self,
+        resource: &GitSourceUrl<'data>,
+    ) -> Result<(Cow<'data, Url>, Fetch, Option<PathBuf>), Error> {
+        // Resolve to a precise Git SHA.
+        let url = if let Some(url) = resolve_precise(
+            resource.url,
+            self.build_context.cache(),
+            self.reporter.as_ref(),
+        )
+        .await?
+        {
+            Cow::Owned(url)
+        } else {
+            Cow::Borrowed(resource.url)
+        };
+
+        // Fetch the Git repository.
+        let (fetch, subdirectory) =
+            fetch_git_archive(&url, self.build_context.cache(), self.reporter.as_ref()).await?;
+
+        Ok((url, fetch, subdirectory))
+    }
+
     /// Download and unzip a source distribution into the cache from an HTTP response.
     async fn download_archive(
         &self,
@@ -1276,6 +1375,16 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         fs::create_dir_all(&cache_shard)
             .await
             .map_err(Error::CacheWrite)?;
+
+        // Avoid races with other processes building into the same cache entry.
+        let _lock = LockedFile::acquire_async(
+            cache_shard.join(".lock"),
+            source,
+            self.build_context.cache().lock_timeout(),
+        )
+        .await
+        .map_err(Error::CacheWrite)?;
+
         let disk_filename = self
             .build_context
             .setup_build(
@@ -14,6 +14,9 @@ use pypi_types::HashDigest;
 pub(crate) struct Revision {
     id: RevisionId,
     hashes: Vec<HashDigest>,
+    /// Whether the source distribution was verified against a detached signature.
+    #[serde(default)]
+    signed: bool,
 }
 
 impl Revision {
@@ -22,6 +25,7 @@ impl Revision {
         Self {
             id: RevisionId::new(),
             hashes: vec![],
+            signed: false,
         }
     }
 
@@ -46,6 +50,18 @@ impl Revision {
         self.hashes = hashes;
         self
     }
+
+    /// Returns `true` if the source distribution was verified against a detached signature.
+    pub(crate) fn is_signed(&self) -> bool {
+        self.signed
+    }
+
+    /// Mark the source distribution as verified against a detached signature.
+    #[must_use]
+    pub(crate) fn with_signed(mut self, signed: bool) -> Self {
+        self.signed = signed;
+        self
+    }
 }
 
 impl Hashed for Revision {
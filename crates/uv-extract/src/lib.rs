@@ -7,4 +7,6 @@ pub mod seek;
 pub mod stream;
 mod sync;
 mod tar;
+#[cfg(all(target_os = "linux", feature = "io-uring"))]
+mod uring;
 mod vendor;
@@ -25,8 +25,9 @@ pub async fn unzip<R: tokio::io::AsyncRead + Unpin>(
 
     while let Some(mut entry) = zip.next_with_entry().await? {
         // Construct the (expected) path to the file on-disk.
+        // On Windows, use an extended-length path, since wheels can contain deeply nested files.
         let path = entry.reader().entry().filename().as_str()?;
-        let path = target.join(path);
+        let path = uv_fs::verbatim_path(&target.join(path)).into_owned();
         let is_dir = entry.reader().entry().dir()?;
 
         // Either create the directory or write the file to disk.
@@ -82,7 +83,7 @@ pub async fn unzip<R: tokio::io::AsyncRead + Unpin>(
             if has_any_executable_bit != 0 {
                 // Construct the (expected) path to the file on-disk.
                 let path = entry.filename().as_str()?;
-                let path = target.join(path);
+                let path = uv_fs::verbatim_path(&target.join(path)).into_owned();
 
                 let permissions = fs_err::tokio::metadata(&path).await?.permissions();
                 fs_err::tokio::set_permissions(
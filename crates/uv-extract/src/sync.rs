@@ -1,20 +1,76 @@
+use std::io::{Cursor, Read, Seek};
 use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 
+use memmap2::Mmap;
+
 use rayon::prelude::*;
 use rustc_hash::FxHashSet;
+use tracing::debug;
 use zip::ZipArchive;
 
 use crate::vendor::{CloneableSeekableReader, HasLength};
 use crate::Error;
 
 /// Unzip a `.zip` archive into the target directory.
-pub fn unzip<R: Send + std::io::Read + std::io::Seek + HasLength>(
-    reader: R,
+pub fn unzip<R: Send + Read + Seek + HasLength>(reader: R, target: &Path) -> Result<(), Error> {
+    let archive = ZipArchive::new(CloneableSeekableReader::new(reader))?;
+    unzip_archive(&archive, target)
+}
+
+/// Unzip a `.zip` archive on disk into the target directory.
+///
+/// The archive is memory-mapped, such that the central directory and the file contents are read
+/// directly from the mapping, rather than through a shared file handle that must be re-seeked
+/// (and re-buffered) on every read. Falls back to [`unzip`] if the file can't be mapped.
+pub fn unzip_file(path: &Path, target: &Path) -> Result<(), Error> {
+    let file = fs_err::File::open(path)?;
+
+    // SAFETY: The mapping is read-only and dropped before returning. Like any mapping, it's
+    // subject to concurrent modification of the underlying file, which we don't guard against
+    // here (as with reading the file directly, the result would be a corrupt wheel).
+    let mmap = unsafe { Mmap::map(file.file()) };
+    unzip_mapped(file, mmap, target)
+}
+
+/// Unzip a `.zip` archive from its memory mapping, or from the file itself if it couldn't be
+/// mapped.
+fn unzip_mapped(
+    file: fs_err::File,
+    mmap: std::io::Result<Mmap>,
+    target: &Path,
+) -> Result<(), Error> {
+    let mmap = match mmap {
+        Ok(mmap) => mmap,
+        Err(err) => {
+            debug!("Failed to memory-map `{}`: {err}", file.path().display());
+            return unzip(file, target);
+        }
+    };
+
+    let archive = ZipArchive::new(Cursor::new(&mmap[..]))?;
+    unzip_archive(&archive, target)
+}
+
+/// Unzip the files in a [`ZipArchive`] into the target directory.
+fn unzip_archive<R: Read + Seek + Clone + Send + Sync>(
+    archive: &ZipArchive<R>,
+    target: &Path,
+) -> Result<(), Error> {
+    // If available, write the files via `io_uring`.
+    #[cfg(all(target_os = "linux", feature = "io-uring"))]
+    if let Some(writer) = crate::uring::Writer::new() {
+        return crate::uring::unzip(archive, target, writer);
+    }
+
+    unzip_parallel(archive, target)
+}
+
+/// Unzip the files in a [`ZipArchive`] into the target directory, in parallel.
+pub(crate) fn unzip_parallel<R: Read + Seek + Clone + Send + Sync>(
+    archive: &ZipArchive<R>,
     target: &Path,
 ) -> Result<(), Error> {
-    // Unzip in parallel.
-    let archive = ZipArchive::new(CloneableSeekableReader::new(reader))?;
     let directories = Mutex::new(FxHashSet::default());
     (0..archive.len())
         .par_bridge()
@@ -27,8 +83,9 @@ pub fn unzip<R: Send + std::io::Read + std::io::Seek + HasLength>(
                 return Ok(());
             };
 
-            // Create necessary parent directories.
-            let path = target.join(enclosed_name);
+            // Create necessary parent directories. On Windows, use an extended-length path, since
+            // wheels can contain deeply nested files.
+            let path = uv_fs::verbatim_path(&target.join(enclosed_name)).into_owned();
             if file.is_dir() {
                 let mut directories = directories.lock().unwrap();
                 if directories.insert(path.clone()) {
@@ -95,3 +152,106 @@ pub fn strip_component(source: impl AsRef<Path>) -> Result<PathBuf, Error> {
         )),
     }
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::io::{Cursor, Write};
+    use std::path::Path;
+
+    use zip::write::FileOptions;
+    use zip::{CompressionMethod, ZipWriter};
+
+    use super::{unzip, unzip_file, unzip_mapped};
+
+    /// Write a wheel-like archive with nested, empty, executable, stored, and large files.
+    fn archive(path: &Path) {
+        let deflated = FileOptions::default().compression_method(CompressionMethod::Deflated);
+        let stored = FileOptions::default().compression_method(CompressionMethod::Stored);
+
+        let mut writer = ZipWriter::new(fs_err::File::create(path).unwrap());
+        writer.add_directory("foo/empty/", deflated).unwrap();
+        writer.start_file("foo/__init__.py", deflated).unwrap();
+        writer.write_all(b"print('hello')\n").unwrap();
+        writer
+            .start_file("foo/data/nested/blob.bin", stored)
+            .unwrap();
+        writer.write_all(&[0, 1, 2, 3, 255]).unwrap();
+        writer.start_file("foo/large.txt", deflated).unwrap();
+        writer.write_all(&b"0123456789".repeat(256 * 1024)).unwrap();
+        writer
+            .start_file("foo-1.0.data/scripts/foo", deflated.unix_permissions(0o755))
+            .unwrap();
+        writer.write_all(b"#!python\n").unwrap();
+        writer
+            .start_file("foo-1.0.dist-info/RECORD", deflated)
+            .unwrap();
+        writer.finish().unwrap();
+    }
+
+    /// Return the (sorted) paths, contents, and executable bits of every entry in a directory.
+    pub(crate) fn snapshot(root: &Path) -> Vec<(String, Option<Vec<u8>>, bool)> {
+        fn walk(root: &Path, dir: &Path, entries: &mut Vec<(String, Option<Vec<u8>>, bool)>) {
+            for entry in fs_err::read_dir(dir).unwrap() {
+                let path = entry.unwrap().path();
+                let relative = path.strip_prefix(root).unwrap().display().to_string();
+                if path.is_dir() {
+                    entries.push((relative, None, false));
+                    walk(root, &path, entries);
+                } else {
+                    #[cfg(unix)]
+                    let executable = {
+                        use std::os::unix::fs::PermissionsExt;
+                        fs_err::metadata(&path).unwrap().permissions().mode() & 0o111 != 0
+                    };
+                    #[cfg(not(unix))]
+                    let executable = false;
+                    entries.push((relative, Some(fs_err::read(&path).unwrap()), executable));
+                }
+            }
+        }
+
+        let mut entries = Vec::new();
+        walk(root, root, &mut entries);
+        entries.sort();
+        entries
+    }
+
+    #[test]
+    fn unzip_file_matches_unzip() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let wheel = temp_dir.path().join("foo-1.0-py3-none-any.whl");
+        archive(&wheel);
+
+        // Read the archive through a shared file handle.
+        let expected = temp_dir.path().join("expected");
+        unzip(fs_err::File::open(&wheel).unwrap(), &expected).unwrap();
+
+        // Read the archive from an in-memory buffer.
+        let buffered = temp_dir.path().join("buffered");
+        let bytes = fs_err::read(&wheel).unwrap();
+        unzip(Cursor::new(bytes), &buffered).unwrap();
+
+        // Read the archive through a memory mapping.
+        let mapped = temp_dir.path().join("mapped");
+        unzip_file(&wheel, &mapped).unwrap();
+
+        // Fall back to reading the file if it can't be mapped.
+        let fallback = temp_dir.path().join("fallback");
+        unzip_mapped(
+            fs_err::File::open(&wheel).unwrap(),
+            Err(std::io::Error::other("mmap failed")),
+            &fallback,
+        )
+        .unwrap();
+
+        let expected = snapshot(&expected);
+        assert_eq!(expected.len(), 12);
+        #[cfg(unix)]
+        assert!(expected
+            .iter()
+            .any(|(path, _, executable)| path.ends_with("scripts/foo") && *executable));
+        assert_eq!(snapshot(&buffered), expected);
+        assert_eq!(snapshot(&mapped), expected);
+        assert_eq!(snapshot(&fallback), expected);
+    }
+}
@@ -0,0 +1,368 @@
+//! Zip extraction backed by `io_uring`, on Linux.
+//!
+//! Extracting a wheel typically means creating thousands of small files, such that the cost is
+//! dominated by syscalls rather than by decompression. Here, files are decompressed in parallel
+//! into memory, and then written in batches: each file's `openat`, `write`, and `close` are
+//! submitted as a single linked chain, using direct descriptors, such that a batch of files costs
+//! a single `io_uring_enter` rather than three syscalls per file.
+
+use std::ffi::CString;
+use std::io::{self, Read, Seek};
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::fs::FileExt;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use io_uring::{opcode, squeue, types, IoUring, Probe};
+use rayon::prelude::*;
+use rustc_hash::FxHashSet;
+use zip::ZipArchive;
+
+use crate::Error;
+
+/// The number of files submitted to the kernel at once.
+const BATCH_SIZE: u32 = 64;
+
+/// The maximum number of decompressed bytes to buffer in memory at once.
+const BUFFER_SIZE: u64 = 64 * 1024 * 1024;
+
+/// Files larger than this are streamed to disk directly, rather than buffered in memory.
+const LARGE_FILE_SIZE: u64 = 8 * 1024 * 1024;
+
+/// A decompressed file, waiting to be written to disk.
+struct PendingFile {
+    path: PathBuf,
+    contents: Vec<u8>,
+    executable: bool,
+}
+
+/// The operations in the chain submitted for each file, encoded in the completion's user data.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Op {
+    Open = 0,
+    Write = 1,
+    Close = 2,
+}
+
+/// Writes files to disk via `io_uring`.
+pub(crate) struct Writer {
+    ring: IoUring,
+}
+
+impl Writer {
+    /// Create a [`Writer`], or return `None` if `io_uring` is unavailable (e.g., on older kernels,
+    /// or if it's disabled via `seccomp` or `io_uring_disabled`).
+    pub(crate) fn new() -> Option<Self> {
+        let ring = IoUring::new(BATCH_SIZE * 4).ok()?;
+
+        // Direct descriptors require Linux 5.15.
+        ring.submitter().register_files_sparse(BATCH_SIZE).ok()?;
+
+        let mut probe = Probe::new();
+        ring.submitter().register_probe(&mut probe).ok()?;
+        if ![
+            opcode::OpenAt::CODE,
+            opcode::Write::CODE,
+            opcode::Close::CODE,
+        ]
+        .into_iter()
+        .all(|code| probe.is_supported(code))
+        {
+            return None;
+        }
+
+        Some(Self { ring })
+    }
+
+    /// Write a set of files to disk. The parent directories must already exist.
+    fn write(&mut self, files: &[PendingFile]) -> io::Result<()> {
+        for batch in files.chunks(BATCH_SIZE as usize) {
+            self.write_batch(batch)?;
+        }
+        Ok(())
+    }
+
+    fn write_batch(&mut self, files: &[PendingFile]) -> io::Result<()> {
+        let paths = files
+            .iter()
+            .map(|file| CString::new(file.path.as_os_str().as_bytes()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for (index, (file, path)) in files.iter().zip(&paths).enumerate() {
+            #[allow(clippy::cast_possible_truncation)]
+            let slot = index as u32;
+            let destination = types::DestinationSlot::try_from_slot_target(slot)
+                .map_err(|_| io::Error::other("invalid direct descriptor slot"))?;
+
+            let entries = [
+                opcode::OpenAt::new(types::Fd(libc::AT_FDCWD), path.as_ptr())
+                    // Direct descriptors are never inherited, and reject `O_CLOEXEC`.
+                    .flags(libc::O_WRONLY | libc::O_CREAT | libc::O_TRUNC)
+                    .mode(0o666)
+                    .file_index(Some(destination))
+                    .build()
+                    .flags(squeue::Flags::IO_LINK)
+                    .user_data(user_data(index, Op::Open)),
+                #[allow(clippy::cast_possible_truncation)]
+                opcode::Write::new(
+                    types::Fixed(slot),
+                    file.contents.as_ptr(),
+                    file.contents.len() as u32,
+                )
+                .offset(0)
+                .build()
+                .flags(squeue::Flags::IO_LINK)
+                .user_data(user_data(index, Op::Write)),
+                opcode::Close::new(types::Fixed(slot))
+                    .build()
+                    .user_data(user_data(index, Op::Close)),
+            ];
+
+            // SAFETY: The paths and contents outlive the submission, as we wait for every
+            // completion below before returning.
+            unsafe { self.ring.submission().push_multiple(&entries) }
+                .map_err(|_| io::Error::other("io_uring submission queue is full"))?;
+        }
+
+        // Wait for every operation to complete. Operations that follow a failure in their chain
+        // are completed with `ECANCELED`.
+        let mut remaining = files.len() * 3;
+        let mut written = vec![0; files.len()];
+        let mut error: Option<(usize, io::Error)> = None;
+        while remaining > 0 {
+            self.ring.submit_and_wait(remaining)?;
+            for cqe in self.ring.completion() {
+                remaining -= 1;
+                let (index, op) = parse_user_data(cqe.user_data());
+                let result = cqe.result();
+                if result < 0 {
+                    if result == -libc::ECANCELED {
+                        continue;
+                    }
+                    if error.as_ref().map_or(true, |(first, _)| index < *first) {
+                        error = Some((index, io::Error::from_raw_os_error(-result)));
+                    }
+                } else if op == Op::Write {
+                    #[allow(clippy::cast_sign_loss)]
+                    let n = result as usize;
+                    written[index] = n;
+                }
+            }
+        }
+
+        if let Some((index, err)) = error {
+            return Err(io::Error::new(
+                err.kind(),
+                format!("failed to write `{}`: {err}", files[index].path.display()),
+            ));
+        }
+
+        for (file, written) in files.iter().zip(written) {
+            // Complete any short writes.
+            if written < file.contents.len() {
+                let outfile = fs_err::OpenOptions::new().write(true).open(&file.path)?;
+                outfile
+                    .file()
+                    .write_all_at(&file.contents[written..], written as u64)?;
+            }
+
+            if file.executable {
+                set_executable(&file.path)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Unzip a `.zip` archive into the target directory, writing the files via `io_uring`.
+///
+/// See [`crate::unzip`] and [`crate::unzip_file`].
+pub(crate) fn unzip<R: Read + Seek + Clone + Send + Sync>(
+    archive: &ZipArchive<R>,
+    target: &Path,
+    mut writer: Writer,
+) -> Result<(), Error> {
+    let directories = Mutex::new(FxHashSet::default());
+    let create_dir_all = |path: &Path| -> io::Result<()> {
+        let mut directories = directories.lock().unwrap();
+        if directories.insert(path.to_path_buf()) {
+            fs_err::create_dir_all(path)?;
+        }
+        Ok(())
+    };
+
+    // Collect the files in the archive, along with their (uncompressed) sizes.
+    let mut files = Vec::new();
+    {
+        let mut archive = archive.clone();
+        for file_number in 0..archive.len() {
+            let file = archive.by_index_raw(file_number)?;
+            let Some(enclosed_name) = file.enclosed_name() else {
+                continue;
+            };
+            let path = target.join(enclosed_name);
+            if file.is_dir() {
+                create_dir_all(&path)?;
+                continue;
+            }
+            files.push((file_number, path, file.size()));
+        }
+    }
+
+    // Split the files into chunks that can be buffered in memory.
+    let mut chunks: Vec<Vec<(usize, PathBuf, u64)>> = vec![Vec::new()];
+    let mut buffered = 0;
+    for (file_number, path, size) in files {
+        if buffered + size > BUFFER_SIZE && buffered > 0 {
+            chunks.push(Vec::new());
+            buffered = 0;
+        }
+        if size <= LARGE_FILE_SIZE {
+            buffered += size;
+        }
+        chunks.last_mut().unwrap().push((file_number, path, size));
+    }
+
+    for chunk in chunks {
+        // Decompress the files in parallel.
+        let pending = chunk
+            .into_par_iter()
+            .map(|(file_number, path, size)| -> Result<_, Error> {
+                let mut archive = archive.clone();
+                let mut file = archive.by_index(file_number)?;
+
+                if let Some(parent) = path.parent() {
+                    create_dir_all(parent)?;
+                }
+
+                let executable = file.unix_mode().is_some_and(|mode| mode & 0o111 != 0);
+
+                // Stream large files to disk directly.
+                if size > LARGE_FILE_SIZE {
+                    let mut outfile = fs_err::File::create(&path)?;
+                    preallocate(&outfile, size);
+                    io::copy(&mut file, &mut outfile)?;
+                    if executable {
+                        set_executable(&path)?;
+                    }
+                    return Ok(None);
+                }
+
+                #[allow(clippy::cast_possible_truncation)]
+                let mut contents = Vec::with_capacity(size as usize);
+                file.read_to_end(&mut contents)?;
+                Ok(Some(PendingFile {
+                    path,
+                    contents,
+                    executable,
+                }))
+            })
+            .filter_map(Result::transpose)
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        // Write the files to disk.
+        writer.write(&pending)?;
+    }
+
+    Ok(())
+}
+
+/// Reserve disk space for a file of the given size up front, to reduce fragmentation.
+///
+/// Unlike `set_len`, which only creates a sparse file, `fallocate` allocates the blocks. Failures
+/// (e.g., on filesystems that don't support it) are ignored, as the blocks will be allocated as
+/// the file is written anyway.
+fn preallocate(file: &fs_err::File, size: u64) {
+    use std::os::fd::AsRawFd;
+
+    let Ok(size) = libc::off_t::try_from(size) else {
+        return;
+    };
+    // SAFETY: The descriptor is valid for the lifetime of `file`.
+    unsafe {
+        libc::fallocate(file.file().as_raw_fd(), 0, 0, size);
+    }
+}
+
+/// Add the executable bit to a file, matching [`crate::unzip`].
+fn set_executable(path: &Path) -> io::Result<()> {
+    use std::fs::Permissions;
+    use std::os::unix::fs::PermissionsExt;
+
+    let permissions = fs_err::metadata(path)?.permissions();
+    fs_err::set_permissions(path, Permissions::from_mode(permissions.mode() | 0o111))
+}
+
+fn user_data(index: usize, op: Op) -> u64 {
+    ((index as u64) << 2) | op as u64
+}
+
+fn parse_user_data(user_data: u64) -> (usize, Op) {
+    #[allow(clippy::cast_possible_truncation)]
+    let index = (user_data >> 2) as usize;
+    let op = match user_data & 0b11 {
+        0 => Op::Open,
+        1 => Op::Write,
+        _ => Op::Close,
+    };
+    (index, op)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use zip::write::FileOptions;
+    use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+    use crate::sync::tests::snapshot;
+    use crate::sync::unzip_parallel;
+
+    use super::{unzip, Writer, BATCH_SIZE, LARGE_FILE_SIZE};
+
+    #[test]
+    fn unzip_matches_parallel() {
+        let Some(writer) = Writer::new() else {
+            // CI sets `UV_TEST_REQUIRE_IO_URING`, such that the test can't silently pass there.
+            assert!(
+                std::env::var_os("UV_TEST_REQUIRE_IO_URING").is_none(),
+                "io_uring is unavailable"
+            );
+            return;
+        };
+
+        // Write enough files to span multiple batches, along with a file that's large enough to
+        // be streamed to disk directly.
+        let options = FileOptions::default().compression_method(CompressionMethod::Deflated);
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        for index in 0..=BATCH_SIZE * 2 {
+            zip.start_file(format!("foo/module_{index}.py"), options)
+                .unwrap();
+            zip.write_all(format!("value = {index}\n").as_bytes())
+                .unwrap();
+        }
+        zip.add_directory("foo/empty/", options).unwrap();
+        zip.start_file("foo/__init__.py", options).unwrap();
+        zip.start_file("foo/large.bin", options).unwrap();
+        #[allow(clippy::cast_possible_truncation)]
+        zip.write_all(&b"0123456789abcdef".repeat(LARGE_FILE_SIZE as usize / 16 + 1))
+            .unwrap();
+        zip.start_file("foo-1.0.data/scripts/foo", options.unix_permissions(0o755))
+            .unwrap();
+        zip.write_all(b"#!python\n").unwrap();
+        let archive = ZipArchive::new(zip.finish().unwrap()).unwrap();
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let expected = temp_dir.path().join("parallel");
+        unzip_parallel(&archive, &expected).unwrap();
+        let actual = temp_dir.path().join("uring");
+        unzip(&archive, &actual, writer).unwrap();
+
+        let expected = snapshot(&expected);
+        assert!(expected
+            .iter()
+            .any(|(path, _, executable)| path.ends_with("scripts/foo") && *executable));
+        assert_eq!(snapshot(&actual), expected);
+    }
+}
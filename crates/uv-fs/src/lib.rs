@@ -1,5 +1,6 @@
 use std::fmt::Display;
 use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
 use fs2::FileExt;
 use fs_err as fs;
@@ -185,7 +186,7 @@ pub async fn rename_with_retry(
         backoff::future::retry(backoff, || async move {
             match fs_err::rename(from, to) {
                 Ok(()) => Ok(()),
-                Err(err) if err.kind() == std::io::ErrorKind::PermissionDenied => {
+                Err(err) if is_transient_error(&err) => {
                     warn!(
                         "Retrying rename from {} to {} due to transient error: {}",
                         from.display(),
@@ -203,6 +204,64 @@ pub async fn rename_with_retry(
     }
 }
 
+/// Rename a file, retrying (on Windows) if it fails due to transient operating system errors, in
+/// a synchronous context.
+///
+/// See [`rename_with_retry`] for the asynchronous equivalent.
+pub fn rename_with_retry_sync(
+    from: impl AsRef<Path>,
+    to: impl AsRef<Path>,
+) -> Result<(), std::io::Error> {
+    if cfg!(windows) {
+        // On Windows, antivirus software and indexers can hold files open without
+        // `FILE_SHARE_DELETE`, causing sharing violations when replacing them. Retry the operation
+        // with some backoff.
+        let from = from.as_ref();
+        let to = to.as_ref();
+
+        let backoff = backoff::ExponentialBackoffBuilder::default()
+            .with_initial_interval(std::time::Duration::from_millis(10))
+            .with_max_elapsed_time(Some(std::time::Duration::from_secs(10)))
+            .build();
+
+        backoff::retry(backoff, || match fs_err::rename(from, to) {
+            Ok(()) => Ok(()),
+            Err(err) if is_transient_error(&err) => {
+                warn!(
+                    "Retrying rename from {} to {} due to transient error: {}",
+                    from.display(),
+                    to.display(),
+                    err
+                );
+                Err(backoff::Error::transient(err))
+            }
+            Err(err) => Err(backoff::Error::permanent(err)),
+        })
+        .map_err(|err| match err {
+            backoff::Error::Permanent(err) => err,
+            backoff::Error::Transient { err, .. } => err,
+        })
+    } else {
+        fs_err::rename(from, to)
+    }
+}
+
+/// Returns `true` if the error is likely to be transient on Windows, i.e., caused by another
+/// process (like an antivirus scanner) temporarily holding a handle to the file.
+fn is_transient_error(err: &std::io::Error) -> bool {
+    /// The process cannot access the file because it is being used by another process.
+    const ERROR_SHARING_VIOLATION: i32 = 32;
+    /// The process cannot access the file because another process has locked a portion of it.
+    const ERROR_LOCK_VIOLATION: i32 = 33;
+
+    err.kind() == std::io::ErrorKind::PermissionDenied
+        || (cfg!(windows)
+            && matches!(
+                err.raw_os_error(),
+                Some(ERROR_SHARING_VIOLATION | ERROR_LOCK_VIOLATION)
+            ))
+}
+
 /// Iterate over the subdirectories of a directory.
 ///
 /// If the directory does not exist, returns an empty iterator.
@@ -276,48 +335,224 @@ pub fn files(path: impl AsRef<Path>) -> impl Iterator<Item = PathBuf> {
 }
 
 /// A file lock that is automatically released when dropped.
+///
+/// The lock is an advisory lock held by the operating system (`flock` on Unix, `LockFileEx` on
+/// Windows), so it's released automatically if the owning process exits or crashes, and a lock
+/// file left behind on disk is never considered held on its own. The PID of the owning process is
+/// written to an adjacent `.pid` file (as, on Windows, the lock file itself can't be read by other
+/// processes while it's locked), to help identify the holder when waiting; it's removed when the
+/// lock is released.
 #[derive(Debug)]
-pub struct LockedFile(fs_err::File);
+pub struct LockedFile {
+    file: fs_err::File,
+    /// Whether the current process is recorded as the owner of the lock.
+    owned: bool,
+}
 
 impl LockedFile {
-    pub fn acquire(path: impl AsRef<Path>, resource: impl Display) -> Result<Self, std::io::Error> {
-        let file = fs_err::File::create(path.as_ref())?;
+    /// Acquire an exclusive lock, waiting for up to `timeout` (or indefinitely, if `None`) for
+    /// another process to release it.
+    ///
+    /// Blocks the current thread while waiting; in asynchronous contexts, use
+    /// [`LockedFile::acquire_async`] instead.
+    pub fn acquire(
+        path: impl AsRef<Path>,
+        resource: impl Display,
+        timeout: Option<Duration>,
+    ) -> Result<Self, std::io::Error> {
+        let file = fs_err::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .open(path.as_ref())?;
         debug!("Trying to lock if free: {}", path.as_ref().user_display());
         match file.file().try_lock_exclusive() {
-            Ok(()) => Ok(Self(file)),
+            Ok(()) => Self::acquired(file),
             Err(err) => {
                 // Log error code and enum kind to help debugging more exotic failures
                 debug!("Try lock error, waiting for exclusive lock: {:?}", err);
-                warn_user!(
-                    "Waiting to acquire lock for {} (lockfile: {})",
-                    resource,
-                    path.user_display(),
-                );
-                file.file().lock_exclusive().map_err(|err| {
-                    // Not an fs_err method, we need to build our own path context
-                    std::io::Error::new(
-                        std::io::ErrorKind::Other,
-                        format!("Could not lock {}: {}", path.as_ref().user_display(), err),
-                    )
-                })?;
-                Ok(Self(file))
+                let owner = Self::owner(path.as_ref());
+                if let Some(owner) = owner {
+                    warn_user!(
+                        "Waiting for another uv process (pid {owner}) to release lock for {} (lockfile: {})",
+                        resource,
+                        path.user_display(),
+                    );
+                } else {
+                    warn_user!(
+                        "Waiting to acquire lock for {} (lockfile: {})",
+                        resource,
+                        path.user_display(),
+                    );
+                }
+
+                let Some(timeout) = timeout else {
+                    file.file().lock_exclusive().map_err(|err| {
+                        // Not an fs_err method, we need to build our own path context
+                        std::io::Error::new(
+                            std::io::ErrorKind::Other,
+                            format!("Could not lock {}: {}", path.as_ref().user_display(), err),
+                        )
+                    })?;
+                    return Self::acquired(file);
+                };
+
+                // Poll for the lock until the timeout elapses.
+                let start = Instant::now();
+                loop {
+                    std::thread::sleep(Duration::from_millis(100));
+                    match file.file().try_lock_exclusive() {
+                        Ok(()) => return Self::acquired(file),
+                        Err(err) if start.elapsed() >= timeout => {
+                            debug!("Try lock error after timeout: {:?}", err);
+                            return Err(std::io::Error::new(
+                                std::io::ErrorKind::TimedOut,
+                                format!(
+                                    "Timed out after {}s waiting for lock on {} (lockfile: {}{})",
+                                    timeout.as_secs_f32(),
+                                    resource,
+                                    path.as_ref().user_display(),
+                                    owner
+                                        .map(|owner| format!(", held by pid {owner}"))
+                                        .unwrap_or_default()
+                                ),
+                            ));
+                        }
+                        Err(_) => {}
+                    }
+                }
+            }
+        }
+    }
+
+    /// Acquire an exclusive lock, waiting for up to `timeout` (or indefinitely, if `None`) for
+    /// another process to release it.
+    ///
+    /// The lock is acquired on a blocking thread, such that waiting doesn't block the runtime.
+    #[cfg(feature = "tokio")]
+    pub async fn acquire_async(
+        path: impl AsRef<Path>,
+        resource: impl Display,
+        timeout: Option<Duration>,
+    ) -> Result<Self, std::io::Error> {
+        let path = path.as_ref().to_path_buf();
+        let resource = resource.to_string();
+        tokio::task::spawn_blocking(move || Self::acquire(path, resource, timeout)).await?
+    }
+
+    /// Acquire an exclusive lock if it's free, without waiting, returning `None` if the lock is
+    /// held (whether shared or exclusive) by another handle.
+    pub fn try_acquire(path: impl AsRef<Path>) -> Result<Option<Self>, std::io::Error> {
+        let file = fs_err::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .open(path.as_ref())?;
+        match file.file().try_lock_exclusive() {
+            Ok(()) => Self::acquired(file).map(Some),
+            Err(err) => {
+                debug!("Lock is held: {} ({err:?})", path.as_ref().user_display());
+                Ok(None)
             }
         }
     }
+
+    /// Acquire a shared lock, waiting for any exclusive lock to be released.
+    ///
+    /// Any number of handles may hold a shared lock at once, so the owner isn't recorded.
+    pub fn acquire_shared(
+        path: impl AsRef<Path>,
+        resource: impl Display,
+    ) -> Result<Self, std::io::Error> {
+        let file = fs_err::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .open(path.as_ref())?;
+        if let Err(err) = file.file().try_lock_shared() {
+            debug!("Try lock error, waiting for shared lock: {:?}", err);
+            warn_user!(
+                "Waiting to acquire lock for {} (lockfile: {})",
+                resource,
+                path.user_display(),
+            );
+            file.file().lock_shared().map_err(|err| {
+                std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    format!("Could not lock {}: {}", path.as_ref().user_display(), err),
+                )
+            })?;
+        }
+        Ok(Self { file, owned: false })
+    }
+
+    /// Record the current process as the owner of an acquired lock.
+    fn acquired(file: fs_err::File) -> Result<Self, std::io::Error> {
+        fs_err::write(owner_path(file.path()), std::process::id().to_string())?;
+        Ok(Self { file, owned: true })
+    }
+
+    /// Read the PID of the process that owns the lock at the given path, if recorded.
+    fn owner(path: &Path) -> Option<u32> {
+        fs_err::read_to_string(owner_path(path))
+            .ok()?
+            .trim()
+            .parse()
+            .ok()
+    }
+}
+
+/// The path to the file that records the owner of the lock at the given path.
+fn owner_path(path: &Path) -> PathBuf {
+    let mut owner = path.as_os_str().to_owned();
+    owner.push(".pid");
+    PathBuf::from(owner)
 }
 
 impl Drop for LockedFile {
     fn drop(&mut self) {
-        if let Err(err) = self.0.file().unlock() {
+        // Remove the owner file while the lock is still held, such that the record of the next
+        // owner is never removed.
+        if self.owned {
+            if let Err(err) = fs_err::remove_file(owner_path(self.file.path())) {
+                debug!("Failed to remove lock owner file: {err}");
+            }
+        }
+        if let Err(err) = self.file.file().unlock() {
             error!(
                 "Failed to unlock {}; program may be stuck: {}",
-                self.0.path().display(),
+                self.file.path().display(),
                 err
             );
         }
     }
 }
 
+/// Set the modification time of a file or directory to the current time.
+pub fn touch(path: impl AsRef<Path>) -> std::io::Result<()> {
+    let path = path.as_ref();
+
+    #[cfg(unix)]
+    let file = fs::File::open(path)?;
+
+    // On Windows, opening a directory requires `FILE_FLAG_BACKUP_SEMANTICS`, and updating its
+    // timestamps requires `FILE_WRITE_ATTRIBUTES`.
+    #[cfg(windows)]
+    let file = {
+        use std::os::windows::fs::OpenOptionsExt;
+
+        const FILE_WRITE_ATTRIBUTES: u32 = 0x0100;
+        const FILE_FLAG_BACKUP_SEMANTICS: u32 = 0x0200_0000;
+
+        fs::OpenOptions::new()
+            .access_mode(FILE_WRITE_ATTRIBUTES)
+            .custom_flags(FILE_FLAG_BACKUP_SEMANTICS)
+            .open(path)?
+    };
+
+    file.file().set_modified(std::time::SystemTime::now())
+}
+
 /// Given a path, return its metadata if the file exists, or `None` if it does not.
 ///
 /// If the file exists but cannot be read, returns an error.
@@ -328,3 +563,83 @@ pub fn metadata_if_exists(path: impl AsRef<Path>) -> std::io::Result<Option<std:
         Err(err) => Err(err),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lock_timeout() -> Result<(), std::io::Error> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join(".lock");
+
+        let _lock = LockedFile::acquire(&path, "test", None)?;
+        let err = LockedFile::acquire(&path, "test", Some(Duration::from_millis(200))).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::TimedOut);
+        assert!(err
+            .to_string()
+            .contains(&format!("held by pid {}", std::process::id())));
+
+        Ok(())
+    }
+
+    #[test]
+    fn lock_contention() -> Result<(), std::io::Error> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join(".lock");
+
+        // Release the lock from another thread while waiting for it.
+        let lock = LockedFile::acquire(&path, "test", None)?;
+        let release = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(300));
+            drop(lock);
+        });
+        let _lock = LockedFile::acquire(&path, "test", Some(Duration::from_secs(10)))?;
+        release.join().unwrap();
+
+        // The owner is readable while the lock is held.
+        assert_eq!(LockedFile::owner(&path), Some(std::process::id()));
+        assert!(LockedFile::try_acquire(&path)?.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn lock_owner_removed() -> Result<(), std::io::Error> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join(".lock");
+
+        let lock = LockedFile::acquire(&path, "test", None)?;
+        assert!(owner_path(&path).is_file());
+        drop(lock);
+        assert!(!owner_path(&path).exists());
+        assert_eq!(LockedFile::owner(&path), None);
+
+        // Shared locks don't record (or remove) an owner.
+        let _lock = LockedFile::acquire_shared(&path, "test")?;
+        assert!(!owner_path(&path).exists());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn lock_async() -> Result<(), std::io::Error> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join(".lock");
+
+        // Waiting for the lock must not block the (single-threaded) runtime, or the lock would
+        // never be released.
+        let lock = LockedFile::acquire(&path, "test", None)?;
+        let (acquired, ()) = tokio::join!(
+            LockedFile::acquire_async(&path, "test", Some(Duration::from_secs(10))),
+            async move {
+                tokio::task::yield_now().await;
+                drop(lock);
+            }
+        );
+        acquired?;
+
+        Ok(())
+    }
+}
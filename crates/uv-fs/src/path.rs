@@ -139,6 +139,63 @@ pub fn absolutize_path(path: &Path) -> Result<Cow<Path>, std::io::Error> {
     path.absolutize_from(&*CWD)
 }
 
+/// Convert an absolute path to its extended-length ("verbatim") form.
+///
+/// On Windows, paths longer than `MAX_PATH` (260 characters) are rejected by most file APIs
+/// unless they're prefixed with `\\?\` (or `\\?\UNC\`, for network shares). Since the prefix
+/// disables path normalization, the path is normalized (e.g., removing `.` and `..`, and
+/// converting forward slashes to backslashes) before the prefix is applied.
+///
+/// Relative paths, and paths that are already in verbatim or device form, are returned
+/// unchanged. On other platforms, this is a no-op.
+pub fn verbatim_path(path: &Path) -> Cow<'_, Path> {
+    #[cfg(windows)]
+    {
+        use std::path::Prefix;
+
+        if !path.is_absolute() {
+            return Cow::Borrowed(path);
+        }
+
+        let Some(Component::Prefix(prefix)) = path.components().next() else {
+            return Cow::Borrowed(path);
+        };
+
+        let Ok(normalized) = normalize_path(path) else {
+            return Cow::Borrowed(path);
+        };
+
+        match prefix.kind() {
+            // Ex) `C:\Users\ferris` -> `\\?\C:\Users\ferris`
+            Prefix::Disk(_) => {
+                let mut verbatim = std::ffi::OsString::from(r"\\?\");
+                verbatim.push(normalized.as_os_str());
+                Cow::Owned(PathBuf::from(verbatim))
+            }
+            // Ex) `\\server\share\ferris` -> `\\?\UNC\server\share\ferris`
+            Prefix::UNC(..) => {
+                let Some(rest) = normalized
+                    .to_str()
+                    .and_then(|path| path.strip_prefix(r"\\"))
+                else {
+                    return Cow::Borrowed(path);
+                };
+                Cow::Owned(PathBuf::from(format!(r"\\?\UNC\{rest}")))
+            }
+            // Ex) `\\?\C:\Users\ferris`, `\\?\UNC\server\share`, or `\\.\COM1`
+            Prefix::Verbatim(_)
+            | Prefix::VerbatimDisk(_)
+            | Prefix::VerbatimUNC(..)
+            | Prefix::DeviceNS(_) => Cow::Borrowed(path),
+        }
+    }
+
+    #[cfg(not(windows))]
+    {
+        Cow::Borrowed(path)
+    }
+}
+
 /// Like `fs_err::canonicalize`, but with permissive failures on Windows.
 ///
 /// On Windows, we can't canonicalize the resolved path to Pythons that are installed via the
@@ -316,4 +373,33 @@ mod tests {
         let err = normalize_path(path).unwrap_err();
         assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
     }
+
+    #[test]
+    fn test_verbatim_path() {
+        if cfg!(windows) {
+            assert_eq!(
+                verbatim_path(Path::new(r"C:\Users\ferris\..\wheel-0.42.0.tar.gz")),
+                Path::new(r"\\?\C:\Users\wheel-0.42.0.tar.gz")
+            );
+            assert_eq!(
+                verbatim_path(Path::new(r"\\server\share\ferris\wheel-0.42.0.tar.gz")),
+                Path::new(r"\\?\UNC\server\share\ferris\wheel-0.42.0.tar.gz")
+            );
+            assert_eq!(
+                verbatim_path(Path::new(r"\\?\C:\Users\ferris")),
+                Path::new(r"\\?\C:\Users\ferris")
+            );
+        } else {
+            assert_eq!(
+                verbatim_path(Path::new("/home/ferris/../wheel-0.42.0.tar.gz")),
+                Path::new("/home/ferris/../wheel-0.42.0.tar.gz")
+            );
+        }
+
+        // Relative paths are returned as-is.
+        assert_eq!(
+            verbatim_path(Path::new("ferris/wheel-0.42.0.tar.gz")),
+            Path::new("ferris/wheel-0.42.0.tar.gz")
+        );
+    }
 }
@@ -4,7 +4,7 @@ use std::sync::Arc;
 
 use futures::{FutureExt, Stream, StreamExt, TryFutureExt, TryStreamExt};
 use tokio::task::JoinError;
-use tracing::instrument;
+use tracing::{debug, instrument, warn};
 use url::Url;
 
 use distribution_types::{
@@ -14,6 +14,7 @@ use distribution_types::{
 use platform_tags::Tags;
 use uv_cache::Cache;
 use uv_client::RegistryClient;
+use uv_configuration::{RetryClass, RetryPolicy};
 use uv_distribution::{DistributionDatabase, LocalWheel};
 use uv_types::{BuildContext, HashStrategy, InFlight};
 
@@ -41,6 +42,7 @@ pub struct Downloader<'a, Context: BuildContext + Send + Sync> {
     tags: &'a Tags,
     cache: &'a Cache,
     hashes: &'a HashStrategy,
+    retry_policy: &'a RetryPolicy,
     database: DistributionDatabase<'a, Context>,
     reporter: Option<Arc<dyn Reporter>>,
 }
@@ -57,6 +59,7 @@ impl<'a, Context: BuildContext + Send + Sync> Downloader<'a, Context> {
             tags,
             cache,
             hashes,
+            retry_policy: client.retry_policy(),
             database: DistributionDatabase::new(client, build_context),
             reporter: None,
         }
@@ -70,6 +73,7 @@ impl<'a, Context: BuildContext + Send + Sync> Downloader<'a, Context> {
             tags: self.tags,
             cache: self.cache,
             hashes: self.hashes,
+            retry_policy: self.retry_policy,
             database: self.database.with_reporter(Facade::from(reporter.clone())),
             reporter: Some(reporter.clone()),
         }
@@ -117,6 +121,34 @@ impl<'a, Context: BuildContext + Send + Sync> Downloader<'a, Context> {
         Ok(wheels)
     }
 
+    /// Download, build, and unzip a stream of distributions in the background, with up to
+    /// `concurrency` fetches in flight at once.
+    ///
+    /// Each distribution is registered in the [`InFlight`] map, such that a subsequent
+    /// [`Downloader::download`] waits on the prefetch rather than fetching the distribution again.
+    /// Failures are logged here, and surface to any download that waits on the same distribution.
+    pub async fn prefetch(
+        &self,
+        distributions: impl Stream<Item = Dist>,
+        concurrency: usize,
+        in_flight: &InFlight,
+    ) {
+        if concurrency == 0 {
+            return;
+        }
+
+        distributions
+            .map(|dist| async move {
+                match self.get_wheel(dist.clone(), in_flight).boxed().await {
+                    Ok(_) => debug!("Prefetched: {dist}"),
+                    Err(err) => debug!("Failed to prefetch {dist}: {err}"),
+                }
+            })
+            .buffer_unordered(concurrency)
+            .collect::<()>()
+            .await;
+    }
+
     /// Build a set of editables
     #[instrument(skip_all)]
     pub async fn build_editables(
@@ -166,32 +198,54 @@ impl<'a, Context: BuildContext + Send + Sync> Downloader<'a, Context> {
     }
 
     /// Download, build, and unzip a single wheel.
-    #[instrument(skip_all, fields(name = % dist, size = ? dist.size(), url = dist.file().map(| file | file.url.to_string()).unwrap_or_default()))]
+    #[instrument(skip_all, fields(name = % dist, size = dist.size(), url = dist.file().map(| file | file.url.to_string()).unwrap_or_default()))]
     pub async fn get_wheel(&self, dist: Dist, in_flight: &InFlight) -> Result<CachedDist, Error> {
         let id = dist.distribution_id();
         if in_flight.downloads.register(id.clone()) {
             let policy = self.hashes.get(&dist);
-            let result = self
-                .database
-                .get_or_build_wheel(&dist, self.tags, policy)
-                .boxed()
-                .map_err(|err| Error::Fetch(dist.clone(), err))
-                .await
-                .and_then(|wheel: LocalWheel| {
-                    if wheel.satisfies(policy) {
-                        Ok(wheel)
-                    } else {
-                        Err(Error::Fetch(
-                            dist.clone(),
-                            uv_distribution::Error::hash_mismatch(
-                                dist.to_string(),
-                                policy.digests(),
-                                wheel.hashes(),
-                            ),
-                        ))
+            let retry_policy = self.retry_policy;
+            let mut attempt = 0;
+            let result = loop {
+                let result = self
+                    .database
+                    .get_or_build_wheel(&dist, self.tags, policy)
+                    .boxed()
+                    .map_err(|err| Error::Fetch(dist.clone(), err))
+                    .await
+                    .and_then(|wheel: LocalWheel| {
+                        if wheel.satisfies(policy) {
+                            Ok(wheel)
+                        } else {
+                            Err(Error::Fetch(
+                                dist.clone(),
+                                uv_distribution::Error::hash_mismatch(
+                                    dist.to_string(),
+                                    policy.digests(),
+                                    wheel.hashes(),
+                                ),
+                            ))
+                        }
+                    });
+
+                // If enabled, retry downloads with mismatched hashes, which may be caused by a
+                // corrupted response (e.g., from a misbehaving proxy).
+                match (&result, &dist) {
+                    (
+                        Err(Error::Fetch(_, uv_distribution::Error::MismatchedHashes { .. })),
+                        Dist::Built(built),
+                    ) if attempt < retry_policy.retries
+                        && retry_policy.is_retryable(RetryClass::HashMismatch) =>
+                    {
+                        warn!("Hash mismatch for {dist}; retrying download");
+                        if let Err(err) = self.database.evict_wheel(built) {
+                            break Err(Error::Fetch(dist.clone(), err));
+                        }
+                        tokio::time::sleep(retry_policy.backoff(attempt)).await;
+                        attempt += 1;
                     }
-                })
-                .map(CachedDist::from);
+                    _ => break result.map(CachedDist::from),
+                }
+            };
             match result {
                 Ok(cached) => {
                     in_flight.downloads.done(id, Ok(cached.clone()));
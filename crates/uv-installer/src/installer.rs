@@ -1,13 +1,25 @@
-use anyhow::{Context, Error, Result};
+use std::collections::hash_map::Entry;
+
+use anyhow::{bail, Context, Error, Result};
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
-use tracing::instrument;
+use rustc_hash::{FxHashMap, FxHashSet};
+use tracing::{debug, instrument};
 
-use distribution_types::CachedDist;
+use distribution_types::{CachedDist, Name};
+use install_wheel_rs::installed_scripts;
+use uv_configuration::ScriptConflictStrategy;
 use uv_interpreter::PythonEnvironment;
+use uv_normalize::PackageName;
+use uv_warnings::warn_user;
+
+use crate::SitePackages;
 
 pub struct Installer<'a> {
     venv: &'a PythonEnvironment,
     link_mode: install_wheel_rs::linker::LinkMode,
+    script_conflicts: ScriptConflictStrategy,
+    renamed_scripts: Option<FxHashMap<PackageName, FxHashMap<String, String>>>,
+    requested: Option<FxHashSet<PackageName>>,
     reporter: Option<Box<dyn Reporter>>,
     installer_name: Option<String>,
 }
@@ -18,6 +30,9 @@ impl<'a> Installer<'a> {
         Self {
             venv,
             link_mode: install_wheel_rs::linker::LinkMode::default(),
+            script_conflicts: ScriptConflictStrategy::default(),
+            renamed_scripts: None,
+            requested: None,
             reporter: None,
             installer_name: Some("uv".to_string()),
         }
@@ -29,6 +44,47 @@ impl<'a> Installer<'a> {
         Self { link_mode, ..self }
     }
 
+    /// Set the [`ScriptConflictStrategy`] to use for scripts that are provided by multiple
+    /// packages.
+    #[must_use]
+    pub fn with_script_conflicts(self, script_conflicts: ScriptConflictStrategy) -> Self {
+        Self {
+            script_conflicts,
+            ..self
+        }
+    }
+
+    /// Detect any scripts that would be provided by more than one package after installing the
+    /// given wheels, and apply the [`ScriptConflictStrategy`], ahead of installation.
+    ///
+    /// Must be called before making any changes to the environment, such that
+    /// [`ScriptConflictStrategy::Error`] fails without modifying it. Packages in `removed` are
+    /// about to be uninstalled, and are ignored. If not called, conflicts are instead detected by
+    /// [`Installer::install`].
+    pub fn check_script_conflicts(
+        self,
+        wheels: &[CachedDist],
+        removed: &FxHashSet<PackageName>,
+    ) -> Result<Self> {
+        let renamed_scripts = self.script_conflicts(wheels, removed)?;
+        Ok(Self {
+            renamed_scripts: Some(renamed_scripts),
+            ..self
+        })
+    }
+
+    /// Set the packages that were requested directly by the user, as opposed to those installed
+    /// as dependencies. Only the requested packages are marked as `REQUESTED`.
+    ///
+    /// By default, all packages are marked as requested.
+    #[must_use]
+    pub fn with_requested(self, requested: FxHashSet<PackageName>) -> Self {
+        Self {
+            requested: Some(requested),
+            ..self
+        }
+    }
+
     /// Set the [`Reporter`] to use for this installer.
     #[must_use]
     pub fn with_reporter(self, reporter: impl Reporter + 'static) -> Self {
@@ -51,12 +107,20 @@ impl<'a> Installer<'a> {
     #[instrument(skip_all, fields(num_wheels = %wheels.len()))]
     pub fn install(self, wheels: &[CachedDist]) -> Result<()> {
         let layout = self.venv.interpreter().layout();
+        let renamed_scripts = match &self.renamed_scripts {
+            Some(renamed_scripts) => renamed_scripts.clone(),
+            None => self.script_conflicts(wheels, &FxHashSet::default())?,
+        };
+        let no_renamed_scripts = FxHashMap::default();
         tokio::task::block_in_place(|| {
             wheels.par_iter().try_for_each(|wheel| {
                 install_wheel_rs::linker::install_wheel(
                     &layout,
                     wheel.path(),
                     wheel.filename(),
+                    self.requested
+                        .as_ref()
+                        .map_or(true, |requested| requested.contains(wheel.name())),
                     wheel
                         .direct_url()?
                         .as_ref()
@@ -65,9 +129,27 @@ impl<'a> Installer<'a> {
                         .as_ref(),
                     self.installer_name.as_deref(),
                     self.link_mode,
+                    renamed_scripts
+                        .get(wheel.name())
+                        .unwrap_or(&no_renamed_scripts),
                 )
                 .with_context(|| format!("Failed to install: {} ({wheel})", wheel.filename()))?;
 
+                // Record the use of the unzipped wheel, for least-recently used cache eviction.
+                if let Err(err) = uv_fs::touch(wheel.path()) {
+                    debug!(
+                        "Failed to update access time for {}: {err}",
+                        wheel.path().display()
+                    );
+                }
+
+                // Symbolic links point into the unzipped wheel, so it must never be evicted.
+                if matches!(self.link_mode, install_wheel_rs::linker::LinkMode::Symlink) {
+                    if let Err(err) = uv_cache::pin_archive(wheel.path(), self.venv.root()) {
+                        debug!("Failed to pin {}: {err}", wheel.path().display());
+                    }
+                }
+
                 if let Some(reporter) = self.reporter.as_ref() {
                     reporter.on_install_progress(wheel);
                 }
@@ -76,6 +158,98 @@ impl<'a> Installer<'a> {
             })
         })
     }
+
+    /// Detect any scripts that would be provided by more than one package after installing the
+    /// given wheels, and apply the [`ScriptConflictStrategy`].
+    ///
+    /// Returns the scripts to install under a different name, for each package.
+    fn script_conflicts(
+        &self,
+        wheels: &[CachedDist],
+        removed: &FxHashSet<PackageName>,
+    ) -> Result<FxHashMap<PackageName, FxHashMap<String, String>>> {
+        let python_minor = self.venv.interpreter().python_minor();
+        let scripts_dir = self.venv.interpreter().scripts();
+
+        // Collect the scripts declared by each wheel, in a stable order, such that the same
+        // package is renamed on every run.
+        let mut declared = wheels
+            .iter()
+            .map(|wheel| {
+                let scripts = install_wheel_rs::linker::wheel_scripts(wheel.path(), python_minor)
+                    .with_context(|| format!("Failed to read scripts from: {wheel}"))?;
+                Ok((wheel.name(), scripts))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        if declared.iter().all(|(_, scripts)| scripts.is_empty()) {
+            return Ok(FxHashMap::default());
+        }
+        declared.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        // Collect the scripts of the installed packages, ignoring any that are being replaced or
+        // removed.
+        let mut owners = FxHashMap::default();
+        let site_packages = SitePackages::from_executable(self.venv)?;
+        for dist in site_packages.iter() {
+            if removed.contains(dist.name())
+                || wheels.iter().any(|wheel| wheel.name() == dist.name())
+            {
+                continue;
+            }
+            // Ignore any packages without a `RECORD` (e.g., legacy editable installs).
+            let Ok(installed) = installed_scripts(dist.path(), scripts_dir) else {
+                continue;
+            };
+            for script in installed {
+                owners.entry(script).or_insert_with(|| dist.name().clone());
+            }
+        }
+
+        let mut conflicts = Vec::new();
+        let mut renamed_scripts = FxHashMap::default();
+        for (name, scripts) in declared {
+            for script in scripts {
+                let owner = match owners.entry(script.clone()) {
+                    Entry::Vacant(entry) => {
+                        entry.insert(name.clone());
+                        continue;
+                    }
+                    Entry::Occupied(entry) => entry.get().clone(),
+                };
+                match self.script_conflicts {
+                    ScriptConflictStrategy::Warn => {
+                        warn_user!(
+                            "The script `{script}` is provided by both `{owner}` and `{name}`, and will be overwritten"
+                        );
+                    }
+                    ScriptConflictStrategy::Error => {
+                        conflicts.push(format!(
+                            "`{script}` (provided by both `{owner}` and `{name}`)"
+                        ));
+                    }
+                    ScriptConflictStrategy::Rename => {
+                        let renamed = format!("{script}-{name}");
+                        debug!(
+                            "Installing the `{script}` script from `{name}` as `{renamed}`, as it's also provided by `{owner}`"
+                        );
+                        renamed_scripts
+                            .entry(name.clone())
+                            .or_default()
+                            .insert(script, renamed);
+                    }
+                }
+            }
+        }
+
+        if !conflicts.is_empty() {
+            bail!(
+                "Multiple packages provide the same scripts: {}",
+                conflicts.join(", ")
+            );
+        }
+
+        Ok(renamed_scripts)
+    }
 }
 
 pub trait Reporter: Send + Sync {
@@ -4,7 +4,9 @@ pub use editable::{is_dynamic, BuiltEditable, ResolvedEditable};
 pub use installer::{Installer, Reporter as InstallReporter};
 pub use plan::{Plan, Planner};
 pub use site_packages::{Diagnostic, SitePackages};
-pub use uninstall::{uninstall, UninstallError};
+pub use transaction::Transaction;
+pub use uninstall::{uninstall, uninstall_legacy, UninstallError};
+pub use wheel_policy::{WheelPolicy, WheelPolicyError};
 
 mod compile;
 mod downloader;
@@ -12,4 +14,6 @@ mod editable;
 mod installer;
 mod plan;
 mod site_packages;
+mod transaction;
 mod uninstall;
+mod wheel_policy;
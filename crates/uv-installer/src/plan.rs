@@ -5,14 +5,16 @@ use anyhow::{bail, Result};
 use rustc_hash::FxHashMap;
 use tracing::{debug, warn};
 
+use distribution_filename::WheelFilename;
 use distribution_types::Hashed;
 use distribution_types::{
-    BuiltDist, CachedDirectUrlDist, CachedDist, Dist, IndexLocations, InstalledDist,
+    BuiltDist, CachedDirectUrlDist, CachedDist, DirectUrl, Dist, IndexLocations, InstalledDist,
     InstalledMetadata, InstalledVersion, Name, SourceDist,
 };
 use pep508_rs::{Requirement, VersionOrUrl};
 use platform_tags::Tags;
 use uv_cache::{ArchiveTarget, ArchiveTimestamp, Cache, CacheBucket, WheelCache};
+use uv_client::SignaturePolicy;
 use uv_configuration::{NoBinary, Reinstall};
 use uv_distribution::{
     BuiltWheelIndex, HttpArchivePointer, LocalArchivePointer, RegistryWheelIndex,
@@ -20,6 +22,7 @@ use uv_distribution::{
 use uv_fs::Simplified;
 use uv_interpreter::PythonEnvironment;
 use uv_types::HashStrategy;
+use uv_warnings::warn_user;
 
 use crate::{ResolvedEditable, SitePackages};
 
@@ -28,6 +31,8 @@ use crate::{ResolvedEditable, SitePackages};
 pub struct Planner<'a> {
     requirements: &'a [Requirement],
     editable_requirements: &'a [ResolvedEditable],
+    exact: bool,
+    signature_policy: Option<&'a SignaturePolicy>,
 }
 
 impl<'a> Planner<'a> {
@@ -37,6 +42,8 @@ impl<'a> Planner<'a> {
         Self {
             requirements,
             editable_requirements: &[],
+            exact: false,
+            signature_policy: None,
         }
     }
 
@@ -49,6 +56,26 @@ impl<'a> Planner<'a> {
         }
     }
 
+    /// Require installed distributions to match the requirements exactly, as in `uv pip sync`.
+    ///
+    /// By default, an installed distribution is kept if its version satisfies the requirement.
+    /// In exact mode, it must also have been installed from the same source (i.e., from a
+    /// registry, or from the same URL, as recorded in its `direct_url.json`).
+    #[must_use]
+    pub fn with_exact(self, exact: bool) -> Self {
+        Self { exact, ..self }
+    }
+
+    /// Set the `--find-links` sources whose distributions must be signed, such that cached
+    /// distributions from those sources are only used if they were verified.
+    #[must_use]
+    pub fn with_signature_policy(self, signature_policy: Option<&'a SignaturePolicy>) -> Self {
+        Self {
+            signature_policy,
+            ..self
+        }
+    }
+
     /// Partition a set of requirements into those that should be linked from the cache, those that
     /// need to be downloaded, and those that should be removed.
     ///
@@ -73,7 +100,8 @@ impl<'a> Planner<'a> {
         tags: &Tags,
     ) -> Result<Plan> {
         // Index all the already-downloaded wheels in the cache.
-        let mut registry_index = RegistryWheelIndex::new(cache, tags, index_locations, hasher);
+        let mut registry_index =
+            RegistryWheelIndex::new(cache, tags, index_locations, hasher, self.signature_policy);
         let built_index = BuiltWheelIndex::new(cache, tags, hasher);
 
         let mut cached = vec![];
@@ -182,12 +210,22 @@ impl<'a> Planner<'a> {
                 match installed_dists.as_slice() {
                     [] => {}
                     [distribution] => {
-                        if installed_satisfies_requirement(distribution, requirement)? {
+                        // In exact mode, reinstall any distributions that were built for another
+                        // interpreter (e.g., if the environment's interpreter was upgraded in
+                        // place), since their extension modules may no longer load.
+                        if self.exact && !installed_is_compatible(distribution, tags)? {
+                            reinstalls.push(distribution.clone());
+                        } else if installed_satisfies_requirement(
+                            distribution,
+                            requirement,
+                            self.exact,
+                        )? {
                             debug!("Requirement already installed: {distribution}");
                             installed.push(distribution.clone());
                             continue;
+                        } else {
+                            reinstalls.push(distribution.clone());
                         }
-                        reinstalls.push(distribution.clone());
                     }
                     // We reinstall installed distributions with multiple versions because
                     // we do not want to keep multiple incompatible versions but removing
@@ -202,6 +240,14 @@ impl<'a> Planner<'a> {
                 continue;
             }
 
+            if let Some(VersionOrUrl::Url(url)) = requirement.version_or_url.as_ref() {
+                if cache.must_revalidate_url(url.raw()) {
+                    debug!("Must revalidate URL requirement: {requirement}");
+                    remote.push(requirement.clone());
+                    continue;
+                }
+            }
+
             // Identify any cached distributions that satisfy the requirement.
             match requirement.version_or_url.as_ref() {
                 None => {
@@ -233,6 +279,15 @@ impl<'a> Planner<'a> {
                         }
                         Dist::Built(BuiltDist::DirectUrl(wheel)) => {
                             if !wheel.filename.is_compatible(tags) {
+                                if let Some((major, minor)) =
+                                    abi3_requires_newer_python(&wheel.filename, venv)
+                                {
+                                    bail!(
+                                        "A URL dependency is an `abi3` wheel that requires CPython {major}.{minor} or later, but the current interpreter is Python {}: {}",
+                                        venv.interpreter().python_version(),
+                                        wheel.url
+                                    );
+                                }
                                 bail!(
                                     "A URL dependency is incompatible with the current platform: {}",
                                     wheel.url
@@ -274,6 +329,15 @@ impl<'a> Planner<'a> {
                         }
                         Dist::Built(BuiltDist::Path(wheel)) => {
                             if !wheel.filename.is_compatible(tags) {
+                                if let Some((major, minor)) =
+                                    abi3_requires_newer_python(&wheel.filename, venv)
+                                {
+                                    bail!(
+                                        "A path dependency is an `abi3` wheel that requires CPython {major}.{minor} or later, but the current interpreter is Python {}: {}",
+                                        venv.interpreter().python_version(),
+                                        wheel.path.user_display()
+                                    );
+                                }
                                 bail!(
                                     "A path dependency is incompatible with the current platform: {}",
                                     wheel.path.user_display()
@@ -419,11 +483,24 @@ pub struct Plan {
 
 /// Returns true if a requirement is satisfied by an installed distribution.
 ///
+/// In exact mode, the distribution must also have been installed from the requested source.
+///
+/// Requested extras aren't considered, since the extras with which a distribution was installed
+/// aren't recorded: the dependencies of an extra are separate requirements.
+///
 /// Returns an error if IO fails during a freshness check for a local path.
 fn installed_satisfies_requirement(
     distribution: &InstalledDist,
     requirement: &Requirement,
+    exact: bool,
 ) -> Result<bool> {
+    if exact {
+        if !installed_matches_source(distribution, requirement) {
+            debug!("Requirement installed from a different source: {distribution}");
+            return Ok(false);
+        }
+    }
+
     // Filter out already-installed packages.
     match requirement.version_or_url.as_ref() {
         // Accept any version of the package.
@@ -440,7 +517,8 @@ fn installed_satisfies_requirement(
         // If the requirement comes from a direct URL, check by URL.
         Some(VersionOrUrl::Url(url)) => {
             if let InstalledDist::Url(installed) = &distribution {
-                if &installed.url == url.raw() {
+                // In exact mode, the source was already compared in full.
+                if exact || &installed.url == url.raw() {
                     // If the requirement came from a local path, check freshness.
                     if let Ok(archive) = url.to_file_path() {
                         if ArchiveTimestamp::up_to_date_with(
@@ -463,3 +541,249 @@ fn installed_satisfies_requirement(
 
     Ok(false)
 }
+
+/// Returns the minimum CPython version of an `abi3` wheel, if it's newer than that of the current
+/// interpreter.
+///
+/// An `abi3` wheel tagged for (e.g.) `cp39` is compatible with CPython 3.9 and later, so these
+/// wheels are otherwise rejected with the same error as any other incompatible wheel.
+fn abi3_requires_newer_python(
+    filename: &WheelFilename,
+    venv: &PythonEnvironment,
+) -> Option<(u8, u8)> {
+    let interpreter = venv.interpreter();
+    if interpreter.implementation_name() != "cpython" {
+        return None;
+    }
+    filename
+        .abi3_minimum_python()
+        .filter(|minimum| *minimum > interpreter.python_tuple())
+}
+
+/// Returns true if the installed distribution is compatible with the current interpreter, per the
+/// tags recorded in its `WHEEL` file.
+///
+/// Distributions without any recorded tags are assumed to be compatible.
+fn installed_is_compatible(distribution: &InstalledDist, tags: &Tags) -> Result<bool> {
+    let installed_tags = install_wheel_rs::installed_tags(distribution.path())?;
+    if installed_tags.is_empty() {
+        return Ok(true);
+    }
+
+    // Each tag may be compressed (e.g., `py2.py3-none-any`).
+    let compatible = installed_tags.iter().any(|tag| {
+        let mut parts = tag
+            .splitn(3, '-')
+            .map(|part| part.split('.').map(ToString::to_string).collect::<Vec<_>>());
+        let (Some(python), Some(abi), Some(platform)) = (parts.next(), parts.next(), parts.next())
+        else {
+            return true;
+        };
+        tags.is_compatible(&python, &abi, &platform)
+    });
+    if !compatible {
+        warn_user!(
+            "{distribution} was built for a different interpreter (`{}`), and will be reinstalled",
+            installed_tags.join("`, `")
+        );
+    }
+    Ok(compatible)
+}
+
+/// Returns true if the installed distribution was installed from the source of the requirement, as
+/// recorded in its `direct_url.json`.
+///
+/// Registry requirements must be satisfied by distributions installed from a registry (i.e.,
+/// without a `direct_url.json`); URL requirements must be satisfied by distributions installed
+/// from the same URL, with the same editability, subdirectory, and Git revision.
+///
+/// If either source can't be determined (e.g., the `direct_url.json` is malformed), the sources
+/// are assumed not to match, such that the distribution is reinstalled.
+fn installed_matches_source(distribution: &InstalledDist, requirement: &Requirement) -> bool {
+    let installed = match InstalledDist::direct_url(distribution.path()) {
+        Ok(installed) => installed,
+        Err(err) => {
+            debug!("Failed to read `direct_url.json` for {distribution}: {err}");
+            return false;
+        }
+    };
+    let Some(VersionOrUrl::Url(url)) = requirement.version_or_url.as_ref() else {
+        return installed.is_none();
+    };
+    let Some(installed) = installed else {
+        return false;
+    };
+    let requested = match DirectUrl::try_from(url.raw()) {
+        Ok(requested) => requested,
+        Err(err) => {
+            debug!("Failed to parse direct URL `{url}`: {err}");
+            return false;
+        }
+    };
+    let requested = match pypi_types::DirectUrl::try_from(&requested) {
+        Ok(requested) => requested,
+        Err(err) => {
+            debug!("Failed to convert direct URL `{url}`: {err}");
+            return false;
+        }
+    };
+
+    match (&requested, &installed) {
+        (
+            pypi_types::DirectUrl::LocalDirectory {
+                url: requested_url,
+                dir_info: requested_dir_info,
+            },
+            pypi_types::DirectUrl::LocalDirectory {
+                url: installed_url,
+                dir_info: installed_dir_info,
+            },
+        ) => {
+            requested_url == installed_url
+                && requested_dir_info.editable.unwrap_or(false)
+                    == installed_dir_info.editable.unwrap_or(false)
+        }
+        (
+            pypi_types::DirectUrl::ArchiveUrl {
+                url: requested_url,
+                subdirectory: requested_subdirectory,
+                ..
+            },
+            pypi_types::DirectUrl::ArchiveUrl {
+                url: installed_url,
+                subdirectory: installed_subdirectory,
+                ..
+            },
+        ) => requested_url == installed_url && requested_subdirectory == installed_subdirectory,
+        (
+            pypi_types::DirectUrl::VcsUrl {
+                url: requested_url,
+                vcs_info: requested_vcs_info,
+                subdirectory: requested_subdirectory,
+            },
+            pypi_types::DirectUrl::VcsUrl {
+                url: installed_url,
+                vcs_info: installed_vcs_info,
+                subdirectory: installed_subdirectory,
+            },
+        ) => {
+            // A precise commit (whether resolved, or requested by its full hash) must match the
+            // installed commit. Otherwise, the requested revision (e.g., a branch) must match.
+            let requested_commit = requested_vcs_info.commit_id.as_ref().or(requested_vcs_info
+                .requested_revision
+                .as_ref()
+                .filter(|revision| is_full_commit(revision)));
+            let revision_matches = if let Some(commit_id) = requested_commit {
+                installed_vcs_info.commit_id.as_ref() == Some(commit_id)
+            } else {
+                requested_vcs_info.requested_revision == installed_vcs_info.requested_revision
+            };
+            requested_url == installed_url
+                && requested_vcs_info.vcs == installed_vcs_info.vcs
+                && requested_subdirectory == installed_subdirectory
+                && revision_matches
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if a Git revision is a full commit hash.
+fn is_full_commit(revision: &str) -> bool {
+    revision.len() == 40 && revision.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use std::str::FromStr;
+
+    use super::*;
+
+    /// Write an installed `werkzeug` distribution to `site-packages`, with the given
+    /// `direct_url.json`, if any.
+    fn install(site_packages: &Path, direct_url: Option<&str>) -> Result<InstalledDist> {
+        let dist_info = site_packages.join("werkzeug-2.0.0.dist-info");
+        fs_err::create_dir_all(&dist_info)?;
+        fs_err::write(
+            dist_info.join("METADATA"),
+            "Metadata-Version: 2.1\nName: werkzeug\nVersion: 2.0.0\n",
+        )?;
+        if let Some(direct_url) = direct_url {
+            fs_err::write(dist_info.join("direct_url.json"), direct_url)?;
+        }
+        Ok(InstalledDist::try_from_path(&dist_info)?.unwrap())
+    }
+
+    /// Returns `true` if the installed distribution satisfies the requirement in exact mode.
+    fn satisfies(installed: &InstalledDist, requirement: &str) -> Result<bool> {
+        installed_satisfies_requirement(installed, &Requirement::from_str(requirement)?, true)
+    }
+
+    /// The extras with which a distribution was installed aren't recorded, so requested extras
+    /// never trigger a reinstall.
+    #[test]
+    fn extras() -> Result<()> {
+        let site_packages = tempfile::tempdir()?;
+        let installed = install(site_packages.path(), None)?;
+
+        assert!(satisfies(&installed, "werkzeug[watchdog]==2.0.0")?);
+        assert!(satisfies(&installed, "werkzeug[undeclared]==2.0.0")?);
+        assert!(!satisfies(&installed, "werkzeug[watchdog]==2.0.1")?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn vcs_revision() -> Result<()> {
+        let site_packages = tempfile::tempdir()?;
+        let installed = install(
+            site_packages.path(),
+            Some(
+                r#"{"url": "https://github.com/pallets/werkzeug.git", "vcs_info": {"vcs": "git", "commit_id": "af160e0b6b7ddd81c22f1652c728ff5ac72d5c74", "requested_revision": "2.0.0"}}"#,
+            ),
+        )?;
+
+        // The same revision, or the installed commit, is satisfied.
+        assert!(satisfies(
+            &installed,
+            "werkzeug @ git+https://github.com/pallets/werkzeug.git@2.0.0"
+        )?);
+        assert!(satisfies(
+            &installed,
+            "werkzeug @ git+https://github.com/pallets/werkzeug.git@af160e0b6b7ddd81c22f1652c728ff5ac72d5c74"
+        )?);
+
+        // A different revision or commit, or a different source, is not.
+        assert!(!satisfies(
+            &installed,
+            "werkzeug @ git+https://github.com/pallets/werkzeug.git@2.0.1"
+        )?);
+        assert!(!satisfies(
+            &installed,
+            "werkzeug @ git+https://github.com/pallets/werkzeug.git@0d1d6ae5f4c63a7ee0a3a3c2c5dd8d1a4f1c6f01"
+        )?);
+        assert!(!satisfies(
+            &installed,
+            "werkzeug @ git+https://github.com/pallets/werkzeug.git"
+        )?);
+        assert!(!satisfies(&installed, "werkzeug==2.0.0")?);
+
+        Ok(())
+    }
+
+    /// A malformed `direct_url.json` is treated as a different source, rather than an error.
+    #[test]
+    fn malformed_direct_url() -> Result<()> {
+        let site_packages = tempfile::tempdir()?;
+        let installed = install(site_packages.path(), None)?;
+        fs_err::write(installed.path().join("direct_url.json"), "{")?;
+
+        assert!(!satisfies(&installed, "werkzeug==2.0.0")?);
+        assert!(!satisfies(
+            &installed,
+            "werkzeug @ https://files.pythonhosted.org/packages/ff/1d/960bb4017c68674a1cb099534840f18d3def3ce44aed12b5ed8b78e0153e/Werkzeug-2.0.0-py3-none-any.whl"
+        )?);
+
+        Ok(())
+    }
+}
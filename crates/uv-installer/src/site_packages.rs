@@ -1,5 +1,6 @@
+use std::collections::BTreeMap;
 use std::iter::Flatten;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::{collections::BTreeSet, hash::BuildHasherDefault};
 
 use anyhow::{Context, Result};
@@ -8,6 +9,7 @@ use rustc_hash::{FxHashMap, FxHashSet};
 use url::Url;
 
 use distribution_types::{InstalledDist, InstalledMetadata, InstalledVersion, Name};
+use install_wheel_rs::installed_scripts;
 use pep440_rs::{Version, VersionSpecifiers};
 use pep508_rs::{Requirement, RequirementsTxtRequirement, VerbatimUrl};
 use requirements_txt::{EditableRequirement, RequirementEntry};
@@ -39,11 +41,23 @@ pub struct SitePackages<'a> {
 impl<'a> SitePackages<'a> {
     /// Build an index of installed packages from the given Python executable.
     pub fn from_executable(venv: &'a PythonEnvironment) -> Result<SitePackages<'a>> {
+        Self::from_paths(venv, venv.site_packages())
+    }
+
+    /// Build an index of installed packages from the given `site-packages` directories, rather
+    /// than those of the given Python environment (e.g., to include a `--target` directory, or the
+    /// user site).
+    ///
+    /// Directories that don't exist are skipped.
+    pub fn from_paths<'p>(
+        venv: &'a PythonEnvironment,
+        paths: impl IntoIterator<Item = &'p Path>,
+    ) -> Result<SitePackages<'a>> {
         let mut distributions: Vec<Option<InstalledDist>> = Vec::new();
         let mut by_name = FxHashMap::default();
         let mut by_url = FxHashMap::default();
 
-        for site_packages in venv.site_packages() {
+        for site_packages in paths {
             // Read the site-packages directory.
             let site_packages = match fs::read_dir(site_packages) {
                 Ok(site_packages) => {
@@ -59,14 +73,7 @@ impl<'a> SitePackages<'a> {
                         .collect::<Result<_, std::io::Error>>()?;
                     directories
                 }
-                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
-                    return Ok(Self {
-                        venv,
-                        distributions,
-                        by_name,
-                        by_url,
-                    });
-                }
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => continue,
                 Err(err) => return Err(err).context("Failed to read site-packages directory"),
             };
 
@@ -199,6 +206,91 @@ impl<'a> SitePackages<'a> {
         self.distributions.iter().any(Option::is_some)
     }
 
+    /// Returns the packages that would be orphaned by removing the given packages, i.e., those
+    /// that are (transitively) required by the removed packages, but neither required by any
+    /// remaining package nor requested directly by the user.
+    ///
+    /// Optional dependencies are treated as required for any of the extras a package provides,
+    /// since the extras that were enabled at install time aren't recorded.
+    pub fn orphans(&self, removed: &FxHashSet<PackageName>) -> Result<Vec<&InstalledDist>> {
+        let dependencies = self.dependencies()?;
+
+        // Iterate until no more packages are orphaned.
+        let mut removed = removed.clone();
+        let mut orphans = Vec::new();
+        loop {
+            let mut changed = false;
+            for dist in self.iter() {
+                if removed.contains(dist.name()) || dist.is_requested() {
+                    continue;
+                }
+
+                // The package must be required by a removed package...
+                if !removed.iter().any(|name| {
+                    dependencies
+                        .get(name)
+                        .is_some_and(|dependencies| dependencies.contains(dist.name()))
+                }) {
+                    continue;
+                }
+
+                // ...and by none of the remaining packages.
+                if dependencies.iter().any(|(name, dependencies)| {
+                    !removed.contains(*name) && dependencies.contains(dist.name())
+                }) {
+                    continue;
+                }
+
+                removed.insert(dist.name().clone());
+                orphans.push(dist);
+                changed = true;
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        Ok(orphans)
+    }
+
+    /// Returns the names of the packages that are required by at least one other installed
+    /// package.
+    ///
+    /// Like [`SitePackages::orphans`], optional dependencies are treated as required.
+    pub fn required(&self) -> Result<FxHashSet<PackageName>> {
+        Ok(self
+            .dependencies()?
+            .into_iter()
+            .flat_map(|(name, dependencies)| {
+                dependencies
+                    .into_iter()
+                    .filter(move |dependency| dependency != name)
+            })
+            .collect())
+    }
+
+    /// Determine the dependencies of each installed package, including those of any extras the
+    /// package provides.
+    fn dependencies(&self) -> Result<FxHashMap<&PackageName, FxHashSet<PackageName>>> {
+        let mut dependencies = FxHashMap::<&PackageName, FxHashSet<PackageName>>::default();
+        for dist in self.iter() {
+            let metadata = dist.metadata()?;
+            dependencies.entry(dist.name()).or_default().extend(
+                metadata
+                    .requires_dist
+                    .into_iter()
+                    .filter(|requirement| {
+                        requirement.evaluate_markers(
+                            self.venv.interpreter().markers(),
+                            &metadata.provides_extras,
+                        )
+                    })
+                    .map(|requirement| requirement.name),
+            );
+        }
+        Ok(dependencies)
+    }
+
     /// Validate the installed packages in the virtual environment.
     pub fn diagnostics(&self) -> Result<Vec<Diagnostic>> {
         let mut diagnostics = Vec::new();
@@ -290,6 +382,30 @@ impl<'a> SitePackages<'a> {
             }
         }
 
+        // Verify that no two packages provide the same script, in which case one of the scripts
+        // will have overwritten the other.
+        let scripts_dir = self.venv.interpreter().scripts();
+        let mut scripts: BTreeMap<String, BTreeSet<PackageName>> = BTreeMap::new();
+        for dist in self.iter() {
+            let Ok(installed) = installed_scripts(dist.path(), scripts_dir) else {
+                continue;
+            };
+            for script in installed {
+                scripts
+                    .entry(script)
+                    .or_default()
+                    .insert(dist.name().clone());
+            }
+        }
+        for (script, packages) in scripts {
+            if packages.len() > 1 {
+                diagnostics.push(Diagnostic::ScriptConflict {
+                    script,
+                    packages: packages.into_iter().collect(),
+                });
+            }
+        }
+
         Ok(diagnostics)
     }
 
@@ -537,6 +653,12 @@ pub enum Diagnostic {
         /// The installed versions of the package.
         paths: Vec<PathBuf>,
     },
+    ScriptConflict {
+        /// The script that's provided by multiple packages.
+        script: String,
+        /// The packages that provide the script.
+        packages: Vec<PackageName>,
+    },
 }
 
 impl Diagnostic {
@@ -574,6 +696,10 @@ impl Diagnostic {
                     paths.iter().fold(String::new(), |acc, path| acc + &format!("\n  - {}", path.display()))
                 )
             },
+            Self::ScriptConflict { script, packages } => format!(
+                "The script `{script}` is provided by multiple packages ({}), and may have been overwritten.",
+                packages.iter().map(|package| format!("`{package}`")).collect::<Vec<_>>().join(", ")
+            ),
         }
     }
 
@@ -589,6 +715,7 @@ impl Diagnostic {
                 ..
             } => name == package || &requirement.name == name,
             Self::DuplicatePackage { package, .. } => name == package,
+            Self::ScriptConflict { packages, .. } => packages.contains(name),
         }
     }
 }
@@ -0,0 +1,314 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use fs_err as fs;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use distribution_types::{CachedDist, InstalledDist};
+use uv_fs::Simplified;
+use uv_interpreter::PythonEnvironment;
+use uv_warnings::warn_user;
+
+/// The name of the directory (within the environment root) in which a [`Transaction`] stages its
+/// backups and journal.
+const TRANSACTION_DIR: &str = ".uv-transaction";
+
+/// The name of the journal file within the transaction directory.
+const JOURNAL: &str = "journal.msgpack";
+
+/// A transaction over the mutations applied to a Python environment.
+///
+/// Before any package is removed, its files are backed up into a staging directory within the
+/// environment; before any package is installed, its `.dist-info` directory is recorded in a
+/// journal. If the mutation fails (or the process is interrupted), the journal is used to restore
+/// the environment to its previous state, such that the environment is either in the old or the
+/// new state, and never in between.
+///
+/// The caller is expected to hold the environment lock (see [`PythonEnvironment::lock`]) for the
+/// lifetime of the transaction.
+#[derive(Debug)]
+pub struct Transaction {
+    /// The staging directory for the transaction.
+    root: PathBuf,
+    /// The journal of mutations applied so far.
+    journal: Journal,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Journal {
+    /// The `site-packages` directories of the environment.
+    site_packages: Vec<PathBuf>,
+    /// Files that were backed up prior to removal, as `(original, backup)` pairs.
+    removed: Vec<(PathBuf, PathBuf)>,
+    /// The names of the `.dist-info` directories that are (or are about to be) installed.
+    installed: Vec<String>,
+}
+
+impl Transaction {
+    /// Begin a transaction against the given environment.
+    ///
+    /// If a previous transaction was interrupted, it's rolled back first.
+    pub fn begin(venv: &PythonEnvironment) -> Result<Self> {
+        Self::begin_in(
+            venv.root(),
+            venv.site_packages().map(Path::to_path_buf).collect(),
+        )
+    }
+
+    /// Begin a transaction against the environment at the given root, with the given
+    /// `site-packages` directories.
+    fn begin_in(venv_root: &Path, site_packages: Vec<PathBuf>) -> Result<Self> {
+        let root = venv_root.join(TRANSACTION_DIR);
+
+        // Recover from any interrupted transaction.
+        if root.join(JOURNAL).is_file() {
+            warn_user!(
+                "Found an incomplete transaction in {}; restoring the environment to its previous state",
+                venv_root.user_display()
+            );
+            Self::recover(&root)?;
+        } else if root.exists() {
+            fs::remove_dir_all(&root)?;
+        }
+
+        fs::create_dir_all(&root)?;
+
+        let transaction = Self {
+            root,
+            journal: Journal {
+                site_packages,
+                ..Journal::default()
+            },
+        };
+        transaction.flush()?;
+        Ok(transaction)
+    }
+
+    /// Back up an installed distribution, prior to removing it from the environment.
+    pub fn backup(&mut self, dist: &InstalledDist) -> Result<()> {
+        let Some(dist_info) = dist.path().file_name() else {
+            return Ok(());
+        };
+        let backup = self.root.join("removed").join(dist_info);
+        let entries = install_wheel_rs::backup_wheel(dist.path(), &backup)
+            .with_context(|| format!("Failed to back up: {}", dist.path().user_display()))?;
+        self.journal.removed.extend(entries);
+        self.flush()
+    }
+
+    /// Record the distributions that are about to be installed into the environment.
+    pub fn stage(&mut self, wheels: &[CachedDist]) -> Result<()> {
+        for wheel in wheels {
+            if let Some(dist_info) = find_dist_info(wheel.path())? {
+                self.journal.installed.push(dist_info);
+            }
+        }
+        self.flush()
+    }
+
+    /// Commit the transaction, discarding any backups.
+    pub fn commit(self) -> Result<()> {
+        // Remove the journal first, such that a partially-removed staging directory is never
+        // mistaken for an incomplete transaction.
+        fs::remove_file(self.root.join(JOURNAL))?;
+        fs::remove_dir_all(&self.root)?;
+        Ok(())
+    }
+
+    /// Roll back the transaction, restoring the environment to its previous state.
+    pub fn rollback(self) -> Result<()> {
+        Self::recover(&self.root)
+    }
+
+    /// Persist the journal to disk.
+    fn flush(&self) -> Result<()> {
+        let journal = rmp_serde::to_vec(&self.journal)?;
+        uv_fs::write_atomic_sync(self.root.join(JOURNAL), journal)?;
+        Ok(())
+    }
+
+    /// Restore the environment from the journal in the given transaction directory.
+    fn recover(root: &Path) -> Result<()> {
+        let journal: Journal = rmp_serde::from_slice(&fs::read(root.join(JOURNAL))?)
+            .context("Failed to read transaction journal")?;
+
+        // Remove any distributions that were (partially) installed.
+        for dist_info in &journal.installed {
+            for site_packages in &journal.site_packages {
+                let path = site_packages.join(dist_info);
+                if !path.is_dir() {
+                    continue;
+                }
+                debug!("Rolling back installation of: {}", path.display());
+                match install_wheel_rs::uninstall_wheel(&path) {
+                    Ok(_) => {}
+                    // If the installation was interrupted before the RECORD was written, remove the
+                    // `.dist-info` directory, such that the distribution isn't considered installed.
+                    Err(install_wheel_rs::Error::MissingRecord(_)) => {
+                        fs::remove_dir_all(&path)?;
+                    }
+                    Err(err) => return Err(err.into()),
+                }
+            }
+        }
+
+        // Restore any distributions that were removed.
+        for (original, backup) in &journal.removed {
+            if !backup.is_file() {
+                continue;
+            }
+            debug!("Restoring: {}", original.display());
+            if let Some(parent) = original.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            uv_fs::rename_with_retry_sync(backup, original)?;
+        }
+
+        fs::remove_file(root.join(JOURNAL))?;
+        fs::remove_dir_all(root)?;
+
+        Ok(())
+    }
+}
+
+/// Find the name of the `.dist-info` directory in an unzipped wheel.
+fn find_dist_info(path: &Path) -> Result<Option<String>> {
+    for entry in fs::read_dir(path)? {
+        let entry = entry?;
+        let path = entry.path();
+        if entry.file_type()?.is_dir() && path.extension().is_some_and(|ext| ext == "dist-info") {
+            return Ok(entry.file_name().to_str().map(ToString::to_string));
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::str::FromStr;
+
+    use distribution_filename::WheelFilename;
+    use distribution_types::CachedRegistryDist;
+
+    use super::*;
+
+    /// Write an installed distribution to `site-packages`, with a `RECORD` listing its files.
+    fn install(site_packages: &Path, name: &str, version: &str, files: &[(&str, &str)]) {
+        let dist_info = format!("{name}-{version}.dist-info");
+        let metadata = format!("{dist_info}/METADATA");
+        let record = format!("{dist_info}/RECORD");
+
+        let mut contents = String::new();
+        for (path, data) in files
+            .iter()
+            .copied()
+            .chain([(metadata.as_str(), "Metadata-Version: 2.1\n")])
+        {
+            let target = site_packages.join(path);
+            fs::create_dir_all(target.parent().unwrap()).unwrap();
+            fs::write(target, data).unwrap();
+            contents.push_str(&format!("{path},,\n"));
+        }
+        contents.push_str(&format!("{record},,\n"));
+        fs::write(site_packages.join(record), contents).unwrap();
+    }
+
+    /// An unzipped wheel in the cache, to be staged for installation.
+    fn wheel(cache: &Path, name: &str, version: &str) -> CachedDist {
+        let filename =
+            WheelFilename::from_str(&format!("{name}-{version}-py3-none-any.whl")).unwrap();
+        let path = cache.join(filename.stem());
+        fs::create_dir_all(path.join(format!("{name}-{version}.dist-info"))).unwrap();
+        CachedDist::Registry(CachedRegistryDist {
+            filename,
+            path,
+            hashes: vec![],
+        })
+    }
+
+    /// The files in the environment, along with their contents.
+    fn snapshot(root: &Path) -> BTreeMap<PathBuf, Vec<u8>> {
+        walkdir::WalkDir::new(root)
+            .into_iter()
+            .map(Result::unwrap)
+            .filter(|entry| entry.file_type().is_file())
+            .map(|entry| {
+                let path = entry.path().strip_prefix(root).unwrap().to_path_buf();
+                (path, fs::read(entry.path()).unwrap())
+            })
+            .collect()
+    }
+
+    /// Apply the first half of an upgrade from `foo` to `bar` and `baz`: remove `foo`, install
+    /// `bar`, and fail midway through installing `baz`.
+    fn upgrade(transaction: &mut Transaction, site_packages: &Path, cache: &Path) -> Result<()> {
+        let foo = site_packages.join("foo-1.0.dist-info");
+        transaction.backup(&InstalledDist::try_from_path(&foo)?.unwrap())?;
+        install_wheel_rs::uninstall_wheel(&foo)?;
+
+        transaction.stage(&[wheel(cache, "bar", "2.0"), wheel(cache, "baz", "3.0")])?;
+        install(
+            site_packages,
+            "bar",
+            "2.0",
+            &[("bar/__init__.py", "bar = 2\n")],
+        );
+        fs::create_dir_all(site_packages.join("baz-3.0.dist-info"))?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn rollback() -> Result<()> {
+        let venv = tempfile::tempdir()?;
+        let cache = tempfile::tempdir()?;
+        let site_packages = venv.path().join("lib").join("site-packages");
+        install(
+            &site_packages,
+            "foo",
+            "1.0",
+            &[("foo/__init__.py", "foo = 1\n"), ("foo/core.py", "\n")],
+        );
+        let before = snapshot(venv.path());
+
+        let mut transaction = Transaction::begin_in(venv.path(), vec![site_packages.clone()])?;
+        upgrade(&mut transaction, &site_packages, cache.path())?;
+        assert_ne!(snapshot(venv.path()), before);
+
+        transaction.rollback()?;
+        assert_eq!(snapshot(venv.path()), before);
+        assert!(!venv.path().join(TRANSACTION_DIR).exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn recover_interrupted() -> Result<()> {
+        let venv = tempfile::tempdir()?;
+        let cache = tempfile::tempdir()?;
+        let site_packages = venv.path().join("lib").join("site-packages");
+        install(
+            &site_packages,
+            "foo",
+            "1.0",
+            &[("foo/__init__.py", "foo = 1\n"), ("foo/core.py", "\n")],
+        );
+        let before = snapshot(venv.path());
+
+        // Simulate an interruption, leaving the journal behind.
+        let mut transaction = Transaction::begin_in(venv.path(), vec![site_packages.clone()])?;
+        upgrade(&mut transaction, &site_packages, cache.path())?;
+        drop(transaction);
+        assert!(venv.path().join(TRANSACTION_DIR).join(JOURNAL).is_file());
+
+        // The next transaction restores the environment before it begins.
+        let transaction = Transaction::begin_in(venv.path(), vec![site_packages.clone()])?;
+        transaction.commit()?;
+        assert_eq!(snapshot(venv.path()), before);
+        assert!(!venv.path().join(TRANSACTION_DIR).exists());
+
+        Ok(())
+    }
+}
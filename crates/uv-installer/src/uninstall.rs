@@ -15,6 +15,20 @@ pub async fn uninstall(
     Ok(uninstall)
 }
 
+/// Uninstall a package without a `RECORD` file from the specified Python environment, on a
+/// best-effort basis.
+pub async fn uninstall_legacy(
+    dist: &InstalledDist,
+) -> Result<install_wheel_rs::LegacyUninstall, UninstallError> {
+    let uninstall = tokio::task::spawn_blocking({
+        let path = dist.path().to_owned();
+        move || install_wheel_rs::uninstall_legacy(&path)
+    })
+    .await??;
+
+    Ok(uninstall)
+}
+
 #[derive(thiserror::Error, Debug)]
 pub enum UninstallError {
     #[error(transparent)]
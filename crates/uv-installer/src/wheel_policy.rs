@@ -0,0 +1,342 @@
+use std::fmt::{Display, Formatter};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use serde::Deserialize;
+use walkdir::WalkDir;
+
+use distribution_types::{CachedDist, Name};
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_warnings::warn_user;
+
+/// The schemes under which a wheel's `.data` directory may install files.
+const DATA_SCHEMES: &[&str] = &["purelib", "platlib", "scripts", "headers", "data"];
+
+/// Modules that Python imports automatically on startup, when found in `site-packages`.
+const STARTUP_MODULES: &[&str] = &["sitecustomize.py", "usercustomize.py"];
+
+/// The file extensions of compiled binaries.
+const BINARY_EXTENSIONS: &[&str] = &["so", "pyd", "dll", "dylib", "exe"];
+
+/// The magic numbers of compiled binaries (ELF, PE, and Mach-O).
+const BINARY_MAGIC: &[&[u8]] = &[
+    b"\x7fELF",
+    b"MZ",
+    b"\xfe\xed\xfa\xce",
+    b"\xfe\xed\xfa\xcf",
+    b"\xce\xfa\xed\xfe",
+    b"\xcf\xfa\xed\xfe",
+    b"\xca\xfe\xba\xbe",
+];
+
+/// A policy for the contents of wheels, checked before any of them are installed.
+///
+/// Read from a TOML file, e.g.:
+///
+/// ```toml
+/// executable-pth = "deny"
+/// binary-in-pure-wheel = "warn"
+/// allow = ["setuptools"]
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct WheelPolicy {
+    /// Files outside the paths a wheel is expected to install to (e.g., the `.dist-info`
+    /// directory of another package, or a `sitecustomize.py`).
+    #[serde(default)]
+    unexpected_path: PolicyAction,
+    /// `.pth` files that execute code on interpreter startup (i.e., with `import` lines).
+    #[serde(default)]
+    executable_pth: PolicyAction,
+    /// Scripts with a shebang for an interpreter other than Python (e.g., `#!/bin/sh`).
+    #[serde(default)]
+    suspicious_shebang: PolicyAction,
+    /// Compiled binaries in wheels that are tagged as pure Python (e.g., `py3-none-any`).
+    #[serde(default)]
+    binary_in_pure_wheel: PolicyAction,
+    /// Packages that are exempt from the policy.
+    #[serde(default)]
+    allow: Vec<PackageName>,
+}
+
+impl WheelPolicy {
+    /// Read a [`WheelPolicy`] from a TOML file.
+    pub fn from_path(path: &Path) -> Result<Self, WheelPolicyError> {
+        let contents = fs_err::read_to_string(path)?;
+        toml::from_str(&contents).map_err(|err| WheelPolicyError::Toml(path.to_path_buf(), err))
+    }
+
+    /// Return the [`PolicyAction`] for a given kind of [`Finding`].
+    fn action(&self, kind: FindingKind) -> PolicyAction {
+        match kind {
+            FindingKind::UnexpectedPath => self.unexpected_path,
+            FindingKind::ExecutablePth => self.executable_pth,
+            FindingKind::SuspiciousShebang => self.suspicious_shebang,
+            FindingKind::BinaryInPureWheel => self.binary_in_pure_wheel,
+        }
+    }
+
+    /// Scan the given (unzipped) wheels, warning about any contents that are subject to the
+    /// policy, and failing if any are denied.
+    pub fn check(&self, wheels: &[CachedDist]) -> Result<(), WheelPolicyError> {
+        let findings = tokio::task::block_in_place(|| {
+            wheels
+                .par_iter()
+                .map(|wheel| {
+                    let findings = self
+                        .scan(wheel)
+                        .map_err(|err| WheelPolicyError::Scan(wheel.to_string(), err))?;
+                    Ok((wheel, findings))
+                })
+                .collect::<Result<Vec<_>, WheelPolicyError>>()
+        })?;
+
+        let mut denied = Vec::new();
+        for (wheel, findings) in findings {
+            for finding in findings {
+                match self.action(finding.kind) {
+                    PolicyAction::Allow => {}
+                    PolicyAction::Warn => {
+                        warn_user!("{finding} (in `{wheel}`)");
+                    }
+                    PolicyAction::Deny => {
+                        denied.push(format!("{finding} (in `{wheel}`)"));
+                    }
+                }
+            }
+        }
+
+        if denied.is_empty() {
+            Ok(())
+        } else {
+            Err(WheelPolicyError::Denied(denied))
+        }
+    }
+
+    /// Scan an (unzipped) wheel for contents that are subject to the policy.
+    fn scan(&self, wheel: &CachedDist) -> Result<Vec<Finding>, std::io::Error> {
+        if self.allow.contains(wheel.name()) {
+            return Ok(Vec::new());
+        }
+
+        let filename = wheel.filename();
+        let pure = filename.abi_tag.iter().all(|tag| tag == "none")
+            && filename.platform_tag.iter().all(|tag| tag == "any");
+
+        let root = wheel.path();
+        let mut findings = Vec::new();
+        for entry in WalkDir::new(root).min_depth(1).sort_by_file_name() {
+            let entry = entry?;
+            let relative = entry
+                .path()
+                .strip_prefix(root)
+                .expect("walkdir starts with root");
+            let components = relative
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy().into_owned())
+                .collect::<Vec<_>>();
+            let relative = components.join("/");
+
+            if entry.file_type().is_dir() {
+                // Any `.dist-info` or `.data` directory must belong to the wheel's own package.
+                if let [name] = components.as_slice() {
+                    let stem = name
+                        .strip_suffix(".dist-info")
+                        .or_else(|| name.strip_suffix(".data"));
+                    if let Some(stem) = stem {
+                        let package = stem
+                            .rsplit_once('-')
+                            .and_then(|(package, _)| PackageName::from_str(package).ok());
+                        if package.as_ref() != Some(wheel.name()) {
+                            findings.push(Finding::new(
+                                FindingKind::UnexpectedPath,
+                                &relative,
+                                "belongs to another package",
+                            ));
+                        }
+                    }
+                }
+                // Files may only be installed to the known schemes.
+                if let [name, scheme] = components.as_slice() {
+                    if name.ends_with(".data") && !DATA_SCHEMES.contains(&scheme.as_str()) {
+                        findings.push(Finding::new(
+                            FindingKind::UnexpectedPath,
+                            &relative,
+                            "isn't a known installation scheme",
+                        ));
+                    }
+                }
+                continue;
+            }
+
+            // Determine whether the file is installed to the root of `site-packages`, or as a
+            // script.
+            let (in_site_packages, is_script) = match components.as_slice() {
+                [_] => (true, false),
+                [data, scheme, _] if data.ends_with(".data") => (
+                    matches!(scheme.as_str(), "purelib" | "platlib"),
+                    scheme == "scripts",
+                ),
+                _ => (false, false),
+            };
+            let file_name = components.last().map(String::as_str).unwrap_or_default();
+
+            if in_site_packages && STARTUP_MODULES.contains(&file_name) {
+                findings.push(Finding::new(
+                    FindingKind::UnexpectedPath,
+                    &relative,
+                    "is imported on interpreter startup",
+                ));
+            }
+
+            if in_site_packages
+                && Path::new(file_name)
+                    .extension()
+                    .is_some_and(|ext| ext.eq_ignore_ascii_case("pth"))
+                && executes_code(entry.path())?
+            {
+                findings.push(Finding::new(
+                    FindingKind::ExecutablePth,
+                    &relative,
+                    "executes code on interpreter startup",
+                ));
+            }
+
+            if is_script {
+                if let Some(interpreter) = suspicious_shebang(entry.path())? {
+                    findings.push(Finding::new(
+                        FindingKind::SuspiciousShebang,
+                        &relative,
+                        format!("runs with `{interpreter}`"),
+                    ));
+                }
+            }
+
+            if pure && is_binary(entry.path())? {
+                findings.push(Finding::new(
+                    FindingKind::BinaryInPureWheel,
+                    &relative,
+                    "is a compiled binary in a pure Python wheel",
+                ));
+            }
+        }
+
+        Ok(findings)
+    }
+}
+
+/// Returns `true` if the `.pth` file has any lines that are executed, rather than added to
+/// `sys.path`.
+fn executes_code(path: &Path) -> Result<bool, std::io::Error> {
+    let contents = fs_err::read(path)?;
+    Ok(String::from_utf8_lossy(&contents)
+        .lines()
+        .any(|line| line.starts_with("import ") || line.starts_with("import\t")))
+}
+
+/// Returns the interpreter of a script's shebang, if it's something other than Python.
+fn suspicious_shebang(path: &Path) -> Result<Option<String>, std::io::Error> {
+    let mut buffer = Vec::new();
+    fs_err::File::open(path)?
+        .take(256)
+        .read_to_end(&mut buffer)?;
+    let Some(shebang) = buffer.strip_prefix(b"#!") else {
+        return Ok(None);
+    };
+    let line = String::from_utf8_lossy(shebang);
+    let line = line.lines().next().unwrap_or_default().trim();
+
+    // Skip over `env` (e.g., `#!/usr/bin/env python3`).
+    let mut words = line.split_whitespace();
+    let mut interpreter = words.next().unwrap_or_default();
+    if interpreter.rsplit('/').next() == Some("env") {
+        interpreter = words
+            .find(|word| !word.starts_with('-'))
+            .unwrap_or_default();
+    }
+    let name = interpreter.rsplit(['/', '\\']).next().unwrap_or_default();
+    if name.starts_with("python") || name.starts_with("pypy") {
+        Ok(None)
+    } else {
+        Ok(Some(line.to_string()))
+    }
+}
+
+/// Returns `true` if the file is a compiled binary, by extension or magic number.
+fn is_binary(path: &Path) -> Result<bool, std::io::Error> {
+    if path.extension().is_some_and(|ext| {
+        BINARY_EXTENSIONS
+            .iter()
+            .any(|binary| ext.eq_ignore_ascii_case(binary))
+    }) {
+        return Ok(true);
+    }
+    let mut magic = Vec::with_capacity(4);
+    fs_err::File::open(path)?.take(4).read_to_end(&mut magic)?;
+    Ok(BINARY_MAGIC.iter().any(|binary| magic.starts_with(binary)))
+}
+
+/// The action to take for contents that are subject to the [`WheelPolicy`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum PolicyAction {
+    /// Install the wheel without comment.
+    Allow,
+    /// Warn about the contents, and install the wheel anyway.
+    #[default]
+    Warn,
+    /// Fail the installation before making any changes to the environment.
+    Deny,
+}
+
+/// The kinds of contents that are subject to the [`WheelPolicy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FindingKind {
+    UnexpectedPath,
+    ExecutablePth,
+    SuspiciousShebang,
+    BinaryInPureWheel,
+}
+
+/// A file in a wheel that's subject to the [`WheelPolicy`].
+#[derive(Debug, Clone)]
+struct Finding {
+    kind: FindingKind,
+    /// The path to the file, relative to the root of the wheel (e.g., `foo.data/scripts/foo`).
+    path: String,
+    reason: String,
+}
+
+impl Finding {
+    fn new(kind: FindingKind, path: &str, reason: impl Into<String>) -> Self {
+        Self {
+            kind,
+            path: path.to_string(),
+            reason: reason.into(),
+        }
+    }
+}
+
+impl Display for Finding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "`{}` {}", self.path, self.reason)
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum WheelPolicyError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error("Failed to parse wheel policy: `{}`", _0.user_display())]
+    Toml(PathBuf, #[source] toml::de::Error),
+
+    #[error("Failed to scan: {0}")]
+    Scan(String, #[source] std::io::Error),
+
+    #[error("The wheel policy denied the installation of: {}", _0.join(", "))]
+    Denied(Vec<String>),
+}
@@ -407,6 +407,115 @@ def get_scheme():
         return get_distutils_scheme()
 
 
+def get_user_scheme():
+    """Return the user Scheme for the current interpreter, i.e., the Scheme used by
+    `pip install --user`.
+
+    The paths returned should be absolute.
+
+    This is based on pip's path discovery logic:
+        https://github.com/pypa/pip/blob/ae5fff36b0aad6e5e0037884927eaa29163c0611/src/pip/_internal/locations/_sysconfig.py#L100
+    """
+    if hasattr(sysconfig, "get_preferred_scheme"):
+        scheme_name = sysconfig.get_preferred_scheme("user")
+    elif os.name == "nt":
+        scheme_name = "nt_user"
+    elif sys.platform == "darwin" and sysconfig.get_config_var("PYTHONFRAMEWORK"):
+        scheme_name = "osx_framework_user"
+    else:
+        scheme_name = "posix_user"
+
+    paths = sysconfig.get_paths(scheme=scheme_name)
+    return {
+        "platlib": paths["platlib"],
+        "purelib": paths["purelib"],
+        "include": paths["include"],
+        "scripts": paths["scripts"],
+        "data": paths["data"],
+    }
+
+
+def get_prefix_scheme(prefix: str):
+    """Return the Scheme for installing into the given prefix, as with `pip install --prefix`.
+
+    The paths returned should be absolute.
+
+    Distribution-patched Pythons often prefer a scheme that doesn't map onto a custom prefix: for
+    example, Debian's `posix_local` installs into `{base}/local/lib/python3/dist-packages`, and its
+    `deb_system` scheme into `{base}/lib/python3/dist-packages`. A custom prefix should instead use
+    the unpatched scheme, such that packages end up in `{prefix}/lib/pythonX.Y/site-packages`.
+    Passing the prefix explicitly (rather than relying on the configured `base`) also avoids
+    patches that rewrite the base from `/usr` to `/usr/local` outside of RPM builds, as on Fedora.
+
+    This is based on pip's path discovery logic:
+        https://github.com/pypa/pip/blob/ae5fff36b0aad6e5e0037884927eaa29163c0611/src/pip/_internal/locations/_sysconfig.py#L140
+    """
+    available_schemes = set(sysconfig.get_scheme_names())
+
+    if os.name == "nt":
+        scheme_name = "nt"
+    else:
+        scheme_name = "posix_prefix"
+
+    # PyPy before 3.10 uses its own schemes, which do respect the prefix.
+    implementation_suffixed = f"{sys.implementation.name}_{os.name}"
+    if sys.implementation.name == "pypy":
+        if implementation_suffixed in available_schemes:
+            scheme_name = implementation_suffixed
+        elif sys.implementation.name in available_schemes:
+            scheme_name = sys.implementation.name
+
+    variables = {
+        "base": prefix,
+        "platbase": prefix,
+        "installed_base": prefix,
+        "installed_platbase": prefix,
+    }
+    paths = sysconfig.get_paths(scheme=scheme_name, vars=variables)
+
+    return {
+        "platlib": paths["platlib"],
+        "purelib": paths["purelib"],
+        "include": paths["include"],
+        "scripts": paths["scripts"],
+        "data": paths["data"],
+    }
+
+
+def is_user_site_enabled() -> bool:
+    """Return `True` if the user site is enabled for the current interpreter.
+
+    We run in isolated mode, which always disables the user site (i.e., `site.ENABLE_USER_SITE`
+    is `False`), so we replicate the checks that `site` would otherwise perform.
+
+    See: https://github.com/python/cpython/blob/v3.12.0/Lib/site.py#L228
+    """
+    if os.environ.get("PYTHONNOUSERSITE"):
+        return False
+
+    if hasattr(os, "getuid") and hasattr(os, "geteuid"):
+        if os.geteuid() != os.getuid():
+            return False
+    if hasattr(os, "getgid") and hasattr(os, "getegid"):
+        if os.getegid() != os.getgid():
+            return False
+
+    # Virtual environments only expose the user site if they include the system site packages.
+    if _running_under_venv():
+        pyvenv_cfg = os.path.join(sys.prefix, "pyvenv.cfg")
+        try:
+            with open(pyvenv_cfg, encoding="utf-8") as f:
+                for line in f:
+                    key, sep, value = line.partition("=")
+                    if sep and key.strip().lower() == "include-system-site-packages":
+                        return value.strip().lower() == "true"
+        except OSError:
+            pass
+        return False
+
+    return True
+
+
 def get_operating_system_and_architecture():
     """Determine the Python interpreter architecture and operating system.
 
@@ -482,6 +591,24 @@ def get_operating_system_and_architecture():
             "major": int(version[0]),
             "minor": int(version[1]),
         }
+    elif operating_system == "ios":
+        # Ex: ios-13.0-arm64-iphonesimulator
+        # https://peps.python.org/pep-0730/#platform-identification
+        version = platform.ios_ver().release.split(".")
+        architecture, sdk = sys.implementation._multiarch.split("-")
+        operating_system = {
+            "name": "ios",
+            "major": int(version[0]),
+            "minor": int(version[1]),
+            "sdk": sdk,
+        }
+    elif operating_system == "android":
+        # Ex: android-24-arm64_v8a
+        # https://peps.python.org/pep-0738/#platform-identification
+        operating_system = {
+            "name": "android",
+            "api_level": sys.getandroidapilevel(),
+        }
     elif operating_system in [
         "freebsd",
         "netbsd",
@@ -541,5 +668,26 @@ def main() -> None:
     print(json.dumps(interpreter_info))
 
 
+def main_user_site() -> None:
+    """Print the user scheme for the current interpreter, and whether the user site is enabled.
+
+    Unlike the rest of the interpreter information, the user scheme depends on the environment
+    (e.g., `PYTHONUSERBASE`), and so is queried separately.
+    """
+    print(
+        json.dumps(
+            {
+                "enabled": is_user_site_enabled(),
+                "scheme": get_user_scheme(),
+            }
+        )
+    )
+
+
+def main_prefix_scheme(prefix: str) -> None:
+    """Print the Scheme for installing into the given prefix."""
+    print(json.dumps(get_prefix_scheme(prefix)))
+
+
 if __name__ == "__main__":
     main()
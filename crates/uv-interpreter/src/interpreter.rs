@@ -1,9 +1,11 @@
+use std::ffi::OsStr;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
 use configparser::ini::Ini;
 use fs_err as fs;
 use once_cell::sync::OnceCell;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use tracing::{debug, warn};
 
@@ -106,6 +108,75 @@ impl Interpreter {
         }
     }
 
+    /// Return a new [`Interpreter`] that installs into the given [`Scheme`] (e.g., the user scheme),
+    /// rather than its default scheme.
+    #[must_use]
+    pub fn with_scheme(self, scheme: Scheme) -> Self {
+        Self { scheme, ..self }
+    }
+
+    /// Query the user scheme for this interpreter, i.e., the scheme used for `--user` installs.
+    ///
+    /// Unlike the rest of the interpreter information, the user scheme depends on the environment
+    /// (e.g., `PYTHONUSERBASE`), so it's never cached.
+    pub fn query_user_site(&self, cache: &Cache) -> Result<UserSite, Error> {
+        run_query_script(&self.sys_executable, "main_user_site", &[], cache)
+    }
+
+    /// Query the scheme for installing into the given prefix with this interpreter, i.e., the
+    /// scheme used for `--prefix` installs.
+    ///
+    /// Distribution-specific schemes (like Debian's `posix_local`) are ignored in favor of the
+    /// standard scheme for the platform, such that packages are installed into
+    /// `{prefix}/lib/pythonX.Y/site-packages` on Unix.
+    pub fn query_prefix_scheme(&self, prefix: &Path, cache: &Cache) -> Result<Scheme, Error> {
+        run_query_script(
+            &self.sys_executable,
+            "main_prefix_scheme",
+            &[prefix.as_os_str()],
+            cache,
+        )
+    }
+
+    /// Query the module search path (`sys.path`) of this interpreter, as seen by code run via
+    /// `python -c` (excluding the leading entry for the current directory).
+    ///
+    /// Like the user scheme, `sys.path` depends on the environment (e.g., `PYTHONPATH`), so it's
+    /// never cached.
+    pub fn query_sys_path(&self) -> Result<Vec<PathBuf>, Error> {
+        let output = Command::new(&self.sys_executable)
+            .arg("-c")
+            .arg("import json, sys; print(json.dumps(sys.path[1:]))")
+            .output()
+            .map_err(|err| Error::PythonSubcommandLaunch {
+                interpreter: self.sys_executable.clone(),
+                err,
+            })?;
+
+        if !output.status.success() {
+            return Err(Error::PythonSubcommandOutput {
+                message: format!(
+                    "Querying `sys.path` of Python at `{}` failed with status {}",
+                    self.sys_executable.display(),
+                    output.status,
+                ),
+                exit_code: output.status,
+                stdout: String::from_utf8_lossy(&output.stdout).trim().to_string(),
+                stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            });
+        }
+
+        serde_json::from_slice(&output.stdout).map_err(|err| Error::PythonSubcommandOutput {
+            message: format!(
+                "Querying `sys.path` of Python at `{}` did not return the expected data: {err}",
+                self.sys_executable.display(),
+            ),
+            exit_code: output.status,
+            stdout: String::from_utf8_lossy(&output.stdout).trim().to_string(),
+            stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        })
+    }
+
     /// Returns the path to the Python virtual environment.
     #[inline]
     pub fn platform(&self) -> &Platform {
@@ -143,37 +214,14 @@ impl Interpreter {
     ///
     /// See: <https://packaging.python.org/en/latest/specifications/externally-managed-environments/>
     pub fn is_externally_managed(&self) -> Option<ExternallyManaged> {
-        // Per the spec, a virtual environment is never externally managed.
+        // Per the spec, a virtual environment is never externally managed by virtue of its base
+        // interpreter. However, virtual environments can opt in to being externally managed via an
+        // `EXTERNALLY-MANAGED` file at their root (see `uv_virtualenv::mark_externally_managed`).
         if self.is_virtualenv() {
-            return None;
+            return ExternallyManaged::from_file(&self.prefix.join("EXTERNALLY-MANAGED"));
         }
 
-        let Ok(contents) = fs::read_to_string(self.stdlib.join("EXTERNALLY-MANAGED")) else {
-            return None;
-        };
-
-        let mut ini = Ini::new_cs();
-        ini.set_multiline(true);
-
-        let Ok(mut sections) = ini.read(contents) else {
-            // If a file exists but is not a valid INI file, we assume the environment is
-            // externally managed.
-            return Some(ExternallyManaged::default());
-        };
-
-        let Some(section) = sections.get_mut("externally-managed") else {
-            // If the file exists but does not contain an "externally-managed" section, we assume
-            // the environment is externally managed.
-            return Some(ExternallyManaged::default());
-        };
-
-        let Some(error) = section.remove("Error") else {
-            // If the file exists but does not contain an "Error" key, we assume the environment is
-            // externally managed.
-            return Some(ExternallyManaged::default());
-        };
-
-        Some(ExternallyManaged { error })
+        ExternallyManaged::from_file(&self.stdlib.join("EXTERNALLY-MANAGED"))
     }
 
     /// Returns the Python version.
@@ -314,17 +362,10 @@ impl Interpreter {
                 platlib: self.platlib().to_path_buf(),
                 scripts: self.scripts().to_path_buf(),
                 data: self.data().to_path_buf(),
-                include: if self.is_virtualenv() {
-                    // If the interpreter is a venv, then the `include` directory has a different structure.
-                    // See: https://github.com/pypa/pip/blob/0ad4c94be74cc24874c6feb5bb3c2152c398a18e/src/pip/_internal/locations/_sysconfig.py#L172
-                    self.prefix.join("include").join("site").join(format!(
-                        "python{}.{}",
-                        self.python_major(),
-                        self.python_minor()
-                    ))
-                } else {
-                    self.include().to_path_buf()
-                },
+                // If the interpreter is a venv, then the `include` directory has a different
+                // structure, which is already reflected in the scheme.
+                // See: https://github.com/pypa/pip/blob/0ad4c94be74cc24874c6feb5bb3c2152c398a18e/src/pip/_internal/locations/_sysconfig.py#L172
+                include: self.include().to_path_buf(),
             },
         }
     }
@@ -351,12 +392,80 @@ pub struct ExternallyManaged {
 }
 
 impl ExternallyManaged {
+    /// Read the `EXTERNALLY-MANAGED` file at the given path, returning `None` if it doesn't exist.
+    fn from_file(path: &Path) -> Option<Self> {
+        let Ok(contents) = fs::read_to_string(path) else {
+            return None;
+        };
+
+        let mut ini = Ini::new_cs();
+        ini.set_multiline(true);
+
+        let Ok(mut sections) = ini.read(contents) else {
+            // If a file exists but is not a valid INI file, we assume the environment is
+            // externally managed.
+            return Some(Self::default());
+        };
+
+        let Some(section) = sections.get_mut("externally-managed") else {
+            // If the file exists but does not contain an "externally-managed" section, we assume
+            // the environment is externally managed.
+            return Some(Self::default());
+        };
+
+        // Prefer a localized error message (e.g., `Error-en_US`, then `Error-en`), falling back
+        // to the default `Error` key.
+        let error = error_keys()
+            .into_iter()
+            .find_map(|key| section.remove(&key))
+            .flatten();
+
+        Some(Self { error })
+    }
+
     /// Return the `EXTERNALLY-MANAGED` error message, if any.
     pub fn into_error(self) -> Option<String> {
         self.error
     }
 }
 
+/// Return the keys to search for the `EXTERNALLY-MANAGED` error message, in order of preference,
+/// based on the user's locale.
+///
+/// See: <https://packaging.python.org/en/latest/specifications/externally-managed-environments/#marking-an-interpreter-as-using-an-external-package-manager>
+fn error_keys() -> Vec<String> {
+    let mut keys = vec![];
+
+    // Like `locale.getlocale(locale.LC_MESSAGES)`, strip the encoding and modifier (e.g.,
+    // `en_US.UTF-8@euro` is `en_US`).
+    let locale = ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .into_iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|value| !value.is_empty());
+    if let Some(locale) = locale {
+        let locale = locale.split(['.', '@']).next().unwrap_or_default();
+        if !locale.is_empty() && locale != "C" && locale != "POSIX" {
+            keys.push(format!("Error-{locale}"));
+            if let Some((language, _)) = locale.split_once(['-', '_']) {
+                keys.push(format!("Error-{language}"));
+            }
+        }
+    }
+
+    keys.push("Error".to_string());
+    keys
+}
+
+/// The user site of a Python interpreter, as used for `--user` installs.
+#[derive(Debug, Clone, Deserialize)]
+pub struct UserSite {
+    /// Whether the user site is enabled (i.e., `site.ENABLE_USER_SITE`), such that packages
+    /// installed into it are visible to the interpreter.
+    pub enabled: bool,
+    /// The user scheme, e.g., `~/.local/lib/python3.12/site-packages` on Linux.
+    pub scheme: Scheme,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(tag = "result", rename_all = "lowercase")]
 enum InterpreterInfoResult {
@@ -393,53 +502,7 @@ struct InterpreterInfo {
 impl InterpreterInfo {
     /// Return the resolved [`InterpreterInfo`] for the given Python executable.
     pub(crate) fn query(interpreter: &Path, cache: &Cache) -> Result<Self, Error> {
-        let tempdir = tempfile::tempdir_in(cache.root())?;
-        Self::setup_python_query_files(tempdir.path())?;
-
-        // Sanitize the path by (1) running under isolated mode (`-I`) to ignore any site packages
-        // modifications, and then (2) adding the path containing our query script to the front of
-        // `sys.path` so that we can import it.
-        let script = format!(
-            r#"import sys; sys.path = ["{}"] + sys.path; from python.get_interpreter_info import main; main()"#,
-            tempdir.path().escape_for_python()
-        );
-        let output = Command::new(interpreter)
-            .arg("-I")
-            .arg("-c")
-            .arg(script)
-            .output()
-            .map_err(|err| Error::PythonSubcommandLaunch {
-                interpreter: interpreter.to_path_buf(),
-                err,
-            })?;
-
-        if !output.status.success() {
-            return Err(Error::PythonSubcommandOutput {
-                message: format!(
-                    "Querying Python at `{}` failed with status {}",
-                    interpreter.display(),
-                    output.status,
-                ),
-                exit_code: output.status,
-                stdout: String::from_utf8_lossy(&output.stdout).trim().to_string(),
-                stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
-            });
-        }
-
-        let result: InterpreterInfoResult =
-            serde_json::from_slice(&output.stdout).map_err(|err| {
-                Error::PythonSubcommandOutput {
-                    message: format!(
-                        "Querying Python at `{}` did not return the expected data: {err}",
-                        interpreter.display(),
-                    ),
-                    exit_code: output.status,
-                    stdout: String::from_utf8_lossy(&output.stdout).trim().to_string(),
-                    stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
-                }
-            })?;
-
-        match result {
+        match run_query_script::<InterpreterInfoResult>(interpreter, "main", &[], cache)? {
             InterpreterInfoResult::Error(err) => Err(Error::QueryScript {
                 err,
                 interpreter: interpreter.to_path_buf(),
@@ -555,6 +618,59 @@ impl InterpreterInfo {
     }
 }
 
+/// Run the given function from the Python probing scripts with the given Python executable,
+/// deserializing its JSON output. Any arguments are passed to the function as strings.
+fn run_query_script<T: DeserializeOwned>(
+    interpreter: &Path,
+    function: &str,
+    args: &[&OsStr],
+    cache: &Cache,
+) -> Result<T, Error> {
+    let tempdir = tempfile::tempdir_in(cache.root())?;
+    InterpreterInfo::setup_python_query_files(tempdir.path())?;
+
+    // Sanitize the path by (1) running under isolated mode (`-I`) to ignore any site packages
+    // modifications, and then (2) adding the path containing our query script to the front of
+    // `sys.path` so that we can import it.
+    let script = format!(
+        r#"import sys; sys.path = ["{}"] + sys.path; from python.get_interpreter_info import {function}; {function}(*sys.argv[1:])"#,
+        tempdir.path().escape_for_python()
+    );
+    let output = Command::new(interpreter)
+        .arg("-I")
+        .arg("-c")
+        .arg(script)
+        .args(args)
+        .output()
+        .map_err(|err| Error::PythonSubcommandLaunch {
+            interpreter: interpreter.to_path_buf(),
+            err,
+        })?;
+
+    if !output.status.success() {
+        return Err(Error::PythonSubcommandOutput {
+            message: format!(
+                "Querying Python at `{}` failed with status {}",
+                interpreter.display(),
+                output.status,
+            ),
+            exit_code: output.status,
+            stdout: String::from_utf8_lossy(&output.stdout).trim().to_string(),
+            stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        });
+    }
+
+    serde_json::from_slice(&output.stdout).map_err(|err| Error::PythonSubcommandOutput {
+        message: format!(
+            "Querying Python at `{}` did not return the expected data: {err}",
+            interpreter.display(),
+        ),
+        exit_code: output.status,
+        stdout: String::from_utf8_lossy(&output.stdout).trim().to_string(),
+        stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+    })
+}
+
 #[cfg(unix)]
 #[cfg(test)]
 mod tests {
@@ -16,8 +16,8 @@ use thiserror::Error;
 
 pub use crate::cfg::PyVenvConfiguration;
 pub use crate::find_python::{find_best_python, find_default_python, find_requested_python};
-pub use crate::interpreter::Interpreter;
 use crate::interpreter::InterpreterInfoError;
+pub use crate::interpreter::{ExternallyManaged, Interpreter, UserSite};
 pub use crate::python_environment::PythonEnvironment;
 pub use crate::virtualenv::Virtualenv;
 
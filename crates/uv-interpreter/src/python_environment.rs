@@ -1,5 +1,6 @@
 use std::env;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use same_file::is_same_file;
 use tracing::{debug, info};
@@ -40,6 +41,21 @@ impl PythonEnvironment {
         })
     }
 
+    /// Create a [`PythonEnvironment`] for the virtual environment at the given root.
+    pub fn from_root(root: &Path, cache: &Cache) -> Result<Self, Error> {
+        if !root.join("pyvenv.cfg").is_file() {
+            return Err(Error::MissingPyVenvCfg(root.to_path_buf()));
+        }
+        let venv = fs_err::canonicalize(root)?;
+        let executable = detect_python_executable(&venv);
+        let interpreter = Interpreter::query(&executable, cache)?;
+
+        Ok(Self {
+            root: venv,
+            interpreter,
+        })
+    }
+
     /// Create a [`PythonEnvironment`] for a Python interpreter specifier (e.g., a path or a binary name).
     pub fn from_requested_python(python: &str, cache: &Cache) -> Result<Self, Error> {
         let Some(interpreter) = find_requested_python(python, cache)? else {
@@ -114,15 +130,22 @@ impl PythonEnvironment {
     }
 
     /// Grab a file lock for the virtual environment to prevent concurrent writes across processes.
-    pub fn lock(&self) -> Result<LockedFile, std::io::Error> {
+    ///
+    /// Waits for up to `timeout` (or indefinitely, if `None`) for another process to release it.
+    pub fn lock(&self, timeout: Option<Duration>) -> Result<LockedFile, std::io::Error> {
         if self.interpreter.is_virtualenv() {
             // If the environment a virtualenv, use a virtualenv-specific lock file.
-            LockedFile::acquire(self.root.join(".lock"), self.root.simplified_display())
+            LockedFile::acquire(
+                self.root.join(".lock"),
+                self.root.simplified_display(),
+                timeout,
+            )
         } else {
             // Otherwise, use a global lock file.
             LockedFile::acquire(
                 env::temp_dir().join(format!("uv-{}.lock", cache_key::digest(&self.root))),
                 self.root.simplified_display(),
+                timeout,
             )
         }
     }
@@ -89,11 +89,18 @@ impl<'a, Context: BuildContext + Send + Sync> LookaheadResolver<'a, Context> {
         // Queue up the initial requirements.
         let mut queue: VecDeque<Requirement> = self
             .constraints
-            .apply(self.overrides.apply(self.requirements))
+            .apply(
+                self.overrides
+                    .apply_markers(self.requirements, markers, &[]),
+            )
             .filter(|requirement| requirement.evaluate_markers(markers, &[]))
             .chain(self.editables.iter().flat_map(|(editable, metadata)| {
                 self.constraints
-                    .apply(self.overrides.apply(&metadata.requires_dist))
+                    .apply(self.overrides.apply_markers(
+                        &metadata.requires_dist,
+                        markers,
+                        &editable.extras,
+                    ))
                     .filter(|requirement| requirement.evaluate_markers(markers, &editable.extras))
             }))
             .cloned()
@@ -110,10 +117,11 @@ impl<'a, Context: BuildContext + Send + Sync> LookaheadResolver<'a, Context> {
 
             while let Some(result) = futures.next().await {
                 if let Some(lookahead) = result? {
-                    for requirement in self
-                        .constraints
-                        .apply(self.overrides.apply(lookahead.requirements()))
-                    {
+                    for requirement in self.constraints.apply(self.overrides.apply_markers(
+                        lookahead.requirements(),
+                        markers,
+                        lookahead.extras(),
+                    )) {
                         if requirement.evaluate_markers(markers, lookahead.extras()) {
                             queue.push_back(requirement.clone());
                         }
@@ -2,7 +2,7 @@ use pubgrub::range::Range;
 
 use distribution_types::{CompatibleDist, IncompatibleDist, IncompatibleSource};
 use distribution_types::{DistributionMetadata, IncompatibleWheel, Name, PrioritizedDist};
-use pep440_rs::Version;
+use pep440_rs::{LocalSegment, Version};
 use pep508_rs::MarkerEnvironment;
 use tracing::debug;
 use uv_normalize::PackageName;
@@ -12,18 +12,19 @@ use crate::preferences::Preferences;
 use crate::prerelease_mode::PreReleaseStrategy;
 use crate::resolution_mode::ResolutionStrategy;
 use crate::version_map::{VersionMap, VersionMapDistHandle};
-use crate::{Exclusions, Manifest, Options};
+use crate::{Exclusions, Manifest, Options, PreferLocal};
 
 #[derive(Debug, Clone)]
 pub(crate) struct CandidateSelector {
     resolution_strategy: ResolutionStrategy,
     prerelease_strategy: PreReleaseStrategy,
+    prefer_local: PreferLocal,
 }
 
 impl CandidateSelector {
     /// Return a [`CandidateSelector`] for the given [`Manifest`].
     pub(crate) fn for_resolution(
-        options: Options,
+        options: &Options,
         manifest: &Manifest,
         markers: &MarkerEnvironment,
     ) -> Self {
@@ -38,6 +39,7 @@ impl CandidateSelector {
                 manifest,
                 markers,
             ),
+            prefer_local: options.prefer_local.clone(),
         }
     }
 
@@ -76,9 +78,11 @@ impl CandidateSelector {
         installed_packages: &'a InstalledPackages,
         exclusions: &'a Exclusions,
     ) -> Option<Candidate<'a>> {
+        let local = self.local_variant(package_name, version_maps);
         if let Some(preferred) = Self::get_preferred(
             package_name,
             range,
+            local,
             version_maps,
             preferences,
             installed_packages,
@@ -95,6 +99,7 @@ impl CandidateSelector {
     fn get_preferred<'a, InstalledPackages: InstalledPackagesProvider>(
         package_name: &'a PackageName,
         range: &Range<Version>,
+        local: Option<&[LocalSegment]>,
         version_maps: &'a [VersionMap],
         preferences: &'a Preferences,
         installed_packages: &'a InstalledPackages,
@@ -103,7 +108,7 @@ impl CandidateSelector {
         // If the package has a preference (e.g., an existing version from an existing lockfile),
         // and the preference satisfies the current range, use that.
         if let Some(version) = preferences.version(package_name) {
-            if range.contains(version) {
+            if range.contains(version) && matches_local(version, local) {
                 // Check for a locally installed distribution that matches the preferred version
                 if !exclusions.contains(package_name) {
                     let installed_dists = installed_packages.get_packages(package_name);
@@ -147,7 +152,7 @@ impl CandidateSelector {
                 [] => {}
                 [dist] => {
                     let version = dist.version();
-                    if range.contains(version) {
+                    if range.contains(version) && matches_local(version, local) {
                         debug!("Found installed version of {dist} that satisfies {range}");
 
                         return Some(Candidate {
@@ -168,6 +173,43 @@ impl CandidateSelector {
         None
     }
 
+    /// Return the local version label that versions of the given package must match, per the
+    /// [`PreferLocal`] policy, if any.
+    fn local_variant(
+        &self,
+        package_name: &PackageName,
+        version_maps: &[VersionMap],
+    ) -> Option<&[LocalSegment]> {
+        self.prefer_local.required(
+            package_name,
+            version_maps
+                .iter()
+                .flat_map(VersionMap::iter)
+                .map(|(version, _)| version),
+        )
+    }
+
+    /// If the [`PreferLocal`] policy excluded every version of the given package in the range,
+    /// return the local version label that the versions failed to match.
+    pub(crate) fn missing_local_variant(
+        &self,
+        package_name: &PackageName,
+        range: &Range<Version>,
+        version_maps: &[VersionMap],
+    ) -> Option<&[LocalSegment]> {
+        let local = self.local_variant(package_name, version_maps)?;
+        let mut versions = version_maps
+            .iter()
+            .flat_map(VersionMap::iter)
+            .map(|(version, _)| version)
+            .filter(|version| range.contains(version));
+        if versions.any(|version| !matches_local(version, Some(local))) {
+            Some(local)
+        } else {
+            None
+        }
+    }
+
     /// Determine the appropriate prerelease strategy for the current package.
     fn allow_prereleases(&self, package_name: &PackageName) -> AllowPreRelease {
         match &self.prerelease_strategy {
@@ -207,6 +249,7 @@ impl CandidateSelector {
         );
         let highest = self.use_highest_version(package_name);
         let allow_prerelease = self.allow_prereleases(package_name);
+        let local = self.local_variant(package_name, version_maps);
 
         if highest {
             version_maps.iter().find_map(|version_map| {
@@ -214,12 +257,19 @@ impl CandidateSelector {
                     version_map.iter().rev(),
                     package_name,
                     range,
+                    local,
                     allow_prerelease,
                 )
             })
         } else {
             version_maps.iter().find_map(|version_map| {
-                Self::select_candidate(version_map.iter(), package_name, range, allow_prerelease)
+                Self::select_candidate(
+                    version_map.iter(),
+                    package_name,
+                    range,
+                    local,
+                    allow_prerelease,
+                )
             })
         }
     }
@@ -242,6 +292,7 @@ impl CandidateSelector {
         versions: impl Iterator<Item = (&'a Version, VersionMapDistHandle<'a>)> + ExactSizeIterator,
         package_name: &'a PackageName,
         range: &Range<Version>,
+        local: Option<&[LocalSegment]>,
         allow_prerelease: AllowPreRelease,
     ) -> Option<Candidate<'a>> {
         #[derive(Debug)]
@@ -253,6 +304,11 @@ impl CandidateSelector {
         let mut prerelease = None;
         let versions_len = versions.len();
         for (step, (version, maybe_dist)) in versions.enumerate() {
+            // Skip any variants that don't match the preferred local version label.
+            if !matches_local(version, local) {
+                continue;
+            }
+
             let candidate = if version.any_prerelease() {
                 if range.contains(version) {
                     match allow_prerelease {
@@ -350,6 +406,11 @@ impl CandidateSelector {
     }
 }
 
+/// Returns `true` if the version matches the required local version label, if any.
+fn matches_local(version: &Version, local: Option<&[LocalSegment]>) -> bool {
+    local.map_or(true, |local| version.local() == local)
+}
+
 #[derive(Debug, Clone)]
 pub(crate) enum CandidateDist<'a> {
     Compatible(CompatibleDist<'a>),
@@ -133,13 +133,7 @@ impl FlatIndex {
 
         // Check if hashes line up
         let hash = if let HashPolicy::Validate(required) = hasher.get_package(&filename.name) {
-            if hashes.is_empty() {
-                Hash::Missing
-            } else if required.iter().any(|hash| hashes.contains(hash)) {
-                Hash::Matched
-            } else {
-                Hash::Mismatched
-            }
+            Hash::from_digests(required, hashes)
         } else {
             Hash::Matched
         };
@@ -175,13 +169,7 @@ impl FlatIndex {
 
         // Check if hashes line up
         let hash = if let HashPolicy::Validate(required) = hasher.get_package(&filename.name) {
-            if hashes.is_empty() {
-                Hash::Missing
-            } else if required.iter().any(|hash| hashes.contains(hash)) {
-                Hash::Matched
-            } else {
-                Hash::Mismatched
-            }
+            Hash::from_digests(required, hashes)
         } else {
             Hash::Matched
         };
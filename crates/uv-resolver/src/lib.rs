@@ -1,10 +1,12 @@
 pub use dependency_mode::DependencyMode;
-pub use error::ResolveError;
+pub use error::{NoSolutionError, ResolveError};
 pub use exclude_newer::ExcludeNewer;
 pub use exclusions::Exclusions;
 pub use flat_index::FlatIndex;
 pub use manifest::Manifest;
+pub use minimum_release_age::{MinimumReleaseAge, ReleaseAge, ReleaseAgeError};
 pub use options::{Options, OptionsBuilder};
+pub use prefer_local::{LocalPreference, LocalPreferenceError, PreferLocal};
 pub use preferences::{Preference, PreferenceError};
 pub use prerelease_mode::PreReleaseMode;
 pub use python_requirement::PythonRequirement;
@@ -29,8 +31,10 @@ mod exclude_newer;
 mod exclusions;
 mod flat_index;
 mod manifest;
+mod minimum_release_age;
 mod options;
 mod pins;
+mod prefer_local;
 mod preferences;
 mod prerelease_mode;
 mod pubgrub;
@@ -102,18 +102,15 @@ impl Manifest {
             .iter()
             .flat_map(|lookahead| {
                 self.overrides
-                    .apply(lookahead.requirements())
-                    .filter(|requirement| requirement.evaluate_markers(markers, lookahead.extras()))
+                    .apply_markers(lookahead.requirements(), markers, lookahead.extras())
             })
             .chain(self.editables.iter().flat_map(|(editable, metadata)| {
                 self.overrides
-                    .apply(&metadata.requires_dist)
-                    .filter(|requirement| requirement.evaluate_markers(markers, &editable.extras))
+                    .apply_markers(&metadata.requires_dist, markers, &editable.extras)
             }))
             .chain(
                 self.overrides
-                    .apply(&self.requirements)
-                    .filter(|requirement| requirement.evaluate_markers(markers, &[])),
+                    .apply_markers(&self.requirements, markers, &[]),
             )
             .chain(
                 self.constraints
@@ -141,18 +138,15 @@ impl Manifest {
             .filter(|lookahead| lookahead.direct())
             .flat_map(|lookahead| {
                 self.overrides
-                    .apply(lookahead.requirements())
-                    .filter(|requirement| requirement.evaluate_markers(markers, lookahead.extras()))
+                    .apply_markers(lookahead.requirements(), markers, lookahead.extras())
             })
             .chain(self.editables.iter().flat_map(|(editable, metadata)| {
                 self.overrides
-                    .apply(&metadata.requires_dist)
-                    .filter(|requirement| requirement.evaluate_markers(markers, &editable.extras))
+                    .apply_markers(&metadata.requires_dist, markers, &editable.extras)
             }))
             .chain(
                 self.overrides
-                    .apply(&self.requirements)
-                    .filter(|requirement| requirement.evaluate_markers(markers, &[])),
+                    .apply_markers(&self.requirements, markers, &[]),
             )
             .map(|requirement| &requirement.name)
     }
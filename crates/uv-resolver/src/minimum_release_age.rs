@@ -0,0 +1,321 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use chrono::{DateTime, Duration, DurationRound, Utc};
+use rustc_hash::FxHashMap;
+
+use uv_normalize::{InvalidNameError, PackageName};
+
+use crate::ExcludeNewer;
+
+#[derive(thiserror::Error, Debug)]
+pub enum ReleaseAgeError {
+    #[error("Invalid release age `{0}` (expected, e.g., `14d` or `<package>=14d`)")]
+    InvalidAge(String),
+    #[error("Invalid package name in release age `{0}`")]
+    InvalidPackage(String, #[source] InvalidNameError),
+}
+
+/// A unit of time in which a release age can be expressed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Unit {
+    Hours,
+    Days,
+    Weeks,
+}
+
+impl Unit {
+    fn suffix(self) -> char {
+        match self {
+            Self::Hours => 'h',
+            Self::Days => 'd',
+            Self::Weeks => 'w',
+        }
+    }
+}
+
+/// The minimum age of a release (e.g., `14d`), optionally scoped to a single package (e.g.,
+/// `torch=3d`).
+///
+/// Ages are expressed as a whole number of hours (`h`), days (`d`), or weeks (`w`). An age of zero
+/// (e.g., `torch=0d`) exempts a package from a minimum age configured for all packages.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReleaseAge {
+    package: Option<PackageName>,
+    amount: u32,
+    unit: Unit,
+}
+
+impl ReleaseAge {
+    /// Return the age as a [`Duration`].
+    fn duration(&self) -> Duration {
+        let amount = i64::from(self.amount);
+        match self.unit {
+            Unit::Hours => Duration::hours(amount),
+            Unit::Days => Duration::days(amount),
+            Unit::Weeks => Duration::weeks(amount),
+        }
+    }
+}
+
+impl FromStr for ReleaseAge {
+    type Err = ReleaseAgeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (package, age) = match s.split_once('=') {
+            Some((package, age)) => (
+                Some(
+                    PackageName::from_str(package)
+                        .map_err(|err| ReleaseAgeError::InvalidPackage(s.to_string(), err))?,
+                ),
+                age,
+            ),
+            None => (None, s),
+        };
+
+        let unit = match age.chars().last() {
+            Some('h') => Unit::Hours,
+            Some('d') => Unit::Days,
+            Some('w') => Unit::Weeks,
+            _ => return Err(ReleaseAgeError::InvalidAge(s.to_string())),
+        };
+        let amount = age[..age.len() - 1]
+            .parse::<u32>()
+            .map_err(|_| ReleaseAgeError::InvalidAge(s.to_string()))?;
+
+        Ok(Self {
+            package,
+            amount,
+            unit,
+        })
+    }
+}
+
+impl Display for ReleaseAge {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(package) = &self.package {
+            write!(f, "{package}=")?;
+        }
+        write!(f, "{}{}", self.amount, self.unit.suffix())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ReleaseAge {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        FromStr::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+/// A policy that excludes releases uploaded more recently than a minimum age, such that new
+/// releases are only considered once they've been available for some time (e.g., to allow for
+/// malicious or broken releases to be yanked).
+///
+/// Like `--exclude-newer`, the policy relies on the upload times reported by the index.
+#[derive(Debug, Default, Clone)]
+pub struct MinimumReleaseAge {
+    /// The minimum age for all packages.
+    global: Option<Duration>,
+    /// The minimum ages for specific packages.
+    packages: FxHashMap<PackageName, Duration>,
+}
+
+impl MinimumReleaseAge {
+    /// Create a [`MinimumReleaseAge`] policy from a set of [`ReleaseAge`] entries.
+    ///
+    /// If multiple entries apply to the same package, the last one wins.
+    pub fn from_ages(ages: impl IntoIterator<Item = ReleaseAge>) -> Self {
+        let mut global = None;
+        let mut packages = FxHashMap::default();
+        for age in ages {
+            let duration = age.duration();
+            if let Some(package) = age.package {
+                packages.insert(package, duration);
+            } else {
+                global = Some(duration);
+            }
+        }
+        Self { global, packages }
+    }
+
+    /// Returns `true` if the policy doesn't constrain any packages.
+    pub fn is_empty(&self) -> bool {
+        self.global.is_none() && self.packages.is_empty()
+    }
+
+    /// Return the time from which release ages are measured: the current time, truncated to the
+    /// hour.
+    ///
+    /// Since ages are expressed in whole hours, every cutoff then falls on an hour boundary, such
+    /// that all resolutions within the same hour apply the same cutoffs.
+    pub fn now() -> DateTime<Utc> {
+        let now = Utc::now();
+        now.duration_trunc(Duration::hours(1)).unwrap_or(now)
+    }
+
+    /// Return the cutoffs that the policy implies at the given time: the cutoff for all packages,
+    /// followed by the cutoff for each package with its own minimum age, sorted by name.
+    ///
+    /// Packages exempted by an age of zero have no cutoff.
+    pub fn cutoffs(
+        &self,
+        now: DateTime<Utc>,
+    ) -> (
+        Option<ExcludeNewer>,
+        Vec<(&PackageName, Option<ExcludeNewer>)>,
+    ) {
+        let cutoff = |age: &Duration| (!age.is_zero()).then(|| ExcludeNewer::from(now - *age));
+        let mut packages = self
+            .packages
+            .iter()
+            .map(|(package, age)| (package, cutoff(age)))
+            .collect::<Vec<_>>();
+        packages.sort_unstable_by_key(|(package, _)| *package);
+        (self.global.as_ref().and_then(cutoff), packages)
+    }
+
+    /// Returns `true` if a (non-zero) minimum age applies to the given package.
+    pub(crate) fn applies(&self, package: &PackageName) -> bool {
+        self.age(package).is_some()
+    }
+
+    /// Return the minimum age for the given package, if any.
+    ///
+    /// An age of zero exempts the package, and so is treated as no minimum age at all.
+    fn age(&self, package: &PackageName) -> Option<Duration> {
+        self.packages
+            .get(package)
+            .or(self.global.as_ref())
+            .copied()
+            .filter(|age| !age.is_zero())
+    }
+
+    /// Return the cutoff after which releases of the given package are excluded, combining the
+    /// minimum age with the `--exclude-newer` timestamp, if any.
+    ///
+    /// If no minimum age applies to the package, or the age is zero, the `--exclude-newer`
+    /// timestamp is returned as-is.
+    pub(crate) fn exclude_newer(
+        &self,
+        package: &PackageName,
+        exclude_newer: Option<ExcludeNewer>,
+        now: DateTime<Utc>,
+    ) -> Option<ExcludeNewer> {
+        let Some(age) = self.age(package) else {
+            return exclude_newer;
+        };
+        let cutoff = ExcludeNewer::from(now - age);
+        match exclude_newer {
+            Some(exclude_newer) if exclude_newer.timestamp_millis() < cutoff.timestamp_millis() => {
+                Some(exclude_newer)
+            }
+            _ => Some(cutoff),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use chrono::{DateTime, Utc};
+    use uv_normalize::PackageName;
+
+    use crate::ExcludeNewer;
+
+    use super::{MinimumReleaseAge, ReleaseAge};
+
+    #[test]
+    fn parse() {
+        for age in ["14d", "12h", "2w", "0d", "torch=3d"] {
+            assert_eq!(ReleaseAge::from_str(age).unwrap().to_string(), age);
+        }
+        assert_eq!(
+            ReleaseAge::from_str("Torch_Vision=1w").unwrap().to_string(),
+            "torch-vision=1w"
+        );
+        assert!(ReleaseAge::from_str("14").is_err());
+        assert!(ReleaseAge::from_str("d").is_err());
+        assert!(ReleaseAge::from_str("-1d").is_err());
+        assert!(ReleaseAge::from_str("14 days").is_err());
+        assert!(ReleaseAge::from_str("-torch=1d").is_err());
+    }
+
+    #[test]
+    fn exclude_newer() {
+        let policy = MinimumReleaseAge::from_ages(
+            ["14d", "torch=0d", "numpy=1w"]
+                .into_iter()
+                .map(|age| ReleaseAge::from_str(age).unwrap()),
+        );
+        let now = DateTime::parse_from_rfc3339("2024-03-25T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let torch = PackageName::from_str("torch").unwrap();
+        let numpy = PackageName::from_str("numpy").unwrap();
+        let anyio = PackageName::from_str("anyio").unwrap();
+
+        let cutoff = policy.exclude_newer(&anyio, None, now).unwrap();
+        assert_eq!(cutoff.to_string(), "2024-03-11 00:00:00 UTC");
+        let cutoff = policy.exclude_newer(&numpy, None, now).unwrap();
+        assert_eq!(cutoff.to_string(), "2024-03-18 00:00:00 UTC");
+
+        // A zero age exempts the package, leaving the `--exclude-newer` timestamp as-is.
+        assert!(policy.exclude_newer(&torch, None, now).is_none());
+        let exclude_newer = ExcludeNewer::from_str("2024-01-01T00:00:00Z").unwrap();
+        let cutoff = policy
+            .exclude_newer(&torch, Some(exclude_newer), now)
+            .unwrap();
+        assert_eq!(cutoff.to_string(), "2024-01-01 00:00:00 UTC");
+
+        // The earlier of the two cutoffs wins.
+        let exclude_newer = ExcludeNewer::from_str("2024-01-01T00:00:00Z").unwrap();
+        let cutoff = policy
+            .exclude_newer(&anyio, Some(exclude_newer), now)
+            .unwrap();
+        assert_eq!(cutoff.to_string(), "2024-01-01 00:00:00 UTC");
+        let exclude_newer = ExcludeNewer::from_str("2024-03-20T00:00:00Z").unwrap();
+        let cutoff = policy
+            .exclude_newer(&anyio, Some(exclude_newer), now)
+            .unwrap();
+        assert_eq!(cutoff.to_string(), "2024-03-11 00:00:00 UTC");
+
+        // Without a policy, the `--exclude-newer` timestamp is returned as-is.
+        assert!(MinimumReleaseAge::default()
+            .exclude_newer(&anyio, None, now)
+            .is_none());
+    }
+
+    #[test]
+    fn cutoffs() {
+        let policy = MinimumReleaseAge::from_ages(
+            ["14d", "torch=0d", "numpy=1w"]
+                .into_iter()
+                .map(|age| ReleaseAge::from_str(age).unwrap()),
+        );
+        let now = DateTime::parse_from_rfc3339("2024-03-25T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let (global, packages) = policy.cutoffs(now);
+        assert_eq!(global.unwrap().to_string(), "2024-03-11 00:00:00 UTC");
+        let packages = packages
+            .into_iter()
+            .map(|(package, cutoff)| (package.to_string(), cutoff.map(|cutoff| cutoff.to_string())))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            packages,
+            [
+                (
+                    "numpy".to_string(),
+                    Some("2024-03-18 00:00:00 UTC".to_string())
+                ),
+                ("torch".to_string(), None),
+            ]
+        );
+    }
+}
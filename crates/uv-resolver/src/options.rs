@@ -1,12 +1,16 @@
-use crate::{DependencyMode, ExcludeNewer, PreReleaseMode, ResolutionMode};
+use crate::{
+    DependencyMode, ExcludeNewer, MinimumReleaseAge, PreReleaseMode, PreferLocal, ResolutionMode,
+};
 
 /// Options for resolving a manifest.
-#[derive(Debug, Default, Copy, Clone)]
+#[derive(Debug, Default, Clone)]
 pub struct Options {
     pub resolution_mode: ResolutionMode,
     pub prerelease_mode: PreReleaseMode,
     pub dependency_mode: DependencyMode,
     pub exclude_newer: Option<ExcludeNewer>,
+    pub minimum_release_age: MinimumReleaseAge,
+    pub prefer_local: PreferLocal,
 }
 
 /// Builder for [`Options`].
@@ -16,6 +20,8 @@ pub struct OptionsBuilder {
     prerelease_mode: PreReleaseMode,
     dependency_mode: DependencyMode,
     exclude_newer: Option<ExcludeNewer>,
+    minimum_release_age: MinimumReleaseAge,
+    prefer_local: PreferLocal,
 }
 
 impl OptionsBuilder {
@@ -52,6 +58,20 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets the [`MinimumReleaseAge`] policy.
+    #[must_use]
+    pub fn minimum_release_age(mut self, minimum_release_age: MinimumReleaseAge) -> Self {
+        self.minimum_release_age = minimum_release_age;
+        self
+    }
+
+    /// Sets the [`PreferLocal`] policy.
+    #[must_use]
+    pub fn prefer_local(mut self, prefer_local: PreferLocal) -> Self {
+        self.prefer_local = prefer_local;
+        self
+    }
+
     /// Builds the options.
     pub fn build(self) -> Options {
         Options {
@@ -59,6 +79,8 @@ impl OptionsBuilder {
             prerelease_mode: self.prerelease_mode,
             dependency_mode: self.dependency_mode,
             exclude_newer: self.exclude_newer,
+            minimum_release_age: self.minimum_release_age,
+            prefer_local: self.prefer_local,
         }
     }
 }
@@ -0,0 +1,228 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use itertools::Itertools;
+use rustc_hash::FxHashMap;
+
+use pep440_rs::{LocalSegment, Version, VersionParseError};
+use uv_normalize::{InvalidNameError, PackageName};
+
+#[derive(thiserror::Error, Debug)]
+pub enum LocalPreferenceError {
+    #[error("Invalid local version preference `{0}` (expected `+<label>` or `<package>+<label>`)")]
+    MissingSeparator(String),
+    #[error("Invalid package name in local version preference `{0}`")]
+    InvalidPackage(String, #[source] InvalidNameError),
+    #[error("Invalid local version label in local version preference `{0}`")]
+    InvalidLocal(String, #[source] VersionParseError),
+}
+
+/// A preferred local version label (e.g., `+cu121`), optionally scoped to a single package (e.g.,
+/// `torch+cu121`).
+///
+/// An empty label (e.g., `torch+`) prefers the public version of a package, i.e., the version
+/// without a local label.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalPreference {
+    package: Option<PackageName>,
+    local: Vec<LocalSegment>,
+}
+
+impl FromStr for LocalPreference {
+    type Err = LocalPreferenceError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((package, local)) = s.split_once('+') else {
+            return Err(LocalPreferenceError::MissingSeparator(s.to_string()));
+        };
+
+        let package = if package.is_empty() {
+            None
+        } else {
+            Some(
+                PackageName::from_str(package)
+                    .map_err(|err| LocalPreferenceError::InvalidPackage(s.to_string(), err))?,
+            )
+        };
+
+        // Parse the label as the local segment of a version, to normalize it (e.g., `CU-121` to
+        // `cu.121`).
+        let local = if local.is_empty() {
+            Vec::new()
+        } else {
+            Version::from_str(&format!("0+{local}"))
+                .map_err(|err| LocalPreferenceError::InvalidLocal(s.to_string(), err))?
+                .local()
+                .to_vec()
+        };
+
+        Ok(Self { package, local })
+    }
+}
+
+impl Display for LocalPreference {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(package) = &self.package {
+            write!(f, "{package}")?;
+        }
+        write!(f, "+{}", self.local.iter().join("."))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for LocalPreference {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        FromStr::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+/// A policy for selecting between the local version variants of a package, as published by
+/// PyTorch-style indexes (e.g., `2.3.0+cu121` vs. `2.3.0+cpu`).
+///
+/// By default, local versions are treated like any other version, such that the highest variant
+/// wins (e.g., `2.3.0+cu121` is preferred over both `2.3.0+cpu` and `2.3.0`).
+#[derive(Debug, Default, Clone)]
+pub struct PreferLocal {
+    /// The label to require for any package that publishes local versions.
+    global: Option<Vec<LocalSegment>>,
+    /// The labels to require for specific packages.
+    packages: FxHashMap<PackageName, Vec<LocalSegment>>,
+}
+
+impl PreferLocal {
+    /// Create a [`PreferLocal`] policy from a set of [`LocalPreference`] entries.
+    ///
+    /// If multiple entries apply to the same package, the last one wins.
+    pub fn from_preferences(preferences: impl IntoIterator<Item = LocalPreference>) -> Self {
+        let mut global = None;
+        let mut packages = FxHashMap::default();
+        for LocalPreference { package, local } in preferences {
+            if let Some(package) = package {
+                packages.insert(package, local);
+            } else {
+                global = Some(local);
+            }
+        }
+        Self { global, packages }
+    }
+
+    /// Returns `true` if the policy doesn't constrain any packages.
+    pub fn is_empty(&self) -> bool {
+        self.global.is_none() && self.packages.is_empty()
+    }
+
+    /// Return the preferences that make up the policy: the preference for all packages, if any,
+    /// followed by the preference for each package, sorted by name.
+    pub fn preferences(&self) -> Vec<LocalPreference> {
+        self.global
+            .iter()
+            .map(|local| LocalPreference {
+                package: None,
+                local: local.clone(),
+            })
+            .chain(
+                self.packages
+                    .iter()
+                    .sorted_unstable_by_key(|(package, _)| *package)
+                    .map(|(package, local)| LocalPreference {
+                        package: Some(package.clone()),
+                        local: local.clone(),
+                    }),
+            )
+            .collect()
+    }
+
+    /// Return the local label configured for the given package, if any.
+    pub(crate) fn get(&self, package: &PackageName) -> Option<&[LocalSegment]> {
+        self.packages
+            .get(package)
+            .or(self.global.as_ref())
+            .map(Vec::as_slice)
+    }
+
+    /// Return the local label that versions of the given package must match, if any.
+    ///
+    /// Labels configured for a specific package always apply. Labels configured for all packages
+    /// only apply to packages that publish at least one local version, since most packages never
+    /// do.
+    pub(crate) fn required<'a, 'b>(
+        &'a self,
+        package: &PackageName,
+        mut versions: impl Iterator<Item = &'b Version>,
+    ) -> Option<&'a [LocalSegment]> {
+        if let Some(local) = self.packages.get(package) {
+            return Some(local);
+        }
+        let local = self.global.as_ref()?;
+        if versions.any(Version::is_local) {
+            Some(local)
+        } else {
+            None
+        }
+    }
+
+    /// Format a local label for display (e.g., `+cu121`).
+    pub(crate) fn display(local: &[LocalSegment]) -> String {
+        format!("+{}", local.iter().join("."))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use pep440_rs::Version;
+    use uv_normalize::PackageName;
+
+    use super::{LocalPreference, PreferLocal};
+
+    #[test]
+    fn parse() {
+        for preference in ["+cu121", "torch+cu121", "torch+", "+", "torch+rocm5.7"] {
+            assert_eq!(
+                LocalPreference::from_str(preference).unwrap().to_string(),
+                preference
+            );
+        }
+        assert_eq!(
+            LocalPreference::from_str("Torch+CU-121")
+                .unwrap()
+                .to_string(),
+            "torch+cu.121"
+        );
+        assert!(LocalPreference::from_str("torch").is_err());
+        assert!(LocalPreference::from_str("torch+cu 121").is_err());
+        assert!(LocalPreference::from_str("-torch+cu121").is_err());
+    }
+
+    #[test]
+    fn required() {
+        let policy = PreferLocal::from_preferences(
+            ["+cu121", "torch+cu118", "numpy+"]
+                .into_iter()
+                .map(|preference| LocalPreference::from_str(preference).unwrap()),
+        );
+        let torch = PackageName::from_str("torch").unwrap();
+        let torchvision = PackageName::from_str("torchvision").unwrap();
+        let numpy = PackageName::from_str("numpy").unwrap();
+        let anyio = PackageName::from_str("anyio").unwrap();
+
+        let local = [Version::from_str("0.18.0+cu121").unwrap()];
+        let public = [Version::from_str("1.0.0").unwrap()];
+
+        // Package-specific labels always apply.
+        let required = policy.required(&torch, public.iter()).unwrap();
+        assert_eq!(PreferLocal::display(required), "+cu118");
+        let required = policy.required(&numpy, public.iter()).unwrap();
+        assert_eq!(PreferLocal::display(required), "+");
+
+        // Global labels only apply to packages with local versions.
+        let required = policy.required(&torchvision, local.iter()).unwrap();
+        assert_eq!(PreferLocal::display(required), "+cu121");
+        assert!(policy.required(&anyio, public.iter()).is_none());
+    }
+}
@@ -31,17 +31,9 @@ impl PubGrubDependencies {
     ) -> Result<Self, ResolveError> {
         let mut dependencies = Vec::default();
 
-        // Iterate over all declared requirements.
-        for requirement in overrides.apply(requirements) {
-            // If the requirement isn't relevant for the current platform, skip it.
-            if let Some(extra) = source_extra {
-                if !requirement.evaluate_markers(env, std::slice::from_ref(extra)) {
-                    continue;
-                }
-            } else if !requirement.evaluate_markers(env, &[]) {
-                continue;
-            }
-
+        // Iterate over all declared requirements that are relevant for the current platform.
+        let extras = source_extra.map(std::slice::from_ref).unwrap_or_default();
+        for requirement in overrides.apply_markers(requirements, env, extras) {
             // Add the package, plus any extra variants.
             for result in std::iter::once(to_pubgrub(requirement, None, urls, locals)).chain(
                 requirement
@@ -67,12 +59,8 @@ impl PubGrubDependencies {
 
                 // If the requirement was constrained, add those constraints.
                 for constraint in constraints.get(&requirement.name).into_iter().flatten() {
-                    // If the requirement isn't relevant for the current platform, skip it.
-                    if let Some(extra) = source_extra {
-                        if !constraint.evaluate_markers(env, std::slice::from_ref(extra)) {
-                            continue;
-                        }
-                    } else if !constraint.evaluate_markers(env, &[]) {
+                    // If the constraint isn't relevant for the current platform, skip it.
+                    if !constraint.evaluate_markers(env, extras) {
                         continue;
                     }
 
@@ -153,6 +141,21 @@ fn to_pubgrub(
                     .fold_ok(Range::full(), |range, specifier| {
                         range.intersection(&specifier.into())
                     })?
+            } else if let Some(local) = locals.preferred(&requirement.name) {
+                // If the user prefers a local variant, allow it to satisfy exact specifiers.
+                specifiers
+                    .iter()
+                    .map(|specifier| {
+                        let range = Range::from(PubGrubSpecifier::try_from(specifier)?);
+                        if let Some(variant) = Locals::preferred_variant(local, specifier) {
+                            Ok::<_, ResolveError>(range.union(&Range::singleton(variant)))
+                        } else {
+                            Ok(range)
+                        }
+                    })
+                    .fold_ok(Range::full(), |range, specifier| {
+                        range.intersection(&specifier)
+                    })?
             } else {
                 specifiers
                     .iter()
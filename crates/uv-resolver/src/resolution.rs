@@ -339,6 +339,16 @@ impl ResolutionGraph {
             .map(|node| node.weight)
     }
 
+    /// Return the hashes for the given package, if any.
+    pub fn hashes(&self, name: &PackageName) -> Option<&[HashDigest]> {
+        self.hashes.get(name).map(Vec::as_slice)
+    }
+
+    /// Replace the hashes for the given package.
+    pub fn set_hashes(&mut self, name: PackageName, hashes: Vec<HashDigest>) {
+        self.hashes.insert(name, hashes);
+    }
+
     /// Return the [`Diagnostic`]s that were encountered while building the graph.
     pub fn diagnostics(&self) -> &[Diagnostic] {
         &self.diagnostics
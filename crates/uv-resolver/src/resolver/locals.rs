@@ -5,21 +5,27 @@ use rustc_hash::FxHashMap;
 
 use distribution_filename::{SourceDistFilename, WheelFilename};
 use distribution_types::RemoteSource;
-use pep440_rs::{Operator, Version, VersionSpecifier, VersionSpecifierBuildError};
+use pep440_rs::{LocalSegment, Operator, Version, VersionSpecifier, VersionSpecifierBuildError};
 use pep508_rs::{MarkerEnvironment, VersionOrUrl};
 use uv_normalize::PackageName;
 
-use crate::Manifest;
+use crate::{Manifest, PreferLocal};
 
 #[derive(Debug, Default)]
 pub(crate) struct Locals {
     /// A map of package names to their associated, required local versions.
     required: FxHashMap<PackageName, Version>,
+    /// The preferred local version labels, as configured by the user.
+    preferred: PreferLocal,
 }
 
 impl Locals {
     /// Determine the set of permitted local versions in the [`Manifest`].
-    pub(crate) fn from_manifest(manifest: &Manifest, markers: &MarkerEnvironment) -> Self {
+    pub(crate) fn from_manifest(
+        manifest: &Manifest,
+        preferred: PreferLocal,
+        markers: &MarkerEnvironment,
+    ) -> Self {
         let mut required: FxHashMap<PackageName, Version> = FxHashMap::default();
 
         // Add all direct requirements and constraints. There's no need to look for conflicts,
@@ -32,7 +38,10 @@ impl Locals {
             }
         }
 
-        Self { required }
+        Self {
+            required,
+            preferred,
+        }
     }
 
     /// Return the local [`Version`] to which a package is pinned, if any.
@@ -40,6 +49,30 @@ impl Locals {
         self.required.get(package)
     }
 
+    /// Return the preferred local label for a package, if any.
+    pub(crate) fn preferred(&self, package: &PackageName) -> Option<&[LocalSegment]> {
+        self.preferred.get(package)
+    }
+
+    /// Given an exact specifier without a local segment, return the version of the preferred local
+    /// variant that should also satisfy the specifier, if any.
+    ///
+    /// For example, given `foo==1.0.0` and a preferred label of `+local`, returns `1.0.0+local`.
+    /// Unlike [`Locals::map`], the public version (`1.0.0`) remains permitted, since the package
+    /// may not publish any local variants at all.
+    pub(crate) fn preferred_variant(
+        local: &[LocalSegment],
+        specifier: &VersionSpecifier,
+    ) -> Option<Version> {
+        if local.is_empty()
+            || *specifier.operator() != Operator::Equal
+            || specifier.version().is_local()
+        {
+            return None;
+        }
+        Some(specifier.version().clone().with_local(local.to_vec()))
+    }
+
     /// Given a specifier that may include the version _without_ a local segment, return a specifier
     /// that includes the local segment from the expected version.
     pub(crate) fn map(
@@ -54,7 +54,7 @@ pub use crate::resolver::provider::{
 use crate::resolver::reporter::Facade;
 pub use crate::resolver::reporter::{BuildId, Reporter};
 use crate::yanks::AllowedYanks;
-use crate::{DependencyMode, Exclusions, FlatIndex, Options};
+use crate::{DependencyMode, Exclusions, FlatIndex, Options, PreferLocal};
 
 mod batch_prefetch;
 mod index;
@@ -133,6 +133,9 @@ pub struct Resolver<
     incomplete_packages: DashMap<PackageName, DashMap<Version, IncompletePackage>>,
     /// The set of all registry-based packages visited during resolution.
     visited: DashSet<PackageName>,
+    /// A channel on which to send the wheels for selected versions, to be downloaded
+    /// speculatively while the resolution is in progress.
+    prefetch: Option<futures::channel::mpsc::UnboundedSender<Dist>>,
     reporter: Option<Arc<dyn Reporter>>,
     provider: Provider,
 }
@@ -169,6 +172,7 @@ impl<
             AllowedYanks::from_manifest(&manifest, markers),
             hasher,
             options.exclude_newer,
+            options.minimum_release_age.clone(),
             build_context.no_binary(),
             build_context.no_build(),
         );
@@ -208,10 +212,11 @@ impl<
             unavailable_packages: DashMap::default(),
             incomplete_packages: DashMap::default(),
             visited: DashSet::default(),
-            selector: CandidateSelector::for_resolution(options, &manifest, markers),
+            selector: CandidateSelector::for_resolution(&options, &manifest, markers),
             dependency_mode: options.dependency_mode,
+            prefetch: None,
             urls: Urls::from_manifest(&manifest, markers)?,
-            locals: Locals::from_manifest(&manifest, markers),
+            locals: Locals::from_manifest(&manifest, options.prefer_local, markers),
             project: manifest.project,
             requirements: manifest.requirements,
             constraints: manifest.constraints,
@@ -239,6 +244,19 @@ impl<
         }
     }
 
+    /// Send the wheel for each selected registry version to the given channel, such that it can
+    /// be downloaded while the resolution is in progress.
+    ///
+    /// The resolver never waits on the receiver; the channel is closed once the resolution
+    /// completes.
+    #[must_use]
+    pub fn with_prefetch(self, prefetch: futures::channel::mpsc::UnboundedSender<Dist>) -> Self {
+        Self {
+            prefetch: Some(prefetch),
+            ..self
+        }
+    }
+
     /// Resolve a set of requirements into a set of pinned versions.
     pub async fn resolve(self) -> Result<ResolutionGraph, ResolveError> {
         // A channel to fetch package metadata (e.g., given `flask`, fetch all versions) and version
@@ -383,6 +401,14 @@ impl<
                                         "was found, but has an invalid format"
                                     }
                                 })
+                                .map(ToString::to_string)
+                                .or_else(|| {
+                                    // Check if the decision was due to the local version policy
+                                    self.missing_local_variant(
+                                        package_name,
+                                        term_intersection.unwrap_positive(),
+                                    )
+                                })
                         } else {
                             None
                         }
@@ -391,7 +417,7 @@ impl<
                     let inc = Incompatibility::no_versions(
                         next.clone(),
                         term_intersection.clone(),
-                        reason.map(ToString::to_string),
+                        reason,
                     );
 
                     state.add_incompatibility(inc);
@@ -800,6 +826,18 @@ impl<
                         ResolvedDistRef::Installed(dist) => Request::Installed(dist.clone()),
                     };
                     request_sink.send(request).await?;
+
+                    // Speculatively download the wheel for this version, on the assumption that
+                    // it'll be retained in the final resolution.
+                    if let Some(prefetch) = self.prefetch.as_ref() {
+                        if let ResolvedDistRef::Installable(
+                            dist @ Dist::Built(BuiltDist::Registry(_)),
+                        ) = dist.for_installation()
+                        {
+                            // If the receiver has exited, the wheel is fetched at install time.
+                            let _ = prefetch.unbounded_send(dist.clone());
+                        }
+                    }
                 }
                 Ok(Some(ResolverVersion::Available(version)))
             }
@@ -1240,6 +1278,27 @@ impl<
         }
     }
 
+    /// If the [`PreferLocal`] policy excluded every version of a package in the given range, return
+    /// the reason, for use in error messages.
+    fn missing_local_variant(
+        &self,
+        package_name: &PackageName,
+        range: &Range<Version>,
+    ) -> Option<String> {
+        let response = self.index.packages.get(package_name)?;
+        let VersionsResponse::Found(ref version_maps) = *response else {
+            return None;
+        };
+        let local = self
+            .selector
+            .missing_local_variant(package_name, range, version_maps)?;
+        if local.is_empty() {
+            Some("has no version without a local version label".to_string())
+        } else {
+            Some(format!("has no `{}` variant", PreferLocal::display(local)))
+        }
+    }
+
     fn on_progress(&self, package: &PubGrubPackage, version: &Version) {
         if let Some(reporter) = self.reporter.as_ref() {
             match package {
@@ -1,6 +1,7 @@
 use std::future::Future;
 
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 
 use distribution_types::{Dist, IndexLocations};
 use platform_tags::Tags;
@@ -9,12 +10,13 @@ use uv_configuration::{NoBinary, NoBuild};
 use uv_distribution::{ArchiveMetadata, DistributionDatabase};
 use uv_normalize::PackageName;
 use uv_types::{BuildContext, HashStrategy};
+use uv_warnings::warn_user_once;
 
 use crate::flat_index::FlatIndex;
 use crate::python_requirement::PythonRequirement;
 use crate::version_map::VersionMap;
 use crate::yanks::AllowedYanks;
-use crate::ExcludeNewer;
+use crate::{ExcludeNewer, MinimumReleaseAge};
 
 pub type PackageVersionsResult = Result<VersionsResponse, uv_client::Error>;
 pub type WheelMetadataResult = Result<MetadataResponse, uv_distribution::Error>;
@@ -84,6 +86,10 @@ pub struct DefaultResolverProvider<'a, Context: BuildContext + Send + Sync> {
     allowed_yanks: AllowedYanks,
     hasher: HashStrategy,
     exclude_newer: Option<ExcludeNewer>,
+    minimum_release_age: MinimumReleaseAge,
+    /// The time at which the resolution started (truncated to the hour), against which release ages
+    /// are measured.
+    now: DateTime<Utc>,
     no_binary: NoBinary,
     no_build: NoBuild,
 }
@@ -100,6 +106,7 @@ impl<'a, Context: BuildContext + Send + Sync> DefaultResolverProvider<'a, Contex
         allowed_yanks: AllowedYanks,
         hasher: &'a HashStrategy,
         exclude_newer: Option<ExcludeNewer>,
+        minimum_release_age: MinimumReleaseAge,
         no_binary: &'a NoBinary,
         no_build: &'a NoBuild,
     ) -> Self {
@@ -112,6 +119,8 @@ impl<'a, Context: BuildContext + Send + Sync> DefaultResolverProvider<'a, Contex
             allowed_yanks,
             hasher: hasher.clone(),
             exclude_newer,
+            minimum_release_age,
+            now: MinimumReleaseAge::now(),
             no_binary: no_binary.clone(),
             no_build: no_build.clone(),
         }
@@ -126,6 +135,20 @@ impl<'a, Context: BuildContext + Send + Sync> ResolverProvider
         &'io self,
         package_name: &'io PackageName,
     ) -> PackageVersionsResult {
+        let exclude_newer =
+            self.minimum_release_age
+                .exclude_newer(package_name, self.exclude_newer, self.now);
+
+        // Files from `--find-links` don't have upload dates, so the minimum release age can't be
+        // enforced for them.
+        if self.minimum_release_age.applies(package_name)
+            && self.flat_index.get(package_name).is_some()
+        {
+            warn_user_once!(
+                "`{package_name}` has files from `--find-links` without upload dates, which are not subject to the minimum release age"
+            );
+        }
+
         match self.client.simple(package_name).await {
             Ok(results) => Ok(VersionsResponse::Found(
                 results
@@ -139,7 +162,7 @@ impl<'a, Context: BuildContext + Send + Sync> ResolverProvider
                             &self.python_requirement,
                             &self.allowed_yanks,
                             &self.hasher,
-                            self.exclude_newer.as_ref(),
+                            exclude_newer.as_ref(),
                             self.flat_index.get(package_name).cloned(),
                             &self.no_binary,
                             &self.no_build,
@@ -369,7 +369,7 @@ impl VersionMapLazy {
                         }
                         None => {
                             warn_user_once!(
-                                "{} is missing an upload date, but user provided: {exclude_newer}",
+                                "{} is missing an upload date, so it's excluded by the cutoff from `--exclude-newer` or `--minimum-release-age`: {exclude_newer}",
                                 file.filename,
                             );
                             (true, None)
@@ -476,16 +476,7 @@ impl VersionMapLazy {
         let hash = if self.required_hashes.is_empty() {
             Hash::Matched
         } else {
-            if hashes.is_empty() {
-                Hash::Missing
-            } else if hashes
-                .iter()
-                .any(|hash| self.required_hashes.contains(hash))
-            {
-                Hash::Matched
-            } else {
-                Hash::Mismatched
-            }
+            Hash::from_digests(&self.required_hashes, hashes)
         };
 
         SourceDistCompatibility::Compatible(hash)
@@ -540,16 +531,7 @@ impl VersionMapLazy {
         let hash = if self.required_hashes.is_empty() {
             Hash::Matched
         } else {
-            if hashes.is_empty() {
-                Hash::Missing
-            } else if hashes
-                .iter()
-                .any(|hash| self.required_hashes.contains(hash))
-            {
-                Hash::Matched
-            } else {
-                Hash::Mismatched
-            }
+            Hash::from_digests(&self.required_hashes, hashes)
         };
 
         WheelCompatibility::Compatible(hash, priority)
@@ -15,7 +15,9 @@ use pep508_rs::{MarkerEnvironment, Requirement, StringVersion};
 use platform_tags::{Arch, Os, Platform, Tags};
 use uv_cache::Cache;
 use uv_client::RegistryClientBuilder;
-use uv_configuration::{BuildKind, Constraints, NoBinary, NoBuild, Overrides, SetupPyStrategy};
+use uv_configuration::{
+    BuildKind, ConfigSettings, Constraints, NoBinary, NoBuild, Overrides, SetupPyStrategy,
+};
 use uv_interpreter::{find_default_python, Interpreter, PythonEnvironment};
 use uv_resolver::{
     DisplayResolutionGraph, ExcludeNewer, Exclusions, FlatIndex, InMemoryIndex, Manifest, Options,
@@ -38,6 +40,7 @@ struct DummyContext {
     cache: Cache,
     interpreter: Interpreter,
     index_locations: IndexLocations,
+    config_settings: ConfigSettings,
 }
 
 impl DummyContext {
@@ -46,6 +49,7 @@ impl DummyContext {
             cache,
             interpreter,
             index_locations: IndexLocations::default(),
+            config_settings: ConfigSettings::default(),
         }
     }
 }
@@ -77,6 +81,10 @@ impl BuildContext for DummyContext {
         SetupPyStrategy::default()
     }
 
+    fn config_settings(&self) -> &ConfigSettings {
+        &self.config_settings
+    }
+
     fn index_locations(&self) -> &IndexLocations {
         &self.index_locations
     }
@@ -382,6 +390,86 @@ async fn black_flake8() -> Result<()> {
     Ok(())
 }
 
+/// Resolve `black` with an override on `mypy-extensions` that applies to the current Python version, to
+/// ensure that the override replaces the declared requirement.
+#[tokio::test]
+async fn black_mypy_extensions_override_marker() -> Result<()> {
+    let manifest = Manifest::new(
+        vec![Requirement::from_str("black<=23.9.1").unwrap()],
+        Constraints::default(),
+        Overrides::from_requirements(vec![Requirement::from_str(
+            "mypy-extensions<0.4.4 ; python_version >= \"3.11\"",
+        )
+        .unwrap()]),
+        vec![],
+        None,
+        vec![],
+        Exclusions::default(),
+        vec![],
+    );
+    let options = OptionsBuilder::new()
+        .exclude_newer(Some(*EXCLUDE_NEWER))
+        .build();
+
+    let resolution = resolve(manifest, options, &MARKERS_311, &TAGS_311).await?;
+
+    assert_snapshot!(DisplayResolutionGraph::from(&resolution), @r###"
+    black==23.9.1
+    click==8.1.7
+        # via black
+    mypy-extensions==0.4.3
+        # via black
+    packaging==23.2
+        # via black
+    pathspec==0.11.2
+        # via black
+    platformdirs==4.0.0
+        # via black
+    "###);
+
+    Ok(())
+}
+
+/// Resolve `black` with an override on `mypy-extensions` that only applies to other Python versions, to
+/// ensure that the declared requirement is retained.
+#[tokio::test]
+async fn black_mypy_extensions_override_inactive_marker() -> Result<()> {
+    let manifest = Manifest::new(
+        vec![Requirement::from_str("black<=23.9.1").unwrap()],
+        Constraints::default(),
+        Overrides::from_requirements(vec![Requirement::from_str(
+            "mypy-extensions<0.4.4 ; python_version < \"3.11\"",
+        )
+        .unwrap()]),
+        vec![],
+        None,
+        vec![],
+        Exclusions::default(),
+        vec![],
+    );
+    let options = OptionsBuilder::new()
+        .exclude_newer(Some(*EXCLUDE_NEWER))
+        .build();
+
+    let resolution = resolve(manifest, options, &MARKERS_311, &TAGS_311).await?;
+
+    assert_snapshot!(DisplayResolutionGraph::from(&resolution), @r###"
+    black==23.9.1
+    click==8.1.7
+        # via black
+    mypy-extensions==1.0.0
+        # via black
+    packaging==23.2
+        # via black
+    pathspec==0.11.2
+        # via black
+    platformdirs==4.0.0
+        # via black
+    "###);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn black_lowest() -> Result<()> {
     let manifest = Manifest::simple(vec![Requirement::from_str("black>21").unwrap()]);
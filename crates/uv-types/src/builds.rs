@@ -1,15 +1,41 @@
 use uv_interpreter::PythonEnvironment;
+use uv_normalize::PackageName;
 
 /// Whether to enforce build isolation when building source distributions.
 #[derive(Debug, Copy, Clone)]
 pub enum BuildIsolation<'a> {
     Isolated,
     Shared(&'a PythonEnvironment),
+    SharedPackage(&'a PythonEnvironment, &'a [PackageName]),
 }
 
 impl<'a> BuildIsolation<'a> {
-    /// Returns `true` if build isolation is enforced.
-    pub fn is_isolated(&self) -> bool {
-        matches!(self, Self::Isolated)
+    /// Returns `true` if build isolation is enforced for the given package name.
+    ///
+    /// Packages without a known name are always built in isolation, unless isolation is disabled
+    /// entirely.
+    pub fn is_isolated(&self, package: Option<&PackageName>) -> bool {
+        match self {
+            Self::Isolated => true,
+            Self::Shared(_) => false,
+            Self::SharedPackage(_, packages) => {
+                package.map_or(true, |package| !packages.contains(package))
+            }
+        }
+    }
+
+    /// Returns the shared environment for the given package name, if build isolation is disabled
+    /// for that package.
+    pub fn shared_environment(
+        &self,
+        package: Option<&PackageName>,
+    ) -> Option<&'a PythonEnvironment> {
+        match *self {
+            Self::Isolated => None,
+            Self::Shared(venv) => Some(venv),
+            Self::SharedPackage(venv, packages) => package
+                .is_some_and(|package| packages.contains(package))
+                .then_some(venv),
+        }
     }
 }
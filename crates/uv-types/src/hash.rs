@@ -5,15 +5,15 @@ use url::Url;
 
 use distribution_types::{DistributionMetadata, HashPolicy, PackageId};
 use pep508_rs::{MarkerEnvironment, RequirementsTxtRequirement, VersionOrUrl};
-use pypi_types::{HashDigest, HashError};
+use pypi_types::{HashAlgorithm, HashDigest, HashError};
 use uv_normalize::PackageName;
 
 #[derive(Debug, Clone)]
 pub enum HashStrategy {
     /// No hash policy is specified.
     None,
-    /// Hashes should be generated (specifically, a SHA-256 hash), but not validated.
-    Generate,
+    /// Hashes should be generated (using the given algorithms), but not validated.
+    Generate(Vec<HashAlgorithm>),
     /// Hashes should be validated against a pre-defined list of hashes. If necessary, hashes should
     /// be generated so as to ensure that the archive is valid.
     Validate(FxHashMap<PackageId, Vec<HashDigest>>),
@@ -24,7 +24,7 @@ impl HashStrategy {
     pub fn get<T: DistributionMetadata>(&self, distribution: &T) -> HashPolicy {
         match self {
             Self::None => HashPolicy::None,
-            Self::Generate => HashPolicy::Generate,
+            Self::Generate(algorithms) => HashPolicy::Generate(algorithms),
             Self::Validate(hashes) => HashPolicy::Validate(
                 hashes
                     .get(&distribution.package_id())
@@ -38,7 +38,7 @@ impl HashStrategy {
     pub fn get_package(&self, name: &PackageName) -> HashPolicy {
         match self {
             Self::None => HashPolicy::None,
-            Self::Generate => HashPolicy::Generate,
+            Self::Generate(algorithms) => HashPolicy::Generate(algorithms),
             Self::Validate(hashes) => HashPolicy::Validate(
                 hashes
                     .get(&PackageId::from_registry(name.clone()))
@@ -52,7 +52,7 @@ impl HashStrategy {
     pub fn get_url(&self, url: &Url) -> HashPolicy {
         match self {
             Self::None => HashPolicy::None,
-            Self::Generate => HashPolicy::Generate,
+            Self::Generate(algorithms) => HashPolicy::Generate(algorithms),
             Self::Validate(hashes) => HashPolicy::Validate(
                 hashes
                     .get(&PackageId::from_url(url))
@@ -66,7 +66,7 @@ impl HashStrategy {
     pub fn allows_package(&self, name: &PackageName) -> bool {
         match self {
             Self::None => true,
-            Self::Generate => true,
+            Self::Generate(_) => true,
             Self::Validate(hashes) => hashes.contains_key(&PackageId::from_registry(name.clone())),
         }
     }
@@ -75,7 +75,7 @@ impl HashStrategy {
     pub fn allows_url(&self, url: &Url) -> bool {
         match self {
             Self::None => true,
-            Self::Generate => true,
+            Self::Generate(_) => true,
             Self::Validate(hashes) => hashes.contains_key(&PackageId::from_url(url)),
         }
     }
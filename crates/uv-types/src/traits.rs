@@ -10,7 +10,7 @@ use uv_cache::Cache;
 use uv_interpreter::{Interpreter, PythonEnvironment};
 
 use crate::BuildIsolation;
-use uv_configuration::{BuildKind, NoBinary, NoBuild, SetupPyStrategy};
+use uv_configuration::{BuildKind, ConfigSettings, NoBinary, NoBuild, SetupPyStrategy};
 
 ///  Avoids cyclic crate dependencies between resolver, installer and builder.
 ///
@@ -76,6 +76,9 @@ pub trait BuildContext: Sync {
     /// The strategy to use when building source distributions that lack a `pyproject.toml`.
     fn setup_py_strategy(&self) -> SetupPyStrategy;
 
+    /// The settings to pass to the PEP 517 build backend when building source distributions.
+    fn config_settings(&self) -> &ConfigSettings;
+
     /// Resolve the given requirements into a ready-to-install set of package versions.
     fn resolve<'a>(
         &'a self,
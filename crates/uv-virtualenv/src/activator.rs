@@ -0,0 +1,57 @@
+/// A shell (or other environment) for which an activation script can be generated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum Activator {
+    /// POSIX-compatible shells, like bash and zsh (`activate`).
+    Bash,
+    /// C shell (`activate.csh`).
+    Csh,
+    /// Fish (`activate.fish`).
+    Fish,
+    /// Nushell (`activate.nu`).
+    Nushell,
+    /// PowerShell (`activate.ps1`).
+    Powershell,
+    /// Command Prompt (`activate.bat`, `deactivate.bat`, and `pydoc.bat`).
+    Cmd,
+    /// Xonsh (`activate.xsh`).
+    Xonsh,
+    /// Activation from within a running Python interpreter (`activate_this.py`).
+    Python,
+}
+
+impl Activator {
+    /// All supported activators.
+    pub const ALL: &'static [Self] = &[
+        Self::Bash,
+        Self::Csh,
+        Self::Fish,
+        Self::Nushell,
+        Self::Powershell,
+        Self::Cmd,
+        Self::Xonsh,
+        Self::Python,
+    ];
+
+    /// The activation script templates for the activator, as pairs of file name and contents,
+    /// with the environment-dependent paths patched out.
+    pub(crate) fn templates(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Self::Bash => &[("activate", include_str!("activator/activate"))],
+            Self::Csh => &[("activate.csh", include_str!("activator/activate.csh"))],
+            Self::Fish => &[("activate.fish", include_str!("activator/activate.fish"))],
+            Self::Nushell => &[("activate.nu", include_str!("activator/activate.nu"))],
+            Self::Powershell => &[("activate.ps1", include_str!("activator/activate.ps1"))],
+            Self::Cmd => &[
+                ("activate.bat", include_str!("activator/activate.bat")),
+                ("deactivate.bat", include_str!("activator/deactivate.bat")),
+                ("pydoc.bat", include_str!("activator/pydoc.bat")),
+            ],
+            Self::Xonsh => &[("activate.xsh", include_str!("activator/activate.xsh"))],
+            Self::Python => &[(
+                "activate_this.py",
+                include_str!("activator/activate_this.py"),
+            )],
+        }
+    }
+}
@@ -1,6 +1,5 @@
 //! Create a bare virtualenv without any packages install
 
-use std::env;
 use std::env::consts::EXE_SUFFIX;
 use std::io;
 use std::io::{BufWriter, Write};
@@ -14,27 +13,12 @@ use tracing::info;
 use uv_fs::Simplified;
 use uv_interpreter::{Interpreter, Virtualenv};
 
-use crate::{Error, Prompt};
+use crate::{Activator, Error, Prompt};
 
-/// The bash activate scripts with the venv dependent paths patches out
-const ACTIVATE_TEMPLATES: &[(&str, &str)] = &[
-    ("activate", include_str!("activator/activate")),
-    ("activate.csh", include_str!("activator/activate.csh")),
-    ("activate.fish", include_str!("activator/activate.fish")),
-    ("activate.nu", include_str!("activator/activate.nu")),
-    ("activate.ps1", include_str!("activator/activate.ps1")),
-    ("activate.bat", include_str!("activator/activate.bat")),
-    ("deactivate.bat", include_str!("activator/deactivate.bat")),
-    ("pydoc.bat", include_str!("activator/pydoc.bat")),
-    (
-        "activate_this.py",
-        include_str!("activator/activate_this.py"),
-    ),
-];
 const VIRTUALENV_PATCH: &str = include_str!("_virtualenv.py");
 
 /// Very basic `.cfg` file format writer.
-fn write_cfg(f: &mut impl Write, data: &[(String, String)]) -> io::Result<()> {
+pub(crate) fn write_cfg(f: &mut impl Write, data: &[(String, String)]) -> io::Result<()> {
     for (key, value) in data {
         writeln!(f, "{key} = {value}")?;
     }
@@ -47,6 +31,7 @@ pub fn create_bare_venv(
     interpreter: &Interpreter,
     prompt: Prompt,
     system_site_packages: bool,
+    activators: &[Activator],
     extra_cfg: Vec<(String, String)>,
 ) -> Result<Virtualenv, Error> {
     // Determine the base Python executable; that is, the Python executable that should be
@@ -80,6 +65,11 @@ pub fn create_bare_venv(
         unimplemented!("Only Windows and Unix are supported")
     };
 
+    // On Windows, use an extended-length path, such that deeply nested environments don't exceed
+    // `MAX_PATH` during creation.
+    let location = uv_fs::verbatim_path(&uv_fs::absolutize_path(location)?).into_owned();
+    let location = location.as_path();
+
     // Validate the existing location.
     match location.metadata() {
         Ok(metadata) => {
@@ -117,21 +107,8 @@ pub fn create_bare_venv(
 
     let location = location.canonicalize()?;
 
-    let bin_name = if cfg!(unix) {
-        "bin"
-    } else if cfg!(windows) {
-        "Scripts"
-    } else {
-        unimplemented!("Only Windows and Unix are supported")
-    };
     let scripts = location.join(&interpreter.virtualenv().scripts);
-    let prompt = match prompt {
-        Prompt::CurrentDirectoryName => env::current_dir()?
-            .file_name()
-            .map(|name| name.to_string_lossy().to_string()),
-        Prompt::Static(value) => Some(value),
-        Prompt::None => None,
-    };
+    let prompt = prompt.resolve()?;
 
     // Add the CACHEDIR.TAG.
     cachedir::ensure_tag(&location)?;
@@ -218,29 +195,7 @@ pub fn create_bare_venv(
     }
 
     // Add all the activate scripts for different shells
-    for (name, template) in ACTIVATE_TEMPLATES {
-        let relative_site_packages = pathdiff::diff_paths(
-            &interpreter.virtualenv().purelib,
-            &interpreter.virtualenv().scripts,
-        )
-        .expect("Failed to calculate relative path to site-packages");
-        let activator = template
-            .replace(
-                "{{ VIRTUAL_ENV_DIR }}",
-                // SAFETY: `unwrap` is guaranteed to succeed because `location` is an `Utf8PathBuf`.
-                location.simplified().to_str().unwrap(),
-            )
-            .replace("{{ BIN_NAME }}", bin_name)
-            .replace(
-                "{{ VIRTUAL_PROMPT }}",
-                prompt.as_deref().unwrap_or_default(),
-            )
-            .replace(
-                "{{ RELATIVE_SITE_PACKAGES }}",
-                relative_site_packages.simplified().to_str().unwrap(),
-            );
-        fs::write(scripts.join(name), activator)?;
-    }
+    write_activators(&location, interpreter, prompt.as_deref(), activators)?;
 
     // Per PEP 405, the Python `home` is the parent directory of the interpreter.
     let python_home = base_python
@@ -322,3 +277,47 @@ pub fn create_bare_venv(
         executable,
     })
 }
+
+/// Write the activation scripts for the given shells into the `bin` (or `Scripts`) directory of
+/// the virtual environment at `location`.
+pub(crate) fn write_activators(
+    location: &Path,
+    interpreter: &Interpreter,
+    prompt: Option<&str>,
+    activators: &[Activator],
+) -> io::Result<()> {
+    let bin_name = if cfg!(unix) {
+        "bin"
+    } else if cfg!(windows) {
+        "Scripts"
+    } else {
+        unimplemented!("Only Windows and Unix are supported")
+    };
+    let scripts = location.join(&interpreter.virtualenv().scripts);
+
+    for (name, template) in activators
+        .iter()
+        .flat_map(|activator| activator.templates())
+    {
+        let relative_site_packages = pathdiff::diff_paths(
+            &interpreter.virtualenv().purelib,
+            &interpreter.virtualenv().scripts,
+        )
+        .expect("Failed to calculate relative path to site-packages");
+        let activator = template
+            .replace(
+                "{{ VIRTUAL_ENV_DIR }}",
+                // SAFETY: `unwrap` is guaranteed to succeed because `location` is an `Utf8PathBuf`.
+                location.simplified().to_str().unwrap(),
+            )
+            .replace("{{ BIN_NAME }}", bin_name)
+            .replace("{{ VIRTUAL_PROMPT }}", prompt.unwrap_or_default())
+            .replace(
+                "{{ RELATIVE_SITE_PACKAGES }}",
+                relative_site_packages.simplified().to_str().unwrap(),
+            );
+        fs::write(scripts.join(name), activator)?;
+    }
+
+    Ok(())
+}
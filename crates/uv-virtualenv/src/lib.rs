@@ -1,13 +1,17 @@
 use std::io;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use platform_tags::PlatformError;
 use thiserror::Error;
+use tracing::debug;
 
+use uv_fs::Simplified;
 use uv_interpreter::{Interpreter, PythonEnvironment};
 
+pub use crate::activator::Activator;
 pub use crate::bare::create_bare_venv;
 
+mod activator;
 mod bare;
 
 #[derive(Debug, Error)]
@@ -20,6 +24,10 @@ pub enum Error {
     Platform(#[from] PlatformError),
     #[error("Reserved key used for pyvenv.cfg: {0}")]
     ReservedConfigKey(String),
+    #[error("Expected a file to add to `site-packages`, but found: {}", _0.user_display())]
+    InvalidSitePackagesExtra(PathBuf),
+    #[error("Reserved file name used for `site-packages`: {}", _0.user_display())]
+    ReservedSitePackagesExtra(PathBuf),
 }
 
 /// The value to use for the shell prompt when inside a virtual environment.
@@ -43,6 +51,17 @@ impl Prompt {
             None => Self::None,
         }
     }
+
+    /// Resolve the prompt to a fixed string, if any.
+    pub(crate) fn resolve(self) -> io::Result<Option<String>> {
+        match self {
+            Self::CurrentDirectoryName => Ok(std::env::current_dir()?
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())),
+            Self::Static(value) => Ok(Some(value)),
+            Self::None => Ok(None),
+        }
+    }
 }
 
 /// Create a virtualenv.
@@ -51,6 +70,7 @@ pub fn create_venv(
     interpreter: Interpreter,
     prompt: Prompt,
     system_site_packages: bool,
+    activators: &[Activator],
     extra_cfg: Vec<(String, String)>,
 ) -> Result<PythonEnvironment, Error> {
     // Create the virtualenv at the given location.
@@ -59,6 +79,7 @@ pub fn create_venv(
         &interpreter,
         prompt,
         system_site_packages,
+        activators,
         extra_cfg,
     )?;
 
@@ -66,3 +87,114 @@ pub fn create_venv(
     let interpreter = interpreter.with_virtualenv(virtualenv);
     Ok(PythonEnvironment::from_interpreter(interpreter))
 }
+
+/// Update the settings of an existing virtual environment in-place, preserving any installed
+/// packages.
+///
+/// Rewrites the `include-system-site-packages` and `prompt` keys of the `pyvenv.cfg` (retaining
+/// any other keys), and regenerates the activation scripts to reflect the prompt. Settings that
+/// aren't provided (i.e., `None` or [`Prompt::None`]) are left unchanged.
+pub fn update_venv(
+    venv: &PythonEnvironment,
+    prompt: Prompt,
+    system_site_packages: Option<bool>,
+) -> Result<(), Error> {
+    let path = venv.root().join("pyvenv.cfg");
+
+    // Like `PyVenvConfiguration::parse`, partition each line on the first equals sign.
+    let mut cfg = fs_err::read_to_string(&path)?
+        .lines()
+        .filter_map(|line| {
+            let (key, value) = line.split_once('=')?;
+            Some((key.trim().to_string(), value.trim().to_string()))
+        })
+        .collect::<Vec<_>>();
+
+    let mut set = |key: &str, value: String| {
+        if let Some(entry) = cfg.iter_mut().find(|(existing, _)| existing == key) {
+            entry.1 = value;
+        } else {
+            cfg.push((key.to_string(), value));
+        }
+    };
+    if let Some(system_site_packages) = system_site_packages {
+        set(
+            "include-system-site-packages",
+            system_site_packages.to_string(),
+        );
+    }
+    if let Some(prompt) = prompt.resolve()? {
+        set("prompt", prompt);
+    }
+
+    let mut contents = Vec::new();
+    bare::write_cfg(&mut contents, &cfg)?;
+    fs_err::write(&path, contents)?;
+
+    // Regenerate the activation scripts, which embed the prompt, retaining the existing set of
+    // activators.
+    let prompt = cfg
+        .iter()
+        .find(|(key, _)| key == "prompt")
+        .map(|(_, value)| value.as_str());
+    let activators = Activator::ALL
+        .iter()
+        .copied()
+        .filter(|activator| {
+            activator
+                .templates()
+                .iter()
+                .all(|(name, _)| venv.scripts().join(name).is_file())
+        })
+        .collect::<Vec<_>>();
+    bare::write_activators(venv.root(), venv.interpreter(), prompt, &activators)?;
+
+    Ok(())
+}
+
+/// Mark a virtual environment as externally managed, optionally with an error message to display
+/// when refusing to modify it.
+///
+/// Per PEP 668, virtual environments are never externally managed by virtue of their base
+/// interpreter. This allows distributors that create environments with uv, but manage their
+/// contents with another tool, to opt in regardless: uv will refuse to modify the environment
+/// unless `--break-system-packages` is provided.
+///
+/// The marker is an `EXTERNALLY-MANAGED` file at the root of the environment, in the same format
+/// as the `EXTERNALLY-MANAGED` file of a Python installation.
+pub fn mark_externally_managed(location: &Path, error: Option<&str>) -> Result<(), Error> {
+    let mut contents = String::from("[externally-managed]\n");
+    if let Some(error) = error {
+        // Indent any continuation lines, to preserve multi-line messages.
+        contents.push_str("Error=");
+        contents.push_str(&error.lines().collect::<Vec<_>>().join("\n "));
+        contents.push('\n');
+    }
+    fs_err::write(location.join("EXTERNALLY-MANAGED"), contents)?;
+    Ok(())
+}
+
+/// Copy extra files (e.g., a `sitecustomize.py`, or `.pth` files) into the `site-packages`
+/// directory of a virtual environment, such that they take effect on interpreter startup.
+///
+/// Existing files with the same name are overwritten; however, the `_virtualenv` patch can't be
+/// replaced, as it's required for the environment to function.
+pub fn add_site_packages_extras(venv: &PythonEnvironment, extras: &[PathBuf]) -> Result<(), Error> {
+    let site_packages = venv.interpreter().purelib();
+    for extra in extras {
+        let file_name = extra
+            .file_name()
+            .filter(|_| extra.is_file())
+            .ok_or_else(|| Error::InvalidSitePackagesExtra(extra.clone()))?;
+        if file_name.to_string_lossy().starts_with("_virtualenv.") {
+            return Err(Error::ReservedSitePackagesExtra(extra.clone()));
+        }
+        debug!(
+            "Adding {} to: {}",
+            extra.user_display(),
+            site_packages.user_display()
+        );
+        fs_err::copy(extra, site_packages.join(file_name))?;
+    }
+    Ok(())
+}
@@ -13,7 +13,7 @@ use tracing_subscriber::{fmt, EnvFilter};
 
 use uv_cache::Cache;
 use uv_interpreter::{find_default_python, find_requested_python};
-use uv_virtualenv::{create_bare_venv, Prompt};
+use uv_virtualenv::{create_bare_venv, Activator, Prompt};
 
 #[derive(Parser, Debug)]
 struct Cli {
@@ -46,6 +46,7 @@ fn run() -> Result<(), uv_virtualenv::Error> {
         &interpreter,
         Prompt::from_args(cli.prompt),
         cli.system_site_packages,
+        Activator::ALL,
         Vec::new(),
     )?;
     Ok(())
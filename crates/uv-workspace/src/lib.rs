@@ -1,5 +1,7 @@
+pub use crate::pip_config::*;
 pub use crate::settings::*;
 pub use crate::workspace::*;
 
+mod pip_config;
 mod settings;
 mod workspace;
@@ -0,0 +1,347 @@
+//! Compatibility with `pip`'s configuration, i.e., `pip.conf` (or `pip.ini`) files and `PIP_*`
+//! environment variables.
+//!
+//! Settings are read following `pip`'s precedence rules: global configuration files, then user
+//! configuration files, then the configuration file of the active virtual environment, then the
+//! file referenced by `PIP_CONFIG_FILE`, and finally `PIP_*` environment variables. Within the
+//! configuration files, the `[install]` section takes precedence over the `[global]` section.
+//!
+//! See: <https://pip.pypa.io/en/stable/topics/configuration/>
+
+use std::collections::BTreeMap;
+use std::env;
+use std::path::{Path, PathBuf};
+
+use configparser::ini::Ini;
+use uv_fs::Simplified;
+
+/// The `pip` settings that uv understands, along with the environment variable that uv reads for
+/// the equivalent setting.
+const SUPPORTED: &[(&str, &str)] = &[
+    ("index-url", "UV_INDEX_URL"),
+    ("extra-index-url", "UV_EXTRA_INDEX_URL"),
+    ("keyring-provider", "UV_KEYRING_PROVIDER"),
+    ("cache-dir", "UV_CACHE_DIR"),
+    ("no-cache-dir", "UV_NO_CACHE"),
+    ("cert", "SSL_CERT_FILE"),
+    ("timeout", "UV_HTTP_TIMEOUT"),
+    ("default-timeout", "UV_HTTP_TIMEOUT"),
+    ("pre", "UV_PRERELEASE"),
+];
+
+/// A setting read from `pip`'s configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PipSetting {
+    /// The name of the `pip` setting, e.g., `index-url`.
+    pub name: String,
+    /// The raw value of the setting.
+    pub value: String,
+    /// Where the setting was read from, e.g., `/etc/pip.conf` or `PIP_INDEX_URL`.
+    pub source: String,
+}
+
+/// A `pip` setting, translated to the equivalent uv environment variable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportedSetting {
+    /// The original `pip` setting.
+    pub setting: PipSetting,
+    /// The environment variable that uv reads the equivalent setting from, e.g., `UV_INDEX_URL`.
+    pub env: &'static str,
+    /// The value of the environment variable.
+    pub value: String,
+}
+
+/// The settings read from `pip`'s configuration files and environment variables.
+#[derive(Debug, Clone, Default)]
+pub struct PipConfig {
+    /// The settings, keyed by name, after applying `pip`'s precedence rules.
+    settings: BTreeMap<String, PipSetting>,
+}
+
+impl PipConfig {
+    /// Read `pip`'s configuration from the standard configuration files and the environment.
+    pub fn load() -> Result<Self, PipConfigError> {
+        let mut files = Vec::new();
+
+        // Per `pip`, setting `PIP_CONFIG_FILE` to `os.devnull` disables all configuration files.
+        let config_file = env::var_os("PIP_CONFIG_FILE").map(PathBuf::from);
+        if config_file.as_deref() != Some(Path::new(DEVNULL)) {
+            files.extend(global_config_files());
+            files.extend(user_config_files());
+            if let Some(venv) = env::var_os("VIRTUAL_ENV").filter(|venv| !venv.is_empty()) {
+                files.push(PathBuf::from(venv).join(CONFIG_BASENAME));
+            }
+            files.extend(config_file);
+        }
+
+        let mut config = Self::default();
+        for file in files {
+            config.read_file(&file)?;
+        }
+        config.read_env(env::vars());
+        Ok(config)
+    }
+
+    /// Read a configuration file, if it exists, overriding any existing settings.
+    fn read_file(&mut self, path: &Path) -> Result<(), PipConfigError> {
+        let content = match fs_err::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+            Err(err) => return Err(err.into()),
+        };
+        self.read_str(&content, &path.user_display().to_string())
+            .map_err(|err| PipConfigError::Parse(path.user_display().to_string(), err))
+    }
+
+    /// Read the contents of a configuration file, overriding any existing settings.
+    fn read_str(&mut self, content: &str, source: &str) -> Result<(), String> {
+        let mut ini = Ini::new();
+        ini.set_multiline(true);
+        let sections = ini.read(content.to_string())?;
+
+        // Settings in the `[install]` section take precedence over those in `[global]`.
+        for section in ["global", "install"] {
+            let Some(section) = sections.get(section) else {
+                continue;
+            };
+            for (key, value) in section {
+                let Some(value) = value else {
+                    continue;
+                };
+                let name = key.replace('_', "-");
+                self.settings.insert(
+                    name.clone(),
+                    PipSetting {
+                        name,
+                        value: value.clone(),
+                        source: source.to_string(),
+                    },
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Read the `PIP_*` environment variables, overriding any existing settings.
+    fn read_env(&mut self, vars: impl IntoIterator<Item = (String, String)>) {
+        for (key, value) in vars {
+            let Some(name) = key.strip_prefix("PIP_") else {
+                continue;
+            };
+            let name = name.to_lowercase().replace('_', "-");
+            if matches!(name.as_str(), "version" | "help" | "config-file") {
+                continue;
+            }
+            self.settings.insert(
+                name.clone(),
+                PipSetting {
+                    name,
+                    value,
+                    source: key,
+                },
+            );
+        }
+    }
+
+    /// Translate the settings into the equivalent uv environment variables.
+    ///
+    /// Returns the settings that could be translated, along with those that uv doesn't support.
+    pub fn translate(&self) -> (Vec<ImportedSetting>, Vec<PipSetting>) {
+        let mut imported: Vec<ImportedSetting> = Vec::new();
+        let mut unsupported = Vec::new();
+        for setting in self.settings.values() {
+            let translated = SUPPORTED
+                .iter()
+                .find(|(name, _)| *name == setting.name)
+                .and_then(|(_, env)| Some((*env, translate(setting)?)));
+            match translated {
+                Some((env, value)) => {
+                    // `timeout` and `default-timeout` are aliases; `pip` prefers the former.
+                    if imported.iter().any(|imported| imported.env == env) {
+                        if setting.name == "default-timeout" {
+                            continue;
+                        }
+                        imported.retain(|imported| imported.env != env);
+                    }
+                    imported.push(ImportedSetting {
+                        setting: setting.clone(),
+                        env,
+                        value,
+                    });
+                }
+                None => unsupported.push(setting.clone()),
+            }
+        }
+        (imported, unsupported)
+    }
+}
+
+/// Translate a supported `pip` setting into the value of the equivalent uv environment variable,
+/// or return `None` if the value isn't supported.
+fn translate(setting: &PipSetting) -> Option<String> {
+    let value = setting.value.trim();
+    match setting.name.as_str() {
+        "index-url" => Some(value.to_string()),
+        // `pip` accepts whitespace- (including newline-) separated URLs.
+        "extra-index-url" => Some(value.split_whitespace().collect::<Vec<_>>().join(" ")),
+        // uv doesn't support the `auto` and `import` providers.
+        "keyring-provider" => matches!(value, "disabled" | "subprocess").then(|| value.to_string()),
+        "cache-dir" | "cert" => Some(expand_user(value)),
+        "no-cache-dir" => parse_bool(value)?.then(|| "1".to_string()),
+        "timeout" | "default-timeout" => {
+            let timeout = value.parse::<f64>().ok()?;
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            (timeout >= 0.0).then(|| (timeout.ceil() as u64).to_string())
+        }
+        "pre" => parse_bool(value)?.then(|| "allow".to_string()),
+        _ => None,
+    }
+}
+
+/// Parse a boolean, as in Python's `strtobool`.
+fn parse_bool(value: &str) -> Option<bool> {
+    match value.to_lowercase().as_str() {
+        "y" | "yes" | "t" | "true" | "on" | "1" => Some(true),
+        "n" | "no" | "f" | "false" | "off" | "0" => Some(false),
+        _ => None,
+    }
+}
+
+/// Expand a leading `~` to the user's home directory, as in Python's `os.path.expanduser`.
+fn expand_user(value: &str) -> String {
+    let rest = if value == "~" {
+        ""
+    } else if let Some(rest) = value
+        .strip_prefix("~/")
+        .or_else(|| value.strip_prefix("~\\"))
+    {
+        rest
+    } else {
+        return value.to_string();
+    };
+    match directories::BaseDirs::new() {
+        Some(dirs) => dirs.home_dir().join(rest).display().to_string(),
+        None => value.to_string(),
+    }
+}
+
+#[cfg(windows)]
+const CONFIG_BASENAME: &str = "pip.ini";
+#[cfg(not(windows))]
+const CONFIG_BASENAME: &str = "pip.conf";
+
+#[cfg(windows)]
+const DEVNULL: &str = "nul";
+#[cfg(not(windows))]
+const DEVNULL: &str = "/dev/null";
+
+/// The global (i.e., system-wide) `pip` configuration files, in increasing order of precedence.
+fn global_config_files() -> Vec<PathBuf> {
+    if cfg!(windows) {
+        env::var_os("PROGRAMDATA")
+            .map(|dir| PathBuf::from(dir).join("pip").join(CONFIG_BASENAME))
+            .into_iter()
+            .collect()
+    } else if cfg!(target_os = "macos") {
+        vec![PathBuf::from("/Library/Application Support/pip").join(CONFIG_BASENAME)]
+    } else {
+        // `pip` reads `$XDG_CONFIG_DIRS/pip/pip.conf`, in reverse order, followed by
+        // `/etc/pip.conf`.
+        let xdg_config_dirs = env::var("XDG_CONFIG_DIRS")
+            .ok()
+            .filter(|dirs| !dirs.is_empty())
+            .unwrap_or_else(|| "/etc/xdg".to_string());
+        let mut files: Vec<PathBuf> = xdg_config_dirs
+            .split(':')
+            .map(|dir| Path::new(dir).join("pip").join(CONFIG_BASENAME))
+            .collect();
+        files.reverse();
+        files.push(PathBuf::from("/etc").join(CONFIG_BASENAME));
+        files
+    }
+}
+
+/// The user-level `pip` configuration files, in increasing order of precedence.
+fn user_config_files() -> Vec<PathBuf> {
+    let Some(dirs) = directories::BaseDirs::new() else {
+        return Vec::new();
+    };
+    let legacy = if cfg!(windows) {
+        dirs.home_dir().join("pip").join(CONFIG_BASENAME)
+    } else {
+        dirs.home_dir().join(".pip").join(CONFIG_BASENAME)
+    };
+    vec![legacy, dirs.config_dir().join("pip").join(CONFIG_BASENAME)]
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum PipConfigError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error("Failed to parse `{0}`: {1}")]
+    Parse(String, String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn precedence() {
+        let mut config = PipConfig::default();
+        config
+            .read_str(
+                "[global]\nindex-url = https://global.example.com/simple\ntimeout = 2.5\n\n[install]\nindex_url = https://install.example.com/simple\n",
+                "/etc/pip.conf",
+            )
+            .unwrap();
+        config
+            .read_str(
+                "[global]\nextra-index-url =\n    https://a.example.com/simple\n    https://b.example.com/simple\ntrusted-host = example.com\n",
+                "~/.config/pip/pip.conf",
+            )
+            .unwrap();
+        config.read_env([
+            ("PIP_NO_CACHE_DIR".to_string(), "yes".to_string()),
+            ("PIP_KEYRING_PROVIDER".to_string(), "auto".to_string()),
+            ("UV_INDEX_URL".to_string(), "ignored".to_string()),
+        ]);
+
+        let (imported, unsupported) = config.translate();
+        let imported = imported
+            .iter()
+            .map(|setting| {
+                (
+                    setting.env,
+                    setting.value.as_str(),
+                    setting.setting.source.as_str(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            imported,
+            vec![
+                (
+                    "UV_EXTRA_INDEX_URL",
+                    "https://a.example.com/simple https://b.example.com/simple",
+                    "~/.config/pip/pip.conf"
+                ),
+                (
+                    "UV_INDEX_URL",
+                    "https://install.example.com/simple",
+                    "/etc/pip.conf"
+                ),
+                ("UV_NO_CACHE", "1", "PIP_NO_CACHE_DIR"),
+                ("UV_HTTP_TIMEOUT", "3", "/etc/pip.conf"),
+            ]
+        );
+
+        let unsupported = unsupported
+            .iter()
+            .map(|setting| setting.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(unsupported, vec!["keyring-provider", "trusted-host"]);
+    }
+}
@@ -1,12 +1,24 @@
+use std::collections::BTreeMap;
+use std::net::IpAddr;
 use std::path::PathBuf;
 
 use serde::Deserialize;
 
-use distribution_types::{FlatIndexLocation, IndexUrl};
+use distribution_types::{
+    Accelerator, AcceleratorIndex, FlatIndexLocation, IndexSettings, IndexUrl,
+};
 use install_wheel_rs::linker::LinkMode;
-use uv_configuration::{ConfigSettings, IndexStrategy, KeyringProviderType, PackageNameSpecifier};
+use pep508_rs::Requirement;
+use platform_tags::TargetLibc;
+use uv_cache::{CacheScope, CacheSize};
+use uv_configuration::{
+    ConfigSettings, IndexStrategy, IpStrategy, KeyringProviderType, PackageNameSpecifier,
+    RetryClass, ScriptConflictStrategy, TagPolicy, UpdateChannel,
+};
 use uv_normalize::PackageName;
-use uv_resolver::{AnnotationStyle, ExcludeNewer, PreReleaseMode, ResolutionMode};
+use uv_resolver::{
+    AnnotationStyle, ExcludeNewer, LocalPreference, PreReleaseMode, ReleaseAge, ResolutionMode,
+};
 use uv_toolchain::PythonVersion;
 
 /// A `pyproject.toml` with an (optional) `[tool.uv]` section.
@@ -33,30 +45,118 @@ pub struct Options {
     pub native_tls: Option<bool>,
     pub no_cache: bool,
     pub cache_dir: Option<PathBuf>,
+    pub cache_max_size: Option<CacheSize>,
+    pub cache_scope: Option<CacheScope>,
+    pub update_channel: Option<UpdateChannel>,
+    /// Project-scoped netrc files to read credentials from, relative to the workspace root.
+    ///
+    /// Earlier files take precedence, and all take precedence over the user's netrc file.
+    pub netrc_files: Option<Vec<PathBuf>>,
+    pub dns: Option<DnsOptions>,
+    pub override_dependencies: Option<Vec<Requirement>>,
+    pub proxy: Option<ProxyOptions>,
+    pub retry: Option<RetryOptions>,
+    pub venv: Option<VenvOptions>,
     pub pip: Option<PipOptions>,
 }
 
+/// A `[tool.uv.dns]` section.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct DnsOptions {
+    /// The strategy for choosing between IPv4 and IPv6 addresses (e.g., `prefer-ipv4`).
+    pub ip_strategy: Option<IpStrategy>,
+    /// The nameservers to resolve hostnames with, rather than the system resolver (e.g.,
+    /// `10.0.0.2` or `10.0.0.2:5353`).
+    pub nameservers: Option<Vec<String>>,
+    /// Fixed addresses for specific hosts, like entries in `/etc/hosts`.
+    pub hosts: Option<BTreeMap<String, IpAddr>>,
+}
+
+/// A `[tool.uv.proxy]` section.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct ProxyOptions {
+    /// Hosts to connect to directly, bypassing any proxy (e.g., `localhost` or
+    /// `.corp.example.com`).
+    pub no_proxy: Option<Vec<String>>,
+    /// The URL (or path) of a proxy auto-config (PAC) script with which to select proxies.
+    pub pac_url: Option<String>,
+}
+
+/// A `[tool.uv.retry]` section.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct RetryOptions {
+    /// The maximum number of retries for a single request or download.
+    pub retries: Option<u32>,
+    /// The minimum delay before a retry, in milliseconds.
+    pub min_backoff_ms: Option<u64>,
+    /// The maximum delay before a retry, in milliseconds.
+    pub max_backoff_ms: Option<u64>,
+    /// The classes of failure that are retried (e.g., `connect`, `server-error`, or
+    /// `hash-mismatch`).
+    pub retryable: Option<Vec<RetryClass>>,
+}
+
+/// A `[tool.uv.venv]` section.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct VenvOptions {
+    /// Files (e.g., a `sitecustomize.py`, or `.pth` files) to copy into the `site-packages`
+    /// directory of each virtual environment created by `uv venv`, relative to the workspace
+    /// root.
+    pub site_packages_extras: Option<Vec<PathBuf>>,
+}
+
 /// A `[tool.uv.pip]` section.
 #[allow(dead_code)]
 #[derive(Debug, Clone, Default, Deserialize)]
 #[serde(deny_unknown_fields, rename_all = "kebab-case")]
 pub struct PipOptions {
     pub system: Option<bool>,
+    pub break_system_packages: Option<bool>,
     pub offline: Option<bool>,
     pub index_url: Option<IndexUrl>,
     pub extra_index_url: Option<IndexUrl>,
     pub no_index: Option<bool>,
     pub find_links: Option<Vec<FlatIndexLocation>>,
+    pub find_links_keys: Option<Vec<FindLinksKeys>>,
+    pub index_settings: Option<Vec<IndexSettings>>,
     pub index_strategy: Option<IndexStrategy>,
+    pub accelerator: Option<Accelerator>,
+    pub accelerator_index: Option<Vec<AcceleratorIndex>>,
     pub keyring_provider: Option<KeyringProviderType>,
     pub no_build: Option<bool>,
     pub no_binary: Option<Vec<PackageNameSpecifier>>,
     pub only_binary: Option<Vec<PackageNameSpecifier>>,
     pub no_build_isolation: Option<bool>,
+    pub no_build_isolation_package: Option<Vec<PackageName>>,
+    pub libc: Option<TargetLibc>,
+    pub force_manylinux: Option<bool>,
+    pub tag_policy: Option<TagPolicy>,
+    pub platform_preference: Option<Vec<String>>,
     pub resolver: Option<ResolverOptions>,
     pub installer: Option<InstallerOptions>,
 }
 
+/// A `[[tool.uv.pip.find-links-keys]]` entry.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct FindLinksKeys {
+    /// The `--find-links` directory or flat index URL whose distributions must be signed, as
+    /// passed to `--find-links`.
+    pub location: String,
+    /// The trusted minisign public keys, base64-encoded, as in the second line of a
+    /// `minisign.pub` file.
+    pub keys: Vec<String>,
+}
+
 /// A `[tool.uv.pip.resolver]` section.
 #[allow(dead_code)]
 #[derive(Debug, Clone, Default, Deserialize)]
@@ -64,6 +164,8 @@ pub struct PipOptions {
 pub struct ResolverOptions {
     pub resolution: Option<ResolutionMode>,
     pub prerelease: Option<PreReleaseMode>,
+    pub prefer_local: Option<Vec<LocalPreference>>,
+    pub minimum_release_age: Option<Vec<ReleaseAge>>,
     pub no_strip_extras: Option<bool>,
     pub no_annotate: Option<bool>,
     pub no_header: Option<bool>,
@@ -85,4 +187,8 @@ pub struct ResolverOptions {
 pub struct InstallerOptions {
     pub link_mode: Option<LinkMode>,
     pub compile_bytecode: Option<bool>,
+    pub script_conflicts: Option<ScriptConflictStrategy>,
+    /// A policy file against which to check the contents of each wheel before installing,
+    /// relative to the workspace root.
+    pub wheel_policy: Option<PathBuf>,
 }
@@ -42,6 +42,17 @@ impl Workspace {
         }
         Ok(None)
     }
+
+    /// The root directory of the workspace, i.e., the directory containing the `uv.toml` or
+    /// `pyproject.toml` file.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// The [`Options`] defined by the workspace.
+    pub fn options(&self) -> &Options {
+        &self.options
+    }
 }
 
 /// Read a `uv.toml` or `pyproject.toml` file in the given directory.
@@ -59,7 +70,7 @@ fn read_options(dir: &Path) -> Result<Option<Options>, WorkspaceError> {
     }
 
     // Read a `pyproject.toml` file in the current directory.
-    let path = path.join("pyproject.toml");
+    let path = dir.join("pyproject.toml");
     match fs_err::read_to_string(&path) {
         Ok(content) => {
             // Parse, but skip any `pyproject.toml` that doesn't have a `[tool.uv]` section.
@@ -0,0 +1,129 @@
+use std::env;
+use std::path::PathBuf;
+use std::process::Command;
+
+use tracing::debug;
+
+use distribution_types::{Accelerator, AcceleratorIndex, IndexUrl};
+use uv_warnings::warn_user;
+
+/// Select the index to use for the given accelerator from the configured accelerator indexes,
+/// detecting the accelerator if none was requested.
+///
+/// The selected index should take priority over all other indexes.
+pub(crate) fn select_index(
+    accelerator: Option<Accelerator>,
+    indexes: &[AcceleratorIndex],
+) -> Option<IndexUrl> {
+    if indexes.is_empty() {
+        if let Some(accelerator) = accelerator {
+            warn_user!(
+                "Ignoring accelerator `{accelerator}`, as no accelerator indexes are configured"
+            );
+        }
+        return None;
+    }
+
+    let accelerator = accelerator.unwrap_or_else(detect);
+    let Some(index) = accelerator.select(indexes) else {
+        warn_user!("No accelerator index is configured for `{accelerator}`");
+        return None;
+    };
+    debug!(
+        "Using accelerator index for `{}`: {}",
+        index.accelerator, index.url
+    );
+    Some(index.index_url())
+}
+
+/// Detect the hardware accelerator available on the current machine.
+///
+/// NVIDIA GPUs are detected via `nvidia-smi`, which reports the newest CUDA version supported by
+/// the installed driver. AMD GPUs are detected via the version file of the ROCm installation. If
+/// neither is found, the machine is assumed to be CPU-only.
+pub(crate) fn detect() -> Accelerator {
+    if let Some(accelerator) = detect_cuda() {
+        debug!("Detected CUDA accelerator: {accelerator}");
+        return accelerator;
+    }
+    if let Some(accelerator) = detect_rocm() {
+        debug!("Detected ROCm accelerator: {accelerator}");
+        return accelerator;
+    }
+    debug!("No accelerator detected; assuming CPU-only");
+    Accelerator::Cpu
+}
+
+/// Detect an NVIDIA GPU via `nvidia-smi`.
+fn detect_cuda() -> Option<Accelerator> {
+    let output = Command::new("nvidia-smi").output().ok()?;
+    if !output.status.success() {
+        debug!("`nvidia-smi` exited with: {}", output.status);
+        return None;
+    }
+    parse_nvidia_smi(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Parse the supported CUDA version from the header of the `nvidia-smi` output, e.g.:
+///
+/// ```text
+/// | NVIDIA-SMI 535.104.05   Driver Version: 535.104.05   CUDA Version: 12.2     |
+/// ```
+fn parse_nvidia_smi(stdout: &str) -> Option<Accelerator> {
+    let (_, version) = stdout.split_once("CUDA Version:")?;
+    let version = version.split_whitespace().next()?;
+    let (major, minor) = version.split_once('.')?;
+    Some(Accelerator::Cuda {
+        major: major.parse().ok()?,
+        minor: minor.parse().ok()?,
+    })
+}
+
+/// Detect an AMD GPU via the version file of the ROCm installation.
+fn detect_rocm() -> Option<Accelerator> {
+    let root = env::var_os("ROCM_PATH").map_or_else(|| PathBuf::from("/opt/rocm"), PathBuf::from);
+    let version = fs_err::read_to_string(root.join(".info").join("version")).ok()?;
+    parse_rocm_version(&version)
+}
+
+/// Parse the contents of the ROCm version file (e.g., `5.7.1-98`).
+fn parse_rocm_version(version: &str) -> Option<Accelerator> {
+    let mut parts = version.trim().split(['.', '-']);
+    Some(Accelerator::Rocm {
+        major: parts.next()?.parse().ok()?,
+        minor: parts.next()?.parse().ok()?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use distribution_types::Accelerator;
+
+    use super::{parse_nvidia_smi, parse_rocm_version};
+
+    #[test]
+    fn nvidia_smi() {
+        let stdout = "\
++---------------------------------------------------------------------------------------+
+| NVIDIA-SMI 535.104.05             Driver Version: 535.104.05   CUDA Version: 12.2     |
+|-----------------------------------------+----------------------+----------------------+
+";
+        assert_eq!(
+            parse_nvidia_smi(stdout),
+            Some(Accelerator::Cuda {
+                major: 12,
+                minor: 2
+            })
+        );
+        assert_eq!(parse_nvidia_smi("No devices were found"), None);
+    }
+
+    #[test]
+    fn rocm_version() {
+        assert_eq!(
+            parse_rocm_version("5.7.1-98\n"),
+            Some(Accelerator::Rocm { major: 5, minor: 7 })
+        );
+        assert_eq!(parse_rocm_version(""), None);
+    }
+}
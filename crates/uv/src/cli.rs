@@ -1,21 +1,35 @@
+use std::ffi::OsString;
 use std::path::PathBuf;
 use std::str::FromStr;
 
 use anyhow::Result;
 
 use clap::{Args, Parser, Subcommand};
+use url::Url;
 
-use distribution_types::{FlatIndexLocation, IndexUrl};
-use uv_cache::CacheArgs;
+use distribution_types::{Accelerator, FlatIndexLocation, IndexUrl};
+use platform_tags::TargetLibc;
+use pypi_types::HashAlgorithm;
+use uv_cache::{CacheAge, CacheArgs};
 use uv_configuration::{
-    ConfigSettingEntry, IndexStrategy, KeyringProviderType, PackageNameSpecifier,
+    ConfigSettingEntry, IndexStrategy, KeyringProviderType, PackageNamePattern,
+    PackageNameSpecifier, ScriptConflictStrategy, SyncLayer, TagPolicy, TargetPlatform,
 };
 use uv_normalize::{ExtraName, PackageName};
-use uv_resolver::{AnnotationStyle, ExcludeNewer, PreReleaseMode, ResolutionMode};
+use uv_resolver::{
+    AnnotationStyle, ExcludeNewer, LocalPreference, PreReleaseMode, ReleaseAge, ResolutionMode,
+};
 use uv_toolchain::PythonVersion;
+use uv_virtualenv::Activator;
 
-use crate::commands::{extra_name_with_clap_error, ListFormat, VersionFormat};
+use crate::commands::{
+    extra_name_with_clap_error, CacheInfoFormat, ListFormat, PythonInspectFormat, ShowFormat,
+    VersionFormat,
+};
 use crate::compat;
+use crate::errors::ErrorFormat;
+use crate::logging::LogFormat;
+use crate::printer::ProgressMode;
 
 #[derive(Parser)]
 #[command(author, version, long_version = crate::version::version(), about)]
@@ -70,6 +84,69 @@ pub(crate) struct GlobalArgs {
     /// included in your system's certificate store.
     #[arg(global = true, long, env = "UV_NATIVE_TLS")]
     pub(crate) native_tls: bool,
+
+    /// The maximum time to wait, in seconds, for another `uv` process to release a lock on a
+    /// shared resource (like a virtual environment or a cache entry).
+    ///
+    /// By default, `uv` waits indefinitely.
+    #[arg(global = true, long, env = "UV_LOCK_TIMEOUT", value_name = "SECONDS")]
+    pub(crate) lock_timeout: Option<u64>,
+
+    /// The format for log messages emitted with `--verbose` (or `RUST_LOG`).
+    ///
+    /// With `json`, each message is emitted as a JSON object on its own line, including the
+    /// active spans and their fields; span durations are emitted when each span closes.
+    #[arg(
+        global = true,
+        long,
+        value_enum,
+        env = "UV_LOG_FORMAT",
+        default_value_t = LogFormat::default()
+    )]
+    pub(crate) log_format: LogFormat,
+
+    /// Print a breakdown of the time spent in each phase of the command once it completes.
+    ///
+    /// Includes the time spent fetching metadata, resolving, downloading (along with the number
+    /// of bytes fetched), building (per package), installing, and compiling bytecode.
+    #[arg(global = true, long)]
+    pub(crate) timings: bool,
+
+    /// Record metadata for each HTTP request to the given file, for debugging (e.g., to attach to
+    /// a bug report).
+    ///
+    /// Each request (with its URL, status, timing, and authorization scheme) and each HTTP cache
+    /// verdict is written as a JSON object on its own line. Credentials and query parameter values
+    /// are redacted.
+    #[arg(global = true, long, env = "UV_HTTP_TRACE", value_name = "FILE")]
+    pub(crate) http_trace: Option<PathBuf>,
+
+    /// The format for errors reported on exit.
+    ///
+    /// With `json`, the error is written to stderr as a single JSON object, including its class
+    /// (e.g., `resolution` or `network`) and exit code.
+    #[arg(
+        global = true,
+        long,
+        value_enum,
+        env = "UV_ERROR_FORMAT",
+        default_value_t = ErrorFormat::default()
+    )]
+    pub(crate) error_format: ErrorFormat,
+
+    /// The style of progress output.
+    ///
+    /// With `plain`, each step (e.g., each download or build) is printed on its own line, prefixed
+    /// with the time elapsed since uv started, along with a periodic summary of the steps in
+    /// progress. Useful in CI, where progress bars aren't shown.
+    #[arg(
+        global = true,
+        long,
+        value_enum,
+        env = "UV_PROGRESS",
+        default_value_t = ProgressMode::default()
+    )]
+    pub(crate) progress: ProgressMode,
 }
 
 #[derive(Debug, Clone, clap::ValueEnum)]
@@ -104,6 +181,18 @@ pub(crate) enum Commands {
     Venv(VenvArgs),
     /// Manage the cache.
     Cache(CacheNamespace),
+    /// Manage package indexes.
+    Index(IndexNamespace),
+    /// Inspect Python interpreters.
+    Python(PythonNamespace),
+    /// Manage credentials in the encrypted credential store.
+    Auth(AuthNamespace),
+    /// Check the environment for common causes of failures (e.g., misconfigured proxies, TLS
+    /// certificates, clock skew, or cache permissions).
+    Doctor(DoctorArgs),
+    /// Serve the cache as a package index, reading through to the upstream indexes.
+    #[cfg(feature = "serve-index")]
+    ServeIndex(ServeIndexArgs),
     /// Manage the `uv` executable.
     #[clap(name = "self")]
     #[cfg(feature = "self-update")]
@@ -119,6 +208,9 @@ pub(crate) enum Commands {
     /// Generate shell completion
     #[clap(alias = "--generate-shell-completion", hide = true)]
     GenerateShellCompletion { shell: clap_complete_command::Shell },
+    /// Run an external `uv-<name>` command found on the `PATH`.
+    #[command(external_subcommand)]
+    External(Vec<OsString>),
 }
 
 #[derive(Args)]
@@ -132,7 +224,37 @@ pub(crate) struct SelfNamespace {
 #[cfg(feature = "self-update")]
 pub(crate) enum SelfCommand {
     /// Update `uv` to the latest version.
-    Update,
+    Update(SelfUpdateArgs),
+    /// Download a `uv` release artifact, e.g., to provision another machine offline.
+    Package(SelfPackageArgs),
+}
+
+#[derive(Args)]
+#[cfg(feature = "self-update")]
+pub(crate) struct SelfUpdateArgs {
+    /// The release channel to update from.
+    ///
+    /// Defaults to the `update-channel` setting in `uv.toml` or `[tool.uv]`, if any, and
+    /// otherwise to `stable`.
+    #[arg(long, value_enum, env = "UV_UPDATE_CHANNEL")]
+    pub(crate) channel: Option<uv_configuration::UpdateChannel>,
+}
+
+#[derive(Args)]
+#[cfg(feature = "self-update")]
+pub(crate) struct SelfPackageArgs {
+    /// The target triple of the platform to download `uv` for (e.g.,
+    /// `x86_64-unknown-linux-gnu`).
+    #[arg(long)]
+    pub(crate) target: String,
+
+    /// The version of `uv` to download. Defaults to the current version.
+    #[arg(long, value_name = "VERSION")]
+    pub(crate) release: Option<String>,
+
+    /// The directory in which to write the release artifact. Defaults to the current directory.
+    #[arg(long, short)]
+    pub(crate) output_dir: Option<PathBuf>,
 }
 
 #[derive(Args)]
@@ -146,9 +268,21 @@ pub(crate) enum CacheCommand {
     /// Clear the cache, removing all entries or those linked to specific packages.
     Clean(CleanArgs),
     /// Prune all unreachable objects from the cache.
-    Prune,
+    Prune(PruneArgs),
+    /// Replace identical files in the cache with hard links to a single copy.
+    Dedupe,
+    /// Compress unzipped wheels that haven't been used recently.
+    ///
+    /// Compressed wheels are decompressed transparently the next time they're used.
+    Compress(CompressArgs),
     /// Show the cache directory.
     Dir,
+    /// Verify the integrity of the cache, re-hashing cached wheels against their recorded digests
+    /// and checking for links to missing archives.
+    Verify(VerifyArgs),
+    /// Summarize the contents of the cache, including per-bucket sizes, the largest entries, and
+    /// the hit rate over recent commands.
+    Info(CacheInfoArgs),
 }
 
 #[derive(Args)]
@@ -158,6 +292,277 @@ pub(crate) struct CleanArgs {
     pub(crate) package: Vec<PackageName>,
 }
 
+#[derive(Args)]
+pub(crate) struct CacheInfoArgs {
+    /// Select the output format between: `text` (default) or `json`.
+    #[clap(long, value_enum, default_value_t = CacheInfoFormat::default())]
+    pub(crate) format: CacheInfoFormat,
+}
+
+#[derive(Args)]
+pub(crate) struct PruneArgs {
+    /// Also remove any unzipped wheels that haven't been used within the given age (e.g., `30d`).
+    ///
+    /// Supported units are `s`, `m`, `h`, `d`, and `w`.
+    #[arg(long, value_name = "AGE")]
+    pub(crate) max_age: Option<CacheAge>,
+}
+
+#[derive(Args)]
+pub(crate) struct CompressArgs {
+    /// Compress any unzipped wheels that haven't been used within the given age (e.g., `30d`).
+    ///
+    /// Supported units are `s`, `m`, `h`, `d`, and `w`.
+    #[arg(long, value_name = "AGE", default_value = "30d")]
+    pub(crate) max_age: CacheAge,
+}
+
+#[derive(Args)]
+pub(crate) struct VerifyArgs {
+    /// Remove any corrupt entries from the cache, such that they're re-fetched on next use.
+    #[arg(long)]
+    pub(crate) prune: bool,
+}
+
+#[derive(Args)]
+pub(crate) struct IndexNamespace {
+    #[clap(subcommand)]
+    pub(crate) command: IndexCommand,
+}
+
+#[derive(Subcommand)]
+pub(crate) enum IndexCommand {
+    /// Write a static package index containing the distributions pinned in a `requirements.txt`
+    /// file, e.g., to install from offline.
+    Snapshot(IndexSnapshotArgs),
+}
+
+#[derive(Args)]
+pub(crate) struct IndexSnapshotArgs {
+    /// Include all packages listed in the given `requirements.txt` files.
+    ///
+    /// Every requirement must be pinned to a specific version (e.g., `flask==3.0.0`), as in the
+    /// output of `uv pip compile`. If a requirement includes hashes, only the files that match
+    /// those hashes are included.
+    #[clap(required(true))]
+    pub(crate) src_file: Vec<PathBuf>,
+
+    /// The directory in which to write the index.
+    ///
+    /// The index is written to `<OUTPUT_DIR>/simple`, and can be served by any static file
+    /// server. Existing snapshots in the directory are extended, rather than replaced.
+    #[arg(long, short)]
+    pub(crate) output_dir: PathBuf,
+
+    /// The URL of the Python package index (by default: <https://pypi.org/simple>).
+    ///
+    /// The index given by this flag is given lower priority than all other
+    /// indexes specified via the `--extra-index-url` flag.
+    #[clap(long, short, env = "UV_INDEX_URL", value_parser = parse_index_url)]
+    pub(crate) index_url: Option<Maybe<IndexUrl>>,
+
+    /// Extra URLs of package indexes to use, in addition to `--index-url`.
+    ///
+    /// All indexes given via this flag take priority over the index
+    /// in `--index-url` (which defaults to PyPI). And when multiple
+    /// `--extra-index-url` flags are given, earlier values take priority.
+    #[clap(long, env = "UV_EXTRA_INDEX_URL", value_delimiter = ' ', value_parser = parse_index_url)]
+    pub(crate) extra_index_url: Vec<Maybe<IndexUrl>>,
+
+    /// The strategy to use when resolving against multiple index URLs.
+    ///
+    /// By default, `uv` will stop at the first index on which a given package is available.
+    #[clap(long, default_value_t, value_enum, env = "UV_INDEX_STRATEGY")]
+    pub(crate) index_strategy: IndexStrategy,
+
+    /// Attempt to use `keyring` for authentication for index urls
+    ///
+    /// Function's similar to `pip`'s `--keyring-provider subprocess` argument,
+    /// `uv` will try to use `keyring` via CLI when this flag is used.
+    #[clap(long, default_value_t, value_enum, env = "UV_KEYRING_PROVIDER")]
+    pub(crate) keyring_provider: KeyringProviderType,
+}
+
+#[derive(Args)]
+pub(crate) struct DoctorArgs {
+    /// The URL of the Python package index (by default: <https://pypi.org/simple>), to check for
+    /// connectivity and clock skew.
+    #[clap(long, short, env = "UV_INDEX_URL", value_parser = parse_index_url)]
+    pub(crate) index_url: Option<Maybe<IndexUrl>>,
+
+    /// Extra URLs of package indexes to check, in addition to `--index-url`.
+    #[clap(long, env = "UV_EXTRA_INDEX_URL", value_delimiter = ' ', value_parser = parse_index_url)]
+    pub(crate) extra_index_url: Vec<Maybe<IndexUrl>>,
+}
+
+#[derive(Args)]
+#[cfg(feature = "serve-index")]
+pub(crate) struct ServeIndexArgs {
+    /// The address on which to listen for requests.
+    ///
+    /// To serve other machines on the network, bind to a non-loopback address (e.g.,
+    /// `0.0.0.0:8000`).
+    #[arg(long, default_value = "127.0.0.1:8000")]
+    pub(crate) bind: std::net::SocketAddr,
+
+    /// The URL of the Python package index (by default: <https://pypi.org/simple>).
+    ///
+    /// The index given by this flag is given lower priority than all other
+    /// indexes specified via the `--extra-index-url` flag.
+    #[clap(long, short, env = "UV_INDEX_URL", value_parser = parse_index_url)]
+    pub(crate) index_url: Option<Maybe<IndexUrl>>,
+
+    /// Extra URLs of package indexes to use, in addition to `--index-url`.
+    ///
+    /// All indexes given via this flag take priority over the index
+    /// in `--index-url` (which defaults to PyPI). And when multiple
+    /// `--extra-index-url` flags are given, earlier values take priority.
+    #[clap(long, env = "UV_EXTRA_INDEX_URL", value_delimiter = ' ', value_parser = parse_index_url)]
+    pub(crate) extra_index_url: Vec<Maybe<IndexUrl>>,
+
+    /// The strategy to use when resolving against multiple index URLs.
+    ///
+    /// By default, `uv` will stop at the first index on which a given package is available.
+    #[clap(long, default_value_t, value_enum, env = "UV_INDEX_STRATEGY")]
+    pub(crate) index_strategy: IndexStrategy,
+
+    /// Attempt to use `keyring` for authentication for index urls
+    ///
+    /// Function's similar to `pip`'s `--keyring-provider subprocess` argument,
+    /// `uv` will try to use `keyring` via CLI when this flag is used.
+    #[clap(long, default_value_t, value_enum, env = "UV_KEYRING_PROVIDER")]
+    pub(crate) keyring_provider: KeyringProviderType,
+
+    /// Run offline, i.e., serve only the projects and files that are already cached.
+    #[arg(long)]
+    pub(crate) offline: bool,
+}
+
+#[derive(Args)]
+pub(crate) struct PythonNamespace {
+    #[clap(subcommand)]
+    pub(crate) command: PythonCommand,
+}
+
+#[derive(Subcommand)]
+pub(crate) enum PythonCommand {
+    /// Show the marker environment, module search path, compatible wheel tags, and installation
+    /// paths of a Python interpreter.
+    Inspect(PythonInspectArgs),
+}
+
+#[derive(Args)]
+pub(crate) struct PythonInspectArgs {
+    /// Select the output format between: `text` (default) or `json`.
+    #[clap(long, value_enum, default_value_t = PythonInspectFormat::default())]
+    pub(crate) format: PythonInspectFormat,
+
+    /// The Python interpreter to inspect.
+    ///
+    /// By default, `uv` inspects the interpreter of the currently activated virtual environment,
+    /// or a virtual environment (`.venv`) located in the current working directory or any parent
+    /// directory, falling back to the system Python if no virtual environment is found.
+    ///
+    /// Supported formats:
+    /// - `3.10` looks for an installed Python 3.10 using `py --list-paths` on Windows, or
+    ///   `python3.10` on Linux and macOS.
+    /// - `python3.10` or `python.exe` looks for a binary with the given name in `PATH`.
+    /// - `/home/ferris/.local/bin/python3.10` uses the exact Python at the given path.
+    #[clap(long, short, verbatim_doc_comment, group = "discovery")]
+    pub(crate) python: Option<String>,
+
+    /// Inspect the system Python.
+    ///
+    /// By default, `uv` inspects the interpreter of the currently activated virtual environment,
+    /// or a virtual environment (`.venv`) located in the current working directory or any parent
+    /// directory, falling back to the system Python if no virtual environment is found. The
+    /// `--system` option instructs `uv` to use the first Python found in the system `PATH`.
+    #[clap(long, env = "UV_SYSTEM_PYTHON", group = "discovery")]
+    pub(crate) system: bool,
+}
+
+#[derive(Args)]
+pub(crate) struct AuthNamespace {
+    #[clap(subcommand)]
+    pub(crate) command: AuthCommand,
+}
+
+#[derive(Subcommand)]
+pub(crate) enum AuthCommand {
+    /// Store credentials for an index.
+    ///
+    /// By default, credentials are stored in the encrypted credential store for the URL's origin
+    /// (i.e., its scheme, host, and port), and used for any request to that origin. The store is
+    /// encrypted with the passphrase in `UV_CREDENTIALS_KEY`.
+    ///
+    /// With `--keyring-provider subprocess`, credentials are instead stored for the URL's host
+    /// via the `keyring` command. With `--netrc`, credentials are instead added to a netrc file.
+    Login(AuthLoginArgs),
+    /// Remove the stored credentials for an index.
+    Logout(AuthLogoutArgs),
+    /// List the indexes (and usernames) with stored credentials.
+    List,
+    /// Check that requests to an index are authenticated, by sending a `HEAD` request with any
+    /// credentials found in the URL, netrc file, credential store, or keyring.
+    Check(AuthCheckArgs),
+}
+
+#[derive(Args)]
+pub(crate) struct AuthLoginArgs {
+    /// The URL of the index.
+    pub(crate) url: Url,
+
+    /// The username to authenticate with.
+    #[clap(long, short)]
+    pub(crate) username: String,
+
+    /// The password (or token) to authenticate with.
+    ///
+    /// If omitted, the password is read from stdin.
+    #[clap(long, short)]
+    pub(crate) password: Option<String>,
+
+    /// Store the credentials in the keyring, rather than the encrypted credential store.
+    #[clap(long, default_value_t, value_enum, env = "UV_KEYRING_PROVIDER")]
+    pub(crate) keyring_provider: KeyringProviderType,
+
+    /// Add the credentials to the given netrc file (e.g., a project-scoped file listed in
+    /// `netrc-files`), rather than the encrypted credential store.
+    ///
+    /// The file is created if it doesn't exist. Existing entries are never overwritten.
+    #[clap(long)]
+    pub(crate) netrc: Option<PathBuf>,
+}
+
+#[derive(Args)]
+pub(crate) struct AuthLogoutArgs {
+    /// The URL of the index.
+    pub(crate) url: Url,
+
+    /// The username to remove credentials for.
+    ///
+    /// Required when removing credentials from the keyring.
+    #[clap(long, short)]
+    pub(crate) username: Option<String>,
+
+    /// Remove the credentials from the keyring, rather than the encrypted credential store.
+    #[clap(long, default_value_t, value_enum, env = "UV_KEYRING_PROVIDER")]
+    pub(crate) keyring_provider: KeyringProviderType,
+}
+
+#[derive(Args)]
+pub(crate) struct AuthCheckArgs {
+    /// The URL of the index.
+    ///
+    /// To look up credentials in the keyring, include the username in the URL (e.g.,
+    /// `https://oauth2accesstoken@example.com/simple`).
+    pub(crate) url: Url,
+
+    /// Attempt to use `keyring` for authentication for the index URL.
+    #[clap(long, default_value_t, value_enum, env = "UV_KEYRING_PROVIDER")]
+    pub(crate) keyring_provider: KeyringProviderType,
+}
+
 #[derive(Args)]
 pub(crate) struct PipNamespace {
     #[clap(subcommand)]
@@ -201,6 +606,17 @@ impl<T> Maybe<T> {
     }
 }
 
+/// Parse a hash algorithm that's supported by pip's hash-checking mode.
+fn parse_hash_algorithm(input: &str) -> Result<HashAlgorithm, String> {
+    match HashAlgorithm::from_str(input) {
+        Ok(HashAlgorithm::Md5) => {
+            Err("`md5` is not supported by pip's hash-checking mode".to_string())
+        }
+        Ok(algorithm) => Ok(algorithm),
+        Err(err) => Err(err.to_string()),
+    }
+}
+
 /// Parse a string into an [`IndexUrl`], mapping the empty string to `None`.
 fn parse_index_url(input: &str) -> Result<Maybe<IndexUrl>, String> {
     if input.is_empty() {
@@ -266,10 +682,57 @@ pub(crate) struct PipCompileArgs {
     #[clap(long, hide = true)]
     pub(crate) pre: bool,
 
+    /// Prefer the given local version label when selecting between the variants of a package
+    /// (e.g., `2.3.0+cu121` vs. `2.3.0+cpu`).
+    ///
+    /// Accepts a label scoped to a single package (e.g., `torch+cu121`), or a label that applies to
+    /// all packages that publish local versions (e.g., `+cu121`). An empty label (e.g., `torch+`)
+    /// selects the version without a local label. Versions that don't match the label are not
+    /// considered.
+    ///
+    /// May also be set via the `prefer-local` setting in the `[pip.resolver]` section of a
+    /// `uv.toml` (or the `[tool.uv.pip.resolver]` section of a `pyproject.toml`).
+    #[clap(long)]
+    pub(crate) prefer_local: Vec<LocalPreference>,
+
+    /// Exclude releases that were uploaded more recently than the given age (e.g., `14d`).
+    ///
+    /// Accepts an age in hours (`h`), days (`d`), or weeks (`w`), which applies to all packages,
+    /// or an age scoped to a single package (e.g., `torch=3d`). An age of zero (e.g., `torch=0d`)
+    /// exempts a package. As with `--exclude-newer`, files without an upload time are excluded.
+    ///
+    /// May also be set via the `minimum-release-age` setting in the `[pip.resolver]` section of a
+    /// `uv.toml` (or the `[tool.uv.pip.resolver]` section of a `pyproject.toml`).
+    #[clap(long)]
+    pub(crate) minimum_release_age: Vec<ReleaseAge>,
+
     /// Write the compiled requirements to the given `requirements.txt` file.
     #[clap(long, short)]
     pub(crate) output_file: Option<PathBuf>,
 
+    /// Write one `requirements.txt` file per extra, in addition to a `base.txt` file that
+    /// includes the dependencies shared across extras.
+    ///
+    /// Each extra file references the base file via `-r base.txt`, such that installing it
+    /// installs the base dependencies too. Includes all extras, unless specific extras are
+    /// requested with `--extra`.
+    #[clap(long, conflicts_with = "output_file", requires = "output_dir")]
+    pub(crate) split_extras: bool,
+
+    /// The directory in which to write the output files when using `--split-extras`.
+    #[clap(long, requires = "split_extras")]
+    pub(crate) output_dir: Option<PathBuf>,
+
+    /// Write the build requirements of any source distributions in the resolution to the given
+    /// `requirements.txt` file.
+    ///
+    /// Includes the pinned build backends (and their dependencies) needed to build each source
+    /// distribution, such that they can be provisioned in advance. The build requirements of each
+    /// source distribution are resolved independently, as they are when building. Requirements
+    /// that the build backend adds dynamically (via `get_requires_for_build_wheel`) are omitted.
+    #[clap(long)]
+    pub(crate) emit_build_requirements: Option<PathBuf>,
+
     /// Include extras in the output file.
     ///
     /// By default, `uv` strips extras, as any packages pulled in by the extras are already included
@@ -299,7 +762,8 @@ pub(crate) struct PipCompileArgs {
         global = true,
         long,
         conflicts_with = "refresh",
-        conflicts_with = "refresh_package"
+        conflicts_with = "refresh_package",
+        conflicts_with = "refresh_url"
     )]
     pub(crate) offline: bool,
 
@@ -311,6 +775,11 @@ pub(crate) struct PipCompileArgs {
     #[clap(long)]
     pub(crate) refresh_package: Vec<PackageName>,
 
+    /// Refresh cached data for a specific artifact URL, e.g., a direct URL wheel or source
+    /// distribution.
+    #[clap(long)]
+    pub(crate) refresh_url: Vec<Url>,
+
     /// The method to use when installing packages from the global cache.
     ///
     /// This option is only used when creating build environments for source distributions.
@@ -357,6 +826,15 @@ pub(crate) struct PipCompileArgs {
     #[clap(long, default_value_t, value_enum, env = "UV_INDEX_STRATEGY")]
     pub(crate) index_strategy: IndexStrategy,
 
+    /// The hardware accelerator for which to select an index (e.g., `cpu`, `cu121`, or
+    /// `rocm5.7`).
+    ///
+    /// Only applies if accelerator indexes are configured via `accelerator-index` in the `[pip]`
+    /// section of a `uv.toml` (or the `[tool.uv.pip]` section of a `pyproject.toml`). Defaults to
+    /// the accelerator detected on the current machine.
+    #[clap(long, env = "UV_ACCELERATOR")]
+    pub(crate) accelerator: Option<Accelerator>,
+
     /// Attempt to use `keyring` for authentication for index urls
     ///
     /// Due to not having Python imports, only `--keyring-provider subprocess` argument is currently
@@ -382,10 +860,35 @@ pub(crate) struct PipCompileArgs {
     #[clap(long, short = 'P')]
     pub(crate) upgrade_package: Vec<PackageName>,
 
+    /// Summarize the changes to the pinned versions in the existing output file.
+    ///
+    /// Lists the packages that were added, removed, or changed, along with a link to the PyPI
+    /// release page (which includes the project's changelog and other URLs) of each new version.
+    #[clap(long, requires = "output_file")]
+    pub(crate) summary: bool,
+
+    /// Include the release notes of each new version in the summary.
+    ///
+    /// For packages from PyPI, the changelog URL is read from the project's URLs, and the notes
+    /// are read from the matching GitHub release (tagged `v<version>` or `<version>`), if any.
+    /// Release notes are cached.
+    #[clap(long, requires = "summary")]
+    pub(crate) release_notes: bool,
+
     /// Include distribution hashes in the output file.
     #[clap(long)]
     pub(crate) generate_hashes: bool,
 
+    /// The algorithm to use for the hashes included via `--generate-hashes`: `sha256`, `sha384`,
+    /// or `sha512`.
+    ///
+    /// May be provided multiple times to include a hash for each algorithm. Hashes that aren't
+    /// reported by the index are computed by downloading the distributions.
+    ///
+    /// By default, the hashes reported by the index (typically, SHA-256) are included as-is.
+    #[clap(long, requires = "generate_hashes", value_parser = parse_hash_algorithm)]
+    pub(crate) hash_algorithm: Vec<HashAlgorithm>,
+
     /// Use legacy `setuptools` behavior when building source distributions without a
     /// `pyproject.toml`.
     #[clap(long)]
@@ -397,6 +900,13 @@ pub(crate) struct PipCompileArgs {
     #[clap(long)]
     pub(crate) no_build_isolation: bool,
 
+    /// Disable isolation when building source distributions for a specific package.
+    ///
+    /// Assumes that the build dependencies specified by PEP 518 for the package are already
+    /// installed.
+    #[clap(long, conflicts_with = "no_build_isolation")]
+    pub(crate) no_build_isolation_package: Vec<PackageName>,
+
     /// Don't build source distributions.
     ///
     /// When enabled, resolving will not run arbitrary code. The cached wheels of already-built
@@ -418,6 +928,42 @@ pub(crate) struct PipCompileArgs {
     #[clap(long, conflicts_with = "no_build")]
     pub(crate) only_binary: Vec<PackageNameSpecifier>,
 
+    /// The Linux C library for which to select wheels (e.g., `manylinux_2_17` or
+    /// `musllinux_1_2`), in lieu of the C library of the current interpreter.
+    ///
+    /// Useful for building images for a different Linux distribution, e.g., to select `musllinux`
+    /// wheels for an Alpine image from a glibc-based host. Targeting a `manylinux` version is
+    /// equivalent to installing a `_manylinux` module that declares compatibility up to the given
+    /// glibc version.
+    ///
+    /// May also be set via the `libc` setting in the `[pip]` section of a `uv.toml` (or the
+    /// `[tool.uv.pip]` section of a `pyproject.toml`).
+    #[clap(long, env = "UV_LIBC")]
+    pub(crate) libc: Option<TargetLibc>,
+
+    /// Select `manylinux` wheels, even if the current interpreter isn't glibc-based.
+    ///
+    /// Useful on musl-based distributions (e.g., Alpine) with a glibc compatibility layer
+    /// installed, in which case `manylinux2014` (i.e., `manylinux_2_17`) wheels are selected. On
+    /// glibc-based distributions, this option has no effect.
+    ///
+    /// May also be set via the `force-manylinux` setting in the `[pip]` section of a `uv.toml` (or
+    /// the `[tool.uv.pip]` section of a `pyproject.toml`).
+    #[clap(long, conflicts_with = "libc")]
+    pub(crate) force_manylinux: bool,
+
+    /// The policy to use when choosing among the wheels that are compatible with the current
+    /// interpreter.
+    ///
+    /// With `prefer-abi3`, wheels built against the stable ABI (e.g., `cp39-abi3`) are chosen over
+    /// version-specific wheels (e.g., `cp312-cp312`), such that the same wheels can be cached and
+    /// shared across Python versions.
+    ///
+    /// May also be set via the `tag-policy` setting in the `[pip]` section of a `uv.toml` (or the
+    /// `[tool.uv.pip]` section of a `pyproject.toml`).
+    #[arg(long, value_enum)]
+    pub(crate) tag_policy: Option<TagPolicy>,
+
     /// Settings to pass to the PEP 517 build backend, specified as `KEY=VALUE` pairs.
     #[clap(long, short = 'C', alias = "config-settings")]
     pub(crate) config_setting: Vec<ConfigSettingEntry>,
@@ -430,6 +976,15 @@ pub(crate) struct PipCompileArgs {
     #[arg(long, short)]
     pub(crate) python_version: Option<PythonVersion>,
 
+    /// The platform for which requirements should be resolved, in lieu of the platform of the
+    /// current interpreter.
+    ///
+    /// Resolves using the wheel tags and environment markers of the given platform, as well as its
+    /// Python version (unless `--python-version` is provided). For example, `pyodide` produces a
+    /// resolution that can be installed into Pyodide (i.e., Python in the browser) via `micropip`.
+    #[arg(long, value_enum, conflicts_with_all = ["libc", "force_manylinux"])]
+    pub(crate) python_platform: Option<TargetPlatform>,
+
     /// Limit candidate packages to those that were uploaded prior to the given date.
     ///
     /// Accepts both RFC 3339 timestamps (e.g., `2006-12-02T02:07:43Z`) and UTC dates in the same
@@ -483,12 +1038,52 @@ pub(crate) struct PipSyncArgs {
     #[clap(long)]
     pub(crate) reinstall_package: Vec<PackageName>,
 
+    /// Only install packages that match the given pattern (e.g., `django-*`), leaving any other
+    /// packages in the environment untouched.
+    #[clap(long)]
+    pub(crate) only_package: Vec<PackageNamePattern>,
+
+    /// Don't install packages that match the given pattern (e.g., `django-*`), leaving them
+    /// untouched if they're already installed.
+    ///
+    /// For example, to install the dependencies of a project in a separate layer of a Docker image,
+    /// pass `--no-install-package` with the name of the project.
+    #[clap(long)]
+    pub(crate) no_install_package: Vec<PackageNamePattern>,
+
+    /// Also skip any dependencies that are only required by the packages excluded via
+    /// `--no-install-package`.
+    ///
+    /// Determining the dependencies of each package requires resolving the requirements.
+    #[clap(long, requires = "no_install_package")]
+    pub(crate) no_install_package_deps: bool,
+
+    /// Only sync the given layer of the environment: either the dependencies (`deps`), or the
+    /// project itself (`project`), i.e., any editables and local directories.
+    ///
+    /// Intended for installing the dependencies and the project in separate layers of a Docker
+    /// image, such that the dependencies layer can be cached across changes to the project.
+    /// Packages in the other layer are left untouched.
+    ///
+    /// Prints a hash of the layer's inputs (i.e., its requirements, the Python version, and the
+    /// platform) to stdout, which build scripts can use for cache invalidation.
+    #[clap(long, value_enum)]
+    pub(crate) layer: Option<SyncLayer>,
+
+    /// Leave the given package in place if it's installed, even if it's not in the
+    /// requirements.
+    ///
+    /// By default, `uv pip sync` removes any installed packages that aren't in the requirements.
+    #[clap(long)]
+    pub(crate) no_prune: Vec<PackageName>,
+
     /// Run offline, i.e., without accessing the network.
     #[arg(
         global = true,
         long,
         conflicts_with = "refresh",
-        conflicts_with = "refresh_package"
+        conflicts_with = "refresh_package",
+        conflicts_with = "refresh_url"
     )]
     pub(crate) offline: bool,
 
@@ -500,6 +1095,11 @@ pub(crate) struct PipSyncArgs {
     #[clap(long)]
     pub(crate) refresh_package: Vec<PackageName>,
 
+    /// Refresh cached data for a specific artifact URL, e.g., a direct URL wheel or source
+    /// distribution.
+    #[clap(long)]
+    pub(crate) refresh_url: Vec<Url>,
+
     /// The method to use when installing packages from the global cache.
     ///
     /// Defaults to `clone` (also known as Copy-on-Write) on macOS, and `hardlink` on Linux and
@@ -507,6 +1107,24 @@ pub(crate) struct PipSyncArgs {
     #[clap(long, value_enum, default_value_t = install_wheel_rs::linker::LinkMode::default())]
     pub(crate) link_mode: install_wheel_rs::linker::LinkMode,
 
+    /// The strategy to use when installing a console or GUI script that's also provided by
+    /// another package in the environment.
+    ///
+    /// By default, uv warns about the conflict, and overwrites the existing script.
+    ///
+    /// May also be set via the `script-conflicts` setting in the `[pip.installer]` section of a
+    /// `uv.toml` (or the `[tool.uv.pip.installer]` section of a `pyproject.toml`).
+    #[clap(long, value_enum)]
+    pub(crate) script_conflicts: Option<ScriptConflictStrategy>,
+
+    /// A policy file against which to check the contents of each wheel before installing (e.g.,
+    /// to warn about or deny `.pth` files that execute code, or binaries in pure Python wheels).
+    ///
+    /// May also be set via the `wheel-policy` setting in the `[pip.installer]` section of a
+    /// `uv.toml` (or the `[tool.uv.pip.installer]` section of a `pyproject.toml`).
+    #[clap(long)]
+    pub(crate) wheel_policy: Option<PathBuf>,
+
     /// The URL of the Python package index (by default: <https://pypi.org/simple>).
     ///
     /// The index given by this flag is given lower priority than all other
@@ -553,6 +1171,15 @@ pub(crate) struct PipSyncArgs {
     #[clap(long, default_value_t, value_enum, env = "UV_INDEX_STRATEGY")]
     pub(crate) index_strategy: IndexStrategy,
 
+    /// The hardware accelerator for which to select an index (e.g., `cpu`, `cu121`, or
+    /// `rocm5.7`).
+    ///
+    /// Only applies if accelerator indexes are configured via `accelerator-index` in the `[pip]`
+    /// section of a `uv.toml` (or the `[tool.uv.pip]` section of a `pyproject.toml`). Defaults to
+    /// the accelerator detected on the current machine.
+    #[clap(long, env = "UV_ACCELERATOR")]
+    pub(crate) accelerator: Option<Accelerator>,
+
     /// Require a matching hash for each requirement.
     ///
     /// Hash-checking mode is all or nothing. If enabled, _all_ requirements must be provided
@@ -600,13 +1227,16 @@ pub(crate) struct PipSyncArgs {
     #[clap(long, env = "UV_SYSTEM_PYTHON", group = "discovery")]
     pub(crate) system: bool,
 
-    /// Allow `uv` to modify an `EXTERNALLY-MANAGED` Python installation.
+    /// Allow `uv` to modify an `EXTERNALLY-MANAGED` Python installation or virtual environment.
     ///
     /// WARNING: `--break-system-packages` is intended for use in continuous integration (CI)
     /// environments, when installing into Python installations that are managed by an external
     /// package manager, like `apt`. It should be used with caution, as such Python installations
     /// explicitly recommend against modifications by other package managers (like `uv` or `pip`).
-    #[clap(long, env = "UV_BREAK_SYSTEM_PACKAGES", requires = "discovery")]
+    ///
+    /// May also be enabled via the `break-system-packages` setting in the `[pip]` section of a
+    /// `uv.toml` (or the `[tool.uv.pip]` section of a `pyproject.toml`).
+    #[clap(long, env = "UV_BREAK_SYSTEM_PACKAGES")]
     pub(crate) break_system_packages: bool,
 
     /// Use legacy `setuptools` behavior when building source distributions without a
@@ -620,6 +1250,13 @@ pub(crate) struct PipSyncArgs {
     #[clap(long)]
     pub(crate) no_build_isolation: bool,
 
+    /// Disable isolation when building source distributions for a specific package.
+    ///
+    /// Assumes that the build dependencies specified by PEP 518 for the package are already
+    /// installed.
+    #[clap(long, conflicts_with = "no_build_isolation")]
+    pub(crate) no_build_isolation_package: Vec<PackageName>,
+
     /// Don't build source distributions.
     ///
     /// When enabled, resolving will not run arbitrary code. The cached wheels of already-built
@@ -651,6 +1288,42 @@ pub(crate) struct PipSyncArgs {
     #[clap(long, conflicts_with = "no_build")]
     pub(crate) only_binary: Vec<PackageNameSpecifier>,
 
+    /// The Linux C library for which to select wheels (e.g., `manylinux_2_17` or
+    /// `musllinux_1_2`), in lieu of the C library of the current interpreter.
+    ///
+    /// Useful for building images for a different Linux distribution, e.g., to select `musllinux`
+    /// wheels for an Alpine image from a glibc-based host. Targeting a `manylinux` version is
+    /// equivalent to installing a `_manylinux` module that declares compatibility up to the given
+    /// glibc version.
+    ///
+    /// May also be set via the `libc` setting in the `[pip]` section of a `uv.toml` (or the
+    /// `[tool.uv.pip]` section of a `pyproject.toml`).
+    #[clap(long, env = "UV_LIBC")]
+    pub(crate) libc: Option<TargetLibc>,
+
+    /// Select `manylinux` wheels, even if the current interpreter isn't glibc-based.
+    ///
+    /// Useful on musl-based distributions (e.g., Alpine) with a glibc compatibility layer
+    /// installed, in which case `manylinux2014` (i.e., `manylinux_2_17`) wheels are selected. On
+    /// glibc-based distributions, this option has no effect.
+    ///
+    /// May also be set via the `force-manylinux` setting in the `[pip]` section of a `uv.toml` (or
+    /// the `[tool.uv.pip]` section of a `pyproject.toml`).
+    #[clap(long, conflicts_with = "libc")]
+    pub(crate) force_manylinux: bool,
+
+    /// The policy to use when choosing among the wheels that are compatible with the current
+    /// interpreter.
+    ///
+    /// With `prefer-abi3`, wheels built against the stable ABI (e.g., `cp39-abi3`) are chosen over
+    /// version-specific wheels (e.g., `cp312-cp312`), such that the same wheels can be cached and
+    /// shared across Python versions.
+    ///
+    /// May also be set via the `tag-policy` setting in the `[pip]` section of a `uv.toml` (or the
+    /// `[tool.uv.pip]` section of a `pyproject.toml`).
+    #[arg(long, value_enum)]
+    pub(crate) tag_policy: Option<TagPolicy>,
+
     /// Compile Python files to bytecode.
     ///
     /// By default, does not compile Python (`.py`) files to bytecode (`__pycache__/*.pyc`), instead
@@ -676,6 +1349,15 @@ pub(crate) struct PipSyncArgs {
     #[clap(long)]
     pub(crate) strict: bool,
 
+    /// Apply changes to the environment atomically.
+    ///
+    /// Removed packages are backed up, and installed packages are recorded in a journal, prior to
+    /// modifying the environment. If the sync fails or is interrupted, the environment is restored
+    /// to its previous state (on failure, immediately; on interruption, at the start of the next
+    /// `uv pip sync --atomic`).
+    #[clap(long)]
+    pub(crate) atomic: bool,
+
     #[command(flatten)]
     pub(crate) compat_args: compat::PipSyncCompatArgs,
 }
@@ -747,7 +1429,8 @@ pub(crate) struct PipInstallArgs {
         global = true,
         long,
         conflicts_with = "refresh",
-        conflicts_with = "refresh_package"
+        conflicts_with = "refresh_package",
+        conflicts_with = "refresh_url"
     )]
     pub(crate) offline: bool,
 
@@ -759,6 +1442,11 @@ pub(crate) struct PipInstallArgs {
     #[clap(long)]
     pub(crate) refresh_package: Vec<PackageName>,
 
+    /// Refresh cached data for a specific artifact URL, e.g., a direct URL wheel or source
+    /// distribution.
+    #[clap(long)]
+    pub(crate) refresh_url: Vec<Url>,
+
     /// Ignore package dependencies, instead only installing those packages explicitly listed
     /// on the command line or in the requirements files.
     #[clap(long)]
@@ -771,6 +1459,24 @@ pub(crate) struct PipInstallArgs {
     #[clap(long, value_enum, default_value_t = install_wheel_rs::linker::LinkMode::default())]
     pub(crate) link_mode: install_wheel_rs::linker::LinkMode,
 
+    /// The strategy to use when installing a console or GUI script that's also provided by
+    /// another package in the environment.
+    ///
+    /// By default, uv warns about the conflict, and overwrites the existing script.
+    ///
+    /// May also be set via the `script-conflicts` setting in the `[pip.installer]` section of a
+    /// `uv.toml` (or the `[tool.uv.pip.installer]` section of a `pyproject.toml`).
+    #[clap(long, value_enum)]
+    pub(crate) script_conflicts: Option<ScriptConflictStrategy>,
+
+    /// A policy file against which to check the contents of each wheel before installing (e.g.,
+    /// to warn about or deny `.pth` files that execute code, or binaries in pure Python wheels).
+    ///
+    /// May also be set via the `wheel-policy` setting in the `[pip.installer]` section of a
+    /// `uv.toml` (or the `[tool.uv.pip.installer]` section of a `pyproject.toml`).
+    #[clap(long)]
+    pub(crate) wheel_policy: Option<PathBuf>,
+
     #[clap(long, value_enum, default_value_t = ResolutionMode::default(), env = "UV_RESOLUTION")]
     pub(crate) resolution: ResolutionMode,
 
@@ -780,6 +1486,30 @@ pub(crate) struct PipInstallArgs {
     #[clap(long, hide = true)]
     pub(crate) pre: bool,
 
+    /// Prefer the given local version label when selecting between the variants of a package
+    /// (e.g., `2.3.0+cu121` vs. `2.3.0+cpu`).
+    ///
+    /// Accepts a label scoped to a single package (e.g., `torch+cu121`), or a label that applies to
+    /// all packages that publish local versions (e.g., `+cu121`). An empty label (e.g., `torch+`)
+    /// selects the version without a local label. Versions that don't match the label are not
+    /// considered.
+    ///
+    /// May also be set via the `prefer-local` setting in the `[pip.resolver]` section of a
+    /// `uv.toml` (or the `[tool.uv.pip.resolver]` section of a `pyproject.toml`).
+    #[clap(long)]
+    pub(crate) prefer_local: Vec<LocalPreference>,
+
+    /// Exclude releases that were uploaded more recently than the given age (e.g., `14d`).
+    ///
+    /// Accepts an age in hours (`h`), days (`d`), or weeks (`w`), which applies to all packages,
+    /// or an age scoped to a single package (e.g., `torch=3d`). An age of zero (e.g., `torch=0d`)
+    /// exempts a package. As with `--exclude-newer`, files without an upload time are excluded.
+    ///
+    /// May also be set via the `minimum-release-age` setting in the `[pip.resolver]` section of a
+    /// `uv.toml` (or the `[tool.uv.pip.resolver]` section of a `pyproject.toml`).
+    #[clap(long)]
+    pub(crate) minimum_release_age: Vec<ReleaseAge>,
+
     /// The URL of the Python package index (by default: <https://pypi.org/simple>).
     ///
     /// The index given by this flag is given lower priority than all other
@@ -826,6 +1556,15 @@ pub(crate) struct PipInstallArgs {
     #[clap(long, default_value_t, value_enum, env = "UV_INDEX_STRATEGY")]
     pub(crate) index_strategy: IndexStrategy,
 
+    /// The hardware accelerator for which to select an index (e.g., `cpu`, `cu121`, or
+    /// `rocm5.7`).
+    ///
+    /// Only applies if accelerator indexes are configured via `accelerator-index` in the `[pip]`
+    /// section of a `uv.toml` (or the `[tool.uv.pip]` section of a `pyproject.toml`). Defaults to
+    /// the accelerator detected on the current machine.
+    #[clap(long, env = "UV_ACCELERATOR")]
+    pub(crate) accelerator: Option<Accelerator>,
+
     /// Require a matching hash for each requirement.
     ///
     /// Hash-checking mode is all or nothing. If enabled, _all_ requirements must be provided
@@ -873,13 +1612,40 @@ pub(crate) struct PipInstallArgs {
     #[clap(long, env = "UV_SYSTEM_PYTHON", group = "discovery")]
     pub(crate) system: bool,
 
-    /// Allow `uv` to modify an `EXTERNALLY-MANAGED` Python installation.
+    /// Install into the user site directory (e.g., `~/.local` on Linux), rather than into the
+    /// environment itself.
+    ///
+    /// The user site is determined by the Python interpreter, and respects `PYTHONUSERBASE`. Any
+    /// scripts are installed into the user scripts directory (e.g., `~/.local/bin` on Linux).
+    ///
+    /// If no virtual environment is found, `--user` uses the first Python found in the system
+    /// `PATH`. Virtual environments only support `--user` if they were created with
+    /// `--system-site-packages`.
+    #[clap(long)]
+    pub(crate) user: bool,
+
+    /// Install into the given prefix directory (e.g., `{prefix}/lib/python3.12/site-packages` and
+    /// `{prefix}/bin` on Linux), rather than into the environment itself.
+    ///
+    /// The layout is determined by the Python interpreter's standard `sysconfig` scheme, ignoring
+    /// any distribution-specific schemes (like Debian's `posix_local`), such that packages are
+    /// installed into `site-packages` rather than `dist-packages`.
+    ///
+    /// If no virtual environment is found, `--prefix` uses the first Python found in the system
+    /// `PATH`.
+    #[clap(long, value_name = "DIR", conflicts_with = "user")]
+    pub(crate) prefix: Option<PathBuf>,
+
+    /// Allow `uv` to modify an `EXTERNALLY-MANAGED` Python installation or virtual environment.
     ///
     /// WARNING: `--break-system-packages` is intended for use in continuous integration (CI)
     /// environments, when installing into Python installations that are managed by an external
     /// package manager, like `apt`. It should be used with caution, as such Python installations
     /// explicitly recommend against modifications by other package managers (like `uv` or `pip`).
-    #[clap(long, env = "UV_BREAK_SYSTEM_PACKAGES", requires = "discovery")]
+    ///
+    /// May also be enabled via the `break-system-packages` setting in the `[pip]` section of a
+    /// `uv.toml` (or the `[tool.uv.pip]` section of a `pyproject.toml`).
+    #[clap(long, env = "UV_BREAK_SYSTEM_PACKAGES")]
     pub(crate) break_system_packages: bool,
 
     /// Use legacy `setuptools` behavior when building source distributions without a
@@ -893,6 +1659,13 @@ pub(crate) struct PipInstallArgs {
     #[clap(long)]
     pub(crate) no_build_isolation: bool,
 
+    /// Disable isolation when building source distributions for a specific package.
+    ///
+    /// Assumes that the build dependencies specified by PEP 518 for the package are already
+    /// installed.
+    #[clap(long, conflicts_with = "no_build_isolation")]
+    pub(crate) no_build_isolation_package: Vec<PackageName>,
+
     /// Don't build source distributions.
     ///
     /// When enabled, resolving will not run arbitrary code. The cached wheels of already-built
@@ -924,6 +1697,42 @@ pub(crate) struct PipInstallArgs {
     #[clap(long, conflicts_with = "no_build")]
     pub(crate) only_binary: Vec<PackageNameSpecifier>,
 
+    /// The Linux C library for which to select wheels (e.g., `manylinux_2_17` or
+    /// `musllinux_1_2`), in lieu of the C library of the current interpreter.
+    ///
+    /// Useful for building images for a different Linux distribution, e.g., to select `musllinux`
+    /// wheels for an Alpine image from a glibc-based host. Targeting a `manylinux` version is
+    /// equivalent to installing a `_manylinux` module that declares compatibility up to the given
+    /// glibc version.
+    ///
+    /// May also be set via the `libc` setting in the `[pip]` section of a `uv.toml` (or the
+    /// `[tool.uv.pip]` section of a `pyproject.toml`).
+    #[clap(long, env = "UV_LIBC")]
+    pub(crate) libc: Option<TargetLibc>,
+
+    /// Select `manylinux` wheels, even if the current interpreter isn't glibc-based.
+    ///
+    /// Useful on musl-based distributions (e.g., Alpine) with a glibc compatibility layer
+    /// installed, in which case `manylinux2014` (i.e., `manylinux_2_17`) wheels are selected. On
+    /// glibc-based distributions, this option has no effect.
+    ///
+    /// May also be set via the `force-manylinux` setting in the `[pip]` section of a `uv.toml` (or
+    /// the `[tool.uv.pip]` section of a `pyproject.toml`).
+    #[clap(long, conflicts_with = "libc")]
+    pub(crate) force_manylinux: bool,
+
+    /// The policy to use when choosing among the wheels that are compatible with the current
+    /// interpreter.
+    ///
+    /// With `prefer-abi3`, wheels built against the stable ABI (e.g., `cp39-abi3`) are chosen over
+    /// version-specific wheels (e.g., `cp312-cp312`), such that the same wheels can be cached and
+    /// shared across Python versions.
+    ///
+    /// May also be set via the `tag-policy` setting in the `[pip]` section of a `uv.toml` (or the
+    /// `[tool.uv.pip]` section of a `pyproject.toml`).
+    #[arg(long, value_enum)]
+    pub(crate) tag_policy: Option<TagPolicy>,
+
     /// Compile Python files to bytecode.
     ///
     /// By default, does not compile Python (`.py`) files to bytecode (`__pycache__/*.pyc`), instead
@@ -960,6 +1769,24 @@ pub(crate) struct PipInstallArgs {
     /// print the resulting plan.
     #[clap(long)]
     pub(crate) dry_run: bool,
+
+    /// Write a JSON report of the packages selected for installation to the given path, in the
+    /// format of pip's installation report (version 1).
+    ///
+    /// The report includes the download URL, hashes, and metadata of each package. Use `-` to
+    /// write the report to stdout. Combine with `--dry-run` to generate a report without
+    /// installing anything.
+    #[clap(long, value_name = "PATH")]
+    pub(crate) report: Option<PathBuf>,
+
+    /// Download wheels speculatively while the resolution is in progress, with up to the given
+    /// number of downloads in flight at once.
+    ///
+    /// Wheels are prefetched for each version the resolver selects, such that network transfers
+    /// overlap with the remainder of the resolution. If the resolver later backtracks, the
+    /// prefetched wheel is left unused in the cache.
+    #[arg(long, env = "UV_PREFETCH", value_name = "N", default_value_t = 0)]
+    pub(crate) prefetch: usize,
 }
 
 #[derive(Args)]
@@ -1007,15 +1834,38 @@ pub(crate) struct PipUninstallArgs {
     #[clap(long, env = "UV_SYSTEM_PYTHON", group = "discovery")]
     pub(crate) system: bool,
 
-    /// Allow `uv` to modify an `EXTERNALLY-MANAGED` Python installation.
+    /// Allow `uv` to modify an `EXTERNALLY-MANAGED` Python installation or virtual environment.
     ///
     /// WARNING: `--break-system-packages` is intended for use in continuous integration (CI)
     /// environments, when installing into Python installations that are managed by an external
     /// package manager, like `apt`. It should be used with caution, as such Python installations
     /// explicitly recommend against modifications by other package managers (like `uv` or `pip`).
-    #[clap(long, env = "UV_BREAK_SYSTEM_PACKAGES", requires = "discovery")]
+    ///
+    /// May also be enabled via the `break-system-packages` setting in the `[pip]` section of a
+    /// `uv.toml` (or the `[tool.uv.pip]` section of a `pyproject.toml`).
+    #[clap(long, env = "UV_BREAK_SYSTEM_PACKAGES")]
     pub(crate) break_system_packages: bool,
 
+    /// Uninstall packages that weren't installed by a standards-compliant installer.
+    ///
+    /// By default, `uv` refuses to uninstall packages that lack a `RECORD` file (e.g., those
+    /// installed by `distutils`), along with packages that are managed by another tool (e.g.,
+    /// `conda`). The `--force` option instructs `uv` to uninstall such packages anyway, falling back
+    /// to the modules listed in `top_level.txt` for packages without a `RECORD`.
+    ///
+    /// WARNING: Without a `RECORD`, `uv` can't identify all files that belong to a package, such
+    /// that some (like scripts or data files) may be left behind.
+    #[clap(long)]
+    pub(crate) force: bool,
+
+    /// Also uninstall any dependencies that are no longer required by another package.
+    ///
+    /// Dependencies are only removed if they weren't requested directly (i.e., if they were
+    /// installed as a dependency of another package, as indicated by the absence of a `REQUESTED`
+    /// file).
+    #[clap(long)]
+    pub(crate) auto_remove: bool,
+
     /// Run offline, i.e., without accessing the network.
     #[arg(global = true, long)]
     pub(crate) offline: bool,
@@ -1033,6 +1883,14 @@ pub(crate) struct PipFreezeArgs {
     #[clap(long)]
     pub(crate) strict: bool,
 
+    /// Restrict to the packages installed in the given `site-packages` directory.
+    ///
+    /// May be provided multiple times, e.g., to include the system `dist-packages`, the user site,
+    /// and a `--target` directory. When multiple directories are provided, each package is
+    /// annotated with the directory that contains it and the tool that installed it.
+    #[clap(long = "path", value_name = "DIR")]
+    pub(crate) paths: Vec<PathBuf>,
+
     /// The Python interpreter for which packages should be listed.
     ///
     /// By default, `uv` lists packages in the currently activated virtual environment, or a virtual
@@ -1075,7 +1933,22 @@ pub(crate) struct PipListArgs {
     #[clap(long)]
     pub(crate) r#exclude: Vec<PackageName>,
 
+    /// Only include packages that aren't required by any other installed package.
+    #[clap(long)]
+    pub(crate) not_required: bool,
+
+    /// Only include packages that were requested directly by the user, as opposed to those that
+    /// were installed as a dependency of another package.
+    ///
+    /// Packages are considered requested if their `.dist-info` directory contains a `REQUESTED`
+    /// file.
+    #[clap(long)]
+    pub(crate) user_requested: bool,
+
     /// Select the output format between: `columns` (default), `freeze`, or `json`.
+    ///
+    /// With `--verbose`, the `json` format also includes the location, installer, and
+    /// `direct_url.json` contents of each package.
     #[clap(long, value_enum, default_value_t = ListFormat::default())]
     pub(crate) format: ListFormat,
 
@@ -1150,6 +2023,18 @@ pub(crate) struct PipShowArgs {
     /// The package(s) to display.
     pub(crate) package: Vec<PackageName>,
 
+    /// Select the output format between: `text` (default) or `json`.
+    #[clap(long, value_enum, default_value_t = ShowFormat::default())]
+    pub(crate) format: ShowFormat,
+
+    /// Show the full list of installed files for each package.
+    ///
+    /// With `--verbose`, each file is verified against the hash and size recorded in the
+    /// package's `RECORD`, and any files that were modified or removed since installation are
+    /// flagged.
+    #[clap(short, long)]
+    pub(crate) files: bool,
+
     /// Validate the virtual environment, to detect packages with missing dependencies or other
     /// issues.
     #[clap(long)]
@@ -1239,6 +2124,45 @@ pub(crate) struct VenvArgs {
     #[clap(long)]
     pub(crate) system_site_packages: bool,
 
+    /// Revoke the virtual environment's access to the system site packages directory.
+    ///
+    /// Only applicable with `--upgrade-settings`, as new virtual environments don't have access to
+    /// the system site packages directory by default.
+    #[clap(
+        long,
+        conflicts_with = "system_site_packages",
+        requires = "upgrade_settings"
+    )]
+    pub(crate) no_system_site_packages: bool,
+
+    /// The shells for which to generate activation scripts, as a comma-separated list.
+    ///
+    /// By default, activation scripts are generated for all supported shells.
+    #[clap(long, value_enum, value_delimiter = ',', value_name = "SHELLS")]
+    pub(crate) seed_activators: Option<Vec<Activator>>,
+
+    /// Update the settings of an existing virtual environment, rather than recreating it.
+    ///
+    /// Applies `--system-site-packages`, `--no-system-site-packages`, and `--prompt` to the
+    /// environment's `pyvenv.cfg` and activation scripts, leaving any other settings (and the
+    /// installed packages) untouched.
+    #[clap(
+        long,
+        conflicts_with_all = ["python", "seed", "seed_activators", "externally_managed"]
+    )]
+    pub(crate) upgrade_settings: bool,
+
+    /// Mark the virtual environment as externally managed, with the given error message.
+    ///
+    /// Intended for distributors that create virtual environments with `uv`, but manage their
+    /// contents with another tool. `uv` will refuse to modify an externally managed virtual
+    /// environment (displaying the error message) unless `--break-system-packages` is provided.
+    ///
+    /// Like an `EXTERNALLY-MANAGED` Python installation (see PEP 668), the environment is marked
+    /// via an `EXTERNALLY-MANAGED` file, which is written to the root of the environment.
+    #[clap(long, value_name = "MESSAGE")]
+    pub(crate) externally_managed: Option<String>,
+
     /// The method to use when installing packages from the global cache.
     ///
     /// This option is only used for installing seed packages.
@@ -0,0 +1,195 @@
+use std::fmt::Write;
+use std::io::BufRead;
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use owo_colors::OwoColorize;
+use url::Url;
+
+use uv_auth::{CredentialStore, UV_CREDENTIALS_KEY};
+use uv_client::BaseClientBuilder;
+use uv_configuration::KeyringProviderType;
+use uv_fs::Simplified;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Store credentials for an index URL in the credential store or keyring.
+pub(crate) fn auth_login(
+    url: &Url,
+    username: String,
+    password: Option<String>,
+    keyring_provider: KeyringProviderType,
+    netrc: Option<&Path>,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    // If no password was provided, read it from `stdin`, such that it can be piped in without
+    // appearing in the process list or shell history.
+    let password = if let Some(password) = password {
+        password
+    } else {
+        let mut password = String::new();
+        std::io::stdin()
+            .lock()
+            .read_line(&mut password)
+            .context("Failed to read password from stdin")?;
+        password.trim_end_matches(['\r', '\n']).to_string()
+    };
+    if password.is_empty() {
+        return Err(anyhow!("A password is required, via `--password` or stdin"));
+    }
+
+    if let Some(netrc) = netrc {
+        uv_auth::write_netrc_entry(netrc, url, &username, &password)?;
+
+        writeln!(
+            printer.stderr(),
+            "Added credentials for {} to: {}",
+            username.bold(),
+            netrc.user_display().cyan()
+        )?;
+
+        return Ok(ExitStatus::Success);
+    }
+
+    if let Some(keyring) = keyring_provider.to_provider() {
+        keyring.store(url, &username, &password)?;
+
+        writeln!(
+            printer.stderr(),
+            "Stored credentials for {} in the keyring at: {}",
+            username.bold(),
+            url.host_str().unwrap_or_default().cyan()
+        )?;
+
+        return Ok(ExitStatus::Success);
+    }
+
+    let mut store = open_store()?;
+    store.insert(url, username.clone(), password)?;
+    store.save()?;
+
+    writeln!(
+        printer.stderr(),
+        "Stored credentials for {} at: {}",
+        username.bold(),
+        url.origin().ascii_serialization().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Remove the credentials for an index URL from the credential store or keyring.
+pub(crate) fn auth_logout(
+    url: &Url,
+    username: Option<&str>,
+    keyring_provider: KeyringProviderType,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if let Some(keyring) = keyring_provider.to_provider() {
+        let username = username.ok_or_else(|| {
+            anyhow!(
+                "A username is required to remove credentials from the keyring, via `--username`"
+            )
+        })?;
+        keyring.remove(url, username)?;
+
+        writeln!(
+            printer.stderr(),
+            "Removed credentials for {} from the keyring at: {}",
+            username.bold(),
+            url.host_str().unwrap_or_default().cyan()
+        )?;
+
+        return Ok(ExitStatus::Success);
+    }
+
+    let mut store = open_store()?;
+
+    if !store.remove(url) {
+        writeln!(
+            printer.stderr(),
+            "No credentials found for: {}",
+            url.origin().ascii_serialization().cyan()
+        )?;
+        return Ok(ExitStatus::Failure);
+    }
+    store.save()?;
+
+    writeln!(
+        printer.stderr(),
+        "Removed credentials for: {}",
+        url.origin().ascii_serialization().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// List the index URLs (and usernames) in the credential store.
+pub(crate) fn auth_list(printer: Printer) -> Result<ExitStatus> {
+    let store = open_store()?;
+
+    let mut entries = store.iter().peekable();
+    if entries.peek().is_none() {
+        writeln!(
+            printer.stderr(),
+            "No credentials found in: {}",
+            store.path().user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    for (origin, username) in entries {
+        writeln!(printer.stdout(), "{origin} {}", username.bold())?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Check that requests to an index URL are authenticated, by sending a `HEAD` request with any
+/// available credentials.
+pub(crate) async fn auth_check(
+    url: &Url,
+    keyring_provider: KeyringProviderType,
+    client_builder: &BaseClientBuilder<'_>,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let client = client_builder.clone().keyring(keyring_provider).build();
+
+    // Avoid echoing any credentials embedded in the URL.
+    let mut display_url = url.clone();
+    let _ = display_url.set_username("");
+    let _ = display_url.set_password(None);
+
+    let response = client
+        .head(url.clone())
+        .send()
+        .await
+        .with_context(|| format!("Failed to send request to: {display_url}"))?;
+    let status = response.status();
+
+    if status.is_success() {
+        writeln!(
+            printer.stderr(),
+            "Authenticated to {} ({})",
+            display_url.cyan(),
+            status.green()
+        )?;
+        Ok(ExitStatus::Success)
+    } else {
+        writeln!(
+            printer.stderr(),
+            "Failed to authenticate to {} ({})",
+            display_url.cyan(),
+            status.red()
+        )?;
+        Ok(ExitStatus::Failure)
+    }
+}
+
+/// Open the credential store, which requires a passphrase.
+fn open_store() -> Result<CredentialStore> {
+    CredentialStore::from_env()?.ok_or_else(|| {
+        anyhow!("Set `{UV_CREDENTIALS_KEY}` to a passphrase to use the credential store")
+    })
+}
@@ -0,0 +1,67 @@
+use std::fmt::Write;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+
+use uv_cache::{Cache, CacheAge};
+use uv_fs::Simplified;
+
+use crate::commands::{human_readable_bytes, ExitStatus};
+use crate::printer::Printer;
+
+/// Compress any unzipped wheels that haven't been used within the given age.
+pub(crate) fn cache_compress(
+    max_age: CacheAge,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if !cache.root().exists() {
+        writeln!(
+            printer.stderr(),
+            "No cache found at: {}",
+            cache.root().user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Compressing cache at: {}",
+        cache.root().user_display().cyan()
+    )?;
+
+    let summary = cache.compress(max_age).with_context(|| {
+        format!(
+            "Failed to compress cache at: {}",
+            cache.root().user_display()
+        )
+    })?;
+
+    // Write a summary of the number of archives compressed.
+    match summary.num_archives {
+        0 => {
+            write!(printer.stderr(), "No unused entries found")?;
+        }
+        1 => {
+            write!(printer.stderr(), "Compressed 1 entry")?;
+        }
+        num_archives => {
+            write!(printer.stderr(), "Compressed {num_archives} entries")?;
+        }
+    }
+
+    // If any, write a summary of the total byte count reclaimed.
+    if summary.total_bytes > 0 {
+        let bytes = if summary.total_bytes < 1024 {
+            format!("{}B", summary.total_bytes)
+        } else {
+            let (bytes, unit) = human_readable_bytes(summary.total_bytes);
+            format!("{bytes:.1}{unit}")
+        };
+        write!(printer.stderr(), " ({} saved)", bytes.green())?;
+    }
+
+    writeln!(printer.stderr())?;
+
+    Ok(ExitStatus::Success)
+}
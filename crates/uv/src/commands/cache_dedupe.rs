@@ -0,0 +1,63 @@
+use std::fmt::Write;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+
+use uv_cache::Cache;
+use uv_fs::Simplified;
+
+use crate::commands::{human_readable_bytes, ExitStatus};
+use crate::printer::Printer;
+
+/// Replace identical files in the cache with hard links to a single copy.
+pub(crate) fn cache_dedupe(cache: &Cache, printer: Printer) -> Result<ExitStatus> {
+    if !cache.root().exists() {
+        writeln!(
+            printer.stderr(),
+            "No cache found at: {}",
+            cache.root().user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Deduplicating cache at: {}",
+        cache.root().user_display().cyan()
+    )?;
+
+    let summary = cache.dedupe().with_context(|| {
+        format!(
+            "Failed to deduplicate cache at: {}",
+            cache.root().user_display()
+        )
+    })?;
+
+    // Write a summary of the number of files linked.
+    match summary.num_files {
+        0 => {
+            write!(printer.stderr(), "No duplicate files found")?;
+        }
+        1 => {
+            write!(printer.stderr(), "Linked 1 file")?;
+        }
+        num_files => {
+            write!(printer.stderr(), "Linked {num_files} files")?;
+        }
+    }
+
+    // If any, write a summary of the total byte count reclaimed.
+    if summary.total_bytes > 0 {
+        let bytes = if summary.total_bytes < 1024 {
+            format!("{}B", summary.total_bytes)
+        } else {
+            let (bytes, unit) = human_readable_bytes(summary.total_bytes);
+            format!("{bytes:.1}{unit}")
+        };
+        write!(printer.stderr(), " ({} saved)", bytes.green())?;
+    }
+
+    writeln!(printer.stderr())?;
+
+    Ok(ExitStatus::Success)
+}
@@ -0,0 +1,148 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use serde::Serialize;
+
+use uv_cache::{BucketInfo, Cache, CacheStats, EntryInfo};
+use uv_fs::Simplified;
+
+use crate::commands::{human_readable_bytes, ExitStatus};
+use crate::printer::Printer;
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub(crate) enum CacheInfoFormat {
+    /// Display a human-readable summary of the cache.
+    #[default]
+    Text,
+    /// Display the summary in a machine-readable JSON format.
+    Json,
+}
+
+/// Summarize the contents of the cache.
+pub(crate) fn cache_info(
+    format: CacheInfoFormat,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if !cache.root().exists() {
+        writeln!(
+            printer.stderr(),
+            "No cache found at: {}",
+            cache.root().user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let info = cache
+        .info()
+        .with_context(|| format!("Failed to read cache at: {}", cache.root().user_display()))?;
+    let stats = cache.stats().with_context(|| {
+        format!(
+            "Failed to read cache statistics at: {}",
+            cache.root().user_display()
+        )
+    })?;
+
+    match format {
+        CacheInfoFormat::Json => {
+            let output = serde_json::to_string(&Report {
+                root: cache.root(),
+                size: info.size(),
+                buckets: &info.buckets,
+                largest: &info.largest,
+                stats: &stats,
+            })?;
+            writeln!(printer.stdout(), "{output}")?;
+        }
+        CacheInfoFormat::Text => {
+            writeln!(
+                printer.stdout(),
+                "{}: {}",
+                "Cache directory".bold(),
+                cache.root().user_display().cyan()
+            )?;
+            writeln!(
+                printer.stdout(),
+                "{}: {}",
+                "Total size".bold(),
+                format_bytes(info.size())
+            )?;
+
+            writeln!(printer.stdout())?;
+            writeln!(printer.stdout(), "{}:", "Buckets".bold())?;
+            let width = info
+                .buckets
+                .iter()
+                .map(|bucket| bucket.name.len())
+                .max()
+                .unwrap_or_default();
+            for bucket in &info.buckets {
+                writeln!(
+                    printer.stdout(),
+                    "  {:width$}  {:>10}  ({} files)",
+                    bucket.name,
+                    format_bytes(bucket.size),
+                    bucket.files,
+                )?;
+            }
+
+            if !info.largest.is_empty() {
+                writeln!(printer.stdout())?;
+                writeln!(printer.stdout(), "{}:", "Largest entries".bold())?;
+                for entry in &info.largest {
+                    let name = entry
+                        .name
+                        .clone()
+                        .unwrap_or_else(|| entry.path.user_display().to_string());
+                    writeln!(
+                        printer.stdout(),
+                        "  {:>10}  {name}",
+                        format_bytes(entry.size)
+                    )?;
+                }
+            }
+
+            if !stats.commands.is_empty() {
+                let hits = stats.hits();
+                let misses = stats.misses();
+                writeln!(printer.stdout())?;
+                #[allow(clippy::cast_precision_loss)]
+                let rate = if hits + misses == 0 {
+                    100.0
+                } else {
+                    hits as f64 / (hits + misses) as f64 * 100.0
+                };
+                writeln!(
+                    printer.stdout(),
+                    "{}: {rate:.1}% ({hits} hits, {misses} misses over the last {} commands)",
+                    "Hit rate".bold(),
+                    stats.commands.len()
+                )?;
+            }
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// The JSON representation of the cache summary.
+#[derive(Serialize)]
+struct Report<'a> {
+    root: &'a Path,
+    size: u64,
+    buckets: &'a [BucketInfo],
+    largest: &'a [EntryInfo],
+    stats: &'a CacheStats,
+}
+
+/// Format a byte count as a human-readable string.
+fn format_bytes(bytes: u64) -> String {
+    if bytes < 1024 {
+        format!("{bytes}B")
+    } else {
+        let (bytes, unit) = human_readable_bytes(bytes);
+        format!("{bytes:.1}{unit}")
+    }
+}
@@ -3,14 +3,21 @@ use std::fmt::Write;
 use anyhow::{Context, Result};
 use owo_colors::OwoColorize;
 
-use uv_cache::Cache;
+use uv_cache::{Cache, CacheAge};
 use uv_fs::Simplified;
 
 use crate::commands::{human_readable_bytes, ExitStatus};
 use crate::printer::Printer;
 
 /// Prune all unreachable objects from the cache.
-pub(crate) fn cache_prune(cache: &Cache, printer: Printer) -> Result<ExitStatus> {
+///
+/// If a maximum age is provided, any unzipped wheels that haven't been used within that age are
+/// removed as well.
+pub(crate) fn cache_prune(
+    max_age: Option<CacheAge>,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
     if !cache.root().exists() {
         writeln!(
             printer.stderr(),
@@ -26,10 +33,16 @@ pub(crate) fn cache_prune(cache: &Cache, printer: Printer) -> Result<ExitStatus>
         cache.root().user_display().cyan()
     )?;
 
-    let summary = cache
+    let mut summary = cache
         .prune()
         .with_context(|| format!("Failed to prune cache at: {}", cache.root().user_display()))?;
 
+    if let Some(max_age) = max_age {
+        summary += cache.evict_older_than(max_age).with_context(|| {
+            format!("Failed to prune cache at: {}", cache.root().user_display())
+        })?;
+    }
+
     // Write a summary of the number of files and directories removed.
     match (summary.num_files, summary.num_dirs) {
         (0, 0) => {
@@ -0,0 +1,184 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use fs_err as fs;
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use install_wheel_rs::{installed_files, FileStatus};
+use uv_cache::Cache;
+use uv_fs::Simplified;
+
+use crate::commands::{human_readable_bytes, ExitStatus};
+use crate::printer::Printer;
+
+/// Verify the integrity of the cache, re-hashing the files of every unzipped wheel against the
+/// digests recorded in its `RECORD`, and checking that every link into the archive bucket
+/// resolves.
+///
+/// If `prune` is set, any corrupt entries are removed from the cache, such that they're re-fetched
+/// on next use.
+pub(crate) fn cache_verify(prune: bool, cache: &Cache, printer: Printer) -> Result<ExitStatus> {
+    if !cache.root().exists() {
+        writeln!(
+            printer.stderr(),
+            "No cache found at: {}",
+            cache.root().user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Verifying cache at: {}",
+        cache.root().user_display().cyan()
+    )?;
+
+    let context = || format!("Failed to verify cache at: {}", cache.root().user_display());
+
+    // Verify each archive against its `RECORD`.
+    let archives = cache.archives().with_context(context)?;
+    let mut corrupt = Vec::new();
+    for archive in &archives {
+        debug!("Verifying cache entry: {}", archive.display());
+        if let Some(problem) = verify_archive(archive) {
+            writeln!(
+                printer.stderr(),
+                "{}: {problem}",
+                archive.user_display().bold()
+            )?;
+            corrupt.push(archive.clone());
+        }
+    }
+
+    // Find any links to archives that no longer exist.
+    let dangling = cache.dangling_links().with_context(context)?;
+    for link in &dangling {
+        writeln!(
+            printer.stderr(),
+            "{}: links to a missing archive",
+            link.user_display().bold()
+        )?;
+    }
+
+    let s = if archives.len() == 1 { "" } else { "s" };
+    write!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Verified {}",
+            format!("{} archive{s}", archives.len()).bold()
+        )
+        .dimmed()
+    )?;
+
+    let problems = corrupt.len() + dangling.len();
+    if problems == 0 {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            "; no corrupt entries found".dimmed()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let s = if problems == 1 { "y" } else { "ies" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!("; found {}", format!("{problems} corrupt entr{s}").bold()).dimmed()
+    )?;
+
+    if !prune {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            "Run with `--prune` to remove the corrupt entries".dimmed()
+        )?;
+        return Ok(ExitStatus::Failure);
+    }
+
+    let summary = cache
+        .remove_archives(&corrupt)
+        .with_context(|| format!("Failed to prune cache at: {}", cache.root().user_display()))?;
+
+    // Write a summary of the number of files removed.
+    match summary.num_files {
+        0 => {
+            write!(printer.stderr(), "No files removed")?;
+        }
+        1 => {
+            write!(printer.stderr(), "Removed 1 file")?;
+        }
+        num_files => {
+            write!(printer.stderr(), "Removed {num_files} files")?;
+        }
+    }
+
+    // If any, write a summary of the total byte count removed.
+    if summary.total_bytes > 0 {
+        let bytes = if summary.total_bytes < 1024 {
+            format!("{}B", summary.total_bytes)
+        } else {
+            let (bytes, unit) = human_readable_bytes(summary.total_bytes);
+            format!("{bytes:.1}{unit}")
+        };
+        write!(printer.stderr(), " ({})", bytes.green())?;
+    }
+
+    writeln!(printer.stderr())?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Verify an unzipped wheel against the `RECORD` of its `.dist-info` directory, returning a
+/// description of the problem if the archive is corrupt.
+fn verify_archive(archive: &Path) -> Option<String> {
+    let dist_info = match find_dist_info(archive) {
+        Ok(Some(dist_info)) => dist_info,
+        Ok(None) => return Some("missing `.dist-info` directory".to_string()),
+        Err(err) => return Some(format!("failed to read archive ({err})")),
+    };
+
+    let files = match installed_files(&dist_info, true) {
+        Ok(files) => files,
+        Err(install_wheel_rs::Error::MissingRecord(_)) => {
+            return Some("missing `RECORD`".to_string())
+        }
+        Err(err) => return Some(format!("failed to read `RECORD` ({err})")),
+    };
+
+    let mut modified = files
+        .into_iter()
+        .filter_map(|file| match file.status {
+            Some(FileStatus::Modified) => Some(format!("{} (modified)", file.path)),
+            Some(FileStatus::Missing) => Some(format!("{} (missing)", file.path)),
+            Some(FileStatus::Unchanged | FileStatus::Unverifiable) | None => None,
+        })
+        .collect::<Vec<_>>();
+    match modified.len() {
+        0 => None,
+        1 => Some(format!("{} doesn't match `RECORD`", modified.remove(0))),
+        len => Some(format!(
+            "{len} files don't match `RECORD`, including {}",
+            modified.remove(0)
+        )),
+    }
+}
+
+/// Find the `.dist-info` directory at the top-level of an unzipped wheel.
+fn find_dist_info(archive: &Path) -> std::io::Result<Option<PathBuf>> {
+    for entry in fs::read_dir(archive)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir()
+            && entry
+                .path()
+                .extension()
+                .is_some_and(|extension| extension == "dist-info")
+        {
+            return Ok(Some(entry.path()));
+        }
+    }
+    Ok(None)
+}
@@ -0,0 +1,376 @@
+use std::env;
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use owo_colors::OwoColorize;
+use tracing::debug;
+use url::Url;
+
+use distribution_types::{IndexLocations, IndexUrl};
+use uv_cache::Cache;
+use uv_client::{BaseClientBuilder, Connectivity};
+use uv_fs::Simplified;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// The maximum difference, in seconds, between the system clock and an index server's clock before
+/// it's reported.
+const MAX_CLOCK_SKEW_SECONDS: i64 = 5 * 60;
+
+/// The result of a single diagnostic check.
+#[derive(Debug)]
+struct Finding {
+    /// The name of the check (e.g., `proxy`).
+    check: &'static str,
+    /// Whether the check found a problem.
+    problem: bool,
+    /// A description of what was found.
+    message: String,
+    /// A suggestion for how to fix the problem, if any.
+    hint: Option<String>,
+}
+
+impl Finding {
+    fn ok(check: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            check,
+            problem: false,
+            message: message.into(),
+            hint: None,
+        }
+    }
+
+    fn problem(check: &'static str, message: impl Into<String>, hint: impl Into<String>) -> Self {
+        Self {
+            check,
+            problem: true,
+            message: message.into(),
+            hint: Some(hint.into()),
+        }
+    }
+}
+
+/// Check the environment for common causes of failures, like misconfigured proxies, TLS
+/// certificates, or clock skew.
+pub(crate) async fn doctor(
+    index_locations: &IndexLocations,
+    client_builder: &BaseClientBuilder<'_>,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let mut findings = Vec::new();
+    findings.extend(check_proxy());
+    findings.push(check_tls(client_builder.is_native_tls()));
+    for index_url in index_locations.indexes() {
+        // Local indexes are read directly, without a request.
+        if index_url.url().scheme() == "file" {
+            continue;
+        }
+        findings.extend(check_index(index_url, client_builder).await);
+    }
+    findings.push(check_cache(cache));
+    findings.extend(check_environment());
+    #[cfg(windows)]
+    findings.extend(check_long_paths());
+
+    for finding in &findings {
+        if finding.problem {
+            writeln!(
+                printer.stdout(),
+                "{} {}",
+                format!("{}:", finding.check).yellow().bold(),
+                finding.message
+            )?;
+        } else {
+            writeln!(
+                printer.stdout(),
+                "{} {}",
+                format!("{}:", finding.check).green().bold(),
+                finding.message
+            )?;
+        }
+        if let Some(hint) = &finding.hint {
+            writeln!(printer.stdout(), "  {} {hint}", "hint:".dimmed())?;
+        }
+    }
+
+    let problems = findings.iter().filter(|finding| finding.problem).count();
+    if problems == 0 {
+        writeln!(printer.stderr(), "{}", "No problems found".dimmed())?;
+        Ok(ExitStatus::Success)
+    } else {
+        let s = if problems == 1 { "" } else { "s" };
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!("Found {}", format!("{problems} problem{s}").bold()).dimmed()
+        )?;
+        Ok(ExitStatus::Failure)
+    }
+}
+
+/// Check the proxy environment variables for invalid or inconsistent values.
+fn check_proxy() -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let mut proxies = Vec::new();
+
+    for name in ["HTTPS_PROXY", "HTTP_PROXY", "ALL_PROXY", "NO_PROXY"] {
+        let upper = env::var(name).ok().filter(|value| !value.is_empty());
+        let lower = env::var(name.to_lowercase())
+            .ok()
+            .filter(|value| !value.is_empty());
+
+        // Different tools disagree on which spelling takes precedence.
+        if let (Some(upper), Some(lower)) = (&upper, &lower) {
+            if upper != lower {
+                findings.push(Finding::problem(
+                    "proxy",
+                    format!(
+                        "`{name}` and `{}` have different values",
+                        name.to_lowercase()
+                    ),
+                    format!("Unset one of `{name}` or `{}`", name.to_lowercase()),
+                ));
+            }
+        }
+
+        let Some(value) = upper.or(lower) else {
+            continue;
+        };
+        if name == "NO_PROXY" {
+            continue;
+        }
+        match Url::parse(&value) {
+            Ok(url) => proxies.push(format!("{name}={}", redact(&url))),
+            Err(err) => findings.push(Finding::problem(
+                "proxy",
+                format!("`{name}` is not a valid URL: {err}"),
+                format!("Set `{name}` to a URL like `http://proxy.example.com:8080`"),
+            )),
+        }
+    }
+
+    if findings.is_empty() {
+        if proxies.is_empty() {
+            findings.push(Finding::ok("proxy", "No proxy configured"));
+        } else {
+            findings.push(Finding::ok(
+                "proxy",
+                format!("Using {}", proxies.join(", ")),
+            ));
+        }
+    }
+
+    findings
+}
+
+/// Check which TLS certificates are in use.
+fn check_tls(native_tls: bool) -> Finding {
+    if let Some(path) = env::var_os("SSL_CERT_FILE") {
+        let path = Path::new(&path);
+        return if path.exists() {
+            Finding::ok(
+                "tls",
+                format!(
+                    "Using certificates from `SSL_CERT_FILE` at: {}",
+                    path.user_display()
+                ),
+            )
+        } else {
+            Finding::problem(
+                "tls",
+                format!(
+                    "`SSL_CERT_FILE` is set to a missing file, and will be ignored: {}",
+                    path.user_display()
+                ),
+                "Set `SSL_CERT_FILE` to an existing certificate bundle, or unset it",
+            )
+        };
+    }
+
+    if native_tls {
+        Finding::ok("tls", "Using the platform's native certificate store")
+    } else {
+        Finding::ok("tls", "Using the bundled `webpki-roots` certificates")
+    }
+}
+
+/// Check that the index is reachable, and that the system clock agrees with the index's.
+async fn check_index(index_url: &IndexUrl, client_builder: &BaseClientBuilder<'_>) -> Vec<Finding> {
+    let client = client_builder
+        .clone()
+        .connectivity(Connectivity::Online)
+        .retries(0)
+        .build();
+
+    let url = redact(index_url.url());
+    debug!("Checking connectivity to: {url}");
+    let response = match client.head(index_url.url().clone()).send().await {
+        Ok(response) => response,
+        Err(err) => {
+            // Surface the root cause, which is typically more informative (e.g., an unknown
+            // certificate issuer, or a refused connection).
+            let cause = anyhow::Error::from(err)
+                .chain()
+                .last()
+                .map(ToString::to_string)
+                .unwrap_or_default();
+            let hint = if cause.to_lowercase().contains("certificate") {
+                "If you're behind a proxy with a custom trust root, use `--native-tls` or set `SSL_CERT_FILE`"
+            } else {
+                "Check your network connection and proxy settings"
+            };
+            return vec![Finding::problem(
+                "network",
+                format!("Failed to reach {url}: {cause}"),
+                hint,
+            )];
+        }
+    };
+
+    let mut findings = vec![if response.status().is_success() {
+        Finding::ok("network", format!("Reached {url} ({})", response.status()))
+    } else {
+        Finding::problem(
+            "network",
+            format!("Received {} from {url}", response.status()),
+            "Check the index URL and your credentials (e.g., with `uv auth check`)",
+        )
+    }];
+
+    // Compare the server's `Date` header to the system clock.
+    if let Some(date) = response
+        .headers()
+        .get("date")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| DateTime::parse_from_rfc2822(value).ok())
+    {
+        let skew = Utc::now().signed_duration_since(date);
+        if skew.num_seconds().abs() > MAX_CLOCK_SKEW_SECONDS {
+            findings.push(Finding::problem(
+                "clock",
+                format!(
+                    "The system clock differs from {url} by {} seconds",
+                    skew.num_seconds()
+                ),
+                "Synchronize the system clock, as certificate validation and caching depend on it",
+            ));
+        } else {
+            findings.push(Finding::ok(
+                "clock",
+                format!("The system clock agrees with {url}"),
+            ));
+        }
+    }
+
+    findings
+}
+
+/// Check that the cache directory is writable.
+fn check_cache(cache: &Cache) -> Finding {
+    let result = fs_err::create_dir_all(cache.root())
+        .and_then(|()| tempfile::NamedTempFile::new_in(cache.root()).map(drop));
+    match result {
+        Ok(()) => Finding::ok(
+            "cache",
+            format!("Writable at: {}", cache.root().user_display()),
+        ),
+        Err(err) => Finding::problem(
+            "cache",
+            format!("Not writable at: {} ({err})", cache.root().user_display()),
+            "Use `--cache-dir` (or `UV_CACHE_DIR`) to select a writable directory",
+        ),
+    }
+}
+
+/// Check for conflicting or stale environment activations.
+fn check_environment() -> Vec<Finding> {
+    let virtual_env = env::var_os("VIRTUAL_ENV").filter(|value| !value.is_empty());
+    let conda_prefix = env::var_os("CONDA_PREFIX").filter(|value| !value.is_empty());
+
+    let mut findings = Vec::new();
+    if let (Some(virtual_env), Some(conda_prefix)) = (&virtual_env, &conda_prefix) {
+        findings.push(Finding::problem(
+            "environment",
+            format!(
+                "Both `VIRTUAL_ENV` ({}) and `CONDA_PREFIX` ({}) are set; `VIRTUAL_ENV` takes precedence",
+                Path::new(virtual_env).user_display(),
+                Path::new(conda_prefix).user_display()
+            ),
+            "Deactivate one of the environments (e.g., with `deactivate` or `conda deactivate`)",
+        ));
+    }
+    for (name, value) in [
+        ("VIRTUAL_ENV", &virtual_env),
+        ("CONDA_PREFIX", &conda_prefix),
+    ] {
+        if let Some(path) = value {
+            if !Path::new(path).is_dir() {
+                findings.push(Finding::problem(
+                    "environment",
+                    format!(
+                        "`{name}` is set to a missing directory: {}",
+                        Path::new(path).user_display()
+                    ),
+                    format!("Unset `{name}`, or recreate the environment"),
+                ));
+            }
+        }
+    }
+
+    if findings.is_empty() {
+        if let Some(path) = virtual_env.or(conda_prefix) {
+            findings.push(Finding::ok(
+                "environment",
+                format!(
+                    "Using the active environment at: {}",
+                    Path::new(&path).user_display()
+                ),
+            ));
+        } else {
+            findings.push(Finding::ok("environment", "No environment is active"));
+        }
+    }
+
+    findings
+}
+
+/// Check whether paths longer than `MAX_PATH` (260 characters) are supported, as cached and
+/// installed packages can be nested deeply.
+#[cfg(windows)]
+fn check_long_paths() -> Option<Finding> {
+    let output = std::process::Command::new("reg")
+        .args([
+            "query",
+            r"HKLM\SYSTEM\CurrentControlSet\Control\FileSystem",
+            "/v",
+            "LongPathsEnabled",
+        ])
+        .output()
+        .ok()?;
+    let enabled = output.status.success()
+        && String::from_utf8_lossy(&output.stdout)
+            .split_whitespace()
+            .last()
+            == Some("0x1");
+    Some(if enabled {
+        Finding::ok("paths", "Long paths are enabled")
+    } else {
+        Finding::problem(
+            "paths",
+            "Long paths are disabled, so paths longer than 260 characters will fail",
+            "Set `LongPathsEnabled` to `1` under `HKLM\\SYSTEM\\CurrentControlSet\\Control\\FileSystem`",
+        )
+    })
+}
+
+/// Remove any credentials from a URL.
+fn redact(url: &Url) -> Url {
+    let mut url = url.clone();
+    let _ = url.set_username("");
+    let _ = url.set_password(None);
+    url
+}
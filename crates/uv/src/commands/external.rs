@@ -0,0 +1,63 @@
+use std::env;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_interpreter::PythonEnvironment;
+use uv_workspace::Workspace;
+
+use crate::commands::ExitStatus;
+
+/// Find the executable for an external `uv-<name>` command on the `PATH`, if any.
+pub(crate) fn find_external(name: &str) -> Option<PathBuf> {
+    which::which(format!("uv-{name}")).ok()
+}
+
+/// Run an external `uv-<name>` command, forwarding the remaining arguments.
+///
+/// The command receives the context of the current invocation via environment variables:
+///
+/// - `UV`: the path to the `uv` executable.
+/// - `UV_CACHE_DIR`: the path to the cache directory.
+/// - `UV_PROJECT_ROOT`: the directory containing the `uv.toml` or `pyproject.toml` file that
+///   configures the current project, if any.
+/// - `UV_ENVIRONMENT`: the path to the active virtual environment, if any.
+pub(crate) fn external(
+    executable: &Path,
+    args: &[OsString],
+    workspace: Option<&Workspace>,
+    cache: &Cache,
+) -> Result<ExitStatus> {
+    let mut command = std::process::Command::new(executable);
+    command.args(args);
+
+    command.env("UV", env::current_exe()?);
+    command.env("UV_CACHE_DIR", cache.root());
+    if let Some(workspace) = workspace {
+        command.env("UV_PROJECT_ROOT", workspace.root());
+    }
+    match PythonEnvironment::from_virtualenv(cache) {
+        Ok(venv) => {
+            command.env("UV_ENVIRONMENT", venv.root());
+        }
+        Err(err) => {
+            debug!("No virtual environment found for external command: {err}");
+        }
+    }
+
+    debug!("Running external command: {}", executable.display());
+    let status = command
+        .status()
+        .with_context(|| format!("Failed to run `{}`", executable.display()))?;
+
+    // Propagate the exit code of the external command. If it was terminated by a signal, there's
+    // no exit code to propagate.
+    match status.code() {
+        Some(0) => Ok(ExitStatus::Success),
+        Some(code) => Ok(ExitStatus::External(u8::try_from(code).unwrap_or(1))),
+        None => Ok(ExitStatus::Error),
+    }
+}
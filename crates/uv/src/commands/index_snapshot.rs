@@ -0,0 +1,498 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::{Component, Path};
+
+use anyhow::{anyhow, bail, Context, Result};
+use owo_colors::OwoColorize;
+use serde_json::json;
+use tracing::debug;
+use url::Url;
+
+use distribution_filename::DistFilename;
+use distribution_types::{File, FileLocation, IndexLocations};
+use pep440_rs::Operator;
+use pep508_rs::{RequirementsTxtRequirement, VersionOrUrl};
+use pypi_types::{HashAlgorithm, HashDigest};
+use uv_cache::Cache;
+use uv_client::{
+    BaseClientBuilder, Connectivity, OwnedArchive, RegistryClient, RegistryClientBuilder,
+};
+use uv_configuration::{IndexStrategy, KeyringProviderType};
+use uv_extract::hash::Hasher;
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_requirements::{RequirementsSource, RequirementsSpecification};
+use uv_warnings::warn_user;
+
+use crate::commands::{elapsed, ExitStatus};
+use crate::printer::Printer;
+
+/// Materialize a static PEP 503 (and PEP 691) index containing the distributions pinned by a set
+/// of locked requirements.
+///
+/// The index is written to `<output_dir>/simple`, with each file stored at
+/// `<output_dir>/files/<sha256>/<filename>`, such that the directory can be served by any static
+/// file server.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn index_snapshot(
+    sources: &[RequirementsSource],
+    output_dir: &Path,
+    index_locations: IndexLocations,
+    index_strategy: IndexStrategy,
+    keyring_provider: KeyringProviderType,
+    client_builder: &BaseClientBuilder<'_>,
+    cache: Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let start = std::time::Instant::now();
+
+    let client_builder = client_builder
+        .clone()
+        .connectivity(Connectivity::Online)
+        .keyring(keyring_provider);
+
+    // Read all requirements from the provided sources.
+    let RequirementsSpecification {
+        requirements,
+        editables,
+        source_trees,
+        index_url,
+        extra_index_urls,
+        no_index,
+        find_links,
+        ..
+    } = RequirementsSpecification::from_simple_sources(sources, &client_builder).await?;
+
+    if !editables.is_empty() || !source_trees.is_empty() {
+        warn_user!("Editable and local requirements can't be snapshotted, and will be skipped");
+    }
+
+    // Incorporate any index locations from the provided sources.
+    let index_locations =
+        index_locations.combine(index_url, extra_index_urls, find_links, no_index);
+
+    // Initialize the registry client.
+    let client = RegistryClientBuilder::from_base_client(cache, client_builder.clone())
+        .index_urls(index_locations.index_urls())
+        .index_strategy(index_strategy)
+        .build();
+
+    let simple_dir = output_dir.join("simple");
+    let files_dir = output_dir.join("files");
+    fs_err::tokio::create_dir_all(&simple_dir).await?;
+
+    // The files for each project, grouped by name, since a project can appear in multiple
+    // requirements (e.g., with different markers).
+    let mut projects = BTreeMap::<PackageName, Vec<ProjectFile>>::new();
+    let mut files = 0;
+    for entry in requirements {
+        // Direct URL requirements aren't served from an index.
+        let requirement = match entry.requirement {
+            RequirementsTxtRequirement::Pep508(requirement)
+                if !matches!(requirement.version_or_url, Some(VersionOrUrl::Url(_))) =>
+            {
+                requirement
+            }
+            requirement => {
+                warn_user!("Skipping direct URL requirement: {requirement}");
+                continue;
+            }
+        };
+
+        // Every requirement must be pinned to a specific version.
+        let Some(VersionOrUrl::VersionSpecifier(specifiers)) = requirement.version_or_url.as_ref()
+        else {
+            bail!(
+                "Requirement must be pinned to a specific version (e.g., `==1.0.0`): {requirement}"
+            );
+        };
+        let version = match &**specifiers {
+            [specifier] if *specifier.operator() == Operator::Equal => specifier.version().clone(),
+            _ => bail!(
+                "Requirement must be pinned to a specific version (e.g., `==1.0.0`): {requirement}"
+            ),
+        };
+
+        // If the requirement includes hashes, only snapshot the matching files.
+        let required = entry
+            .hashes
+            .iter()
+            .map(|digest| digest.parse::<HashDigest>())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Find the files for the pinned version.
+        let version_files = client
+            .simple(&requirement.name)
+            .await?
+            .into_iter()
+            .find_map(|(_, archive)| {
+                OwnedArchive::deserialize(&archive)
+                    .into_iter()
+                    .find(|datum| datum.version == version)
+            })
+            .with_context(|| {
+                format!(
+                    "Version {version} of `{}` was not found in the index",
+                    requirement.name
+                )
+            })?
+            .files
+            .all()
+            .filter(|(_, file)| {
+                required.is_empty() || file.hashes.iter().any(|hash| required.contains(hash))
+            })
+            .collect::<Vec<_>>();
+        if version_files.is_empty() {
+            bail!("No files matching the required hashes were found for: {requirement}");
+        }
+
+        // Download each file, and record it in the project page.
+        let links = projects.entry(requirement.name.clone()).or_default();
+        for (_, file) in version_files {
+            if links.iter().any(|link| link.file.filename == file.filename) {
+                continue;
+            }
+            validate_filename(&file.filename, &requirement.name)?;
+            let digest = download_file(&client, &file, &files_dir, &required, printer).await?;
+            links.push(ProjectFile {
+                href: format!(
+                    "../../files/{digest}/{}",
+                    urlencoding::encode(&file.filename)
+                ),
+                file,
+                hash: Some(HashDigest {
+                    algorithm: HashAlgorithm::Sha256,
+                    digest: digest.into_boxed_str(),
+                }),
+            });
+            files += 1;
+        }
+    }
+
+    for (name, links) in &projects {
+        write_project_page(&simple_dir, name, links).await?;
+    }
+    write_root_page(&simple_dir).await?;
+
+    let packages = projects.len();
+    let s = if packages == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Snapshotted {} ({} file{}) to {} {}",
+            format!("{packages} package{s}").bold(),
+            files,
+            if files == 1 { "" } else { "s" },
+            simple_dir.user_display().cyan(),
+            format!("in {}", elapsed(start.elapsed())).dimmed()
+        )
+        .dimmed()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// The PEP 629 repository version declaration for HTML pages.
+const REPOSITORY_VERSION: &str = r#"<meta name="pypi:repository-version" content="1.0">"#;
+
+/// A file listed on a project page.
+pub(super) struct ProjectFile {
+    pub(super) file: File,
+    /// The link to the file, relative to the project page.
+    pub(super) href: String,
+    /// The digest to include in the link, if known.
+    pub(super) hash: Option<HashDigest>,
+}
+
+/// Validate that a filename provided by an index is a distribution filename for the given
+/// package.
+///
+/// Index-provided filenames are used as path components, so anything other than a single, normal
+/// path component (e.g., `../../evil.whl`) is rejected.
+pub(super) fn validate_filename(filename: &str, name: &PackageName) -> Result<()> {
+    let mut components = Path::new(filename).components();
+    let single = matches!(
+        (components.next(), components.next()),
+        (Some(Component::Normal(_)), None)
+    );
+    if !single || filename.contains(['/', '\\']) {
+        bail!("Invalid filename provided by the index: `{filename}`");
+    }
+    if DistFilename::try_from_filename(filename, name).is_none() {
+        bail!("Invalid distribution filename provided by the index for `{name}`: `{filename}`");
+    }
+    Ok(())
+}
+
+/// Download a file into the snapshot, returning its SHA-256 digest.
+///
+/// Files are stored by digest, such that existing files are reused across snapshots. The
+/// filename must have been checked with [`validate_filename`].
+async fn download_file(
+    client: &RegistryClient,
+    file: &File,
+    files_dir: &Path,
+    required: &[HashDigest],
+    printer: Printer,
+) -> Result<String> {
+    // If the index provides a SHA-256 digest, and we've already stored the file, reuse it.
+    let known = file
+        .hashes
+        .iter()
+        .find(|hash| hash.algorithm == HashAlgorithm::Sha256)
+        .map(|hash| hash.digest.to_string());
+    if let Some(digest) = known.as_ref() {
+        let path = files_dir.join(digest).join(&file.filename);
+        if path.is_file() {
+            debug!("Reusing snapshotted file: {}", path.user_display());
+            return Ok(digest.clone());
+        }
+    }
+
+    let bytes = match &file.url {
+        FileLocation::RelativeUrl(base, url) => {
+            fetch(client, &pypi_types::base_url_join_relative(base, url)?).await?
+        }
+        FileLocation::AbsoluteUrl(url) => fetch(client, &Url::parse(url)?).await?,
+        FileLocation::Path(path) => fs_err::tokio::read(path).await?,
+    };
+
+    // Verify the file against the digests provided by the index and the requirement.
+    for expected in &file.hashes {
+        let computed = digest(&bytes, expected.algorithm);
+        if computed != *expected {
+            bail!(
+                "Hash mismatch for `{}`\n\nExpected:\n  {expected}\n\nComputed:\n  {computed}",
+                file.filename
+            );
+        }
+    }
+    if !required.is_empty()
+        && !required
+            .iter()
+            .any(|expected| digest(&bytes, expected.algorithm) == *expected)
+    {
+        bail!(
+            "Hash mismatch for `{}`: none of the required hashes matched",
+            file.filename
+        );
+    }
+
+    let digest = digest(&bytes, HashAlgorithm::Sha256).digest.to_string();
+
+    let path = files_dir.join(&digest).join(&file.filename);
+    fs_err::tokio::create_dir_all(path.parent().unwrap()).await?;
+    uv_fs::write_atomic(&path, &bytes).await?;
+    writeln!(
+        printer.stderr(),
+        " {} {}",
+        "+".green(),
+        file.filename.as_str().bold()
+    )?;
+
+    Ok(digest)
+}
+
+/// Compute the digest of the given bytes.
+fn digest(bytes: &[u8], algorithm: HashAlgorithm) -> HashDigest {
+    let mut hasher = Hasher::from(algorithm);
+    hasher.update(bytes);
+    HashDigest::from(hasher)
+}
+
+/// Fetch the contents of the given URL.
+async fn fetch(client: &RegistryClient, url: &Url) -> Result<Vec<u8>> {
+    debug!("Downloading: {url}");
+    let bytes = client
+        .uncached_client()
+        .client()
+        .get(url.clone())
+        .send()
+        .await?
+        .error_for_status()
+        .map_err(uv_client::BetterReqwestError::from)?
+        .bytes()
+        .await
+        .map_err(uv_client::BetterReqwestError::from)?;
+    Ok(bytes.to_vec())
+}
+
+/// Write the PEP 503 (HTML) and PEP 691 (JSON) pages for a project.
+async fn write_project_page(
+    simple_dir: &Path,
+    name: &PackageName,
+    files: &[ProjectFile],
+) -> Result<()> {
+    let project_dir = simple_dir.join(name.as_ref());
+    fs_err::tokio::create_dir_all(&project_dir).await?;
+
+    let html = project_page(name, files)?;
+
+    let json = json!({
+        "meta": { "api-version": "1.0" },
+        "name": name,
+        "files": files
+            .iter()
+            .map(|ProjectFile { file, href, hash }| {
+                json!({
+                    "filename": file.filename,
+                    "url": href,
+                    "hashes": hash
+                        .iter()
+                        .map(|hash| (hash.algorithm.to_string(), json!(hash.digest)))
+                        .collect::<serde_json::Map<_, _>>(),
+                    "requires-python": file.requires_python.as_ref().map(ToString::to_string),
+                    "yanked": file.yanked.as_ref().is_some_and(pypi_types::Yanked::is_yanked),
+                })
+            })
+            .collect::<Vec<_>>(),
+    });
+
+    uv_fs::write_atomic(project_dir.join("index.html"), html).await?;
+    uv_fs::write_atomic(
+        project_dir.join("index.json"),
+        serde_json::to_string_pretty(&json)?,
+    )
+    .await?;
+    Ok(())
+}
+
+/// Render the PEP 503 (HTML) page for a project.
+pub(super) fn project_page(name: &PackageName, files: &[ProjectFile]) -> Result<String> {
+    let mut html = String::new();
+    writeln!(html, "<!DOCTYPE html>")?;
+    writeln!(html, "<html>")?;
+    writeln!(html, "  <head>")?;
+    writeln!(html, "    {REPOSITORY_VERSION}")?;
+    writeln!(html, "    <title>Links for {name}</title>")?;
+    writeln!(html, "  </head>")?;
+    writeln!(html, "  <body>")?;
+    writeln!(html, "    <h1>Links for {name}</h1>")?;
+    for ProjectFile { file, href, hash } in files {
+        let fragment = hash
+            .as_ref()
+            .map(|hash| format!("#{}={}", hash.algorithm, hash.digest))
+            .unwrap_or_default();
+        let requires_python = file
+            .requires_python
+            .as_ref()
+            .map(|specifiers| {
+                format!(
+                    " data-requires-python=\"{}\"",
+                    html_escape::encode_double_quoted_attribute(&specifiers.to_string())
+                )
+            })
+            .unwrap_or_default();
+        let yanked = match file.yanked.as_ref() {
+            Some(pypi_types::Yanked::Reason(reason)) => {
+                format!(
+                    " data-yanked=\"{}\"",
+                    html_escape::encode_double_quoted_attribute(reason)
+                )
+            }
+            Some(pypi_types::Yanked::Bool(true)) => " data-yanked".to_string(),
+            _ => String::new(),
+        };
+        writeln!(
+            html,
+            "    <a href=\"{}{fragment}\"{requires_python}{yanked}>{}</a><br>",
+            html_escape::encode_double_quoted_attribute(href),
+            html_escape::encode_text(&file.filename)
+        )?;
+    }
+    html.push_str("  </body>\n</html>\n");
+    Ok(html)
+}
+
+/// Write the PEP 503 (HTML) and PEP 691 (JSON) root pages, listing every project in the index,
+/// including any from previous snapshots.
+async fn write_root_page(simple_dir: &Path) -> Result<()> {
+    let mut projects = Vec::new();
+    for entry in fs_err::read_dir(simple_dir)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            let name = entry
+                .file_name()
+                .into_string()
+                .map_err(|name| anyhow!("Invalid project directory: {}", name.to_string_lossy()))?;
+            projects.push(name);
+        }
+    }
+    projects.sort_unstable();
+
+    let mut html = String::new();
+    writeln!(html, "<!DOCTYPE html>")?;
+    writeln!(html, "<html>")?;
+    writeln!(html, "  <head>")?;
+    writeln!(html, "    {REPOSITORY_VERSION}")?;
+    writeln!(html, "    <title>Simple index</title>")?;
+    writeln!(html, "  </head>")?;
+    writeln!(html, "  <body>")?;
+    for name in &projects {
+        writeln!(
+            html,
+            "    <a href=\"{}/\">{}</a><br>",
+            html_escape::encode_double_quoted_attribute(name),
+            html_escape::encode_text(name)
+        )?;
+    }
+    html.push_str("  </body>\n</html>\n");
+
+    let json = json!({
+        "meta": { "api-version": "1.0" },
+        "projects": projects
+            .iter()
+            .map(|name| json!({ "name": name }))
+            .collect::<Vec<_>>(),
+    });
+
+    uv_fs::write_atomic(simple_dir.join("index.html"), html).await?;
+    uv_fs::write_atomic(
+        simple_dir.join("index.json"),
+        serde_json::to_string_pretty(&json)?,
+    )
+    .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_normalize::PackageName;
+
+    use super::validate_filename;
+
+    #[test]
+    fn valid_filenames() {
+        let name = PackageName::from_str("iniconfig").unwrap();
+        assert!(validate_filename("iniconfig-2.0.0-py3-none-any.whl", &name).is_ok());
+        assert!(validate_filename("iniconfig-2.0.0.tar.gz", &name).is_ok());
+    }
+
+    #[test]
+    fn traversal_filenames() {
+        let name = PackageName::from_str("iniconfig").unwrap();
+        for filename in [
+            "../../iniconfig-2.0.0-py3-none-any.whl",
+            "iniconfig-2.0.0-py3-none-any/../../../evil.whl",
+            "iniconfig-2.0.0-py3-none-any\\..\\..\\evil.whl",
+            "/etc/iniconfig-2.0.0.tar.gz",
+            "..",
+            "",
+        ] {
+            assert!(
+                validate_filename(filename, &name).is_err(),
+                "expected `{filename}` to be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn foreign_filenames() {
+        let name = PackageName::from_str("iniconfig").unwrap();
+        assert!(validate_filename("flask-3.0.0.tar.gz", &name).is_err());
+        assert!(validate_filename("iniconfig.txt", &name).is_err());
+    }
+}
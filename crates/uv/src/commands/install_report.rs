@@ -0,0 +1,246 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{anyhow, bail, Result};
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+use url::Url;
+
+use distribution_types::{
+    BuiltDist, Dist, DistributionMetadata, FileLocation, Name, ResolvedDist, SourceDist,
+    VersionOrUrl,
+};
+use pep508_rs::MarkerEnvironment;
+use pypi_types::{ArchiveInfo, DirInfo, DirectUrl, HashDigest, Metadata23};
+use uv_installer::BuiltEditable;
+use uv_normalize::{ExtraName, PackageName};
+use uv_resolver::{InMemoryIndex, MetadataResponse, ResolutionGraph};
+
+use crate::printer::Printer;
+
+/// A report of the distributions selected for installation, in the format of pip's installation
+/// report (version 1), as emitted by `pip install --report`.
+///
+/// See: <https://pip.pypa.io/en/stable/reference/installation-report/>
+#[derive(Debug, Serialize)]
+pub(super) struct InstallationReport<'a> {
+    /// The version of the report format.
+    version: &'static str,
+    /// The version of the tool that generated the report. pip uses its own version here; we use
+    /// uv's.
+    pip_version: &'static str,
+    /// The distributions that were selected for installation.
+    install: Vec<InstallationReportItem>,
+    /// The marker environment of the target interpreter.
+    environment: &'a MarkerEnvironment,
+}
+
+/// A distribution selected for installation.
+#[derive(Debug, Serialize)]
+struct InstallationReportItem {
+    /// The location of the distribution, in the format of `direct_url.json`.
+    download_info: DirectUrl,
+    /// Whether the distribution was requested via a direct URL or path, rather than a registry.
+    is_direct: bool,
+    /// Whether the selected file was yanked from the registry.
+    is_yanked: bool,
+    /// Whether the distribution was requested directly, rather than as a dependency.
+    requested: bool,
+    /// The extras that were requested for the distribution, if it was requested directly.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    requested_extras: Option<Vec<ExtraName>>,
+    /// The core metadata of the distribution.
+    metadata: ReportMetadata,
+}
+
+/// The core metadata of a distribution, in the JSON-compatible form described in PEP 566.
+///
+/// Only the fields that are read during resolution are included.
+#[derive(Debug, Serialize)]
+struct ReportMetadata {
+    name: PackageName,
+    version: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    requires_dist: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    requires_python: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    provides_extra: Vec<ExtraName>,
+}
+
+impl From<&Metadata23> for ReportMetadata {
+    fn from(metadata: &Metadata23) -> Self {
+        Self {
+            name: metadata.name.clone(),
+            version: metadata.version.to_string(),
+            requires_dist: metadata
+                .requires_dist
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            requires_python: metadata.requires_python.as_ref().map(ToString::to_string),
+            provides_extra: metadata.provides_extras.clone(),
+        }
+    }
+}
+
+impl<'a> InstallationReport<'a> {
+    /// Create a report from the given resolution.
+    ///
+    /// Distributions that are already installed (and will be left as-is) are omitted, as in pip.
+    /// The `requested` map contains the packages that were requested directly, along with any
+    /// extras that were requested for each.
+    pub(super) fn from_resolution(
+        resolution: &ResolutionGraph,
+        requested: &FxHashMap<PackageName, Vec<ExtraName>>,
+        editables: &[BuiltEditable],
+        index: &InMemoryIndex,
+        markers: &'a MarkerEnvironment,
+    ) -> Result<Self> {
+        let mut install = resolution
+            .petgraph()
+            .node_weights()
+            .filter_map(|dist| match dist {
+                ResolvedDist::Installable(dist) => Some(dist),
+                ResolvedDist::Installed(_) => None,
+            })
+            .map(|dist| {
+                // Editables aren't part of the index, since they're built prior to resolution.
+                let (metadata, hashes) = if let Some(editable) = editables
+                    .iter()
+                    .find(|editable| &editable.metadata.name == dist.name())
+                {
+                    (editable.metadata.clone(), vec![])
+                } else {
+                    match index.get_metadata(&dist.version_id()).as_deref() {
+                        Some(MetadataResponse::Found(archive)) => {
+                            (archive.metadata.clone(), archive.hashes.clone())
+                        }
+                        _ => bail!("Missing metadata for: {dist}"),
+                    }
+                };
+
+                let extras = requested.get(dist.name());
+                Ok(InstallationReportItem {
+                    download_info: download_info(dist, &hashes)?,
+                    is_direct: dist.file().is_none(),
+                    is_yanked: dist
+                        .file()
+                        .and_then(|file| file.yanked.as_ref())
+                        .is_some_and(pypi_types::Yanked::is_yanked),
+                    requested: extras.is_some(),
+                    requested_extras: extras.filter(|extras| !extras.is_empty()).map(|extras| {
+                        let mut extras = extras.clone();
+                        extras.sort_unstable();
+                        extras.dedup();
+                        extras
+                    }),
+                    metadata: ReportMetadata::from(&metadata),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        install.sort_unstable_by(|a, b| a.metadata.name.cmp(&b.metadata.name));
+
+        Ok(Self {
+            version: "1",
+            pip_version: env!("CARGO_PKG_VERSION"),
+            install,
+            environment: markers,
+        })
+    }
+
+    /// Write the report to the given path, or to stdout if the path is `-`.
+    pub(super) fn write(&self, path: &Path, printer: Printer) -> Result<()> {
+        let report = serde_json::to_string_pretty(self)?;
+        if path == Path::new("-") {
+            writeln!(printer.stdout(), "{report}")?;
+        } else {
+            fs_err::write(path, report)?;
+        }
+        Ok(())
+    }
+}
+
+/// Return the `download_info` for a distribution, in the format of `direct_url.json`.
+fn download_info(dist: &Dist, hashes: &[HashDigest]) -> Result<DirectUrl> {
+    // For registry distributions, point to the selected file.
+    if let Some(file) = dist.file() {
+        let url = match &file.url {
+            FileLocation::RelativeUrl(base, url) => pypi_types::base_url_join_relative(base, url)?,
+            FileLocation::AbsoluteUrl(url) => Url::parse(url)?,
+            FileLocation::Path(path) => Url::from_file_path(path)
+                .map_err(|()| anyhow!("Invalid file path: {}", path.display()))?,
+        };
+        let hashes = if file.hashes.is_empty() {
+            hashes
+        } else {
+            &file.hashes
+        };
+        return Ok(DirectUrl::ArchiveUrl {
+            url: url.to_string(),
+            archive_info: archive_info(hashes),
+            subdirectory: None,
+        });
+    }
+
+    match dist {
+        // Local archives are represented as archives, rather than directories.
+        Dist::Built(BuiltDist::Path(wheel)) => Ok(DirectUrl::ArchiveUrl {
+            url: wheel.url.raw().to_string(),
+            archive_info: archive_info(hashes),
+            subdirectory: None,
+        }),
+        Dist::Source(SourceDist::Path(sdist)) if !sdist.path.is_dir() => {
+            Ok(DirectUrl::ArchiveUrl {
+                url: sdist.url.raw().to_string(),
+                archive_info: archive_info(hashes),
+                subdirectory: None,
+            })
+        }
+        Dist::Source(SourceDist::Path(sdist)) => Ok(DirectUrl::LocalDirectory {
+            url: sdist.url.raw().to_string(),
+            dir_info: DirInfo {
+                editable: sdist.editable.then_some(true),
+            },
+        }),
+        _ => {
+            let VersionOrUrl::Url(url) = dist.version_or_url() else {
+                bail!("Missing URL for: {dist}");
+            };
+            let direct_url = distribution_types::DirectUrl::try_from(url.raw())?;
+            match DirectUrl::try_from(&direct_url)? {
+                DirectUrl::ArchiveUrl {
+                    url, subdirectory, ..
+                } => Ok(DirectUrl::ArchiveUrl {
+                    url,
+                    archive_info: archive_info(hashes),
+                    subdirectory,
+                }),
+                direct_url => Ok(direct_url),
+            }
+        }
+    }
+}
+
+/// Return the `archive_info` for an archive with the given hashes.
+fn archive_info(hashes: &[HashDigest]) -> ArchiveInfo {
+    if hashes.is_empty() {
+        return ArchiveInfo {
+            hash: None,
+            hashes: None,
+        };
+    }
+    ArchiveInfo {
+        // The legacy `hash` field uses the `<algorithm>=<digest>` format.
+        hash: hashes
+            .first()
+            .map(|hash| format!("{}={}", hash.algorithm, hash.digest)),
+        hashes: Some(
+            hashes
+                .iter()
+                .map(|hash| (hash.algorithm.to_string(), hash.digest.to_string()))
+                .collect::<HashMap<_, _>>(),
+        ),
+    }
+}
@@ -0,0 +1,61 @@
+use anyhow::Result;
+use serde::Serialize;
+
+use distribution_types::{InstalledDist, Name};
+use pypi_types::DirectUrl;
+use uv_fs::Simplified;
+
+/// An installed package, as represented in the JSON output of `uv pip list` and `uv pip show`.
+#[derive(Debug, Serialize)]
+pub(super) struct InstalledPackage {
+    name: String,
+    version: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    editable_project_location: Option<String>,
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    provenance: Option<Provenance>,
+}
+
+/// Where an installed package lives, and how it got there.
+#[derive(Debug, Serialize)]
+struct Provenance {
+    /// The directory containing the package (e.g., the `site-packages` directory).
+    location: String,
+    /// The contents of the `INSTALLER` file, e.g., `uv` or `pip`.
+    installer: Option<String>,
+    /// The contents of the `direct_url.json` file, for packages installed from a URL or path.
+    direct_url: Option<DirectUrl>,
+}
+
+impl InstalledPackage {
+    /// Include the provenance of the package (its location, installer, and direct URL) by reading
+    /// the metadata in its `.dist-info` directory.
+    pub(super) fn with_provenance(mut self, dist: &InstalledDist) -> Result<Self> {
+        self.provenance = Some(Provenance {
+            location: dist
+                .path()
+                .parent()
+                .expect("package path is not root")
+                .simplified_display()
+                .to_string(),
+            installer: dist
+                .installer()?
+                .map(|installer| installer.trim().to_string()),
+            direct_url: InstalledDist::direct_url(dist.path())?,
+        });
+        Ok(self)
+    }
+}
+
+impl From<&InstalledDist> for InstalledPackage {
+    fn from(dist: &InstalledDist) -> Self {
+        Self {
+            name: dist.name().to_string(),
+            version: dist.version().to_string(),
+            editable_project_location: dist
+                .as_editable()
+                .map(|url| url.to_file_path().unwrap().simplified_display().to_string()),
+            provenance: None,
+        }
+    }
+}
@@ -1,36 +1,64 @@
+use std::borrow::Cow;
 use std::time::Duration;
 use std::{fmt::Display, fmt::Write, process::ExitCode};
 
 use anyhow::Context;
 use owo_colors::OwoColorize;
+use tracing::debug;
 
+pub(crate) use auth::{auth_check, auth_list, auth_login, auth_logout};
 pub(crate) use cache_clean::cache_clean;
+pub(crate) use cache_compress::cache_compress;
+pub(crate) use cache_dedupe::cache_dedupe;
 pub(crate) use cache_dir::cache_dir;
+pub(crate) use cache_info::{cache_info, CacheInfoFormat};
 pub(crate) use cache_prune::cache_prune;
+pub(crate) use cache_verify::cache_verify;
 use distribution_types::InstalledMetadata;
+pub(crate) use doctor::doctor;
+pub(crate) use external::{external, find_external};
+pub(crate) use index_snapshot::index_snapshot;
 pub(crate) use pip_check::pip_check;
 pub(crate) use pip_compile::{extra_name_with_clap_error, pip_compile};
 pub(crate) use pip_freeze::pip_freeze;
-pub(crate) use pip_install::pip_install;
+pub(crate) use pip_install::{pip_install, Error as PipInstallError};
 pub(crate) use pip_list::pip_list;
 pub(crate) use pip_show::pip_show;
 pub(crate) use pip_sync::pip_sync;
 pub(crate) use pip_uninstall::pip_uninstall;
+use platform_tags::{Tags, TargetLibc};
+pub(crate) use python_inspect::{python_inspect, PythonInspectFormat};
 #[cfg(feature = "self-update")]
-pub(crate) use self_update::self_update;
+pub(crate) use self_update::{self_package, self_update};
+#[cfg(feature = "serve-index")]
+pub(crate) use serve_index::serve_index;
 use uv_cache::Cache;
+use uv_configuration::TagPolicy;
 use uv_fs::Simplified;
 use uv_installer::compile_tree;
-use uv_interpreter::PythonEnvironment;
+use uv_interpreter::{ExternallyManaged, Interpreter, PythonEnvironment};
 use uv_normalize::PackageName;
+use uv_toolchain::PythonVersion;
+use uv_warnings::warn_user;
 pub(crate) use venv::venv;
 pub(crate) use version::version;
 
+use crate::errors::ErrorClass;
 use crate::printer::Printer;
 
+mod auth;
 mod cache_clean;
+mod cache_compress;
+mod cache_dedupe;
 mod cache_dir;
+mod cache_info;
 mod cache_prune;
+mod cache_verify;
+mod doctor;
+mod external;
+mod index_snapshot;
+mod install_report;
+mod installed;
 mod pip_check;
 mod pip_compile;
 mod pip_freeze;
@@ -39,9 +67,13 @@ mod pip_list;
 mod pip_show;
 mod pip_sync;
 mod pip_uninstall;
+mod python_inspect;
+mod release_notes;
 mod reporters;
 #[cfg(feature = "self-update")]
 mod self_update;
+#[cfg(feature = "serve-index")]
+mod serve_index;
 mod venv;
 mod version;
 
@@ -58,15 +90,30 @@ pub(crate) enum ExitStatus {
     /// The command failed with an unexpected error.
     #[allow(unused)]
     Error,
+
+    /// The command failed with an error of a known class, with a stable exit code.
+    Classified(ErrorClass),
+
+    /// The command's exit status is propagated from an external command.
+    External(u8),
+}
+
+impl ExitStatus {
+    /// The numeric exit code for this status.
+    pub(crate) fn code(self) -> u8 {
+        match self {
+            Self::Success => 0,
+            Self::Failure => 1,
+            Self::Error => 2,
+            Self::Classified(class) => class.exit_code(),
+            Self::External(code) => code,
+        }
+    }
 }
 
 impl From<ExitStatus> for ExitCode {
     fn from(status: ExitStatus) -> Self {
-        match status {
-            ExitStatus::Success => Self::from(0),
-            ExitStatus::Failure => Self::from(1),
-            ExitStatus::Error => Self::from(2),
-        }
+        Self::from(status.code())
     }
 }
 
@@ -125,6 +172,15 @@ pub(crate) enum ListFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub(crate) enum ShowFormat {
+    /// Display the packages in a human-readable, `pip show`-like format.
+    #[default]
+    Text,
+    /// Display the packages in a machine-readable JSON format.
+    Json,
+}
+
 /// Compile all Python source files in site-packages to bytecode, to speed up the
 /// initial run of any subsequent executions.
 ///
@@ -160,6 +216,116 @@ pub(super) async fn compile_bytecode(
     Ok(())
 }
 
+/// Return the error to report when refusing to modify an externally managed environment.
+///
+/// See the `--break-system-packages` option on `pip install`, `pip sync`, and `pip uninstall`.
+pub(super) fn externally_managed_error(
+    venv: &PythonEnvironment,
+    externally_managed: ExternallyManaged,
+) -> anyhow::Error {
+    // A virtual environment can only be externally managed if it was explicitly marked as such,
+    // so the usual advice (to create a virtual environment) doesn't apply.
+    if venv.interpreter().is_virtualenv() {
+        return if let Some(error) = externally_managed.into_error() {
+            anyhow::anyhow!(
+                "The virtual environment at {} is externally managed, and indicates the following:\n\n{}\n\nTo modify it regardless, use `--break-system-packages`.",
+                venv.root().user_display().cyan(),
+                textwrap::indent(&error, "  ").green(),
+            )
+        } else {
+            anyhow::anyhow!(
+                "The virtual environment at {} is externally managed. To modify it regardless, use `--break-system-packages`.",
+                venv.root().user_display().cyan()
+            )
+        };
+    }
+
+    if let Some(error) = externally_managed.into_error() {
+        anyhow::anyhow!(
+            "The interpreter at {} is externally managed, and indicates the following:\n\n{}\n\nConsider creating a virtual environment with `uv venv`.",
+            venv.root().user_display().cyan(),
+            textwrap::indent(&error, "  ").green(),
+        )
+    } else {
+        anyhow::anyhow!(
+            "The interpreter at {} is externally managed. Instead, create a virtual environment with `uv venv`.",
+            venv.root().user_display().cyan()
+        )
+    }
+}
+
+/// Return the [`Tags`] to use when selecting wheels for the given interpreter.
+///
+/// See the `--python-version` option on `pip compile`, the `--libc`, `--force-manylinux`, and
+/// `--tag-policy` options on `pip compile`, `pip sync`, and `pip install`, and the
+/// `platform-preference` setting.
+pub(super) fn target_tags<'a>(
+    interpreter: &'a Interpreter,
+    python_version: Option<&PythonVersion>,
+    libc: Option<TargetLibc>,
+    force_manylinux: bool,
+    tag_policy: TagPolicy,
+    platform_preference: &[String],
+) -> anyhow::Result<Cow<'a, Tags>> {
+    let platform = if let Some(libc) = libc {
+        debug!("Selecting wheels for libc: {libc}");
+        Some(interpreter.platform().with_libc(libc)?)
+    } else if force_manylinux {
+        debug!("Selecting wheels for manylinux, regardless of the interpreter's libc");
+        Some(interpreter.platform().with_manylinux()?)
+    } else {
+        None
+    };
+
+    let tags = if platform.is_none() && python_version.is_none() {
+        Cow::Borrowed(interpreter.tags()?)
+    } else {
+        Cow::Owned(Tags::from_env(
+            platform.as_ref().unwrap_or_else(|| interpreter.platform()),
+            python_version.map_or_else(
+                || interpreter.python_tuple(),
+                |python_version| (python_version.major(), python_version.minor()),
+            ),
+            interpreter.implementation_name(),
+            interpreter.implementation_tuple(),
+            interpreter.gil_disabled(),
+        )?)
+    };
+
+    Ok(apply_tag_policy(tags, tag_policy, platform_preference))
+}
+
+/// Apply the [`TagPolicy`] and the preferred platforms to the given [`Tags`].
+pub(super) fn apply_tag_policy<'a>(
+    tags: Cow<'a, Tags>,
+    tag_policy: TagPolicy,
+    platform_preference: &[String],
+) -> Cow<'a, Tags> {
+    let tags = match tag_policy {
+        TagPolicy::Default => tags,
+        TagPolicy::PreferAbi3 => {
+            debug!("Preferring `abi3` wheels over version-specific wheels");
+            Cow::Owned(tags.prefer_abi3())
+        }
+    };
+
+    if platform_preference.is_empty() {
+        return tags;
+    }
+    for platform in platform_preference {
+        if !tags.supports_platform(platform) {
+            warn_user!(
+                "The preferred platform `{platform}` isn't supported by the target interpreter, and will be ignored"
+            );
+        }
+    }
+    debug!(
+        "Preferring wheels for platforms: {}",
+        platform_preference.join(", ")
+    );
+    Cow::Owned(tags.with_platform_preference(platform_preference))
+}
+
 /// Formats a number of bytes into a human readable SI-prefixed size.
 ///
 /// Returns a tuple of `(quantity, units)`.
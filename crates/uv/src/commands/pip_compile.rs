@@ -1,30 +1,48 @@
 use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet};
 use std::env;
 use std::fmt::Write;
 use std::io::stdout;
 use std::ops::Deref;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
-use anstream::{eprint, AutoStream, StripStream};
+use anstream::{AutoStream, StripStream};
 use anyhow::{anyhow, Context, Result};
+use futures::{StreamExt, TryStreamExt};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use serde::{Deserialize, Serialize};
 use tempfile::tempdir_in;
 use tracing::debug;
+use url::Url;
 
-use distribution_types::{IndexLocations, LocalEditable, LocalEditables, Verbatim};
+use distribution_types::{
+    BuiltDist, Dist, DistributionMetadata, File, FileLocation, FlatIndexLocation, IndexLocations,
+    IndexUrl, LocalEditable, LocalEditables, Name, ResolvedDist, SourceDist, Verbatim,
+    VersionOrUrl,
+};
 use install_wheel_rs::linker::LinkMode;
-use platform_tags::Tags;
+use pep440_rs::{Operator, Version};
+use pep508_rs::{MarkerEnvironment, Requirement, RequirementsTxtRequirement};
+use platform_tags::{Tags, TargetLibc};
+use pypi_types::{HashAlgorithm, HashDigest, Yanked};
 use requirements_txt::EditableRequirement;
-use uv_cache::Cache;
-use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
+use rustc_hash::{FxHashMap, FxHashSet};
+use uv_build::SourceBuild;
+use uv_cache::{Cache, CacheBucket, CacheEntry, Timestamp};
+use uv_client::{
+    BaseClient, BaseClientBuilder, Connectivity, FlatIndexClient, OwnedArchive, RegistryClient,
+    RegistryClientBuilder,
+};
 use uv_configuration::KeyringProviderType;
 use uv_configuration::{
     ConfigSettings, Constraints, IndexStrategy, NoBinary, NoBuild, Overrides, SetupPyStrategy,
-    Upgrade,
+    TagPolicy, TargetPlatform, Upgrade,
 };
 use uv_dispatch::BuildDispatch;
+use uv_distribution::DistributionDatabase;
+use uv_extract::hash::Hasher;
 use uv_fs::Simplified;
 use uv_installer::Downloader;
 use uv_interpreter::{find_best_python, PythonEnvironment};
@@ -35,15 +53,17 @@ use uv_requirements::{
 };
 use uv_resolver::{
     AnnotationStyle, DependencyMode, DisplayResolutionGraph, ExcludeNewer, Exclusions, FlatIndex,
-    InMemoryIndex, Manifest, OptionsBuilder, PreReleaseMode, PythonRequirement, ResolutionMode,
-    Resolver,
+    InMemoryIndex, Manifest, MinimumReleaseAge, OptionsBuilder, PreReleaseMode, PreferLocal,
+    Preference, PythonRequirement, ResolutionGraph, ResolutionMode, Resolver,
 };
 use uv_toolchain::PythonVersion;
-use uv_types::{BuildIsolation, EmptyInstalledPackages, HashStrategy, InFlight};
+use uv_types::{BuildContext, BuildIsolation, EmptyInstalledPackages, HashStrategy, InFlight};
 use uv_warnings::warn_user;
 
+use crate::commands::release_notes::{self, ReleaseNotes};
 use crate::commands::reporters::{DownloadReporter, ResolverReporter};
-use crate::commands::{elapsed, ExitStatus};
+use crate::commands::{apply_tag_policy, elapsed, target_tags, ExitStatus};
+use crate::errors::report_no_solution;
 use crate::printer::Printer;
 
 /// Resolve a set of requirements into a set of pinned versions.
@@ -54,11 +74,18 @@ pub(crate) async fn pip_compile(
     overrides: &[RequirementsSource],
     extras: ExtrasSpecification<'_>,
     output_file: Option<&Path>,
+    split_extras: Option<&Path>,
+    emit_build_requirements: Option<&Path>,
     resolution_mode: ResolutionMode,
     prerelease_mode: PreReleaseMode,
+    prefer_local: PreferLocal,
+    minimum_release_age: MinimumReleaseAge,
     dependency_mode: DependencyMode,
     upgrade: Upgrade,
+    summary: bool,
+    release_notes: bool,
     generate_hashes: bool,
+    hash_algorithms: Vec<HashAlgorithm>,
     no_emit_packages: Vec<PackageName>,
     include_extras: bool,
     include_annotations: bool,
@@ -75,11 +102,17 @@ pub(crate) async fn pip_compile(
     config_settings: ConfigSettings,
     connectivity: Connectivity,
     no_build_isolation: bool,
+    no_build_isolation_package: Vec<PackageName>,
     no_build: NoBuild,
     python_version: Option<PythonVersion>,
+    python_platform: Option<TargetPlatform>,
+    libc: Option<TargetLibc>,
+    force_manylinux: bool,
+    tag_policy: TagPolicy,
+    platform_preference: &[String],
     exclude_newer: Option<ExcludeNewer>,
     annotation_style: AnnotationStyle,
-    native_tls: bool,
+    client_builder: &BaseClientBuilder<'_>,
     quiet: bool,
     link_mode: LinkMode,
     cache: Cache,
@@ -95,11 +128,14 @@ pub(crate) async fn pip_compile(
         ));
     }
 
-    let client_builder = BaseClientBuilder::new()
+    let client_builder = client_builder
+        .clone()
         .connectivity(connectivity)
-        .native_tls(native_tls)
         .keyring(keyring_provider);
 
+    // Retain the sources, to determine the top-level requirements of each extra.
+    let sources = requirements;
+
     // Read all requirements from the provided sources.
     let RequirementsSpecification {
         project,
@@ -144,6 +180,41 @@ pub(crate) async fn pip_compile(
         }
     }
 
+    // If the output is split by extra, determine the top-level requirements of each file.
+    let split = if let Some(output_dir) = split_extras {
+        if !source_trees.is_empty() {
+            return Err(anyhow!(
+                "`--split-extras` requires the dependencies to be declared statically (e.g., in the `[project]` table of a `pyproject.toml`)"
+            ));
+        }
+        // The base file would collide with the file for an extra named `base`.
+        if used_extras.iter().any(|extra| extra.as_ref() == "base") {
+            return Err(anyhow!(
+                "`--split-extras` can't be used with an extra named `base`, as it would conflict with `base.txt`"
+            ));
+        }
+        let base = (
+            output_dir.join("base.txt"),
+            top_level_names(sources, &ExtrasSpecification::None, &client_builder).await?,
+        );
+        let mut extras = Vec::with_capacity(used_extras.len());
+        for extra in used_extras.iter().sorted() {
+            extras.push((
+                output_dir.join(format!("{extra}.txt")),
+                top_level_names(
+                    sources,
+                    &ExtrasSpecification::Some(std::slice::from_ref(extra)),
+                    &client_builder,
+                )
+                .await?,
+            ));
+        }
+        fs_err::create_dir_all(output_dir)?;
+        Some((base, extras))
+    } else {
+        None
+    };
+
     // Find an interpreter to use for building distributions
     let interpreter = find_best_python(python_version.as_ref(), &cache)?;
     debug!(
@@ -175,35 +246,66 @@ pub(crate) async fn pip_compile(
     // Create a shared in-memory index.
     let source_index = InMemoryIndex::default();
 
-    // If we're resolving against a different Python version, use a separate index. Source
-    // distributions will be built against the installed version, and so the index may contain
-    // different package priorities than in the top-level resolution.
-    let top_level_index = if python_version.is_some() {
+    // If we're resolving against a different Python version or platform, use a separate index.
+    // Source distributions will be built against the installed version, and so the index may
+    // contain different package priorities than in the top-level resolution.
+    let top_level_index = if python_version.is_some()
+        || python_platform.is_some()
+        || libc.is_some()
+        || force_manylinux
+        || tag_policy != TagPolicy::Default
+        || !platform_preference.is_empty()
+    {
         InMemoryIndexRef::Owned(InMemoryIndex::default())
     } else {
         InMemoryIndexRef::Borrowed(&source_index)
     };
 
     // Determine the tags, markers, and interpreter to use for resolution.
-    let tags = if let Some(python_version) = python_version.as_ref() {
-        Cow::Owned(Tags::from_env(
-            interpreter.platform(),
-            (python_version.major(), python_version.minor()),
-            interpreter.implementation_name(),
-            interpreter.implementation_tuple(),
-            interpreter.gil_disabled(),
-        )?)
+    let (tags, markers) = if let Some(python_platform) = python_platform {
+        debug!("Resolving for platform: {python_platform}");
+        let python_tuple = python_version.as_ref().map_or_else(
+            || python_platform.python_tuple(),
+            |python_version| (python_version.major(), python_version.minor()),
+        );
+        let tags = Tags::from_env(
+            &python_platform.platform(),
+            python_tuple,
+            "cpython",
+            python_tuple,
+            false,
+        )?;
+        let markers = python_version.map_or_else(
+            || python_platform.markers(),
+            |python_version| python_version.markers(&python_platform.markers()),
+        );
+        (
+            apply_tag_policy(Cow::Owned(tags), tag_policy, platform_preference),
+            Cow::Owned(markers),
+        )
     } else {
-        Cow::Borrowed(interpreter.tags()?)
+        let tags = target_tags(
+            &interpreter,
+            python_version.as_ref(),
+            libc,
+            force_manylinux,
+            tag_policy,
+            platform_preference,
+        )?;
+        let markers = python_version.map_or_else(
+            || Cow::Borrowed(interpreter.markers()),
+            |python_version| Cow::Owned(python_version.markers(interpreter.markers())),
+        );
+        (tags, markers)
     };
-    let markers = python_version.map_or_else(
-        || Cow::Borrowed(interpreter.markers()),
-        |python_version| Cow::Owned(python_version.markers(interpreter.markers())),
-    );
 
     // Generate, but don't enforce hashes for the requirements.
     let hasher = if generate_hashes {
-        HashStrategy::Generate
+        HashStrategy::Generate(if hash_algorithms.is_empty() {
+            vec![HashAlgorithm::Sha256]
+        } else {
+            hash_algorithms.clone()
+        })
     } else {
         HashStrategy::None
     };
@@ -212,17 +314,155 @@ pub(crate) async fn pip_compile(
     let index_locations =
         index_locations.combine(index_url, extra_index_urls, find_links, no_index);
 
+    // If we're writing to an output file, and the inputs are unchanged since it was generated,
+    // reuse the previous resolution. (Editables and source trees are excluded, as their metadata
+    // can change without any change to the inputs, as are resolutions that emit build
+    // requirements, which aren't part of the cached output.)
+    let resolution_entry = if output_file.is_some()
+        && emit_build_requirements.is_none()
+        && upgrade.is_none()
+        && cache.refresh().is_none()
+        && editables.is_empty()
+        && source_trees.is_empty()
+    {
+        // Snapshot any local files on which the resolution depends, such that changes to the
+        // `--find-links` directories or local archives invalidate the resolution.
+        let urls = requirements
+            .iter()
+            .chain(&overrides)
+            .filter_map(|entry| match &entry.requirement {
+                RequirementsTxtRequirement::Pep508(requirement) => requirement_url(requirement),
+                RequirementsTxtRequirement::Unnamed(requirement) => Some(&*requirement.url),
+            })
+            .chain(constraints.iter().filter_map(requirement_url));
+        let snapshot = snapshot_local_files(index_locations.flat_index(), urls);
+
+        snapshot.map(|snapshot| {
+            let (release_cutoff, package_release_cutoffs) =
+                minimum_release_age.cutoffs(MinimumReleaseAge::now());
+            let no_build_key = |no_build: &NoBuild| match no_build {
+                NoBuild::None => (0u8, Vec::new()),
+                NoBuild::All => (1, Vec::new()),
+                NoBuild::Packages(packages) => (2, to_strings(packages)),
+            };
+            let inputs = (
+                env!("CARGO_PKG_VERSION"),
+                (
+                    project.as_ref().map(ToString::to_string),
+                    to_strings(&requirements),
+                    to_strings(&constraints),
+                    to_strings(&overrides),
+                    match &extras {
+                        ExtrasSpecification::None => (0u8, Vec::new()),
+                        ExtrasSpecification::All => (1, Vec::new()),
+                        ExtrasSpecification::Some(extras) => (2, to_strings(*extras)),
+                    },
+                    snapshot,
+                ),
+                (
+                    markers_key(&markers),
+                    tags.to_string(),
+                    to_strings(index_locations.indexes()),
+                    to_strings(index_locations.flat_index()),
+                    index_locations
+                        .settings()
+                        .iter()
+                        .map(|settings| (settings.url.to_string(), to_strings(&settings.packages)))
+                        .collect::<Vec<_>>(),
+                    index_strategy.clone() as u8,
+                ),
+                (
+                    exclude_newer.map(|exclude_newer| exclude_newer.timestamp_millis()),
+                    release_cutoff.map(|cutoff| cutoff.timestamp_millis()),
+                    package_release_cutoffs
+                        .into_iter()
+                        .map(|(package, cutoff)| {
+                            (
+                                package.to_string(),
+                                cutoff.map(|cutoff| cutoff.timestamp_millis()),
+                            )
+                        })
+                        .collect::<Vec<_>>(),
+                    resolution_mode as u8,
+                    prerelease_mode as u8,
+                    to_strings(prefer_local.preferences()),
+                    dependency_mode as u8,
+                ),
+                (
+                    no_build_key(&no_build),
+                    no_build_key(&specified_no_build),
+                    &config_settings,
+                    setup_py as u8,
+                    no_build_isolation,
+                    to_strings(&no_build_isolation_package),
+                ),
+                (
+                    generate_hashes,
+                    to_strings(&hash_algorithms),
+                    to_strings(&no_emit_packages),
+                    include_extras,
+                    include_annotations,
+                    include_header,
+                    include_marker_expression,
+                    include_index_annotation,
+                    annotation_style as u8,
+                    include_index_url,
+                    include_find_links,
+                ),
+                cmd(
+                    include_index_url,
+                    include_find_links,
+                    custom_compile_command.clone(),
+                ),
+            );
+            cache.entry(
+                CacheBucket::Resolutions,
+                "",
+                format!("{}.msgpack", cache_key::digest(&inputs)),
+            )
+        })
+    } else {
+        None
+    };
+
     // Initialize the registry client.
-    let client = RegistryClientBuilder::new(cache.clone())
-        .native_tls(native_tls)
-        .connectivity(connectivity)
+    let client = RegistryClientBuilder::from_base_client(cache.clone(), client_builder.clone())
         .index_urls(index_locations.index_urls())
         .index_strategy(index_strategy)
-        .keyring(keyring_provider)
         .markers(&markers)
         .platform(interpreter.platform())
         .build();
 
+    if let (Some(entry), Some(output_file)) = (resolution_entry.as_ref(), output_file) {
+        if let Some(resolution) = read_resolution(entry, output_file, &client).await {
+            debug!("Reusing cached resolution: {}", entry.path().display());
+
+            let s = if resolution.packages == 1 { "" } else { "s" };
+            writeln!(
+                printer.stderr(),
+                "{}",
+                format!(
+                    "Resolved {} in {}",
+                    format!("{} package{}", resolution.packages, s).bold(),
+                    elapsed(start.elapsed())
+                )
+                .dimmed()
+            )?;
+
+            let mut writer = OutputWriter::new(!quiet, Some(output_file))?;
+            write!(writer, "{}", resolution.output)?;
+
+            return Ok(ExitStatus::Success);
+        }
+    }
+
+    // If requested, read the existing pins, to summarize the changes after resolving.
+    let pins = if summary {
+        read_lockfile(output_file, Upgrade::None).await?
+    } else {
+        Vec::new()
+    };
+
     // Read the lockfile, if present.
     let preferences = read_lockfile(output_file, upgrade).await?;
 
@@ -241,8 +481,11 @@ pub(crate) async fn pip_compile(
     let build_isolation = if no_build_isolation {
         venv = PythonEnvironment::from_interpreter(interpreter.clone());
         BuildIsolation::Shared(&venv)
-    } else {
+    } else if no_build_isolation_package.is_empty() {
         BuildIsolation::Isolated
+    } else {
+        venv = PythonEnvironment::from_interpreter(interpreter.clone());
+        BuildIsolation::SharedPackage(&venv, &no_build_isolation_package)
     };
 
     // Combine the `--no-build` flags.
@@ -263,7 +506,12 @@ pub(crate) async fn pip_compile(
         &no_build,
         &NoBinary::None,
     )
-    .with_options(OptionsBuilder::new().exclude_newer(exclude_newer).build());
+    .with_options(
+        OptionsBuilder::new()
+            .exclude_newer(exclude_newer)
+            .minimum_release_age(minimum_release_age.clone())
+            .build(),
+    );
 
     // Resolve the requirements from the provided sources.
     let requirements = {
@@ -383,6 +631,12 @@ pub(crate) async fn pip_compile(
     .resolve(&markers)
     .await?;
 
+    // Track the editables, which are always included in the base file when splitting by extra.
+    let editable_names = editables
+        .iter()
+        .map(|(.., metadata)| metadata.name.clone())
+        .collect::<Vec<_>>();
+
     // Create a manifest of the requirements.
     let manifest = Manifest::new(
         requirements,
@@ -399,6 +653,8 @@ pub(crate) async fn pip_compile(
     let options = OptionsBuilder::new()
         .resolution_mode(resolution_mode)
         .prerelease_mode(prerelease_mode)
+        .prefer_local(prefer_local)
+        .minimum_release_age(minimum_release_age)
         .dependency_mode(dependency_mode)
         .exclude_newer(exclude_newer)
         .build();
@@ -419,12 +675,9 @@ pub(crate) async fn pip_compile(
     )?
     .with_reporter(ResolverReporter::from(printer));
 
-    let resolution = match resolver.resolve().await {
+    let mut resolution = match resolver.resolve().await {
         Err(uv_resolver::ResolveError::NoSolution(err)) => {
-            let report = miette::Report::msg(format!("{err}"))
-                .context("No solution found when resolving dependencies:");
-            eprint!("{report:?}");
-            return Ok(ExitStatus::Failure);
+            return Ok(report_no_solution(&err, printer.error_format()));
         }
         result => result,
     }?;
@@ -452,100 +705,881 @@ pub(crate) async fn pip_compile(
         )?;
     }
 
-    // Write the resolved dependencies to the output channel.
-    let mut writer = OutputWriter::new(!quiet || output_file.is_none(), output_file)?;
+    // If requested, summarize the changes relative to the existing output file.
+    if summary {
+        let release_notes = (release_notes && connectivity == Connectivity::Online)
+            .then(|| (client.uncached_client(), &cache));
+        report_changes(&pins, &resolution, release_notes, printer).await?;
+    }
 
-    if include_header {
-        writeln!(
-            writer,
-            "{}",
-            "# This file was autogenerated by uv via the following command:".green()
+    // If specific hash algorithms were requested, include a hash for each, computing any that
+    // weren't reported by the index.
+    if generate_hashes && !hash_algorithms.is_empty() {
+        generate_resolution_hashes(&mut resolution, &hash_algorithms, &client).await?;
+    }
+
+    // Determine the files to write, along with the packages to omit from each (in addition to
+    // those excluded via `--no-emit-package`) and the base file to reference, if any.
+    let outputs = if let Some((base, extras)) = split {
+        split_outputs(&resolution, base, extras, &editable_names)
+    } else {
+        vec![Output {
+            file: output_file.map(Path::to_path_buf),
+            omit: FxHashSet::default(),
+            base: None,
+        }]
+    };
+
+    let mut last_writer = None;
+    for output in outputs {
+        // Write the resolved dependencies to the output channel.
+        let mut writer = OutputWriter::new(
+            split_extras.is_none() && (!quiet || output_file.is_none()),
+            output.file.as_deref(),
         )?;
-        writeln!(
+
+        if include_header {
+            writeln!(
+                writer,
+                "{}",
+                "# This file was autogenerated by uv via the following command:".green()
+            )?;
+            writeln!(
+                writer,
+                "{}",
+                format!(
+                    "#    {}",
+                    cmd(
+                        include_index_url,
+                        include_find_links,
+                        custom_compile_command.clone()
+                    )
+                )
+                .green()
+            )?;
+        }
+
+        if include_marker_expression {
+            let relevant_markers = resolution.marker_tree(&manifest, &top_level_index, &markers);
+            writeln!(
+                writer,
+                "{}",
+                "# Pinned dependencies known to be valid for:".green()
+            )?;
+            writeln!(writer, "{}", format!("#    {relevant_markers}").green())?;
+        }
+
+        // Write the index locations to the output channel.
+        let mut wrote_index = false;
+
+        // If necessary, include the `--index-url` and `--extra-index-url` locations.
+        if include_index_url {
+            if let Some(index) = index_locations.index() {
+                writeln!(writer, "--index-url {}", index.verbatim())?;
+                wrote_index = true;
+            }
+            for extra_index in index_locations.extra_index() {
+                writeln!(writer, "--extra-index-url {}", extra_index.verbatim())?;
+                wrote_index = true;
+            }
+        }
+
+        // If necessary, include the `--find-links` locations.
+        if include_find_links {
+            for flat_index in index_locations.flat_index() {
+                writeln!(writer, "--find-links {flat_index}")?;
+                wrote_index = true;
+            }
+        }
+
+        // If we wrote an index, add a newline to separate it from the requirements
+        if wrote_index {
+            writeln!(writer)?;
+        }
+
+        // If necessary, reference the base file, which includes any shared dependencies.
+        if let Some(base) = output.base.as_deref() {
+            writeln!(writer, "-r {base}")?;
+        }
+
+        write!(
             writer,
             "{}",
-            format!(
-                "#    {}",
-                cmd(
-                    include_index_url,
-                    include_find_links,
-                    custom_compile_command
-                )
+            DisplayResolutionGraph::new(
+                &resolution,
+                &no_emit_packages
+                    .iter()
+                    .chain(output.omit.iter())
+                    .cloned()
+                    .collect::<Vec<_>>(),
+                generate_hashes,
+                include_extras,
+                include_annotations,
+                include_index_annotation,
+                annotation_style,
             )
-            .green()
         )?;
+
+        // If any "unsafe" packages were excluded, notify the user.
+        let excluded = no_emit_packages
+            .iter()
+            .filter(|name| resolution.contains(name) && !output.omit.contains(*name))
+            .collect::<Vec<_>>();
+        if !excluded.is_empty() {
+            writeln!(writer)?;
+            writeln!(
+                writer,
+                "{}",
+                "# The following packages were excluded from the output:".green()
+            )?;
+            for package in excluded {
+                writeln!(writer, "# {package}")?;
+            }
+        }
+
+        last_writer = Some(writer);
     }
 
-    if include_marker_expression {
-        let relevant_markers = resolution.marker_tree(&manifest, &top_level_index, &markers);
-        writeln!(
-            writer,
-            "{}",
-            "# Pinned dependencies known to be valid for:".green()
-        )?;
-        writeln!(writer, "{}", format!("#    {relevant_markers}").green())?;
+    // If requested, write the build requirements of any source distributions in the resolution.
+    if let Some(emit_build_requirements) = emit_build_requirements {
+        let algorithms = match &hasher {
+            HashStrategy::Generate(algorithms) => algorithms.as_slice(),
+            _ => &[],
+        };
+        let build_requirements =
+            resolve_build_requirements(&resolution, &hasher, algorithms, &client, &build_dispatch)
+                .await?;
+
+        let mut writer = OutputWriter::new(false, Some(emit_build_requirements))?;
+        if include_header {
+            writeln!(
+                writer,
+                "{}",
+                "# This file was autogenerated by uv via the following command:".green()
+            )?;
+            writeln!(
+                writer,
+                "{}",
+                format!(
+                    "#    {}",
+                    cmd(
+                        include_index_url,
+                        include_find_links,
+                        custom_compile_command
+                    )
+                )
+                .green()
+            )?;
+            writeln!(
+                writer,
+                "{}",
+                "# Build requirements for the source distributions in the resolution.".green()
+            )?;
+        }
+        for BuildRequirement {
+            requirement,
+            hashes,
+            sources,
+        } in &build_requirements
+        {
+            let mut line = requirement.to_string();
+            for hash in hashes {
+                line.push_str(" \\\n");
+                line.push_str("    --hash=");
+                line.push_str(&hash.to_string());
+            }
+            writeln!(writer, "{line}")?;
+            if include_annotations && !sources.is_empty() {
+                match sources.iter().exactly_one() {
+                    Ok(source) => writeln!(writer, "{}", format!("    # via {source}").green())?,
+                    Err(sources) => {
+                        writeln!(writer, "{}", "    # via".green())?;
+                        for source in sources {
+                            writeln!(writer, "{}", format!("    #   {source}").green())?;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // Persist the resolution, such that it can be reused if the inputs are unchanged.
+    if let (Some(entry), Some(writer)) = (resolution_entry, last_writer) {
+        let resolution = CachedResolution {
+            packages: resolution.len(),
+            pins: CachedPin::from_resolution(&resolution, &index_locations),
+            output: writer.into_buffer(),
+        };
+        if let Err(err) = write_resolution(&entry, &resolution) {
+            debug!("Failed to cache resolution: {err}");
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// A pinned build requirement, along with its hashes and the source distributions that require it.
+struct BuildRequirement {
+    requirement: Requirement,
+    hashes: Vec<HashDigest>,
+    sources: BTreeSet<PackageName>,
+}
+
+/// Resolve the build requirements of every source distribution in the resolution.
+///
+/// The build requirements are resolved together, such that the pins are compatible with one
+/// another and can be installed from a single file. If `algorithms` is non-empty, each pin is
+/// annotated with the hashes of all files for the pinned version.
+async fn resolve_build_requirements(
+    resolution: &ResolutionGraph,
+    hasher: &HashStrategy,
+    algorithms: &[HashAlgorithm],
+    client: &RegistryClient,
+    build_dispatch: &BuildDispatch<'_>,
+) -> Result<Vec<BuildRequirement>> {
+    let database = DistributionDatabase::new(client, build_dispatch);
+
+    // Read the build requirements of each source distribution, and track the source distributions
+    // whose build environment includes each package.
+    let mut requirements: Vec<Requirement> = Vec::new();
+    let mut sources: BTreeMap<PackageName, BTreeSet<PackageName>> = BTreeMap::new();
+    for dist in resolution.petgraph().node_weights() {
+        let ResolvedDist::Installable(Dist::Source(source_dist)) = dist else {
+            continue;
+        };
+
+        let source_tree = database
+            .unpack_source_dist(source_dist, hasher.get(source_dist))
+            .await
+            .with_context(|| format!("Failed to unpack: {source_dist}"))?;
+        let static_requirements =
+            SourceBuild::static_requirements(&source_tree, build_dispatch.setup_py_strategy())
+                .with_context(|| {
+                    format!("Failed to read the build requirements of: {source_dist}")
+                })?;
+        debug!(
+            "Build requirements for {source_dist}: {}",
+            static_requirements.iter().join(", ")
+        );
+
+        let resolved = build_dispatch
+            .resolve(&static_requirements)
+            .await
+            .with_context(|| {
+                format!("Failed to resolve the build requirements of: {source_dist}")
+            })?;
+        for package in resolved.packages() {
+            sources
+                .entry(package.clone())
+                .or_default()
+                .insert(source_dist.name().clone());
+        }
+
+        for requirement in static_requirements {
+            if !requirements.contains(&requirement) {
+                requirements.push(requirement);
+            }
+        }
+    }
+
+    if requirements.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    // Resolve the build requirements of all source distributions together.
+    let resolved = build_dispatch
+        .resolve(&requirements)
+        .await
+        .context("Failed to resolve the build requirements of the source distributions together")?;
+
+    let mut build_requirements = Vec::with_capacity(resolved.len());
+    for dist in resolved.distributions() {
+        let hashes = if algorithms.is_empty() {
+            Vec::new()
+        } else if let Some((name, version, index, file)) = registry_file(dist) {
+            version_hashes(name, version, index, file, algorithms, client).await?
+        } else {
+            warn_user!("Unable to generate hashes for build requirement: {dist}");
+            Vec::new()
+        };
+        build_requirements.push(BuildRequirement {
+            requirement: Requirement::from(dist.clone()),
+            hashes,
+            sources: sources.remove(dist.name()).unwrap_or_default(),
+        });
+    }
+    build_requirements.sort_unstable_by(|a, b| a.requirement.name.cmp(&b.requirement.name));
+
+    Ok(build_requirements)
+}
+
+/// Replace the hashes for every registry distribution in the resolution with a hash for each of the
+/// given algorithms, across all files for the pinned version.
+///
+/// Hashes that aren't reported by the index are computed by downloading the files. Hashes for
+/// direct URL distributions are computed with the requested algorithms during resolution.
+async fn generate_resolution_hashes(
+    resolution: &mut ResolutionGraph,
+    algorithms: &[HashAlgorithm],
+    client: &RegistryClient,
+) -> Result<()> {
+    let dists = resolution
+        .petgraph()
+        .node_weights()
+        .filter_map(registry_file)
+        .collect::<Vec<_>>();
+
+    for (name, version, index, file) in dists {
+        let digests =
+            version_hashes(name.clone(), version, index, file, algorithms, client).await?;
+        resolution.set_hashes(name, digests);
     }
 
-    // Write the index locations to the output channel.
-    let mut wrote_index = false;
+    Ok(())
+}
+
+/// Return the name, version, index, and file of a registry distribution.
+fn registry_file(dist: &ResolvedDist) -> Option<(PackageName, Version, IndexUrl, File)> {
+    match dist {
+        ResolvedDist::Installable(Dist::Built(BuiltDist::Registry(wheel))) => Some((
+            wheel.filename.name.clone(),
+            wheel.filename.version.clone(),
+            wheel.index.clone(),
+            (*wheel.file).clone(),
+        )),
+        ResolvedDist::Installable(Dist::Source(SourceDist::Registry(sdist))) => Some((
+            sdist.filename.name.clone(),
+            sdist.filename.version.clone(),
+            sdist.index.clone(),
+            (*sdist.file).clone(),
+        )),
+        _ => None,
+    }
+}
+
+/// Compute a hash for each of the given algorithms across all files for the pinned version of a
+/// registry distribution.
+async fn version_hashes(
+    name: PackageName,
+    version: Version,
+    index: IndexUrl,
+    file: File,
+    algorithms: &[HashAlgorithm],
+    client: &RegistryClient,
+) -> Result<Vec<HashDigest>> {
+    // Find every file for the pinned version on the index. If the distribution came from a
+    // `--find-links` location, only the selected file is known.
+    let files = client
+        .simple(&name)
+        .await
+        .ok()
+        .and_then(|results| {
+            results
+                .into_iter()
+                .filter(|(result_index, _)| *result_index == index)
+                .find_map(|(_, archive)| {
+                    OwnedArchive::deserialize(&archive)
+                        .into_iter()
+                        .find(|datum| datum.version == version)
+                })
+        })
+        .map(|datum| datum.files.all().map(|(_, file)| file).collect::<Vec<_>>())
+        .unwrap_or_else(|| vec![file]);
+
+    let mut digests = futures::stream::iter(files)
+        .map(|file| file_hashes(file, algorithms, client))
+        .buffer_unordered(50)
+        .try_concat()
+        .await?;
+    digests.sort_unstable();
+    digests.dedup();
+    Ok(digests)
+}
+
+/// Print the packages that were added, removed, or changed relative to the given pins, along with
+/// a link to the release page of any new versions from PyPI.
+///
+/// If a client is provided, the release notes of any new versions from PyPI are fetched (or read
+/// from the cache) and included.
+async fn report_changes(
+    pins: &[Preference],
+    resolution: &ResolutionGraph,
+    notes_client: Option<(BaseClient, &Cache)>,
+    printer: Printer,
+) -> Result<()> {
+    let previous = pins
+        .iter()
+        .filter_map(|pin| {
+            let Some(pep508_rs::VersionOrUrl::VersionSpecifier(specifiers)) =
+                pin.requirement().version_or_url.as_ref()
+            else {
+                return None;
+            };
+            let [specifier] = specifiers.as_ref() else {
+                return None;
+            };
+            (*specifier.operator() == Operator::Equal).then_some((pin.name(), specifier.version()))
+        })
+        .collect::<FxHashMap<_, _>>();
+
+    let current = resolution
+        .petgraph()
+        .node_weights()
+        .filter_map(|dist| match dist.version_or_url() {
+            VersionOrUrl::Version(version) => Some((dist.name(), (version, dist))),
+            VersionOrUrl::Url(_) => None,
+        })
+        .collect::<FxHashMap<_, _>>();
 
-    // If necessary, include the `--index-url` and `--extra-index-url` locations.
-    if include_index_url {
-        if let Some(index) = index_locations.index() {
-            writeln!(writer, "--index-url {}", index.verbatim())?;
-            wrote_index = true;
+    for name in previous.keys().chain(current.keys()).unique().sorted() {
+        let before = previous.get(name).copied();
+        let after = current.get(name).copied();
+        if before.is_some() && before == after.map(|(version, _)| version) {
+            continue;
         }
-        for extra_index in index_locations.extra_index() {
-            writeln!(writer, "--extra-index-url {}", extra_index.verbatim())?;
-            wrote_index = true;
+
+        if let Some(version) = before {
+            writeln!(
+                printer.stderr(),
+                " {} {}{}",
+                "-".red(),
+                name.as_ref().bold(),
+                format!("=={version}").dimmed()
+            )?;
+        }
+        if let Some((version, dist)) = after {
+            // Link to the release page, which includes the project URLs (e.g., the changelog).
+            let is_pypi = matches!(
+                dist,
+                ResolvedDist::Installable(dist) if matches!(dist.index(), Some(IndexUrl::Pypi(_)))
+            );
+            let link = if is_pypi {
+                format!(" (https://pypi.org/project/{name}/{version}/)")
+            } else {
+                String::new()
+            };
+            writeln!(
+                printer.stderr(),
+                " {} {}{}{}",
+                "+".green(),
+                name.as_ref().bold(),
+                format!("=={version}").dimmed(),
+                link.dimmed()
+            )?;
+
+            if let Some((client, cache)) = notes_client.as_ref().filter(|_| is_pypi) {
+                match release_notes::release_notes(name, version, client, cache).await {
+                    Ok(notes) => write_release_notes(&notes, printer)?,
+                    Err(err) => {
+                        warn_user!(
+                            "Failed to fetch the release notes for {name}=={version}: {err}"
+                        );
+                    }
+                }
+            }
         }
     }
 
-    // If necessary, include the `--find-links` locations.
-    if include_find_links {
-        for flat_index in index_locations.flat_index() {
-            writeln!(writer, "--find-links {flat_index}")?;
-            wrote_index = true;
+    Ok(())
+}
+
+/// Print the release notes for a version, indented beneath its entry in the summary.
+fn write_release_notes(notes: &ReleaseNotes, printer: Printer) -> Result<()> {
+    if let Some(changelog) = notes.changelog.as_deref() {
+        writeln!(printer.stderr(), "     Changelog: {}", changelog.cyan())?;
+    }
+    if let Some(release) = notes.release.as_deref() {
+        writeln!(printer.stderr(), "     Release notes: {}", release.cyan())?;
+    }
+    if let Some(body) = notes.body.as_deref() {
+        for line in body.trim().lines() {
+            writeln!(printer.stderr(), "       {}", line.trim_end().dimmed())?;
         }
     }
+    Ok(())
+}
 
-    // If we wrote an index, add a newline to separate it from the requirements
-    if wrote_index {
-        writeln!(writer)?;
+/// Return the hashes of a file for the given algorithms, downloading the file if any of the
+/// hashes aren't reported by the index.
+async fn file_hashes(
+    file: File,
+    algorithms: &[HashAlgorithm],
+    client: &RegistryClient,
+) -> Result<Vec<HashDigest>> {
+    let reported = file
+        .hashes
+        .iter()
+        .filter(|hash| algorithms.contains(&hash.algorithm))
+        .cloned()
+        .collect::<Vec<_>>();
+    if algorithms
+        .iter()
+        .all(|algorithm| reported.iter().any(|hash| hash.algorithm == *algorithm))
+    {
+        return Ok(reported);
     }
 
-    write!(
-        writer,
-        "{}",
-        DisplayResolutionGraph::new(
-            &resolution,
-            &no_emit_packages,
-            generate_hashes,
-            include_extras,
-            include_annotations,
-            include_index_annotation,
-            annotation_style,
-        )
-    )?;
+    let mut hashers = algorithms
+        .iter()
+        .copied()
+        .map(Hasher::from)
+        .collect::<Vec<_>>();
+    let url = match &file.url {
+        FileLocation::RelativeUrl(base, url) => pypi_types::base_url_join_relative(base, url)?,
+        FileLocation::AbsoluteUrl(url) => Url::parse(url)?,
+        FileLocation::Path(path) => {
+            let bytes = fs_err::tokio::read(path).await?;
+            for hasher in &mut hashers {
+                hasher.update(&bytes);
+            }
+            return Ok(hashers.into_iter().map(HashDigest::from).collect());
+        }
+    };
 
-    // If any "unsafe" packages were excluded, notify the user.
-    let excluded = no_emit_packages
+    debug!("Downloading {} to compute hashes", file.filename);
+    let mut response = client
+        .uncached_client()
+        .client()
+        .get(url)
+        .send()
+        .await?
+        .error_for_status()
+        .map_err(uv_client::BetterReqwestError::from)?;
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .map_err(uv_client::BetterReqwestError::from)?
+    {
+        for hasher in &mut hashers {
+            hasher.update(&chunk);
+        }
+    }
+
+    let computed = hashers
         .into_iter()
-        .filter(|name| resolution.contains(name))
+        .map(HashDigest::from)
         .collect::<Vec<_>>();
-    if !excluded.is_empty() {
-        writeln!(writer)?;
-        writeln!(
-            writer,
-            "{}",
-            "# The following packages were excluded from the output:".green()
-        )?;
-        for package in excluded {
-            writeln!(writer, "# {package}")?;
+
+    // If the index reported a hash for any of the algorithms, verify it.
+    for expected in &file.hashes {
+        if let Some(actual) = computed
+            .iter()
+            .find(|actual| actual.algorithm == expected.algorithm)
+        {
+            if actual != expected {
+                return Err(anyhow!(
+                    "Hash mismatch for `{}`\n\nExpected:\n  {expected}\n\nComputed:\n  {actual}",
+                    file.filename
+                ));
+            }
         }
     }
 
-    Ok(ExitStatus::Success)
+    Ok(computed)
+}
+
+/// A file to write the resolution to.
+struct Output {
+    /// The path to the file, or `None` to only write to the standard output.
+    file: Option<PathBuf>,
+    /// The packages to omit from the file, as they're written to another file instead.
+    omit: FxHashSet<PackageName>,
+    /// The file to reference via `-r`, which includes any shared dependencies.
+    base: Option<String>,
+}
+
+/// Return the names of the top-level requirements in the given sources, when enabling the given
+/// extras.
+async fn top_level_names(
+    sources: &[RequirementsSource],
+    extras: &ExtrasSpecification<'_>,
+    client_builder: &BaseClientBuilder<'_>,
+) -> Result<FxHashSet<PackageName>> {
+    let spec =
+        RequirementsSpecification::from_sources(sources, &[], &[], extras, client_builder).await?;
+    Ok(spec
+        .requirements
+        .into_iter()
+        .filter_map(|entry| match entry.requirement {
+            RequirementsTxtRequirement::Pep508(requirement) => Some(requirement.name),
+            RequirementsTxtRequirement::Unnamed(_) => None,
+        })
+        .collect())
+}
+
+/// Partition the resolution into a base file and one file per extra.
+///
+/// Each extra file contains the packages that are only required when enabling that extra, and
+/// references the base file for everything else. Any packages that can't be attributed to an
+/// extra (e.g., those required by unnamed requirements) are written to the base file.
+fn split_outputs(
+    resolution: &ResolutionGraph,
+    (base_file, mut base_names): (PathBuf, FxHashSet<PackageName>),
+    extras: Vec<(PathBuf, FxHashSet<PackageName>)>,
+    editables: &[PackageName],
+) -> Vec<Output> {
+    let graph = resolution.petgraph();
+
+    // Collect the given packages, along with all of their transitive dependencies.
+    let closure = |names: &FxHashSet<PackageName>| {
+        let mut stack = graph
+            .node_indices()
+            .filter(|index| names.contains(graph[*index].name()))
+            .collect::<Vec<_>>();
+        let mut visited = FxHashSet::default();
+        while let Some(index) = stack.pop() {
+            if visited.insert(index) {
+                stack.extend(graph.neighbors(index));
+            }
+        }
+        visited
+            .into_iter()
+            .map(|index| graph[index].name().clone())
+            .collect::<FxHashSet<_>>()
+    };
+
+    let all = graph
+        .node_weights()
+        .map(|dist| dist.name().clone())
+        .collect::<FxHashSet<_>>();
+
+    base_names.extend(editables.iter().cloned());
+    let base = closure(&base_names);
+    let extras = extras
+        .into_iter()
+        .map(|(file, names)| {
+            let packages = closure(&names)
+                .into_iter()
+                .filter(|name| !base.contains(name))
+                .collect::<FxHashSet<_>>();
+            (file, packages)
+        })
+        .collect::<Vec<_>>();
+
+    let base_reference = base_file
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string());
+
+    let mut outputs = Vec::with_capacity(extras.len() + 1);
+    outputs.push(Output {
+        omit: all
+            .iter()
+            .filter(|name| extras.iter().any(|(_, packages)| packages.contains(*name)))
+            .cloned()
+            .collect(),
+        file: Some(base_file),
+        base: None,
+    });
+    for (file, packages) in extras {
+        outputs.push(Output {
+            file: Some(file),
+            omit: all.difference(&packages).cloned().collect(),
+            base: base_reference.clone(),
+        });
+    }
+    outputs
+}
+
+/// A `pip compile` resolution, persisted to the cache.
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedResolution {
+    /// The number of packages in the resolution.
+    packages: usize,
+    /// The distributions in the resolution that were selected from a registry.
+    pins: Vec<CachedPin>,
+    /// The rendered output, including any ANSI escape codes.
+    output: String,
+}
+
+/// A distribution selected from a registry, as recorded in a [`CachedResolution`].
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedPin {
+    name: PackageName,
+    version: Version,
+    index: IndexUrl,
+    filename: String,
+    /// Whether the file was yanked at the time of resolution.
+    yanked: bool,
+}
+
+impl CachedPin {
+    /// Collect the distributions in the resolution that were selected from one of the given
+    /// indexes. (Distributions from `--find-links` locations are covered by the snapshot of local
+    /// files instead.)
+    fn from_resolution(
+        resolution: &ResolutionGraph,
+        index_locations: &IndexLocations,
+    ) -> Vec<Self> {
+        resolution
+            .petgraph()
+            .node_weights()
+            .filter_map(|dist| match dist {
+                ResolvedDist::Installable(Dist::Built(BuiltDist::Registry(wheel))) => Some((
+                    &wheel.filename.name,
+                    &wheel.filename.version,
+                    &wheel.index,
+                    &*wheel.file,
+                )),
+                ResolvedDist::Installable(Dist::Source(SourceDist::Registry(sdist))) => Some((
+                    &sdist.filename.name,
+                    &sdist.filename.version,
+                    &sdist.index,
+                    &*sdist.file,
+                )),
+                _ => None,
+            })
+            .filter(|(.., index, _)| index_locations.indexes().any(|url| url == *index))
+            .map(|(name, version, index, file)| Self {
+                name: name.clone(),
+                version: version.clone(),
+                index: index.clone(),
+                filename: file.filename.clone(),
+                yanked: file.yanked.as_ref().is_some_and(Yanked::is_yanked),
+            })
+            .collect()
+    }
+
+    /// Returns `true` if the file is still available on the index, and its yanked status is
+    /// unchanged since the resolution was cached.
+    async fn is_current(&self, client: &RegistryClient) -> bool {
+        let Ok(results) = client.simple(&self.name).await else {
+            return false;
+        };
+        results
+            .into_iter()
+            .filter(|(index, _)| *index == self.index)
+            .find_map(|(_, archive)| {
+                OwnedArchive::deserialize(&archive)
+                    .into_iter()
+                    .find(|datum| datum.version == self.version)
+            })
+            .and_then(|datum| {
+                datum
+                    .files
+                    .all()
+                    .find(|(_, file)| file.filename == self.filename)
+            })
+            .is_some_and(|(_, file)| {
+                file.yanked.as_ref().is_some_and(Yanked::is_yanked) == self.yanked
+            })
+    }
+}
+
+/// Read a cached resolution, if it matches the current contents of the output file and the current
+/// state of the index.
+///
+/// If the output file was modified (e.g., to change a pin), or any pinned file was since yanked or
+/// removed from its index, the resolution can't be reused.
+async fn read_resolution(
+    entry: &CacheEntry,
+    output_file: &Path,
+    client: &RegistryClient,
+) -> Option<CachedResolution> {
+    let resolution = fs_err::read(entry.path()).ok()?;
+    let resolution = rmp_serde::from_slice::<CachedResolution>(&resolution).ok()?;
+    let existing = fs_err::read_to_string(output_file).ok()?;
+    if anstream::adapter::strip_str(&resolution.output).to_string() != existing {
+        return None;
+    }
+
+    let current = futures::stream::iter(&resolution.pins)
+        .map(|pin| pin.is_current(client))
+        .buffer_unordered(50)
+        .all(|current| async move { current })
+        .await;
+    if !current {
+        debug!("Index state changed since the resolution was cached");
+        return None;
+    }
+
+    Some(resolution)
+}
+
+/// Write a resolution to the cache.
+fn write_resolution(entry: &CacheEntry, resolution: &CachedResolution) -> Result<()> {
+    fs_err::create_dir_all(entry.dir())?;
+    uv_fs::write_atomic_sync(entry.path(), rmp_serde::to_vec(resolution)?)?;
+    Ok(())
+}
+
+/// Take a snapshot of the local files on which a resolution depends, as the path, size, and
+/// timestamp of each file: the contents of any `--find-links` directories, along with any local
+/// archives among the requirements.
+///
+/// Returns `None` if the resolution depends on inputs that a snapshot can't capture, like remote
+/// `--find-links` pages or local source trees.
+fn snapshot_local_files<'a>(
+    find_links: impl Iterator<Item = &'a FlatIndexLocation>,
+    urls: impl Iterator<Item = &'a Url>,
+) -> Option<Vec<(PathBuf, u64, Timestamp)>> {
+    let mut snapshot = Vec::new();
+
+    for location in find_links {
+        let path = match location {
+            FlatIndexLocation::Path(path) => path.clone(),
+            FlatIndexLocation::Url(url) if url.scheme() == "file" => url.to_file_path().ok()?,
+            FlatIndexLocation::Url(_) => return None,
+        };
+        let mut entries = fs_err::read_dir(&path)
+            .ok()?
+            .map(|entry| {
+                let entry = entry?;
+                let metadata = entry.metadata()?;
+                Ok((
+                    entry.path(),
+                    metadata.len(),
+                    Timestamp::from_metadata(&metadata),
+                ))
+            })
+            .collect::<std::io::Result<Vec<_>>>()
+            .ok()?;
+        entries.sort_unstable();
+        snapshot.extend(entries);
+    }
+
+    for url in urls {
+        if url.scheme() != "file" {
+            continue;
+        }
+        let path = url.to_file_path().ok()?;
+        let metadata = fs_err::metadata(&path).ok()?;
+        if !metadata.is_file() {
+            return None;
+        }
+        snapshot.push((path, metadata.len(), Timestamp::from_metadata(&metadata)));
+    }
+
+    Some(snapshot)
+}
+
+/// Return the URL of a direct URL requirement, if any.
+fn requirement_url(requirement: &Requirement) -> Option<&Url> {
+    match &requirement.version_or_url {
+        Some(pep508_rs::VersionOrUrl::Url(url)) => Some(url),
+        _ => None,
+    }
+}
+
+/// Return the marker values of the target environment, for the key of a cached resolution.
+fn markers_key(markers: &MarkerEnvironment) -> Vec<&str> {
+    vec![
+        &markers.implementation_name,
+        &markers.implementation_version.string,
+        &markers.os_name,
+        &markers.platform_machine,
+        &markers.platform_python_implementation,
+        &markers.platform_release,
+        &markers.platform_system,
+        &markers.platform_version,
+        &markers.python_full_version.string,
+        &markers.python_version.string,
+        &markers.sys_platform,
+    ]
+}
+
+/// Format each item as a string, for the key of a cached resolution.
+fn to_strings<T: ToString>(items: impl IntoIterator<Item = T>) -> Vec<String> {
+    items.into_iter().map(|item| item.to_string()).collect()
 }
 
 /// Format the `uv` command used to generate the output file.
@@ -604,6 +1638,12 @@ fn cmd(
                 return Some(None);
             }
 
+            // Always skip the `--summary` and `--release-notes` flags.
+            if arg == "--summary" || arg == "--release-notes" {
+                *skip_next = None;
+                return Some(None);
+            }
+
             // Always skip the `--quiet` flag.
             if arg == "--quiet" || arg == "-q" {
                 *skip_next = None;
@@ -629,6 +1669,8 @@ fn cmd(
 struct OutputWriter {
     stdout: Option<AutoStream<std::io::Stdout>>,
     output_file: Option<StripStream<std::fs::File>>,
+    /// A copy of everything written, such that the output can be cached.
+    buffer: String,
 }
 
 #[allow(clippy::disallowed_types)]
@@ -645,9 +1687,15 @@ impl OutputWriter {
         Ok(Self {
             stdout,
             output_file,
+            buffer: String::new(),
         })
     }
 
+    /// Return everything written so far.
+    fn into_buffer(self) -> String {
+        self.buffer
+    }
+
     /// Write the given arguments to both the standard output and the output file, if present.
     fn write_fmt(&mut self, args: std::fmt::Arguments<'_>) -> std::io::Result<()> {
         use std::io::Write;
@@ -660,6 +1708,8 @@ impl OutputWriter {
             write!(stdout, "{args}")?;
         }
 
+        self.buffer.push_str(&args.to_string());
+
         Ok(())
     }
 }
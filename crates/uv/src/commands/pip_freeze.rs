@@ -1,9 +1,11 @@
 use std::fmt::Write;
+use std::path::{Path, PathBuf};
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
 use tracing::debug;
+use unicode_width::UnicodeWidthStr;
 
 use distribution_types::{InstalledDist, Name};
 use uv_cache::Cache;
@@ -15,9 +17,14 @@ use crate::commands::ExitStatus;
 use crate::printer::Printer;
 
 /// Enumerate the installed packages in the current environment.
+///
+/// If `paths` is non-empty, the packages in the given `site-packages` directories are enumerated
+/// instead. If multiple directories are given, each package is annotated with the directory that
+/// contains it, along with the tool that installed it.
 pub(crate) fn pip_freeze(
     exclude_editable: bool,
     strict: bool,
+    paths: &[PathBuf],
     python: Option<&str>,
     system: bool,
     cache: &Cache,
@@ -45,23 +52,52 @@ pub(crate) fn pip_freeze(
     );
 
     // Build the installed index.
-    let site_packages = SitePackages::from_executable(&venv)?;
-    for dist in site_packages
+    let site_packages = if paths.is_empty() {
+        SitePackages::from_executable(&venv)?
+    } else {
+        for path in paths {
+            if !path.is_dir() {
+                bail!("Directory not found: {}", path.user_display());
+            }
+        }
+        SitePackages::from_paths(&venv, paths.iter().map(PathBuf::as_path))?
+    };
+
+    let dists = site_packages
         .iter()
         .filter(|dist| !(exclude_editable && dist.is_editable()))
-        .sorted_unstable_by(|a, b| a.name().cmp(b.name()).then(a.version().cmp(b.version())))
-    {
-        match dist {
-            InstalledDist::Registry(dist) => {
-                writeln!(printer.stdout(), "{}=={}", dist.name().bold(), dist.version)?;
-            }
-            InstalledDist::Url(dist) => {
-                if dist.editable {
-                    writeln!(printer.stdout(), "-e {}", dist.url)?;
-                } else {
-                    writeln!(printer.stdout(), "{} @ {}", dist.name().bold(), dist.url)?;
-                }
-            }
+        .sorted_unstable_by(|a, b| {
+            a.name()
+                .cmp(b.name())
+                .then(a.version().cmp(b.version()))
+                .then(a.path().cmp(b.path()))
+        })
+        .collect_vec();
+
+    if paths.len() > 1 {
+        // Annotate each package with its provenance, aligning the annotations into a column.
+        let width = dists
+            .iter()
+            .map(|dist| requirement(dist).width())
+            .max()
+            .unwrap_or(0);
+        for dist in dists {
+            let requirement = requirement(dist);
+            let location = dist.path().parent().unwrap_or_else(|| Path::new(""));
+            let installer = dist.installer()?;
+            let installer = installer.as_deref().map_or("unknown", str::trim);
+            writeln!(
+                printer.stdout(),
+                "{}{:padding$}  {}",
+                styled(dist),
+                "",
+                format!("# {} ({installer})", location.user_display()).dimmed(),
+                padding = width - requirement.width(),
+            )?;
+        }
+    } else {
+        for dist in dists {
+            writeln!(printer.stdout(), "{}", styled(dist))?;
         }
     }
 
@@ -80,3 +116,31 @@ pub(crate) fn pip_freeze(
 
     Ok(ExitStatus::Success)
 }
+
+/// Format an installed distribution as a requirement.
+fn requirement(dist: &InstalledDist) -> String {
+    match dist {
+        InstalledDist::Registry(dist) => format!("{}=={}", dist.name(), dist.version),
+        InstalledDist::Url(dist) => {
+            if dist.editable {
+                format!("-e {}", dist.url)
+            } else {
+                format!("{} @ {}", dist.name(), dist.url)
+            }
+        }
+    }
+}
+
+/// Format an installed distribution as a requirement, for display.
+fn styled(dist: &InstalledDist) -> String {
+    match dist {
+        InstalledDist::Registry(dist) => format!("{}=={}", dist.name().bold(), dist.version),
+        InstalledDist::Url(dist) => {
+            if dist.editable {
+                format!("-e {}", dist.url)
+            } else {
+                format!("{} @ {}", dist.name().bold(), dist.url)
+            }
+        }
+    }
+}
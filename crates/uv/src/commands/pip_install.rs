@@ -1,22 +1,25 @@
 use std::fmt::Write;
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::pin::pin;
 
-use anstream::eprint;
 use anyhow::{anyhow, Context, Result};
 
+use futures::channel::mpsc::UnboundedSender;
+use futures::future::{select, Either};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use rustc_hash::{FxHashMap, FxHashSet};
 use tempfile::tempdir_in;
 use tracing::debug;
 
 use distribution_types::{
-    DistributionMetadata, IndexLocations, InstalledMetadata, LocalDist, LocalEditable,
+    Dist, DistributionMetadata, IndexLocations, InstalledMetadata, LocalDist, LocalEditable,
     LocalEditables, Name, Resolution,
 };
 use install_wheel_rs::linker::LinkMode;
-use pep508_rs::{MarkerEnvironment, Requirement};
-use platform_tags::Tags;
+use pep508_rs::{MarkerEnvironment, Requirement, RequirementsTxtRequirement};
+use platform_tags::{Tags, TargetLibc};
 use pypi_types::{Metadata23, Yanked};
 use requirements_txt::EditableRequirement;
 use uv_cache::Cache;
@@ -26,26 +29,34 @@ use uv_client::{
 use uv_configuration::KeyringProviderType;
 use uv_configuration::{
     ConfigSettings, Constraints, IndexStrategy, NoBinary, NoBuild, Overrides, Reinstall,
-    SetupPyStrategy, Upgrade,
+    ScriptConflictStrategy, SetupPyStrategy, TagPolicy, Upgrade,
 };
 use uv_dispatch::BuildDispatch;
 use uv_fs::Simplified;
-use uv_installer::{BuiltEditable, Downloader, Plan, Planner, ResolvedEditable, SitePackages};
+use uv_installer::{
+    BuiltEditable, Downloader, Plan, Planner, ResolvedEditable, SitePackages, WheelPolicy,
+};
 use uv_interpreter::{Interpreter, PythonEnvironment};
-use uv_normalize::PackageName;
+use uv_normalize::{ExtraName, PackageName};
 use uv_requirements::{
     ExtrasSpecification, LookaheadResolver, NamedRequirementsResolver, RequirementsSource,
     RequirementsSpecification, SourceTreeResolver,
 };
 use uv_resolver::{
-    DependencyMode, ExcludeNewer, Exclusions, FlatIndex, InMemoryIndex, Manifest, Options,
-    OptionsBuilder, PreReleaseMode, Preference, ResolutionGraph, ResolutionMode, Resolver,
+    DependencyMode, ExcludeNewer, Exclusions, FlatIndex, InMemoryIndex, Manifest,
+    MinimumReleaseAge, Options, OptionsBuilder, PreReleaseMode, PreferLocal, Preference,
+    ResolutionGraph, ResolutionMode, Resolver,
 };
 use uv_types::{BuildIsolation, HashStrategy, InFlight};
 use uv_warnings::warn_user;
 
+use crate::commands::install_report::InstallationReport;
 use crate::commands::reporters::{DownloadReporter, InstallReporter, ResolverReporter};
-use crate::commands::{compile_bytecode, elapsed, ChangeEvent, ChangeEventKind, ExitStatus};
+use crate::commands::{
+    compile_bytecode, elapsed, externally_managed_error, target_tags, ChangeEvent, ChangeEventKind,
+    ExitStatus,
+};
+use crate::errors::report_no_solution;
 use crate::printer::Printer;
 
 use super::DryRunEvent;
@@ -59,6 +70,8 @@ pub(crate) async fn pip_install(
     extras: &ExtrasSpecification<'_>,
     resolution_mode: ResolutionMode,
     prerelease_mode: PreReleaseMode,
+    prefer_local: PreferLocal,
+    minimum_release_age: MinimumReleaseAge,
     dependency_mode: DependencyMode,
     upgrade: Upgrade,
     index_locations: IndexLocations,
@@ -66,29 +79,40 @@ pub(crate) async fn pip_install(
     keyring_provider: KeyringProviderType,
     reinstall: Reinstall,
     link_mode: LinkMode,
+    script_conflicts: ScriptConflictStrategy,
+    wheel_policy: Option<&WheelPolicy>,
     compile: bool,
     require_hashes: bool,
     setup_py: SetupPyStrategy,
     connectivity: Connectivity,
     config_settings: &ConfigSettings,
     no_build_isolation: bool,
+    no_build_isolation_package: Vec<PackageName>,
     no_build: NoBuild,
     no_binary: NoBinary,
+    libc: Option<TargetLibc>,
+    force_manylinux: bool,
+    tag_policy: TagPolicy,
+    platform_preference: &[String],
     strict: bool,
     exclude_newer: Option<ExcludeNewer>,
     python: Option<String>,
     system: bool,
+    user: bool,
+    prefix: Option<PathBuf>,
     break_system_packages: bool,
-    native_tls: bool,
+    client_builder: &BaseClientBuilder<'_>,
     cache: Cache,
     dry_run: bool,
+    report: Option<PathBuf>,
+    prefetch: usize,
     printer: Printer,
 ) -> Result<ExitStatus> {
     let start = std::time::Instant::now();
 
-    let client_builder = BaseClientBuilder::new()
+    let client_builder = client_builder
+        .clone()
         .connectivity(connectivity)
-        .native_tls(native_tls)
         .keyring(keyring_provider);
 
     // Read all requirements from the provided sources.
@@ -120,6 +144,14 @@ pub(crate) async fn pip_install(
         PythonEnvironment::from_requested_python(python, &cache)?
     } else if system {
         PythonEnvironment::from_default_python(&cache)?
+    } else if user || prefix.is_some() {
+        match PythonEnvironment::from_virtualenv(&cache) {
+            Ok(venv) => venv,
+            Err(uv_interpreter::Error::VenvNotFound) => {
+                PythonEnvironment::from_default_python(&cache)?
+            }
+            Err(err) => return Err(err.into()),
+        }
     } else {
         PythonEnvironment::from_virtualenv(&cache)?
     };
@@ -129,27 +161,54 @@ pub(crate) async fn pip_install(
         venv.python_executable().user_display().cyan()
     );
 
-    // If the environment is externally managed, abort.
-    if let Some(externally_managed) = venv.interpreter().is_externally_managed() {
-        if break_system_packages {
-            debug!("Ignoring externally managed environment due to `--break-system-packages`");
-        } else {
-            return if let Some(error) = externally_managed.into_error() {
+    // If requested, install into the user site, rather than the environment itself.
+    let venv = if user {
+        let user_site = venv.interpreter().query_user_site(&cache)?;
+        if !user_site.enabled {
+            return if venv.interpreter().is_virtualenv() {
                 Err(anyhow::anyhow!(
-                    "The interpreter at {} is externally managed, and indicates the following:\n\n{}\n\nConsider creating a virtual environment with `uv venv`.",
-                    venv.root().user_display().cyan(),
-                    textwrap::indent(&error, "  ").green(),
+                    "Can't perform a `--user` install, as user site-packages are not visible in the virtual environment at {}. Instead, create the virtual environment with `--system-site-packages`.",
+                    venv.root().user_display().cyan()
                 ))
             } else {
                 Err(anyhow::anyhow!(
-                    "The interpreter at {} is externally managed. Instead, create a virtual environment with `uv venv`.",
-                    venv.root().user_display().cyan()
+                    "Can't perform a `--user` install, as user site-packages are disabled for the interpreter at {} (e.g., due to `PYTHONNOUSERSITE`).",
+                    venv.python_executable().user_display().cyan()
                 ))
             };
         }
+        debug!(
+            "Installing into user site at {}",
+            user_site.scheme.purelib.user_display().cyan()
+        );
+        PythonEnvironment::from_interpreter(
+            venv.interpreter().clone().with_scheme(user_site.scheme),
+        )
+    } else if let Some(prefix) = prefix.as_deref() {
+        let prefix = uv_fs::absolutize_path(prefix)?;
+        let scheme = venv.interpreter().query_prefix_scheme(&prefix, &cache)?;
+        debug!(
+            "Installing into prefix at {}",
+            scheme.purelib.user_display().cyan()
+        );
+        PythonEnvironment::from_interpreter(venv.interpreter().clone().with_scheme(scheme))
+    } else {
+        venv
+    };
+
+    // If the environment is externally managed, abort. Like `pip`, installing into a custom
+    // prefix skips the check, since the prefix isn't part of the managed environment.
+    if let Some(externally_managed) = venv.interpreter().is_externally_managed() {
+        if prefix.is_some() {
+            debug!("Ignoring externally managed environment due to `--prefix`");
+        } else if break_system_packages {
+            debug!("Ignoring externally managed environment due to `--break-system-packages`");
+        } else {
+            return Err(externally_managed_error(&venv, externally_managed));
+        }
     }
 
-    let _lock = venv.lock()?;
+    let _lock = venv.lock(cache.lock_timeout())?;
 
     // Determine the set of installed packages.
     let site_packages = SitePackages::from_executable(&venv)?;
@@ -159,6 +218,7 @@ pub(crate) async fn pip_install(
     // magnitude faster to validate the environment than to resolve the requirements.
     if reinstall.is_none()
         && upgrade.is_none()
+        && report.is_none()
         && source_trees.is_empty()
         && overrides.is_empty()
         && site_packages.satisfies(&requirements, &editables, &constraints)?
@@ -177,13 +237,31 @@ pub(crate) async fn pip_install(
         )?;
         if dry_run {
             writeln!(printer.stderr(), "Would make no changes")?;
+        } else {
+            mark_requested(
+                requirements
+                    .iter()
+                    .filter_map(|entry| match &entry.requirement {
+                        RequirementsTxtRequirement::Pep508(requirement) => Some(&requirement.name),
+                        RequirementsTxtRequirement::Unnamed(_) => None,
+                    }),
+                &venv,
+            )?;
         }
         return Ok(ExitStatus::Success);
     }
 
     // Determine the tags, markers, and interpreter to use for resolution.
     let interpreter = venv.interpreter().clone();
-    let tags = venv.interpreter().tags()?;
+    let tags = target_tags(
+        venv.interpreter(),
+        None,
+        libc,
+        force_manylinux,
+        tag_policy,
+        platform_preference,
+    )?;
+    let tags = tags.as_ref();
     let markers = venv.interpreter().markers();
 
     // Collect the set of required hashes.
@@ -204,12 +282,9 @@ pub(crate) async fn pip_install(
         index_locations.combine(index_url, extra_index_urls, find_links, no_index);
 
     // Initialize the registry client.
-    let client = RegistryClientBuilder::new(cache.clone())
-        .native_tls(native_tls)
-        .connectivity(connectivity)
+    let client = RegistryClientBuilder::from_base_client(cache.clone(), client_builder.clone())
         .index_urls(index_locations.index_urls())
         .index_strategy(index_strategy)
-        .keyring(keyring_provider)
         .markers(markers)
         .platform(interpreter.platform())
         .build();
@@ -224,8 +299,10 @@ pub(crate) async fn pip_install(
     // Determine whether to enable build isolation.
     let build_isolation = if no_build_isolation {
         BuildIsolation::Shared(&venv)
-    } else {
+    } else if no_build_isolation_package.is_empty() {
         BuildIsolation::Isolated
+    } else {
+        BuildIsolation::SharedPackage(&venv, &no_build_isolation_package)
     };
 
     // Combine the `--no-binary` and `--no-build` flags.
@@ -254,7 +331,12 @@ pub(crate) async fn pip_install(
         &no_build,
         &no_binary,
     )
-    .with_options(OptionsBuilder::new().exclude_newer(exclude_newer).build());
+    .with_options(
+        OptionsBuilder::new()
+            .exclude_newer(exclude_newer)
+            .minimum_release_age(minimum_release_age.clone())
+            .build(),
+    );
 
     // Resolve the requirements from the provided sources.
     let requirements = {
@@ -322,59 +404,104 @@ pub(crate) async fn pip_install(
     let options = OptionsBuilder::new()
         .resolution_mode(resolution_mode)
         .prerelease_mode(prerelease_mode)
+        .prefer_local(prefer_local)
+        .minimum_release_age(minimum_release_age)
         .dependency_mode(dependency_mode)
         .exclude_newer(exclude_newer)
         .build();
 
-    // Resolve the requirements.
-    let resolution = match resolve(
-        requirements,
-        constraints,
-        overrides,
-        project,
-        &editables,
-        &hasher,
-        &site_packages,
-        &reinstall,
-        &upgrade,
-        &interpreter,
-        tags,
-        markers,
-        &client,
-        &flat_index,
-        &index,
-        &resolve_dispatch,
-        options,
-        printer,
-    )
-    .await
+    // Track the packages that were requested directly, for the installation report.
+    let mut requested = FxHashMap::<PackageName, Vec<ExtraName>>::default();
+    for (name, extras) in requirements
+        .iter()
+        .map(|requirement| (&requirement.name, &requirement.extras))
+        .chain(editables.iter().map(|built_editable| {
+            (
+                &built_editable.metadata.name,
+                &built_editable.editable.extras,
+            )
+        }))
     {
-        Ok(resolution) => Resolution::from(resolution),
+        requested
+            .entry(name.clone())
+            .or_default()
+            .extend(extras.iter().cloned());
+    }
+
+    // Track in-flight downloads for the installation. Any wheels prefetched during resolution are
+    // registered here, such that the installer waits on them rather than fetching them again.
+    let install_in_flight = InFlight::default();
+
+    // Download the wheels for the selected versions while the resolution is in progress. Don't
+    // prefetch any wheels if we won't install them.
+    let (prefetch_sink, prefetch_stream) = futures::channel::mpsc::unbounded();
+    let prefetch_sink = (prefetch > 0 && !dry_run).then_some(prefetch_sink);
+    let prefetcher = Downloader::new(&cache, tags, &hasher, &client, &resolve_dispatch);
+    let prefetch_fut = pin!(prefetcher.prefetch(prefetch_stream, prefetch, &install_in_flight));
+
+    // Resolve the requirements, without waiting on any outstanding prefetches.
+    let (resolution, prefetch_fut) = {
+        let resolve_fut = pin!(resolve(
+            requirements,
+            constraints,
+            overrides,
+            project,
+            &editables,
+            &hasher,
+            &site_packages,
+            &reinstall,
+            &upgrade,
+            &interpreter,
+            tags,
+            markers,
+            &client,
+            &flat_index,
+            &index,
+            &resolve_dispatch,
+            options,
+            prefetch_sink,
+            printer,
+        ));
+        match select(resolve_fut, prefetch_fut).await {
+            Either::Left((resolution, prefetch_fut)) => (resolution, Some(prefetch_fut)),
+            Either::Right(((), resolve_fut)) => (resolve_fut.await, None),
+        }
+    };
+
+    let resolution = match resolution {
+        Ok(resolution) => {
+            if let Some(report) = report.as_deref() {
+                InstallationReport::from_resolution(
+                    &resolution,
+                    &requested,
+                    &editables,
+                    &index,
+                    markers,
+                )?
+                .write(report, printer)?;
+            }
+            Resolution::from(resolution)
+        }
         Err(Error::Resolve(uv_resolver::ResolveError::NoSolution(err))) => {
-            let report = miette::Report::msg(format!("{err}"))
-                .context("No solution found when resolving dependencies:");
-            eprint!("{report:?}");
-            return Ok(ExitStatus::Failure);
+            return Ok(report_no_solution(&err, printer.error_format()));
         }
         Err(err) => return Err(err.into()),
     };
 
-    // Re-initialize the in-flight map.
-    let in_flight = InFlight::default();
-
     // If we're running with `--reinstall`, initialize a separate `BuildDispatch`, since we may
     // end up removing some distributions from the environment.
+    let reinstall_dispatch;
     let install_dispatch = if reinstall.is_none() {
-        resolve_dispatch
+        &resolve_dispatch
     } else {
-        BuildDispatch::new(
+        reinstall_dispatch = BuildDispatch::new(
             &client,
             &cache,
             &interpreter,
             &index_locations,
             &flat_index,
             &index,
-            &in_flight,
+            &install_in_flight,
             setup_py,
             config_settings,
             build_isolation,
@@ -382,30 +509,54 @@ pub(crate) async fn pip_install(
             &no_build,
             &no_binary,
         )
-        .with_options(OptionsBuilder::new().exclude_newer(exclude_newer).build())
+        .with_options(
+            OptionsBuilder::new()
+                .exclude_newer(exclude_newer)
+                .minimum_release_age(minimum_release_age.clone())
+                .build(),
+        );
+        &reinstall_dispatch
     };
 
-    // Sync the environment.
-    install(
+    // Sync the environment, while driving any outstanding prefetches, since the installer may be
+    // waiting on them.
+    let install_fut = pin!(install(
         &resolution,
+        &requested,
         editables,
         site_packages,
         &reinstall,
         &no_binary,
         link_mode,
+        script_conflicts,
+        wheel_policy,
         compile,
         &index_locations,
         &hasher,
         tags,
         &client,
-        &in_flight,
-        &install_dispatch,
+        &install_in_flight,
+        install_dispatch,
         &cache,
         &venv,
         dry_run,
         printer,
-    )
-    .await?;
+    ));
+    match prefetch_fut {
+        // Once the installation completes, any remaining prefetches are for versions that the
+        // resolver backtracked away from, and can be dropped.
+        Some(prefetch_fut) => match select(install_fut, prefetch_fut).await {
+            Either::Left((result, _)) => result?,
+            Either::Right(((), install_fut)) => install_fut.await?,
+        },
+        None => install_fut.await?,
+    }
+
+    // Mark any requested packages that were already installed (as a dependency of another
+    // package) as requested.
+    if !dry_run {
+        mark_requested(requested.keys(), &venv)?;
+    }
 
     // Validate the environment.
     if strict {
@@ -550,6 +701,7 @@ async fn resolve(
     index: &InMemoryIndex,
     build_dispatch: &BuildDispatch<'_>,
     options: Options,
+    prefetch: Option<UnboundedSender<Dist>>,
     printer: Printer,
 ) -> Result<ResolutionGraph, Error> {
     let start = std::time::Instant::now();
@@ -621,6 +773,10 @@ async fn resolve(
         site_packages,
     )?
     .with_reporter(ResolverReporter::from(printer));
+    let resolver = match prefetch {
+        Some(prefetch) => resolver.with_prefetch(prefetch),
+        None => resolver,
+    };
     let resolution = resolver.resolve().await?;
 
     let s = if resolution.len() == 1 { "" } else { "s" };
@@ -653,11 +809,14 @@ async fn resolve(
 #[allow(clippy::too_many_arguments)]
 async fn install(
     resolution: &Resolution,
+    requested: &FxHashMap<PackageName, Vec<ExtraName>>,
     built_editables: Vec<BuiltEditable>,
     site_packages: SitePackages<'_>,
     reinstall: &Reinstall,
     no_binary: &NoBinary,
     link_mode: LinkMode,
+    script_conflicts: ScriptConflictStrategy,
+    wheel_policy: Option<&WheelPolicy>,
     compile: bool,
     index_urls: &IndexLocations,
     hasher: &HashStrategy,
@@ -684,6 +843,7 @@ async fn install(
     // downloaded (`remote`), and those that should be removed (`extraneous`).
     let plan = Planner::with_requirements(&requirements)
         .with_editable_requirements(&editables)
+        .with_signature_policy(client.signature_policy())
         .build(
             site_packages,
             reinstall,
@@ -708,6 +868,9 @@ async fn install(
         extraneous: _,
     } = plan;
 
+    // Record the cache hit rate, for `uv cache info`.
+    cache.record_stats("pip install", cached.len(), remote.len());
+
     // Nothing to do.
     if remote.is_empty() && cached.is_empty() && reinstalls.is_empty() {
         let s = if resolution.len() == 1 { "" } else { "s" };
@@ -764,6 +927,19 @@ async fn install(
         wheels
     };
 
+    // Check the contents of the wheels against the policy, before making any changes.
+    let wheels = wheels.into_iter().chain(cached).collect::<Vec<_>>();
+    if let Some(wheel_policy) = wheel_policy {
+        wheel_policy.check(&wheels)?;
+    }
+
+    // Detect any conflicting scripts, before making any changes.
+    let installer = uv_installer::Installer::new(venv)
+        .with_link_mode(link_mode)
+        .with_script_conflicts(script_conflicts)
+        .with_requested(requested.keys().cloned().collect())
+        .check_script_conflicts(&wheels, &FxHashSet::default())?;
+
     // Remove any existing installations.
     if !reinstalls.is_empty() {
         for dist_info in &reinstalls {
@@ -792,11 +968,9 @@ async fn install(
     }
 
     // Install the resolved distributions.
-    let wheels = wheels.into_iter().chain(cached).collect::<Vec<_>>();
     if !wheels.is_empty() {
         let start = std::time::Instant::now();
-        uv_installer::Installer::new(venv)
-            .with_link_mode(link_mode)
+        installer
             .with_reporter(InstallReporter::from(printer).with_length(wheels.len() as u64))
             .install(&wheels)?;
 
@@ -1043,14 +1217,44 @@ fn validate(
     Ok(())
 }
 
+/// Mark the installed distributions of the given packages as `REQUESTED`, e.g., if they were
+/// previously installed as a dependency of another package.
+fn mark_requested<'a>(
+    names: impl IntoIterator<Item = &'a PackageName>,
+    venv: &PythonEnvironment,
+) -> Result<(), Error> {
+    let site_packages = SitePackages::from_executable(venv)?;
+    for name in names {
+        for dist in site_packages.get_packages(name) {
+            if dist.is_requested() {
+                continue;
+            }
+            match install_wheel_rs::mark_requested(dist.path()) {
+                Ok(()) => debug!("Marked {} as requested", dist.name()),
+                Err(install_wheel_rs::Error::MissingRecord(_)) => {
+                    debug!(
+                        "Unable to mark {} as requested (missing RECORD)",
+                        dist.name()
+                    );
+                }
+                Err(err) => return Err(anyhow::Error::from(err).into()),
+            }
+        }
+    }
+    Ok(())
+}
+
 #[derive(thiserror::Error, Debug)]
-enum Error {
+pub(crate) enum Error {
     #[error(transparent)]
     Resolve(#[from] uv_resolver::ResolveError),
 
     #[error(transparent)]
     Uninstall(#[from] uv_installer::UninstallError),
 
+    #[error(transparent)]
+    WheelPolicy(#[from] uv_installer::WheelPolicyError),
+
     #[error(transparent)]
     Client(#[from] uv_client::Error),
 
@@ -4,7 +4,7 @@ use std::fmt::Write;
 use anyhow::Result;
 use itertools::Itertools;
 use owo_colors::OwoColorize;
-use serde::Serialize;
+use rustc_hash::FxHashSet;
 use tracing::debug;
 use unicode_width::UnicodeWidthStr;
 
@@ -15,6 +15,7 @@ use uv_installer::SitePackages;
 use uv_interpreter::PythonEnvironment;
 use uv_normalize::PackageName;
 
+use crate::commands::installed::InstalledPackage;
 use crate::commands::ExitStatus;
 use crate::printer::Printer;
 
@@ -26,6 +27,8 @@ pub(crate) fn pip_list(
     editable: bool,
     exclude_editable: bool,
     exclude: &[PackageName],
+    not_required: bool,
+    user_requested: bool,
     format: &ListFormat,
     strict: bool,
     python: Option<&str>,
@@ -57,6 +60,13 @@ pub(crate) fn pip_list(
     // Build the installed index.
     let site_packages = SitePackages::from_executable(&venv)?;
 
+    // Determine the packages that are required by other packages, for `--not-required`.
+    let required = if not_required {
+        site_packages.required()?
+    } else {
+        FxHashSet::default()
+    };
+
     // Filter if `--editable` is specified; always sort by name.
     let results = site_packages
         .iter()
@@ -64,6 +74,8 @@ pub(crate) fn pip_list(
             (!dist.is_editable() && !editable) || (dist.is_editable() && !exclude_editable)
         })
         .filter(|dist| !exclude.contains(dist.name()))
+        .filter(|dist| !required.contains(dist.name()))
+        .filter(|dist| !user_requested || dist.is_requested())
         .sorted_unstable_by(|a, b| a.name().cmp(b.name()).then(a.version().cmp(b.version())))
         .collect_vec();
     if results.is_empty() {
@@ -113,7 +125,19 @@ pub(crate) fn pip_list(
             }
         }
         ListFormat::Json => {
-            let rows = results.iter().copied().map(Entry::from).collect_vec();
+            // Like `pip`, only include the provenance of each package in verbose mode.
+            let rows = results
+                .iter()
+                .copied()
+                .map(|dist| {
+                    let package = InstalledPackage::from(dist);
+                    if printer.is_verbose() {
+                        package.with_provenance(dist)
+                    } else {
+                        Ok(package)
+                    }
+                })
+                .collect::<Result<Vec<_>>>()?;
             let output = serde_json::to_string(&rows)?;
             writeln!(printer.stdout(), "{output}")?;
         }
@@ -145,27 +169,6 @@ pub(crate) fn pip_list(
     Ok(ExitStatus::Success)
 }
 
-/// An entry in a JSON list of installed packages.
-#[derive(Debug, Serialize)]
-struct Entry {
-    name: String,
-    version: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    editable_project_location: Option<String>,
-}
-
-impl From<&InstalledDist> for Entry {
-    fn from(dist: &InstalledDist) -> Self {
-        Self {
-            name: dist.name().to_string(),
-            version: dist.version().to_string(),
-            editable_project_location: dist
-                .as_editable()
-                .map(|url| url.to_file_path().unwrap().simplified_display().to_string()),
-        }
-    }
-}
-
 #[derive(Debug)]
 struct Column {
     /// The header of the column.
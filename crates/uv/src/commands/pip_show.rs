@@ -4,21 +4,26 @@ use anyhow::Result;
 use itertools::{Either, Itertools};
 use owo_colors::OwoColorize;
 use rustc_hash::FxHashMap;
+use serde::Serialize;
 use tracing::debug;
 
-use distribution_types::Name;
+use distribution_types::{InstalledDist, Name};
+use install_wheel_rs::{FileStatus, InstalledFile};
 use uv_cache::Cache;
 use uv_fs::Simplified;
 use uv_installer::SitePackages;
 use uv_interpreter::PythonEnvironment;
 use uv_normalize::PackageName;
 
-use crate::commands::ExitStatus;
+use crate::commands::installed::InstalledPackage;
+use crate::commands::{ExitStatus, ShowFormat};
 use crate::printer::Printer;
 
 /// Show information about one or more installed packages.
 pub(crate) fn pip_show(
     mut packages: Vec<PackageName>,
+    format: ShowFormat,
+    files: bool,
     strict: bool,
     python: Option<&str>,
     system: bool,
@@ -89,7 +94,7 @@ pub(crate) fn pip_show(
         )?;
     }
 
-    let distributions = distributions.iter().flatten().collect_vec();
+    let distributions = distributions.iter().flatten().copied().collect_vec();
 
     // Like `pip`, if no packages were found, return a failure.
     if distributions.is_empty() {
@@ -137,64 +142,128 @@ pub(crate) fn pip_show(
         }
     }
 
-    // Print the information for each package.
-    for (i, distribution) in distributions.iter().enumerate() {
-        if i > 0 {
-            // Print a separator between packages.
-            writeln!(printer.stdout(), "---")?;
-        }
+    // Determine the packages that require the given package.
+    let required_by = |name: &PackageName| {
+        requires_map
+            .iter()
+            .filter(|(other, pkgs)| **other != name && pkgs.iter().any(|pkg| pkg == name))
+            .map(|(other, _)| *other)
+            .sorted_unstable()
+            .dedup()
+            .collect_vec()
+    };
 
-        // Print the name, version, and location (e.g., the `site-packages` directory).
-        writeln!(printer.stdout(), "Name: {}", distribution.name())?;
-        writeln!(printer.stdout(), "Version: {}", distribution.version())?;
-        writeln!(
-            printer.stdout(),
-            "Location: {}",
-            distribution
-                .path()
-                .parent()
-                .expect("package path is not root")
-                .simplified_display()
-        )?;
+    // In verbose mode, verify the listed files against their `RECORD` entries.
+    let verify = printer.is_verbose();
 
-        if let Some(path) = distribution
-            .as_editable()
-            .and_then(|url| url.to_file_path().ok())
-        {
+    if let ShowFormat::Json = format {
+        let entries = distributions
+            .iter()
+            .map(|distribution| {
+                let requires = requires_map.get(distribution.name());
+                Ok(Entry {
+                    package: InstalledPackage::from(*distribution).with_provenance(distribution)?,
+                    requires: requires
+                        .map(|requires| requires.iter().map(ToString::to_string).collect()),
+                    required_by: requires.map(|_| {
+                        required_by(distribution.name())
+                            .into_iter()
+                            .map(ToString::to_string)
+                            .collect()
+                    }),
+                    files: if files {
+                        read_files(distribution, verify)?.map(|files| {
+                            files
+                                .into_iter()
+                                .map(|file| FileEntry {
+                                    path: file.path,
+                                    status: file.status.map(status_label),
+                                })
+                                .collect()
+                        })
+                    } else {
+                        None
+                    },
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let output = serde_json::to_string(&entries)?;
+        writeln!(printer.stdout(), "{output}")?;
+    } else {
+        // Print the information for each package.
+        for (i, distribution) in distributions.iter().enumerate() {
+            if i > 0 {
+                // Print a separator between packages.
+                writeln!(printer.stdout(), "---")?;
+            }
+
+            // Print the name, version, and location (e.g., the `site-packages` directory).
+            writeln!(printer.stdout(), "Name: {}", distribution.name())?;
+            writeln!(printer.stdout(), "Version: {}", distribution.version())?;
             writeln!(
                 printer.stdout(),
-                "Editable project location: {}",
-                path.simplified_display()
+                "Location: {}",
+                distribution
+                    .path()
+                    .parent()
+                    .expect("package path is not root")
+                    .simplified_display()
             )?;
-        }
-
-        // If available, print the requirements.
-        if let Some(requires) = requires_map.get(distribution.name()) {
-            if requires.is_empty() {
-                writeln!(printer.stdout(), "Requires:")?;
-            } else {
-                writeln!(printer.stdout(), "Requires: {}", requires.iter().join(", "))?;
-            }
 
-            let required_by = requires_map
-                .iter()
-                .filter(|(name, pkgs)| {
-                    **name != distribution.name()
-                        && pkgs.iter().any(|pkg| pkg == distribution.name())
-                })
-                .map(|(name, _)| name)
-                .sorted_unstable()
-                .dedup()
-                .collect_vec();
-            if required_by.is_empty() {
-                writeln!(printer.stdout(), "Required-by:")?;
-            } else {
+            if let Some(path) = distribution
+                .as_editable()
+                .and_then(|url| url.to_file_path().ok())
+            {
                 writeln!(
                     printer.stdout(),
-                    "Required-by: {}",
-                    required_by.into_iter().join(", "),
+                    "Editable project location: {}",
+                    path.simplified_display()
                 )?;
             }
+
+            // If available, print the requirements.
+            if let Some(requires) = requires_map.get(distribution.name()) {
+                if requires.is_empty() {
+                    writeln!(printer.stdout(), "Requires:")?;
+                } else {
+                    writeln!(printer.stdout(), "Requires: {}", requires.iter().join(", "))?;
+                }
+
+                let required_by = required_by(distribution.name());
+                if required_by.is_empty() {
+                    writeln!(printer.stdout(), "Required-by:")?;
+                } else {
+                    writeln!(
+                        printer.stdout(),
+                        "Required-by: {}",
+                        required_by.into_iter().join(", "),
+                    )?;
+                }
+            }
+
+            // If requested, print the installed files, flagging any that were modified.
+            if files {
+                writeln!(printer.stdout(), "Files:")?;
+                let Some(files) = read_files(distribution, verify)? else {
+                    writeln!(printer.stdout(), "  Cannot locate RECORD")?;
+                    continue;
+                };
+                for file in files {
+                    match file.status {
+                        Some(status @ (FileStatus::Modified | FileStatus::Missing)) => {
+                            writeln!(
+                                printer.stdout(),
+                                "  {} {}",
+                                file.path,
+                                format!("({})", status_label(status)).red()
+                            )?;
+                        }
+                        _ => {
+                            writeln!(printer.stdout(), "  {}", file.path)?;
+                        }
+                    }
+                }
+            }
         }
     }
 
@@ -213,3 +282,42 @@ pub(crate) fn pip_show(
 
     Ok(ExitStatus::Success)
 }
+
+/// A package in the JSON output of `uv pip show`.
+#[derive(Debug, Serialize)]
+struct Entry {
+    #[serde(flatten)]
+    package: InstalledPackage,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    requires: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    required_by: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    files: Option<Vec<FileEntry>>,
+}
+
+/// An installed file in the JSON output of `uv pip show --files`.
+#[derive(Debug, Serialize)]
+struct FileEntry {
+    path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<&'static str>,
+}
+
+/// Read the files installed by a distribution, returning `None` if it has no `RECORD`.
+fn read_files(dist: &InstalledDist, verify: bool) -> Result<Option<Vec<InstalledFile>>> {
+    match install_wheel_rs::installed_files(dist.path(), verify) {
+        Ok(files) => Ok(Some(files)),
+        Err(install_wheel_rs::Error::MissingRecord(_)) => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+fn status_label(status: FileStatus) -> &'static str {
+    match status {
+        FileStatus::Unchanged => "unchanged",
+        FileStatus::Modified => "modified",
+        FileStatus::Missing => "missing",
+        FileStatus::Unverifiable => "unverifiable",
+    }
+}
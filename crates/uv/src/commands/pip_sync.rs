@@ -1,18 +1,22 @@
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Write;
+use std::path::Path;
 
-use anstream::eprint;
 use anyhow::{anyhow, Context, Result};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use rustc_hash::{FxHashMap, FxHashSet};
 use tracing::debug;
 
 use distribution_types::{
-    IndexLocations, InstalledMetadata, LocalDist, LocalEditable, LocalEditables, Name, ResolvedDist,
+    IndexLocations, InstalledDist, InstalledMetadata, LocalDist, LocalEditable, LocalEditables,
+    Name, ResolvedDist,
 };
 use install_wheel_rs::linker::LinkMode;
 
-use platform_tags::Tags;
-use pypi_types::Yanked;
+use pep508_rs::{Requirement, VersionOrUrl};
+use platform_tags::{Tags, TargetLibc};
+use pypi_types::{DirectUrl, HashAlgorithm, HashDigest, Yanked};
 use requirements_txt::EditableRequirement;
 use uv_cache::{ArchiveTarget, ArchiveTimestamp, Cache};
 use uv_client::{
@@ -20,22 +24,34 @@ use uv_client::{
 };
 use uv_configuration::KeyringProviderType;
 use uv_configuration::{
-    ConfigSettings, IndexStrategy, NoBinary, NoBuild, Reinstall, SetupPyStrategy,
+    ConfigSettings, Constraints, IndexStrategy, InstallFilter, NoBinary, NoBuild, Overrides,
+    Reinstall, ScriptConflictStrategy, SetupPyStrategy, SyncLayer, TagPolicy,
 };
 use uv_dispatch::BuildDispatch;
+use uv_extract::hash::Hasher;
 use uv_fs::Simplified;
-use uv_installer::{is_dynamic, Downloader, Plan, Planner, ResolvedEditable, SitePackages};
+use uv_installer::{
+    is_dynamic, Downloader, Plan, Planner, ResolvedEditable, SitePackages, Transaction, WheelPolicy,
+};
 use uv_interpreter::{Interpreter, PythonEnvironment};
+use uv_normalize::PackageName;
 use uv_requirements::{
     ExtrasSpecification, NamedRequirementsResolver, RequirementsSource, RequirementsSpecification,
     SourceTreeResolver,
 };
-use uv_resolver::{DependencyMode, FlatIndex, InMemoryIndex, Manifest, OptionsBuilder, Resolver};
+use uv_resolver::{
+    DependencyMode, Exclusions, FlatIndex, InMemoryIndex, Manifest, OptionsBuilder, PreReleaseMode,
+    Preference, Resolver,
+};
 use uv_types::{BuildIsolation, EmptyInstalledPackages, HashStrategy, InFlight};
 use uv_warnings::warn_user;
 
 use crate::commands::reporters::{DownloadReporter, InstallReporter, ResolverReporter};
-use crate::commands::{compile_bytecode, elapsed, ChangeEvent, ChangeEventKind, ExitStatus};
+use crate::commands::{
+    compile_bytecode, elapsed, externally_managed_error, target_tags, ChangeEvent, ChangeEventKind,
+    ExitStatus,
+};
+use crate::errors::report_no_solution;
 use crate::printer::Printer;
 
 /// Install a set of locked requirements into the current Python environment.
@@ -43,7 +59,13 @@ use crate::printer::Printer;
 pub(crate) async fn pip_sync(
     sources: &[RequirementsSource],
     reinstall: &Reinstall,
+    install_filter: &InstallFilter,
+    no_install_package_deps: bool,
+    layer: Option<SyncLayer>,
+    no_prune: &[PackageName],
     link_mode: LinkMode,
+    script_conflicts: ScriptConflictStrategy,
+    wheel_policy: Option<&WheelPolicy>,
     compile: bool,
     require_hashes: bool,
     index_locations: IndexLocations,
@@ -53,21 +75,27 @@ pub(crate) async fn pip_sync(
     connectivity: Connectivity,
     config_settings: &ConfigSettings,
     no_build_isolation: bool,
+    no_build_isolation_package: Vec<PackageName>,
     no_build: NoBuild,
     no_binary: NoBinary,
+    libc: Option<TargetLibc>,
+    force_manylinux: bool,
+    tag_policy: TagPolicy,
+    platform_preference: &[String],
     strict: bool,
+    atomic: bool,
     python: Option<String>,
     system: bool,
     break_system_packages: bool,
-    native_tls: bool,
+    client_builder: &BaseClientBuilder<'_>,
     cache: Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
     let start = std::time::Instant::now();
 
-    let client_builder = BaseClientBuilder::new()
+    let client_builder = client_builder
+        .clone()
         .connectivity(connectivity)
-        .native_tls(native_tls)
         .keyring(keyring_provider);
 
     // Read all requirements from the provided sources.
@@ -113,25 +141,22 @@ pub(crate) async fn pip_sync(
         if break_system_packages {
             debug!("Ignoring externally managed environment due to `--break-system-packages`");
         } else {
-            return if let Some(error) = externally_managed.into_error() {
-                Err(anyhow::anyhow!(
-                    "The interpreter at {} is externally managed, and indicates the following:\n\n{}\n\nConsider creating a virtual environment with `uv venv`.",
-                    venv.root().user_display().cyan(),
-                    textwrap::indent(&error, "  ").green(),
-                ))
-            } else {
-                Err(anyhow::anyhow!(
-                    "The interpreter at {} is externally managed. Instead, create a virtual environment with `uv venv`.",
-                    venv.root().user_display().cyan()
-                ))
-            };
+            return Err(externally_managed_error(&venv, externally_managed));
         }
     }
 
-    let _lock = venv.lock()?;
+    let _lock = venv.lock(cache.lock_timeout())?;
 
     // Determine the current environment markers.
-    let tags = venv.interpreter().tags()?;
+    let tags = target_tags(
+        venv.interpreter(),
+        None,
+        libc,
+        force_manylinux,
+        tag_policy,
+        platform_preference,
+    )?;
+    let tags = tags.as_ref();
     let markers = venv.interpreter().markers();
 
     // Collect the set of required hashes.
@@ -151,12 +176,9 @@ pub(crate) async fn pip_sync(
         index_locations.combine(index_url, extra_index_urls, find_links, no_index);
 
     // Initialize the registry client.
-    let client = RegistryClientBuilder::new(cache.clone())
-        .native_tls(native_tls)
-        .connectivity(connectivity)
+    let client = RegistryClientBuilder::from_base_client(cache.clone(), client_builder.clone())
         .index_urls(index_locations.index_urls())
         .index_strategy(index_strategy)
-        .keyring(keyring_provider)
         .markers(venv.interpreter().markers())
         .platform(venv.interpreter().platform())
         .build();
@@ -177,8 +199,10 @@ pub(crate) async fn pip_sync(
     // Determine whether to enable build isolation.
     let build_isolation = if no_build_isolation {
         BuildIsolation::Shared(&venv)
-    } else {
+    } else if no_build_isolation_package.is_empty() {
         BuildIsolation::Isolated
+    } else {
+        BuildIsolation::SharedPackage(&venv, &no_build_isolation_package)
     };
 
     // Combine the `--no-binary` and `--no-build` flags.
@@ -234,8 +258,72 @@ pub(crate) async fn pip_sync(
         requirements
     };
 
+    // If requested, restrict the requirements to those in the given layer.
+    let (requirements, editables) = match layer {
+        None => (requirements, editables),
+        Some(SyncLayer::Deps) => (
+            requirements
+                .into_iter()
+                .filter(|requirement| !is_project_requirement(requirement))
+                .collect(),
+            Vec::new(),
+        ),
+        Some(SyncLayer::Project) => (
+            requirements
+                .into_iter()
+                .filter(is_project_requirement)
+                .collect(),
+            editables,
+        ),
+    };
+
+    // Emit a hash of the layer's inputs, e.g., for cache invalidation in build scripts.
+    if layer.is_some() {
+        let mut inputs = requirements
+            .iter()
+            .map(ToString::to_string)
+            .chain(editables.iter().map(ToString::to_string))
+            .chain(
+                index_locations
+                    .indexes()
+                    .map(|index| format!("index-url=={index}")),
+            )
+            .chain(
+                index_locations
+                    .flat_index()
+                    .map(|flat_index| format!("find-links=={flat_index}")),
+            )
+            .chain([
+                format!("python=={}", venv.interpreter().python_version()),
+                format!("tags=={tags}"),
+            ])
+            .collect::<BTreeSet<_>>();
+
+        // Include the contents of any local project sources, which may change without changing
+        // the requirement itself.
+        let sources = requirements
+            .iter()
+            .filter_map(|requirement| match &requirement.version_or_url {
+                Some(VersionOrUrl::Url(url)) => url.to_file_path().ok(),
+                _ => None,
+            })
+            .filter(|path| path.is_dir())
+            .chain(editables.iter().map(|editable| editable.path.clone()));
+        for source in sources {
+            let digest = source_tree_digest(&source).with_context(|| {
+                format!("Failed to hash project source: {}", source.user_display())
+            })?;
+            inputs.insert(format!("source=={}:{digest}", source.user_display()));
+        }
+
+        writeln!(printer.stdout(), "{}", cache_key::digest(&inputs))?;
+    }
+
+    // Retain the editable requirements, to map any installed editables back to their sources.
+    let editable_requirements = editables.clone();
+
     // Resolve any editables.
-    let resolved_editables = resolve_editables(
+    let mut resolved_editables = resolve_editables(
         editables,
         &site_packages,
         reinstall,
@@ -249,6 +337,109 @@ pub(crate) async fn pip_sync(
     )
     .await?;
 
+    // Determine the packages to skip, per `--only-package` and `--no-install-package`.
+    let mut skipped = requirements
+        .iter()
+        .map(|requirement| &requirement.name)
+        .chain(resolved_editables.editables.iter().map(Name::name))
+        .filter(|name| !install_filter.includes(name))
+        .cloned()
+        .collect::<FxHashSet<_>>();
+
+    // If requested, skip any dependencies that are only required by the excluded packages.
+    if no_install_package_deps && !skipped.is_empty() {
+        // Include the editables, such that their dependencies are attributed to them.
+        let editables = resolved_editables
+            .editables
+            .iter()
+            .map(|editable| match editable {
+                ResolvedEditable::Built(built) => {
+                    Ok((built.editable.clone(), built.metadata.clone()))
+                }
+                ResolvedEditable::Installed(dist) => {
+                    let requirement = editable_requirements
+                        .iter()
+                        .find(|requirement| dist.as_editable() == Some(requirement.raw()))
+                        .with_context(|| format!("Failed to find the source for: {dist}"))?;
+                    let editable = LocalEditable {
+                        url: requirement.url.clone(),
+                        path: requirement.path.clone(),
+                        extras: requirement.extras.clone(),
+                    };
+                    Ok((editable, dist.metadata()?))
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Prefer the installed versions of any dependencies that aren't pinned in the
+        // requirements, as in `pip install`.
+        let preferences = site_packages
+            .requirements()
+            .map(Preference::from_requirement)
+            .collect();
+
+        // The requirements are pinned, so accept any pre-release versions they select.
+        let options = OptionsBuilder::new()
+            .prerelease_mode(PreReleaseMode::Allow)
+            .build();
+
+        // Resolve the requirements to determine the dependencies between them.
+        let manifest = Manifest::new(
+            requirements.clone(),
+            Constraints::default(),
+            Overrides::default(),
+            preferences,
+            None,
+            editables,
+            Exclusions::default(),
+            Vec::new(),
+        );
+        let resolver = Resolver::new(
+            manifest,
+            options,
+            venv.interpreter().markers(),
+            venv.interpreter(),
+            tags,
+            &client,
+            &flat_index,
+            &index,
+            &hasher,
+            &build_dispatch,
+            &site_packages,
+        )?
+        .with_reporter(ResolverReporter::from(printer));
+        let resolution = resolver.resolve().await?;
+
+        let graph = resolution.petgraph();
+        let mut dependencies = FxHashMap::<&PackageName, Vec<&PackageName>>::default();
+        for edge in graph.raw_edges() {
+            dependencies
+                .entry(graph[edge.source()].name())
+                .or_default()
+                .push(graph[edge.target()].name());
+        }
+
+        let excluded = requirements
+            .iter()
+            .map(|requirement| &requirement.name)
+            .chain(resolved_editables.editables.iter().map(Name::name))
+            .filter(|name| install_filter.excludes(name))
+            .collect::<Vec<_>>();
+        let unique = unique_dependencies(&excluded, &dependencies);
+        for name in &unique {
+            debug!("Skipping dependency of excluded package: {name}");
+        }
+        skipped.extend(unique.into_iter().cloned());
+    }
+
+    let requirements = requirements
+        .into_iter()
+        .filter(|requirement| !skipped.contains(&requirement.name))
+        .collect::<Vec<_>>();
+    resolved_editables
+        .editables
+        .retain(|editable| !skipped.contains(editable.name()));
+
     // Partition into those that should be linked from the cache (`cached`), those that need to be
     // downloaded (`remote`), and those that should be removed (`extraneous`).
     let Plan {
@@ -259,6 +450,8 @@ pub(crate) async fn pip_sync(
         extraneous,
     } = Planner::with_requirements(&requirements)
         .with_editable_requirements(&resolved_editables.editables)
+        .with_exact(true)
+        .with_signature_policy(client.signature_policy())
         .build(
             site_packages,
             reinstall,
@@ -271,6 +464,29 @@ pub(crate) async fn pip_sync(
         )
         .context("Failed to determine installation plan")?;
 
+    // Record the cache hit rate, for `uv cache info`.
+    cache.record_stats("pip sync", cached.len(), remote.len());
+
+    // Leave any skipped packages (or packages in another layer, or exempt from pruning) in place,
+    // rather than removing them.
+    let extraneous = extraneous
+        .into_iter()
+        .filter(|dist| !skipped.contains(dist.name()) && install_filter.includes(dist.name()))
+        .filter(|dist| {
+            if no_prune.contains(dist.name()) {
+                debug!("Preserving package exempt from pruning: {dist}");
+                false
+            } else {
+                true
+            }
+        })
+        .filter(|dist| match layer {
+            None => true,
+            Some(SyncLayer::Deps) => !is_project_dist(dist),
+            Some(SyncLayer::Project) => is_project_dist(dist),
+        })
+        .collect::<Vec<_>>();
+
     // Nothing to do.
     if remote.is_empty() && cached.is_empty() && reinstalls.is_empty() && extraneous.is_empty() {
         let s = if num_requirements == 1 { "" } else { "s" };
@@ -294,9 +510,8 @@ pub(crate) async fn pip_sync(
     } else {
         let start = std::time::Instant::now();
 
-        // Determine the tags, markers, and interpreter to use for resolution.
+        // Determine the markers and interpreter to use for resolution.
         let interpreter = venv.interpreter();
-        let tags = interpreter.tags()?;
         let markers = interpreter.markers();
 
         // Resolve with `--no-deps`.
@@ -326,10 +541,7 @@ pub(crate) async fn pip_sync(
 
         let resolution = match resolver.resolve().await {
             Err(uv_resolver::ResolveError::NoSolution(err)) => {
-                let report = miette::Report::msg(format!("{err}"))
-                    .context("No solution found when resolving dependencies:");
-                eprint!("{report:?}");
-                return Ok(ExitStatus::Failure);
+                return Ok(report_no_solution(&err, printer.error_format()));
             }
             result => result,
         }?;
@@ -384,71 +596,114 @@ pub(crate) async fn pip_sync(
         wheels
     };
 
-    // Remove any unnecessary packages.
-    if !extraneous.is_empty() || !reinstalls.is_empty() {
-        let start = std::time::Instant::now();
+    // Check the contents of the wheels against the policy, before making any changes.
+    let wheels = wheels.into_iter().chain(cached).collect::<Vec<_>>();
+    if let Some(wheel_policy) = wheel_policy {
+        wheel_policy.check(&wheels)?;
+    }
+
+    // Detect any conflicting scripts, before making any changes.
+    let installer = uv_installer::Installer::new(&venv)
+        .with_link_mode(link_mode)
+        .with_script_conflicts(script_conflicts)
+        .check_script_conflicts(
+            &wheels,
+            &extraneous.iter().map(|dist| dist.name().clone()).collect(),
+        )?;
+
+    // If requested, apply the changes as a transaction, such that the environment can be restored
+    // to its previous state on failure.
+    let mut transaction = if atomic {
+        Some(Transaction::begin(&venv)?)
+    } else {
+        None
+    };
+
+    let result = async {
+        // Remove any unnecessary packages.
+        if !extraneous.is_empty() || !reinstalls.is_empty() {
+            let start = std::time::Instant::now();
 
-        for dist_info in extraneous.iter().chain(reinstalls.iter()) {
-            match uv_installer::uninstall(dist_info).await {
-                Ok(summary) => {
-                    debug!(
-                        "Uninstalled {} ({} file{}, {} director{})",
-                        dist_info.name(),
-                        summary.file_count,
-                        if summary.file_count == 1 { "" } else { "s" },
-                        summary.dir_count,
-                        if summary.dir_count == 1 { "y" } else { "ies" },
-                    );
+            for dist_info in extraneous.iter().chain(reinstalls.iter()) {
+                if let Some(transaction) = transaction.as_mut() {
+                    transaction.backup(dist_info)?;
                 }
-                Err(uv_installer::UninstallError::Uninstall(
-                    install_wheel_rs::Error::MissingRecord(_),
-                )) => {
-                    warn_user!(
-                        "Failed to uninstall package at {} due to missing RECORD file. Installation may result in an incomplete environment.",
-                        dist_info.path().user_display().cyan(),
-                    );
+                match uv_installer::uninstall(dist_info).await {
+                    Ok(summary) => {
+                        debug!(
+                            "Uninstalled {} ({} file{}, {} director{})",
+                            dist_info.name(),
+                            summary.file_count,
+                            if summary.file_count == 1 { "" } else { "s" },
+                            summary.dir_count,
+                            if summary.dir_count == 1 { "y" } else { "ies" },
+                        );
+                    }
+                    Err(uv_installer::UninstallError::Uninstall(
+                        install_wheel_rs::Error::MissingRecord(_),
+                    )) => {
+                        warn_user!(
+                            "Failed to uninstall package at {} due to missing RECORD file. Installation may result in an incomplete environment.",
+                            dist_info.path().user_display().cyan(),
+                        );
+                    }
+                    Err(err) => return Err(err.into()),
                 }
-                Err(err) => return Err(err.into()),
             }
+
+            let s = if extraneous.len() + reinstalls.len() == 1 {
+                ""
+            } else {
+                "s"
+            };
+            writeln!(
+                printer.stderr(),
+                "{}",
+                format!(
+                    "Uninstalled {} in {}",
+                    format!("{} package{}", extraneous.len() + reinstalls.len(), s).bold(),
+                    elapsed(start.elapsed())
+                )
+                .dimmed()
+            )?;
         }
 
-        let s = if extraneous.len() + reinstalls.len() == 1 {
-            ""
-        } else {
-            "s"
-        };
-        writeln!(
-            printer.stderr(),
-            "{}",
-            format!(
-                "Uninstalled {} in {}",
-                format!("{} package{}", extraneous.len() + reinstalls.len(), s).bold(),
-                elapsed(start.elapsed())
-            )
-            .dimmed()
-        )?;
-    }
+        // Install the resolved distributions.
+        if !wheels.is_empty() {
+            let start = std::time::Instant::now();
+            if let Some(transaction) = transaction.as_mut() {
+                transaction.stage(&wheels)?;
+            }
+            installer
+                .with_reporter(InstallReporter::from(printer).with_length(wheels.len() as u64))
+                .install(&wheels)?;
 
-    // Install the resolved distributions.
-    let wheels = wheels.into_iter().chain(cached).collect::<Vec<_>>();
-    if !wheels.is_empty() {
-        let start = std::time::Instant::now();
-        uv_installer::Installer::new(&venv)
-            .with_link_mode(link_mode)
-            .with_reporter(InstallReporter::from(printer).with_length(wheels.len() as u64))
-            .install(&wheels)?;
+            let s = if wheels.len() == 1 { "" } else { "s" };
+            writeln!(
+                printer.stderr(),
+                "{}",
+                format!(
+                    "Installed {} in {}",
+                    format!("{} package{}", wheels.len(), s).bold(),
+                    elapsed(start.elapsed())
+                )
+                .dimmed()
+            )?;
+        }
 
-        let s = if wheels.len() == 1 { "" } else { "s" };
-        writeln!(
-            printer.stderr(),
-            "{}",
-            format!(
-                "Installed {} in {}",
-                format!("{} package{}", wheels.len(), s).bold(),
-                elapsed(start.elapsed())
-            )
-            .dimmed()
-        )?;
+        Ok::<(), anyhow::Error>(())
+    }
+    .await;
+
+    match (transaction, result) {
+        (Some(transaction), Ok(())) => transaction.commit()?,
+        (Some(transaction), Err(err)) => {
+            transaction
+                .rollback()
+                .context("Failed to restore the environment to its previous state")?;
+            return Err(err.context("Failed to sync the environment; no changes were applied"));
+        }
+        (None, result) => result?,
     }
 
     if compile {
@@ -551,6 +806,98 @@ struct ResolvedEditables {
     temp_dir: Option<tempfile::TempDir>,
 }
 
+/// Returns `true` if the requirement belongs to the project layer, i.e., if it refers to a local
+/// directory.
+fn is_project_requirement(requirement: &Requirement) -> bool {
+    matches!(
+        &requirement.version_or_url,
+        Some(VersionOrUrl::Url(url)) if url.to_file_path().is_ok_and(|path| path.is_dir())
+    )
+}
+
+/// Compute a digest of the contents of a local source tree, for the layer hash.
+///
+/// Hidden files and directories (e.g., `.git`), `__pycache__` directories, and any virtual
+/// environments within the tree are skipped, as they don't affect the built project.
+fn source_tree_digest(root: &Path) -> Result<String> {
+    let mut files = BTreeMap::new();
+    let mut queue = vec![root.to_path_buf()];
+    while let Some(dir) = queue.pop() {
+        for entry in fs_err::read_dir(&dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            let name = entry.file_name();
+            if name.to_string_lossy().starts_with('.') || name == "__pycache__" {
+                continue;
+            }
+            let file_type = entry.file_type()?;
+            if file_type.is_dir() {
+                if !path.join("pyvenv.cfg").is_file() {
+                    queue.push(path);
+                }
+            } else if file_type.is_file() {
+                let mut hasher = Hasher::from(HashAlgorithm::Sha256);
+                hasher.update(&fs_err::read(&path)?);
+                let relative = path
+                    .strip_prefix(root)
+                    .expect("source tree entries are within the root")
+                    .to_string_lossy()
+                    .replace('\\', "/");
+                files.insert(relative, HashDigest::from(hasher).digest.to_string());
+            }
+        }
+    }
+    Ok(cache_key::digest(&files))
+}
+
+/// Returns `true` if the installed distribution belongs to the project layer, i.e., if it was
+/// installed from a local directory (editable or otherwise).
+fn is_project_dist(dist: &InstalledDist) -> bool {
+    dist.is_editable()
+        || matches!(
+            InstalledDist::direct_url(dist.path()),
+            Ok(Some(DirectUrl::LocalDirectory { .. }))
+        )
+}
+
+/// Returns the dependencies of the `excluded` packages that aren't required by any other package,
+/// i.e., the packages that are only reachable through the excluded packages.
+fn unique_dependencies<'a>(
+    excluded: &[&'a PackageName],
+    dependencies: &FxHashMap<&'a PackageName, Vec<&'a PackageName>>,
+) -> FxHashSet<&'a PackageName> {
+    // Find all packages that are reachable from the excluded packages.
+    let mut reachable = FxHashSet::default();
+    let mut queue = excluded.to_vec();
+    while let Some(name) = queue.pop() {
+        if reachable.insert(name) {
+            queue.extend(dependencies.get(name).into_iter().flatten().copied());
+        }
+    }
+
+    // Find all packages that are reachable from any other package, without passing through an
+    // excluded package.
+    let mut required = FxHashSet::default();
+    let mut queue = dependencies
+        .iter()
+        .flat_map(|(name, dependencies)| std::iter::once(*name).chain(dependencies.iter().copied()))
+        .filter(|name| !reachable.contains(name))
+        .collect::<Vec<_>>();
+    while let Some(name) = queue.pop() {
+        if excluded.contains(&name) {
+            continue;
+        }
+        if required.insert(name) {
+            queue.extend(dependencies.get(name).into_iter().flatten().copied());
+        }
+    }
+
+    reachable
+        .into_iter()
+        .filter(|name| !excluded.contains(name) && !required.contains(name))
+        .collect()
+}
+
 /// Resolve the set of editables that need to be installed.
 #[allow(clippy::too_many_arguments)]
 async fn resolve_editables(
@@ -1,19 +1,21 @@
 use std::fmt::Write;
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use itertools::{Either, Itertools};
 use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
 use tracing::debug;
 
-use distribution_types::{InstalledMetadata, Name};
+use distribution_types::{InstalledDist, InstalledMetadata, Name};
 use pep508_rs::{Requirement, RequirementsTxtRequirement, UnnamedRequirement};
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, Connectivity};
 use uv_configuration::KeyringProviderType;
 use uv_fs::Simplified;
 use uv_interpreter::PythonEnvironment;
+use uv_warnings::warn_user;
 
-use crate::commands::{elapsed, ExitStatus};
+use crate::commands::{elapsed, externally_managed_error, ExitStatus};
 use crate::printer::Printer;
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
 
@@ -24,16 +26,18 @@ pub(crate) async fn pip_uninstall(
     python: Option<String>,
     system: bool,
     break_system_packages: bool,
+    force: bool,
+    auto_remove: bool,
     cache: Cache,
     connectivity: Connectivity,
-    native_tls: bool,
+    client_builder: &BaseClientBuilder<'_>,
     keyring_provider: KeyringProviderType,
     printer: Printer,
 ) -> Result<ExitStatus> {
     let start = std::time::Instant::now();
-    let client_builder = BaseClientBuilder::new()
+    let client_builder = client_builder
+        .clone()
         .connectivity(connectivity)
-        .native_tls(native_tls)
         .keyring(keyring_provider);
 
     // Read all requirements from the provided sources.
@@ -58,22 +62,11 @@ pub(crate) async fn pip_uninstall(
         if break_system_packages {
             debug!("Ignoring externally managed environment due to `--break-system-packages`");
         } else {
-            return if let Some(error) = externally_managed.into_error() {
-                Err(anyhow::anyhow!(
-                    "The interpreter at {} is externally managed, and indicates the following:\n\n{}\n\nConsider creating a virtual environment with `uv venv`.",
-                    venv.root().user_display().cyan(),
-                    textwrap::indent(&error, "  ").green(),
-                ))
-            } else {
-                Err(anyhow::anyhow!(
-                    "The interpreter at {} is externally managed. Instead, create a virtual environment with `uv venv`.",
-                    venv.root().user_display().cyan()
-                ))
-            };
+            return Err(externally_managed_error(&venv, externally_managed));
         }
     }
 
-    let _lock = venv.lock()?;
+    let _lock = venv.lock(cache.lock_timeout())?;
 
     // Index the current `site-packages` directory.
     let site_packages = uv_installer::SitePackages::from_executable(&venv)?;
@@ -111,7 +104,7 @@ pub(crate) async fn pip_uninstall(
     };
 
     // Map to the local distributions.
-    let distributions = {
+    let mut distributions = {
         let mut distributions = Vec::with_capacity(names.len() + urls.len());
 
         // Identify all packages that are installed.
@@ -152,6 +145,19 @@ pub(crate) async fn pip_uninstall(
         distributions
     };
 
+    // If requested, remove any dependencies that are no longer required.
+    if auto_remove {
+        let removed = distributions
+            .iter()
+            .map(|dist| dist.name().clone())
+            .collect::<FxHashSet<_>>();
+        for orphan in site_packages.orphans(&removed)? {
+            debug!("Removing orphaned dependency: {}", orphan.name());
+            distributions.push(orphan);
+        }
+        distributions.sort_unstable_by_key(|dist| dist.path());
+    }
+
     if distributions.is_empty() {
         writeln!(
             printer.stderr(),
@@ -162,16 +168,57 @@ pub(crate) async fn pip_uninstall(
         return Ok(ExitStatus::Success);
     }
 
+    // Refuse to uninstall any packages that can't be fully removed, or that are managed by another
+    // tool, before making any changes to the environment.
+    if !force {
+        for distribution in &distributions {
+            match Provenance::from_dist(distribution)? {
+                Provenance::Record => {}
+                Provenance::Legacy => bail!(
+                    "Cannot uninstall `{}`, as it has no RECORD file (e.g., because it was installed by `distutils`); use `--force` to remove it on a best-effort basis",
+                    distribution.name()
+                ),
+                Provenance::External(installer) => bail!(
+                    "Cannot uninstall `{}`, as it's managed by `{installer}`; use `{installer}` to remove it instead, or `--force` to remove it anyway",
+                    distribution.name()
+                ),
+            }
+        }
+    }
+
     // Uninstall each package.
     for distribution in &distributions {
-        let summary = uv_installer::uninstall(distribution).await?;
+        let (file_count, dir_count) = if distribution.path().join("RECORD").is_file() {
+            let summary = uv_installer::uninstall(distribution).await?;
+            (summary.file_count, summary.dir_count)
+        } else {
+            let summary = uv_installer::uninstall_legacy(distribution).await?;
+            if summary.top_level {
+                for module in &summary.missing {
+                    warn_user!(
+                        "Unable to find the top-level module `{module}` of `{}`",
+                        distribution.name()
+                    );
+                }
+                warn_user!(
+                    "`{}` has no RECORD file; any scripts or data files it installed remain in the environment",
+                    distribution.name()
+                );
+            } else {
+                warn_user!(
+                    "`{}` has no RECORD or top_level.txt file; only its metadata was removed, and its files remain in the environment",
+                    distribution.name()
+                );
+            }
+            (summary.file_count, summary.dir_count)
+        };
         debug!(
             "Uninstalled {} ({} file{}, {} director{})",
             distribution.name(),
-            summary.file_count,
-            if summary.file_count == 1 { "" } else { "s" },
-            summary.dir_count,
-            if summary.dir_count == 1 { "y" } else { "ies" },
+            file_count,
+            if file_count == 1 { "" } else { "s" },
+            dir_count,
+            if dir_count == 1 { "y" } else { "ies" },
         );
     }
 
@@ -203,3 +250,28 @@ pub(crate) async fn pip_uninstall(
 
     Ok(ExitStatus::Success)
 }
+
+/// How an installed package was installed, as it pertains to its uninstallation.
+#[derive(Debug)]
+enum Provenance {
+    /// The package has a `RECORD` file, and can be uninstalled in full.
+    Record,
+    /// The package has no `RECORD` file (e.g., it was installed by `distutils`).
+    Legacy,
+    /// The package is managed by another tool, like `conda`.
+    External(String),
+}
+
+impl Provenance {
+    /// Detect the [`Provenance`] of an installed distribution from its `.dist-info` directory.
+    fn from_dist(dist: &InstalledDist) -> Result<Self> {
+        if !dist.path().join("RECORD").is_file() {
+            return Ok(Self::Legacy);
+        }
+        let installer = dist.installer()?;
+        match installer.as_deref().map(str::trim) {
+            Some(installer @ "conda") => Ok(Self::External(installer.to_string())),
+            _ => Ok(Self::Record),
+        }
+    }
+}
@@ -0,0 +1,174 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+use serde::Serialize;
+use tracing::debug;
+
+use pep508_rs::MarkerEnvironment;
+use uv_cache::Cache;
+use uv_fs::Simplified;
+use uv_interpreter::{Interpreter, PythonEnvironment};
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub(crate) enum PythonInspectFormat {
+    /// Display a human-readable summary of the interpreter.
+    #[default]
+    Text,
+    /// Display the summary in a machine-readable JSON format.
+    Json,
+}
+
+/// Show the marker environment, module search path, compatible wheel tags, and installation paths
+/// of a Python interpreter.
+pub(crate) fn python_inspect(
+    format: PythonInspectFormat,
+    python: Option<&str>,
+    system: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    // Detect the current Python interpreter.
+    let venv = if let Some(python) = python {
+        PythonEnvironment::from_requested_python(python, cache)?
+    } else if system {
+        PythonEnvironment::from_default_python(cache)?
+    } else {
+        match PythonEnvironment::from_virtualenv(cache) {
+            Ok(venv) => venv,
+            Err(uv_interpreter::Error::VenvNotFound) => {
+                PythonEnvironment::from_default_python(cache)?
+            }
+            Err(err) => return Err(err.into()),
+        }
+    };
+    let interpreter = venv.interpreter();
+
+    debug!(
+        "Inspecting Python {} at {}",
+        interpreter.python_version(),
+        venv.python_executable().user_display().cyan()
+    );
+
+    let report = Report {
+        executable: interpreter.sys_executable(),
+        version: interpreter.python_version().to_string(),
+        implementation: interpreter.implementation_name(),
+        markers: interpreter.markers(),
+        sys_path: interpreter.query_sys_path()?,
+        tags: interpreter.tags()?.prioritized(),
+        paths: Paths::from(interpreter),
+    };
+
+    match format {
+        PythonInspectFormat::Json => {
+            let output = serde_json::to_string(&report)?;
+            writeln!(printer.stdout(), "{output}")?;
+        }
+        PythonInspectFormat::Text => {
+            writeln!(
+                printer.stdout(),
+                "{}: {}",
+                "Executable".bold(),
+                report.executable.user_display().cyan()
+            )?;
+            writeln!(
+                printer.stdout(),
+                "{}: {} ({})",
+                "Version".bold(),
+                report.version,
+                report.implementation
+            )?;
+
+            writeln!(printer.stdout())?;
+            writeln!(printer.stdout(), "{}:", "Markers".bold())?;
+            if let serde_json::Value::Object(markers) = serde_json::to_value(report.markers)? {
+                for (key, value) in markers {
+                    let value = match value {
+                        serde_json::Value::String(value) => value,
+                        value => value.to_string(),
+                    };
+                    writeln!(printer.stdout(), "  {key}: {value}")?;
+                }
+            }
+
+            writeln!(printer.stdout())?;
+            writeln!(printer.stdout(), "{}:", "Module search path".bold())?;
+            for path in &report.sys_path {
+                writeln!(printer.stdout(), "  {}", path.user_display())?;
+            }
+
+            writeln!(printer.stdout())?;
+            writeln!(
+                printer.stdout(),
+                "{}:",
+                "Compatible tags (highest priority first)".bold()
+            )?;
+            for tag in &report.tags {
+                writeln!(printer.stdout(), "  {tag}")?;
+            }
+
+            writeln!(printer.stdout())?;
+            writeln!(printer.stdout(), "{}:", "Installation paths".bold())?;
+            let Paths {
+                stdlib,
+                purelib,
+                platlib,
+                scripts,
+                data,
+                include,
+            } = &report.paths;
+            for (name, path) in [
+                ("stdlib", stdlib),
+                ("purelib", purelib),
+                ("platlib", platlib),
+                ("scripts", scripts),
+                ("data", data),
+                ("include", include),
+            ] {
+                writeln!(printer.stdout(), "  {name}: {}", path.user_display())?;
+            }
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+#[derive(Serialize)]
+struct Report<'a> {
+    executable: &'a Path,
+    version: String,
+    implementation: &'a str,
+    markers: &'a MarkerEnvironment,
+    sys_path: Vec<PathBuf>,
+    tags: Vec<String>,
+    paths: Paths<'a>,
+}
+
+/// The `sysconfig` installation paths of an interpreter.
+#[derive(Serialize)]
+struct Paths<'a> {
+    stdlib: &'a Path,
+    purelib: &'a Path,
+    platlib: &'a Path,
+    scripts: &'a Path,
+    data: &'a Path,
+    include: &'a Path,
+}
+
+impl<'a> From<&'a Interpreter> for Paths<'a> {
+    fn from(interpreter: &'a Interpreter) -> Self {
+        Self {
+            stdlib: interpreter.stdlib(),
+            purelib: interpreter.purelib(),
+            platlib: interpreter.platlib(),
+            scripts: interpreter.scripts(),
+            data: interpreter.data(),
+            include: interpreter.include(),
+        }
+    }
+}
@@ -0,0 +1,203 @@
+//! Fetch the release notes for a version of a package from PyPI and GitHub, for
+//! `pip compile --summary --release-notes`.
+
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+use url::Url;
+
+use pep440_rs::Version;
+use uv_cache::{Cache, CacheBucket};
+use uv_client::{BaseClient, BetterReqwestError};
+use uv_normalize::PackageName;
+
+/// The release notes for a single version of a package.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(crate) struct ReleaseNotes {
+    /// The URL of the project's changelog, per its project URLs on PyPI.
+    pub(crate) changelog: Option<String>,
+    /// The URL of the GitHub release for the version, if any.
+    pub(crate) release: Option<String>,
+    /// The body of the GitHub release for the version, if any.
+    pub(crate) body: Option<String>,
+}
+
+/// The subset of the PyPI JSON API response for a version that's relevant to release notes.
+#[derive(Debug, Deserialize)]
+struct PypiRelease {
+    info: PypiInfo,
+}
+
+#[derive(Debug, Deserialize)]
+struct PypiInfo {
+    #[serde(default)]
+    home_page: Option<String>,
+    #[serde(default)]
+    project_urls: Option<BTreeMap<String, String>>,
+}
+
+/// The subset of the GitHub API response for a release that's relevant to release notes.
+#[derive(Debug, Deserialize)]
+struct GitHubRelease {
+    html_url: String,
+    #[serde(default)]
+    body: Option<String>,
+}
+
+/// Fetch the release notes for the given version of a package on PyPI.
+///
+/// Release notes are cached indefinitely, since they're tied to a published version; refreshing
+/// the package (e.g., via `--refresh-package`) fetches them again.
+pub(crate) async fn release_notes(
+    name: &PackageName,
+    version: &Version,
+    client: &BaseClient,
+    cache: &Cache,
+) -> Result<ReleaseNotes> {
+    let cache_entry = cache.entry(
+        CacheBucket::ReleaseNotes,
+        name.to_string(),
+        format!("{version}.json"),
+    );
+    if cache.freshness(&cache_entry, Some(name))?.is_fresh() {
+        match fs_err::tokio::read(cache_entry.path()).await {
+            Ok(cached) => match serde_json::from_slice(&cached) {
+                Ok(notes) => return Ok(notes),
+                Err(err) => debug!("Ignoring malformed cached release notes: {err}"),
+            },
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err.into()),
+        }
+    }
+
+    let mut notes = ReleaseNotes::default();
+
+    let url = format!("https://pypi.org/pypi/{name}/{version}/json");
+    if let Some(release) = fetch_json::<PypiRelease>(client, &url).await? {
+        let project_urls = release.info.project_urls.unwrap_or_default();
+        notes.changelog = changelog_url(&project_urls).map(ToString::to_string);
+
+        // Find the GitHub repository, to fetch the notes for the matching release.
+        let repository = project_urls
+            .values()
+            .chain(release.info.home_page.as_ref())
+            .find_map(|url| github_repository(url));
+        if let Some((owner, repo)) = repository {
+            for tag in [format!("v{version}"), version.to_string()] {
+                let url =
+                    format!("https://api.github.com/repos/{owner}/{repo}/releases/tags/{tag}");
+                if let Some(release) = fetch_json::<GitHubRelease>(client, &url).await? {
+                    notes.release = Some(release.html_url);
+                    notes.body = release.body.filter(|body| !body.trim().is_empty());
+                    break;
+                }
+            }
+        }
+    }
+
+    fs_err::tokio::create_dir_all(cache_entry.dir()).await?;
+    uv_fs::write_atomic(cache_entry.path(), serde_json::to_vec(&notes)?).await?;
+
+    Ok(notes)
+}
+
+/// Fetch and parse the JSON document at the given URL, returning `None` if it doesn't exist.
+async fn fetch_json<T: serde::de::DeserializeOwned>(
+    client: &BaseClient,
+    url: &str,
+) -> Result<Option<T>> {
+    debug!("Downloading: {url}");
+    let response = client.client().get(url).send().await?;
+    if response.status().as_u16() == 404 {
+        return Ok(None);
+    }
+    let bytes = response
+        .error_for_status()
+        .map_err(BetterReqwestError::from)?
+        .bytes()
+        .await
+        .map_err(BetterReqwestError::from)?;
+    Ok(Some(serde_json::from_slice(&bytes)?))
+}
+
+/// Return the changelog URL from a project's URLs, if any.
+///
+/// Project URL labels are free-form, so they're compared after normalization (e.g., `Changelog`,
+/// `Change Log`, and `changelog` are all equivalent).
+fn changelog_url(project_urls: &BTreeMap<String, String>) -> Option<&str> {
+    project_urls.iter().find_map(|(label, url)| {
+        let label = label
+            .chars()
+            .filter(char::is_ascii_alphanumeric)
+            .collect::<String>()
+            .to_ascii_lowercase();
+        matches!(
+            label.as_str(),
+            "changelog" | "changes" | "releasenotes" | "history" | "whatsnew"
+        )
+        .then_some(url.as_str())
+    })
+}
+
+/// Parse the owner and name of a GitHub repository from a URL, if it refers to one.
+fn github_repository(url: &str) -> Option<(String, String)> {
+    let url = Url::parse(url).ok()?;
+    if !matches!(url.host_str()?, "github.com" | "www.github.com") {
+        return None;
+    }
+    let mut segments = url.path_segments()?;
+    let owner = segments.next().filter(|owner| !owner.is_empty())?;
+    let repo = segments.next()?;
+    let repo = repo.strip_suffix(".git").unwrap_or(repo);
+    if repo.is_empty() {
+        return None;
+    }
+    Some((owner.to_string(), repo.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::{changelog_url, github_repository};
+
+    #[test]
+    fn changelog() {
+        let project_urls = BTreeMap::from([
+            (
+                "Documentation".to_string(),
+                "https://click.palletsprojects.com/".to_string(),
+            ),
+            (
+                "Change Log".to_string(),
+                "https://click.palletsprojects.com/changes/".to_string(),
+            ),
+        ]);
+        assert_eq!(
+            changelog_url(&project_urls),
+            Some("https://click.palletsprojects.com/changes/")
+        );
+        assert_eq!(changelog_url(&BTreeMap::new()), None);
+    }
+
+    #[test]
+    fn repository() {
+        assert_eq!(
+            github_repository("https://github.com/pallets/click/"),
+            Some(("pallets".to_string(), "click".to_string()))
+        );
+        assert_eq!(
+            github_repository("https://github.com/pallets/click.git"),
+            Some(("pallets".to_string(), "click".to_string()))
+        );
+        assert_eq!(
+            github_repository("https://github.com/pallets/click/blob/main/CHANGES.rst"),
+            Some(("pallets".to_string(), "click".to_string()))
+        );
+        assert_eq!(github_repository("https://github.com/pallets"), None);
+        assert_eq!(github_repository("https://gitlab.com/pallets/click"), None);
+        assert_eq!(github_repository("not a url"), None);
+    }
+}
@@ -1,5 +1,7 @@
-use std::sync::{Arc, Mutex};
-use std::time::Duration;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::{mpsc, Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
 use owo_colors::OwoColorize;
@@ -11,7 +13,7 @@ use distribution_types::{
 };
 use uv_normalize::PackageName;
 
-use crate::printer::Printer;
+use crate::printer::{Printer, Stderr};
 
 #[derive(Debug)]
 pub(crate) struct DownloadReporter {
@@ -19,6 +21,7 @@ pub(crate) struct DownloadReporter {
     multi_progress: MultiProgress,
     progress: ProgressBar,
     bars: Arc<Mutex<Vec<ProgressBar>>>,
+    plain: Option<PlainProgress>,
 }
 
 impl From<Printer> for DownloadReporter {
@@ -36,6 +39,9 @@ impl From<Printer> for DownloadReporter {
             multi_progress,
             progress,
             bars: Arc::new(Mutex::new(Vec::new())),
+            plain: printer
+                .plain_progress()
+                .map(|(stderr, start)| PlainProgress::new(stderr, start, "Fetching packages")),
         }
     }
 }
@@ -44,29 +50,49 @@ impl DownloadReporter {
     #[must_use]
     pub(crate) fn with_length(self, length: u64) -> Self {
         self.progress.set_length(length);
+        if let Some(plain) = &self.plain {
+            plain.set_length(length);
+        }
         self
     }
 }
 
 impl DownloadReporter {
-    fn on_any_build_start(&self, color_string: &str) -> usize {
+    fn on_any_build_start(&self, source: &(impl ColorDisplay + std::fmt::Display)) -> usize {
         let progress = self.multi_progress.insert_before(
             &self.progress,
             ProgressBar::with_draw_target(None, self.printer.target()),
         );
 
         progress.set_style(ProgressStyle::with_template("{wide_msg}").unwrap());
-        progress.set_message(format!("{} {}", "Building".bold().cyan(), color_string));
+        progress.set_message(format!(
+            "{} {}",
+            "Building".bold().cyan(),
+            source.to_color_string()
+        ));
 
         let mut bars = self.bars.lock().unwrap();
         bars.push(progress);
-        bars.len() - 1
+        let id = bars.len() - 1;
+
+        if let Some(plain) = &self.plain {
+            plain.start_step(id, "Building", source.to_string());
+        }
+        id
     }
 
-    fn on_any_build_complete(&self, color_string: &str, id: usize) {
+    fn on_any_build_complete(&self, source: &(impl ColorDisplay + std::fmt::Display), id: usize) {
         let bars = self.bars.lock().unwrap();
         let progress = &bars[id];
-        progress.finish_with_message(format!("   {} {}", "Built".bold().green(), color_string));
+        progress.finish_with_message(format!(
+            "   {} {}",
+            "Built".bold().green(),
+            source.to_color_string()
+        ));
+
+        if let Some(plain) = &self.plain {
+            plain.finish_step(id, "Built");
+        }
     }
 }
 
@@ -74,26 +100,34 @@ impl uv_installer::DownloadReporter for DownloadReporter {
     fn on_progress(&self, dist: &CachedDist) {
         self.progress.set_message(format!("{dist}"));
         self.progress.inc(1);
+
+        if let Some(plain) = &self.plain {
+            plain.inc(&format!("Fetched {dist}"));
+        }
     }
 
     fn on_complete(&self) {
         self.progress.finish_and_clear();
+
+        if let Some(plain) = &self.plain {
+            plain.finish();
+        }
     }
 
     fn on_build_start(&self, source: &BuildableSource) -> usize {
-        self.on_any_build_start(&source.to_color_string())
+        self.on_any_build_start(source)
     }
 
     fn on_build_complete(&self, source: &BuildableSource, index: usize) {
-        self.on_any_build_complete(&source.to_color_string(), index);
+        self.on_any_build_complete(source, index);
     }
 
     fn on_editable_build_start(&self, dist: &LocalEditable) -> usize {
-        self.on_any_build_start(&dist.to_color_string())
+        self.on_any_build_start(dist)
     }
 
     fn on_editable_build_complete(&self, dist: &LocalEditable, id: usize) {
-        self.on_any_build_complete(&dist.to_color_string(), id);
+        self.on_any_build_complete(dist, id);
     }
 
     fn on_checkout_start(&self, url: &Url, rev: &str) -> usize {
@@ -113,7 +147,12 @@ impl uv_installer::DownloadReporter for DownloadReporter {
 
         let mut bars = self.bars.lock().unwrap();
         bars.push(progress);
-        bars.len() - 1
+        let id = bars.len() - 1;
+
+        if let Some(plain) = &self.plain {
+            plain.start_step(id, "Updating", format!("{url} ({rev})"));
+        }
+        id
     }
 
     fn on_checkout_complete(&self, url: &Url, rev: &str, index: usize) {
@@ -125,12 +164,17 @@ impl uv_installer::DownloadReporter for DownloadReporter {
             url,
             rev.dimmed()
         ));
+
+        if let Some(plain) = &self.plain {
+            plain.finish_step(index, "Updated");
+        }
     }
 }
 
 #[derive(Debug)]
 pub(crate) struct InstallReporter {
     progress: ProgressBar,
+    plain: Option<PlainProgress>,
 }
 
 impl From<Printer> for InstallReporter {
@@ -140,7 +184,12 @@ impl From<Printer> for InstallReporter {
             ProgressStyle::with_template("{bar:20} [{pos}/{len}] {wide_msg:.dim}").unwrap(),
         );
         progress.set_message("Installing wheels...");
-        Self { progress }
+        Self {
+            progress,
+            plain: printer
+                .plain_progress()
+                .map(|(stderr, start)| PlainProgress::new(stderr, start, "Installing wheels")),
+        }
     }
 }
 
@@ -148,6 +197,9 @@ impl InstallReporter {
     #[must_use]
     pub(crate) fn with_length(self, length: u64) -> Self {
         self.progress.set_length(length);
+        if let Some(plain) = &self.plain {
+            plain.set_length(length);
+        }
         self
     }
 }
@@ -156,10 +208,18 @@ impl uv_installer::InstallReporter for InstallReporter {
     fn on_install_progress(&self, wheel: &CachedDist) {
         self.progress.set_message(format!("{wheel}"));
         self.progress.inc(1);
+
+        if let Some(plain) = &self.plain {
+            plain.inc(&format!("Installed {wheel}"));
+        }
     }
 
     fn on_install_complete(&self) {
         self.progress.finish_and_clear();
+
+        if let Some(plain) = &self.plain {
+            plain.finish();
+        }
     }
 }
 
@@ -169,6 +229,7 @@ pub(crate) struct ResolverReporter {
     multi_progress: MultiProgress,
     progress: ProgressBar,
     bars: Arc<Mutex<Vec<ProgressBar>>>,
+    plain: Option<PlainProgress>,
 }
 
 impl From<Printer> for ResolverReporter {
@@ -189,6 +250,9 @@ impl From<Printer> for ResolverReporter {
             multi_progress,
             progress,
             bars: Arc::new(Mutex::new(Vec::new())),
+            plain: printer
+                .plain_progress()
+                .map(|(stderr, start)| PlainProgress::new(stderr, start, "Resolving dependencies")),
         }
     }
 }
@@ -197,22 +261,30 @@ impl ResolverReporter {
     #[must_use]
     pub(crate) fn with_length(self, length: u64) -> Self {
         self.progress.set_length(length);
+        if let Some(plain) = &self.plain {
+            plain.set_length(length);
+        }
         self
     }
 
     fn on_progress(&self, name: &PackageName, version_or_url: &VersionOrUrl) {
-        match version_or_url {
-            VersionOrUrl::Version(version) => {
-                self.progress.set_message(format!("{name}=={version}"));
-            }
-            VersionOrUrl::Url(url) => {
-                self.progress.set_message(format!("{name} @ {url}"));
-            }
+        let message = match version_or_url {
+            VersionOrUrl::Version(version) => format!("{name}=={version}"),
+            VersionOrUrl::Url(url) => format!("{name} @ {url}"),
+        };
+
+        if let Some(plain) = &self.plain {
+            plain.inc(&format!("Selected {message}"));
         }
+        self.progress.set_message(message);
     }
 
     fn on_complete(&self) {
         self.progress.finish_and_clear();
+
+        if let Some(plain) = &self.plain {
+            plain.finish();
+        }
     }
 
     fn on_build_start(&self, source: &BuildableSource) -> usize {
@@ -230,7 +302,12 @@ impl ResolverReporter {
 
         let mut bars = self.bars.lock().unwrap();
         bars.push(progress);
-        bars.len() - 1
+        let id = bars.len() - 1;
+
+        if let Some(plain) = &self.plain {
+            plain.start_step(id, "Building", source.to_string());
+        }
+        id
     }
 
     fn on_build_complete(&self, source: &BuildableSource, index: usize) {
@@ -241,6 +318,10 @@ impl ResolverReporter {
             "Built".bold().green(),
             source.to_color_string(),
         ));
+
+        if let Some(plain) = &self.plain {
+            plain.finish_step(index, "Built");
+        }
     }
 
     fn on_checkout_start(&self, url: &Url, rev: &str) -> usize {
@@ -260,7 +341,12 @@ impl ResolverReporter {
 
         let mut bars = self.bars.lock().unwrap();
         bars.push(progress);
-        bars.len() - 1
+        let id = bars.len() - 1;
+
+        if let Some(plain) = &self.plain {
+            plain.start_step(id, "Updating", format!("{url} ({rev})"));
+        }
+        id
     }
 
     fn on_checkout_complete(&self, url: &Url, rev: &str, index: usize) {
@@ -272,6 +358,10 @@ impl ResolverReporter {
             url,
             rev.dimmed()
         ));
+
+        if let Some(plain) = &self.plain {
+            plain.finish_step(index, "Updated");
+        }
     }
 }
 
@@ -319,6 +409,123 @@ impl uv_distribution::Reporter for ResolverReporter {
     }
 }
 
+/// The interval at which [`PlainProgress`] summarizes the steps in progress.
+const PLAIN_PROGRESS_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Line-oriented progress output, for non-interactive environments (e.g., CI).
+///
+/// Each line is prefixed with the time elapsed since uv started. While any work is outstanding,
+/// a summary of the steps in progress is printed periodically, such that the logs of a job that
+/// times out show what uv was doing.
+#[derive(Debug)]
+struct PlainProgress {
+    stderr: Stderr,
+    start: Instant,
+    state: Arc<Mutex<PlainProgressState>>,
+    /// Dropped to stop the periodic summary.
+    heartbeat: Mutex<Option<mpsc::Sender<()>>>,
+}
+
+#[derive(Debug)]
+struct PlainProgressState {
+    /// The overall task (e.g., "Resolving dependencies").
+    task: &'static str,
+    position: u64,
+    length: Option<u64>,
+    /// The steps in progress (e.g., builds), by ID.
+    steps: BTreeMap<usize, String>,
+}
+
+impl PlainProgress {
+    fn new(stderr: Stderr, start: Instant, task: &'static str) -> Self {
+        let state = Arc::new(Mutex::new(PlainProgressState {
+            task,
+            position: 0,
+            length: None,
+            steps: BTreeMap::new(),
+        }));
+
+        let (sender, receiver) = mpsc::channel::<()>();
+        let heartbeat_state = state.clone();
+        std::thread::spawn(move || {
+            while let Err(mpsc::RecvTimeoutError::Timeout) =
+                receiver.recv_timeout(PLAIN_PROGRESS_INTERVAL)
+            {
+                let summary = heartbeat_state.lock().unwrap().summary();
+                Self::write_line(stderr, start, &summary);
+            }
+        });
+
+        Self {
+            stderr,
+            start,
+            state,
+            heartbeat: Mutex::new(Some(sender)),
+        }
+    }
+
+    fn write_line(mut stderr: Stderr, start: Instant, message: &str) {
+        let elapsed = start.elapsed().as_secs_f32();
+        let _ = writeln!(stderr, "[{elapsed:>7.1}s] {message}");
+    }
+
+    fn line(&self, message: &str) {
+        Self::write_line(self.stderr, self.start, message);
+    }
+
+    fn set_length(&self, length: u64) {
+        self.state.lock().unwrap().length = Some(length);
+    }
+
+    /// Record the completion of a unit of the overall task.
+    fn inc(&self, message: &str) {
+        let position = {
+            let mut state = self.state.lock().unwrap();
+            state.position += 1;
+            match state.length {
+                Some(length) => format!(" [{}/{length}]", state.position),
+                None => String::new(),
+            }
+        };
+        self.line(&format!("{message}{position}"));
+    }
+
+    fn start_step(&self, id: usize, verb: &str, step: String) {
+        self.line(&format!("{verb} {step}"));
+        self.state.lock().unwrap().steps.insert(id, step);
+    }
+
+    fn finish_step(&self, id: usize, verb: &str) {
+        if let Some(step) = self.state.lock().unwrap().steps.remove(&id) {
+            self.line(&format!("{verb} {step}"));
+        }
+    }
+
+    fn finish(&self) {
+        self.heartbeat.lock().unwrap().take();
+    }
+}
+
+impl Drop for PlainProgress {
+    fn drop(&mut self) {
+        self.finish();
+    }
+}
+
+impl PlainProgressState {
+    fn summary(&self) -> String {
+        let mut summary = self.task.to_string();
+        if let Some(length) = self.length {
+            let _ = write!(summary, " [{}/{length}]", self.position);
+        }
+        if !self.steps.is_empty() {
+            let steps = self.steps.values().map(String::as_str).collect::<Vec<_>>();
+            let _ = write!(summary, "; in progress: {}", steps.join(", "));
+        }
+        summary
+    }
+}
+
 /// Like [`std::fmt::Display`], but with colors.
 trait ColorDisplay {
     fn to_color_string(&self) -> String;
@@ -1,16 +1,82 @@
 use std::fmt::Write;
+use std::path::Path;
+use std::str::FromStr;
 
-use anyhow::Result;
-use axoupdater::{AxoUpdater, AxoupdateError};
+use anyhow::{bail, Context, Result};
+use axoupdater::{AxoUpdater, AxoupdateError, UpdateRequest};
 use owo_colors::OwoColorize;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
 use tracing::debug;
-use uv_client::BetterReqwestError;
+
+use pep440_rs::Version;
+use uv_client::{BaseClient, BaseClientBuilder, BetterReqwestError};
+use uv_configuration::UpdateChannel;
+use uv_fs::Simplified;
 
 use crate::commands::ExitStatus;
 use crate::printer::Printer;
 
+/// The GitHub API endpoint listing the published `uv` releases, most recent first.
+const RELEASES_URL: &str = "https://api.github.com/repos/astral-sh/uv/releases?per_page=100";
+
+/// The GitHub API endpoint resolving a `uv` release tag to the commit it was built from.
+const COMMITS_URL: &str = "https://api.github.com/repos/astral-sh/uv/commits";
+
+/// A published `uv` release, as returned by the GitHub API.
+#[derive(Debug, Deserialize)]
+struct Release {
+    tag_name: String,
+    #[serde(default)]
+    prerelease: bool,
+    #[serde(default)]
+    draft: bool,
+}
+
+impl Release {
+    /// The version of the release, or `None` for non-versioned releases (like `nightly`).
+    fn version(&self) -> Option<Version> {
+        Version::from_str(&self.tag_name).ok()
+    }
+}
+
+/// Select the release to update to on the given channel, if any.
+///
+/// Draft releases are never selected. The stable channel selects the latest versioned release
+/// that isn't a pre-release (whether marked as such on GitHub, or by its version); the preview
+/// channel selects the latest versioned release; and the nightly channel selects the `nightly`
+/// release.
+fn select_release(channel: UpdateChannel, releases: &[Release]) -> Option<&Release> {
+    let mut releases = releases.iter().filter(|release| !release.draft);
+    match channel {
+        UpdateChannel::Stable => releases
+            .filter(|release| !release.prerelease)
+            .filter_map(|release| Some((release.version()?, release)))
+            .filter(|(version, _)| !version.any_prerelease())
+            .max_by(|(a, _), (b, _)| a.cmp(b))
+            .map(|(_, release)| release),
+        UpdateChannel::Preview => releases
+            .filter_map(|release| Some((release.version()?, release)))
+            .max_by(|(a, _), (b, _)| a.cmp(b))
+            .map(|(_, release)| release),
+        UpdateChannel::Nightly => releases.find(|release| release.tag_name == "nightly"),
+    }
+}
+
+/// Returns `true` if the current binary was built from the given commit.
+///
+/// Binaries built outside a Git checkout don't record their commit, so they're never considered
+/// current.
+fn is_current_build(commit: &str, current: Option<&str>) -> bool {
+    current.is_some_and(|current| current.eq_ignore_ascii_case(commit.trim()))
+}
+
 /// Attempt to update the `uv` binary.
-pub(crate) async fn self_update(printer: Printer) -> Result<ExitStatus> {
+pub(crate) async fn self_update(
+    channel: UpdateChannel,
+    client_builder: &BaseClientBuilder<'_>,
+    printer: Printer,
+) -> Result<ExitStatus> {
     let mut updater = AxoUpdater::new_for("uv");
     updater.disable_installer_output();
 
@@ -63,14 +129,76 @@ pub(crate) async fn self_update(printer: Printer) -> Result<ExitStatus> {
         printer.stderr(),
         "{}",
         format_args!(
-            "{}{} Checking for updates...",
+            "{}{} Checking for updates{}...",
             "info".cyan().bold(),
-            ":".bold()
+            ":".bold(),
+            match channel {
+                UpdateChannel::Stable => "",
+                UpdateChannel::Preview => " on the preview channel",
+                UpdateChannel::Nightly => " on the nightly channel",
+            }
         )
     )?;
 
-    // Run the updater. This involves a network request, since we need to determine the latest
-    // available version of `uv`.
+    // Determine the release to update to, based on the channel. This involves a network request,
+    // since we need to determine the latest available version of `uv`.
+    let client = client_builder.build();
+    let Some(releases) = fetch(&client, RELEASES_URL).await? else {
+        bail!("Failed to fetch the list of `uv` releases: {RELEASES_URL}");
+    };
+    let releases: Vec<Release> =
+        serde_json::from_slice(&releases).context("Failed to parse the list of `uv` releases")?;
+    let Some(release) = select_release(channel, &releases) else {
+        bail!(
+            "No `uv` release found on the {} channel",
+            match channel {
+                UpdateChannel::Stable => "stable",
+                UpdateChannel::Preview => "preview",
+                UpdateChannel::Nightly => "nightly",
+            }
+        );
+    };
+    debug!("Selected release: {}", release.tag_name);
+
+    // Versioned releases are compared against the current version. The nightly release is
+    // rebuilt in place, so it's compared against the commit of the current build instead.
+    if let Some(version) = release.version() {
+        let current = Version::from_str(env!("CARGO_PKG_VERSION"))?;
+        if version <= current {
+            writeln!(
+                printer.stderr(),
+                "{}",
+                format_args!(
+                    "{}{} You're on the latest version of `uv` ({}).",
+                    "success".green().bold(),
+                    ":".bold(),
+                    format!("v{current}").bold().white()
+                )
+            )?;
+            return Ok(ExitStatus::Success);
+        }
+    } else if let Some(commit) = fetch_commit(&client, &release.tag_name).await? {
+        debug!("Release {} was built from: {commit}", release.tag_name);
+        if is_current_build(&commit, option_env!("UV_COMMIT_HASH")) {
+            writeln!(
+                printer.stderr(),
+                "{}",
+                format_args!(
+                    "{}{} You're on the latest {} build of `uv` ({}).",
+                    "success".green().bold(),
+                    ":".bold(),
+                    release.tag_name,
+                    option_env!("UV_COMMIT_SHORT_HASH")
+                        .unwrap_or(commit.as_str())
+                        .bold()
+                        .white()
+                )
+            )?;
+            return Ok(ExitStatus::Success);
+        }
+    }
+    updater.configure_version_specifier(UpdateRequest::SpecificTag(release.tag_name.clone()));
+
     match updater.run().await {
         Ok(Some(result)) => {
             writeln!(
@@ -112,3 +240,168 @@ pub(crate) async fn self_update(printer: Printer) -> Result<ExitStatus> {
 
     Ok(ExitStatus::Success)
 }
+
+/// Download the `uv` release artifact for the given target triple, along with its SHA-256
+/// checksum, such that it can be installed on another machine without network access.
+pub(crate) async fn self_package(
+    target: &str,
+    release: Option<&str>,
+    output_dir: &Path,
+    client_builder: &BaseClientBuilder<'_>,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let release = release.unwrap_or(env!("CARGO_PKG_VERSION"));
+
+    // Release artifacts are published as `.zip` archives on Windows, and as `.tar.gz` archives
+    // elsewhere.
+    let extension = if target.contains("windows") {
+        "zip"
+    } else {
+        "tar.gz"
+    };
+    let filename = format!("uv-{target}.{extension}");
+    let url = format!("https://github.com/astral-sh/uv/releases/download/{release}/{filename}");
+
+    let client = client_builder.build();
+
+    let Some(artifact) = fetch(&client, &url).await? else {
+        bail!("No `uv` release artifact found for target `{target}` in release {release}: {url}");
+    };
+    let Some(checksum) = fetch(&client, &format!("{url}.sha256")).await? else {
+        bail!("No checksum found for `uv` release artifact: {url}");
+    };
+
+    // The checksum file contains the digest, followed by the filename.
+    let checksum = String::from_utf8(checksum)
+        .ok()
+        .and_then(|checksum| checksum.split_whitespace().next().map(str::to_lowercase))
+        .with_context(|| format!("Invalid checksum for `uv` release artifact: {url}"))?;
+    let computed = format!("{:x}", Sha256::digest(&artifact));
+    if computed != checksum {
+        bail!("Hash mismatch for `{url}` (expected: `{checksum}`, computed: `{computed}`)");
+    }
+
+    fs_err::tokio::create_dir_all(output_dir).await?;
+    let path = output_dir.join(&filename);
+    uv_fs::write_atomic(&path, &artifact).await?;
+    uv_fs::write_atomic(
+        output_dir.join(format!("{filename}.sha256")),
+        format!("{checksum} *{filename}\n"),
+    )
+    .await?;
+
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format_args!(
+            "{}{} Downloaded `uv` {} for {} to {}",
+            "success".green().bold(),
+            ":".bold(),
+            format!("v{release}").bold().white(),
+            target.bold(),
+            path.user_display().cyan()
+        )
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Fetch the contents of the given URL, returning `None` if it doesn't exist.
+async fn fetch(client: &BaseClient, url: &str) -> Result<Option<Vec<u8>>> {
+    debug!("Downloading: {url}");
+    let response = client.client().get(url).send().await?;
+    if response.status().as_u16() == 404 {
+        return Ok(None);
+    }
+    let bytes = response
+        .error_for_status()
+        .map_err(BetterReqwestError::from)?
+        .bytes()
+        .await
+        .map_err(BetterReqwestError::from)?;
+    Ok(Some(bytes.to_vec()))
+}
+
+/// Fetch the hash of the commit that the given release tag points to, returning `None` if the tag
+/// doesn't exist.
+async fn fetch_commit(client: &BaseClient, tag: &str) -> Result<Option<String>> {
+    let url = format!("{COMMITS_URL}/{tag}");
+    debug!("Downloading: {url}");
+    let response = client
+        .client()
+        .get(&url)
+        .header("Accept", "application/vnd.github.sha")
+        .send()
+        .await?;
+    if response.status().as_u16() == 404 {
+        return Ok(None);
+    }
+    let commit = response
+        .error_for_status()
+        .map_err(BetterReqwestError::from)?
+        .text()
+        .await
+        .map_err(BetterReqwestError::from)?;
+    Ok(Some(commit.trim().to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use uv_configuration::UpdateChannel;
+
+    use super::{is_current_build, select_release, Release};
+
+    fn releases(releases: &[(&str, bool, bool)]) -> Vec<Release> {
+        releases
+            .iter()
+            .map(|&(tag_name, prerelease, draft)| Release {
+                tag_name: tag_name.to_string(),
+                prerelease,
+                draft,
+            })
+            .collect()
+    }
+
+    fn select(channel: UpdateChannel, releases: &[Release]) -> Option<&str> {
+        select_release(channel, releases).map(|release| release.tag_name.as_str())
+    }
+
+    #[test]
+    fn channels() {
+        // The GitHub API lists releases by creation date, not by version.
+        let releases = releases(&[
+            ("nightly", true, false),
+            ("0.2.0", false, true),
+            ("0.2.0a1", true, false),
+            ("0.1.11rc1", false, false),
+            ("0.1.9", false, false),
+            ("0.1.10", false, false),
+        ]);
+        assert_eq!(select(UpdateChannel::Stable, &releases), Some("0.1.10"));
+        assert_eq!(select(UpdateChannel::Preview, &releases), Some("0.2.0a1"));
+        assert_eq!(select(UpdateChannel::Nightly, &releases), Some("nightly"));
+    }
+
+    #[test]
+    fn channel_without_release() {
+        let releases = releases(&[("0.2.0a1", true, false), ("nightly", true, true)]);
+        assert_eq!(select(UpdateChannel::Stable, &releases), None);
+        assert_eq!(select(UpdateChannel::Preview, &releases), Some("0.2.0a1"));
+        assert_eq!(select(UpdateChannel::Nightly, &releases), None);
+        assert_eq!(select(UpdateChannel::Preview, &[]), None);
+    }
+
+    #[test]
+    fn current_build() {
+        let commit = "53b0f5d924110e5b26fbf09f6fd3a03d67b475b7";
+        assert!(is_current_build(commit, Some(commit)));
+        assert!(is_current_build(&format!("{commit}\n"), Some(commit)));
+        assert!(is_current_build(&commit.to_uppercase(), Some(commit)));
+        assert!(!is_current_build(
+            "0000000000000000000000000000000000000000",
+            Some(commit)
+        ));
+        // Builds without commit information can't be compared.
+        assert!(!is_current_build(commit, None));
+    }
+}
@@ -0,0 +1,370 @@
+use std::convert::Infallible;
+use std::fmt::Write;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use anyhow::{anyhow, bail, Result};
+use futures::TryStreamExt;
+use http_body_util::combinators::UnsyncBoxBody;
+use http_body_util::{BodyExt, Full, StreamBody};
+use hyper::body::{Bytes, Frame, Incoming};
+use hyper::header::{CONTENT_LENGTH, CONTENT_TYPE, LOCATION};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Method, Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use owo_colors::OwoColorize;
+use tokio::io::AsyncWriteExt;
+use tokio::net::TcpListener;
+use tokio_util::io::ReaderStream;
+use tracing::{debug, warn};
+use url::Url;
+
+use distribution_types::{File, FileLocation, IndexLocations, IndexUrl};
+use pypi_types::HashDigest;
+use uv_cache::{Cache, CacheBucket, WheelCache};
+use uv_client::{
+    BaseClientBuilder, Connectivity, ErrorKind, OwnedArchive, RegistryClient,
+    RegistryClientBuilder, SimpleMetadata,
+};
+use uv_configuration::{IndexStrategy, KeyringProviderType};
+use uv_extract::hash::Hasher;
+use uv_normalize::PackageName;
+
+use crate::commands::index_snapshot::{project_page, validate_filename, ProjectFile};
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+type Body = UnsyncBoxBody<Bytes, std::io::Error>;
+
+/// The state shared across all requests to the server.
+struct State {
+    client: RegistryClient,
+    cache: Cache,
+}
+
+/// Serve the configured indexes as a PEP 503 simple index, reading through to the upstream
+/// indexes and serving files from the cache.
+///
+/// Project pages are fetched via the registry client, and are thus cached according to the
+/// upstream cache headers. Files are downloaded on first use and served from the cache thereafter.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn serve_index(
+    address: SocketAddr,
+    index_locations: IndexLocations,
+    index_strategy: IndexStrategy,
+    keyring_provider: KeyringProviderType,
+    connectivity: Connectivity,
+    client_builder: &BaseClientBuilder<'_>,
+    cache: Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let client = RegistryClientBuilder::from_base_client(cache.clone(), client_builder.clone())
+        .connectivity(connectivity)
+        .index_urls(index_locations.index_urls())
+        .index_strategy(index_strategy)
+        .keyring(keyring_provider)
+        .build();
+    let state = Arc::new(State { client, cache });
+
+    let listener = TcpListener::bind(address).await?;
+    writeln!(
+        printer.stderr(),
+        "Serving index at: {}",
+        format!("http://{}/simple/", listener.local_addr()?).cyan()
+    )?;
+
+    loop {
+        let (socket, remote) = listener.accept().await?;
+        let state = state.clone();
+        tokio::spawn(async move {
+            let service = service_fn(move |request| handle(state.clone(), request));
+            if let Err(err) = http1::Builder::new()
+                .serve_connection(TokioIo::new(socket), service)
+                .await
+            {
+                debug!("Failed to serve connection from {remote}: {err}");
+            }
+        });
+    }
+}
+
+/// Handle a single request to the server.
+async fn handle(
+    state: Arc<State>,
+    request: Request<Incoming>,
+) -> Result<Response<Body>, Infallible> {
+    if request.method() != Method::GET {
+        return Ok(text(StatusCode::METHOD_NOT_ALLOWED, "Method not allowed"));
+    }
+
+    let path = request.uri().path();
+    debug!("Received request for: {path}");
+    let segments = path.trim_start_matches('/').split('/').collect::<Vec<_>>();
+    let response = match segments.as_slice() {
+        ["simple", project] | ["simple", project, ""] => {
+            let Ok(name) = PackageName::from_str(project) else {
+                return Ok(text(StatusCode::NOT_FOUND, "Not found"));
+            };
+
+            // Redirect to the normalized project URL, per PEP 503.
+            let canonical = format!("/simple/{name}/");
+            if path != canonical {
+                return Ok(Response::builder()
+                    .status(StatusCode::MOVED_PERMANENTLY)
+                    .header(LOCATION, canonical)
+                    .body(empty())
+                    .unwrap());
+            }
+
+            serve_project(&state, &name).await
+        }
+        ["files", project, filename] => {
+            let Ok(name) = PackageName::from_str(project) else {
+                return Ok(text(StatusCode::NOT_FOUND, "Not found"));
+            };
+            let Some(filename) = requested_filename(filename, &name) else {
+                return Ok(text(StatusCode::BAD_REQUEST, "Bad request"));
+            };
+            serve_file(&state, &name, &filename).await
+        }
+        _ => return Ok(text(StatusCode::NOT_FOUND, "Not found")),
+    };
+
+    Ok(response.unwrap_or_else(|err| {
+        warn!("Failed to serve {path}: {err}");
+        text(StatusCode::BAD_GATEWAY, "Bad gateway")
+    }))
+}
+
+/// Serve the project page for a package, listing the files available on the upstream indexes.
+async fn serve_project(state: &State, name: &PackageName) -> Result<Response<Body>> {
+    let Some(results) = simple(state, name).await? else {
+        return Ok(text(StatusCode::NOT_FOUND, "Not found"));
+    };
+
+    // Link each file to the server, such that it's downloaded through the cache.
+    let mut files = Vec::new();
+    for (_, file) in files_by_index(results) {
+        if files
+            .iter()
+            .any(|existing: &ProjectFile| existing.file.filename == file.filename)
+        {
+            continue;
+        }
+        if let Err(err) = validate_filename(&file.filename, name) {
+            warn!("Skipping file for {name}: {err}");
+            continue;
+        }
+        files.push(ProjectFile {
+            href: format!("../../files/{name}/{}", urlencoding::encode(&file.filename)),
+            hash: file.hashes.first().cloned(),
+            file,
+        });
+    }
+
+    let html = project_page(name, &files)?;
+    Ok(Response::builder()
+        .header(CONTENT_TYPE, "text/html")
+        .body(
+            Full::new(Bytes::from(html))
+                .map_err(|err| match err {})
+                .boxed_unsync(),
+        )
+        .unwrap())
+}
+
+/// Decode the filename from a request path segment, returning `None` if it isn't a valid
+/// distribution filename for the given package.
+fn requested_filename(segment: &str, name: &PackageName) -> Option<String> {
+    let filename = urlencoding::decode(segment).ok()?;
+    validate_filename(&filename, name).ok()?;
+    Some(filename.into_owned())
+}
+
+/// Serve a file, downloading it from the upstream index if it isn't already cached.
+async fn serve_file(state: &State, name: &PackageName, filename: &str) -> Result<Response<Body>> {
+    let Some(results) = simple(state, name).await? else {
+        return Ok(text(StatusCode::NOT_FOUND, "Not found"));
+    };
+    let Some((index, file)) = files_by_index(results).find(|(_, file)| file.filename == filename)
+    else {
+        return Ok(text(StatusCode::NOT_FOUND, "Not found"));
+    };
+
+    let path = match &file.url {
+        // Local files can be served directly.
+        FileLocation::Path(path) => path.clone(),
+        FileLocation::RelativeUrl(base, url) => {
+            let url = pypi_types::base_url_join_relative(base, url)?;
+            download(state, &index, name, &file, url).await?
+        }
+        FileLocation::AbsoluteUrl(url) => {
+            let url = Url::parse(url)?;
+            download(state, &index, name, &file, url).await?
+        }
+    };
+
+    let reader = fs_err::tokio::File::open(&path).await?;
+    let length = reader.metadata().await?.len();
+    let body = StreamBody::new(ReaderStream::new(reader).map_ok(Frame::data));
+    Ok(Response::builder()
+        .header(CONTENT_TYPE, "application/octet-stream")
+        .header(CONTENT_LENGTH, length)
+        .body(body.boxed_unsync())
+        .unwrap())
+}
+
+/// Download a file into the cache, returning its path.
+///
+/// If the file is already cached, it's reused. Otherwise, the file is verified against the
+/// digests provided by the index before it's persisted.
+async fn download(
+    state: &State,
+    index: &IndexUrl,
+    name: &PackageName,
+    file: &File,
+    url: Url,
+) -> Result<PathBuf> {
+    // The filename is provided by the upstream index, and is used as a path component below.
+    validate_filename(&file.filename, name)?;
+
+    let cache_entry = state.cache.entry(
+        CacheBucket::Files,
+        WheelCache::Index(index).wheel_dir(name.as_ref()),
+        &file.filename,
+    );
+    if cache_entry.path().is_file() {
+        debug!("Serving cached file: {}", file.filename);
+        return Ok(cache_entry.into_path_buf());
+    }
+
+    debug!("Downloading {} from: {url}", file.filename);
+    let mut response = state
+        .client
+        .uncached_client()
+        .client()
+        .get(url)
+        .send()
+        .await?
+        .error_for_status()
+        .map_err(uv_client::BetterReqwestError::from)?;
+
+    // Stream the file into a temporary directory, computing each digest along the way.
+    fs_err::tokio::create_dir_all(cache_entry.dir()).await?;
+    let temp_dir = tempfile::tempdir_in(cache_entry.dir())?;
+    let temp_file = temp_dir.path().join(&file.filename);
+    let mut writer = fs_err::tokio::File::create(&temp_file).await?;
+    let mut hashers = file
+        .hashes
+        .iter()
+        .map(|hash| Hasher::from(hash.algorithm))
+        .collect::<Vec<_>>();
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .map_err(uv_client::BetterReqwestError::from)?
+    {
+        for hasher in &mut hashers {
+            hasher.update(&chunk);
+        }
+        writer.write_all(&chunk).await?;
+    }
+    writer.flush().await?;
+    drop(writer);
+
+    for (expected, hasher) in file.hashes.iter().zip(hashers) {
+        let computed = HashDigest::from(hasher);
+        if computed != *expected {
+            bail!(
+                "Hash mismatch for `{}`\n\nExpected:\n  {expected}\n\nComputed:\n  {computed}",
+                file.filename
+            );
+        }
+    }
+
+    fs_err::tokio::rename(&temp_file, cache_entry.path()).await?;
+    Ok(cache_entry.into_path_buf())
+}
+
+/// Fetch the simple metadata for a package, or `None` if it isn't available on any index.
+async fn simple(
+    state: &State,
+    name: &PackageName,
+) -> Result<Option<Vec<(IndexUrl, OwnedArchive<SimpleMetadata>)>>> {
+    match state.client.simple(name).await {
+        Ok(results) => Ok(Some(results)),
+        Err(err) => match err.kind() {
+            ErrorKind::PackageNotFound(_) | ErrorKind::Offline(_) => Ok(None),
+            _ => Err(anyhow!(err)),
+        },
+    }
+}
+
+/// Flatten the simple metadata for a package into the files available on each index, in order of
+/// index priority.
+fn files_by_index(
+    results: Vec<(IndexUrl, OwnedArchive<SimpleMetadata>)>,
+) -> impl Iterator<Item = (IndexUrl, File)> {
+    results.into_iter().flat_map(|(index, archive)| {
+        OwnedArchive::deserialize(&archive)
+            .into_iter()
+            .flat_map(|datum| datum.files.all())
+            .map(move |(_, file)| (index.clone(), file))
+            .collect::<Vec<_>>()
+    })
+}
+
+/// Return an empty body.
+fn empty() -> Body {
+    Full::new(Bytes::new())
+        .map_err(|err| match err {})
+        .boxed_unsync()
+}
+
+/// Return a plain-text response with the given status.
+fn text(status: StatusCode, message: &str) -> Response<Body> {
+    Response::builder()
+        .status(status)
+        .header(CONTENT_TYPE, "text/plain")
+        .body(
+            Full::new(Bytes::from(message.to_string()))
+                .map_err(|err| match err {})
+                .boxed_unsync(),
+        )
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_normalize::PackageName;
+
+    use super::requested_filename;
+
+    #[test]
+    fn requested_filename_decodes() {
+        let name = PackageName::from_str("tqdm").unwrap();
+        assert_eq!(
+            requested_filename("tqdm-4.66.1%2Bcpu-py3-none-any.whl", &name).as_deref(),
+            Some("tqdm-4.66.1+cpu-py3-none-any.whl")
+        );
+    }
+
+    #[test]
+    fn requested_filename_traversal() {
+        let name = PackageName::from_str("tqdm").unwrap();
+        for segment in [
+            "..%2F..%2Ftqdm-4.66.1.tar.gz",
+            "tqdm-4.66.1-py3-none-any%2F..%2F..%2Fevil.whl",
+            "tqdm-4.66.1-py3-none-any%5C..%5C..%5Cevil.whl",
+            "%2E%2E",
+            "flask-3.0.0.tar.gz",
+        ] {
+            assert_eq!(requested_filename(segment, &name), None, "{segment}");
+        }
+    }
+}
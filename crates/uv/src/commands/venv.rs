@@ -1,5 +1,5 @@
 use std::fmt::Write;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::vec;
 
@@ -15,14 +15,15 @@ use distribution_types::{DistributionMetadata, IndexLocations, Name, ResolvedDis
 use install_wheel_rs::linker::LinkMode;
 use pep508_rs::Requirement;
 use uv_cache::Cache;
-use uv_client::{Connectivity, FlatIndexClient, RegistryClientBuilder};
+use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::KeyringProviderType;
 use uv_configuration::{ConfigSettings, IndexStrategy, NoBinary, NoBuild, SetupPyStrategy};
 use uv_dispatch::BuildDispatch;
 use uv_fs::Simplified;
-use uv_interpreter::{find_default_python, find_requested_python, Error};
+use uv_interpreter::{find_default_python, find_requested_python, Error, PythonEnvironment};
 use uv_resolver::{ExcludeNewer, FlatIndex, InMemoryIndex, OptionsBuilder};
 use uv_types::{BuildContext, BuildIsolation, HashStrategy, InFlight};
+use uv_virtualenv::Activator;
 
 use crate::commands::ExitStatus;
 use crate::printer::Printer;
@@ -38,11 +39,15 @@ pub(crate) async fn venv(
     index_strategy: IndexStrategy,
     keyring_provider: KeyringProviderType,
     prompt: uv_virtualenv::Prompt,
-    system_site_packages: bool,
+    system_site_packages: Option<bool>,
+    upgrade_settings: bool,
+    activators: &[Activator],
+    externally_managed: Option<&str>,
+    site_packages_extras: &[PathBuf],
     connectivity: Connectivity,
     seed: bool,
     exclude_newer: Option<ExcludeNewer>,
-    native_tls: bool,
+    client_builder: &BaseClientBuilder<'_>,
     cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
@@ -55,10 +60,14 @@ pub(crate) async fn venv(
         keyring_provider,
         prompt,
         system_site_packages,
+        upgrade_settings,
+        activators,
+        externally_managed,
+        site_packages_extras,
         connectivity,
         seed,
         exclude_newer,
-        native_tls,
+        client_builder,
         cache,
         printer,
     )
@@ -78,6 +87,14 @@ enum VenvError {
     #[diagnostic(code(uv::venv::creation))]
     Creation(#[source] uv_virtualenv::Error),
 
+    #[error("Failed to add extra files to `site-packages`")]
+    #[diagnostic(code(uv::venv::site_packages_extras))]
+    SitePackagesExtras(#[source] uv_virtualenv::Error),
+
+    #[error("Failed to update virtualenv")]
+    #[diagnostic(code(uv::venv::update))]
+    Update(#[source] uv_virtualenv::Error),
+
     #[error("Failed to install seed packages")]
     #[diagnostic(code(uv::venv::seed))]
     Seed(#[source] anyhow::Error),
@@ -101,14 +118,35 @@ async fn venv_impl(
     index_strategy: IndexStrategy,
     keyring_provider: KeyringProviderType,
     prompt: uv_virtualenv::Prompt,
-    system_site_packages: bool,
+    system_site_packages: Option<bool>,
+    upgrade_settings: bool,
+    activators: &[Activator],
+    externally_managed: Option<&str>,
+    site_packages_extras: &[PathBuf],
     connectivity: Connectivity,
     seed: bool,
     exclude_newer: Option<ExcludeNewer>,
-    native_tls: bool,
+    client_builder: &BaseClientBuilder<'_>,
     cache: &Cache,
     printer: Printer,
 ) -> miette::Result<ExitStatus> {
+    // Update the existing virtual environment in-place, if requested.
+    if upgrade_settings {
+        let venv = PythonEnvironment::from_root(path, cache).into_diagnostic()?;
+
+        writeln!(
+            printer.stderr(),
+            "Updating virtualenv at: {}",
+            path.user_display().cyan()
+        )
+        .into_diagnostic()?;
+
+        uv_virtualenv::update_venv(&venv, prompt, system_site_packages)
+            .map_err(VenvError::Update)?;
+
+        return Ok(ExitStatus::Success);
+    }
+
     // Locate the Python interpreter.
     let interpreter = if let Some(python_request) = python_request {
         find_requested_python(python_request, cache)
@@ -138,9 +176,15 @@ async fn venv_impl(
     let extra_cfg = vec![("uv".to_string(), env!("CARGO_PKG_VERSION").to_string())];
 
     // Create the virtual environment.
-    let venv =
-        uv_virtualenv::create_venv(path, interpreter, prompt, system_site_packages, extra_cfg)
-            .map_err(VenvError::Creation)?;
+    let venv = uv_virtualenv::create_venv(
+        path,
+        interpreter,
+        prompt,
+        system_site_packages.unwrap_or(false),
+        activators,
+        extra_cfg,
+    )
+    .map_err(VenvError::Creation)?;
 
     // Install seed packages.
     if seed {
@@ -148,8 +192,7 @@ async fn venv_impl(
         let interpreter = venv.interpreter();
 
         // Instantiate a client.
-        let client = RegistryClientBuilder::new(cache.clone())
-            .native_tls(native_tls)
+        let client = RegistryClientBuilder::from_base_client(cache.clone(), client_builder.clone())
             .index_urls(index_locations.index_urls())
             .index_strategy(index_strategy)
             .keyring(keyring_provider)
@@ -240,8 +283,22 @@ async fn venv_impl(
         }
     }
 
-    // Determine the appropriate activation command.
-    let activation = match Shell::from_env() {
+    // Add any extra files to `site-packages`, once any seed packages are installed, such that
+    // (e.g.) a `sitecustomize.py` can't interfere with seeding.
+    if !site_packages_extras.is_empty() {
+        uv_virtualenv::add_site_packages_extras(&venv, site_packages_extras)
+            .map_err(VenvError::SitePackagesExtras)?;
+    }
+
+    // Mark the environment as externally managed, once any seed packages are installed.
+    if let Some(error) = externally_managed {
+        uv_virtualenv::mark_externally_managed(path, Some(error)).map_err(VenvError::Creation)?;
+    }
+
+    // Determine the appropriate activation command, if an activation script was generated for
+    // the current shell.
+    let shell = Shell::from_env().filter(|shell| activators.contains(&shell.activator()));
+    let activation = match shell {
         None => None,
         Some(Shell::Bash | Shell::Zsh) => Some(format!(
             "source {}",
@@ -259,6 +316,10 @@ async fn venv_impl(
             "source {}",
             shlex_posix(venv.scripts().join("activate.csh"))
         )),
+        Some(Shell::Xonsh) => Some(format!(
+            "source {}",
+            shlex_posix(venv.scripts().join("activate.xsh"))
+        )),
         Some(Shell::Powershell) => Some(shlex_windows(
             venv.scripts().join("activate"),
             Shell::Powershell,
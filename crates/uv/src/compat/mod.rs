@@ -3,6 +3,10 @@ use clap::{Args, ValueEnum};
 
 use uv_warnings::warn_user;
 
+pub(crate) use pip_config::PipConfigImport;
+
+mod pip_config;
+
 pub(crate) trait CompatArgs {
     fn validate(&self) -> Result<()>;
 }
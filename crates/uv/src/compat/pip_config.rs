@@ -0,0 +1,90 @@
+use std::env;
+
+use anyhow::Result;
+use itertools::Itertools;
+
+use uv_warnings::warn_user;
+use uv_workspace::{ImportedSetting, PipConfig, PipSetting};
+
+/// The settings imported from `pip`'s configuration.
+#[derive(Debug)]
+pub(crate) struct PipConfigImport {
+    /// The settings that were imported.
+    imported: Vec<ImportedSetting>,
+    /// The settings that were superseded by an existing uv environment variable.
+    superseded: Vec<ImportedSetting>,
+    /// The settings that uv doesn't support.
+    unsupported: Vec<PipSetting>,
+}
+
+impl PipConfigImport {
+    /// Import settings from `pip`'s configuration files and `PIP_*` environment variables, if
+    /// enabled via `UV_IMPORT_PIP_CONFIG`.
+    ///
+    /// Settings are imported by setting the equivalent uv environment variables, such that any
+    /// command-line arguments and existing `UV_*` environment variables take precedence. As such,
+    /// this must be called before parsing the command-line arguments, and before any other threads
+    /// (e.g., the async runtime's workers) are started, as modifying the environment isn't
+    /// thread-safe.
+    pub(crate) fn from_env() -> Result<Option<Self>> {
+        let enabled = env::var("UV_IMPORT_PIP_CONFIG")
+            .is_ok_and(|value| value == "1" || value.eq_ignore_ascii_case("true"));
+        if !enabled {
+            return Ok(None);
+        }
+
+        let (settings, unsupported) = PipConfig::load()?.translate();
+        let (superseded, imported): (Vec<_>, Vec<_>) = settings
+            .into_iter()
+            .partition(|setting| env::var_os(setting.env).is_some());
+        for setting in &imported {
+            env::set_var(setting.env, &setting.value);
+        }
+
+        Ok(Some(Self {
+            imported,
+            superseded,
+            unsupported,
+        }))
+    }
+
+    /// Warn the user about the imported (and ignored) settings.
+    pub(crate) fn warn(&self) {
+        if !self.imported.is_empty() {
+            warn_user!(
+                "Imported settings from pip's configuration:\n{}",
+                self.imported
+                    .iter()
+                    .map(|setting| format!(
+                        "  {} = {} (from {}, as `{}`)",
+                        setting.setting.name,
+                        setting.setting.value.trim(),
+                        setting.setting.source,
+                        setting.env
+                    ))
+                    .join("\n")
+            );
+        }
+        if !self.superseded.is_empty() {
+            warn_user!(
+                "Ignored settings from pip's configuration, as the equivalent uv settings are already set: {}",
+                self.superseded
+                    .iter()
+                    .map(|setting| format!(
+                        "{} (from {}, superseded by `{}`)",
+                        setting.setting.name, setting.setting.source, setting.env
+                    ))
+                    .join(", ")
+            );
+        }
+        if !self.unsupported.is_empty() {
+            warn_user!(
+                "Ignored unsupported settings from pip's configuration: {}",
+                self.unsupported
+                    .iter()
+                    .map(|setting| format!("{} (from {})", setting.name, setting.source))
+                    .join(", ")
+            );
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use anstream::{eprint, eprintln};
+use owo_colors::OwoColorize;
+use serde_json::json;
+
+use uv_client::ErrorKind;
+use uv_resolver::{NoSolutionError, ResolveError};
+
+use crate::commands::{ExitStatus, PipInstallError};
+
+/// The format for errors reported on exit.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub(crate) enum ErrorFormat {
+    /// Human-readable error messages.
+    #[default]
+    Text,
+    /// A single JSON object, including the error class and exit code.
+    Json,
+}
+
+/// A class of failure, each with a stable, documented exit code.
+///
+/// Exit codes `0` (success), `1` (failure due to user input), and `2` (unexpected error) retain
+/// their existing meanings; classified failures use the codes below. Codes are never reassigned:
+/// new classes are given new codes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum ErrorClass {
+    /// The requirements could not be resolved (e.g., due to conflicting versions or URLs).
+    Resolution,
+    /// A network request failed, or network access was required while offline.
+    Network,
+    /// A source distribution or editable failed to build.
+    Build,
+    /// Hash-checking failed, e.g., due to a mismatched or missing hash.
+    Hash,
+    /// The Python interpreter or virtual environment could not be found.
+    Environment,
+}
+
+impl ErrorClass {
+    /// The exit code for this class of failure.
+    pub(crate) fn exit_code(self) -> u8 {
+        match self {
+            Self::Resolution => 3,
+            Self::Network => 4,
+            Self::Build => 5,
+            Self::Hash => 6,
+            Self::Environment => 7,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Resolution => "resolution",
+            Self::Network => "network",
+            Self::Build => "build",
+            Self::Hash => "hash",
+            Self::Environment => "environment",
+        }
+    }
+
+    /// Classify an error by the outermost error in its chain with a known class.
+    pub(crate) fn from_error(err: &anyhow::Error) -> Option<Self> {
+        err.chain().find_map(classify)
+    }
+}
+
+/// Classify a single error in a chain.
+///
+/// Transparent wrappers (like [`ResolveError::Client`]) don't appear in the chain on their own,
+/// as they forward to the source of the wrapped error, so they're unwrapped explicitly.
+fn classify(err: &(dyn std::error::Error + 'static)) -> Option<ErrorClass> {
+    if let Some(err) = err.downcast_ref::<PipInstallError>() {
+        return match err {
+            PipInstallError::Resolve(err) => classify(err),
+            PipInstallError::Client(err) => classify_client(err.kind()),
+            PipInstallError::Hash(_) => Some(ErrorClass::Hash),
+            PipInstallError::Anyhow(err) => ErrorClass::from_error(err),
+            _ => None,
+        };
+    }
+    if let Some(err) = err.downcast_ref::<ResolveError>() {
+        return match err {
+            ResolveError::NoSolution(_)
+            | ResolveError::ConflictingUrlsDirect(..)
+            | ResolveError::ConflictingUrlsTransitive(..)
+            | ResolveError::ConflictingEditables(..) => Some(ErrorClass::Resolution),
+            ResolveError::UnhashedPackage(_) => Some(ErrorClass::Hash),
+            ResolveError::Client(err) => classify_client(err.kind()),
+            _ => None,
+        };
+    }
+    if let Some(err) = err.downcast_ref::<uv_distribution::Error>() {
+        return match err {
+            uv_distribution::Error::Build(..) | uv_distribution::Error::BuildEditable(..) => {
+                Some(ErrorClass::Build)
+            }
+            uv_distribution::Error::MismatchedHashes { .. }
+            | uv_distribution::Error::MissingHashes { .. }
+            | uv_distribution::Error::MissingActualHashes { .. }
+            | uv_distribution::Error::MissingExpectedHashes { .. }
+            | uv_distribution::Error::HashesNotSupportedSourceTree(_)
+            | uv_distribution::Error::HashesNotSupportedGit(_) => Some(ErrorClass::Hash),
+            uv_distribution::Error::Reqwest(_)
+            | uv_distribution::Error::ReqwestMiddlewareError(_) => Some(ErrorClass::Network),
+            uv_distribution::Error::Client(err) => classify_client(err.kind()),
+            _ => None,
+        };
+    }
+    if let Some(err) = err.downcast_ref::<uv_client::Error>() {
+        return classify_client(err.kind());
+    }
+    if let Some(kind) = err.downcast_ref::<ErrorKind>() {
+        return classify_client(kind);
+    }
+    if err.is::<uv_client::BetterReqwestError>() {
+        return Some(ErrorClass::Network);
+    }
+    if err.is::<uv_types::HashStrategyError>() {
+        return Some(ErrorClass::Hash);
+    }
+    if let Some(err) = err.downcast_ref::<uv_interpreter::Error>() {
+        return match err {
+            uv_interpreter::Error::MissingPyVenvCfg(_)
+            | uv_interpreter::Error::PythonNotFound
+            | uv_interpreter::Error::VenvNotFound
+            | uv_interpreter::Error::RequestedPythonNotFound(_)
+            | uv_interpreter::Error::NoSuchPython(_)
+            | uv_interpreter::Error::NoPythonInstalledUnix
+            | uv_interpreter::Error::NoPythonInstalledWindows => Some(ErrorClass::Environment),
+            _ => None,
+        };
+    }
+    None
+}
+
+fn classify_client(kind: &ErrorKind) -> Option<ErrorClass> {
+    match kind {
+        ErrorKind::ReqwestError(_)
+        | ErrorKind::ReqwestMiddlewareError(_)
+        | ErrorKind::FileNotFound(..)
+        | ErrorKind::Offline(_) => Some(ErrorClass::Network),
+        _ => None,
+    }
+}
+
+/// Report an error that caused the command to exit, returning the appropriate exit status.
+pub(crate) fn report_error(err: &anyhow::Error, format: ErrorFormat) -> ExitStatus {
+    let class = ErrorClass::from_error(err);
+    let status = class.map_or(ExitStatus::Error, ExitStatus::Classified);
+
+    match format {
+        ErrorFormat::Text => {
+            let mut causes = err.chain();
+            eprintln!("{}: {}", "error".red().bold(), causes.next().unwrap());
+            for err in causes {
+                eprintln!("  {}: {}", "Caused by".red().bold(), err);
+            }
+        }
+        ErrorFormat::Json => {
+            let mut causes = err.chain().map(ToString::to_string);
+            let message = causes.next().unwrap_or_default();
+            print_json(class, status, &message, &causes.collect::<Vec<_>>());
+        }
+    }
+
+    status
+}
+
+/// Report a resolution failure, returning the appropriate exit status.
+pub(crate) fn report_no_solution(err: &NoSolutionError, format: ErrorFormat) -> ExitStatus {
+    let status = ExitStatus::Classified(ErrorClass::Resolution);
+
+    match format {
+        ErrorFormat::Text => {
+            let report = miette::Report::msg(format!("{err}"))
+                .context("No solution found when resolving dependencies:");
+            eprint!("{report:?}");
+        }
+        ErrorFormat::Json => {
+            print_json(
+                Some(ErrorClass::Resolution),
+                status,
+                "No solution found when resolving dependencies",
+                &[err.to_string()],
+            );
+        }
+    }
+
+    status
+}
+
+fn print_json(class: Option<ErrorClass>, status: ExitStatus, message: &str, causes: &[String]) {
+    let error = json!({
+        "error": {
+            "class": class.map(ErrorClass::as_str),
+            "exit_code": status.code(),
+            "message": message,
+            "causes": causes,
+        }
+    });
+    eprintln!("{error}");
+}
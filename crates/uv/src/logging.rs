@@ -11,8 +11,8 @@ use tracing_durations_export::{
     plot::PlotConfig, DurationsLayer, DurationsLayerBuilder, DurationsLayerDropGuard,
 };
 use tracing_subscriber::filter::Directive;
-use tracing_subscriber::fmt::format::Writer;
-use tracing_subscriber::fmt::{FmtContext, FormatEvent, FormatFields};
+use tracing_subscriber::fmt::format::{FmtSpan, Writer};
+use tracing_subscriber::fmt::{FmtContext, FormatEvent, FormatFields, MakeWriter};
 use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::registry::LookupSpan;
 use tracing_subscriber::util::SubscriberInitExt;
@@ -20,6 +20,8 @@ use tracing_subscriber::{EnvFilter, Layer, Registry};
 use tracing_tree::time::Uptime;
 use tracing_tree::HierarchicalLayer;
 
+use crate::timings::Timings;
+
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum Level {
     /// Suppress all tracing output by default (overridable by `RUST_LOG`).
@@ -31,6 +33,16 @@ pub(crate) enum Level {
     ExtraVerbose,
 }
 
+/// The format for log messages.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub(crate) enum LogFormat {
+    /// Human-readable log messages.
+    #[default]
+    Text,
+    /// Newline-delimited JSON log messages, including the active spans and their fields.
+    Json,
+}
+
 struct UvFormat {
     display_timestamp: bool,
     display_level: bool,
@@ -112,9 +124,14 @@ where
 /// The [`Level`] is used to dictate the default filters (which can be overridden by the `RUST_LOG`
 /// environment variable) along with the formatting of the output. For example, [`Level::Verbose`]
 /// includes targets and timestamps, along with all `uv=debug` messages by default.
+///
+/// With [`LogFormat::Json`], messages are emitted as newline-delimited JSON, and an event is emitted
+/// (with timings) whenever a span closes.
 pub(crate) fn setup_logging(
     level: Level,
+    format: LogFormat,
     durations: impl Layer<Registry> + Send + Sync,
+    timings: Option<&Timings>,
 ) -> anyhow::Result<()> {
     let default_directive = match level {
         Level::Default => {
@@ -134,15 +151,39 @@ pub(crate) fn setup_logging(
             tracing::level_filters::LevelFilter::INFO,
         ));
 
+    // If requested, record the time spent in each phase of the command (for `--timings`).
+    let timings_layer = timings.map(|timings| {
+        timings
+            .layer()
+            .with_filter(tracing_subscriber::filter::Targets::new().with_target(
+                env!("CARGO_PKG_NAME"),
+                tracing::level_filters::LevelFilter::INFO,
+            ))
+    });
+
     let filter = EnvFilter::builder()
         .with_default_directive(default_directive)
         .from_env()
         .context("Invalid RUST_LOG directives")?;
 
-    match level {
-        Level::Default | Level::Verbose => {
+    // If configured, export spans to an OpenTelemetry collector.
+    #[cfg(feature = "otel")]
+    let otel_layer = setup_otel()?;
+    #[cfg(not(feature = "otel"))]
+    let otel_layer = None::<tracing_subscriber::layer::Identity>;
+
+    match (level, format) {
+        (_, LogFormat::Json) => {
+            tracing_subscriber::registry()
+                .with(durations_layer)
+                .with(timings_layer)
+                .with(otel_layer)
+                .with(json_layer(std::io::stderr).with_filter(filter))
+                .init();
+        }
+        (Level::Default | Level::Verbose, LogFormat::Text) => {
             // Regardless of the tracing level, show messages without any adornment.
-            let format = UvFormat {
+            let event_format = UvFormat {
                 display_timestamp: false,
                 display_level: true,
                 show_spans: false,
@@ -155,19 +196,23 @@ pub(crate) fn setup_logging(
             };
             tracing_subscriber::registry()
                 .with(durations_layer)
+                .with(timings_layer)
+                .with(otel_layer)
                 .with(
                     tracing_subscriber::fmt::layer()
-                        .event_format(format)
+                        .event_format(event_format)
                         .with_writer(std::io::stderr)
                         .with_ansi(ansi)
                         .with_filter(filter),
                 )
                 .init();
         }
-        Level::ExtraVerbose => {
+        (Level::ExtraVerbose, LogFormat::Text) => {
             // Regardless of the tracing level, include the uptime and target for each message.
             tracing_subscriber::registry()
                 .with(durations_layer)
+                .with(timings_layer)
+                .with(otel_layer)
                 .with(
                     HierarchicalLayer::default()
                         .with_targets(true)
@@ -182,6 +227,76 @@ pub(crate) fn setup_logging(
     Ok(())
 }
 
+/// A layer that writes newline-delimited JSON log messages (for [`LogFormat::Json`]).
+///
+/// Each message includes the current span and the list of active spans, along with their fields.
+/// An additional message is written (with `time.busy` and `time.idle`) whenever a span closes.
+fn json_layer<S, W>(writer: W) -> impl Layer<S> + Send + Sync
+where
+    S: Subscriber + for<'span> LookupSpan<'span>,
+    W: for<'writer> MakeWriter<'writer> + Send + Sync + 'static,
+{
+    tracing_subscriber::fmt::layer()
+        .json()
+        .with_current_span(true)
+        .with_span_list(true)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_writer(writer)
+}
+
+/// Configure an OpenTelemetry exporter, if `OTEL_EXPORTER_OTLP_ENDPOINT` is set.
+///
+/// Spans from uv's own crates are exported via OTLP over gRPC. The exporter respects the standard
+/// `OTEL_*` environment variables (e.g., `OTEL_EXPORTER_OTLP_HEADERS`).
+#[cfg(feature = "otel")]
+fn setup_otel<S>() -> anyhow::Result<Option<impl Layer<S> + Send + Sync>>
+where
+    S: Subscriber + for<'span> LookupSpan<'span>,
+{
+    use opentelemetry::KeyValue;
+
+    if std::env::var_os("OTEL_EXPORTER_OTLP_ENDPOINT").is_none() {
+        return Ok(None);
+    }
+
+    let tracer = opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_exporter(opentelemetry_otlp::new_exporter().tonic())
+        .with_trace_config(opentelemetry_sdk::trace::config().with_resource(
+            opentelemetry_sdk::Resource::new(vec![
+                KeyValue::new("service.name", env!("CARGO_PKG_NAME")),
+                KeyValue::new("service.version", env!("CARGO_PKG_VERSION")),
+            ]),
+        ))
+        .install_batch(opentelemetry_sdk::runtime::Tokio)
+        .context("Failed to initialize the OpenTelemetry exporter")?;
+
+    // Only export our own spans (and not, e.g., those of the exporter itself).
+    let filter = tracing_subscriber::filter::Targets::new()
+        .with_target("uv", tracing::level_filters::LevelFilter::INFO)
+        .with_target(
+            "install_wheel_rs",
+            tracing::level_filters::LevelFilter::INFO,
+        );
+
+    Ok(Some(
+        tracing_opentelemetry::layer()
+            .with_tracer(tracer)
+            .with_filter(filter),
+    ))
+}
+
+/// Flushes any pending spans to the OpenTelemetry collector when dropped.
+#[cfg(feature = "otel")]
+pub(crate) struct OtelGuard;
+
+#[cfg(feature = "otel")]
+impl Drop for OtelGuard {
+    fn drop(&mut self) {
+        opentelemetry::global::shutdown_tracer_provider();
+    }
+}
+
 /// Setup the `TRACING_DURATIONS_FILE` environment variable to enable tracing durations.
 #[cfg(feature = "tracing-durations-export")]
 pub(crate) fn setup_duration() -> anyhow::Result<(
@@ -215,3 +330,75 @@ pub(crate) fn setup_duration() -> anyhow::Result<(
         Ok((None, None))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+    use std::sync::{Arc, Mutex};
+
+    use tracing::{debug, info_span};
+    use tracing_subscriber::fmt::MakeWriter;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    use super::json_layer;
+
+    /// A writer that captures the log output in memory.
+    #[derive(Debug, Clone, Default)]
+    struct Capture(Arc<Mutex<Vec<u8>>>);
+
+    impl io::Write for Capture {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'writer> MakeWriter<'writer> for Capture {
+        type Writer = Self;
+
+        fn make_writer(&'writer self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    #[test]
+    fn json() {
+        let capture = Capture::default();
+        let subscriber = tracing_subscriber::registry().with(json_layer(capture.clone()));
+        tracing::subscriber::with_default(subscriber, || {
+            let span = info_span!("resolve", requirements = 2);
+            let _enter = span.enter();
+            let span = info_span!("get_metadata", package = "flask");
+            let _enter = span.enter();
+            debug!(version = "3.0.0", "Selecting candidate");
+        });
+
+        // Every line must be a JSON object; replace the fields that vary between runs.
+        let output = String::from_utf8(capture.0.lock().unwrap().clone()).unwrap();
+        let lines = output
+            .lines()
+            .map(|line| {
+                let mut value: serde_json::Value = serde_json::from_str(line).unwrap();
+                let object = value.as_object_mut().unwrap();
+                object["timestamp"] = "[TIMESTAMP]".into();
+                let fields = object["fields"].as_object_mut().unwrap();
+                for field in ["time.busy", "time.idle"] {
+                    if let Some(time) = fields.get_mut(field) {
+                        *time = "[TIME]".into();
+                    }
+                }
+                serde_json::to_string(&value).unwrap()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        insta::assert_snapshot!(lines, @r###"
+        {"fields":{"message":"Selecting candidate","version":"3.0.0"},"level":"DEBUG","span":{"name":"get_metadata","package":"flask"},"spans":[{"name":"resolve","requirements":2},{"name":"get_metadata","package":"flask"}],"target":"uv::logging::tests","timestamp":"[TIMESTAMP]"}
+        {"fields":{"message":"close","time.busy":"[TIME]","time.idle":"[TIME]"},"level":"INFO","span":{"name":"get_metadata","package":"flask"},"spans":[{"name":"resolve","requirements":2}],"target":"uv::logging::tests","timestamp":"[TIMESTAMP]"}
+        {"fields":{"message":"close","time.busy":"[TIME]","time.idle":"[TIME]"},"level":"INFO","span":{"name":"resolve","requirements":2},"spans":[],"target":"uv::logging::tests","timestamp":"[TIMESTAMP]"}
+        "###);
+    }
+}
@@ -1,27 +1,43 @@
 use std::env;
 use std::io::stdout;
+use std::net::{IpAddr, SocketAddr};
 use std::path::PathBuf;
 use std::process::ExitCode;
+use std::str::FromStr;
+use std::time::Duration;
 
-use anstream::eprintln;
 use anyhow::Result;
 use clap::error::{ContextKind, ContextValue};
 use clap::{CommandFactory, Parser};
-use owo_colors::OwoColorize;
-use tracing::instrument;
+use tracing::{debug, instrument};
+use url::Url;
 
-use distribution_types::IndexLocations;
+use distribution_types::{FlatIndexLocation, IndexLocations};
+use uv_auth::{LazyCredentialStore, ScopedNetrc};
 use uv_cache::{Cache, Refresh};
-use uv_client::Connectivity;
-use uv_configuration::{ConfigSettings, NoBinary, NoBuild, Reinstall, SetupPyStrategy, Upgrade};
+use uv_client::{
+    BaseClientBuilder, CertificatePin, Connectivity, DnsConfig, HostPattern, HttpTrace,
+    ProxyConfig, SignaturePolicy, TlsError, TlsOverride,
+};
+use uv_configuration::{
+    ConfigSettings, InstallFilter, NoBinary, NoBuild, Reinstall, RetryPolicy, SetupPyStrategy,
+    Upgrade,
+};
+use uv_installer::WheelPolicy;
 use uv_requirements::{ExtrasSpecification, RequirementsSource};
-use uv_resolver::{DependencyMode, PreReleaseMode};
+use uv_resolver::{DependencyMode, MinimumReleaseAge, PreReleaseMode, PreferLocal};
+use uv_warnings::warn_user;
 
-use crate::cli::{CacheCommand, CacheNamespace, Cli, Commands, Maybe, PipCommand, PipNamespace};
+use crate::cli::{
+    AuthCommand, AuthNamespace, CacheCommand, CacheNamespace, Cli, Commands, IndexCommand,
+    IndexNamespace, Maybe, PipCommand, PipNamespace, PythonCommand, PythonNamespace, VerifyArgs,
+};
 #[cfg(feature = "self-update")]
 use crate::cli::{SelfCommand, SelfNamespace};
 use crate::commands::ExitStatus;
-use crate::compat::CompatArgs;
+use crate::compat::{CompatArgs, PipConfigImport};
+use crate::errors::ErrorFormat;
+use crate::timings::Timings;
 
 #[cfg(target_os = "windows")]
 #[global_allocator]
@@ -39,73 +55,359 @@ static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
 #[global_allocator]
 static GLOBAL: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;
 
+mod accelerator;
 mod cli;
 mod commands;
 mod compat;
+mod errors;
 mod logging;
 mod printer;
 mod shell;
+mod timings;
 mod version;
 
-#[instrument]
-async fn run() -> Result<ExitStatus> {
-    let cli = match Cli::try_parse() {
-        Ok(cli) => cli,
-        Err(mut err) => {
-            if let Some(ContextValue::String(subcommand)) = err.get(ContextKind::InvalidSubcommand)
-            {
-                match subcommand.as_str() {
-                    "compile" | "lock" => {
-                        err.insert(
-                            ContextKind::SuggestedSubcommand,
-                            ContextValue::String("uv pip compile".to_string()),
-                        );
-                    }
-                    "sync" => {
-                        err.insert(
-                            ContextKind::SuggestedSubcommand,
-                            ContextValue::String("uv pip sync".to_string()),
-                        );
-                    }
-                    "install" | "add" => {
-                        err.insert(
-                            ContextKind::SuggestedSubcommand,
-                            ContextValue::String("uv pip install".to_string()),
-                        );
-                    }
-                    "uninstall" | "remove" => {
-                        err.insert(
-                            ContextKind::SuggestedSubcommand,
-                            ContextValue::String("uv pip uninstall".to_string()),
-                        );
-                    }
-                    "freeze" => {
-                        err.insert(
-                            ContextKind::SuggestedSubcommand,
-                            ContextValue::String("uv pip freeze".to_string()),
-                        );
-                    }
-                    "list" => {
-                        err.insert(
-                            ContextKind::SuggestedSubcommand,
-                            ContextValue::String("uv pip list".to_string()),
-                        );
-                    }
-                    "show" => {
-                        err.insert(
-                            ContextKind::SuggestedSubcommand,
-                            ContextValue::String("uv pip show".to_string()),
-                        );
-                    }
-                    _ => {}
-                }
+/// Exit with the given parse error, suggesting the `uv pip` equivalent of common `pip`
+/// subcommands.
+fn exit_with_suggestions(mut err: clap::Error) -> ! {
+    if let Some(ContextValue::String(subcommand)) = err.get(ContextKind::InvalidSubcommand) {
+        match subcommand.as_str() {
+            "compile" | "lock" => {
+                err.insert(
+                    ContextKind::SuggestedSubcommand,
+                    ContextValue::String("uv pip compile".to_string()),
+                );
+            }
+            "sync" => {
+                err.insert(
+                    ContextKind::SuggestedSubcommand,
+                    ContextValue::String("uv pip sync".to_string()),
+                );
+            }
+            "install" | "add" => {
+                err.insert(
+                    ContextKind::SuggestedSubcommand,
+                    ContextValue::String("uv pip install".to_string()),
+                );
+            }
+            "uninstall" | "remove" => {
+                err.insert(
+                    ContextKind::SuggestedSubcommand,
+                    ContextValue::String("uv pip uninstall".to_string()),
+                );
+            }
+            "freeze" => {
+                err.insert(
+                    ContextKind::SuggestedSubcommand,
+                    ContextValue::String("uv pip freeze".to_string()),
+                );
             }
-            err.exit()
+            "list" => {
+                err.insert(
+                    ContextKind::SuggestedSubcommand,
+                    ContextValue::String("uv pip list".to_string()),
+                );
+            }
+            "show" => {
+                err.insert(
+                    ContextKind::SuggestedSubcommand,
+                    ContextValue::String("uv pip show".to_string()),
+                );
+            }
+            _ => {}
         }
+    }
+    err.exit()
+}
+
+#[instrument(skip(cli))]
+async fn run(cli: Cli, pip_config: Option<PipConfigImport>) -> Result<ExitStatus> {
+    // Resolve external subcommands up front, such that unknown subcommands are rejected before
+    // doing any other work.
+    let external = if let Commands::External(args) = &cli.command {
+        let Some(executable) = args
+            .first()
+            .and_then(|name| name.to_str())
+            .and_then(commands::find_external)
+        else {
+            // Re-parse without external subcommands, to report the unknown subcommand as usual.
+            let err = Cli::command()
+                .allow_external_subcommands(false)
+                .external_subcommand_value_parser(None::<clap::builder::ValueParser>)
+                .try_get_matches()
+                .expect_err("unknown subcommands are rejected without external subcommands");
+            exit_with_suggestions(err)
+        };
+        Some(executable)
+    } else {
+        None
     };
 
     // Load the workspace settings.
-    let _ = uv_workspace::Workspace::find(env::current_dir()?)?;
+    let workspace = uv_workspace::Workspace::find(env::current_dir()?)?;
+
+    // Allow modifications to externally managed environments, if enabled in the settings.
+    let break_system_packages = workspace
+        .as_ref()
+        .and_then(|workspace| workspace.options().pip.as_ref()?.break_system_packages)
+        .unwrap_or(false);
+
+    // Apply any overrides declared in the settings, in addition to those provided via `--override`.
+    let override_dependencies = workspace
+        .as_ref()
+        .and_then(|workspace| workspace.options().override_dependencies.clone())
+        .unwrap_or_default();
+
+    // Disable build isolation for any packages listed in the settings, in addition to those
+    // provided via `--no-build-isolation-package`.
+    let no_build_isolation_package = workspace
+        .as_ref()
+        .and_then(|workspace| {
+            workspace
+                .options()
+                .pip
+                .as_ref()?
+                .no_build_isolation_package
+                .clone()
+        })
+        .unwrap_or_default();
+
+    // Read the accelerator indexes, used to select builds for the current hardware.
+    let (accelerator, accelerator_indexes) = workspace
+        .as_ref()
+        .and_then(|workspace| workspace.options().pip.as_ref())
+        .map(|pip| {
+            (
+                pip.accelerator,
+                pip.accelerator_index.clone().unwrap_or_default(),
+            )
+        })
+        .unwrap_or_default();
+
+    // Read the libc to target when selecting wheels, if it differs from that of the interpreter.
+    let (libc, force_manylinux) = workspace
+        .as_ref()
+        .and_then(|workspace| workspace.options().pip.as_ref())
+        .map(|pip| (pip.libc, pip.force_manylinux.unwrap_or_default()))
+        .unwrap_or_default();
+
+    // Read the policy for choosing among compatible wheels (e.g., to prefer `abi3` wheels).
+    let tag_policy = workspace
+        .as_ref()
+        .and_then(|workspace| workspace.options().pip.as_ref()?.tag_policy)
+        .unwrap_or_default();
+
+    // Read the platforms to prefer when choosing among compatible wheels (e.g., `manylinux2014`).
+    let platform_preference = workspace
+        .as_ref()
+        .and_then(|workspace| {
+            workspace
+                .options()
+                .pip
+                .as_ref()?
+                .platform_preference
+                .clone()
+        })
+        .unwrap_or_default();
+
+    // Read the preferred local version labels, in addition to those provided via `--prefer-local`.
+    let prefer_local = workspace
+        .as_ref()
+        .and_then(|workspace| {
+            workspace
+                .options()
+                .pip
+                .as_ref()?
+                .resolver
+                .as_ref()?
+                .prefer_local
+                .clone()
+        })
+        .unwrap_or_default();
+
+    // Read the minimum release ages, in addition to those provided via `--minimum-release-age`.
+    let minimum_release_age = workspace
+        .as_ref()
+        .and_then(|workspace| {
+            workspace
+                .options()
+                .pip
+                .as_ref()?
+                .resolver
+                .as_ref()?
+                .minimum_release_age
+                .clone()
+        })
+        .unwrap_or_default();
+
+    // Read the policy for scripts provided by more than one package.
+    let script_conflicts = workspace
+        .as_ref()
+        .and_then(|workspace| {
+            workspace
+                .options()
+                .pip
+                .as_ref()?
+                .installer
+                .as_ref()?
+                .script_conflicts
+        })
+        .unwrap_or_default();
+
+    // Read the policy for the contents of installed wheels, relative to the workspace root.
+    let wheel_policy = workspace.as_ref().and_then(|workspace| {
+        let wheel_policy = workspace
+            .options()
+            .pip
+            .as_ref()?
+            .installer
+            .as_ref()?
+            .wheel_policy
+            .as_ref()?;
+        Some(workspace.root().join(wheel_policy))
+    });
+
+    // Apply any per-index settings (e.g., to work around the quirks of specific index servers).
+    let index_settings = workspace
+        .as_ref()
+        .and_then(|workspace| workspace.options().pip.as_ref()?.index_settings.clone())
+        .unwrap_or_default();
+
+    // Read credentials from any project-scoped netrc files, resolved relative to the workspace root.
+    let mut scoped_netrc = ScopedNetrc::default();
+    if let Some(workspace) = workspace.as_ref() {
+        if let Some(netrc_files) = workspace.options().netrc_files.as_ref() {
+            let netrc_files = netrc_files
+                .iter()
+                .map(|file| workspace.root().join(file))
+                .collect::<Vec<_>>();
+            scoped_netrc = ScopedNetrc::from_files(&netrc_files)?;
+        }
+    }
+
+    // Determine the policy for retrying failed requests and downloads.
+    let retry_policy = if let Some(retry) = workspace
+        .as_ref()
+        .and_then(|workspace| workspace.options().retry.as_ref())
+    {
+        let default = RetryPolicy::default();
+        let policy = RetryPolicy {
+            retries: retry.retries.unwrap_or(default.retries),
+            min_backoff: retry
+                .min_backoff_ms
+                .map_or(default.min_backoff, Duration::from_millis),
+            max_backoff: retry
+                .max_backoff_ms
+                .map_or(default.max_backoff, Duration::from_millis),
+            retryable: retry.retryable.clone().unwrap_or(default.retryable),
+        };
+        if policy.min_backoff > policy.max_backoff {
+            anyhow::bail!(
+                "The minimum retry backoff ({}ms) must not exceed the maximum retry backoff ({}ms)",
+                policy.min_backoff.as_millis(),
+                policy.max_backoff.as_millis()
+            );
+        }
+        policy
+    } else {
+        RetryPolicy::default()
+    };
+
+    // Determine any TLS settings that are scoped to a specific index.
+    let tls_overrides = index_settings
+        .iter()
+        .filter(|settings| {
+            settings.cert.is_some() || settings.allow_insecure || !settings.pins.is_empty()
+        })
+        .map(|settings| {
+            let mut tls = TlsOverride::new(&settings.url)?;
+            if let Some(cert) = settings.cert.as_ref() {
+                let cert = workspace
+                    .as_ref()
+                    .map_or_else(|| cert.clone(), |workspace| workspace.root().join(cert));
+                tls = tls.with_ca_bundle(&cert)?;
+            }
+            if settings.allow_insecure {
+                tls = tls.with_insecure()?;
+            }
+            let pins = settings
+                .pins
+                .iter()
+                .map(|pin| CertificatePin::from_str(pin))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(tls.with_pins(pins))
+        })
+        .collect::<Result<Vec<_>, TlsError>>()?;
+
+    // Determine the DNS resolution (e.g., for split-horizon DNS).
+    let dns_config = if let Some(dns) = workspace
+        .as_ref()
+        .and_then(|workspace| workspace.options().dns.as_ref())
+    {
+        let nameservers = dns
+            .nameservers
+            .iter()
+            .flatten()
+            .map(|nameserver| {
+                // Default to the standard DNS port.
+                SocketAddr::from_str(nameserver)
+                    .or_else(|_| IpAddr::from_str(nameserver).map(|ip| SocketAddr::new(ip, 53)))
+                    .map_err(|_| anyhow::anyhow!("Invalid nameserver: `{nameserver}`"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        dns.hosts.iter().flatten().fold(
+            DnsConfig::default()
+                .with_ip_strategy(dns.ip_strategy.unwrap_or_default())
+                .with_nameservers(nameservers),
+            |config, (host, addr)| config.with_host(host, *addr),
+        )
+    } else {
+        DnsConfig::default()
+    };
+
+    // Determine the proxy selection, on top of the standard proxy environment variables.
+    let proxy_config = {
+        let mut config = index_settings
+            .iter()
+            .filter_map(|settings| Some((&settings.url, settings.proxy.clone()?)))
+            .fold(ProxyConfig::default(), |config, (url, proxy)| {
+                config.with_host_proxy(url, proxy)
+            });
+        if let Some(workspace) = workspace.as_ref() {
+            if let Some(proxy) = workspace.options().proxy.as_ref() {
+                if let Some(no_proxy) = proxy.no_proxy.as_ref() {
+                    let no_proxy = no_proxy
+                        .iter()
+                        .map(|pattern| HostPattern::from_str(pattern))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    config = config.with_no_proxy(no_proxy);
+                }
+                if let Some(pac_url) = proxy.pac_url.as_ref() {
+                    // Accept either a URL or a path, resolved relative to the workspace root.
+                    let pac_url = match Url::parse(pac_url) {
+                        // Single-letter schemes are Windows drive letters, not URLs.
+                        Ok(url) if url.scheme().len() > 1 => url,
+                        _ => Url::from_file_path(workspace.root().join(pac_url))
+                            .map_err(|()| anyhow::anyhow!("Invalid `pac-url`: `{pac_url}`"))?,
+                    };
+                    config = config.with_pac_url(pac_url);
+                }
+            }
+        }
+        config
+    };
+
+    // Require signatures for distributions from any signed `--find-links` sources.
+    let mut signature_policy = SignaturePolicy::default();
+    if let Some(find_links_keys) = workspace
+        .as_ref()
+        .and_then(|workspace| workspace.options().pip.as_ref()?.find_links_keys.as_ref())
+    {
+        for source in find_links_keys {
+            let location = FlatIndexLocation::from_str(&source.location)
+                .map_err(|err| anyhow::anyhow!("Invalid `--find-links` entry: {err}"))?;
+            signature_policy = signature_policy.with_source(location, &source.keys)?;
+        }
+    }
 
     let globals = cli.global_args;
 
@@ -114,29 +416,57 @@ async fn run() -> Result<ExitStatus> {
     let (duration_layer, _duration_guard) = logging::setup_duration()?;
     #[cfg(not(feature = "tracing-durations-export"))]
     let duration_layer = None::<tracing_subscriber::layer::Identity>;
+    let timings = globals.timings.then(Timings::default);
     logging::setup_logging(
         match globals.verbose {
             0 => logging::Level::Default,
             1 => logging::Level::Verbose,
             2.. => logging::Level::ExtraVerbose,
         },
+        globals.log_format,
         duration_layer,
+        timings.as_ref(),
     )?;
 
+    // Flush any pending spans to the OpenTelemetry collector on exit.
+    #[cfg(feature = "otel")]
+    let _otel_guard = logging::OtelGuard;
+
     // Configure the `Printer`, which controls user-facing output in the CLI.
-    let printer = if globals.quiet {
-        printer::Printer::Quiet
+    let verbosity = if globals.quiet {
+        printer::Verbosity::Quiet
     } else if globals.verbose > 0 {
-        printer::Printer::Verbose
+        printer::Verbosity::Verbose
     } else {
-        printer::Printer::Default
+        printer::Verbosity::Default
     };
+    let printer = printer::Printer::new(verbosity, globals.progress, globals.error_format);
 
     // Configure the `warn!` macros, which control user-facing warnings in the CLI.
     if !globals.quiet {
         uv_warnings::enable();
     }
 
+    if let Some(pip_config) = pip_config {
+        pip_config.warn();
+    }
+
+    // Configure the settings shared by all HTTP clients.
+    let mut client_builder = BaseClientBuilder::new()
+        .native_tls(globals.native_tls)
+        .retry_policy(retry_policy)
+        .tls_overrides(tls_overrides)?
+        .dns_config(dns_config)
+        .proxy_config(proxy_config)
+        .signature_policy(signature_policy)
+        .scoped_netrc(scoped_netrc)
+        .credential_store(LazyCredentialStore::default());
+
+    // Record HTTP requests to the trace file, if requested.
+    if let Some(http_trace) = globals.http_trace.as_deref() {
+        client_builder = client_builder.http_trace(HttpTrace::create(http_trace)?);
+    }
+
     if globals.no_color {
         anstream::ColorChoice::write_global(anstream::ColorChoice::Never);
     } else {
@@ -154,15 +484,63 @@ async fn run() -> Result<ExitStatus> {
         )
     }))?;
 
-    let cache = Cache::try_from(cli.cache_args)?;
+    // Scope the cache to the project, if requested, keyed by the workspace root (or the current
+    // directory, outside of a workspace).
+    let cache_scope = cli
+        .cache_args
+        .cache_scope()
+        .or_else(|| {
+            workspace
+                .as_ref()
+                .and_then(|workspace| workspace.options().cache_scope)
+        })
+        .unwrap_or_default();
+    let project_root = match workspace.as_ref() {
+        Some(workspace) => workspace.root().to_path_buf(),
+        None => env::current_dir()?,
+    };
+    let cache = Cache::try_from(cli.cache_args)?
+        .with_scope(cache_scope, &project_root)?
+        .with_lock_timeout(globals.lock_timeout.map(Duration::from_secs));
+
+    // Retain a handle to the cache, to enforce the size limit once the command completes.
+    let evictor = cache.clone();
 
-    match cli.command {
+    // Hold a shared lock on the cache while the command runs, such that other processes don't
+    // evict entries from underneath it. (`uv cache clean` removes the lock file itself, while
+    // `uv cache dedupe`, `uv cache compress`, and `uv cache verify --prune` acquire an exclusive
+    // lock.)
+    let cache_lock = if matches!(
+        cli.command,
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Clean(_)
+                | CacheCommand::Dedupe
+                | CacheCommand::Compress(_)
+                | CacheCommand::Verify(VerifyArgs { prune: true })
+        }) | Commands::Clean(_)
+    ) {
+        None
+    } else {
+        match cache.lock_shared() {
+            Ok(lock) => Some(lock),
+            Err(err) => {
+                debug!("Failed to lock the cache: {err}");
+                None
+            }
+        }
+    };
+
+    let status = match cli.command {
         Commands::Pip(PipNamespace {
             command: PipCommand::Compile(args),
         }) => {
             args.compat_args.validate()?;
 
-            let cache = cache.with_refresh(Refresh::from_args(args.refresh, args.refresh_package));
+            let cache = cache.with_refresh(Refresh::from_args(
+                args.refresh,
+                args.refresh_package,
+                args.refresh_url,
+            ));
             let requirements = args
                 .src_file
                 .into_iter()
@@ -177,17 +555,30 @@ async fn run() -> Result<ExitStatus> {
                 .r#override
                 .into_iter()
                 .map(RequirementsSource::from_overrides_txt)
+                .chain(
+                    override_dependencies
+                        .iter()
+                        .map(ToString::to_string)
+                        .map(RequirementsSource::Package),
+                )
                 .collect::<Vec<_>>();
+            let accelerator_index =
+                accelerator::select_index(args.accelerator.or(accelerator), &accelerator_indexes);
             let index_urls = IndexLocations::new(
                 args.index_url.and_then(Maybe::into_option),
-                args.extra_index_url
+                accelerator_index
                     .into_iter()
-                    .filter_map(Maybe::into_option)
+                    .chain(
+                        args.extra_index_url
+                            .into_iter()
+                            .filter_map(Maybe::into_option),
+                    )
                     .collect(),
                 args.find_links,
                 args.no_index,
-            );
-            let extras = if args.all_extras {
+            )
+            .with_settings(index_settings);
+            let extras = if args.all_extras || (args.split_extras && args.extra.is_empty()) {
                 ExtrasSpecification::All
             } else if args.extra.is_empty() {
                 ExtrasSpecification::None
@@ -206,6 +597,15 @@ async fn run() -> Result<ExitStatus> {
             } else {
                 args.prerelease
             };
+            let prefer_local = PreferLocal::from_preferences(
+                prefer_local.iter().cloned().chain(args.prefer_local),
+            );
+            let minimum_release_age = MinimumReleaseAge::from_ages(
+                minimum_release_age
+                    .iter()
+                    .cloned()
+                    .chain(args.minimum_release_age),
+            );
             let setup_py = if args.legacy_setup_py {
                 SetupPyStrategy::Setuptools
             } else {
@@ -218,11 +618,18 @@ async fn run() -> Result<ExitStatus> {
                 &overrides,
                 extras,
                 args.output_file.as_deref(),
+                args.output_dir.as_deref().filter(|_| args.split_extras),
+                args.emit_build_requirements.as_deref(),
                 args.resolution,
                 prerelease,
+                prefer_local,
+                minimum_release_age,
                 dependency_mode,
                 upgrade,
+                args.summary,
+                args.release_notes,
                 args.generate_hashes,
+                args.hash_algorithm,
                 args.no_emit_package,
                 args.no_strip_extras,
                 !args.no_annotate,
@@ -243,11 +650,20 @@ async fn run() -> Result<ExitStatus> {
                     Connectivity::Online
                 },
                 args.no_build_isolation,
+                args.no_build_isolation_package
+                    .into_iter()
+                    .chain(no_build_isolation_package.iter().cloned())
+                    .collect(),
                 no_build,
                 args.python_version,
+                args.python_platform,
+                args.libc.or(libc.filter(|_| !args.force_manylinux)),
+                args.force_manylinux || force_manylinux,
+                args.tag_policy.unwrap_or(tag_policy),
+                &platform_preference,
                 args.exclude_newer,
                 args.annotation_style,
-                globals.native_tls,
+                &client_builder,
                 globals.quiet,
                 args.link_mode,
                 cache,
@@ -260,22 +676,35 @@ async fn run() -> Result<ExitStatus> {
         }) => {
             args.compat_args.validate()?;
 
-            let cache = cache.with_refresh(Refresh::from_args(args.refresh, args.refresh_package));
+            let cache = cache.with_refresh(Refresh::from_args(
+                args.refresh,
+                args.refresh_package,
+                args.refresh_url,
+            ));
+            let accelerator_index =
+                accelerator::select_index(args.accelerator.or(accelerator), &accelerator_indexes);
             let index_urls = IndexLocations::new(
                 args.index_url.and_then(Maybe::into_option),
-                args.extra_index_url
+                accelerator_index
                     .into_iter()
-                    .filter_map(Maybe::into_option)
+                    .chain(
+                        args.extra_index_url
+                            .into_iter()
+                            .filter_map(Maybe::into_option),
+                    )
                     .collect(),
                 args.find_links,
                 args.no_index,
-            );
+            )
+            .with_settings(index_settings);
             let sources = args
                 .src_file
                 .into_iter()
                 .map(RequirementsSource::from_requirements_file)
                 .collect::<Vec<_>>();
             let reinstall = Reinstall::from_args(args.reinstall, args.reinstall_package);
+            let install_filter =
+                InstallFilter::from_args(args.only_package, args.no_install_package);
             let no_binary = NoBinary::from_args(args.no_binary);
             let no_build = NoBuild::from_args(args.only_binary, args.no_build);
             let setup_py = if args.legacy_setup_py {
@@ -284,11 +713,22 @@ async fn run() -> Result<ExitStatus> {
                 SetupPyStrategy::Pep517
             };
             let config_settings = args.config_setting.into_iter().collect::<ConfigSettings>();
+            let wheel_policy = args
+                .wheel_policy
+                .or(wheel_policy)
+                .map(|path| WheelPolicy::from_path(&path))
+                .transpose()?;
 
             commands::pip_sync(
                 &sources,
                 &reinstall,
+                &install_filter,
+                args.no_install_package_deps,
+                args.layer,
+                &args.no_prune,
                 args.link_mode,
+                args.script_conflicts.unwrap_or(script_conflicts),
+                wheel_policy.as_ref(),
                 args.compile,
                 args.require_hashes,
                 index_urls,
@@ -302,13 +742,22 @@ async fn run() -> Result<ExitStatus> {
                 },
                 &config_settings,
                 args.no_build_isolation,
+                args.no_build_isolation_package
+                    .into_iter()
+                    .chain(no_build_isolation_package.iter().cloned())
+                    .collect(),
                 no_build,
                 no_binary,
+                args.libc.or(libc.filter(|_| !args.force_manylinux)),
+                args.force_manylinux || force_manylinux,
+                args.tag_policy.unwrap_or(tag_policy),
+                &platform_preference,
                 args.strict,
+                args.atomic,
                 args.python,
                 args.system,
-                args.break_system_packages,
-                globals.native_tls,
+                args.break_system_packages || break_system_packages,
+                &client_builder,
                 cache,
                 printer,
             )
@@ -317,7 +766,11 @@ async fn run() -> Result<ExitStatus> {
         Commands::Pip(PipNamespace {
             command: PipCommand::Install(args),
         }) => {
-            let cache = cache.with_refresh(Refresh::from_args(args.refresh, args.refresh_package));
+            let cache = cache.with_refresh(Refresh::from_args(
+                args.refresh,
+                args.refresh_package,
+                args.refresh_url,
+            ));
             let requirements = args
                 .package
                 .into_iter()
@@ -338,16 +791,29 @@ async fn run() -> Result<ExitStatus> {
                 .r#override
                 .into_iter()
                 .map(RequirementsSource::from_overrides_txt)
+                .chain(
+                    override_dependencies
+                        .iter()
+                        .map(ToString::to_string)
+                        .map(RequirementsSource::Package),
+                )
                 .collect::<Vec<_>>();
+            let accelerator_index =
+                accelerator::select_index(args.accelerator.or(accelerator), &accelerator_indexes);
             let index_urls = IndexLocations::new(
                 args.index_url.and_then(Maybe::into_option),
-                args.extra_index_url
+                accelerator_index
                     .into_iter()
-                    .filter_map(Maybe::into_option)
+                    .chain(
+                        args.extra_index_url
+                            .into_iter()
+                            .filter_map(Maybe::into_option),
+                    )
                     .collect(),
                 args.find_links,
                 args.no_index,
-            );
+            )
+            .with_settings(index_settings);
             let extras = if args.all_extras {
                 ExtrasSpecification::All
             } else if args.extra.is_empty() {
@@ -369,12 +835,26 @@ async fn run() -> Result<ExitStatus> {
             } else {
                 args.prerelease
             };
+            let prefer_local = PreferLocal::from_preferences(
+                prefer_local.iter().cloned().chain(args.prefer_local),
+            );
+            let minimum_release_age = MinimumReleaseAge::from_ages(
+                minimum_release_age
+                    .iter()
+                    .cloned()
+                    .chain(args.minimum_release_age),
+            );
             let setup_py = if args.legacy_setup_py {
                 SetupPyStrategy::Setuptools
             } else {
                 SetupPyStrategy::Pep517
             };
             let config_settings = args.config_setting.into_iter().collect::<ConfigSettings>();
+            let wheel_policy = args
+                .wheel_policy
+                .or(wheel_policy)
+                .map(|path| WheelPolicy::from_path(&path))
+                .transpose()?;
 
             commands::pip_install(
                 &requirements,
@@ -383,6 +863,8 @@ async fn run() -> Result<ExitStatus> {
                 &extras,
                 args.resolution,
                 prerelease,
+                prefer_local,
+                minimum_release_age,
                 dependency_mode,
                 upgrade,
                 index_urls,
@@ -390,6 +872,8 @@ async fn run() -> Result<ExitStatus> {
                 args.keyring_provider,
                 reinstall,
                 args.link_mode,
+                args.script_conflicts.unwrap_or(script_conflicts),
+                wheel_policy.as_ref(),
                 args.compile,
                 args.require_hashes,
                 setup_py,
@@ -400,16 +884,28 @@ async fn run() -> Result<ExitStatus> {
                 },
                 &config_settings,
                 args.no_build_isolation,
+                args.no_build_isolation_package
+                    .into_iter()
+                    .chain(no_build_isolation_package.iter().cloned())
+                    .collect(),
                 no_build,
                 no_binary,
+                args.libc.or(libc.filter(|_| !args.force_manylinux)),
+                args.force_manylinux || force_manylinux,
+                args.tag_policy.unwrap_or(tag_policy),
+                &platform_preference,
                 args.strict,
                 args.exclude_newer,
                 args.python,
                 args.system,
-                args.break_system_packages,
-                globals.native_tls,
+                args.user,
+                args.prefix,
+                args.break_system_packages || break_system_packages,
+                &client_builder,
                 cache,
                 args.dry_run,
+                args.report,
+                args.prefetch,
                 printer,
             )
             .await
@@ -431,14 +927,16 @@ async fn run() -> Result<ExitStatus> {
                 &sources,
                 args.python,
                 args.system,
-                args.break_system_packages,
+                args.break_system_packages || break_system_packages,
+                args.force,
+                args.auto_remove,
                 cache,
                 if args.offline {
                     Connectivity::Offline
                 } else {
                     Connectivity::Online
                 },
-                globals.native_tls,
+                &client_builder,
                 args.keyring_provider,
                 printer,
             )
@@ -449,6 +947,7 @@ async fn run() -> Result<ExitStatus> {
         }) => commands::pip_freeze(
             args.exclude_editable,
             args.strict,
+            &args.paths,
             args.python.as_deref(),
             args.system,
             &cache,
@@ -463,6 +962,8 @@ async fn run() -> Result<ExitStatus> {
                 args.editable,
                 args.exclude_editable,
                 &args.exclude,
+                args.not_required,
+                args.user_requested,
                 &args.format,
                 args.strict,
                 args.python.as_deref(),
@@ -475,6 +976,8 @@ async fn run() -> Result<ExitStatus> {
             command: PipCommand::Show(args),
         }) => commands::pip_show(
             args.package,
+            args.format,
+            args.files,
             args.strict,
             args.python.as_deref(),
             args.system,
@@ -489,14 +992,135 @@ async fn run() -> Result<ExitStatus> {
         })
         | Commands::Clean(args) => commands::cache_clean(&args.package, &cache, printer),
         Commands::Cache(CacheNamespace {
-            command: CacheCommand::Prune,
-        }) => commands::cache_prune(&cache, printer),
+            command: CacheCommand::Prune(args),
+        }) => commands::cache_prune(args.max_age, &cache, printer),
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Dedupe,
+        }) => commands::cache_dedupe(&cache, printer),
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Compress(args),
+        }) => commands::cache_compress(args.max_age, &cache, printer),
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Info(args),
+        }) => commands::cache_info(args.format, &cache, printer),
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Dir,
         }) => {
             commands::cache_dir(&cache);
             Ok(ExitStatus::Success)
         }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Verify(args),
+        }) => commands::cache_verify(args.prune, &cache, printer),
+        Commands::Python(PythonNamespace {
+            command: PythonCommand::Inspect(args),
+        }) => commands::python_inspect(
+            args.format,
+            args.python.as_deref(),
+            args.system,
+            &cache,
+            printer,
+        ),
+        Commands::Auth(AuthNamespace {
+            command: AuthCommand::Login(args),
+        }) => commands::auth_login(
+            &args.url,
+            args.username,
+            args.password,
+            args.keyring_provider,
+            args.netrc.as_deref(),
+            printer,
+        ),
+        Commands::Auth(AuthNamespace {
+            command: AuthCommand::Logout(args),
+        }) => commands::auth_logout(
+            &args.url,
+            args.username.as_deref(),
+            args.keyring_provider,
+            printer,
+        ),
+        Commands::Auth(AuthNamespace {
+            command: AuthCommand::List,
+        }) => commands::auth_list(printer),
+        Commands::Auth(AuthNamespace {
+            command: AuthCommand::Check(args),
+        }) => {
+            commands::auth_check(&args.url, args.keyring_provider, &client_builder, printer).await
+        }
+        Commands::Doctor(args) => {
+            let index_locations = IndexLocations::new(
+                args.index_url.and_then(Maybe::into_option),
+                args.extra_index_url
+                    .into_iter()
+                    .filter_map(Maybe::into_option)
+                    .collect(),
+                Vec::new(),
+                false,
+            )
+            .with_settings(index_settings);
+
+            commands::doctor(&index_locations, &client_builder, &cache, printer).await
+        }
+        Commands::Index(IndexNamespace {
+            command: IndexCommand::Snapshot(args),
+        }) => {
+            let index_locations = IndexLocations::new(
+                args.index_url.and_then(Maybe::into_option),
+                args.extra_index_url
+                    .into_iter()
+                    .filter_map(Maybe::into_option)
+                    .collect(),
+                Vec::new(),
+                false,
+            )
+            .with_settings(index_settings);
+            let sources = args
+                .src_file
+                .into_iter()
+                .map(RequirementsSource::from_requirements_file)
+                .collect::<Vec<_>>();
+
+            commands::index_snapshot(
+                &sources,
+                &args.output_dir,
+                index_locations,
+                args.index_strategy,
+                args.keyring_provider,
+                &client_builder,
+                cache,
+                printer,
+            )
+            .await
+        }
+        #[cfg(feature = "serve-index")]
+        Commands::ServeIndex(args) => {
+            let index_locations = IndexLocations::new(
+                args.index_url.and_then(Maybe::into_option),
+                args.extra_index_url
+                    .into_iter()
+                    .filter_map(Maybe::into_option)
+                    .collect(),
+                Vec::new(),
+                false,
+            )
+            .with_settings(index_settings);
+
+            commands::serve_index(
+                args.bind,
+                index_locations,
+                args.index_strategy,
+                args.keyring_provider,
+                if args.offline {
+                    Connectivity::Offline
+                } else {
+                    Connectivity::Online
+                },
+                &client_builder,
+                cache,
+                printer,
+            )
+            .await
+        }
         Commands::Venv(args) => {
             args.compat_args.validate()?;
 
@@ -509,17 +1133,38 @@ async fn run() -> Result<ExitStatus> {
                 // No find links for the venv subcommand, to keep things simple
                 Vec::new(),
                 args.no_index,
-            );
+            )
+            .with_settings(index_settings);
 
-            // Since we use ".venv" as the default name, we use "." as the default prompt.
+            // Since we use ".venv" as the default name, we use "." as the default prompt. When
+            // updating an existing environment, only an explicit prompt is applied.
             let prompt = args.prompt.or_else(|| {
-                if args.name == PathBuf::from(".venv") {
+                if !args.upgrade_settings && args.name == PathBuf::from(".venv") {
                     Some(".".to_string())
                 } else {
                     None
                 }
             });
 
+            // Resolve any `site-packages` extras relative to the workspace root.
+            let site_packages_extras = workspace
+                .as_ref()
+                .and_then(|workspace| {
+                    let extras = workspace
+                        .options()
+                        .venv
+                        .as_ref()?
+                        .site_packages_extras
+                        .as_ref()?;
+                    Some(
+                        extras
+                            .iter()
+                            .map(|extra| workspace.root().join(extra))
+                            .collect::<Vec<_>>(),
+                    )
+                })
+                .unwrap_or_default();
+
             commands::venv(
                 &args.name,
                 args.python.as_deref(),
@@ -528,7 +1173,19 @@ async fn run() -> Result<ExitStatus> {
                 args.index_strategy,
                 args.keyring_provider,
                 uv_virtualenv::Prompt::from_args(prompt),
-                args.system_site_packages,
+                if args.system_site_packages {
+                    Some(true)
+                } else if args.no_system_site_packages {
+                    Some(false)
+                } else {
+                    None
+                },
+                args.upgrade_settings,
+                args.seed_activators
+                    .as_deref()
+                    .unwrap_or(uv_virtualenv::Activator::ALL),
+                args.externally_managed.as_deref(),
+                &site_packages_extras,
                 if args.offline {
                     Connectivity::Offline
                 } else {
@@ -536,7 +1193,7 @@ async fn run() -> Result<ExitStatus> {
                 },
                 args.seed,
                 args.exclude_newer,
-                globals.native_tls,
+                &client_builder,
                 &cache,
                 printer,
             )
@@ -544,8 +1201,35 @@ async fn run() -> Result<ExitStatus> {
         }
         #[cfg(feature = "self-update")]
         Commands::Self_(SelfNamespace {
-            command: SelfCommand::Update,
-        }) => commands::self_update(printer).await,
+            command: SelfCommand::Update(args),
+        }) => {
+            let channel = args
+                .channel
+                .or_else(|| {
+                    workspace
+                        .as_ref()
+                        .and_then(|workspace| workspace.options().update_channel)
+                })
+                .unwrap_or_default();
+            commands::self_update(channel, &client_builder, printer).await
+        }
+        #[cfg(feature = "self-update")]
+        Commands::Self_(SelfNamespace {
+            command: SelfCommand::Package(args),
+        }) => {
+            let output_dir = match args.output_dir {
+                Some(output_dir) => output_dir,
+                None => env::current_dir()?,
+            };
+            commands::self_package(
+                &args.target,
+                args.release.as_deref(),
+                &output_dir,
+                &client_builder,
+                printer,
+            )
+            .await
+        }
         Commands::Version { output_format } => {
             commands::version(output_format, &mut stdout())?;
             Ok(ExitStatus::Success)
@@ -554,10 +1238,56 @@ async fn run() -> Result<ExitStatus> {
             shell.generate(&mut Cli::command(), &mut stdout());
             Ok(ExitStatus::Success)
         }
+        Commands::External(args) => {
+            let executable = external.expect("external subcommands are resolved up front");
+            commands::external(&executable, &args[1..], workspace.as_ref(), &cache)
+        }
+    };
+
+    // Report the time spent in each phase of the command.
+    if let Some(timings) = timings {
+        timings.report(printer)?;
+    }
+
+    // Evict the least-recently used entries from the cache, if it exceeds the size limit. Eviction
+    // requires exclusive access to the cache, so release our own lock first.
+    drop(cache_lock);
+    if let Some(max_size) = evictor.max_size() {
+        match evictor.evict(max_size) {
+            Ok(summary) => {
+                if summary.num_files > 0 || summary.num_dirs > 0 {
+                    debug!(
+                        "Evicted {} files ({} bytes) from the cache",
+                        summary.num_files, summary.total_bytes
+                    );
+                }
+            }
+            Err(err) => {
+                warn_user!("Failed to evict entries from the cache: {err}");
+            }
+        }
     }
+
+    status
 }
 
 fn main() -> ExitCode {
+    // If enabled, import settings from `pip`'s configuration, prior to parsing the command line.
+    // The settings are applied to the process environment, so this must happen before the runtime
+    // starts any threads.
+    let pip_config = match PipConfigImport::from_env() {
+        Ok(pip_config) => pip_config,
+        Err(err) => return errors::report_error(&err, ErrorFormat::default()).into(),
+    };
+
+    let cli = match Cli::try_parse() {
+        Ok(cli) => cli,
+        Err(err) => exit_with_suggestions(err),
+    };
+
+    // Retain the format for errors reported on exit, as the command consumes the arguments.
+    let error_format = cli.global_args.error_format;
+
     let result = if let Ok(stack_size) = env::var("UV_STACK_SIZE") {
         // Artificially limit the stack size to test for stack overflows. Windows has a default stack size of 1MB,
         // which is lower than the linux and mac default.
@@ -569,7 +1299,7 @@ fn main() -> ExitCode {
                 .thread_stack_size(stack_size)
                 .build()
                 .expect("Failed building the Runtime")
-                .block_on(run())
+                .block_on(run(cli, pip_config))
         };
         std::thread::Builder::new()
             .stack_size(stack_size)
@@ -582,18 +1312,11 @@ fn main() -> ExitCode {
             .enable_all()
             .build()
             .expect("Failed building the Runtime")
-            .block_on(run())
+            .block_on(run(cli, pip_config))
     };
 
     match result {
         Ok(code) => code.into(),
-        Err(err) => {
-            let mut causes = err.chain();
-            eprintln!("{}: {}", "error".red().bold(), causes.next().unwrap());
-            for err in causes {
-                eprintln!("  {}: {}", "Caused by".red().bold(), err);
-            }
-            ExitStatus::Error.into()
-        }
+        Err(err) => errors::report_error(&err, error_format).into(),
     }
 }
@@ -1,43 +1,111 @@
+use std::time::Instant;
+
 use anstream::{eprint, print};
 use indicatif::ProgressDrawTarget;
 
+use crate::errors::ErrorFormat;
+
+/// The style of progress output.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub(crate) enum ProgressMode {
+    /// Show interactive progress bars, if stderr is a terminal.
+    #[default]
+    Auto,
+    /// Print a timestamped line for each step (e.g., each download or build), along with a
+    /// periodic summary of the steps in progress.
+    Plain,
+    /// Don't show any progress.
+    None,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub(crate) enum Printer {
-    /// A printer that prints to standard streams (e.g., stdout).
+pub(crate) enum Verbosity {
+    /// Print to standard streams (e.g., stdout).
     Default,
-    /// A printer that suppresses all output.
+    /// Suppress all output.
     Quiet,
-    /// A printer that prints all output, including debug messages.
+    /// Print all output, including debug messages.
     Verbose,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Printer {
+    verbosity: Verbosity,
+    /// The style of progress output.
+    progress: ProgressMode,
+    /// The time at which uv started, relative to which plain progress output is timestamped.
+    start: Instant,
+    /// The format for errors reported on exit.
+    error_format: ErrorFormat,
+}
+
 impl Printer {
+    /// Create a [`Printer`] with the given verbosity, style of progress output, and error format.
+    pub(crate) fn new(
+        verbosity: Verbosity,
+        progress: ProgressMode,
+        error_format: ErrorFormat,
+    ) -> Self {
+        Self {
+            verbosity,
+            progress,
+            start: Instant::now(),
+            error_format,
+        }
+    }
+
+    /// Return the format for errors reported on exit.
+    pub(crate) fn error_format(self) -> ErrorFormat {
+        self.error_format
+    }
+
+    /// Returns `true` if the printer prints all output, including debug messages.
+    pub(crate) fn is_verbose(self) -> bool {
+        self.verbosity == Verbosity::Verbose
+    }
+
     /// Return the [`ProgressDrawTarget`] for this printer.
     pub(crate) fn target(self) -> ProgressDrawTarget {
-        match self {
-            Self::Default => ProgressDrawTarget::stderr(),
-            Self::Quiet => ProgressDrawTarget::hidden(),
+        // Progress bars are only shown in the default mode.
+        if self.progress != ProgressMode::Auto {
+            return ProgressDrawTarget::hidden();
+        }
+        match self.verbosity {
+            Verbosity::Default => ProgressDrawTarget::stderr(),
+            Verbosity::Quiet => ProgressDrawTarget::hidden(),
             // Confusingly, hide the progress bar when in verbose mode.
             // Otherwise, it gets interleaved with debug messages.
-            Self::Verbose => ProgressDrawTarget::hidden(),
+            Verbosity::Verbose => ProgressDrawTarget::hidden(),
+        }
+    }
+
+    /// Return the [`Stderr`] for line-oriented progress output, if enabled, along with the time
+    /// relative to which its lines are timestamped.
+    pub(crate) fn plain_progress(self) -> Option<(Stderr, Instant)> {
+        match self.progress {
+            ProgressMode::Plain => match self.stderr() {
+                Stderr::Enabled => Some((Stderr::Enabled, self.start)),
+                Stderr::Disabled => None,
+            },
+            ProgressMode::Auto | ProgressMode::None => None,
         }
     }
 
     /// Return the [`Stdout`] for this printer.
     pub(crate) fn stdout(self) -> Stdout {
-        match self {
-            Self::Default => Stdout::Enabled,
-            Self::Quiet => Stdout::Disabled,
-            Self::Verbose => Stdout::Enabled,
+        match self.verbosity {
+            Verbosity::Default => Stdout::Enabled,
+            Verbosity::Quiet => Stdout::Disabled,
+            Verbosity::Verbose => Stdout::Enabled,
         }
     }
 
     /// Return the [`Stderr`] for this printer.
     pub(crate) fn stderr(self) -> Stderr {
-        match self {
-            Self::Default => Stderr::Enabled,
-            Self::Quiet => Stderr::Disabled,
-            Self::Verbose => Stderr::Enabled,
+        match self.verbosity {
+            Verbosity::Default => Stderr::Enabled,
+            Verbosity::Quiet => Stderr::Disabled,
+            Verbosity::Verbose => Stderr::Enabled,
         }
     }
 }
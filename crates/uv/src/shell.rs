@@ -1,5 +1,7 @@
 use std::path::Path;
 
+use uv_virtualenv::Activator;
+
 /// Shells for which virtualenv activation scripts are available.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub(crate) enum Shell {
@@ -17,6 +19,8 @@ pub(crate) enum Shell {
     Nushell,
     /// C SHell (csh)
     Csh,
+    /// Xonsh
+    Xonsh,
 }
 
 impl Shell {
@@ -33,6 +37,8 @@ impl Shell {
     pub(crate) fn from_env() -> Option<Shell> {
         if std::env::var_os("NU_VERSION").is_some() {
             Some(Shell::Nushell)
+        } else if std::env::var_os("XONSH_VERSION").is_some() {
+            Some(Shell::Xonsh)
         } else if std::env::var_os("FISH_VERSION").is_some() {
             Some(Shell::Fish)
         } else if std::env::var_os("BASH_VERSION").is_some() {
@@ -69,6 +75,19 @@ impl Shell {
     pub(crate) fn from_shell_path<P: AsRef<Path>>(path: P) -> Option<Shell> {
         parse_shell_from_path(path.as_ref())
     }
+
+    /// Return the [`Activator`] that provides the shell's activation script.
+    pub(crate) fn activator(self) -> Activator {
+        match self {
+            Shell::Bash | Shell::Zsh => Activator::Bash,
+            Shell::Fish => Activator::Fish,
+            Shell::Powershell => Activator::Powershell,
+            Shell::Cmd => Activator::Cmd,
+            Shell::Nushell => Activator::Nushell,
+            Shell::Csh => Activator::Csh,
+            Shell::Xonsh => Activator::Xonsh,
+        }
+    }
 }
 
 fn parse_shell_from_path(path: &Path) -> Option<Shell> {
@@ -78,6 +97,7 @@ fn parse_shell_from_path(path: &Path) -> Option<Shell> {
         "zsh" => Some(Shell::Zsh),
         "fish" => Some(Shell::Fish),
         "csh" => Some(Shell::Csh),
+        "xonsh" => Some(Shell::Xonsh),
         "powershell" | "powershell_ise" => Some(Shell::Powershell),
         _ => None,
     }
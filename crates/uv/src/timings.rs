@@ -0,0 +1,232 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Write as _};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use owo_colors::OwoColorize;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing::{Metadata, Subscriber};
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+
+use crate::commands::{elapsed, human_readable_bytes};
+use crate::printer::Printer;
+
+/// A phase of a command, as reported by `--timings`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Phase {
+    Metadata,
+    Resolution,
+    Download,
+    Build,
+    Install,
+    Compile,
+}
+
+impl Phase {
+    /// Map a span (by crate and name) to the phase it contributes to, if any.
+    fn from_span(metadata: &Metadata<'_>) -> Option<Self> {
+        let krate = metadata.target().split("::").next()?;
+        match (krate, metadata.name()) {
+            ("uv_client", "simple_api" | "wheel_metadata") => Some(Self::Metadata),
+            ("uv_resolver", "solve") => Some(Self::Resolution),
+            ("uv_distribution", "wheel" | "download" | "download_source_dist") => {
+                Some(Self::Download)
+            }
+            ("uv_distribution", "build_distribution" | "build_metadata") => Some(Self::Build),
+            ("uv_installer", "install") => Some(Self::Install),
+            ("uv_installer", "compile_tree") => Some(Self::Compile),
+            _ => None,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Metadata => "Metadata fetch",
+            Self::Resolution => "Resolution",
+            Self::Download => "Downloads",
+            Self::Build => "Builds",
+            Self::Install => "Install",
+            Self::Compile => "Bytecode compilation",
+        }
+    }
+}
+
+/// The wall-clock time spent in a phase.
+///
+/// Spans within a phase frequently overlap (e.g., concurrent downloads), so the time is measured
+/// from the first span opening to the last span closing, rather than summed across spans.
+#[derive(Debug, Default)]
+struct PhaseTiming {
+    /// The number of open spans in the phase.
+    active: usize,
+    /// The time at which the phase (most recently) became active.
+    since: Option<Instant>,
+    /// The total time during which the phase was active.
+    total: Duration,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    phases: BTreeMap<Phase, PhaseTiming>,
+    /// The time spent building each distribution.
+    builds: BTreeMap<String, Duration>,
+    /// The number of bytes fetched for remote distributions.
+    bytes: u64,
+}
+
+/// Collects the time spent in each phase of a command, for `--timings`.
+///
+/// Timings are derived from the `tracing` spans emitted throughout the codebase; see
+/// [`Timings::layer`].
+#[derive(Debug, Default, Clone)]
+pub(crate) struct Timings(Arc<Mutex<State>>);
+
+impl Timings {
+    /// Return a [`Layer`] that records span timings into this collector.
+    pub(crate) fn layer(&self) -> TimingsLayer {
+        TimingsLayer(self.0.clone())
+    }
+
+    /// Print a breakdown of the recorded timings.
+    pub(crate) fn report(&self, printer: Printer) -> anyhow::Result<()> {
+        let state = self.0.lock().unwrap();
+
+        let mut lines = Vec::new();
+        for (phase, timing) in &state.phases {
+            let mut line = elapsed(timing.total);
+            if *phase == Phase::Download && state.bytes > 0 {
+                let (bytes, unit) = human_readable_bytes(state.bytes);
+                write!(line, " ({bytes:.1}{unit})")?;
+            }
+            lines.push((format!("  {}", phase.label()), line));
+
+            // Break builds down by distribution, slowest first.
+            if *phase == Phase::Build {
+                let mut builds = state.builds.iter().collect::<Vec<_>>();
+                builds.sort_by(|a, b| b.1.cmp(a.1));
+                for (dist, duration) in builds {
+                    lines.push((format!("    {dist}"), elapsed(*duration)));
+                }
+            }
+        }
+
+        if lines.is_empty() {
+            return Ok(());
+        }
+
+        let width = lines
+            .iter()
+            .map(|(label, _)| label.len())
+            .max()
+            .unwrap_or(0);
+        let mut stderr = printer.stderr();
+        writeln!(stderr, "{}", "Timings:".bold())?;
+        for (label, duration) in lines {
+            writeln!(stderr, "{label:width$}  {}", duration.dimmed())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A [`Layer`] that records the time spent in each [`Phase`].
+pub(crate) struct TimingsLayer(Arc<Mutex<State>>);
+
+/// The timing data attached to an open span.
+struct SpanTiming {
+    phase: Phase,
+    start: Instant,
+    dist: Option<String>,
+}
+
+impl<S> Layer<S> for TimingsLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let metadata = attrs.metadata();
+
+        // Track the size of each remote distribution that's fetched.
+        if metadata.name() == "get_wheel" {
+            let mut visitor = FieldVisitor::default();
+            attrs.record(&mut visitor);
+            if let Some(size) = visitor.size {
+                self.0.lock().unwrap().bytes += size;
+            }
+            return;
+        }
+
+        let Some(phase) = Phase::from_span(metadata) else {
+            return;
+        };
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+
+        let mut visitor = FieldVisitor::default();
+        attrs.record(&mut visitor);
+
+        let start = Instant::now();
+        {
+            let mut state = self.0.lock().unwrap();
+            let timing = state.phases.entry(phase).or_default();
+            if timing.active == 0 {
+                timing.since = Some(start);
+            }
+            timing.active += 1;
+        }
+
+        span.extensions_mut().insert(SpanTiming {
+            phase,
+            start,
+            dist: visitor.dist,
+        });
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(&id) else {
+            return;
+        };
+        let Some(timing) = span.extensions_mut().remove::<SpanTiming>() else {
+            return;
+        };
+
+        let end = Instant::now();
+        let mut state = self.0.lock().unwrap();
+        if let Some(phase) = state.phases.get_mut(&timing.phase) {
+            phase.active = phase.active.saturating_sub(1);
+            if phase.active == 0 {
+                if let Some(since) = phase.since.take() {
+                    phase.total += end.duration_since(since);
+                }
+            }
+        }
+        if let (Phase::Build, Some(dist)) = (timing.phase, timing.dist) {
+            *state.builds.entry(dist).or_default() += end.duration_since(timing.start);
+        }
+    }
+}
+
+/// Extracts the fields relevant to [`Timings`] from a span.
+#[derive(Default)]
+struct FieldVisitor {
+    dist: Option<String>,
+    size: Option<u64>,
+}
+
+impl Visit for FieldVisitor {
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        if field.name() == "size" {
+            self.size = Some(value);
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "dist" {
+            self.dist = Some(format!("{value:?}"));
+        }
+    }
+}
@@ -0,0 +1,152 @@
+#![cfg(feature = "python")]
+
+use std::process::Command;
+
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+
+use common::uv_snapshot;
+
+use crate::common::{get_bin, TestContext};
+
+mod common;
+
+/// Create an `auth` command with options shared across scenarios.
+fn auth_command(context: &TestContext, subcommand: &str) -> Command {
+    let mut command = Command::new(get_bin());
+    command
+        .arg("auth")
+        .arg(subcommand)
+        .env("UV_CREDENTIALS_KEY", "passphrase")
+        .env(
+            "UV_CREDENTIALS_FILE",
+            context.temp_dir.child("credentials.json").path(),
+        )
+        .env("UV_NO_WRAP", "1")
+        .current_dir(&context.temp_dir);
+
+    command
+}
+
+/// Store, list, and remove credentials.
+#[test]
+fn login_list_logout() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), auth_command(&context, "login")
+        .arg("https://pkgs.example.com/simple/")
+        .arg("--username")
+        .arg("ci")
+        .arg("--password")
+        .arg("secret"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Stored credentials for ci at: https://pkgs.example.com
+    "###
+    );
+
+    // The password shouldn't be stored in plain text.
+    let contents = fs_err::read_to_string(context.temp_dir.child("credentials.json"))?;
+    assert!(!contents.contains("secret"));
+
+    uv_snapshot!(context.filters(), auth_command(&context, "list"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://pkgs.example.com ci
+
+    ----- stderr -----
+    "###
+    );
+
+    uv_snapshot!(context.filters(), auth_command(&context, "logout")
+        .arg("https://pkgs.example.com/other/"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Removed credentials for: https://pkgs.example.com
+    "###
+    );
+
+    uv_snapshot!(context.filters(), auth_command(&context, "logout")
+        .arg("https://pkgs.example.com/other/"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    No credentials found for: https://pkgs.example.com
+    "###
+    );
+
+    Ok(())
+}
+
+/// Reading the store with the wrong passphrase should fail.
+#[test]
+fn wrong_passphrase() {
+    let context = TestContext::new("3.12");
+
+    auth_command(&context, "login")
+        .arg("https://pkgs.example.com/simple/")
+        .arg("--username")
+        .arg("ci")
+        .arg("--password")
+        .arg("secret")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), auth_command(&context, "list")
+        .env("UV_CREDENTIALS_KEY", "incorrect"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to decrypt credential store at `[TEMP_DIR]/credentials.json`; is `UV_CREDENTIALS_KEY` correct?
+    "###
+    );
+}
+
+/// Removing credentials from the keyring requires a username.
+#[test]
+fn logout_keyring_missing_username() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), auth_command(&context, "logout")
+        .arg("https://pkgs.example.com/simple/")
+        .arg("--keyring-provider")
+        .arg("subprocess"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: A username is required to remove credentials from the keyring, via `--username`
+    "###
+    );
+}
+
+/// Requests to a public index should succeed without any credentials.
+#[test]
+#[cfg(feature = "pypi")]
+fn check_public_index() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), auth_command(&context, "check")
+        .arg("https://pypi.org/simple/"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Authenticated to https://pypi.org/simple/ (200 OK)
+    "###
+    );
+}
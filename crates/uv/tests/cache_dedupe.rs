@@ -0,0 +1,155 @@
+#![cfg(all(feature = "python", feature = "pypi"))]
+
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+
+use common::uv_snapshot;
+
+use crate::common::{copy_dir_all, TestContext};
+
+mod common;
+
+/// `cache dedupe` should be a no-op if there are no duplicate files in the cache.
+#[test]
+fn dedupe_no_op() {
+    let context = TestContext::new("3.12");
+
+    // Install a requirement, to populate the cache.
+    context.install().arg("iniconfig==2.0.0").assert().success();
+
+    uv_snapshot!(context.filters(), context.cache().arg("dedupe"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Deduplicating cache at: [CACHE_DIR]/
+    No duplicate files found
+    "###);
+}
+
+/// `cache dedupe` should link identical files across archives.
+#[test]
+fn dedupe_duplicate_archive() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Install a requirement, to populate the cache.
+    context.install().arg("iniconfig==2.0.0").assert().success();
+
+    // Duplicate the unzipped wheel within the archive bucket.
+    let archive = context.cache_dir.child("archive-v0");
+    let original = fs_err::read_dir(archive.path())?
+        .filter_map(Result::ok)
+        .find(|entry| entry.path().is_dir())
+        .expect("an archive to exist")
+        .path();
+    copy_dir_all(original, archive.child("duplicate"))?;
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain([(r"Linked \d+ files", "Linked [N] files")])
+        .collect();
+
+    uv_snapshot!(filters, context.cache().arg("dedupe"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Deduplicating cache at: [CACHE_DIR]/
+    Linked [N] files ([SIZE] saved)
+    "###);
+
+    // Running again should be a no-op, since the files are already linked.
+    uv_snapshot!(context.filters(), context.cache().arg("dedupe"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Deduplicating cache at: [CACHE_DIR]/
+    No duplicate files found
+    "###);
+
+    // Reinstalling from the deduplicated cache should succeed.
+    context
+        .install()
+        .arg("iniconfig==2.0.0")
+        .arg("--reinstall")
+        .assert()
+        .success();
+
+    context.assert_command("import iniconfig").success();
+
+    Ok(())
+}
+
+/// With `--cache-dedupe`, files should be shared via the blob store as wheels are unzipped.
+#[test]
+#[cfg(unix)]
+fn dedupe_on_unzip() {
+    let context = TestContext::new("3.12");
+
+    context
+        .install()
+        .arg("iniconfig==2.0.0")
+        .arg("--cache-dedupe")
+        .assert()
+        .success();
+
+    context
+        .cache_dir
+        .child("blobs-v0")
+        .assert(predicates::path::is_dir());
+
+    // A subsequent `cache dedupe` should find nothing left to link.
+    uv_snapshot!(context.filters(), context.cache().arg("dedupe"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Deduplicating cache at: [CACHE_DIR]/
+    No duplicate files found
+    "###);
+
+    context.assert_command("import iniconfig").success();
+}
+
+/// `cache compress` should compress unused archives, which are restored on next use.
+#[test]
+fn compress_unused_archive() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context.install().arg("iniconfig==2.0.0").assert().success();
+
+    uv_snapshot!(context.filters(), context.cache().arg("compress").arg("--max-age").arg("0s"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Compressing cache at: [CACHE_DIR]/
+    Compressed 1 entry ([SIZE] saved)
+    "###);
+
+    // Reinstalling should restore the archive from its compressed copy.
+    context
+        .install()
+        .arg("iniconfig==2.0.0")
+        .arg("--reinstall")
+        .assert()
+        .success();
+
+    let compressed = fs_err::read_dir(context.cache_dir.child("archive-v0").path())?
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "zip"))
+        .count();
+    assert_eq!(compressed, 0);
+
+    context.assert_command("import iniconfig").success();
+
+    Ok(())
+}
@@ -0,0 +1,138 @@
+#![cfg(all(feature = "python", feature = "pypi"))]
+
+use std::process::Command;
+
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+
+use crate::common::{create_venv, get_bin, TestContext};
+
+mod common;
+
+/// Create a `pip install` command with options shared across scenarios.
+fn install_command(context: &TestContext) -> Command {
+    let mut command = Command::new(get_bin());
+    command
+        .arg("pip")
+        .arg("install")
+        .arg("--cache-dir")
+        .arg(context.cache_dir.path())
+        .env("VIRTUAL_ENV", context.venv.as_os_str())
+        .env("UV_NO_WRAP", "1")
+        .current_dir(&context.temp_dir);
+
+    if cfg!(all(windows, debug_assertions)) {
+        // TODO(konstin): Reduce stack usage in debug mode enough that the tests pass with the
+        // default windows stack of 1MB
+        command.env("UV_STACK_SIZE", (8 * 1024 * 1024).to_string());
+    }
+
+    command
+}
+
+/// Return the number of unzipped wheels in the cache.
+fn archives(context: &TestContext) -> Result<usize> {
+    let mut count = 0;
+    for entry in fs_err::read_dir(context.cache_dir.child("archive-v0").path())? {
+        if entry?.file_type()?.is_dir() {
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+
+/// Entries should only be evicted once the cache exceeds the size limit.
+#[test]
+fn evict_size_limit() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // A generous limit leaves the cache untouched.
+    install_command(&context)
+        .arg("iniconfig==2.0.0")
+        .arg("--cache-max-size")
+        .arg("10GB")
+        .assert()
+        .success();
+    assert_eq!(archives(&context)?, 1);
+
+    // A limit that the cache exceeds evicts the unzipped wheels.
+    install_command(&context)
+        .arg("tomli==2.0.1")
+        .arg("--cache-max-size")
+        .arg("1B")
+        .assert()
+        .success();
+    assert_eq!(archives(&context)?, 0);
+
+    // Environments installed by copying or hard linking are unaffected.
+    context.assert_command("import iniconfig").success();
+    context.assert_command("import tomli").success();
+
+    Ok(())
+}
+
+/// Entries that are referenced by environments installed with `--link-mode symlink` should
+/// never be evicted.
+#[test]
+fn evict_symlinked() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    install_command(&context)
+        .arg("iniconfig==2.0.0")
+        .arg("--link-mode")
+        .arg("symlink")
+        .assert()
+        .success();
+    assert_eq!(archives(&context)?, 1);
+
+    // Only the entry that isn't referenced via symbolic links is evicted.
+    install_command(&context)
+        .arg("tomli==2.0.1")
+        .arg("--cache-max-size")
+        .arg("1B")
+        .assert()
+        .success();
+    assert_eq!(archives(&context)?, 1);
+
+    context.assert_command("import iniconfig").success();
+    context.assert_command("import tomli").success();
+
+    Ok(())
+}
+
+/// Once the environment that installed an entry with `--link-mode symlink` is removed, the entry
+/// is no longer pinned, and its pin is cleaned up.
+#[test]
+fn evict_symlinked_environment_removed() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    install_command(&context)
+        .arg("iniconfig==2.0.0")
+        .arg("--link-mode")
+        .arg("symlink")
+        .assert()
+        .success();
+    assert_eq!(archives(&context)?, 1);
+
+    fs_err::remove_dir_all(&context.venv)?;
+
+    let other = context.temp_dir.child("other");
+    other.create_dir_all()?;
+    let venv = create_venv(&other, &context.cache_dir, "3.12");
+    install_command(&context)
+        .arg("tomli==2.0.1")
+        .arg("--cache-max-size")
+        .arg("1B")
+        .env("VIRTUAL_ENV", venv.as_os_str())
+        .assert()
+        .success();
+    assert_eq!(archives(&context)?, 0);
+
+    // No pins are left behind.
+    for entry in fs_err::read_dir(context.cache_dir.child("archive-v0").path())? {
+        assert_ne!(entry?.path().extension(), Some("pin".as_ref()));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,106 @@
+#![cfg(all(feature = "python", feature = "pypi"))]
+
+use std::process::Command;
+
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+
+use crate::common::{get_bin, TestContext};
+
+mod common;
+
+/// Create a `cache info` command with options shared across scenarios.
+fn info_command(context: &TestContext) -> Command {
+    let mut command = Command::new(get_bin());
+    command
+        .arg("cache")
+        .arg("info")
+        .arg("--cache-dir")
+        .arg(context.cache_dir.path())
+        .env("VIRTUAL_ENV", context.venv.as_os_str())
+        .env("UV_NO_WRAP", "1")
+        .current_dir(&context.temp_dir);
+
+    if cfg!(all(windows, debug_assertions)) {
+        // TODO(konstin): Reduce stack usage in debug mode enough that the tests pass with the
+        // default windows stack of 1MB
+        command.env("UV_STACK_SIZE", (8 * 1024 * 1024).to_string());
+    }
+
+    command
+}
+
+/// Create a `pip sync` command with options shared across scenarios.
+fn sync_command(context: &TestContext) -> Command {
+    let mut command = Command::new(get_bin());
+    command
+        .arg("pip")
+        .arg("sync")
+        .arg("--cache-dir")
+        .arg(context.cache_dir.path())
+        .env("VIRTUAL_ENV", context.venv.as_os_str())
+        .env("UV_NO_WRAP", "1")
+        .current_dir(&context.temp_dir);
+
+    if cfg!(all(windows, debug_assertions)) {
+        // TODO(konstin): Reduce stack usage in debug mode enough that the tests pass with the
+        // default windows stack of 1MB
+        command.env("UV_STACK_SIZE", (8 * 1024 * 1024).to_string());
+    }
+
+    command
+}
+
+/// `cache info --format json` should report the bucket sizes, the largest entries, and the hit
+/// rate across commands.
+#[test]
+fn info_json() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    // Install a requirement, to populate the cache (a miss).
+    sync_command(&context)
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    // Reinstall the requirement from the cache (a hit).
+    sync_command(&context)
+        .arg("requirements.txt")
+        .arg("--reinstall")
+        .assert()
+        .success();
+
+    let output = info_command(&context)
+        .arg("--format")
+        .arg("json")
+        .output()?;
+    assert!(output.status.success());
+
+    let info: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+
+    // The archive bucket should contain the unzipped wheel.
+    let archive = info["buckets"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|bucket| bucket["name"] == "archive-v0")
+        .unwrap();
+    assert!(archive["size"].as_u64().unwrap() > 0);
+
+    // The largest entry should be the wheel.
+    assert_eq!(
+        info["largest"][0]["name"], "iniconfig-2.0.0-py3-none-any",
+        "{info:#}"
+    );
+
+    // One miss, followed by one hit.
+    assert_eq!(info["stats"]["commands"].as_array().unwrap().len(), 2);
+    assert_eq!(info["stats"]["commands"][0]["misses"], 1);
+    assert_eq!(info["stats"]["commands"][1]["hits"], 1);
+
+    Ok(())
+}
@@ -156,3 +156,50 @@ fn prune_stale_symlink() -> Result<()> {
 
     Ok(())
 }
+
+/// `cache prune --max-age` should remove any unzipped wheels that haven't been used recently,
+/// along with the links that reference them.
+#[test]
+fn prune_max_age() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio")?;
+
+    // Install a requirement, to populate the cache.
+    sync_command(&context)
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    // A generous age should leave the cache untouched.
+    uv_snapshot!(context.filters(), prune_command(&context).arg("--max-age").arg("30d"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    No unused entries found
+    "###);
+
+    // An age of zero should remove the archive, its link, and its pointer.
+    uv_snapshot!(context.filters(), prune_command(&context).arg("--max-age").arg("0s"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    Removed 46 files ([SIZE])
+    "###);
+
+    // Reinstalling should re-fetch the evicted wheel.
+    sync_command(&context)
+        .arg("requirements.txt")
+        .arg("--reinstall")
+        .assert()
+        .success();
+
+    Ok(())
+}
@@ -0,0 +1,157 @@
+#![cfg(all(feature = "python", feature = "pypi"))]
+
+use std::process::Command;
+
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+
+use common::uv_snapshot;
+
+use crate::common::{get_bin, TestContext};
+
+mod common;
+
+/// Create a `cache verify` command with options shared across scenarios.
+fn verify_command(context: &TestContext) -> Command {
+    let mut command = Command::new(get_bin());
+    command
+        .arg("cache")
+        .arg("verify")
+        .arg("--cache-dir")
+        .arg(context.cache_dir.path())
+        .env("VIRTUAL_ENV", context.venv.as_os_str())
+        .env("UV_NO_WRAP", "1")
+        .current_dir(&context.temp_dir);
+
+    if cfg!(all(windows, debug_assertions)) {
+        // TODO(konstin): Reduce stack usage in debug mode enough that the tests pass with the
+        // default windows stack of 1MB
+        command.env("UV_STACK_SIZE", (8 * 1024 * 1024).to_string());
+    }
+
+    command
+}
+
+/// Create a `pip sync` command with options shared across scenarios.
+fn sync_command(context: &TestContext) -> Command {
+    let mut command = Command::new(get_bin());
+    command
+        .arg("pip")
+        .arg("sync")
+        .arg("--cache-dir")
+        .arg(context.cache_dir.path())
+        .env("VIRTUAL_ENV", context.venv.as_os_str())
+        .env("UV_NO_WRAP", "1")
+        .current_dir(&context.temp_dir);
+
+    if cfg!(all(windows, debug_assertions)) {
+        // TODO(konstin): Reduce stack usage in debug mode enough that the tests pass with the
+        // default windows stack of 1MB
+        command.env("UV_STACK_SIZE", (8 * 1024 * 1024).to_string());
+    }
+
+    command
+}
+
+/// `cache verify` should succeed if the cached wheels match their `RECORD`.
+#[test]
+fn verify_intact() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    // Install a requirement, to populate the cache.
+    sync_command(&context)
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), verify_command(&context), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    Verified 1 archive; no corrupt entries found
+    "###);
+
+    Ok(())
+}
+
+/// `cache verify` should report cached wheels that don't match their `RECORD`, and remove them
+/// with `--prune`.
+#[test]
+fn verify_corrupt_archive() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    // Install a requirement, to populate the cache.
+    sync_command(&context)
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    // Corrupt a file within the unzipped wheel.
+    let archive = fs_err::read_dir(context.cache_dir.child("archive-v0").path())?
+        .next()
+        .expect("an archive to exist")?
+        .path();
+    fs_err::write(archive.join("iniconfig").join("__init__.py"), "corrupt")?;
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain([
+            (r"archive-v0/[A-Za-z0-9_-]+", "archive-v0/[ARCHIVE]"),
+            (r"Removed \d+ files", "Removed [N] files"),
+        ])
+        .collect();
+
+    uv_snapshot!(filters, verify_command(&context), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    [CACHE_DIR]/archive-v0/[ARCHIVE]: iniconfig/__init__.py (modified) doesn't match `RECORD`
+    Verified 1 archive; found 1 corrupt entry
+    Run with `--prune` to remove the corrupt entries
+    "###);
+
+    uv_snapshot!(filters, verify_command(&context).arg("--prune"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    [CACHE_DIR]/archive-v0/[ARCHIVE]: iniconfig/__init__.py (modified) doesn't match `RECORD`
+    Verified 1 archive; found 1 corrupt entry
+    Removed [N] files ([SIZE])
+    "###);
+
+    // Reinstalling should re-fetch the wheel, rather than reusing the corrupt archive.
+    sync_command(&context)
+        .arg("requirements.txt")
+        .arg("--reinstall")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), verify_command(&context), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    Verified 1 archive; no corrupt entries found
+    "###);
+
+    Ok(())
+}
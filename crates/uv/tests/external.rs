@@ -0,0 +1,77 @@
+#![cfg(all(unix, feature = "python"))]
+
+use std::os::unix::fs::PermissionsExt;
+use std::process::Command;
+
+use anyhow::Result;
+use assert_fs::prelude::*;
+
+use crate::common::{get_bin, uv_snapshot, TestContext};
+
+mod common;
+
+/// Unknown subcommands should be dispatched to a `uv-<name>` executable on the `PATH`, along with
+/// the context of the invocation.
+#[test]
+fn external_subcommand() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let bin = context.temp_dir.child("bin");
+    let hello = bin.child("uv-hello");
+    hello.write_str(indoc::indoc! {r#"
+        #!/bin/sh
+        echo "args: $*"
+        echo "cache: $UV_CACHE_DIR/"
+        echo "project: $UV_PROJECT_ROOT/"
+        echo "environment: $UV_ENVIRONMENT/"
+        exit 3
+    "#})?;
+    fs_err::set_permissions(&hello, std::fs::Permissions::from_mode(0o755))?;
+
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml.write_str("native-tls = false")?;
+
+    let path = std::env::join_paths(std::iter::once(bin.to_path_buf()).chain(
+        std::env::split_paths(&std::env::var_os("PATH").unwrap_or_default()),
+    ))?;
+
+    uv_snapshot!(context.filters(), Command::new(get_bin())
+        .arg("--cache-dir")
+        .arg(context.cache_dir.path())
+        .arg("hello")
+        .arg("world")
+        .arg("--flag")
+        .env("PATH", path)
+        .env("VIRTUAL_ENV", context.venv.as_os_str())
+        .current_dir(&context.temp_dir), @r###"
+    success: false
+    exit_code: 3
+    ----- stdout -----
+    args: world --flag
+    cache: [CACHE_DIR]/
+    project: [TEMP_DIR]/
+    environment: [VENV]/
+
+    ----- stderr -----
+    "###
+    );
+
+    Ok(())
+}
+
+/// Unknown subcommands without a corresponding executable should be rejected as usual.
+#[test]
+fn external_subcommand_missing() {
+    uv_snapshot!(Command::new(get_bin()).arg("hello"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: unrecognized subcommand 'hello'
+
+    Usage: uv [OPTIONS] <COMMAND>
+
+    For more information, try '--help'.
+    "###);
+}
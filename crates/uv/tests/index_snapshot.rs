@@ -0,0 +1,153 @@
+#![cfg(all(feature = "python", feature = "pypi"))]
+
+use std::process::Command;
+
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+
+use common::uv_snapshot;
+
+use crate::common::{get_bin, TestContext};
+
+mod common;
+
+/// Create an `index snapshot` command with options shared across scenarios.
+fn snapshot_command(context: &TestContext) -> Command {
+    let mut command = Command::new(get_bin());
+    command
+        .arg("index")
+        .arg("snapshot")
+        .arg("--cache-dir")
+        .arg(context.cache_dir.path())
+        .env("UV_NO_WRAP", "1")
+        .current_dir(&context.temp_dir);
+
+    if cfg!(all(windows, debug_assertions)) {
+        // TODO(konstin): Reduce stack usage in debug mode enough that the tests pass with the
+        // default windows stack of 1MB
+        command.env("UV_STACK_SIZE", (8 * 1024 * 1024).to_string());
+    }
+
+    command
+}
+
+/// Snapshot a pinned requirement, including only the file that matches its hash.
+#[test]
+fn snapshot_hashed() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(
+        "iniconfig==2.0.0 --hash=sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374",
+    )?;
+
+    uv_snapshot!(context.filters(), snapshot_command(&context)
+        .arg("requirements.txt")
+        .arg("--output-dir")
+        .arg("snapshot"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+     + iniconfig-2.0.0-py3-none-any.whl
+    Snapshotted 1 package (1 file) to snapshot/simple in [TIME]
+    "###
+    );
+
+    context
+        .temp_dir
+        .child("snapshot/files/b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374/iniconfig-2.0.0-py3-none-any.whl")
+        .assert(predicates::path::is_file());
+
+    let index = fs_err::read_to_string(context.temp_dir.child("snapshot/simple/index.html"))?;
+    insta::assert_snapshot!(index, @r###"
+    <!DOCTYPE html>
+    <html>
+      <head>
+        <meta name="pypi:repository-version" content="1.0">
+        <title>Simple index</title>
+      </head>
+      <body>
+        <a href="iniconfig/">iniconfig</a><br>
+      </body>
+    </html>
+    "###);
+
+    let project = fs_err::read_to_string(
+        context
+            .temp_dir
+            .child("snapshot/simple/iniconfig/index.html"),
+    )?;
+    insta::assert_snapshot!(project, @r###"
+    <!DOCTYPE html>
+    <html>
+      <head>
+        <meta name="pypi:repository-version" content="1.0">
+        <title>Links for iniconfig</title>
+      </head>
+      <body>
+        <h1>Links for iniconfig</h1>
+        <a href="../../files/b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374/iniconfig-2.0.0-py3-none-any.whl#sha256=b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374" data-requires-python="&gt;=3.7">iniconfig-2.0.0-py3-none-any.whl</a><br>
+      </body>
+    </html>
+    "###);
+
+    Ok(())
+}
+
+/// Snapshot a project that's pinned to different versions in separate requirements, listing the
+/// files for both versions on a single project page.
+#[test]
+fn snapshot_multiple_versions() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc::indoc! {r#"
+        iniconfig==2.0.0 ; python_version >= "3.8"
+        iniconfig==1.1.1 ; python_version < "3.8"
+        "#
+    })?;
+
+    snapshot_command(&context)
+        .arg("requirements.txt")
+        .arg("--output-dir")
+        .arg("snapshot")
+        .assert()
+        .success();
+
+    let project = fs_err::read_to_string(
+        context
+            .temp_dir
+            .child("snapshot/simple/iniconfig/index.html"),
+    )?;
+    assert!(project.contains(">iniconfig-2.0.0-py3-none-any.whl</a>"));
+    assert!(project.contains(">iniconfig-1.1.1-py2.py3-none-any.whl</a>"));
+
+    Ok(())
+}
+
+/// Reject requirements that aren't pinned to a specific version.
+#[test]
+fn snapshot_unpinned() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig>=2.0.0")?;
+
+    uv_snapshot!(context.filters(), snapshot_command(&context)
+        .arg("requirements.txt")
+        .arg("--output-dir")
+        .arg("snapshot"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Requirement must be pinned to a specific version (e.g., `==1.0.0`): iniconfig>=2.0.0
+    "###
+    );
+
+    Ok(())
+}
@@ -47,6 +47,152 @@ fn compile_requirements_in() -> Result<()> {
     Ok(())
 }
 
+/// Re-resolve with unchanged inputs, reusing the previous resolution; then modify the output
+/// file, which should invalidate the previous resolution.
+#[test]
+fn compile_unchanged_inputs() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio")?;
+
+    uv_snapshot!(context
+        .compile()
+        .arg("requirements.in")
+        .arg("--output-file")
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] --exclude-newer 2024-03-25T00:00:00Z requirements.in --output-file requirements.txt
+    anyio==4.3.0
+    idna==3.6
+        # via anyio
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###);
+
+    // The inputs are unchanged, so the resolution should be reused.
+    uv_snapshot!(context
+        .compile()
+        .arg("requirements.in")
+        .arg("--output-file")
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] --exclude-newer 2024-03-25T00:00:00Z requirements.in --output-file requirements.txt
+    anyio==4.3.0
+    idna==3.6
+        # via anyio
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###);
+
+    // Pin an older version of `anyio` in the output file, which should be respected.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        anyio==3.7.0
+        idna==3.6
+        sniffio==1.3.1
+    "})?;
+
+    uv_snapshot!(context
+        .compile()
+        .arg("requirements.in")
+        .arg("--output-file")
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] --exclude-newer 2024-03-25T00:00:00Z requirements.in --output-file requirements.txt
+    anyio==3.7.0
+    idna==3.6
+        # via anyio
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###);
+
+    Ok(())
+}
+
+/// Re-resolve after removing a wheel from a `--find-links` directory, which should invalidate the
+/// previous resolution.
+#[test]
+fn compile_unchanged_inputs_find_links() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("validation")?;
+
+    let links = context.temp_dir.child("links");
+    for wheel in [
+        "validation-1.0.0-py3-none-any.whl",
+        "validation-2.0.0-py3-none-any.whl",
+    ] {
+        links.child(wheel).write_file(
+            &context
+                .workspace_root
+                .join("scripts")
+                .join("links")
+                .join(wheel),
+        )?;
+    }
+
+    uv_snapshot!(context
+        .compile()
+        .arg("requirements.in")
+        .arg("--find-links")
+        .arg("links")
+        .arg("--no-index")
+        .arg("--output-file")
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] --exclude-newer 2024-03-25T00:00:00Z requirements.in --no-index --output-file requirements.txt
+    validation==2.0.0
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###);
+
+    // Remove the selected wheel, such that the previous resolution can't be reused.
+    fs_err::remove_file(links.child("validation-2.0.0-py3-none-any.whl").path())?;
+
+    uv_snapshot!(context
+        .compile()
+        .arg("requirements.in")
+        .arg("--find-links")
+        .arg("links")
+        .arg("--no-index")
+        .arg("--output-file")
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] --exclude-newer 2024-03-25T00:00:00Z requirements.in --no-index --output-file requirements.txt
+    validation==1.0.0
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###);
+
+    Ok(())
+}
+
 /// Resolve a specific version of `anyio` from a `requirements.in` file with a `--annotation-style=line` flag.
 #[test]
 fn compile_requirements_in_annotation_line() -> Result<()> {
@@ -288,6 +434,80 @@ fn compile_constraints_markers() -> Result<()> {
     Ok(())
 }
 
+/// Resolve a package with a constraint that only applies to older Python versions, targeting a
+/// Python version to which the constraint doesn't apply.
+#[test]
+fn compile_constraints_markers_inactive() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio")?;
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str("anyio<4 ; python_version < '3.10'")?;
+
+    uv_snapshot!(context.compile()
+            .arg("requirements.in")
+            .arg("--constraint")
+            .arg("constraints.txt")
+            .arg("--python-version")
+            .arg("3.12"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] --exclude-newer 2024-03-25T00:00:00Z requirements.in --constraint constraints.txt --python-version 3.12
+    anyio==4.3.0
+    idna==3.6
+        # via anyio
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Resolve a package with a constraint that only applies to older Python versions, targeting a
+/// Python version to which the constraint applies.
+#[test]
+fn compile_constraints_markers_active() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio")?;
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str("anyio<4 ; python_version < '3.10'")?;
+
+    uv_snapshot!(context.compile()
+            .arg("requirements.in")
+            .arg("--constraint")
+            .arg("constraints.txt")
+            .arg("--python-version")
+            .arg("3.9"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] --exclude-newer 2024-03-25T00:00:00Z requirements.in --constraint constraints.txt --python-version 3.9
+    anyio==3.7.1
+    exceptiongroup==1.2.0
+        # via anyio
+    idna==3.6
+        # via anyio
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 /// Resolve a package from an optional dependency group in a `pyproject.toml` file.
 #[test]
 fn compile_pyproject_toml_extra() -> Result<()> {
@@ -759,6 +979,58 @@ optional-dependencies.foo = [
     Ok(())
 }
 
+/// Request an accelerator without any accelerator indexes configured.
+#[test]
+fn compile_accelerator_without_indexes() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig")?;
+
+    uv_snapshot!(context.compile()
+            .arg("requirements.in")
+            .arg("--accelerator")
+            .arg("cu121"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] --exclude-newer 2024-03-25T00:00:00Z requirements.in --accelerator cu121
+    iniconfig==2.0.0
+
+    ----- stderr -----
+    warning: Ignoring accelerator `cu121`, as no accelerator indexes are configured
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Reject an invalid accelerator.
+#[test]
+fn compile_accelerator_invalid() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig")?;
+
+    uv_snapshot!(context.compile()
+            .arg("requirements.in")
+            .arg("--accelerator")
+            .arg("gpu"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'gpu' for '--accelerator <ACCELERATOR>': Invalid accelerator `gpu` (expected `cpu`, `cu<version>`, or `rocm<version>`)
+
+    For more information, try '--help'.
+    "###
+    );
+
+    Ok(())
+}
+
 /// Resolve a specific version of Black at Python 3.12.
 #[test]
 fn compile_python_312() -> Result<()> {
@@ -877,7 +1149,7 @@ fn compile_python_37() -> Result<()> {
             .arg("--python-version")
             .arg("3.7"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -1450,7 +1722,7 @@ fn conflicting_direct_url_dependency() -> Result<()> {
     uv_snapshot!(context.compile()
             .arg("requirements.in"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -1497,7 +1769,7 @@ fn conflicting_repeated_url_dependency_version_mismatch() -> Result<()> {
     uv_snapshot!(context.compile()
             .arg("requirements.in"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -1551,7 +1823,7 @@ fn conflicting_repeated_url_dependency_version_match() -> Result<()> {
     uv_snapshot!(context.compile()
             .arg("requirements.in"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -1574,7 +1846,7 @@ fn conflicting_transitive_url_dependency() -> Result<()> {
     uv_snapshot!(context.compile()
             .arg("requirements.in"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -1632,7 +1904,7 @@ fn conflicting_repeated_url_dependency() -> Result<()> {
     uv_snapshot!(context.compile()
             .arg("requirements.in"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -1761,7 +2033,7 @@ fn incompatible_narrowed_url_dependency() -> Result<()> {
     uv_snapshot!(context.compile()
             .arg("requirements.in"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -1924,7 +2196,7 @@ fn requirement_constraint_override_url() -> Result<()> {
         .arg("--override")
         .arg("overrides.txt"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -2030,8 +2302,9 @@ optional-dependencies.bar = [
     Ok(())
 }
 
+/// Write one output file per extra, with the shared dependencies in a base file.
 #[test]
-fn compile_pyproject_toml_all_extras_annotation_line() -> Result<()> {
+fn compile_pyproject_toml_split_extras() -> Result<()> {
     let context = TestContext::new("3.12");
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
     pyproject_toml.write_str(
@@ -2051,33 +2324,54 @@ optional-dependencies.bar = [
     )?;
 
     uv_snapshot!(context.compile()
-            .arg("--annotation-style=line")
             .arg("pyproject.toml")
-            .arg("--all-extras"), @r###"
+            .arg("--split-extras")
+            .arg("--output-dir")
+            .arg("requirements")
+            .arg("--no-header"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
-    # This file was autogenerated by uv via the following command:
-    #    uv pip compile --cache-dir [CACHE_DIR] --exclude-newer 2024-03-25T00:00:00Z --annotation-style=line pyproject.toml --all-extras
-    anyio==3.7.0              # via httpcore
-    certifi==2024.2.2         # via httpcore
-    h11==0.14.0               # via httpcore
-    httpcore==0.18.0
-    idna==3.6                 # via anyio
-    iniconfig==1.1.1
-    sniffio==1.3.1            # via anyio, httpcore
 
     ----- stderr -----
     Resolved 7 packages in [TIME]
     "###
     );
 
+    let base = fs_err::read_to_string(context.temp_dir.child("requirements/base.txt"))?;
+    insta::assert_snapshot!(base, @r###"
+    anyio==3.7.0
+        # via httpcore
+    idna==3.6
+        # via anyio
+    sniffio==1.3.1
+        # via
+        #   anyio
+        #   httpcore
+    "###);
+
+    let bar = fs_err::read_to_string(context.temp_dir.child("requirements/bar.txt"))?;
+    insta::assert_snapshot!(bar, @r###"
+    -r base.txt
+    certifi==2024.2.2
+        # via httpcore
+    h11==0.14.0
+        # via httpcore
+    httpcore==0.18.0
+    "###);
+
+    let foo = fs_err::read_to_string(context.temp_dir.child("requirements/foo.txt"))?;
+    insta::assert_snapshot!(foo, @r###"
+    -r base.txt
+    iniconfig==1.1.1
+    "###);
+
     Ok(())
 }
 
-/// Resolve packages from all optional dependency groups in a `pyproject.toml` file.
+/// Reject an extra named `base` with `--split-extras`, as it would overwrite the base file.
 #[test]
-fn compile_does_not_allow_both_extra_and_all_extras() -> Result<()> {
+fn compile_pyproject_toml_split_extras_base() -> Result<()> {
     let context = TestContext::new("3.12");
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
     pyproject_toml.write_str(
@@ -2087,10 +2381,90 @@ requires = ["setuptools", "wheel"]
 [project]
 name = "project"
 dependencies = ["anyio==3.7.0"]
-optional-dependencies.foo = [
+optional-dependencies.base = [
     "iniconfig==1.1.1",
 ]
-optional-dependencies.bar = [
+"#,
+    )?;
+
+    uv_snapshot!(context.compile()
+            .arg("pyproject.toml")
+            .arg("--split-extras")
+            .arg("--output-dir")
+            .arg("requirements"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `--split-extras` can't be used with an extra named `base`, as it would conflict with `base.txt`
+    "###
+    );
+
+    Ok(())
+}
+
+#[test]
+fn compile_pyproject_toml_all_extras_annotation_line() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"[build-system]
+requires = ["setuptools", "wheel"]
+
+[project]
+name = "project"
+dependencies = ["anyio==3.7.0"]
+optional-dependencies.foo = [
+    "iniconfig==1.1.1",
+]
+optional-dependencies.bar = [
+    "httpcore==0.18.0",
+]
+"#,
+    )?;
+
+    uv_snapshot!(context.compile()
+            .arg("--annotation-style=line")
+            .arg("pyproject.toml")
+            .arg("--all-extras"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] --exclude-newer 2024-03-25T00:00:00Z --annotation-style=line pyproject.toml --all-extras
+    anyio==3.7.0              # via httpcore
+    certifi==2024.2.2         # via httpcore
+    h11==0.14.0               # via httpcore
+    httpcore==0.18.0
+    idna==3.6                 # via anyio
+    iniconfig==1.1.1
+    sniffio==1.3.1            # via anyio, httpcore
+
+    ----- stderr -----
+    Resolved 7 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Resolve packages from all optional dependency groups in a `pyproject.toml` file.
+#[test]
+fn compile_does_not_allow_both_extra_and_all_extras() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"[build-system]
+requires = ["setuptools", "wheel"]
+
+[project]
+name = "project"
+dependencies = ["anyio==3.7.0"]
+optional-dependencies.foo = [
+    "iniconfig==1.1.1",
+]
+optional-dependencies.bar = [
     "httpcore==0.18.0",
 ]
 "#,
@@ -2136,7 +2510,7 @@ dependencies = ["anyio==3.7.0", "anyio==4.0.0"]
     uv_snapshot!(context.compile()
             .arg("pyproject.toml"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -2167,7 +2541,7 @@ dependencies = ["anyio==300.1.4"]
     uv_snapshot!(context.compile()
             .arg("pyproject.toml"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -2262,6 +2636,66 @@ fn compile_exclude_newer() -> Result<()> {
     Ok(())
 }
 
+/// Exclude releases that are newer than the minimum release age, unless exempted.
+#[test]
+fn compile_minimum_release_age() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("tqdm==4.64.0")?;
+
+    // No release of `tqdm` is older than 2000 weeks.
+    uv_snapshot!(context.compile()
+            .arg("requirements.in")
+            .arg("--minimum-release-age")
+            .arg("2000w"), @r###"
+    success: false
+    exit_code: 3
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because there is no version of tqdm==4.64.0 and you require tqdm==4.64.0, we can conclude that the requirements are unsatisfiable.
+    "###
+    );
+
+    // Exempt `tqdm` from the minimum release age.
+    uv_snapshot!(context.compile()
+            .arg("requirements.in")
+            .arg("--minimum-release-age")
+            .arg("2000w")
+            .arg("--minimum-release-age")
+            .arg("tqdm=0d"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] --exclude-newer 2024-03-25T00:00:00Z requirements.in --minimum-release-age 2000w --minimum-release-age tqdm=0d
+    tqdm==4.64.0
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    // Check the error message for an invalid age.
+    uv_snapshot!(context.compile()
+            .arg("requirements.in")
+            .arg("--minimum-release-age")
+            .arg("14"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value '14' for '--minimum-release-age <MINIMUM_RELEASE_AGE>': Invalid release age `14` (expected, e.g., `14d` or `<package>=14d`)
+
+    For more information, try '--help'.
+    "###
+    );
+
+    Ok(())
+}
+
 /// Resolve a local path dependency on a specific wheel.
 #[test]
 fn compile_wheel_path_dependency() -> Result<()> {
@@ -2612,7 +3046,7 @@ fn compile_yanked_version_indirect() -> Result<()> {
     uv_snapshot!(context.compile()
             .arg("requirements.in"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -2717,6 +3151,142 @@ fn override_multi_dependency() -> Result<()> {
     Ok(())
 }
 
+/// Flask==3.0.0 depends on Werkzeug>=3.0.0. Demonstrate that an override with extras both
+/// replaces the requirement and enables the extra.
+#[test]
+fn override_dependency_extra() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("flask==3.0.0")?;
+
+    let overrides_txt = context.temp_dir.child("overrides.txt");
+    overrides_txt.write_str("werkzeug[watchdog]==2.3.0")?;
+
+    uv_snapshot!(context.compile()
+            .arg("requirements.in")
+            .arg("--override")
+            .arg("overrides.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] --exclude-newer 2024-03-25T00:00:00Z requirements.in --override overrides.txt
+    blinker==1.7.0
+        # via flask
+    click==8.1.7
+        # via flask
+    flask==3.0.0
+    itsdangerous==2.1.2
+        # via flask
+    jinja2==3.1.3
+        # via flask
+    markupsafe==2.1.5
+        # via
+        #   jinja2
+        #   werkzeug
+    watchdog==4.0.0
+        # via werkzeug
+    werkzeug==2.3.0
+        # via flask
+
+    ----- stderr -----
+    Resolved 8 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Flask==3.0.0 depends on Werkzeug>=3.0.0. Demonstrate that an override scoped to other Python
+/// versions doesn't apply, and that the declared requirement is used instead.
+#[test]
+fn override_dependency_inactive_marker() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("flask==3.0.0")?;
+
+    let overrides_txt = context.temp_dir.child("overrides.txt");
+    overrides_txt.write_str("werkzeug==2.3.0 ; python_version < '3.10'")?;
+
+    uv_snapshot!(context.compile()
+            .arg("requirements.in")
+            .arg("--override")
+            .arg("overrides.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] --exclude-newer 2024-03-25T00:00:00Z requirements.in --override overrides.txt
+    blinker==1.7.0
+        # via flask
+    click==8.1.7
+        # via flask
+    flask==3.0.0
+    itsdangerous==2.1.2
+        # via flask
+    jinja2==3.1.3
+        # via flask
+    markupsafe==2.1.5
+        # via
+        #   jinja2
+        #   werkzeug
+    werkzeug==3.0.1
+        # via flask
+
+    ----- stderr -----
+    Resolved 7 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Flask==3.0.0 depends on Werkzeug>=3.0.0. Demonstrate that we can override this requirement
+/// via `override-dependencies` in the `[tool.uv]` section of a `pyproject.toml`.
+#[test]
+fn override_dependency_settings() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("flask==3.0.0")?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"[tool.uv]
+override-dependencies = ["werkzeug==2.3.0"]
+"#,
+    )?;
+
+    uv_snapshot!(context.compile()
+            .arg("requirements.in"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] --exclude-newer 2024-03-25T00:00:00Z requirements.in
+    blinker==1.7.0
+        # via flask
+    click==8.1.7
+        # via flask
+    flask==3.0.0
+    itsdangerous==2.1.2
+        # via flask
+    jinja2==3.1.3
+        # via flask
+    markupsafe==2.1.5
+        # via
+        #   jinja2
+        #   werkzeug
+    werkzeug==2.3.0
+        # via flask
+
+    ----- stderr -----
+    Resolved 7 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 /// Flask==3.0.0 depends on Werkzeug>=3.0.0. Demonstrate that we can override this
 /// requirement with a URL.
 #[test]
@@ -3566,23 +4136,26 @@ fn generate_hashes_registry() -> Result<()> {
     Ok(())
 }
 
-/// Include hashes from the URL in the generated output.
+/// Include hashes for an explicit algorithm in the generated output.
 #[test]
-fn generate_hashes_source_distribution_url() -> Result<()> {
+fn generate_hashes_algorithm() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("anyio @ https://files.pythonhosted.org/packages/2d/b8/7333d87d5f03247215d86a86362fd3e324111788c6cdd8d2e6196a6ba833/anyio-4.2.0.tar.gz")?;
+    requirements_in.write_str("anyio==4.0.0")?;
 
     uv_snapshot!(context.compile()
         .arg("requirements.in")
-        .arg("--generate-hashes"), @r###"
+        .arg("--generate-hashes")
+        .arg("--hash-algorithm")
+        .arg("sha256"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
     # This file was autogenerated by uv via the following command:
-    #    uv pip compile --cache-dir [CACHE_DIR] --exclude-newer 2024-03-25T00:00:00Z requirements.in --generate-hashes
-    anyio @ https://files.pythonhosted.org/packages/2d/b8/7333d87d5f03247215d86a86362fd3e324111788c6cdd8d2e6196a6ba833/anyio-4.2.0.tar.gz \
-        --hash=sha256:e1875bb4b4e2de1669f4bc7869b6d3f54231cdced71605e6e64c9be77e3be50f
+    #    uv pip compile --cache-dir [CACHE_DIR] --exclude-newer 2024-03-25T00:00:00Z requirements.in --generate-hashes --hash-algorithm sha256
+    anyio==4.0.0 \
+        --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f \
+        --hash=sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a
     idna==3.6 \
         --hash=sha256:9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca \
         --hash=sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f
@@ -3600,12 +4173,116 @@ fn generate_hashes_source_distribution_url() -> Result<()> {
     Ok(())
 }
 
-/// Include hashes from the URL in the generated output.
+/// Compute hashes for algorithms that aren't reported by the index, here, for a wheel in a local
+/// `--find-links` directory.
 #[test]
-fn generate_hashes_built_distribution_url() -> Result<()> {
+fn generate_hashes_algorithm_computed() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("anyio @ https://files.pythonhosted.org/packages/14/fd/2f20c40b45e4fb4324834aea24bd4afdf1143390242c0b33774da0e2e34f/anyio-4.3.0-py3-none-any.whl")?;
+    requirements_in.write_str("validation==2.0.0")?;
+
+    let links = context.temp_dir.child("links");
+    links.child("validation-2.0.0-py3-none-any.whl").write_file(
+        &context
+            .workspace_root
+            .join("scripts")
+            .join("links")
+            .join("validation-2.0.0-py3-none-any.whl"),
+    )?;
+
+    uv_snapshot!(context.compile()
+        .arg("requirements.in")
+        .arg("--find-links")
+        .arg("links")
+        .arg("--no-index")
+        .arg("--generate-hashes")
+        .arg("--hash-algorithm")
+        .arg("sha384")
+        .arg("--hash-algorithm")
+        .arg("sha512"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] --exclude-newer 2024-03-25T00:00:00Z requirements.in --no-index --generate-hashes --hash-algorithm sha384 --hash-algorithm sha512
+    validation==2.0.0 \
+        --hash=sha384:8112ea1b4aa137524cefff692ab8b51b29c589594802c56f58da118933b285f39bef8a7d742b3373f4b9e5dd6983ea55 \
+        --hash=sha512:ed15c88c0e8809db4c53343a5da92b1a01d70fbf34f008fb1e06fd2d2a0ae6594b3e29edabca23fa15170f19d0f80c2d57e771c275ec4a4071094e671326d470
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Reject hash algorithms that aren't supported by pip's hash-checking mode.
+#[test]
+fn generate_hashes_algorithm_md5() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio==4.0.0")?;
+
+    uv_snapshot!(context.compile()
+        .arg("requirements.in")
+        .arg("--generate-hashes")
+        .arg("--hash-algorithm")
+        .arg("md5"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'md5' for '--hash-algorithm <HASH_ALGORITHM>': `md5` is not supported by pip's hash-checking mode
+
+    For more information, try '--help'.
+    "###
+    );
+
+    Ok(())
+}
+
+/// Include hashes from the URL in the generated output.
+#[test]
+fn generate_hashes_source_distribution_url() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio @ https://files.pythonhosted.org/packages/2d/b8/7333d87d5f03247215d86a86362fd3e324111788c6cdd8d2e6196a6ba833/anyio-4.2.0.tar.gz")?;
+
+    uv_snapshot!(context.compile()
+        .arg("requirements.in")
+        .arg("--generate-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] --exclude-newer 2024-03-25T00:00:00Z requirements.in --generate-hashes
+    anyio @ https://files.pythonhosted.org/packages/2d/b8/7333d87d5f03247215d86a86362fd3e324111788c6cdd8d2e6196a6ba833/anyio-4.2.0.tar.gz \
+        --hash=sha256:e1875bb4b4e2de1669f4bc7869b6d3f54231cdced71605e6e64c9be77e3be50f
+    idna==3.6 \
+        --hash=sha256:9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca \
+        --hash=sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f
+        # via anyio
+    sniffio==1.3.1 \
+        --hash=sha256:2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2 \
+        --hash=sha256:f4324edc670a0f49750a81b895f35c3adb843cca46f0530f79fc1babb23789dc
+        # via anyio
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Include hashes from the URL in the generated output.
+#[test]
+fn generate_hashes_built_distribution_url() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio @ https://files.pythonhosted.org/packages/14/fd/2f20c40b45e4fb4324834aea24bd4afdf1143390242c0b33774da0e2e34f/anyio-4.3.0-py3-none-any.whl")?;
 
     uv_snapshot!(context.compile()
         .arg("requirements.in")
@@ -4008,6 +4685,67 @@ fn upgrade_all() -> Result<()> {
     Ok(())
 }
 
+/// Summarize the changes when upgrading an existing resolution.
+#[test]
+fn upgrade_summary() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("black==23.10.1")?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        # This file was autogenerated by uv via the following command:
+        #    uv pip compile requirements.in --python-version 3.12 --cache-dir [CACHE_DIR]
+        black==23.10.1
+        click==8.1.2
+            # via black
+        mypy-extensions==1.0.0
+            # via black
+        packaging==23.2
+            # via black
+        pathspec==0.11.0
+            # via black
+        platformdirs==4.0.0
+            # via black
+        tomli==2.0.1
+            # via black
+    "})?;
+
+    uv_snapshot!(context.compile()
+        .arg("requirements.in")
+        .arg("--output-file")
+        .arg("requirements.txt")
+        .arg("--upgrade-package")
+        .arg("click")
+        .arg("--summary"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] --exclude-newer 2024-03-25T00:00:00Z requirements.in --output-file requirements.txt --upgrade-package click
+    black==23.10.1
+    click==8.1.7
+        # via black
+    mypy-extensions==1.0.0
+        # via black
+    packaging==23.2
+        # via black
+    pathspec==0.11.0
+        # via black
+    platformdirs==4.0.0
+        # via black
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+     - click==8.1.2
+     + click==8.1.7 (https://pypi.org/project/click/8.1.7/)
+     - tomli==2.0.1
+    "###
+    );
+
+    Ok(())
+}
+
 /// Use an existing resolution for `black==23.10.1`, with stale versions of `click` and `pathspec`.
 /// Only `click` should be upgraded.
 #[test]
@@ -4420,7 +5158,7 @@ fn no_index_requirements_txt() -> Result<()> {
     uv_snapshot!(context.compile()
             .arg("requirements.in"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -4528,7 +5266,7 @@ fn offline_registry() -> Result<()> {
             .arg("requirements.in")
             .arg("--offline"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -4655,7 +5393,7 @@ fn offline_find_links() -> Result<()> {
             .arg("https://download.pytorch.org/whl/torch_stable.html")
             .arg("--offline"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -4674,7 +5412,7 @@ fn offline_find_links() -> Result<()> {
             .arg("--no-index")
             .arg("--offline"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -4700,7 +5438,7 @@ fn offline_direct_url() -> Result<()> {
             .arg("requirements.in")
             .arg("--offline"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 4
     ----- stdout -----
 
     ----- stderr -----
@@ -4758,7 +5496,7 @@ fn invalid_metadata_requires_python() -> Result<()> {
             .arg("--find-links")
             .arg(context.workspace_root.join("scripts").join("links")), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -4790,7 +5528,7 @@ fn invalid_metadata_multiple_dist_info() -> Result<()> {
             .arg("--find-links")
             .arg(context.workspace_root.join("scripts").join("links")), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -5008,7 +5746,7 @@ fn compile_constraints_incompatible_url() -> Result<()> {
             .arg("--constraint")
             .arg("constraints.txt"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -5031,7 +5769,7 @@ fn index_url_in_requirements() -> Result<()> {
     uv_snapshot!(context.compile()
             .arg("requirements.in"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -5365,7 +6103,7 @@ fn compile_constraints_incompatible_version() -> Result<()> {
             .arg("--constraint")
             .arg("constraints.txt"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -5393,7 +6131,7 @@ fn conflicting_url_markers() -> Result<()> {
             .arg("--constraint")
             .arg("constraints.txt"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -5541,7 +6279,7 @@ fn override_with_incompatible_constraint() -> Result<()> {
             .arg("--override")
             .arg("overrides.txt"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -5852,6 +6590,57 @@ fn empty_index_url_env_var() -> Result<()> {
     Ok(())
 }
 
+/// Import settings from `pip`'s configuration when `UV_IMPORT_PIP_CONFIG` is set.
+#[test]
+fn import_pip_config() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio")?;
+
+    let pip_conf = context.temp_dir.child("pip.conf");
+    pip_conf.write_str(indoc! {r"
+        [global]
+        index-url = https://pypi.org/simple
+        trusted-host = pypi.org
+        timeout = 60
+    "})?;
+
+    uv_snapshot!(context.compile()
+            .arg("requirements.in")
+            .arg("--emit-index-url")
+            .env("UV_IMPORT_PIP_CONFIG", "1")
+            .env("PIP_CONFIG_FILE", pip_conf.path())
+            .env("PIP_NO_CACHE_DIR", "true")
+            .env("UV_HTTP_TIMEOUT", "30")
+            .env("HOME", context.temp_dir.path())
+            .env("XDG_CONFIG_HOME", context.temp_dir.path())
+            .env("XDG_CONFIG_DIRS", context.temp_dir.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] --exclude-newer 2024-03-25T00:00:00Z requirements.in --emit-index-url
+    --index-url https://pypi.org/simple
+
+    anyio==4.3.0
+    idna==3.6
+        # via anyio
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    warning: Imported settings from pip's configuration:
+      index-url = https://pypi.org/simple (from pip.conf, as `UV_INDEX_URL`)
+      no-cache-dir = true (from PIP_NO_CACHE_DIR, as `UV_NO_CACHE`)
+    warning: Ignored settings from pip's configuration, as the equivalent uv settings are already set: timeout (from pip.conf, superseded by `UV_HTTP_TIMEOUT`)
+    warning: Ignored unsupported settings from pip's configuration: trusted-host (from pip.conf)
+    Resolved 3 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 /// Setting `EXTRA_UV_INDEX_URL` to the empty string should treat it as "unset".
 #[test]
 fn empty_extra_index_url_env_var() -> Result<()> {
@@ -6238,7 +7027,7 @@ fn not_found_direct_url() -> Result<()> {
     uv_snapshot!(context.compile()
             .arg("requirements.in"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 4
     ----- stdout -----
 
     ----- stderr -----
@@ -6277,7 +7066,7 @@ requires-python = "<=3.8"
     uv_snapshot!(context.filters(), context.compile()
         .arg("requirements.in"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -7385,7 +8174,7 @@ requires-python = ">3.8"
         .arg("--override")
         .arg("overrides.txt"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -7554,7 +8343,7 @@ fn compile_index_url_first_match() -> Result<()> {
         .arg("requirements.in")
         .arg("--no-deps"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -7640,129 +8429,254 @@ fn compile_index_url_fallback_prefer_primary() -> Result<()> {
     Ok(())
 }
 
-/// Ensure that `--emit-index-annotation` prints the index URL for each package.
+/// Select a specific local version variant via `--prefer-local`.
+///
+/// Both the CPU and CUDA indexes publish `torch==2.2.0` as local versions (`2.2.0+cpu` and
+/// `2.2.0+cu121`). By default, the CUDA variant sorts higher; with `--prefer-local torch+cpu`, the
+/// CPU variant should be selected instead, despite the exact `==2.2.0` requirement.
 #[test]
-fn emit_index_annotation_pypi_org_simple() -> Result<()> {
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))] // Only Linux has wheels for both variants
+fn compile_prefer_local() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("requests")?;
+    requirements_in.write_str("torch==2.2.0")?;
 
-    uv_snapshot!(context.compile()
+    uv_snapshot!(context.compile_without_exclude_newer()
+        .arg("--index-strategy")
+        .arg("unsafe-best-match")
+        .arg("--index-url")
+        .arg("https://download.pytorch.org/whl/cpu")
+        .arg("--extra-index-url")
+        .arg("https://download.pytorch.org/whl/cu121")
+        .arg("--prefer-local")
+        .arg("torch+cpu")
         .arg("requirements.in")
-        .arg("--emit-index-annotation"), @r###"
+        .arg("--no-deps"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
     # This file was autogenerated by uv via the following command:
-    #    uv pip compile --cache-dir [CACHE_DIR] --exclude-newer 2024-03-25T00:00:00Z requirements.in --emit-index-annotation
-    certifi==2024.2.2
-        # via requests
-        # from https://pypi.org/simple
-    charset-normalizer==3.3.2
-        # via requests
-        # from https://pypi.org/simple
-    idna==3.6
-        # via requests
-        # from https://pypi.org/simple
-    requests==2.31.0
-        # from https://pypi.org/simple
-    urllib3==2.2.1
-        # via requests
-        # from https://pypi.org/simple
+    #    uv pip compile --cache-dir [CACHE_DIR] --index-strategy unsafe-best-match --prefer-local torch+cpu requirements.in --no-deps
+    torch==2.2.0+cpu
 
     ----- stderr -----
-    Resolved 5 packages in [TIME]
+    Resolved 1 package in [TIME]
     "###
     );
 
     Ok(())
 }
 
-/// Ensure that `--emit-index-annotation` plays nicely with `--no-annotate`.
-///
-/// For now, `--no-annotate` doesn't affect `--emit-index-annotation`, in that we still emit the
-/// index annotation, and leave `--no-annotate` to only affect the package _source_ annotations.
+/// Reject an invalid `--prefer-local` value.
 #[test]
-fn emit_index_annotation_no_annotate() -> Result<()> {
+fn compile_prefer_local_invalid() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("requests")?;
+    requirements_in.write_str("torch")?;
 
     uv_snapshot!(context.compile()
-        .arg("requirements.in")
-        .arg("--emit-index-annotation")
-        .arg("--no-annotate"), @r###"
-    success: true
-    exit_code: 0
+        .arg("--prefer-local")
+        .arg("cu121")
+        .arg("requirements.in"), @r###"
+    success: false
+    exit_code: 2
     ----- stdout -----
-    # This file was autogenerated by uv via the following command:
-    #    uv pip compile --cache-dir [CACHE_DIR] --exclude-newer 2024-03-25T00:00:00Z requirements.in --emit-index-annotation --no-annotate
-    certifi==2024.2.2
-        # from https://pypi.org/simple
-    charset-normalizer==3.3.2
-        # from https://pypi.org/simple
-    idna==3.6
-        # from https://pypi.org/simple
-    requests==2.31.0
-        # from https://pypi.org/simple
-    urllib3==2.2.1
-        # from https://pypi.org/simple
 
     ----- stderr -----
-    Resolved 5 packages in [TIME]
+    error: invalid value 'cu121' for '--prefer-local <PREFER_LOCAL>': Invalid local version preference `cu121` (expected `+<label>` or `<package>+<label>`)
+
+    For more information, try '--help'.
     "###
     );
 
     Ok(())
 }
 
-/// Ensure that `--emit-index-annotation` plays nicely with `--annotation-style=line`.
+/// Reject an invalid `--libc` value.
 #[test]
-fn emit_index_annotation_line() -> Result<()> {
+fn compile_libc_invalid() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("requests")?;
+    requirements_in.write_str("anyio")?;
 
     uv_snapshot!(context.compile()
-        .arg("requirements.in")
-        .arg("--emit-index-annotation")
-        .arg("--annotation-style")
-        .arg("line"), @r###"
-    success: true
-    exit_code: 0
+        .arg("--libc")
+        .arg("musl")
+        .arg("requirements.in"), @r###"
+    success: false
+    exit_code: 2
     ----- stdout -----
-    # This file was autogenerated by uv via the following command:
-    #    uv pip compile --cache-dir [CACHE_DIR] --exclude-newer 2024-03-25T00:00:00Z requirements.in --emit-index-annotation --annotation-style line
-    certifi==2024.2.2         # via requests
-        # from https://pypi.org/simple
-    charset-normalizer==3.3.2  # via requests
-        # from https://pypi.org/simple
-    idna==3.6                 # via requests
-        # from https://pypi.org/simple
-    requests==2.31.0
-        # from https://pypi.org/simple
-    urllib3==2.2.1            # via requests
-        # from https://pypi.org/simple
 
     ----- stderr -----
-    Resolved 5 packages in [TIME]
+    error: invalid value 'musl' for '--libc <LIBC>': Invalid libc `musl` (expected, e.g., `manylinux_2_17` or `musllinux_1_2`)
+
+    For more information, try '--help'.
     "###
     );
 
     Ok(())
 }
 
-/// `--emit-index-annotation` where packages are pulled from two distinct indexes.
+/// Resolve for Pyodide, using its tags and markers rather than those of the current interpreter.
 #[test]
-fn emit_index_annotation_multiple_indexes() -> Result<()> {
+fn compile_python_platform_pyodide() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("uv\nrequests")?;
-
+    requirements_in.write_str(indoc! {r"
+        anyio==4.3.0
+        uvloop ; sys_platform != 'emscripten'
+    "})?;
+
+    uv_snapshot!(context.compile()
+        .arg("requirements.in")
+        .arg("--python-platform")
+        .arg("pyodide"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] --exclude-newer 2024-03-25T00:00:00Z requirements.in --python-platform pyodide
+    anyio==4.3.0
+    idna==3.6
+        # via anyio
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Ensure that `--emit-index-annotation` prints the index URL for each package.
+#[test]
+fn emit_index_annotation_pypi_org_simple() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("requests")?;
+
+    uv_snapshot!(context.compile()
+        .arg("requirements.in")
+        .arg("--emit-index-annotation"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] --exclude-newer 2024-03-25T00:00:00Z requirements.in --emit-index-annotation
+    certifi==2024.2.2
+        # via requests
+        # from https://pypi.org/simple
+    charset-normalizer==3.3.2
+        # via requests
+        # from https://pypi.org/simple
+    idna==3.6
+        # via requests
+        # from https://pypi.org/simple
+    requests==2.31.0
+        # from https://pypi.org/simple
+    urllib3==2.2.1
+        # via requests
+        # from https://pypi.org/simple
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Ensure that `--emit-index-annotation` plays nicely with `--no-annotate`.
+///
+/// For now, `--no-annotate` doesn't affect `--emit-index-annotation`, in that we still emit the
+/// index annotation, and leave `--no-annotate` to only affect the package _source_ annotations.
+#[test]
+fn emit_index_annotation_no_annotate() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("requests")?;
+
+    uv_snapshot!(context.compile()
+        .arg("requirements.in")
+        .arg("--emit-index-annotation")
+        .arg("--no-annotate"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] --exclude-newer 2024-03-25T00:00:00Z requirements.in --emit-index-annotation --no-annotate
+    certifi==2024.2.2
+        # from https://pypi.org/simple
+    charset-normalizer==3.3.2
+        # from https://pypi.org/simple
+    idna==3.6
+        # from https://pypi.org/simple
+    requests==2.31.0
+        # from https://pypi.org/simple
+    urllib3==2.2.1
+        # from https://pypi.org/simple
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Ensure that `--emit-index-annotation` plays nicely with `--annotation-style=line`.
+#[test]
+fn emit_index_annotation_line() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("requests")?;
+
+    uv_snapshot!(context.compile()
+        .arg("requirements.in")
+        .arg("--emit-index-annotation")
+        .arg("--annotation-style")
+        .arg("line"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] --exclude-newer 2024-03-25T00:00:00Z requirements.in --emit-index-annotation --annotation-style line
+    certifi==2024.2.2         # via requests
+        # from https://pypi.org/simple
+    charset-normalizer==3.3.2  # via requests
+        # from https://pypi.org/simple
+    idna==3.6                 # via requests
+        # from https://pypi.org/simple
+    requests==2.31.0
+        # from https://pypi.org/simple
+    urllib3==2.2.1            # via requests
+        # from https://pypi.org/simple
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// `--emit-index-annotation` where packages are pulled from two distinct indexes.
+#[test]
+fn emit_index_annotation_multiple_indexes() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("uv\nrequests")?;
+
     uv_snapshot!(context.compile()
         .arg("requirements.in")
         .arg("--extra-index-url")
@@ -7799,7 +8713,7 @@ fn no_version_for_direct_dependency() -> Result<()> {
         // Must error before we make any network requests
         .arg("--offline"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -7810,3 +8724,376 @@ fn no_version_for_direct_dependency() -> Result<()> {
 
     Ok(())
 }
+
+/// Report a resolution failure as JSON, with its exit code, via `--error-format json`.
+#[test]
+fn error_format_json_no_solution() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("pypyp==1,>=1.2")?;
+
+    uv_snapshot!(context.compile()
+        .arg("requirements.in")
+        .arg("--offline")
+        .arg("--error-format")
+        .arg("json"), @r###"
+    success: false
+    exit_code: 3
+    ----- stdout -----
+
+    ----- stderr -----
+    {"error":{"causes":["you require pypyp ∅"],"class":"resolution","exit_code":3,"message":"No solution found when resolving dependencies"}}
+    "###
+    );
+
+    Ok(())
+}
+
+/// Report a network failure as JSON, with its exit code, via `UV_ERROR_FORMAT`.
+#[test]
+fn error_format_json_network() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl")?;
+
+    uv_snapshot!(context.compile()
+        .arg("requirements.in")
+        .arg("--offline")
+        .env("UV_ERROR_FORMAT", "json"), @r###"
+    success: false
+    exit_code: 4
+    ----- stdout -----
+
+    ----- stderr -----
+    {"error":{"causes":["Network connectivity is disabled, but the requested data wasn't found in the cache for: `https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl`"],"class":"network","exit_code":4,"message":"Failed to download: iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl"}}
+    "###
+    );
+
+    Ok(())
+}
+
+/// Reject a retry policy with a minimum backoff that exceeds the maximum backoff.
+#[test]
+fn retry_settings_invalid_backoff() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio==3.7.0")?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"[tool.uv.retry]
+retries = 5
+min-backoff-ms = 2000
+max-backoff-ms = 1000
+retryable = ["connect", "server-error", "hash-mismatch"]
+"#,
+    )?;
+
+    uv_snapshot!(context.compile()
+            .arg("requirements.in"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The minimum retry backoff (2000ms) must not exceed the maximum retry backoff (1000ms)
+    "###
+    );
+
+    Ok(())
+}
+
+/// Reject invalid `no-proxy` host patterns in `[tool.uv.proxy]`.
+#[test]
+fn proxy_settings_invalid_no_proxy() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio==3.7.0")?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"[tool.uv.proxy]
+no-proxy = ["localhost", "corp.*.example.com"]
+"#,
+    )?;
+
+    uv_snapshot!(context.compile()
+            .arg("requirements.in"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Invalid `no-proxy` host pattern: `corp.*.example.com`
+    "###
+    );
+
+    Ok(())
+}
+
+/// Reject invalid nameservers in `[tool.uv.dns]`.
+#[test]
+fn dns_settings_invalid_nameserver() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio==3.7.0")?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"[tool.uv.dns]
+ip-strategy = "prefer-ipv4"
+nameservers = ["10.0.0.2", "dns.corp.example.com"]
+"#,
+    )?;
+
+    uv_snapshot!(context.compile()
+            .arg("requirements.in"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Invalid nameserver: `dns.corp.example.com`
+    "###
+    );
+
+    Ok(())
+}
+
+/// Reject disabling certificate verification for PyPI via `[[tool.uv.pip.index-settings]]`.
+#[test]
+fn index_settings_insecure_pypi() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio==3.7.0")?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"[[tool.uv.pip.index-settings]]
+url = "https://pypi.org/simple"
+allow-insecure = true
+"#,
+    )?;
+
+    uv_snapshot!(context.compile()
+            .arg("requirements.in"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Certificate verification can't be disabled for `pypi.org`
+    "###
+    );
+
+    Ok(())
+}
+
+/// Reject a missing certificate bundle in `[[tool.uv.pip.index-settings]]`.
+#[test]
+fn index_settings_missing_cert() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio==3.7.0")?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"[[tool.uv.pip.index-settings]]
+url = "https://pypi.lab.example.com/simple"
+cert = "lab-ca.pem"
+"#,
+    )?;
+
+    let filters = [(r"`.*lab-ca.pem`", "`[TEMP_DIR]/lab-ca.pem`")]
+        .into_iter()
+        .chain(context.filters())
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.compile()
+            .arg("requirements.in"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: failed to open file `[TEMP_DIR]/lab-ca.pem`
+      Caused by: No such file or directory (os error 2)
+    "###
+    );
+
+    Ok(())
+}
+
+/// Reject invalid certificate pins in `[[tool.uv.pip.index-settings]]`.
+#[test]
+fn index_settings_invalid_pin() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio==3.7.0")?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"[[tool.uv.pip.index-settings]]
+url = "https://pypi.corp.example.com/simple"
+pins = ["sha256/not-a-hash"]
+"#,
+    )?;
+
+    uv_snapshot!(context.compile()
+            .arg("requirements.in"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Invalid certificate pin: `sha256/not-a-hash` (expected `sha256/<base64>` or `cert-sha256/<base64>`)
+    "###
+    );
+
+    Ok(())
+}
+
+/// Require at least one trusted key for a signed `--find-links` source.
+#[test]
+fn find_links_keys_empty() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio==3.7.0")?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"[[tool.uv.pip.find-links-keys]]
+location = "https://wheels.corp.example.com/"
+keys = []
+"#,
+    )?;
+
+    uv_snapshot!(context.compile()
+            .arg("requirements.in"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No trusted keys configured for signed `--find-links` source: `https://wheels.corp.example.com/`
+    "###
+    );
+
+    Ok(())
+}
+
+/// Warn about preferred platforms that aren't supported by the target interpreter.
+#[test]
+fn platform_preference_unsupported() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio==3.7.0")?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"[tool.uv.pip]
+platform-preference = ["manylinux_9_99"]
+"#,
+    )?;
+
+    uv_snapshot!(context.compile()
+            .arg("requirements.in"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] --exclude-newer 2024-03-25T00:00:00Z requirements.in
+    anyio==3.7.0
+    idna==3.6
+        # via anyio
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    warning: The preferred platform `manylinux_9_99` isn't supported by the target interpreter, and will be ignored
+    Resolved 3 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Emit the build requirements of a source distribution in the resolution.
+#[test]
+fn emit_build_requirements() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("flask @ https://files.pythonhosted.org/packages/d8/09/c1a7354d3925a3c6c8cfdebf4245bae67d633ffda1ba415add06ffc839c5/flask-3.0.0.tar.gz")?;
+
+    uv_snapshot!(context.compile()
+            .arg("requirements.in")
+            .arg("--emit-build-requirements")
+            .arg("build-requirements.txt")
+            .arg("--no-header"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    blinker==1.7.0
+        # via flask
+    click==8.1.7
+        # via flask
+    flask @ https://files.pythonhosted.org/packages/d8/09/c1a7354d3925a3c6c8cfdebf4245bae67d633ffda1ba415add06ffc839c5/flask-3.0.0.tar.gz
+    itsdangerous==2.1.2
+        # via flask
+    jinja2==3.1.3
+        # via flask
+    markupsafe==2.1.5
+        # via
+        #   jinja2
+        #   werkzeug
+    werkzeug==3.0.1
+        # via flask
+
+    ----- stderr -----
+    Resolved 7 packages in [TIME]
+    "###
+    );
+
+    let build_requirements =
+        fs_err::read_to_string(context.temp_dir.child("build-requirements.txt"))?;
+    insta::assert_snapshot!(build_requirements, @r###"
+    flit-core==3.9.0
+        # via flask
+    "###);
+
+    Ok(())
+}
+
+/// Emit hashes for the build requirements of a source distribution in the resolution.
+#[test]
+fn emit_build_requirements_hashes() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("flask @ https://files.pythonhosted.org/packages/d8/09/c1a7354d3925a3c6c8cfdebf4245bae67d633ffda1ba415add06ffc839c5/flask-3.0.0.tar.gz")?;
+
+    uv_snapshot!(context.compile()
+            .arg("requirements.in")
+            .arg("--emit-build-requirements")
+            .arg("build-requirements.txt")
+            .arg("--generate-hashes")
+            .arg("--output-file")
+            .arg("requirements.txt")
+            .arg("--quiet"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "###
+    );
+
+    let build_requirements =
+        fs_err::read_to_string(context.temp_dir.child("build-requirements.txt"))?;
+    assert!(
+        build_requirements.contains("\nflit-core==3.9.0 \\\n    --hash=sha256:"),
+        "{build_requirements}"
+    );
+
+    Ok(())
+}
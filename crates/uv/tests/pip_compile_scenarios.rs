@@ -126,7 +126,7 @@ fn compatible_python_incompatible_override() -> Result<()> {
         .arg("--python-version=3.9")
         , @r###"
                  success: false
-                 exit_code: 1
+                 exit_code: 3
                  ----- stdout -----
 
                  ----- stderr -----
@@ -180,7 +180,7 @@ fn incompatible_python_compatible_override_unavailable_no_wheels() -> Result<()>
         .arg("--python-version=3.11")
         , @r###"
                  success: false
-                 exit_code: 1
+                 exit_code: 3
                  ----- stdout -----
 
                  ----- stderr -----
@@ -291,7 +291,7 @@ fn incompatible_python_compatible_override_no_compatible_wheels() -> Result<()>
         .arg("--python-version=3.11")
         , @r###"
                  success: false
-                 exit_code: 1
+                 exit_code: 3
                  ----- stdout -----
 
                  ----- stderr -----
@@ -349,7 +349,7 @@ fn incompatible_python_compatible_override_other_wheel() -> Result<()> {
         .arg("--python-version=3.11")
         , @r###"
                  success: false
-                 exit_code: 1
+                 exit_code: 3
                  ----- stdout -----
 
                  ----- stderr -----
@@ -405,7 +405,7 @@ fn python_patch_override_no_patch() -> Result<()> {
         .arg("--python-version=3.8")
         , @r###"
                  success: false
-                 exit_code: 1
+                 exit_code: 3
                  ----- stdout -----
 
                  ----- stderr -----
@@ -210,3 +210,61 @@ fn freeze_with_editable() -> Result<()> {
 
     Ok(())
 }
+
+/// List the packages across multiple `site-packages` directories, annotated with their provenance.
+#[test]
+fn freeze_paths() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("tomli==2.0.1")?;
+
+    // Run `pip sync`.
+    sync_command(&context)
+        .arg(requirements_txt.path())
+        .assert()
+        .success();
+
+    // Create a separate `dist-packages` directory, as managed by a system package manager.
+    let dist_packages = context.temp_dir.child("dist-packages");
+    dist_packages
+        .child("six-1.16.0.dist-info")
+        .child("INSTALLER")
+        .write_str("debian\n")?;
+    dist_packages
+        .child("tomli-1.2.0.dist-info")
+        .create_dir_all()?;
+
+    // Run `pip freeze`.
+    uv_snapshot!(context.filters(), command(&context)
+        .arg("--path")
+        .arg(context.site_packages())
+        .arg("--path")
+        .arg(dist_packages.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    six==1.16.0   # dist-packages (debian)
+    tomli==1.2.0  # dist-packages (unknown)
+    tomli==2.0.1  # [SITE_PACKAGES]/ (uv)
+
+    ----- stderr -----
+    "###
+    );
+
+    // Run `pip freeze` on a single directory.
+    uv_snapshot!(context.filters(), command(&context)
+        .arg("--path")
+        .arg(dist_packages.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    six==1.16.0
+    tomli==1.2.0
+
+    ----- stderr -----
+    "###
+    );
+
+    Ok(())
+}
@@ -191,7 +191,7 @@ dependencies = ["flask==1.0.x"]
         .arg("-r")
         .arg("pyproject.toml"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 5
     ----- stdout -----
 
     ----- stderr -----
@@ -262,7 +262,7 @@ fn no_solution() {
         .arg("WerkZeug<1.0.0")
         .arg("--strict"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -302,6 +302,129 @@ fn install_package() {
     context.assert_command("import flask").success();
 }
 
+/// Print a breakdown of the time spent in each phase with `--timings`.
+#[test]
+fn install_timings() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.install()
+        .arg("MarkupSafe==2.1.3")
+        .arg("--timings"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + markupsafe==2.1.3
+    Timings:
+      Metadata fetch  [TIME]
+      Resolution      [TIME]
+      Downloads       [TIME] ([SIZE])
+      Install         [TIME]
+    "###
+    );
+}
+
+/// Print a timestamped line for each step with `--progress plain`.
+#[test]
+fn install_progress_plain() {
+    let context = TestContext::new("3.12");
+    let filters = [(r"\[\s*\d+\.\d+s\]", "[ELAPSED]")]
+        .into_iter()
+        .chain(context.filters())
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.install()
+        .arg("MarkupSafe==2.1.3")
+        .arg("--progress")
+        .arg("plain"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    [ELAPSED] Selected markupsafe==2.1.3
+    Resolved 1 package in [TIME]
+    [ELAPSED] Fetched markupsafe==2.1.3 [1/1]
+    Downloaded 1 package in [TIME]
+    [ELAPSED] Installed markupsafe==2.1.3 [1/1]
+    Installed 1 package in [TIME]
+     + markupsafe==2.1.3
+    "###
+    );
+}
+
+/// Record each HTTP request (and cache verdict) with `--http-trace`.
+#[test]
+fn install_http_trace() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let trace = context.temp_dir.child("trace.jsonl");
+
+    context
+        .install()
+        .arg("MarkupSafe==2.1.3")
+        .arg("--http-trace")
+        .arg(trace.path())
+        .assert()
+        .success();
+
+    let events = fs_err::read_to_string(trace.path())?
+        .lines()
+        .map(serde_json::from_str::<serde_json::Value>)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // The index page should be fetched from PyPI, after missing the (empty) cache.
+    assert!(events.iter().any(|event| event["event"] == "cache"
+        && event["verdict"] == "miss"
+        && event["url"] == "https://pypi.org/simple/markupsafe/"));
+    assert!(events.iter().any(|event| event["event"] == "request"
+        && event["method"] == "GET"
+        && event["url"] == "https://pypi.org/simple/markupsafe/"
+        && event["status"] == 200));
+
+    Ok(())
+}
+
+/// Download wheels during resolution with `--prefetch`, such that they're already cached (or
+/// in flight) at install time.
+#[test]
+fn install_prefetch() {
+    let context = TestContext::new("3.12");
+
+    // Depending on timing, the installer may still be waiting on some of the prefetches.
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain([(r"Downloaded \d+ packages? in \[TIME\]\n", "")])
+        .collect();
+
+    uv_snapshot!(filters, context.install()
+        .arg("Flask")
+        .arg("--prefetch")
+        .arg("4"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 7 packages in [TIME]
+    Installed 7 packages in [TIME]
+     + blinker==1.7.0
+     + click==8.1.7
+     + flask==3.0.2
+     + itsdangerous==2.1.2
+     + jinja2==3.1.3
+     + markupsafe==2.1.5
+     + werkzeug==3.0.1
+    "###
+    );
+
+    context.assert_command("import flask").success();
+}
+
 /// Install a package from a `requirements.txt` into a virtual environment.
 #[test]
 fn install_requirements_txt() -> Result<()> {
@@ -928,7 +1051,7 @@ fn install_no_index() {
         .arg("Flask")
         .arg("--no-index"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -952,7 +1075,7 @@ fn install_no_index_version() {
         .arg("Flask==3.0.0")
         .arg("--no-index"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -1245,6 +1368,35 @@ fn install_git_private_https_pat_not_authorized() {
     "###);
 }
 
+/// With `--cache-scope project`, wheels built from source should be cached per-project.
+#[test]
+fn install_cache_scope_project() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .install()
+        .arg("anyio==4.3.0")
+        .arg("--no-deps")
+        .arg("--no-binary")
+        .arg("anyio")
+        .arg("--cache-scope")
+        .arg("project")
+        .assert()
+        .success();
+
+    context.assert_command("import anyio").success();
+
+    // The built wheel should be stored within a project scope, rather than shared.
+    let built_wheels = context.cache_dir.child("built-wheels-v3");
+    built_wheels
+        .child("pypi")
+        .assert(predicates::path::missing());
+    let projects = fs_err::read_dir(built_wheels.child("project").path())?.count();
+    assert_eq!(projects, 1);
+
+    Ok(())
+}
+
 /// Install a package without using pre-built wheels.
 #[test]
 fn reinstall_no_binary() {
@@ -1347,7 +1499,7 @@ fn only_binary_requirements_txt() {
         .arg("requirements.txt")
         .arg("--strict"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -2436,7 +2588,7 @@ fn no_build_isolation() -> Result<()> {
         .arg("requirements.in")
         .arg("--no-build-isolation"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 5
     ----- stdout -----
 
     ----- stderr -----
@@ -2491,6 +2643,80 @@ fn no_build_isolation() -> Result<()> {
     Ok(())
 }
 
+/// Install with `--no-build-isolation-package`, to disable isolation during PEP 517 builds for a
+/// specific package.
+#[test]
+fn no_build_isolation_package() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio @ https://files.pythonhosted.org/packages/db/4d/3970183622f0330d3c23d9b8a5f52e365e50381fd484d08e3285104333d3/anyio-4.3.0.tar.gz")?;
+
+    // We expect the build to fail, because `setuptools` is not installed.
+    let filters = std::iter::once((r"exit code: 1", "exit status: 1"))
+        .chain(context.filters())
+        .collect::<Vec<_>>();
+    uv_snapshot!(filters, context.install()
+        .arg("-r")
+        .arg("requirements.in")
+        .arg("--no-build-isolation-package")
+        .arg("anyio"), @r###"
+    success: false
+    exit_code: 5
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to download and build: anyio @ https://files.pythonhosted.org/packages/db/4d/3970183622f0330d3c23d9b8a5f52e365e50381fd484d08e3285104333d3/anyio-4.3.0.tar.gz
+      Caused by: Failed to build: anyio @ https://files.pythonhosted.org/packages/db/4d/3970183622f0330d3c23d9b8a5f52e365e50381fd484d08e3285104333d3/anyio-4.3.0.tar.gz
+      Caused by: Build backend failed to determine metadata through `prepare_metadata_for_build_wheel` with exit status: 1
+    --- stdout:
+
+    --- stderr:
+    Traceback (most recent call last):
+      File "<string>", line 8, in <module>
+    ModuleNotFoundError: No module named 'setuptools'
+    ---
+    "###
+    );
+
+    // Install `setuptools` and `wheel`.
+    uv_snapshot!(context.install()
+        .arg("setuptools")
+        .arg("wheel"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Downloaded 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + setuptools==69.2.0
+     + wheel==0.43.0
+    "###);
+
+    // We expect the build to succeed, since `setuptools` is now installed.
+    uv_snapshot!(context.install()
+        .arg("-r")
+        .arg("requirements.in")
+        .arg("--no-build-isolation-package")
+        .arg("anyio"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Downloaded 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==0.0.0 (from https://files.pythonhosted.org/packages/db/4d/3970183622f0330d3c23d9b8a5f52e365e50381fd484d08e3285104333d3/anyio-4.3.0.tar.gz)
+     + idna==3.6
+     + sniffio==1.3.1
+    "###
+    );
+
+    Ok(())
+}
+
 /// This tests that `uv` can read UTF-16LE encoded requirements.txt files.
 ///
 /// Ref: <https://github.com/astral-sh/uv/issues/2276>
@@ -2595,6 +2821,71 @@ fn dry_run_install() -> std::result::Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// Write an installation report, in the format of `pip install --report`.
+#[test]
+fn install_report() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let report = context.temp_dir.child("report.json");
+
+    uv_snapshot!(context.install()
+        .arg("iniconfig==2.0.0")
+        .arg("--dry-run")
+        .arg("--report")
+        .arg(report.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Would download 1 package
+    Would install 1 package
+     + iniconfig==2.0.0
+    "###
+    );
+
+    let mut report: serde_json::Value = serde_json::from_str(&fs_err::read_to_string(report)?)?;
+    let fields = report.as_object_mut().unwrap();
+
+    // The environment varies by platform, and the version by release.
+    let environment = fields.remove("environment").unwrap();
+    assert_eq!(environment["python_version"], "3.12");
+    let version = fields.remove("pip_version").unwrap();
+    assert_eq!(version, env!("CARGO_PKG_VERSION"));
+
+    insta::assert_json_snapshot!(report, @r###"
+    {
+      "install": [
+        {
+          "download_info": {
+            "archive_info": {
+              "hash": "sha256=b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374",
+              "hashes": {
+                "sha256": "b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374"
+              }
+            },
+            "url": "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl"
+          },
+          "is_direct": false,
+          "is_yanked": false,
+          "metadata": {
+            "name": "iniconfig",
+            "requires_python": ">=3.7",
+            "version": "2.0.0"
+          },
+          "requested": true
+        }
+      ],
+      "version": "1"
+    }
+    "###);
+
+    // Nothing should be installed.
+    context.assert_command("import iniconfig").failure();
+
+    Ok(())
+}
+
 #[test]
 fn dry_run_install_url_dependency() -> std::result::Result<(), Box<dyn std::error::Error>> {
     let context = TestContext::new("3.12");
@@ -2849,7 +3140,7 @@ requires-python = "<=3.8"
     uv_snapshot!(context.filters(), context.install()
         .arg(format!("example @ {}", editable_dir.path().display())), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -3069,7 +3360,7 @@ fn reinstall_no_index() {
         .arg("--reinstall")
         .arg("--strict"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -3187,7 +3478,7 @@ fn already_installed_dependent_editable() {
         .arg("--find-links")
         .arg(BUILD_VENDOR_LINKS_URL), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -3283,7 +3574,7 @@ fn already_installed_local_path_dependent() {
         .arg("--find-links")
         .arg(BUILD_VENDOR_LINKS_URL), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -3323,7 +3614,7 @@ fn already_installed_local_path_dependent() {
         .arg("--find-links")
         .arg(BUILD_VENDOR_LINKS_URL), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -3395,7 +3686,7 @@ fn already_installed_local_version_of_remote_package() {
         .arg("anyio==4.2.0")
         .arg("--no-index"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -3412,7 +3703,7 @@ fn already_installed_local_version_of_remote_package() {
         .arg("anyio==4.3.0+foo")
         .arg("--reinstall"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -3623,7 +3914,7 @@ fn already_installed_remote_url() {
         .arg("--no-index")
         .arg("--reinstall"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -3668,7 +3959,7 @@ fn already_installed_remote_url() {
     uv_snapshot!(
         context.install().arg("uv-public-pypackage==0.2.0").arg("--no-index"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -3831,7 +4122,7 @@ fn require_hashes_mismatch() -> Result<()> {
         .arg("requirements.txt")
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 6
     ----- stdout -----
 
     ----- stderr -----
@@ -3859,7 +4150,7 @@ fn require_hashes_missing_dependency() -> Result<()> {
         .arg("requirements.txt")
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 6
     ----- stdout -----
 
     ----- stderr -----
@@ -3888,7 +4179,7 @@ fn require_hashes_editable() -> Result<()> {
         .arg(requirements_txt.path())
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 6
     ----- stdout -----
 
     ----- stderr -----
@@ -3920,7 +4211,7 @@ fn require_hashes_constraint() -> Result<()> {
         .arg("-c")
         .arg(constraints_txt.path()), @r###"
     success: false
-    exit_code: 2
+    exit_code: 6
     ----- stdout -----
 
     ----- stderr -----
@@ -3945,7 +4236,7 @@ fn require_hashes_constraint() -> Result<()> {
         .arg("-c")
         .arg(constraints_txt.path()), @r###"
     success: false
-    exit_code: 2
+    exit_code: 6
     ----- stdout -----
 
     ----- stderr -----
@@ -4063,7 +4354,7 @@ fn require_hashes_override() -> Result<()> {
         .arg("--override")
         .arg(overrides_txt.path()), @r###"
     success: false
-    exit_code: 2
+    exit_code: 6
     ----- stdout -----
 
     ----- stderr -----
@@ -4088,7 +4379,7 @@ fn require_hashes_override() -> Result<()> {
         .arg("--override")
         .arg(overrides_txt.path()), @r###"
     success: false
-    exit_code: 2
+    exit_code: 6
     ----- stdout -----
 
     ----- stderr -----
@@ -4098,3 +4389,404 @@ fn require_hashes_override() -> Result<()> {
 
     Ok(())
 }
+
+/// `--user` installs are rejected in a virtual environment that can't see the user site.
+#[test]
+fn install_user_isolated_venv() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.install()
+        .arg("--user")
+        .arg("iniconfig")
+        .env("PYTHONUSERBASE", context.temp_dir.child("user").path()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Can't perform a `--user` install, as user site-packages are not visible in the virtual environment at [VENV]/. Instead, create the virtual environment with `--system-site-packages`.
+    "###
+    );
+}
+
+/// Install a package into the user site, including its scripts.
+#[test]
+#[cfg(unix)]
+fn install_user() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let project_root = fs_err::canonicalize(std::env::current_dir()?.join("../.."))?;
+
+    // Expose the system site packages (and thus, the user site) to the virtual environment.
+    let pyvenv_cfg = context.venv.join("pyvenv.cfg");
+    fs_err::write(
+        &pyvenv_cfg,
+        fs_err::read_to_string(&pyvenv_cfg)?.replace(
+            "include-system-site-packages = false",
+            "include-system-site-packages = true",
+        ),
+    )?;
+
+    let user_base = context.temp_dir.child("user");
+
+    let filters = [
+        (r"(\d+m )?(\d+\.)?\d+(ms|s)", "[TIME]"),
+        (
+            r"simple-launcher==0\.1\.0 \(from .+\.whl\)",
+            "simple_launcher.whl",
+        ),
+    ];
+
+    uv_snapshot!(filters, context.install()
+        .arg("--user")
+        .arg(format!("simple_launcher@{}", project_root.join("scripts/links/simple_launcher-0.1.0-py3-none-any.whl").display()))
+        .env("PYTHONUSERBASE", user_base.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + simple_launcher.whl
+    "###
+    );
+
+    // The package and its scripts should be installed into the user site, not the environment.
+    user_base
+        .child("lib/python3.12/site-packages/simple_launcher")
+        .assert(predicates::path::is_dir());
+    user_base
+        .child("bin/simple_launcher")
+        .assert(predicates::path::is_file());
+    context
+        .venv
+        .join("bin/simple_launcher")
+        .assert(predicates::path::missing());
+
+    Ok(())
+}
+
+/// Install a package into a custom prefix, rather than the environment itself.
+#[test]
+#[cfg(unix)]
+fn install_prefix() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let prefix = context.temp_dir.child("prefix");
+
+    uv_snapshot!(context.install()
+        .arg("--prefix")
+        .arg(prefix.path())
+        .arg("iniconfig==2.0.0"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    // The package should be installed into the standard scheme under the prefix.
+    prefix
+        .child("lib/python3.12/site-packages/iniconfig")
+        .assert(predicates::path::is_dir());
+    context
+        .site_packages()
+        .join("iniconfig")
+        .assert(predicates::path::missing());
+
+    // The prefix is indexed separately, so the package is already satisfied there...
+    uv_snapshot!(context.install()
+        .arg("--prefix")
+        .arg(prefix.path())
+        .arg("iniconfig==2.0.0"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Audited 1 package in [TIME]
+    "###
+    );
+
+    // ...but not in the environment itself.
+    context.assert_command("import iniconfig").failure();
+
+    Ok(())
+}
+
+/// Virtual environments that are marked as externally managed are only modified with
+/// `--break-system-packages`, or the equivalent setting.
+#[test]
+fn install_externally_managed_venv() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    fs_err::write(
+        context.venv.join("EXTERNALLY-MANAGED"),
+        "[externally-managed]\nError=This environment is managed by the system.\n Use `example install` instead.\n",
+    )?;
+
+    uv_snapshot!(context.filters(), context.install()
+        .arg("iniconfig"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The virtual environment at [VENV]/ is externally managed, and indicates the following:
+
+      This environment is managed by the system.
+      Use `example install` instead.
+
+    To modify it regardless, use `--break-system-packages`.
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.install()
+        .arg("iniconfig")
+        .arg("--break-system-packages"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    // The setting can also be enabled in a `uv.toml`.
+    context
+        .temp_dir
+        .child("uv.toml")
+        .write_str("[pip]\nbreak-system-packages = true")?;
+
+    uv_snapshot!(context.filters(), context.install()
+        .arg("anyio==4.3.0")
+        .arg("--no-deps"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + anyio==4.3.0
+    "###
+    );
+
+    Ok(())
+}
+
+/// Deny wheels with `.pth` files that execute code via `--wheel-policy`, before installing.
+#[test]
+fn install_wheel_policy_deny_pth() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let wheel_policy = context.temp_dir.child("wheel-policy.toml");
+    wheel_policy.write_str(indoc! {r#"
+        unexpected-path = "allow"
+        executable-pth = "deny"
+        suspicious-shebang = "allow"
+        binary-in-pure-wheel = "allow"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.install()
+        .arg("setuptools==69.2.0")
+        .arg("--wheel-policy")
+        .arg("wheel-policy.toml"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    error: The wheel policy denied the installation of: `distutils-precedence.pth` executes code on interpreter startup (in `setuptools==69.2.0`)
+    "###
+    );
+
+    // Exempt packages aren't scanned.
+    wheel_policy.write_str(indoc! {r#"
+        executable-pth = "deny"
+        allow = ["setuptools"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.install()
+        .arg("setuptools==69.2.0")
+        .arg("--wheel-policy")
+        .arg("wheel-policy.toml"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + setuptools==69.2.0
+    "###
+    );
+
+    Ok(())
+}
+
+/// Create a local package that provides a `shared` console script, which prints the name of the
+/// package.
+fn script_package(context: &TestContext, name: &str) -> Result<()> {
+    let package = context.temp_dir.child(name);
+    package.child("pyproject.toml").write_str(&format!(
+        r#"[project]
+name = "{name}"
+version = "0.1.0"
+requires-python = ">=3.8"
+
+[project.scripts]
+shared = "{name}:main"
+"#
+    ))?;
+    package
+        .child(format!("{name}.py"))
+        .write_str(&format!("def main():\n    print(\"{name}\")\n"))?;
+    Ok(())
+}
+
+/// Run the `shared` script in the virtual environment, returning its output.
+fn run_script(context: &TestContext, script: &str) -> String {
+    let executable = context
+        .venv
+        .join(if cfg!(windows) { "Scripts" } else { "bin" })
+        .join(format!("{script}{}", std::env::consts::EXE_SUFFIX));
+    let output = Command::new(executable).output().unwrap();
+    String::from_utf8(output.stdout).unwrap().trim().to_string()
+}
+
+/// Install two packages that provide the same script, warning about the conflict and
+/// overwriting the script by default.
+#[test]
+fn script_conflicts_warn() -> Result<()> {
+    let context = TestContext::new("3.12");
+    script_package(&context, "first")?;
+    script_package(&context, "second")?;
+
+    uv_snapshot!(context.filters(), context.install()
+        .arg("first @ ./first"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + first==0.1.0 (from file://[TEMP_DIR]/first)
+    "###
+    );
+
+    // The second package overwrites the script, and the environment reports the conflict.
+    uv_snapshot!(context.filters(), context.install()
+        .arg("second @ ./second"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    warning: The script `shared` is provided by both `first` and `second`, and will be overwritten
+    Installed 1 package in [TIME]
+     + second==0.1.0 (from file://[TEMP_DIR]/second)
+    warning: The script `shared` is provided by multiple packages (`first`, `second`), and may have been overwritten.
+    "###
+    );
+
+    assert_eq!(run_script(&context, "shared"), "second");
+
+    Ok(())
+}
+
+/// Install two packages that provide the same script with `--script-conflicts error`, which
+/// should fail before making any changes to the environment, including reinstalls.
+#[test]
+fn script_conflicts_error() -> Result<()> {
+    let context = TestContext::new("3.12");
+    script_package(&context, "first")?;
+    script_package(&context, "second")?;
+
+    uv_snapshot!(context.filters(), context.install()
+        .arg("first @ ./first"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + first==0.1.0 (from file://[TEMP_DIR]/first)
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.install()
+        .arg("first @ ./first")
+        .arg("second @ ./second")
+        .arg("--reinstall-package")
+        .arg("first")
+        .arg("--script-conflicts")
+        .arg("error"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Downloaded 2 packages in [TIME]
+    error: Multiple packages provide the same scripts: `shared` (provided by both `first` and `second`)
+    "###
+    );
+
+    // The existing installation is untouched.
+    context.assert_command("import first").success();
+    context.assert_command("import second").failure();
+    assert_eq!(run_script(&context, "shared"), "first");
+
+    Ok(())
+}
+
+/// Install two packages that provide the same script with `--script-conflicts rename`, which
+/// should install the conflicting script under a different name.
+#[test]
+fn script_conflicts_rename() -> Result<()> {
+    let context = TestContext::new("3.12");
+    script_package(&context, "first")?;
+    script_package(&context, "second")?;
+
+    uv_snapshot!(context.filters(), context.install()
+        .arg("first @ ./first")
+        .arg("second @ ./second")
+        .arg("--script-conflicts")
+        .arg("rename"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Downloaded 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + first==0.1.0 (from file://[TEMP_DIR]/first)
+     + second==0.1.0 (from file://[TEMP_DIR]/second)
+    "###
+    );
+
+    assert_eq!(run_script(&context, "shared"), "first");
+    assert_eq!(run_script(&context, "shared-second"), "second");
+
+    Ok(())
+}
@@ -86,7 +86,7 @@ fn requires_package_does_not_exist() {
         .arg("requires-package-does-not-exist-a")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -125,7 +125,7 @@ fn requires_exact_version_does_not_exist() {
         .arg("requires-exact-version-does-not-exist-a==2.0.0")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -166,7 +166,7 @@ fn requires_greater_version_does_not_exist() {
         .arg("requires-greater-version-does-not-exist-a>1.0.0")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -208,7 +208,7 @@ fn requires_less_version_does_not_exist() {
         .arg("requires-less-version-does-not-exist-a<2.0.0")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -249,7 +249,7 @@ fn transitive_requires_package_does_not_exist() {
         .arg("transitive-requires-package-does-not-exist-a")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -290,7 +290,7 @@ fn excluded_only_version() {
         .arg("excluded-only-version-a!=1.0.0")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -346,7 +346,7 @@ fn excluded_only_compatible_version() {
                 .arg("excluded-only-compatible-version-b<3.0.0,>=2.0.0")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -452,7 +452,7 @@ fn dependency_excludes_range_of_compatible_versions() {
                 .arg("dependency-excludes-range-of-compatible-versions-c")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -577,7 +577,7 @@ fn dependency_excludes_non_contiguous_range_of_compatible_versions() {
                 .arg("dependency-excludes-non-contiguous-range-of-compatible-versions-c")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -902,7 +902,7 @@ fn extra_incompatible_with_extra() {
         .arg("extra-incompatible-with-extra-a[extra_b,extra_c]")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -1018,7 +1018,7 @@ fn extra_incompatible_with_root() {
                 .arg("extra-incompatible-with-root-b==2.0.0")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -1125,7 +1125,7 @@ fn direct_incompatible_versions() {
                 .arg("direct-incompatible-versions-a==2.0.0")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -1178,7 +1178,7 @@ fn transitive_incompatible_with_root_version() {
                 .arg("transitive-incompatible-with-root-version-b==1.0.0")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -1236,7 +1236,7 @@ fn transitive_incompatible_with_transitive() {
                 .arg("transitive-incompatible-with-transitive-b")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -1283,7 +1283,7 @@ fn local_simple() {
         .arg("local-simple-a==1.2.3")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -1369,7 +1369,7 @@ fn local_used_without_sdist() {
         .arg("local-used-without-sdist-a==1.2.3")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -1522,7 +1522,7 @@ fn local_transitive_greater_than() {
                 .arg("local-transitive-greater-than-b==2.0.0+foo")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -1633,7 +1633,7 @@ fn local_transitive_less_than() {
                 .arg("local-transitive-less-than-b==2.0.0+foo")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -1743,7 +1743,7 @@ fn local_transitive_confounding() {
         .arg("local-transitive-confounding-a")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -1792,7 +1792,7 @@ fn local_transitive_conflicting() {
                 .arg("local-transitive-conflicting-b==2.0.0+foo")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -1901,7 +1901,7 @@ fn local_greater_than() {
         .arg("local-greater-than-a>1.2.3")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -1979,7 +1979,7 @@ fn local_less_than() {
         .arg("local-less-than-a<1.2.3")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -2014,7 +2014,7 @@ fn local_less_than_or_equal() {
         .arg("local-less-than-or-equal-a<=1.2.3")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -2054,7 +2054,7 @@ fn post_simple() {
         .arg("post-simple-a==1.2.3")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -2132,7 +2132,7 @@ fn post_greater_than() {
         .arg("post-greater-than-a>1.2.3")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -2258,7 +2258,7 @@ fn post_less_than_or_equal() {
         .arg("post-less-than-or-equal-a<=1.2.3")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -2297,7 +2297,7 @@ fn post_less_than() {
         .arg("post-less-than-a<1.2.3")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -2334,7 +2334,7 @@ fn post_local_greater_than() {
         .arg("post-local-greater-than-a>1.2.3")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -2375,7 +2375,7 @@ fn post_local_greater_than_post() {
         .arg("post-local-greater-than-post-a>1.2.3.post1")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -2416,7 +2416,7 @@ fn post_equal_not_available() {
         .arg("post-equal-not-available-a==1.2.3.post0")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -2503,7 +2503,7 @@ fn post_greater_than_post_not_available() {
         .arg("post-greater-than-post-not-available-a>1.2.3.post2")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -2589,7 +2589,7 @@ fn package_only_prereleases_in_range() {
         .arg("package-only-prereleases-in-range-a>0.1.0")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -3037,7 +3037,7 @@ fn transitive_package_only_prereleases_in_range() {
         .arg("transitive-package-only-prereleases-in-range-a")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -3159,7 +3159,7 @@ fn transitive_prerelease_and_stable_dependency() {
                 .arg("transitive-prerelease-and-stable-dependency-b")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -3326,7 +3326,7 @@ fn transitive_prerelease_and_stable_dependency_many_versions() {
                 .arg("transitive-prerelease-and-stable-dependency-many-versions-b")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -3410,7 +3410,7 @@ fn transitive_prerelease_and_stable_dependency_many_versions_holes() {
                 .arg("transitive-prerelease-and-stable-dependency-many-versions-holes-b")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -3666,7 +3666,7 @@ fn python_version_does_not_exist() {
         .arg("python-version-does-not-exist-a==1.0.0")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -3708,7 +3708,7 @@ fn python_less_than_current() {
         .arg("python-less-than-current-a==1.0.0")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -3750,7 +3750,7 @@ fn python_greater_than_current() {
         .arg("python-greater-than-current-a==1.0.0")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -3793,7 +3793,7 @@ fn python_greater_than_current_patch() {
         .arg("python-greater-than-current-patch-a==1.0.0")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -3857,7 +3857,7 @@ fn python_greater_than_current_many() {
         .arg("python-greater-than-current-many-a==1.0.0")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -3957,7 +3957,7 @@ fn python_greater_than_current_excluded() {
         .arg("python-greater-than-current-excluded-a>=2.0.0")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -4154,7 +4154,7 @@ fn no_sdist_no_wheels_with_matching_platform() {
         .arg("no-sdist-no-wheels-with-matching-platform-a")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -4195,7 +4195,7 @@ fn no_sdist_no_wheels_with_matching_python() {
         .arg("no-sdist-no-wheels-with-matching-python-a")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -4236,7 +4236,7 @@ fn no_sdist_no_wheels_with_matching_abi() {
         .arg("no-sdist-no-wheels-with-matching-abi-a")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -4279,7 +4279,7 @@ fn no_wheels_no_build() {
         .arg("no-wheels-no-build-a")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -4318,7 +4318,7 @@ fn only_wheels_no_binary() {
         .arg("only-wheels-no-binary-a")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -4435,7 +4435,7 @@ fn package_only_yanked() {
         .arg("package-only-yanked-a")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -4474,7 +4474,7 @@ fn package_only_yanked_in_range() {
         .arg("package-only-yanked-in-range-a>0.1.0")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -4617,7 +4617,7 @@ fn transitive_package_only_yanked() {
         .arg("transitive-package-only-yanked-a")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -4666,7 +4666,7 @@ fn transitive_package_only_yanked_in_range() {
         .arg("transitive-package-only-yanked-in-range-a")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -4790,7 +4790,7 @@ fn transitive_yanked_and_unyanked_dependency() {
                 .arg("transitive-yanked-and-unyanked-dependency-b")
         , @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
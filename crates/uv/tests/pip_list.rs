@@ -1,6 +1,7 @@
 use std::process::Command;
 
 use anyhow::Result;
+use assert_cmd::prelude::*;
 use assert_fs::fixture::FileWriteStr;
 use assert_fs::fixture::PathChild;
 
@@ -565,3 +566,83 @@ fn list_format_freeze() {
     "###
     );
 }
+
+#[test]
+fn list_not_required_user_requested() {
+    let context = TestContext::new("3.12");
+
+    install_command(&context)
+        .arg("anyio==4.3.0")
+        .assert()
+        .success();
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain(vec![(r"\-\-\-\-\-\-+.*", "[UNDERLINE]"), ("  +", " ")])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, Command::new(get_bin())
+    .arg("pip")
+    .arg("list")
+    .arg("--not-required")
+    .arg("--cache-dir")
+    .arg(context.cache_dir.path())
+    .env("VIRTUAL_ENV", context.venv.as_os_str())
+    .env("UV_NO_WRAP", "1")
+    .current_dir(&context.temp_dir), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Package Version
+    [UNDERLINE]
+    anyio 4.3.0
+
+    ----- stderr -----
+    "###
+    );
+
+    uv_snapshot!(filters, Command::new(get_bin())
+    .arg("pip")
+    .arg("list")
+    .arg("--user-requested")
+    .arg("--cache-dir")
+    .arg(context.cache_dir.path())
+    .env("VIRTUAL_ENV", context.venv.as_os_str())
+    .env("UV_NO_WRAP", "1")
+    .current_dir(&context.temp_dir), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Package Version
+    [UNDERLINE]
+    anyio 4.3.0
+
+    ----- stderr -----
+    "###
+    );
+
+    // Requesting an installed dependency marks it as requested.
+    install_command(&context).arg("idna").assert().success();
+
+    uv_snapshot!(filters, Command::new(get_bin())
+    .arg("pip")
+    .arg("list")
+    .arg("--user-requested")
+    .arg("--cache-dir")
+    .arg(context.cache_dir.path())
+    .env("VIRTUAL_ENV", context.venv.as_os_str())
+    .env("UV_NO_WRAP", "1")
+    .current_dir(&context.temp_dir), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Package Version
+    [UNDERLINE]
+    anyio 4.3.0
+    idna 3.6
+
+    ----- stderr -----
+    "###
+    );
+}
@@ -545,3 +545,111 @@ fn show_required_by_multiple() -> Result<()> {
 
     Ok(())
 }
+
+/// `--format json` should include the provenance of each package.
+#[test]
+fn show_json() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("requests==2.31.0")?;
+
+    uv_snapshot!(install_command(&context)
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Downloaded 5 packages in [TIME]
+    Installed 5 packages in [TIME]
+     + certifi==2024.2.2
+     + charset-normalizer==3.3.2
+     + idna==3.6
+     + requests==2.31.0
+     + urllib3==2.2.1
+    "###
+    );
+
+    uv_snapshot!(context.filters(), Command::new(get_bin())
+        .arg("pip")
+        .arg("show")
+        .arg("idna")
+        .arg("--format")
+        .arg("json")
+        .arg("--cache-dir")
+        .arg(context.cache_dir.path())
+        .env("VIRTUAL_ENV", context.venv.as_os_str())
+        .env("UV_NO_WRAP", "1")
+        .current_dir(&context.temp_dir), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [{"name":"idna","version":"3.6","location":"[SITE_PACKAGES]/","installer":"uv","direct_url":null,"requires":[],"required_by":["requests"]}]
+
+    ----- stderr -----
+    "###
+    );
+
+    Ok(())
+}
+
+/// `--files --verbose` should flag files that were modified or removed since installation.
+#[test]
+fn show_files_verify() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(install_command(&context)
+        .arg("iniconfig==2.0.0"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    // Modify one file, and remove another.
+    let site_packages = context.site_packages();
+    fs_err::write(site_packages.join("iniconfig/__init__.py"), "# patched\n")?;
+    fs_err::remove_file(site_packages.join("iniconfig/py.typed"))?;
+
+    let output = Command::new(get_bin())
+        .arg("pip")
+        .arg("show")
+        .arg("iniconfig")
+        .arg("--files")
+        .arg("--verbose")
+        .arg("--cache-dir")
+        .arg(context.cache_dir.path())
+        .env("VIRTUAL_ENV", context.venv.as_os_str())
+        .env("UV_NO_WRAP", "1")
+        .current_dir(&context.temp_dir)
+        .output()?;
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout)?;
+    let files = stdout
+        .lines()
+        .skip_while(|line| *line != "Files:")
+        .skip(1)
+        .collect::<Vec<_>>();
+    assert!(
+        files.contains(&"  iniconfig/__init__.py (modified)"),
+        "{stdout}"
+    );
+    assert!(
+        files.contains(&"  iniconfig/py.typed (missing)"),
+        "{stdout}"
+    );
+    assert!(files.contains(&"  iniconfig/_parse.py"), "{stdout}");
+
+    Ok(())
+}
@@ -247,6 +247,42 @@ fn install_hardlink() -> Result<()> {
     Ok(())
 }
 
+/// Install a package into a virtual environment using symlink semantics.
+#[test]
+#[cfg(unix)]
+fn install_symlink() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3")?;
+
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt")
+        .arg("--link-mode")
+        .arg("symlink")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + markupsafe==2.1.3
+    "###
+    );
+
+    context.assert_command("import markupsafe").success();
+
+    // Removing the cache _should_ invalidate the virtual environment.
+    fs::remove_dir_all(context.cache_dir.path())?;
+
+    context.assert_command("import markupsafe").failure();
+
+    Ok(())
+}
+
 /// Install multiple packages into a virtual environment.
 #[test]
 fn install_many() -> Result<()> {
@@ -412,6 +448,49 @@ fn add_remove() -> Result<()> {
     Ok(())
 }
 
+/// Replace a package in a virtual environment with `--atomic`, and verify that the transaction
+/// directory is removed on success.
+#[test]
+fn sync_atomic() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3")?;
+
+    command(&context)
+        .arg("requirements.txt")
+        .arg("--atomic")
+        .assert()
+        .success();
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("tomli==2.0.1")?;
+
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt")
+        .arg("--atomic"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - markupsafe==2.1.3
+     + tomli==2.0.1
+    "###
+    );
+
+    context.assert_command("import tomli").success();
+    context.assert_command("import markupsafe").failure();
+
+    assert!(!context.venv.join(".uv-transaction").exists());
+
+    Ok(())
+}
+
 /// Install a package into a virtual environment, then install a second package into the same
 /// virtual environment.
 #[test]
@@ -698,8 +777,8 @@ fn install_url_then_install_url() -> Result<()> {
     Ok(())
 }
 
-/// Install a package via a URL, then via a registry version. The second install _should_ remove the
-/// URL-based version, but doesn't right now.
+/// Install a package via a URL, then via a registry version. The second install should remove the
+/// URL-based version.
 #[test]
 fn install_url_then_install_version() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -724,7 +803,12 @@ fn install_url_then_install_version() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    Audited 1 package in [TIME]
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - werkzeug==2.0.0 (from https://files.pythonhosted.org/packages/ff/1d/960bb4017c68674a1cb099534840f18d3def3ce44aed12b5ed8b78e0153e/Werkzeug-2.0.0-py3-none-any.whl)
+     + werkzeug==2.0.0
     "###
     );
 
@@ -815,7 +899,7 @@ fn install_no_index() -> Result<()> {
         .arg("--no-index")
         .arg("--strict"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -867,7 +951,7 @@ fn install_no_index_cached() -> Result<()> {
         .arg("--no-index")
         .arg("--strict"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -1072,7 +1156,7 @@ fn mismatched_name() -> Result<()> {
         .arg("requirements.txt")
         .arg("--strict"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -2116,6 +2200,58 @@ fn refresh_package() -> Result<()> {
     Ok(())
 }
 
+/// Verify that we can force refresh of selective artifact URLs.
+#[test]
+fn refresh_url() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("werkzeug @ https://files.pythonhosted.org/packages/ff/1d/960bb4017c68674a1cb099534840f18d3def3ce44aed12b5ed8b78e0153e/Werkzeug-2.0.0-py3-none-any.whl\ntomli==2.0.1")?;
+
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Downloaded 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + tomli==2.0.1
+     + werkzeug==2.0.0 (from https://files.pythonhosted.org/packages/ff/1d/960bb4017c68674a1cb099534840f18d3def3ce44aed12b5ed8b78e0153e/Werkzeug-2.0.0-py3-none-any.whl)
+    "###
+    );
+
+    // Re-run the installation into a new virtual environment with `--refresh-url`. Ensure that we
+    // revalidate the selected URL, but reuse the cached registry distribution.
+    let parent = context.temp_dir.child("parent");
+    parent.create_dir_all()?;
+    let venv = create_venv(&parent, &context.cache_dir, "3.12");
+
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt")
+        .arg("--refresh-url")
+        .arg("https://files.pythonhosted.org/packages/ff/1d/960bb4017c68674a1cb099534840f18d3def3ce44aed12b5ed8b78e0153e/Werkzeug-2.0.0-py3-none-any.whl")
+        .arg("--strict")
+        .env("VIRTUAL_ENV", venv.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Installed 2 packages in [TIME]
+     + tomli==2.0.1
+     + werkzeug==2.0.0 (from https://files.pythonhosted.org/packages/ff/1d/960bb4017c68674a1cb099534840f18d3def3ce44aed12b5ed8b78e0153e/Werkzeug-2.0.0-py3-none-any.whl)
+    "###
+    );
+
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "maturin")]
 fn sync_editable() -> Result<()> {
@@ -2523,7 +2659,7 @@ fn find_links_offline_no_match() -> Result<()> {
         .arg("--find-links")
         .arg(context.workspace_root.join("scripts/links/")), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -2646,7 +2782,7 @@ fn offline() -> Result<()> {
         .arg("requirements.in")
         .arg("--offline"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -3075,7 +3211,7 @@ requires-python = "<=3.5"
     uv_snapshot!(context.filters(), command(&context)
         .arg("requirements.in"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -3141,7 +3277,7 @@ fn require_hashes_missing_hash() -> Result<()> {
         .arg("requirements.txt")
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 6
     ----- stdout -----
 
     ----- stderr -----
@@ -3182,7 +3318,7 @@ fn require_hashes_missing_version() -> Result<()> {
         .arg("requirements.txt")
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 6
     ----- stdout -----
 
     ----- stderr -----
@@ -3223,7 +3359,7 @@ fn require_hashes_invalid_operator() -> Result<()> {
         .arg("requirements.txt")
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 6
     ----- stdout -----
 
     ----- stderr -----
@@ -3249,7 +3385,7 @@ fn require_hashes_wheel_no_binary() -> Result<()> {
         .arg(":all:")
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 6
     ----- stdout -----
 
     ----- stderr -----
@@ -3342,7 +3478,7 @@ fn require_hashes_source_only_binary() -> Result<()> {
         .arg(":all:")
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 6
     ----- stdout -----
 
     ----- stderr -----
@@ -3375,7 +3511,7 @@ fn require_hashes_wrong_digest() -> Result<()> {
         .arg("requirements.txt")
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 6
     ----- stdout -----
 
     ----- stderr -----
@@ -3408,7 +3544,7 @@ fn require_hashes_wrong_algorithm() -> Result<()> {
         .arg("requirements.txt")
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 6
     ----- stdout -----
 
     ----- stderr -----
@@ -3479,7 +3615,7 @@ fn require_hashes_source_url() -> Result<()> {
         .arg("--reinstall")
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 6
     ----- stdout -----
 
     ----- stderr -----
@@ -3510,7 +3646,7 @@ fn require_hashes_source_url_mismatch() -> Result<()> {
         .arg("requirements.txt")
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 6
     ----- stdout -----
 
     ----- stderr -----
@@ -3579,7 +3715,7 @@ fn require_hashes_wheel_url() -> Result<()> {
         .arg("--reinstall")
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 6
     ----- stdout -----
 
     ----- stderr -----
@@ -3634,7 +3770,7 @@ fn require_hashes_wheel_url_mismatch() -> Result<()> {
         .arg("requirements.txt")
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 6
     ----- stdout -----
 
     ----- stderr -----
@@ -3667,7 +3803,7 @@ fn require_hashes_git() -> Result<()> {
         .arg("requirements.txt")
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 6
     ----- stdout -----
 
     ----- stderr -----
@@ -3697,7 +3833,7 @@ fn require_hashes_source_tree() -> Result<()> {
         .arg("requirements.txt")
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 6
     ----- stdout -----
 
     ----- stderr -----
@@ -3742,7 +3878,7 @@ fn require_hashes_re_download() -> Result<()> {
         .arg("--reinstall")
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 6
     ----- stdout -----
 
     ----- stderr -----
@@ -3833,7 +3969,7 @@ fn require_hashes_wheel_path_mismatch() -> Result<()> {
         .arg("requirements.txt")
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 6
     ----- stdout -----
 
     ----- stderr -----
@@ -3903,7 +4039,7 @@ fn require_hashes_source_path_mismatch() -> Result<()> {
         .arg("requirements.txt")
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 6
     ----- stdout -----
 
     ----- stderr -----
@@ -3993,7 +4129,7 @@ fn require_hashes_repeated_dependency() -> Result<()> {
         .arg("requirements.txt")
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 6
     ----- stdout -----
 
     ----- stderr -----
@@ -4010,7 +4146,7 @@ fn require_hashes_repeated_dependency() -> Result<()> {
         .arg("requirements.txt")
         .arg("--require-hashes"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 6
     ----- stdout -----
 
     ----- stderr -----
@@ -4114,7 +4250,7 @@ fn require_hashes_repeated_hash() -> Result<()> {
         .arg("--require-hashes")
         .arg("--reinstall"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 6
     ----- stdout -----
 
     ----- stderr -----
@@ -4244,7 +4380,7 @@ fn require_hashes_find_links_no_hash() -> Result<()> {
         .arg("--find-links")
         .arg("https://raw.githubusercontent.com/astral-test/astral-test-hash/main/no-hash/simple-html/example-a-961b4c22/index.html"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 6
     ----- stdout -----
 
     ----- stderr -----
@@ -4274,7 +4410,7 @@ fn require_hashes_find_links_no_hash() -> Result<()> {
         .arg("--find-links")
         .arg("https://raw.githubusercontent.com/astral-test/astral-test-hash/main/no-hash/simple-html/example-a-961b4c22/index.html"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 6
     ----- stdout -----
 
     ----- stderr -----
@@ -4366,7 +4502,7 @@ fn require_hashes_find_links_invalid_hash() -> Result<()> {
         .arg("--find-links")
         .arg("https://raw.githubusercontent.com/astral-test/astral-test-hash/main/invalid-hash/simple-html/example-a-961b4c22/index.html"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 6
     ----- stdout -----
 
     ----- stderr -----
@@ -4395,7 +4531,7 @@ fn require_hashes_find_links_invalid_hash() -> Result<()> {
         .arg("--find-links")
         .arg("https://raw.githubusercontent.com/astral-test/astral-test-hash/main/invalid-hash/simple-html/example-a-961b4c22/index.html"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 6
     ----- stdout -----
 
     ----- stderr -----
@@ -4475,7 +4611,7 @@ fn require_hashes_find_links_invalid_hash() -> Result<()> {
         .arg("--find-links")
         .arg("https://raw.githubusercontent.com/astral-test/astral-test-hash/main/invalid-hash/simple-html/example-a-961b4c22/index.html"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 6
     ----- stdout -----
 
     ----- stderr -----
@@ -4538,7 +4674,7 @@ fn require_hashes_registry_valid_hash() -> Result<()> {
         .arg("--find-links")
         .arg("https://astral-test.github.io/astral-test-hash/valid-hash/simple-html/"), @r###"
     success: false
-    exit_code: 1
+    exit_code: 3
     ----- stdout -----
 
     ----- stderr -----
@@ -4566,7 +4702,7 @@ fn require_hashes_registry_invalid_hash() -> Result<()> {
         .arg("--index-url")
         .arg("https://astral-test.github.io/astral-test-hash/invalid-hash/simple-html/"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 6
     ----- stdout -----
 
     ----- stderr -----
@@ -4595,7 +4731,7 @@ fn require_hashes_registry_invalid_hash() -> Result<()> {
         .arg("--index-url")
         .arg("https://astral-test.github.io/astral-test-hash/invalid-hash/simple-html/"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 6
     ----- stdout -----
 
     ----- stderr -----
@@ -4675,7 +4811,7 @@ fn require_hashes_registry_invalid_hash() -> Result<()> {
         .arg("--index-url")
         .arg("https://astral-test.github.io/astral-test-hash/invalid-hash/simple-html/"), @r###"
     success: false
-    exit_code: 2
+    exit_code: 6
     ----- stdout -----
 
     ----- stderr -----
@@ -4693,3 +4829,494 @@ fn require_hashes_registry_invalid_hash() -> Result<()> {
 
     Ok(())
 }
+
+/// Skip any packages that match `--no-install-package`.
+#[test]
+fn no_install_package_pattern() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        anyio==4.3.0
+        idna==3.6
+        sniffio==1.3.1
+        "
+    })?;
+
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt")
+        .arg("--no-install-package")
+        .arg("Any*"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Downloaded 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + idna==3.6
+     + sniffio==1.3.1
+    "###
+    );
+
+    Ok(())
+}
+
+/// Skip any packages that match `--no-install-package`, along with any dependencies that aren't
+/// required by another package.
+#[test]
+fn no_install_package_deps() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        anyio==4.3.0
+        idna==3.6
+        iniconfig==2.0.0
+        sniffio==1.3.1
+        "
+    })?;
+
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt")
+        .arg("--no-install-package")
+        .arg("anyio")
+        .arg("--no-install-package-deps"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    Ok(())
+}
+
+/// Skip an editable that matches `--no-install-package`, along with any dependencies that are only
+/// required by the editable.
+#[test]
+fn no_install_package_deps_editable() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"[project]
+name = "example"
+version = "0.0.0"
+dependencies = [
+  "anyio==4.3.0"
+]
+requires-python = ">=3.8"
+"#,
+    )?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        -e .
+        anyio==4.3.0
+        idna==3.6
+        iniconfig==2.0.0
+        sniffio==1.3.1
+        "
+    })?;
+
+    uv_snapshot!(context.filters(), command(&context)
+        .arg("requirements.txt")
+        .arg("--no-install-package")
+        .arg("example")
+        .arg("--no-install-package-deps"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Built 1 editable in [TIME]
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    Ok(())
+}
+
+/// Sync the dependencies and the project in separate layers.
+#[test]
+fn sync_layers() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(&indoc::formatdoc! {r"
+        anyio==4.3.0
+        idna==3.6
+        sniffio==1.3.1
+        -e file://{workspace_root}/scripts/packages/poetry_editable
+        ",
+        workspace_root = context.workspace_root.simplified_display(),
+    })?;
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain(vec![(r"(?m)^[0-9a-f]{16}$", "[HASH]")])
+        .collect::<Vec<_>>();
+
+    // Install the dependencies, without building the editable.
+    uv_snapshot!(filters, command(&context)
+        .arg(requirements_txt.path())
+        .arg("--layer")
+        .arg("deps"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [HASH]
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Downloaded 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==4.3.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "###
+    );
+
+    // Install the project, leaving the dependencies in place.
+    uv_snapshot!(filters, command(&context)
+        .arg(requirements_txt.path())
+        .arg("--layer")
+        .arg("project"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [HASH]
+
+    ----- stderr -----
+    Built 1 editable in [TIME]
+    Installed 1 package in [TIME]
+     + poetry-editable==0.1.0 (from file://[WORKSPACE]/scripts/packages/poetry_editable)
+    "###
+    );
+
+    Ok(())
+}
+
+/// The layer hash should change with the index locations and with the project's sources.
+#[test]
+fn sync_layers_hash() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    copy_dir_all(
+        context
+            .workspace_root
+            .join("scripts/packages/poetry_editable"),
+        context.temp_dir.join("poetry_editable"),
+    )?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        anyio==4.3.0
+        idna==3.6
+        sniffio==1.3.1
+        -e ./poetry_editable
+        "
+    })?;
+
+    let layer_hash = |layer: &str, args: &[&str]| -> Result<String> {
+        let output = command(&context)
+            .arg("requirements.txt")
+            .arg("--layer")
+            .arg(layer)
+            .args(args)
+            .output()?;
+        assert!(output.status.success());
+        Ok(String::from_utf8(output.stdout)?)
+    };
+
+    // Adding a `--find-links` location changes the hash of the dependencies.
+    let deps = layer_hash("deps", &[])?;
+    let find_links = context.workspace_root.join("scripts").join("links");
+    let deps_find_links = layer_hash(
+        "deps",
+        &["--find-links", find_links.to_str().expect("UTF-8 path")],
+    )?;
+    assert_ne!(deps, deps_find_links);
+
+    // Modifying the project's sources changes the hash of the project.
+    let project = layer_hash("project", &[])?;
+    assert_eq!(project, layer_hash("project", &[])?);
+    context
+        .temp_dir
+        .child("poetry_editable")
+        .child("poetry_editable")
+        .child("__init__.py")
+        .write_str("def b():\n    pass\n")?;
+    assert_ne!(project, layer_hash("project", &[])?);
+
+    Ok(())
+}
+
+/// Leave packages that aren't in the requirements in place with `--no-prune`.
+#[test]
+fn no_prune() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0\ntomli==2.0.1")?;
+
+    command(&context).arg("requirements.txt").assert().success();
+
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt")
+        .arg("--no-prune")
+        .arg("tomli"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Audited 1 package in [TIME]
+    "###
+    );
+
+    context.assert_command("import tomli").success();
+
+    Ok(())
+}
+
+/// Reinstall packages that were built for a different interpreter (e.g., after the environment's
+/// interpreter was upgraded in place).
+#[test]
+fn reinstall_incompatible_interpreter() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3")?;
+
+    command(&context).arg("requirements.txt").assert().success();
+
+    // Record the tags of another interpreter in the installed distribution.
+    let wheel = context
+        .site_packages()
+        .join("MarkupSafe-2.1.3.dist-info")
+        .join("WHEEL");
+    let contents = fs::read_to_string(&wheel)?
+        .lines()
+        .filter(|line| !line.starts_with("Tag:"))
+        .chain(["Tag: cp311-cp311-manylinux_2_17_x86_64"])
+        .collect::<Vec<_>>()
+        .join("\n");
+    fs::write(&wheel, contents)?;
+
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: markupsafe==2.1.3 was built for a different interpreter (`cp311-cp311-manylinux_2_17_x86_64`), and will be reinstalled
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - markupsafe==2.1.3
+     + markupsafe==2.1.3
+    "###
+    );
+
+    context.assert_command("import markupsafe").success();
+
+    Ok(())
+}
+
+/// Create a local package that provides a `shared` console script, which prints the name of the
+/// package, returning its URL.
+fn script_package(context: &TestContext, name: &str) -> Result<Url> {
+    let package = context.temp_dir.child(name);
+    package.child("pyproject.toml").write_str(&format!(
+        r#"[project]
+name = "{name}"
+version = "0.1.0"
+requires-python = ">=3.8"
+
+[project.scripts]
+shared = "{name}:main"
+"#
+    ))?;
+    package
+        .child(format!("{name}.py"))
+        .write_str(&format!("def main():\n    print(\"{name}\")\n"))?;
+    Ok(Url::from_file_path(package.path()).unwrap())
+}
+
+/// Run a script in the virtual environment, returning its output.
+fn run_script(context: &TestContext, script: &str) -> String {
+    let executable = context
+        .venv
+        .join(if cfg!(windows) { "Scripts" } else { "bin" })
+        .join(format!("{script}{EXE_SUFFIX}"));
+    let output = Command::new(executable).output().unwrap();
+    String::from_utf8(output.stdout).unwrap().trim().to_string()
+}
+
+/// Sync two packages that provide the same script, warning about the conflict and overwriting
+/// the script by default.
+#[test]
+fn script_conflicts_warn() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let first = script_package(&context, "first")?;
+    let second = script_package(&context, "second")?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(&format!("first @ {first}\nsecond @ {second}"))?;
+
+    uv_snapshot!(context.filters(), command(&context)
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Downloaded 2 packages in [TIME]
+    warning: The script `shared` is provided by both `first` and `second`, and will be overwritten
+    Installed 2 packages in [TIME]
+     + first==0.1.0 (from file://[TEMP_DIR]/first)
+     + second==0.1.0 (from file://[TEMP_DIR]/second)
+    warning: The script `shared` is provided by multiple packages (`first`, `second`), and may have been overwritten.
+    "###
+    );
+
+    Ok(())
+}
+
+/// Sync a package that provides the same script as an installed package with
+/// `--script-conflicts error`, which should fail before making any changes to the environment.
+#[test]
+fn script_conflicts_error() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let first = script_package(&context, "first")?;
+    let second = script_package(&context, "second")?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(&format!("first @ {first}"))?;
+
+    uv_snapshot!(context.filters(), command(&context)
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + first==0.1.0 (from file://[TEMP_DIR]/first)
+    "###
+    );
+
+    // Replacing `first` with `second` doesn't conflict, since `first` is removed.
+    requirements_txt.write_str(&format!("second @ {second}"))?;
+
+    uv_snapshot!(context.filters(), command(&context)
+        .arg("requirements.txt")
+        .arg("--script-conflicts")
+        .arg("error"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - first==0.1.0 (from file://[TEMP_DIR]/first)
+     + second==0.1.0 (from file://[TEMP_DIR]/second)
+    "###
+    );
+
+    // Adding `first` back conflicts with `second`.
+    requirements_txt.write_str(&format!("first @ {first}\nsecond @ {second}"))?;
+
+    uv_snapshot!(context.filters(), command(&context)
+        .arg("requirements.txt")
+        .arg("--script-conflicts")
+        .arg("error"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    error: Multiple packages provide the same scripts: `shared` (provided by both `second` and `first`)
+    "###
+    );
+
+    // The environment is untouched.
+    context.assert_command("import first").failure();
+    context.assert_command("import second").success();
+    assert_eq!(run_script(&context, "shared"), "second");
+
+    Ok(())
+}
+
+/// Sync a package that provides the same script as an installed package with
+/// `--script-conflicts rename`, which should install the script under a different name.
+#[test]
+fn script_conflicts_rename() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let first = script_package(&context, "first")?;
+    let second = script_package(&context, "second")?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(&format!("second @ {second}"))?;
+
+    uv_snapshot!(context.filters(), command(&context)
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + second==0.1.0 (from file://[TEMP_DIR]/second)
+    "###
+    );
+
+    requirements_txt.write_str(&format!("first @ {first}\nsecond @ {second}"))?;
+
+    uv_snapshot!(context.filters(), command(&context)
+        .arg("requirements.txt")
+        .arg("--script-conflicts")
+        .arg("rename")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + first==0.1.0 (from file://[TEMP_DIR]/first)
+    "###
+    );
+
+    assert_eq!(run_script(&context, "shared"), "second");
+    assert_eq!(run_script(&context, "shared-first"), "first");
+
+    Ok(())
+}
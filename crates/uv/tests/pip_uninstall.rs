@@ -220,7 +220,93 @@ fn missing_record() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    error: Cannot uninstall package; RECORD file not found at: [SITE_PACKAGES]/MarkupSafe-2.1.3.dist-info/RECORD
+    error: Cannot uninstall `markupsafe`, as it has no RECORD file (e.g., because it was installed by `distutils`); use `--force` to remove it on a best-effort basis
+    "###
+    );
+
+    Ok(())
+}
+
+#[test]
+fn missing_record_force() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3")?;
+
+    sync_command(&context)
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    // Delete the RECORD file.
+    let dist_info = context.site_packages().join("MarkupSafe-2.1.3.dist-info");
+    fs_err::remove_file(dist_info.join("RECORD"))?;
+
+    // Fall back to `top_level.txt`.
+    uv_snapshot!(context.filters(), uninstall_command(&context)
+        .arg("MarkupSafe")
+        .arg("--force"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `markupsafe` has no RECORD file; any scripts or data files it installed remain in the environment
+    Uninstalled 1 package in [TIME]
+     - markupsafe==2.1.3
+    "###
+    );
+
+    assert!(!dist_info.exists());
+
+    Command::new(venv_to_interpreter(&context.venv))
+        .arg("-c")
+        .arg("import markupsafe")
+        .current_dir(&context.temp_dir)
+        .assert()
+        .failure();
+
+    Ok(())
+}
+
+#[test]
+fn external_installer() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3")?;
+
+    sync_command(&context)
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    // Mark the package as installed by `conda`.
+    let dist_info = context.site_packages().join("MarkupSafe-2.1.3.dist-info");
+    fs_err::write(dist_info.join("INSTALLER"), "conda\n")?;
+
+    uv_snapshot!(context.filters(), uninstall_command(&context)
+        .arg("MarkupSafe"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Cannot uninstall `markupsafe`, as it's managed by `conda`; use `conda` to remove it instead, or `--force` to remove it anyway
+    "###
+    );
+
+    uv_snapshot!(context.filters(), uninstall_command(&context)
+        .arg("MarkupSafe")
+        .arg("--force"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 1 package in [TIME]
+     - markupsafe==2.1.3
     "###
     );
 
@@ -418,3 +504,34 @@ fn uninstall_duplicate() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn uninstall_auto_remove() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // `idna` is requested directly, while `sniffio` is only installed as a dependency of `anyio`.
+    context
+        .install()
+        .arg("anyio==4.3.0")
+        .arg("idna==3.6")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), uninstall_command(&context)
+        .arg("anyio")
+        .arg("--auto-remove"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 2 packages in [TIME]
+     - anyio==4.3.0
+     - sniffio==1.3.1
+    "###
+    );
+
+    context.assert_installed("idna", "3.6");
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "python")]
+
+use std::path::PathBuf;
+use std::process::Command;
+
+use anyhow::Result;
+use assert_cmd::prelude::*;
+
+use crate::common::{get_bin, TestContext};
+
+mod common;
+
+/// Create a `python inspect` command with options shared across scenarios.
+fn inspect_command(context: &TestContext) -> Command {
+    let mut command = Command::new(get_bin());
+    command
+        .arg("python")
+        .arg("inspect")
+        .arg("--cache-dir")
+        .arg(context.cache_dir.path())
+        .env("VIRTUAL_ENV", context.venv.as_os_str())
+        .env("UV_NO_WRAP", "1")
+        .current_dir(&context.temp_dir);
+
+    command
+}
+
+/// `python inspect --format json` should report the markers, `sys.path`, tags, and installation
+/// paths of the virtual environment's interpreter.
+#[test]
+fn inspect_json() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let output = inspect_command(&context)
+        .arg("--format")
+        .arg("json")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let report: serde_json::Value = serde_json::from_slice(&output)?;
+
+    assert_eq!(report["markers"]["python_version"], "3.12");
+    assert_eq!(report["implementation"], "cpython");
+
+    // The highest-priority tag should be specific to the interpreter.
+    let tags = report["tags"].as_array().expect("tags to be an array");
+    assert!(tags[0].as_str().unwrap().starts_with("cp312-cp312-"));
+    assert!(tags.iter().any(|tag| tag == "py3-none-any"));
+
+    // The virtual environment's `site-packages` should be on `sys.path`, and used for
+    // installation.
+    let site_packages = context.site_packages().canonicalize()?;
+    let sys_path = report["sys_path"]
+        .as_array()
+        .expect("sys_path to be an array")
+        .iter()
+        .filter_map(|path| PathBuf::from(path.as_str()?).canonicalize().ok())
+        .collect::<Vec<_>>();
+    assert!(sys_path.contains(&site_packages));
+    assert_eq!(
+        PathBuf::from(report["paths"]["purelib"].as_str().unwrap()).canonicalize()?,
+        site_packages
+    );
+
+    Ok(())
+}
@@ -0,0 +1,161 @@
+#![cfg(feature = "serve-index")]
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::Path;
+use std::process::{Child, Command, Stdio};
+
+use anyhow::{Context, Result};
+use assert_fs::prelude::*;
+
+use crate::common::get_bin;
+
+mod common;
+
+/// The wheel served by the fixture index.
+const WHEEL: &str = "validation-1.0.0-py3-none-any.whl";
+
+/// A file listed by the fixture index whose filename escapes the files directory once decoded
+/// (`validation-1.0.0-py3-none-any/../../../escaped.whl`).
+const TRAVERSAL: &str = "validation-1.0.0-py3-none-any%2F..%2F..%2F..%2Fescaped.whl";
+
+/// Serve a fixture simple index on an ephemeral port, returning its URL.
+///
+/// The index lists a single project (`validation`), with one valid wheel and one file whose
+/// filename attempts a path traversal. Any other file request is served a dummy payload.
+fn fixture_index() -> Result<String> {
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let url = format!("http://{}/simple", listener.local_addr()?);
+    let wheel = fs_err::read(
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../../scripts/links")
+            .join(WHEEL),
+    )?;
+
+    std::thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let _ = respond(stream, &wheel);
+        }
+    });
+
+    Ok(url)
+}
+
+/// Respond to a single request to the fixture index.
+fn respond(mut stream: TcpStream, wheel: &[u8]) -> Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut request = String::new();
+    reader.read_line(&mut request)?;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 || header == "\r\n" {
+            break;
+        }
+    }
+
+    let path = request.split_whitespace().nth(1).unwrap_or_default();
+    let (status, content_type, body) = if path == "/simple/validation/" {
+        let html = format!(
+            "<!DOCTYPE html>\n<html>\n  <body>\n    \
+             <a href=\"/files/{WHEEL}\">{WHEEL}</a><br>\n    \
+             <a href=\"/files/{TRAVERSAL}\">{TRAVERSAL}</a><br>\n  \
+             </body>\n</html>\n"
+        );
+        ("200 OK", "text/html", html.into_bytes())
+    } else if path == format!("/files/{WHEEL}") {
+        ("200 OK", "application/octet-stream", wheel.to_vec())
+    } else if path.starts_with("/files/") {
+        ("200 OK", "application/octet-stream", b"escaped".to_vec())
+    } else {
+        ("404 Not Found", "text/plain", b"Not found".to_vec())
+    };
+
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        body.len()
+    )?;
+    stream.write_all(&body)?;
+    Ok(())
+}
+
+/// Start `uv serve-index` against the given upstream index, returning the process and the URL of
+/// the served index.
+fn serve_index(index_url: &str, cache_dir: &Path) -> Result<(Child, String)> {
+    let mut child = Command::new(get_bin())
+        .arg("serve-index")
+        .arg("--index-url")
+        .arg(index_url)
+        .arg("--bind")
+        .arg("127.0.0.1:0")
+        .arg("--cache-dir")
+        .arg(cache_dir)
+        .env("NO_COLOR", "1")
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    let stderr = child.stderr.take().context("Missing stderr")?;
+    let mut lines = BufReader::new(stderr).lines();
+    let url = loop {
+        let line = lines
+            .next()
+            .context("`uv serve-index` exited before serving")??;
+        if let Some(url) = line.strip_prefix("Serving index at: ") {
+            break url.trim().to_string();
+        }
+    };
+
+    // Drain the remaining output, such that the server never blocks on a full pipe.
+    std::thread::spawn(move || lines.for_each(drop));
+
+    Ok((child, url))
+}
+
+/// Serve a fixture index, ensuring that files are proxied through the cache and that filenames
+/// that would escape the cache are rejected.
+#[test]
+fn serve_fixture_index() -> Result<()> {
+    let temp_dir = assert_fs::TempDir::new()?;
+    let cache_dir = temp_dir.child("cache");
+
+    let index_url = fixture_index()?;
+    let (mut child, url) = serve_index(&index_url, cache_dir.path())?;
+
+    let root = url.trim_end_matches("simple/");
+
+    let result = (|| -> Result<()> {
+        // The project page only links the valid wheel.
+        let response = reqwest::blocking::get(format!("{url}validation/"))?;
+        assert_eq!(response.status(), reqwest::StatusCode::OK);
+        let html = response.text()?;
+        assert!(html.contains(&format!("href=\"../../files/validation/{WHEEL}\"")));
+        assert!(!html.contains("escaped"));
+
+        // The wheel is downloaded through the cache.
+        let response = reqwest::blocking::get(format!("{root}files/validation/{WHEEL}"))?;
+        assert_eq!(response.status(), reqwest::StatusCode::OK);
+        let expected = fs_err::read(
+            Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("../../scripts/links")
+                .join(WHEEL),
+        )?;
+        assert_eq!(response.bytes()?.as_ref(), expected.as_slice());
+
+        // The traversal filename is rejected, and nothing is written outside the cache.
+        let response = reqwest::blocking::get(format!("{root}files/validation/{TRAVERSAL}"))?;
+        assert_eq!(response.status(), reqwest::StatusCode::BAD_REQUEST);
+        assert!(!temp_dir.child("escaped.whl").exists());
+
+        // Unknown files aren't served.
+        let response = reqwest::blocking::get(format!(
+            "{root}files/validation/validation-2.0.0-py3-none-any.whl"
+        ))?;
+        assert_eq!(response.status(), reqwest::StatusCode::NOT_FOUND);
+
+        Ok(())
+    })();
+
+    child.kill()?;
+    child.wait()?;
+    result
+}
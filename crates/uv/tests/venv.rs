@@ -527,3 +527,256 @@ fn verify_nested_pyvenv_cfg() -> Result<()> {
 
     Ok(())
 }
+
+/// Mark a virtual environment as externally managed.
+#[test]
+fn create_venv_externally_managed() -> Result<()> {
+    let context = VenvTestContext::new(&["3.12"]);
+
+    uv_snapshot!(context.filters(), context.venv_command()
+        .arg(context.venv.as_os_str())
+        .arg("--python")
+        .arg("3.12")
+        .arg("--externally-managed")
+        .arg("This environment is managed by the system.\nUse `example install` instead."), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using Python 3.12.[X] interpreter at: [PATH]
+    Creating virtualenv at: .venv
+    Activate with: source .venv/bin/activate
+    "###
+    );
+
+    let externally_managed = fs_err::read_to_string(context.venv.child("EXTERNALLY-MANAGED"))?;
+    insta::assert_snapshot!(externally_managed, @r###"
+    [externally-managed]
+    Error=This environment is managed by the system.
+     Use `example install` instead.
+    "###);
+
+    Ok(())
+}
+
+/// Copy the `site-packages` extras configured in a `uv.toml` into the virtual environment.
+#[test]
+fn create_venv_site_packages_extras() -> Result<()> {
+    let context = VenvTestContext::new(&["3.12"]);
+
+    let shims = context.temp_dir.child("shims");
+    shims.create_dir_all()?;
+    shims
+        .child("sitecustomize.py")
+        .write_str("import os\nos.environ['UV_SITECUSTOMIZE'] = '1'\n")?;
+    shims
+        .child("shims.pth")
+        .write_str(&shims.path().simplified_display().to_string())?;
+
+    context
+        .temp_dir
+        .child("uv.toml")
+        .write_str(indoc::indoc! {r#"
+        [venv]
+        site-packages-extras = ["shims/sitecustomize.py", "shims/shims.pth"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.venv_command()
+        .arg(context.venv.as_os_str())
+        .arg("--python")
+        .arg("3.12"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using Python 3.12.[X] interpreter at: [PATH]
+    Creating virtualenv at: .venv
+    Activate with: source .venv/bin/activate
+    "###
+    );
+
+    // Both files should take effect on interpreter startup.
+    let output = Command::new(common::venv_to_interpreter(&context.venv))
+        .arg("-c")
+        .arg("import os, sys; print(os.environ.get('UV_SITECUSTOMIZE')); print(*sys.path, sep='\\n')")
+        .current_dir(context.temp_dir.path())
+        .output()?;
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout)?;
+    let mut lines = stdout.lines();
+    assert_eq!(lines.next(), Some("1"));
+    let shims = fs_err::canonicalize(shims.path())?;
+    assert!(lines.any(|path| fs_err::canonicalize(path).is_ok_and(|path| path == shims)));
+
+    Ok(())
+}
+
+/// Reject `site-packages` extras that would replace the `_virtualenv` patch.
+#[test]
+fn create_venv_site_packages_extras_reserved() -> Result<()> {
+    let context = VenvTestContext::new(&["3.12"]);
+
+    context.temp_dir.child("_virtualenv.pth").touch()?;
+    context
+        .temp_dir
+        .child("uv.toml")
+        .write_str(indoc::indoc! {r#"
+        [venv]
+        site-packages-extras = ["_virtualenv.pth"]
+    "#})?;
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain([(
+            r"file name used for `site-packages`: .*".to_string(),
+            "file name used for `site-packages`: [PATH]".to_string(),
+        )])
+        .collect();
+
+    uv_snapshot!(filters, context.venv_command()
+        .arg(context.venv.as_os_str())
+        .arg("--python")
+        .arg("3.12")
+        .env("UV_NO_WRAP", "1"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Using Python 3.12.[X] interpreter at: [PATH]
+    Creating virtualenv at: .venv
+    uv::venv::site_packages_extras
+
+      × Failed to add extra files to `site-packages`
+      ╰─▶ Reserved file name used for `site-packages`: [PATH]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Update the settings of an existing virtual environment, without recreating it.
+#[test]
+fn upgrade_settings() -> Result<()> {
+    let context = VenvTestContext::new(&["3.12"]);
+
+    context
+        .venv_command()
+        .arg(context.venv.as_os_str())
+        .arg("--python")
+        .arg("3.12")
+        .assert()
+        .success();
+
+    // Add a file to the environment, which should be retained.
+    let pyvenv_cfg = context.venv.child("pyvenv.cfg");
+    let marker = context.venv.child("marker.txt");
+    marker.touch()?;
+
+    uv_snapshot!(context.filters(), context.venv_command()
+        .arg(context.venv.as_os_str())
+        .arg("--upgrade-settings")
+        .arg("--system-site-packages")
+        .arg("--prompt")
+        .arg("my-project"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Updating virtualenv at: .venv
+    "###
+    );
+
+    marker.assert(predicates::path::exists());
+    let cfg = fs_err::read_to_string(&pyvenv_cfg)?;
+    assert!(cfg.contains("include-system-site-packages = true"));
+    assert!(cfg.contains("prompt = my-project"));
+    assert!(cfg.contains("uv = "));
+
+    // The activation scripts should be regenerated with the new prompt.
+    let scripts = context
+        .venv
+        .child(if cfg!(windows) { "Scripts" } else { "bin" });
+    let activate = fs_err::read_to_string(scripts.child("activate"))?;
+    assert!(activate.contains("my-project"));
+
+    // Without a setting, the existing value should be retained.
+    context
+        .venv_command()
+        .arg(context.venv.as_os_str())
+        .arg("--upgrade-settings")
+        .assert()
+        .success();
+    let cfg = fs_err::read_to_string(&pyvenv_cfg)?;
+    assert!(cfg.contains("include-system-site-packages = true"));
+    assert!(cfg.contains("prompt = my-project"));
+
+    // Revoke access to the system site packages.
+    context
+        .venv_command()
+        .arg(context.venv.as_os_str())
+        .arg("--upgrade-settings")
+        .arg("--no-system-site-packages")
+        .assert()
+        .success();
+    let cfg = fs_err::read_to_string(&pyvenv_cfg)?;
+    assert!(cfg.contains("include-system-site-packages = false"));
+    marker.assert(predicates::path::exists());
+
+    Ok(())
+}
+
+/// `--upgrade-settings` requires an existing virtual environment.
+#[test]
+fn upgrade_settings_missing() {
+    let context = VenvTestContext::new(&["3.12"]);
+
+    uv_snapshot!(context.filters(), context.venv_command()
+        .arg(context.venv.as_os_str())
+        .arg("--upgrade-settings")
+        .env("UV_NO_WRAP", "1"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × Expected `.venv` to be a virtualenv, but `pyvenv.cfg` is missing
+    "###
+    );
+}
+
+/// Generate activation scripts for a subset of shells.
+#[test]
+fn seed_activators() {
+    let context = VenvTestContext::new(&["3.12"]);
+
+    context
+        .venv_command()
+        .arg(context.venv.as_os_str())
+        .arg("--python")
+        .arg("3.12")
+        .arg("--seed-activators")
+        .arg("nushell,xonsh")
+        .assert()
+        .success();
+
+    let scripts = context
+        .venv
+        .child(if cfg!(windows) { "Scripts" } else { "bin" });
+    scripts
+        .child("activate.nu")
+        .assert(predicates::path::is_file());
+    scripts
+        .child("activate.xsh")
+        .assert(predicates::path::is_file());
+    scripts
+        .child("activate")
+        .assert(predicates::path::missing());
+    scripts
+        .child("activate.fish")
+        .assert(predicates::path::missing());
+}